@@ -0,0 +1,44 @@
+use common::{core_type::CoreType, traits::DatabaseInterface, types::Result};
+use common_debug_signers::validate_debug_command_signature;
+use function_name::named;
+use serde_json::json;
+
+use crate::AlgoTxParams;
+
+/// Debug Set ALGO Tx Params
+///
+/// This function sets the parameters used when signing ALGO transactions. The validity window is
+/// the number of rounds a signed transaction remains valid for, up to a maximum of 1000. The fee
+/// per byte is multiplied by the size of a transaction to get its fee, with the ALGO fee stored in
+/// the database acting as a minimum. Passing a fee per byte of 0 means the stored fee is always
+/// used. Enabling leases gives every signed peg-in group a lease unique to that peg-in, which is
+/// required for safely re-signing still-valid peg-ins, eg in order to raise their fees.
+#[named]
+pub fn debug_set_algo_tx_params<D: DatabaseInterface>(
+    db: &D,
+    validity_window: u64,
+    fee_per_byte: u64,
+    use_leases: bool,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    info!("✔ Debug setting ALGO tx params...");
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| {
+            get_debug_command_hash!(
+                function_name!(),
+                &validity_window,
+                &fee_per_byte,
+                &use_leases,
+                core_type
+            )()
+        })
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| AlgoTxParams::new(validity_window, fee_per_byte, use_leases))
+        .and_then(|params| {
+            params.put_in_db(db)?;
+            db.end_transaction()?;
+            Ok(json!({ "success": true, "algo_tx_params": params }).to_string())
+        })
+}
@@ -1,3 +1,4 @@
 mod debug_reset_algo_chain;
+mod debug_set_algo_tx_params;
 
-pub use self::debug_reset_algo_chain::debug_reset_algo_chain;
+pub use self::{debug_reset_algo_chain::debug_reset_algo_chain, debug_set_algo_tx_params::debug_set_algo_tx_params};
@@ -1,7 +1,7 @@
 use common::{constants::ALGO_TAIL_LENGTH, traits::DatabaseInterface, types::Result};
 use serde::{Deserialize, Serialize};
 
-use crate::{AlgoDbUtils, ALGO_SAFE_ADDRESS};
+use crate::{AlgoDbUtils, AlgoTxParams, ALGO_SAFE_ADDRESS};
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AlgoEnclaveState {
@@ -23,6 +23,7 @@ pub struct AlgoEnclaveState {
     algo_canon_block_hash: String,
     algo_anchor_block_hash: String,
     algo_latest_block_hash: String,
+    algo_tx_params: AlgoTxParams,
 }
 
 impl AlgoEnclaveState {
@@ -58,6 +59,7 @@ impl AlgoEnclaveState {
             algo_genesis_hash: db_utils.get_genesis_hash()?.to_string(),
             algo_canon_to_tip_length: db_utils.get_canon_to_tip_length()?,
             algo_linker_hash: db_utils.get_linker_hash_or_else_genesis_hash()?.to_string(),
+            algo_tx_params: AlgoTxParams::get_from_db(db_utils.get_db())?,
         })
     }
 }
@@ -113,6 +115,7 @@ mod tests {
             algo_linker_hash: AlgorandHash::default().to_string(),
             algo_genesis_hash: AlgorandHash::from_genesis_id(genesis_id).unwrap().to_string(),
             algo_core_is_validating: !cfg!(feature = "non-validating"),
+            algo_tx_params: AlgoTxParams::default(),
             // NOTE: The redeem address is generated randomly on initialization!
             algo_address: db_utils
                 .get_algo_private_key()
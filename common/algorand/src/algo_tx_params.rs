@@ -0,0 +1,128 @@
+use std::cmp::max;
+
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    traits::DatabaseInterface,
+    types::{Byte, Bytes, Result},
+    utils::get_prefixed_db_key,
+};
+use rust_algorand::MicroAlgos;
+use serde::{Deserialize, Serialize};
+
+use crate::algo_constants::ALGO_MAX_VALIDITY_WINDOW;
+
+lazy_static! {
+    pub static ref ALGO_TX_PARAMS_KEY: [Byte; 32] = get_prefixed_db_key("algo_tx_params_key");
+}
+
+/// Parameters governing the ALGO transactions signed by the core. The validity window is the
+/// number of rounds after its first valid round that a transaction remains valid for, and the fee
+/// per byte is used to derive a fee from a transaction's size, with the fee stored in the database
+/// acting as a minimum. When leases are enabled, each signed group is given a lease unique to that
+/// peg-in, meaning it cannot be confirmed alongside any re-signed version of itself.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AlgoTxParams {
+    pub validity_window: u64,
+    pub fee_per_byte: u64,
+    pub use_leases: bool,
+}
+
+impl Default for AlgoTxParams {
+    fn default() -> Self {
+        Self {
+            fee_per_byte: 0,
+            use_leases: false,
+            validity_window: ALGO_MAX_VALIDITY_WINDOW,
+        }
+    }
+}
+
+impl AlgoTxParams {
+    pub fn new(validity_window: u64, fee_per_byte: u64, use_leases: bool) -> Result<Self> {
+        if validity_window == 0 || validity_window > ALGO_MAX_VALIDITY_WINDOW {
+            Err(format!(
+                "ALGO tx validity window must be between 1 & {} rounds, got {}!",
+                ALGO_MAX_VALIDITY_WINDOW, validity_window
+            )
+            .into())
+        } else {
+            Ok(Self {
+                use_leases,
+                fee_per_byte,
+                validity_window,
+            })
+        }
+    }
+
+    pub fn get_last_valid_round(&self, first_valid_round: u64) -> u64 {
+        first_valid_round.saturating_add(self.validity_window)
+    }
+
+    /// Calculates the fee for a transaction of the passed in size, never returning less than the
+    /// passed in minimum fee.
+    pub fn get_fee(&self, minimum_fee: &MicroAlgos, tx_size_in_bytes: usize) -> MicroAlgos {
+        let fee = max(
+            minimum_fee.to_algos(),
+            self.fee_per_byte.saturating_mul(tx_size_in_bytes as u64),
+        );
+        debug!("✔ ALGO fee for tx of {} bytes: {}", tx_size_in_bytes, fee);
+        MicroAlgos::new(fee)
+    }
+
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        match db.get(ALGO_TX_PARAMS_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => {
+                debug!("✘ No `AlgoTxParams` in db ∴ using defaults!");
+                Ok(Self::default())
+            },
+        }
+    }
+
+    pub fn put_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        info!("✔ Putting ALGO tx params in db: {:?}", self);
+        db.put(
+            ALGO_TX_PARAMS_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+
+    #[test]
+    fn should_fail_to_create_params_with_invalid_validity_window() {
+        assert!(AlgoTxParams::new(0, 1, true).is_err());
+        assert!(AlgoTxParams::new(ALGO_MAX_VALIDITY_WINDOW + 1, 1, true).is_err());
+    }
+
+    #[test]
+    fn should_get_fee_no_lower_than_minimum() {
+        let params = AlgoTxParams::new(100, 3, true).unwrap();
+        let minimum = MicroAlgos::new(1000);
+        assert_eq!(params.get_fee(&minimum, 200).to_algos(), 1000);
+        assert_eq!(params.get_fee(&minimum, 500).to_algos(), 1500);
+    }
+
+    #[test]
+    fn should_put_and_get_algo_tx_params_in_db() {
+        let db = get_test_database();
+        assert_eq!(AlgoTxParams::get_from_db(&db).unwrap(), AlgoTxParams::default());
+        let params = AlgoTxParams::new(500, 1, true).unwrap();
+        params.put_in_db(&db).unwrap();
+        assert_eq!(AlgoTxParams::get_from_db(&db).unwrap(), params);
+    }
+}
@@ -9,6 +9,7 @@ mod algo_relevant_asset_txs;
 mod algo_signed_group_txs;
 mod algo_state;
 mod algo_submission_material;
+mod algo_tx_params;
 mod algo_user_data;
 mod check_parent_exists;
 mod check_submitted_block_is_subsequent;
@@ -29,6 +30,7 @@ pub use self::{
     algo_constants::{
         ALGO_CORE_IS_INITIALIZED_JSON,
         ALGO_MAX_FOREIGN_ITEMS,
+        ALGO_MAX_VALIDITY_WINDOW,
         ALGO_SAFE_ADDRESS,
         MAX_BYTES_FOR_ALGO_USER_DATA,
     },
@@ -37,7 +39,7 @@ pub use self::{
         start_algo_db_transaction_and_return_state,
     },
     algo_db_utils::{AlgoDatabaseKeysJson, AlgoDbUtils},
-    algo_debug_functions::debug_reset_algo_chain,
+    algo_debug_functions::{debug_reset_algo_chain, debug_set_algo_tx_params},
     algo_enclave_state::AlgoEnclaveState,
     algo_note_metadata::{encode_algo_note_metadata, AlgoNoteMetadata},
     algo_relevant_asset_txs::AlgoRelevantAssetTxs,
@@ -48,6 +50,7 @@ pub use self::{
         AlgoSubmissionMaterial,
         AlgoSubmissionMaterials,
     },
+    algo_tx_params::AlgoTxParams,
     algo_user_data::AlgoUserData,
     check_parent_exists::check_parent_of_algo_block_in_state_exists,
     check_submitted_block_is_subsequent::check_submitted_block_is_subsequent_and_return_state,
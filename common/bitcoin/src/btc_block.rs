@@ -9,13 +9,9 @@ use common::{
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
 
-#[cfg(not(feature = "ltc"))]
-use crate::bitcoin_crate_alias::blockdata::block::BlockHeader as BtcBlockHeader;
-#[cfg(feature = "ltc")]
-use crate::bitcoin_crate_alias::blockdata::block::Header as BtcBlockHeader;
 use crate::{
     bitcoin_crate_alias::{
-        blockdata::block::Block as BtcBlock,
+        blockdata::block::{Block as BtcBlock, BlockHeader as BtcBlockHeader},
         consensus::encode::deserialize as btc_deserialize,
         hash_types::{BlockHash, TxMerkleNode},
         hashes::Hash,
@@ -66,7 +62,6 @@ pub struct BtcBlockJson {
 }
 
 impl BtcBlockJson {
-    #[cfg(not(feature = "ltc"))]
     pub fn to_block_header(&self) -> Result<BtcBlockHeader> {
         info!("✔ Parsing `BtcBlockJson` to `BtcBlockHeader`...");
         Ok(BtcBlockHeader {
@@ -79,18 +74,6 @@ impl BtcBlockJson {
         })
     }
 
-    #[cfg(feature = "ltc")]
-    pub fn to_block_header(&self) -> Result<BtcBlockHeader> {
-        info!("✔ Parsing `BtcBlockJson` to `BtcBlockHeader`...");
-        Ok(BtcBlockHeader {
-            time: self.timestamp,
-            nonce: self.nonce,
-            merkle_root: TxMerkleNode::from_str(&self.merkle_root)?,
-            prev_blockhash: BlockHash::from_str(&self.previousblockhash)?,
-            bits: crate::bitcoin_crate_alias::CompactTarget::from_consensus(self.bits),
-            version: crate::bitcoin_crate_alias::block::Version::from_consensus(self.version),
-        })
-    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Constructor)]
@@ -158,7 +141,7 @@ impl BtcBlockInDbFormat {
         ))?)
     }
 
-    #[cfg(all(test, not(feature = "ltc")))]
+    #[cfg(test)]
     fn to_bytes_legacy(&self) -> Result<Bytes> {
         let serialized_id = self.id.to_vec();
         Ok(serde_json::to_vec(&SerializedBlockInDbFormatLegacy::new(
@@ -294,7 +277,7 @@ pub struct SerializedBlockInDbFormatLegacy {
 }
 
 impl SerializedBlockInDbFormatLegacy {
-    #[cfg(all(test, not(feature = "ltc")))]
+    #[cfg(test)]
     pub fn new(
         id: Bytes,
         height: Bytes,
@@ -319,7 +302,7 @@ impl SerializedBlockInDbFormatLegacy {
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
@@ -1,23 +1,13 @@
 use std::str::FromStr;
 
-#[cfg(feature = "ltc")]
-use crate::bitcoin_crate_alias::locktime::absolute::PackedLockTime;
-#[cfg(not(feature = "ltc"))]
-use crate::bitcoin_crate_alias::PackedLockTime;
 use crate::{
-    bitcoin_crate_alias::{hashes::sha256d, Sequence},
+    bitcoin_crate_alias::{hashes::sha256d, PackedLockTime, Sequence},
     btc_utils::calculate_dust_amount,
 };
 
-#[cfg(feature = "ltc")]
-pub const BTC_TAIL_LENGTH: u64 = 40;
-#[cfg(not(feature = "ltc"))]
 pub const BTC_TAIL_LENGTH: u64 = 10;
 
-#[cfg(not(feature = "ltc"))]
 pub const MINIMUM_REQUIRED_SATOSHIS: u64 = 5000;
-#[cfg(feature = "ltc")]
-pub const MINIMUM_REQUIRED_SATOSHIS: u64 = 10_000;
 
 pub const DUST_RELAY_FEE: u64 = 3; // NOTE: Unit: satoshis-per-byte
 pub const BTC_TX_VERSION: i32 = 1;
@@ -30,7 +30,7 @@ pub fn debug_set_btc_account_nonce<D: DatabaseInterface>(
         .map(prepend_debug_output_marker_to_string)
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::test_utils::{get_test_database, DUMMY_DEBUG_COMMAND_SIGNATURE};
 
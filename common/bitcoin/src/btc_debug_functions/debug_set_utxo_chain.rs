@@ -0,0 +1,84 @@
+use common::{
+    core_type::CoreType,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::prepend_debug_output_marker_to_string,
+};
+use common_debug_signers::validate_debug_command_signature;
+use function_name::named;
+use serde_json::json;
+
+use crate::{btc_database_utils::BtcDbUtils, utxo_chain_params::UtxoChainParams};
+
+/// # Debug Set UTXO Chain
+///
+/// This function sets the UTXO chain the core is tracking to the passed in chain, either `bitcoin`
+/// or `litecoin`, storing that chain's params in the database for the network the core was
+/// initialized with. The core's own address is re-encoded into its canonical form for that chain.
+///
+/// ### NOTE:
+///
+/// This is intended for migrating cores that were initialized before the UTXO chain was selectable
+/// at runtime, eg litecoin cores built with the since removed `ltc` feature, which will not run
+/// until this has been done. It does NOT change the core's keys, UTXOs or blocks, so use with
+/// caution!
+#[named]
+pub fn debug_set_utxo_chain<D: DatabaseInterface>(
+    db: &D,
+    chain: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    info!("✔ Debug setting UTXO chain to '{chain}'...");
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), chain, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| BtcDbUtils::new(db).get_btc_network_from_db())
+        .and_then(|network| UtxoChainParams::from_str_and_network(chain, &network))
+        .and_then(|params| params.put_in_db(db).and(Ok(params)))
+        .and_then(|params| {
+            let db_utils = BtcDbUtils::new(db);
+            if let Ok(address) = db_utils.get_btc_address_from_db() {
+                db_utils.put_btc_address_in_db(&params.to_canonical_address(&address))?;
+            };
+            Ok(params)
+        })
+        .and_then(|params| {
+            db.end_transaction()?;
+            Ok(json!({"success": true, "utxo_chain_params": params}).to_string())
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::{get_test_database, DUMMY_DEBUG_COMMAND_SIGNATURE};
+
+    use common_safe_addresses::SAFE_LTC_ADDRESS_STR;
+
+    use super::*;
+    use crate::{bitcoin_crate_alias::network::constants::Network as BtcNetwork, utxo_chain_params::UtxoChain};
+
+    #[test]
+    fn should_set_utxo_chain() {
+        let db = get_test_database();
+        BtcDbUtils::new(&db).put_btc_network_in_db(BtcNetwork::Testnet).unwrap();
+        debug_set_utxo_chain(&db, "litecoin", &CoreType::BtcOnInt, DUMMY_DEBUG_COMMAND_SIGNATURE).unwrap();
+        let expected_result = UtxoChainParams::new(UtxoChain::Litecoin, &BtcNetwork::Testnet);
+        assert_eq!(UtxoChainParams::get_from_db(&db).unwrap(), expected_result);
+    }
+
+    #[test]
+    fn should_convert_core_address_to_canonical_form_when_setting_utxo_chain() {
+        let db = get_test_database();
+        let db_utils = BtcDbUtils::new(&db);
+        db_utils.put_btc_network_in_db(BtcNetwork::Bitcoin).unwrap();
+        db_utils.put_btc_address_in_db(SAFE_LTC_ADDRESS_STR).unwrap();
+        assert!(UtxoChainParams::get_from_db(&db).is_err());
+        debug_set_utxo_chain(&db, "litecoin", &CoreType::BtcOnInt, DUMMY_DEBUG_COMMAND_SIGNATURE).unwrap();
+        let params = UtxoChainParams::get_from_db(&db).unwrap();
+        let result = db_utils.get_btc_address_from_db().unwrap();
+        assert_eq!(params.from_canonical_address(&result), SAFE_LTC_ADDRESS_STR);
+        assert!(result.starts_with('1'));
+    }
+}
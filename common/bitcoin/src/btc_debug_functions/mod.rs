@@ -1,9 +1,11 @@
 mod debug_set_btc_account_nonce;
 mod debug_set_btc_fee;
 mod debug_set_btc_utxo_nonce;
+mod debug_set_utxo_chain;
 
 pub use self::{
     debug_set_btc_account_nonce::debug_set_btc_account_nonce,
     debug_set_btc_fee::debug_set_btc_fee,
     debug_set_btc_utxo_nonce::debug_set_btc_utxo_nonce,
+    debug_set_utxo_chain::debug_set_utxo_chain,
 };
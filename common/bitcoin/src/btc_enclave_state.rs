@@ -1,11 +1,10 @@
 use common::{traits::DatabaseInterface, types::Result};
-use common_safe_addresses::SAFE_BTC_ADDRESS_STR;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    btc_constants::{BTC_TAIL_LENGTH, MINIMUM_REQUIRED_SATOSHIS},
     btc_database_utils::BtcDbUtils,
     update_btc_linker_hash::get_linker_hash_or_genesis_hash as get_btc_linker_hash,
+    utxo_chain_params::UtxoChainParams,
     utxo_manager::{get_total_number_of_utxos_from_db, get_total_utxo_balance_from_db, get_utxo_nonce_from_db},
 };
 
@@ -35,6 +34,8 @@ pub struct BtcEnclaveState {
     btc_latest_block_hash: String,
     btc_anchor_block_hash: String,
     btc_minimum_required_satoshis: u64,
+    btc_dust_amount: u64,
+    btc_pow_algorithm: String,
 }
 
 impl BtcEnclaveState {
@@ -46,18 +47,20 @@ impl BtcEnclaveState {
         let btc_anchor_block = db_utils.get_btc_anchor_block_from_db()?;
         let btc_latest_block = db_utils.get_btc_latest_block_from_db()?;
         let btc_public_key_hex = hex::encode(db_utils.get_btc_public_key_slice_from_db()?);
-        let btc_fork = if cfg!(feature = "ltc") { "litecoin" } else { "bitcoin" }.to_string();
+        let chain_params = UtxoChainParams::get_from_db(db)?;
         Ok(Self {
-            btc_fork,
-            btc_tail_length: BTC_TAIL_LENGTH,
+            btc_fork: chain_params.chain.to_string(),
+            btc_tail_length: chain_params.tail_length,
+            btc_dust_amount: chain_params.get_dust_amount(),
+            btc_safe_address: chain_params.get_safe_address(),
+            btc_pow_algorithm: format!("{:?}", chain_params.pow_algorithm).to_lowercase(),
             btc_public_key: btc_public_key_hex,
             btc_utxo_nonce: get_utxo_nonce_from_db(db)?,
             btc_tail_block_number: btc_tail_block.height,
             btc_canon_block_number: btc_canon_block.height,
             btc_latest_block_number: btc_latest_block.height,
-            btc_address: db_utils.get_btc_address_from_db()?,
+            btc_address: chain_params.from_canonical_address(&db_utils.get_btc_address_from_db()?),
             btc_anchor_block_number: btc_anchor_block.height,
-            btc_safe_address: SAFE_BTC_ADDRESS_STR.to_string(),
             btc_tail_block_hash: btc_tail_block.id.to_string(),
             btc_sats_per_byte: db_utils.get_btc_fee_from_db()?,
             btc_canon_block_hash: btc_canon_block.id.to_string(),
@@ -65,7 +68,7 @@ impl BtcEnclaveState {
             btc_latest_block_hash: btc_latest_block.id.to_string(),
             btc_anchor_block_hash: btc_anchor_block.id.to_string(),
             btc_core_is_validating: !cfg!(feature = "non-validating"),
-            btc_minimum_required_satoshis: MINIMUM_REQUIRED_SATOSHIS,
+            btc_minimum_required_satoshis: chain_params.minimum_required_satoshis,
             btc_utxo_total_value: get_total_utxo_balance_from_db(db)?,
             btc_number_of_utxos: get_total_number_of_utxos_from_db(db),
             btc_linker_hash: get_btc_linker_hash(db_utils)?.to_string(),
@@ -1,13 +1,9 @@
-#[cfg(not(feature = "ltc"))]
 use std::str::FromStr;
 
 use common::types::{Byte, Bytes, Result};
 use common_chain_ids::BtcChainId;
 use common_metadata::{Metadata, MetadataAddress, MetadataChainId, MetadataProtocolId};
-#[cfg(not(feature = "ltc"))]
 use common_safe_addresses::safely_convert_str_to_btc_address;
-#[cfg(feature = "ltc")]
-use common_safe_addresses::safely_convert_str_to_ltc_address;
 
 pub trait ToMetadata {
     fn get_user_data(&self) -> Option<Bytes>;
@@ -54,7 +50,6 @@ pub trait ToMetadata {
         }
     }
 
-    #[cfg(not(feature = "ltc"))]
     fn to_metadata(&self, user_data: &[Byte], btc_chain_id: &BtcChainId) -> Result<Option<Metadata>> {
         info!("✔ Getting metadata from user data...");
         Ok(Some(Metadata::new(
@@ -65,15 +60,4 @@ pub trait ToMetadata {
             )?,
         )))
     }
-
-    #[cfg(feature = "ltc")]
-    fn to_metadata(&self, user_data: &[Byte], _btc_chain_id: &BtcChainId) -> Result<Option<Metadata>> {
-        info!("✔ Getting metadata from user data...");
-        let ma = &MetadataAddress {
-            // NOTE: We only support litecoin mainnet
-            metadata_chain_id: MetadataChainId::LitecoinMainnet,
-            address: safely_convert_str_to_ltc_address(&self.get_originating_tx_address()).to_string(),
-        };
-        Ok(Some(Metadata::new(user_data, ma)))
-    }
 }
@@ -1,8 +1,5 @@
 use common::types::Result;
-#[cfg(not(feature = "ltc"))]
 use common_safe_addresses::{safely_convert_str_to_btc_address, SAFE_BTC_ADDRESS};
-#[cfg(feature = "ltc")]
-use common_safe_addresses::{safely_convert_str_to_ltc_address, SAFE_LTC_ADDRESS};
 use derive_more::{Constructor, Deref, DerefMut};
 
 use crate::{
@@ -38,27 +35,6 @@ impl BtcRecipientsAndAmounts {
     }
 }
 
-#[cfg(feature = "ltc")]
-impl Default for BtcRecipientAndAmount {
-    fn default() -> Self {
-        Self {
-            amount: 0,
-            recipient: SAFE_LTC_ADDRESS.clone(),
-        }
-    }
-}
-
-#[cfg(feature = "ltc")]
-impl BtcRecipientAndAmount {
-    pub fn new(recipient: &str, amount: u64) -> Result<Self> {
-        Ok(BtcRecipientAndAmount {
-            amount,
-            recipient: safely_convert_str_to_ltc_address(recipient),
-        })
-    }
-}
-
-#[cfg(not(feature = "ltc"))]
 impl Default for BtcRecipientAndAmount {
     fn default() -> Self {
         Self {
@@ -68,7 +44,6 @@ impl Default for BtcRecipientAndAmount {
     }
 }
 
-#[cfg(not(feature = "ltc"))]
 impl BtcRecipientAndAmount {
     pub fn new(recipient: &str, amount: u64) -> Result<Self> {
         Ok(BtcRecipientAndAmount {
@@ -7,7 +7,8 @@ use crate::{
     bitcoin_crate_alias::blockdata::{block::Block as BtcBlock, transaction::Transaction as BtcTransaction},
     btc_block::{BtcBlockAndId, BtcBlockJson},
     btc_utils::convert_hex_tx_to_btc_transaction,
-    deposit_address_info::DepositAddressInfoJsonList,
+    deposit_address_info::{DepositAddressInfoJson, DepositAddressInfoJsonList},
+    utxo_chain_params::UtxoChainParams,
     BtcState,
 };
 
@@ -16,7 +17,10 @@ pub fn parse_btc_submission_json_and_put_in_state<'a, D: DatabaseInterface>(
     state: BtcState<'a, D>,
 ) -> Result<BtcState<'a, D>> {
     info!("✔ Parsing BTC submission json and adding to state...");
-    BtcSubmissionMaterialJson::from_str(json_str).and_then(|result| state.add_btc_submission_json(result))
+    let chain_params = UtxoChainParams::get_from_db(state.db)?;
+    BtcSubmissionMaterialJson::from_str(json_str)
+        .map(|json| json.to_canonical_addresses(&chain_params))
+        .and_then(|result| state.add_btc_submission_json(result))
 }
 
 pub fn parse_submission_material_and_put_in_state<'a, D: DatabaseInterface>(
@@ -24,7 +28,11 @@ pub fn parse_submission_material_and_put_in_state<'a, D: DatabaseInterface>(
     state: BtcState<'a, D>,
 ) -> Result<BtcState<'a, D>> {
     info!("✔ Parsing BTC submisson material and adding to state...");
-    BtcSubmissionMaterial::from_str(json_str).and_then(|result| state.add_btc_submission_material(result))
+    let chain_params = UtxoChainParams::get_from_db(state.db)?;
+    BtcSubmissionMaterialJson::from_str(json_str)
+        .map(|json| json.to_canonical_addresses(&chain_params))
+        .and_then(|json| BtcSubmissionMaterial::from_json(&json))
+        .and_then(|result| state.add_btc_submission_material(result))
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default, Deserialize)]
@@ -50,6 +58,24 @@ impl FromStr for BtcSubmissionMaterialJson {
 }
 
 impl BtcSubmissionMaterialJson {
+    /// Converts the deposit addresses in this submission material into their canonical form, so
+    /// that they may be parsed regardless of which UTXO chain the core is tracking.
+    pub fn to_canonical_addresses(self, chain_params: &UtxoChainParams) -> Self {
+        let deposit_address_list = DepositAddressInfoJsonList::new(
+            self.deposit_address_list
+                .iter()
+                .map(|info| DepositAddressInfoJson {
+                    btc_deposit_address: chain_params.to_canonical_address(&info.btc_deposit_address),
+                    ..info.clone()
+                })
+                .collect(),
+        );
+        Self {
+            deposit_address_list,
+            ..self
+        }
+    }
+
     fn convert_hex_txs_to_btc_transactions(hex_txs: Vec<String>) -> Result<Vec<BtcTransaction>> {
         hex_txs
             .into_iter()
@@ -97,7 +123,7 @@ impl FromStr for BtcSubmissionMaterial {
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::{get_sample_btc_block_n, get_sample_btc_submission_material_json_string};
@@ -145,7 +145,7 @@ pub fn create_signed_raw_btc_tx_for_n_input_n_outputs(
     })
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::AppError;
 
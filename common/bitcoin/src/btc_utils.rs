@@ -199,7 +199,7 @@ pub fn get_btc_tx_id_from_str(tx_id: &str) -> Result<Txid> {
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))] // TODO Create then move this to chains/test_utils!
+#[cfg(test)] // TODO Create then move this to chains/test_utils!
 pub fn get_tx_id_from_signed_btc_tx(signed_btc_tx: &BtcTransaction) -> String {
     let mut tx_id = signed_btc_tx.txid().to_vec();
     tx_id.reverse();
@@ -219,7 +219,7 @@ pub fn convert_wei_to_satoshis(ptoken: U256) -> u64 {
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
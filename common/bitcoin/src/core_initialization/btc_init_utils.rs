@@ -2,6 +2,7 @@ use common::{traits::DatabaseInterface, types::Result};
 
 use crate::{
     bitcoin_crate_alias::network::constants::Network as BtcNetwork,
+    utxo_chain_params::UtxoChainParams,
     utxo_manager::set_utxo_balance_to_zero,
     BtcState,
 };
@@ -59,6 +60,16 @@ pub fn put_btc_network_in_db_and_return_state<'a, D: DatabaseInterface>(
         .and(Ok(state))
 }
 
+pub fn put_utxo_chain_params_in_db_and_return_state<'a, D: DatabaseInterface>(
+    chain: &str,
+    network: &str,
+    state: BtcState<'a, D>,
+) -> Result<BtcState<'a, D>> {
+    UtxoChainParams::from_str_and_network(chain, &get_btc_network_from_arg(network))
+        .and_then(|params| params.put_in_db(state.db))
+        .and(Ok(state))
+}
+
 pub fn put_btc_fee_in_db_and_return_state<D: DatabaseInterface>(fee: u64, state: BtcState<D>) -> Result<BtcState<D>> {
     state.btc_db_utils.put_btc_fee_in_db(fee).and(Ok(state))
 }
@@ -14,6 +14,7 @@ use crate::{
             put_btc_tail_block_hash_in_db_and_return_state,
             put_canon_to_tip_length_in_db_and_return_state,
             put_difficulty_threshold_in_db,
+            put_utxo_chain_params_in_db_and_return_state,
         },
         generate_and_store_btc_keys::generate_and_store_btc_keys_and_return_state,
         get_btc_init_output_json::get_btc_init_output_json,
@@ -22,6 +23,7 @@ use crate::{
     set_btc_anchor_block_hash::maybe_set_btc_anchor_block_hash,
     set_btc_canon_block_hash::maybe_set_btc_canon_block_hash,
     set_btc_latest_block_hash::maybe_set_btc_latest_block_hash,
+    utxo_chain_params::UtxoChain,
     validate_btc_block_header::validate_btc_block_header_in_state,
     validate_btc_difficulty::validate_difficulty_of_btc_block_in_state,
     validate_btc_merkle_root::validate_btc_merkle_root,
@@ -29,6 +31,7 @@ use crate::{
     BtcState,
 };
 
+/// Initializes the core for bitcoin. Use `init_btc_core_for_chain` for any other UTXO chain.
 pub fn init_btc_core<D: DatabaseInterface>(
     state: BtcState<D>,
     block_json_string: &str,
@@ -37,10 +40,34 @@ pub fn init_btc_core<D: DatabaseInterface>(
     network: &str,
     canon_to_tip_length: u64,
 ) -> Result<String> {
-    info!("✔ Initializing enclave for BTC...");
+    init_btc_core_for_chain(
+        state,
+        block_json_string,
+        fee,
+        difficulty,
+        network,
+        canon_to_tip_length,
+        &UtxoChain::Bitcoin.to_string(),
+    )
+}
+
+/// Initializes the core for the passed in UTXO chain, either `bitcoin` or `litecoin`.
+/// The chain's params are stored in the database, meaning all subsequent submissions are
+/// validated & parsed according to that chain's rules.
+pub fn init_btc_core_for_chain<D: DatabaseInterface>(
+    state: BtcState<D>,
+    block_json_string: &str,
+    fee: u64,
+    difficulty: u64,
+    network: &str,
+    canon_to_tip_length: u64,
+    chain: &str,
+) -> Result<String> {
+    info!("✔ Initializing enclave for {chain}...");
     start_btc_db_transaction(state)
         .and_then(|state| put_difficulty_threshold_in_db(difficulty, state))
         .and_then(|state| put_btc_network_in_db_and_return_state(network, state))
+        .and_then(|state| put_utxo_chain_params_in_db_and_return_state(chain, network, state))
         .and_then(|state| put_btc_fee_in_db_and_return_state(fee, state))
         .and_then(|state| parse_submission_material_and_put_in_state(block_json_string, state))
         .and_then(validate_btc_block_header_in_state)
@@ -66,6 +93,8 @@ pub fn init_btc_core<D: DatabaseInterface>(
         .and_then(get_btc_init_output_json)
 }
 
+/// Initializes the core for bitcoin if it's not already initialized. Use
+/// `maybe_initialize_btc_core_for_chain` for any other UTXO chain.
 pub fn maybe_initialize_btc_core<D: DatabaseInterface>(
     db: &D,
     block_json_string: &str,
@@ -73,6 +102,26 @@ pub fn maybe_initialize_btc_core<D: DatabaseInterface>(
     difficulty: u64,
     network: &str,
     canon_to_tip_length: u64,
+) -> Result<String> {
+    maybe_initialize_btc_core_for_chain(
+        db,
+        block_json_string,
+        fee,
+        difficulty,
+        network,
+        canon_to_tip_length,
+        &UtxoChain::Bitcoin.to_string(),
+    )
+}
+
+pub fn maybe_initialize_btc_core_for_chain<D: DatabaseInterface>(
+    db: &D,
+    block_json_string: &str,
+    fee: u64,
+    difficulty: u64,
+    network: &str,
+    canon_to_tip_length: u64,
+    chain: &str,
 ) -> Result<String> {
     info!("✔ Maybe initializing BTC core...");
     let state = BtcState::init(db);
@@ -80,6 +129,14 @@ pub fn maybe_initialize_btc_core<D: DatabaseInterface>(
     if CoreType::native_core_is_initialized(db) {
         Ok(BTC_CORE_IS_INITIALIZED_JSON.to_string())
     } else {
-        init_btc_core(state, block_json_string, fee, difficulty, network, canon_to_tip_length)
+        init_btc_core_for_chain(
+            state,
+            block_json_string,
+            fee,
+            difficulty,
+            network,
+            canon_to_tip_length,
+            chain,
+        )
     }
 }
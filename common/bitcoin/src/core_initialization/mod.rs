@@ -3,4 +3,9 @@ mod generate_and_store_btc_keys;
 mod get_btc_init_output_json;
 mod initialize_btc_core;
 
-pub use self::initialize_btc_core::{init_btc_core, maybe_initialize_btc_core};
+pub use self::initialize_btc_core::{
+    init_btc_core,
+    init_btc_core_for_chain,
+    maybe_initialize_btc_core,
+    maybe_initialize_btc_core_for_chain,
+};
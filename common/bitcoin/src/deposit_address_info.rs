@@ -99,7 +99,7 @@ impl DepositAddressInfoJson {
     }
 
     #[allow(clippy::should_implement_trait)]
-    #[cfg(all(test, not(feature = "ltc")))]
+    #[cfg(test)]
     pub fn from_str(json_string: &str) -> Result<Self> {
         Ok(serde_json::from_str(json_string)?)
     }
@@ -346,7 +346,7 @@ impl DepositAddressInfo {
             .map(|chain_id| info!("✔ Chain ID successfully parsed: {}", chain_id))
     }
 
-    #[cfg(all(test, not(feature = "ltc")))]
+    #[cfg(test)]
     pub fn from_str(s: &str) -> Result<Self> {
         Self::from_json(&DepositAddressInfoJson::from_str(s)?)
     }
@@ -400,7 +400,7 @@ pub fn validate_deposit_address_list_in_state<D: DatabaseInterface>(state: BtcSt
         .and(Ok(state))
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::errors::AppError;
 
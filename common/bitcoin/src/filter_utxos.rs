@@ -1,7 +1,7 @@
 use common::{traits::DatabaseInterface, types::Result};
 
 use crate::{
-    btc_constants::MINIMUM_REQUIRED_SATOSHIS,
+    utxo_chain_params::UtxoChainParams,
     utxo_manager::{utxos_exist_in_db, BtcUtxoAndValue, BtcUtxosAndValues},
     BtcState,
 };
@@ -30,12 +30,15 @@ pub fn filter_out_utxos_extant_in_db<D: DatabaseInterface>(
     })
 }
 
-pub fn filter_out_utxos_whose_value_is_too_low(utxos: &BtcUtxosAndValues) -> Result<BtcUtxosAndValues> {
+pub fn filter_out_utxos_whose_value_is_too_low(
+    utxos: &BtcUtxosAndValues,
+    minimum_required_satoshis: u64,
+) -> Result<BtcUtxosAndValues> {
     Ok(BtcUtxosAndValues::new(
         utxos
             .0
             .iter()
-            .filter(|utxo| match utxo.value >= MINIMUM_REQUIRED_SATOSHIS {
+            .filter(|utxo| match utxo.value >= minimum_required_satoshis {
                 true => true,
                 false => {
                     info!("✘ Filtering UTXO ∵ value too low: {:?}", utxo);
@@ -55,16 +58,20 @@ pub fn filter_out_utxos_extant_in_db_from_state<D: DatabaseInterface>(state: Btc
 
 pub fn filter_out_value_too_low_utxos_from_state<D: DatabaseInterface>(state: BtcState<D>) -> Result<BtcState<D>> {
     info!("✔ Maybe filtering out any UTXOs below minimum # of Satoshis...");
-    filter_out_utxos_whose_value_is_too_low(&state.utxos_and_values)
-        .and_then(|utxos| state.replace_utxos_and_values(utxos))
+    filter_out_utxos_whose_value_is_too_low(
+        &state.utxos_and_values,
+        UtxoChainParams::get_from_db(state.db)?.minimum_required_satoshis,
+    )
+    .and_then(|utxos| state.replace_utxos_and_values(utxos))
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::test_utils::{get_random_num_between, get_test_database};
 
     use super::*;
     use crate::{
+        btc_constants::MINIMUM_REQUIRED_SATOSHIS,
         test_utils::get_sample_utxo_and_values,
         utxo_manager::{save_utxos_to_db, set_utxo_balance_to_zero},
     };
@@ -74,7 +81,7 @@ mod tests {
         let expected_num_utxos_after_filtering = 3;
         let utxos = get_sample_utxo_and_values();
         let utxos_length_before = utxos.len();
-        let result = filter_out_utxos_whose_value_is_too_low(&utxos).unwrap();
+        let result = filter_out_utxos_whose_value_is_too_low(&utxos, MINIMUM_REQUIRED_SATOSHIS).unwrap();
         let utxos_length_after = result.len();
         assert!(utxos_length_after < utxos_length_before);
         assert_ne!(utxos_length_before, utxos_length_after);
@@ -1,8 +1,6 @@
-// NOTE: This means we can (relatively) easily make this LTC based instead of BTC.
-#[cfg(not(feature = "ltc"))]
+// NOTE: The UTXO chain is selectable at runtime via the `UtxoChainParams`, with addresses handled
+// internally in the canonical form of this library.
 use bitcoin as bitcoin_crate_alias;
-#[cfg(feature = "ltc")]
-use litecoin as bitcoin_crate_alias;
 
 mod add_btc_block_to_db;
 mod btc_block;
@@ -43,6 +41,7 @@ mod update_btc_canon_block_hash;
 mod update_btc_latest_block_hash;
 mod update_btc_linker_hash;
 mod update_btc_tail_block_hash;
+mod utxo_chain_params;
 mod utxo_manager;
 mod validate_btc_block_header;
 mod validate_btc_difficulty;
@@ -64,7 +63,12 @@ pub use self::{
     },
     btc_crypto::BtcPrivateKey,
     btc_database_utils::{end_btc_db_transaction, BtcDatabaseKeysJson, BtcDbUtils},
-    btc_debug_functions::{debug_set_btc_account_nonce, debug_set_btc_fee, debug_set_btc_utxo_nonce},
+    btc_debug_functions::{
+        debug_set_btc_account_nonce,
+        debug_set_btc_fee,
+        debug_set_btc_utxo_nonce,
+        debug_set_utxo_chain,
+    },
     btc_enclave_state::BtcEnclaveState,
     btc_metadata::ToMetadata,
     btc_recipients_and_amounts::{BtcRecipientAndAmount, BtcRecipientsAndAmounts},
@@ -88,7 +92,12 @@ pub use self::{
         get_pay_to_pub_key_hash_script,
     },
     check_btc_parent_exists::check_for_parent_of_btc_block_in_state,
-    core_initialization::{init_btc_core, maybe_initialize_btc_core},
+    core_initialization::{
+        init_btc_core,
+        init_btc_core_for_chain,
+        maybe_initialize_btc_core,
+        maybe_initialize_btc_core_for_chain,
+    },
     deposit_address_info::{
         validate_deposit_address_list_in_state,
         DepositAddressInfoJson,
@@ -118,6 +127,7 @@ pub use self::{
     update_btc_latest_block_hash::maybe_update_btc_latest_block_hash,
     update_btc_linker_hash::maybe_update_btc_linker_hash,
     update_btc_tail_block_hash::maybe_update_btc_tail_block_hash,
+    utxo_chain_params::{PowAlgorithm, UtxoChain, UtxoChainParams},
     utxo_manager::{
         debug_add_multiple_utxos,
         debug_clear_all_utxos,
@@ -1,6 +1,11 @@
 use common::{traits::DatabaseInterface, types::Result};
 
-use crate::{btc_block::BtcBlockInDbFormat, btc_constants::BTC_TAIL_LENGTH, btc_database_utils::BtcDbUtils, BtcState};
+use crate::{
+    btc_block::BtcBlockInDbFormat,
+    btc_database_utils::BtcDbUtils,
+    utxo_chain_params::UtxoChainParams,
+    BtcState,
+};
 
 fn does_tail_block_require_updating<D: DatabaseInterface>(
     db_utils: &BtcDbUtils<D>,
@@ -15,17 +20,18 @@ fn does_tail_block_require_updating<D: DatabaseInterface>(
 pub fn maybe_update_btc_tail_block_hash<D: DatabaseInterface>(state: BtcState<D>) -> Result<BtcState<D>> {
     info!("✔ Maybe updating BTC tail block hash...");
     let canon_to_tip_length = state.btc_db_utils.get_btc_canon_to_tip_length_from_db()?;
+    let tail_length = UtxoChainParams::get_from_db(state.db)?.tail_length;
     state
         .btc_db_utils
         .get_btc_latest_block_from_db()
         .map(|latest_btc_block| {
             info!(
                 "✔ Searching for tail block {} blocks back from tip...",
-                canon_to_tip_length + BTC_TAIL_LENGTH,
+                canon_to_tip_length + tail_length,
             );
             state
                 .btc_db_utils
-                .maybe_get_nth_ancestor_btc_block_and_id(&latest_btc_block.id, canon_to_tip_length + BTC_TAIL_LENGTH)
+                .maybe_get_nth_ancestor_btc_block_and_id(&latest_btc_block.id, canon_to_tip_length + tail_length)
         })
         .and_then(|maybe_ancester_block_and_id| match maybe_ancester_block_and_id {
             None => {
@@ -36,7 +42,7 @@ pub fn maybe_update_btc_tail_block_hash<D: DatabaseInterface>(state: BtcState<D>
                 Ok(state)
             },
             Some(ancestor_block) => {
-                info!("✔ {}th ancestor block found...", canon_to_tip_length + BTC_TAIL_LENGTH,);
+                info!("✔ {}th ancestor block found...", canon_to_tip_length + tail_length,);
                 if does_tail_block_require_updating(&state.btc_db_utils, &ancestor_block)? {
                     info!("✔ Updating BTC tail block...");
                     state
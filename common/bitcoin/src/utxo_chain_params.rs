@@ -0,0 +1,449 @@
+use std::{fmt, str::FromStr};
+
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    errors::AppError,
+    traits::DatabaseInterface,
+    types::{Byte, Bytes, Result},
+    utils::get_prefixed_db_key,
+};
+use common_safe_addresses::{SAFE_BTC_ADDRESS_STR, SAFE_LTC_ADDRESS_STR};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bitcoin_crate_alias::{
+        bech32,
+        hashes::{sha256d, Hash},
+        network::constants::Network as BtcNetwork,
+        util::{
+            address::Address as BtcAddress,
+            base58::{check_encode_slice, from_check},
+        },
+    },
+    btc_database_utils::BtcDbUtils,
+    btc_utils::calculate_dust_amount,
+};
+
+lazy_static! {
+    pub static ref UTXO_CHAIN_PARAMS_KEY: [Byte; 32] = get_prefixed_db_key("utxo_chain_params_key");
+}
+
+const BTC_BLOCK_HEADER_LENGTH: usize = 80;
+const BTC_BLOCK_HEADER_BITS_OFFSET: usize = 72;
+
+/// The UTXO chains a BTC core may track.
+///
+/// ### NOTE:
+///
+/// Dogecoin is NOT supported, since its blocks are merge-mined via AuxPoW & its difficulty
+/// retargets every block, neither of which the BTC light client can validate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum UtxoChain {
+    Bitcoin,
+    Litecoin,
+}
+
+impl FromStr for UtxoChain {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_ref() {
+            "bitcoin" | "btc" => Ok(Self::Bitcoin),
+            "litecoin" | "ltc" => Ok(Self::Litecoin),
+            "dogecoin" | "doge" => Err(format!(
+                "UTXO chain '{s}' is not supported: its AuxPoW blocks & per-block difficulty retargeting cannot be \
+                 validated by the BTC light client!"
+            )
+            .into()),
+            _ => Err(format!("Unrecognized UTXO chain: '{s}'!").into()),
+        }
+    }
+}
+
+impl fmt::Display for UtxoChain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Bitcoin => "bitcoin",
+            Self::Litecoin => "litecoin",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PowAlgorithm {
+    Sha256d,
+    Scrypt,
+}
+
+impl PowAlgorithm {
+    /// Hashes the passed in serialized block header, returning the hash in the little-endian byte
+    /// order in which it's compared against the target.
+    pub fn hash_block_header(&self, header_bytes: &[Byte]) -> Result<Bytes> {
+        match self {
+            Self::Sha256d => Ok(sha256d::Hash::hash(header_bytes).to_vec()),
+            Self::Scrypt => {
+                // NOTE: Litecoin uses scrypt with N = 1024, r = 1 & p = 1, using the
+                // serialized header as both the password & the salt.
+                let params = scrypt::Params::new(10, 1, 1, 32)
+                    .map_err(|e| AppError::from(format!("Error creating scrypt params: {e}")))?;
+                let mut output = vec![0u8; 32];
+                scrypt::scrypt(header_bytes, header_bytes, &params, &mut output)
+                    .map_err(|e| AppError::from(format!("Error scrypt hashing block header: {e}")))?;
+                Ok(output)
+            },
+        }
+    }
+}
+
+/// Converts compact `bits` into a big-endian 256 bit target.
+fn get_target_from_compact_bits(bits: u32) -> Result<[Byte; 32]> {
+    let exponent = (bits >> 24) as usize;
+    let mantissa = bits & 0x007f_ffff;
+    let mut target = [0u8; 32];
+    if bits & 0x0080_0000 != 0 {
+        return Err(format!("Compact bits {bits:#x} encode a negative target!").into());
+    } else if exponent <= 3 {
+        target[28..].copy_from_slice(&(mantissa >> (8 * (3 - exponent))).to_be_bytes());
+    } else if exponent > 32 {
+        return Err(format!("Compact bits {bits:#x} encode a target that overflows 256 bits!").into());
+    } else {
+        let start = 32 - exponent;
+        target[start..start + 3].copy_from_slice(&mantissa.to_be_bytes()[1..]);
+    };
+    Ok(target)
+}
+
+/// Re-encodes an address from the format of one UTXO chain's params to that of another. Base58check
+/// addresses have their version prefix swapped, & bech32 addresses their human readable part.
+/// Addresses that are in neither of those formats, or which are not in the format of the `from`
+/// params, are returned as is.
+fn convert_address(address: &str, from: &UtxoChainParams, to: &UtxoChainParams) -> String {
+    if let Ok(bytes) = from_check(address) {
+        let maybe_prefix = if bytes.len() != 21 {
+            None
+        } else if bytes[0] == from.pub_key_hash_prefix {
+            Some(to.pub_key_hash_prefix)
+        } else if bytes[0] == from.script_hash_prefix {
+            Some(to.script_hash_prefix)
+        } else {
+            None
+        };
+        return match maybe_prefix {
+            Some(prefix) => check_encode_slice(&[&[prefix], &bytes[1..]].concat()),
+            None => address.to_string(),
+        };
+    };
+    match bech32::decode(address) {
+        Ok((hrp, data, variant)) if hrp == from.get_bech32_hrp() => {
+            bech32::encode(to.get_bech32_hrp(), data, variant).unwrap_or_else(|_| address.to_string())
+        },
+        _ => address.to_string(),
+    }
+}
+
+/// The parameters of the UTXO chain a BTC core is tracking, set at initialization time. These
+/// allow a single core binary to be initialized for either bitcoin or litecoin.
+///
+/// ### NOTES:
+///
+///  - Addresses are handled internally in their canonical form, meaning that of the chain the underlying bitcoin
+///    library was built for. Addresses from submission material & peg-out requests are converted to that form via
+///    `to_canonical_address`, & back again for display via `from_canonical_address`.
+///
+///  - The `pow_limit_bits` are the easiest target the chain's retarget rules allow. Blocks whose target is easier than
+///    this are rejected.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UtxoChainParams {
+    pub chain: UtxoChain,
+    pub is_testnet: bool,
+    pub pub_key_hash_prefix: Byte,
+    pub script_hash_prefix: Byte,
+    pub pow_algorithm: PowAlgorithm,
+    pub pow_limit_bits: u32,
+    pub tail_length: u64,
+    pub dust_relay_fee: u64, // NOTE: Unit: satoshis-per-byte
+    pub minimum_required_satoshis: u64,
+}
+
+impl UtxoChainParams {
+    pub fn new(chain: UtxoChain, network: &BtcNetwork) -> Self {
+        let is_testnet = *network != BtcNetwork::Bitcoin;
+        match chain {
+            UtxoChain::Bitcoin => Self {
+                chain,
+                is_testnet,
+                pub_key_hash_prefix: if is_testnet { 0x6f } else { 0x00 },
+                script_hash_prefix: if is_testnet { 0xc4 } else { 0x05 },
+                pow_algorithm: PowAlgorithm::Sha256d,
+                pow_limit_bits: 0x1d00_ffff,
+                tail_length: 10,
+                dust_relay_fee: 3,
+                minimum_required_satoshis: 5000,
+            },
+            UtxoChain::Litecoin => Self {
+                chain,
+                is_testnet,
+                pub_key_hash_prefix: if is_testnet { 0x6f } else { 0x30 },
+                script_hash_prefix: if is_testnet { 0x3a } else { 0x32 },
+                pow_algorithm: PowAlgorithm::Scrypt,
+                pow_limit_bits: 0x1e0f_ffff,
+                tail_length: 40,
+                dust_relay_fee: 3,
+                minimum_required_satoshis: 10_000,
+            },
+        }
+    }
+
+    pub fn from_str_and_network(chain: &str, network: &BtcNetwork) -> Result<Self> {
+        Ok(Self::new(UtxoChain::from_str(chain)?, network))
+    }
+
+    fn get_network(&self) -> BtcNetwork {
+        if self.is_testnet {
+            BtcNetwork::Testnet
+        } else {
+            BtcNetwork::Bitcoin
+        }
+    }
+
+    fn get_bech32_hrp(&self) -> &'static str {
+        match (self.chain, self.is_testnet) {
+            (UtxoChain::Bitcoin, false) => "bc",
+            (UtxoChain::Bitcoin, true) => "tb",
+            (UtxoChain::Litecoin, false) => "ltc",
+            (UtxoChain::Litecoin, true) => "tltc",
+        }
+    }
+
+    fn get_canonical_params(&self) -> Self {
+        Self::new(UtxoChain::Bitcoin, &self.get_network())
+    }
+
+    pub fn get_dust_amount(&self) -> u64 {
+        calculate_dust_amount(self.dust_relay_fee)
+    }
+
+    pub fn to_canonical_address(&self, address: &str) -> String {
+        convert_address(address, self, &self.get_canonical_params())
+    }
+
+    pub fn from_canonical_address(&self, address: &str) -> String {
+        convert_address(address, &self.get_canonical_params(), self)
+    }
+
+    /// Gets the safe address for this chain, in this chain's own address format.
+    pub fn get_safe_address(&self) -> String {
+        match self.chain {
+            UtxoChain::Bitcoin => SAFE_BTC_ADDRESS_STR.to_string(),
+            UtxoChain::Litecoin => SAFE_LTC_ADDRESS_STR.to_string(),
+        }
+    }
+
+    pub fn get_canonical_safe_address(&self) -> String {
+        self.to_canonical_address(&self.get_safe_address())
+    }
+
+    /// Converts the passed in address to its canonical form, defaulting to the canonical form of
+    /// this chain's safe address if the result is not a valid address.
+    pub fn safely_convert_str_to_canonical_address(&self, address: &str) -> String {
+        let canonical_address = self.to_canonical_address(address);
+        match BtcAddress::from_str(&canonical_address) {
+            Ok(_) => canonical_address,
+            Err(_) => {
+                info!(
+                    "✘ '{address}' is not a valid {} address ∴ defaulting to safe address!",
+                    self.chain
+                );
+                self.get_canonical_safe_address()
+            },
+        }
+    }
+
+    /// Calculates the difficulty encoded by the passed in compact `bits`, relative to bitcoin's
+    /// maximum target, as is conventional for all of the supported chains.
+    pub fn get_difficulty(bits: u32) -> f64 {
+        let mut shift = (bits >> 24) & 0xff;
+        let mut difficulty = f64::from(0x0000_ffff) / f64::from(bits & 0x00ff_ffff);
+        while shift < 29 {
+            difficulty *= 256.0;
+            shift += 1;
+        }
+        while shift > 29 {
+            difficulty /= 256.0;
+            shift -= 1;
+        }
+        difficulty
+    }
+
+    pub fn get_bits_from_block_header(header_bytes: &[Byte]) -> Result<u32> {
+        if header_bytes.len() != BTC_BLOCK_HEADER_LENGTH {
+            Err(format!(
+                "Block header must be {} bytes, got {}!",
+                BTC_BLOCK_HEADER_LENGTH,
+                header_bytes.len()
+            )
+            .into())
+        } else {
+            let mut bits = [0u8; 4];
+            bits.copy_from_slice(&header_bytes[BTC_BLOCK_HEADER_BITS_OFFSET..BTC_BLOCK_HEADER_BITS_OFFSET + 4]);
+            Ok(u32::from_le_bytes(bits))
+        }
+    }
+
+    /// Validates the proof-of-work of the passed in serialized block header, checking that its
+    /// hash is no greater than the target encoded in its `bits`, & that that target is no easier
+    /// than this chain's proof-of-work limit.
+    pub fn validate_proof_of_work(&self, header_bytes: &[Byte]) -> Result<()> {
+        let target = get_target_from_compact_bits(Self::get_bits_from_block_header(header_bytes)?)?;
+        if target > get_target_from_compact_bits(self.pow_limit_bits)? {
+            return Err(format!("✘ Invalid block! Target is easier than the {} PoW limit!", self.chain).into());
+        };
+        let mut hash = self.pow_algorithm.hash_block_header(header_bytes)?;
+        hash.reverse();
+        if hash.as_slice() > target.as_slice() {
+            Err("✘ Invalid block! PoW validation error: Block hash > target!".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Gets the chain params from the database. Cores initialized before these params existed
+    /// have none stored, in which case bitcoin params are used, on the network stored in the
+    /// database, but only if the core's own address is a bitcoin address. Any other core (eg a
+    /// litecoin core built with the old `ltc` feature) needs its chain set via the
+    /// `debug_set_utxo_chain` command before it can be used.
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        match db.get(UTXO_CHAIN_PARAMS_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => {
+                debug!("✘ No `UtxoChainParams` in db ∴ checking this is a bitcoin core...");
+                let db_utils = BtcDbUtils::new(db);
+                let network = db_utils.get_btc_network_from_db().unwrap_or(BtcNetwork::Bitcoin);
+                match db_utils.get_btc_address_from_db() {
+                    Ok(address) if BtcAddress::from_str(&address).is_err() => Err(format!(
+                        "✘ No UTXO chain params in db & core address '{address}' is not a bitcoin address - \
+                         set them via `debug_set_utxo_chain`!"
+                    )
+                    .into()),
+                    _ => Ok(Self::new(UtxoChain::Bitcoin, &network)),
+                }
+            },
+        }
+    }
+
+    pub fn put_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        info!("✔ Putting UTXO chain params in db: {:?}", self);
+        db.put(
+            UTXO_CHAIN_PARAMS_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+    use crate::{
+        bitcoin_crate_alias::consensus::encode::serialize as btc_serialize,
+        test_utils::get_sample_btc_block_and_id,
+    };
+
+    #[test]
+    fn should_get_utxo_chain_from_str() {
+        assert_eq!(UtxoChain::from_str("Bitcoin").unwrap(), UtxoChain::Bitcoin);
+        assert_eq!(UtxoChain::from_str("ltc").unwrap(), UtxoChain::Litecoin);
+        assert!(UtxoChain::from_str("DOGE")
+            .unwrap_err()
+            .to_string()
+            .contains("not supported"));
+        assert!(UtxoChain::from_str("bitcoin-cash").is_err());
+    }
+
+    #[test]
+    fn should_get_target_from_compact_bits() {
+        let result = get_target_from_compact_bits(0x1d00_ffff).unwrap();
+        let mut expected_result = [0u8; 32];
+        expected_result[4] = 0xff;
+        expected_result[5] = 0xff;
+        assert_eq!(result, expected_result);
+        assert!(get_target_from_compact_bits(0x0480_0000).is_err());
+    }
+
+    #[test]
+    fn should_convert_addresses_to_and_from_canonical_form() {
+        let params = UtxoChainParams::new(UtxoChain::Litecoin, &BtcNetwork::Bitcoin);
+        let canonical = params.to_canonical_address(SAFE_LTC_ADDRESS_STR);
+        assert!(canonical.starts_with('1'));
+        assert_eq!(params.from_canonical_address(&canonical), SAFE_LTC_ADDRESS_STR);
+        let btc_params = UtxoChainParams::new(UtxoChain::Bitcoin, &BtcNetwork::Bitcoin);
+        assert_eq!(
+            btc_params.to_canonical_address(SAFE_BTC_ADDRESS_STR),
+            SAFE_BTC_ADDRESS_STR
+        );
+    }
+
+    #[test]
+    fn should_convert_bech32_addresses_to_and_from_canonical_form() {
+        let canonical = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let params = UtxoChainParams::new(UtxoChain::Litecoin, &BtcNetwork::Bitcoin);
+        let ltc_address = params.from_canonical_address(canonical);
+        assert!(ltc_address.starts_with("ltc1"));
+        assert_eq!(params.to_canonical_address(&ltc_address), canonical);
+        assert_eq!(params.safely_convert_str_to_canonical_address(&ltc_address), canonical);
+    }
+
+    #[test]
+    fn should_default_to_canonical_safe_address_if_address_is_invalid() {
+        let params = UtxoChainParams::new(UtxoChain::Litecoin, &BtcNetwork::Bitcoin);
+        assert_eq!(
+            params.safely_convert_str_to_canonical_address("not an address"),
+            params.to_canonical_address(SAFE_LTC_ADDRESS_STR)
+        );
+    }
+
+    #[test]
+    fn should_validate_proof_of_work_of_btc_block_header() {
+        let header_bytes = btc_serialize(&get_sample_btc_block_and_id().unwrap().block.header);
+        let params = UtxoChainParams::new(UtxoChain::Bitcoin, &BtcNetwork::Bitcoin);
+        params.validate_proof_of_work(&header_bytes).unwrap();
+        let scrypt_params = UtxoChainParams::new(UtxoChain::Litecoin, &BtcNetwork::Bitcoin);
+        assert!(scrypt_params.validate_proof_of_work(&header_bytes).is_err());
+    }
+
+    #[test]
+    fn should_put_and_get_utxo_chain_params_in_db() {
+        let db = get_test_database();
+        assert_eq!(
+            UtxoChainParams::get_from_db(&db).unwrap(),
+            UtxoChainParams::new(UtxoChain::Bitcoin, &BtcNetwork::Bitcoin)
+        );
+        let params = UtxoChainParams::new(UtxoChain::Litecoin, &BtcNetwork::Testnet);
+        params.put_in_db(&db).unwrap();
+        assert_eq!(UtxoChainParams::get_from_db(&db).unwrap(), params);
+    }
+
+    #[test]
+    fn should_not_get_default_params_from_db_if_core_address_is_not_a_bitcoin_address() {
+        let db = get_test_database();
+        BtcDbUtils::new(&db)
+            .put_btc_address_in_db(SAFE_LTC_ADDRESS_STR)
+            .unwrap();
+        assert!(UtxoChainParams::get_from_db(&db).is_err());
+        UtxoChainParams::new(UtxoChain::Litecoin, &BtcNetwork::Bitcoin)
+            .put_in_db(&db)
+            .unwrap();
+        assert_eq!(UtxoChainParams::get_from_db(&db).unwrap().chain, UtxoChain::Litecoin);
+    }
+}
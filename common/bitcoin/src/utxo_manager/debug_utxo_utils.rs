@@ -306,7 +306,7 @@ pub fn debug_add_multiple_utxos<D: DatabaseInterface>(
         })
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::test_utils::{get_test_database, DUMMY_DEBUG_COMMAND_SIGNATURE};
 
@@ -12,8 +12,9 @@ use crate::{
         consensus::encode::deserialize as btc_deserialize,
         hashes::{sha256d, Hash},
     },
-    btc_constants::{BTC_TX_LOCK_TIME, BTC_TX_VERSION, DUST_AMOUNT},
+    btc_constants::{BTC_TX_LOCK_TIME, BTC_TX_VERSION},
     btc_utils::create_new_pay_to_pub_key_hash_output,
+    utxo_chain_params::UtxoChainParams,
     utxo_manager::{
         utxo_database_utils::{get_all_utxo_db_keys, get_first_utxo_and_value, get_utxo_from_db},
         utxo_types::{BtcUtxoAndValue, BtcUtxosAndValues},
@@ -142,10 +143,11 @@ fn get_enough_utxos_to_cover_total_recursively<D: DatabaseInterface>(
     if total_cost <= total_utxo_value {
         // NOTE: Now we can safely subtract and find the change amount without underflowing...
         let change_amount = total_utxo_value - total_cost;
-        debug!("✔ Dust amount: {} Satoshis", *DUST_AMOUNT);
+        let dust_amount = UtxoChainParams::get_from_db(db)?.get_dust_amount();
+        debug!("✔ Dust amount: {} Satoshis", dust_amount);
         debug!("✔ Change amount: {} Satoshis", change_amount);
         // NOTE: And finally check if the change output will be dust or not!
-        if change_amount <= *DUST_AMOUNT {
+        if change_amount <= dust_amount {
             debug!("Change UTXO will be dust, we need another!");
             get_enough_utxos_to_cover_total_recursively(db, sats_required, num_outputs, sats_per_byte, inputs)
         } else {
@@ -158,7 +160,7 @@ fn get_enough_utxos_to_cover_total_recursively<D: DatabaseInterface>(
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::test_utils::get_test_database;
 
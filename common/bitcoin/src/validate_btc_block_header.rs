@@ -21,7 +21,7 @@ pub fn validate_btc_block_header_in_state<D: DatabaseInterface>(state: BtcState<
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
@@ -1,48 +1,28 @@
 use common::{traits::DatabaseInterface, types::Result};
 
-#[cfg(not(feature = "ltc"))]
-use crate::bitcoin_crate_alias::blockdata::block::BlockHeader as BtcBlockHeader;
-#[cfg(feature = "ltc")]
-use crate::bitcoin_crate_alias::blockdata::block::Header as BtcBlockHeader;
-use crate::{bitcoin_crate_alias::network::constants::Network as BtcNetwork, BtcState};
+use crate::{
+    bitcoin_crate_alias::{
+        blockdata::block::BlockHeader as BtcBlockHeader,
+        consensus::encode::serialize as btc_serialize,
+        network::constants::Network as BtcNetwork,
+    },
+    utxo_chain_params::UtxoChainParams,
+    BtcState,
+};
 
-#[cfg(not(feature = "ltc"))]
 fn check_difficulty_is_above_threshold(
     threshold: u64,
     btc_block_header: &BtcBlockHeader,
     network: BtcNetwork,
 ) -> Result<()> {
-    // NOTE: Network not configurable in difficulty calculation ∵ all members
-    // of the enum return the same value from underlying lib!
     info!("✔ Checking BTC block difficulty is above threshold...");
-    let difficulty = btc_block_header.difficulty(network);
+    let difficulty = UtxoChainParams::get_difficulty(UtxoChainParams::get_bits_from_block_header(&btc_serialize(
+        btc_block_header,
+    ))?);
     if network != BtcNetwork::Bitcoin {
         warn!("not on mainnet - skipping difficulty check");
         Ok(())
-    } else if difficulty >= threshold {
-        info!("✔ BTC block difficulty is above threshold");
-        Ok(())
-    } else {
-        let msg = format!("difficulty of {difficulty} is below threshold of {threshold}");
-        warn!("{msg}");
-        Err(msg.into())
-    }
-}
-
-#[cfg(feature = "ltc")]
-fn check_difficulty_is_above_threshold(
-    threshold: u64,
-    btc_block_header: &BtcBlockHeader,
-    network: BtcNetwork,
-) -> Result<()> {
-    // NOTE: Network not configurable in difficulty calculation ∵ all members
-    // of the enum return the same value from underlying lib!
-    info!("✔ Checking BTC block difficulty is above threshold...");
-    let difficulty = btc_block_header.difficulty();
-    if network != BtcNetwork::Bitcoin {
-        warn!("not on mainnet - skipping difficulty check");
-        Ok(())
-    } else if difficulty >= threshold.into() {
+    } else if difficulty >= threshold as f64 {
         info!("✔ BTC block difficulty is above threshold");
         Ok(())
     } else {
@@ -67,7 +47,7 @@ pub fn validate_difficulty_of_btc_block_in_state<D: DatabaseInterface>(state: Bt
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::get_sample_btc_block_and_id;
@@ -87,7 +67,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(not(feature = "ltc"))]
     fn should_skip_difficulty_check_if_not_on_mainnet() {
         let threshold = 0;
         let block_header = get_sample_btc_block_and_id().unwrap().block.header;
@@ -96,4 +75,13 @@ mod tests {
         assert!(difficulty > threshold);
         assert!(check_difficulty_is_above_threshold(threshold, &block_header, network,).is_ok());
     }
+
+    #[test]
+    fn should_calculate_same_difficulty_as_underlying_lib() {
+        let block_header = get_sample_btc_block_and_id().unwrap().block.header;
+        let result = UtxoChainParams::get_difficulty(block_header.bits) as u64;
+        let expected_result = block_header.difficulty(BtcNetwork::Bitcoin);
+        // NOTE: Allowing for the floating point rounding of the former.
+        assert!(result.abs_diff(expected_result) <= 1);
+    }
 }
@@ -1,31 +1,17 @@
 use common::{traits::DatabaseInterface, types::Result};
 
-#[cfg(not(feature = "ltc"))]
-use crate::bitcoin_crate_alias::blockdata::block::BlockHeader as BtcBlockHeader;
-#[cfg(feature = "ltc")]
-use crate::bitcoin_crate_alias::blockdata::block::Header as BtcBlockHeader;
-use crate::BtcState;
+use crate::{
+    bitcoin_crate_alias::{blockdata::block::BlockHeader as BtcBlockHeader, consensus::encode::serialize as btc_serialize},
+    utxo_chain_params::UtxoChainParams,
+    BtcState,
+};
 
-#[cfg(not(feature = "ltc"))]
-fn validate_proof_of_work_in_block(btc_block_header: &BtcBlockHeader) -> Result<()> {
-    match btc_block_header.validate_pow(&btc_block_header.target()) {
-        Ok(_) => {
-            info!("✔ BTC block's proof-of-work is valid!");
-            Ok(())
-        },
-        Err(_) => Err("✘ Invalid block! PoW validation error: Block hash > target!".into()),
-    }
-}
-
-#[cfg(feature = "ltc")]
-fn validate_proof_of_work_in_block(btc_block_header: &BtcBlockHeader) -> Result<()> {
-    match btc_block_header.validate_pow(btc_block_header.target()) {
-        Ok(_) => {
-            info!("✔ BTC block's proof-of-work is valid!");
-            Ok(())
-        },
-        Err(_) => Err("✘ Invalid block! PoW validation error: Block hash > target!".into()),
-    }
+fn validate_proof_of_work_in_block(btc_block_header: &BtcBlockHeader, params: &UtxoChainParams) -> Result<()> {
+    params
+        .validate_proof_of_work(&btc_serialize(btc_block_header))
+        .map(|_| {
+            info!("✔ {} block's proof-of-work is valid!", params.chain);
+        })
 }
 
 pub fn validate_proof_of_work_of_btc_block_in_state<D: DatabaseInterface>(state: BtcState<D>) -> Result<BtcState<D>> {
@@ -34,19 +20,28 @@ pub fn validate_proof_of_work_of_btc_block_in_state<D: DatabaseInterface>(state:
         Ok(state)
     } else {
         info!("✔ Validating BTC block's proof-of-work...");
-        validate_proof_of_work_in_block(&state.get_btc_block_and_id()?.block.header).map(|_| state)
+        validate_proof_of_work_in_block(
+            &state.get_btc_block_and_id()?.block.header,
+            &UtxoChainParams::get_from_db(state.db)?,
+        )
+        .map(|_| state)
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::get_sample_btc_block_and_id;
+    use crate::{
+        bitcoin_crate_alias::network::constants::Network as BtcNetwork,
+        test_utils::get_sample_btc_block_and_id,
+        utxo_chain_params::UtxoChain,
+    };
 
     #[test]
     fn should_validate_proof_of_work_in_valid_block() {
         let block_header = get_sample_btc_block_and_id().unwrap().block.header;
-        if let Err(e) = validate_proof_of_work_in_block(&block_header) {
+        let params = UtxoChainParams::new(UtxoChain::Bitcoin, &BtcNetwork::Bitcoin);
+        if let Err(e) = validate_proof_of_work_in_block(&block_header, &params) {
             panic!("PoW should be valid in sample block: {}", e);
         }
     }
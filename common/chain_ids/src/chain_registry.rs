@@ -0,0 +1,375 @@
+use std::{
+    fmt,
+    sync::{RwLock, RwLockReadGuard},
+};
+
+use common::{
+    traits::ChainId,
+    types::{Byte, Bytes, Result},
+    utils::{convert_hex_to_eth_address, decode_prefixed_hex},
+};
+use derive_more::{Constructor, Deref};
+use ethereum_types::{Address as EthAddress, H256 as KeccakHash};
+use serde::{Deserialize, Serialize};
+
+use crate::{AlgoChainId, BtcChainId, EosChainId};
+
+const COMPILED_IN_EVM_CHAINS_JSON: &str = include_str!("../registry/evm_chains.json");
+
+// NOTE: The first byte of a metadata chain ID is that of its protocol.
+const BTC_METADATA_PROTOCOL_BYTE: Byte = 0x01;
+const EOS_METADATA_PROTOCOL_BYTE: Byte = 0x02;
+const ALGO_METADATA_PROTOCOL_BYTE: Byte = 0x03;
+
+lazy_static! {
+    static ref CHAIN_REGISTRY: RwLock<ChainRegistry> =
+        RwLock::new(ChainRegistry::compiled_in().expect("✘ Invalid compiled-in chain registry!"));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainRegistryProtocol {
+    Bitcoin,
+    Ethereum,
+    Eos,
+    Algorand,
+}
+
+impl fmt::Display for ChainRegistryProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Eos => "eos",
+            Self::Bitcoin => "bitcoin",
+            Self::Ethereum => "ethereum",
+            Self::Algorand => "algorand",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A single chain's definition. Everything a core needs to know about a chain that isn't implied
+/// by its protocol lives here, so onboarding a new chain is a matter of adding an entry rather than
+/// touching every enum that refers to it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainRegistryEntry {
+    pub name: String,
+    pub chain_id: u64,
+    pub protocol: ChainRegistryProtocol,
+    pub metadata_chain_id: String,
+    pub network_id_name: String,
+    pub network_id_disambiguator: Byte,
+    pub safe_address: String,
+    pub eip_1559_activation_block: Option<u64>,
+    pub eip_4844_activation_block: Option<u64>,
+    pub gas_limit_multiplier: usize,
+    pub aliases: Vec<String>,
+}
+
+impl ChainRegistryEntry {
+    pub fn get_metadata_chain_id_bytes(&self) -> Result<Bytes> {
+        decode_prefixed_hex(&self.metadata_chain_id)
+    }
+
+    pub fn get_safe_address(&self) -> Result<EthAddress> {
+        convert_hex_to_eth_address(&self.safe_address)
+    }
+
+    pub fn has_alias(&self, s: &str) -> bool {
+        let needle = s.to_lowercase();
+        self.name.to_lowercase() == needle || self.aliases.iter().any(|alias| alias.to_lowercase() == needle)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let metadata_bytes = self.get_metadata_chain_id_bytes()?;
+        if metadata_bytes.len() != 4 {
+            return Err(format!(
+                "Chain registry entry '{}' has invalid metadata chain ID: '{}'",
+                self.name, self.metadata_chain_id
+            )
+            .into());
+        };
+        if self.gas_limit_multiplier == 0 {
+            return Err(format!(
+                "Chain registry entry '{}' cannot have a zero gas limit multiplier",
+                self.name
+            )
+            .into());
+        };
+        self.get_safe_address().map(|_| ())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Constructor, Deref)]
+pub struct ChainRegistry(Vec<ChainRegistryEntry>);
+
+impl ChainRegistry {
+    /// Returns a read guard over the process-wide registry, which starts out as the compiled-in
+    /// table and may be extended once at runtime via `ChainRegistry::register_from_json_str`.
+    pub fn global() -> RwLockReadGuard<'static, ChainRegistry> {
+        CHAIN_REGISTRY.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn compiled_in() -> Result<Self> {
+        Self::from_json_str(COMPILED_IN_EVM_CHAINS_JSON)
+    }
+
+    /// Sets the process-wide registry to the compiled-in table extended with the entries found in
+    /// the passed in JSON. The whole set is validated first, so a bad config leaves the registry
+    /// untouched. Registering the same entries again is a no-op, so that a config can be reloaded.
+    pub fn register_from_json_str(s: &str) -> Result<()> {
+        let mut registry = CHAIN_REGISTRY.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match registry.get_registered(s)? {
+            Some(registered) => {
+                info!("✔ Registering extra chains in the chain registry...");
+                *registry = registered;
+            },
+            None => debug!("✔ Extra chains already registered in the chain registry"),
+        };
+        Ok(())
+    }
+
+    /// Returns the compiled-in registry extended with the entries in the passed in JSON, or `None`
+    /// if that's what this registry already is. Since chains may already have been resolved via
+    /// this registry, replacing one set of extra chains with a different one is an error.
+    fn get_registered(&self, s: &str) -> Result<Option<Self>> {
+        let compiled_in = Self::compiled_in()?;
+        let registered = compiled_in.extend_from_json_str(s)?;
+        if self == &registered {
+            Ok(None)
+        } else if self == &compiled_in {
+            Ok(Some(registered))
+        } else {
+            Err("Different extra chains are already registered - changing them requires a restart!".into())
+        }
+    }
+
+    pub fn from_json_str(s: &str) -> Result<Self> {
+        let entries: Vec<ChainRegistryEntry> = serde_json::from_str(s)?;
+        let registry = Self::new(entries);
+        registry.validate()?;
+        Ok(registry)
+    }
+
+    pub fn extend_from_json_str(&self, s: &str) -> Result<Self> {
+        let entries: Vec<ChainRegistryEntry> = serde_json::from_str(s)?;
+        let registry = Self::new([self.0.clone(), entries].concat());
+        registry.validate()?;
+        Ok(registry)
+    }
+
+    /// Gets the metadata chain ID bytes of the built-in non-EVM chains, which no chain in the
+    /// registry may reuse, since those bytes would then resolve to two different chains.
+    ///
+    /// ### NOTE:
+    ///
+    /// These mirror the bytes of the non-EVM `MetadataChainId`s, which can't be used directly here
+    /// since the metadata crate depends on this one.
+    pub fn get_built_in_non_evm_metadata_chain_id_bytes() -> Result<Vec<Bytes>> {
+        let get_bytes = |protocol_byte: Byte, hash: KeccakHash| [vec![protocol_byte], hash[..3].to_vec()].concat();
+        let eos_chain_ids = [
+            EosChainId::EosMainnet,
+            EosChainId::TelosMainnet,
+            EosChainId::EosJungleTestnet,
+            EosChainId::UltraMainnet,
+            EosChainId::UltraTestnet,
+            EosChainId::FioMainnet,
+            EosChainId::LibreTestnet,
+            EosChainId::LibreMainnet,
+        ];
+        let mut bytes = vec![
+            vec![BTC_METADATA_PROTOCOL_BYTE, 0, 0, 0],          // NOTE: Unknown BTC chain.
+            vec![EOS_METADATA_PROTOCOL_BYTE, 0, 0, 0],          // NOTE: Unknown EOS chain.
+            vec![BTC_METADATA_PROTOCOL_BYTE, 0x84, 0x04, 0x35], // NOTE: Litecoin mainnet.
+            get_bytes(ALGO_METADATA_PROTOCOL_BYTE, AlgoChainId::Mainnet.keccak_hash()?),
+        ];
+        for chain_id in [BtcChainId::Bitcoin, BtcChainId::Testnet] {
+            bytes.push(get_bytes(BTC_METADATA_PROTOCOL_BYTE, chain_id.keccak_hash()?));
+        }
+        for chain_id in eos_chain_ids {
+            bytes.push(get_bytes(EOS_METADATA_PROTOCOL_BYTE, chain_id.keccak_hash()?));
+        }
+        Ok(bytes)
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.iter().try_for_each(|entry| entry.validate())?;
+        let non_evm_metadata_chain_id_bytes = Self::get_built_in_non_evm_metadata_chain_id_bytes()?;
+        self.iter().try_for_each(|entry| -> Result<()> {
+            if non_evm_metadata_chain_id_bytes.contains(&entry.get_metadata_chain_id_bytes()?) {
+                Err(format!(
+                    "Metadata chain ID {} of '{}' collides with a built-in non-EVM chain's in chain registry!",
+                    entry.metadata_chain_id, entry.name
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        })?;
+        self.iter().enumerate().try_for_each(|(i, entry)| {
+            self.iter().skip(i + 1).try_for_each(|other| {
+                if entry.chain_id == other.chain_id && entry.protocol == other.protocol {
+                    Err(format!("Duplicate chain ID {} in chain registry!", entry.chain_id).into())
+                } else if entry.metadata_chain_id.to_lowercase() == other.metadata_chain_id.to_lowercase() {
+                    Err(format!(
+                        "Duplicate metadata chain ID {} in chain registry!",
+                        entry.metadata_chain_id
+                    )
+                    .into())
+                } else if entry.name.to_lowercase() == other.name.to_lowercase() {
+                    Err(format!("Duplicate chain name '{}' in chain registry!", entry.name).into())
+                } else if entry.network_id_name.to_lowercase() == other.network_id_name.to_lowercase() {
+                    Err(format!(
+                        "Duplicate network ID name '{}' in chain registry!",
+                        entry.network_id_name
+                    )
+                    .into())
+                } else if let Some(alias) = entry.aliases.iter().find(|alias| other.has_alias(alias)) {
+                    Err(format!("Alias '{alias}' is used by more than one chain in the chain registry!").into())
+                } else {
+                    Ok(())
+                }
+            })
+        })
+    }
+
+    pub fn get_by_chain_id(&self, protocol: ChainRegistryProtocol, chain_id: u64) -> Option<&ChainRegistryEntry> {
+        self.iter()
+            .find(|entry| entry.protocol == protocol && entry.chain_id == chain_id)
+    }
+
+    pub fn get_by_alias(&self, s: &str) -> Option<&ChainRegistryEntry> {
+        self.iter().find(|entry| entry.has_alias(s))
+    }
+
+    pub fn get_by_metadata_chain_id_bytes(&self, bytes: &[Byte]) -> Option<&ChainRegistryEntry> {
+        self.iter().find(|entry| match entry.get_metadata_chain_id_bytes() {
+            Ok(ref entry_bytes) => entry_bytes == bytes,
+            Err(_) => false,
+        })
+    }
+
+    pub fn get_by_metadata_chain_id_hex(&self, s: &str) -> Option<&ChainRegistryEntry> {
+        let needle = s.to_lowercase();
+        self.iter()
+            .find(|entry| entry.metadata_chain_id.to_lowercase() == needle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EthChainId;
+
+    fn get_sample_extra_chain_json() -> &'static str {
+        r#"[{
+            "name": "BaseMainnet",
+            "chain_id": 8453,
+            "protocol": "ethereum",
+            "metadata_chain_id": "0x00123456",
+            "network_id_name": "base",
+            "network_id_disambiguator": 0,
+            "safe_address": "0x71A440EE9Fa7F99FB9a697e96eC7839B8A1643B8",
+            "eip_1559_activation_block": 0,
+            "eip_4844_activation_block": null,
+            "gas_limit_multiplier": 1,
+            "aliases": ["base", "basemainnet"]
+        }]"#
+    }
+
+    #[test]
+    fn compiled_in_registry_should_be_valid() {
+        let result = ChainRegistry::compiled_in();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn all_known_eth_chain_ids_should_be_in_registry() {
+        let registry = ChainRegistry::global();
+        EthChainId::get_all().iter().for_each(|id| {
+            assert!(registry
+                .get_by_chain_id(ChainRegistryProtocol::Ethereum, id.to_u64())
+                .is_some())
+        })
+    }
+
+    #[test]
+    fn should_extend_registry_from_json() {
+        let registry = ChainRegistry::compiled_in().unwrap();
+        let result = registry.extend_from_json_str(get_sample_extra_chain_json()).unwrap();
+        assert_eq!(result.len(), registry.len() + 1);
+        let entry = result.get_by_alias("base").unwrap();
+        assert_eq!(entry.chain_id, 8453);
+    }
+
+    #[test]
+    fn should_fail_to_extend_registry_with_duplicate_alias() {
+        let registry = ChainRegistry::compiled_in().unwrap();
+        let json = get_sample_extra_chain_json().replace("[\"base\",", "[\"eth\",");
+        let expected_error = "Alias 'eth' is used by more than one chain in the chain registry!";
+        match registry.extend_from_json_str(&json) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(common::AppError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_extend_registry_with_duplicate_chain_id() {
+        let registry = ChainRegistry::compiled_in().unwrap();
+        let json = get_sample_extra_chain_json().replace("8453", "137");
+        assert!(registry.extend_from_json_str(&json).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_extend_registry_with_metadata_chain_id_of_non_evm_chain() {
+        let registry = ChainRegistry::compiled_in().unwrap();
+        let json = get_sample_extra_chain_json().replace("0x00123456", "0x01840435");
+        let expected_error =
+            "Metadata chain ID 0x01840435 of 'BaseMainnet' collides with a built-in non-EVM chain's in chain registry!";
+        match registry.extend_from_json_str(&json) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(common::AppError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_get_registered_chains_from_compiled_in_registry() {
+        let registry = ChainRegistry::compiled_in().unwrap();
+        let result = registry.get_registered(get_sample_extra_chain_json()).unwrap().unwrap();
+        assert_eq!(result.len(), registry.len() + 1);
+    }
+
+    #[test]
+    fn should_register_same_extra_chains_idempotently() {
+        let registry = ChainRegistry::compiled_in()
+            .unwrap()
+            .extend_from_json_str(get_sample_extra_chain_json())
+            .unwrap();
+        let result = registry.get_registered(get_sample_extra_chain_json()).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_not_replace_registered_extra_chains_with_different_ones() {
+        let registry = ChainRegistry::compiled_in()
+            .unwrap()
+            .extend_from_json_str(get_sample_extra_chain_json())
+            .unwrap();
+        let json = get_sample_extra_chain_json().replace("8453", "8454");
+        let expected_error = "Different extra chains are already registered - changing them requires a restart!";
+        match registry.get_registered(&json) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(common::AppError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_get_entry_by_metadata_chain_id_bytes() {
+        let registry = ChainRegistry::global();
+        let bytes = hex::decode("005fe7f9").unwrap();
+        let result = registry.get_by_metadata_chain_id_bytes(&bytes).unwrap();
+        assert_eq!(result.chain_id, 1);
+    }
+}
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
 use super::ChainIdT;
+use crate::chain_registry::{ChainRegistry, ChainRegistryEntry, ChainRegistryProtocol};
 
 #[derive(Clone, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
 pub enum EthChainId {
@@ -54,21 +55,11 @@ impl FromStr for EthChainId {
     type Err = AppError;
 
     fn from_str(s: &str) -> Result<Self> {
-        match &*s.to_lowercase() {
-            "bscmainnet" | "bsc" | "56" => Ok(Self::BscMainnet),
-            "ethereumgoerli" | "goerli" | "5" => Ok(Self::Goerli),
-            "xdaimainnet" | "xdai" | "100" => Ok(Self::XDaiMainnet),
-            "ethereumropsten" | "ropsten" | "3" => Ok(Self::Ropsten),
-            "ethereumrinkeby" | "rinkeby" | "4" => Ok(Self::Rinkeby),
-            "interimchain" | "interim" | "947" => Ok(Self::InterimChain),
-            "fantommainnet" | "fantom" | "250" => Ok(Self::FantomMainnet),
-            "ethereumsepolia" | "sepolia" | "11155111" => Ok(Self::Sepolia),
-            "polygonmainnet" | "polygon" | "137" => Ok(Self::PolygonMainnet),
-            "arbitrummainnet" | "arbitrum" | "42161" => Ok(Self::ArbitrumMainnet),
-            "ethmainnet" | "ethereummainnet" | "mainnet" | "1" => Ok(Self::Mainnet),
-            "luxomainnet" | "luxo" | "luxochain" | "110" => Ok(Self::LuxochainMainnet),
-            _ => match s.parse::<u64>() {
-                Ok(u_64) => Ok(Self::Unknown(u_64)),
+        let maybe_registered_chain_id = ChainRegistry::global().get_by_alias(s).map(|entry| entry.chain_id);
+        match maybe_registered_chain_id {
+            Some(chain_id) => Self::from_unsigned_int(chain_id),
+            None => match s.parse::<u64>() {
+                Ok(u_64) => Self::from_unsigned_int(u_64),
                 Err(_) => Err(format!("✘ Unrecognized ETH network: '{}'!", s).into()),
             },
         }
@@ -85,7 +76,6 @@ const ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT: usize = 450_000;
 #[cfg(feature = "include-origin-tx-details")]
 const ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT: usize = 1_000_000;
 
-const ARBITRUM_GAS_MULTIPLIER: usize = 10;
 const ERC777_CHANGE_PNETWORK_GAS_LIMIT: usize = 30_000;
 const ERC20_VAULT_MIGRATE_GAS_LIMIT: usize = 2_000_000;
 const ERC777_MINT_WITH_NO_DATA_GAS_LIMIT: usize = 180_000;
@@ -94,58 +84,49 @@ const ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT: usize = 250_000;
 
 impl EthChainId {
     pub fn get_erc777_change_pnetwork_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC777_CHANGE_PNETWORK_GAS_LIMIT,
-            _ => ERC777_CHANGE_PNETWORK_GAS_LIMIT,
-        }
+        self.get_gas_limit_multiplier() * ERC777_CHANGE_PNETWORK_GAS_LIMIT
     }
 
     pub fn get_erc777_mint_with_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC777_MINT_WITH_DATA_GAS_LIMIT,
-            _ => ERC777_MINT_WITH_DATA_GAS_LIMIT,
-        }
+        self.get_gas_limit_multiplier() * ERC777_MINT_WITH_DATA_GAS_LIMIT
     }
 
     pub fn get_erc777_mint_with_no_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC777_MINT_WITH_NO_DATA_GAS_LIMIT,
-            _ => ERC777_MINT_WITH_NO_DATA_GAS_LIMIT,
-        }
+        self.get_gas_limit_multiplier() * ERC777_MINT_WITH_NO_DATA_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_migrate_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_MIGRATE_GAS_LIMIT,
-            _ => ERC20_VAULT_MIGRATE_GAS_LIMIT,
-        }
+        self.get_gas_limit_multiplier() * ERC20_VAULT_MIGRATE_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_pegout_without_user_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT,
-            _ => ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT,
-        }
+        self.get_gas_limit_multiplier() * ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_pegout_with_user_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT,
-            _ => ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT,
-        }
+        self.get_gas_limit_multiplier() * ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_change_supported_token_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_CHANGE_SUPPORTED_TOKEN_GAS_LIMIT,
-            _ => ERC20_VAULT_CHANGE_SUPPORTED_TOKEN_GAS_LIMIT,
-        }
+        self.get_gas_limit_multiplier() * ERC20_VAULT_CHANGE_SUPPORTED_TOKEN_GAS_LIMIT
     }
 
     pub fn unknown() -> Self {
         Self::Unknown(0)
     }
 
+    pub fn get_registry_entry(&self) -> Option<ChainRegistryEntry> {
+        ChainRegistry::global()
+            .get_by_chain_id(ChainRegistryProtocol::Ethereum, self.to_u64())
+            .cloned()
+    }
+
+    fn get_gas_limit_multiplier(&self) -> usize {
+        self.get_registry_entry()
+            .map(|entry| entry.gas_limit_multiplier)
+            .unwrap_or(1)
+    }
+
     pub fn to_bytes(&self) -> Result<Bytes> {
         match self {
             // NOTE: The following explicit ones are for legacy reasons...
@@ -234,20 +215,9 @@ impl EthChainId {
 
 impl fmt::Display for EthChainId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::Unknown(_) => write!(f, "EthUnknown"),
-            Self::Goerli => write!(f, "EthereumGoerli"),
-            Self::BscMainnet => write!(f, "BscMainnet"),
-            Self::Sepolia => write!(f, "SepoliaTestnet"),
-            Self::Rinkeby => write!(f, "EthereumRinkeby"),
-            Self::Ropsten => write!(f, "EthereumRopsten"),
-            Self::XDaiMainnet => write!(f, "XDaiMainnet"),
-            Self::Mainnet => write!(f, "EthereumMainnet"),
-            Self::InterimChain => write!(f, "InterimChain"),
-            Self::FantomMainnet => write!(f, "FantomMainnet"),
-            Self::PolygonMainnet => write!(f, "PolygonMainnet"),
-            Self::ArbitrumMainnet => write!(f, "ArbritrumMainnet"),
-            Self::LuxochainMainnet => write!(f, "LuxochainMainnet"),
+        match self.get_registry_entry() {
+            Some(entry) => write!(f, "{}", entry.name),
+            None => write!(f, "EthUnknown"),
         }
     }
 }
@@ -355,4 +325,28 @@ mod tests {
             .enumerate()
             .for_each(|(i, chain_id_hex)| assert_eq!(chain_id_hex, expected_chain_ids_keccak_hashes[i]));
     }
+
+    #[test]
+    fn should_get_gas_limits_and_display_name_from_registry() {
+        let result = EthChainId::ArbitrumMainnet;
+        assert_eq!(result.to_string(), "ArbritrumMainnet");
+        assert_eq!(
+            result.get_erc777_change_pnetwork_gas_limit(),
+            10 * ERC777_CHANGE_PNETWORK_GAS_LIMIT
+        );
+        assert_eq!(
+            EthChainId::Mainnet.get_erc777_change_pnetwork_gas_limit(),
+            ERC777_CHANGE_PNETWORK_GAS_LIMIT
+        );
+    }
+
+    #[test]
+    fn should_get_eth_chain_id_from_str_via_registry_aliases() {
+        assert_eq!(EthChainId::from_str("binance").unwrap(), EthChainId::BscMainnet);
+        assert_eq!(EthChainId::from_str("42161").unwrap(), EthChainId::ArbitrumMainnet);
+        assert_eq!(
+            EthChainId::from_str("ArbitrumMainnet").unwrap(),
+            EthChainId::ArbitrumMainnet
+        );
+    }
 }
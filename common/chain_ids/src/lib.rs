@@ -1,6 +1,7 @@
 mod algo_chain_id;
 mod btc_chain_id;
 mod chain_id_traits;
+mod chain_registry;
 mod eos_chain_id;
 mod eth_chain_id;
 
@@ -8,6 +9,7 @@ pub use self::{
     algo_chain_id::AlgoChainId,
     btc_chain_id::BtcChainId,
     chain_id_traits::ChainIdT,
+    chain_registry::{ChainRegistry, ChainRegistryEntry, ChainRegistryProtocol},
     eos_chain_id::EosChainId,
     eth_chain_id::EthChainId,
 };
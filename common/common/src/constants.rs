@@ -10,6 +10,7 @@ pub const MAX_DATA_SENSITIVITY_LEVEL: Option<u8> = Some(255);
 pub const CORE_VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
 pub const ETH_ZERO_ADDRESS_STR: &str = "0x0000000000000000000000000000000000000000";
 pub const ZERO_CONFS_WARNING: &str = "WARNING: NUMBER OF CONFIRMATIONS IS SET TO ZERO!";
+pub const DB_KEY_NOT_FOUND_ERROR: &str = "Cannot find item in database!";
 
 lazy_static! {
     pub static ref THIRTY_TWO_ZERO_BYTES: Vec<u8> = vec![0; 32];
@@ -4,6 +4,7 @@ use crate::utils::get_prefixed_db_key;
 
 lazy_static! {
     pub static ref ETH_EVM_DICTIONARY_KEY: [u8; 32] = get_prefixed_db_key("eth-evm-dictionary");
+    pub static ref ETH_EVM_PENDING_TX_INFOS_KEY: [u8; 32] = get_prefixed_db_key("eth-evm-pending-tx-infos");
     // NOTE: The actual string hashed remains as it was originally for backwards compatibility.
     pub static ref EOS_ETH_DICTIONARY_KEY: [u8; 32] = get_prefixed_db_key("eos-erc20-dictionary");
     pub static ref EVM_ALGO_DICTIONARY_KEY: [u8; 32] = get_prefixed_db_key("evm-algo-dictionary");
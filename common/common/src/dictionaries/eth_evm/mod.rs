@@ -17,7 +17,12 @@ use crate::{
     utils::{get_unix_timestamp, strip_hex_prefix},
 };
 
+mod pending_tx_infos;
 pub mod test_utils;
+mod token_limits;
+
+pub use self::pending_tx_infos::{apply_token_limits, EthEvmPendingTxInfo, EthEvmPendingTxInfos};
+pub use crate::dictionaries::token_dictionary::TokenLimitViolation;
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Constructor, Deref, DerefMut, Serialize, Deserialize)]
 pub struct EthEvmTokenDictionary(pub Vec<EthEvmTokenDictionaryEntry>);
@@ -104,7 +109,7 @@ impl EthEvmTokenDictionary {
         }
     }
 
-    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+    pub(crate) fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
         db.put(
             ETH_EVM_DICTIONARY_KEY.to_vec(),
             self.to_bytes()?,
@@ -353,6 +358,21 @@ pub struct EthEvmTokenDictionaryEntry {
     pub last_withdrawal_human_readable: String,
     pub eth_token_decimals: Option<u16>,
     pub evm_token_decimals: Option<u16>,
+    // NOTE: The optional limits below are all denominated in the ETH token's units.
+    pub min_peg_amount: Option<U256>,
+    pub max_tx_amount: Option<U256>,
+    pub volume_cap: Option<U256>,
+    pub volume_cap_window: Option<u64>,
+    pub window_volume: U256,
+    pub window_start: u64,
+    pub disabled: bool,
+}
+
+fn parse_optional_decimal_amount(amount: &Option<String>) -> Result<Option<U256>> {
+    match amount {
+        Some(s) => Ok(Some(U256::from_dec_str(s)?)),
+        None => Ok(None),
+    }
 }
 
 impl FromStr for EthEvmTokenDictionaryEntry {
@@ -376,6 +396,21 @@ impl EthEvmTokenDictionaryEntry {
             last_withdrawal: Some(self.last_withdrawal),
             eth_token_decimals: self.eth_token_decimals,
             evm_token_decimals: self.evm_token_decimals,
+            min_peg_amount: self.min_peg_amount.map(|amount| amount.to_string()),
+            max_tx_amount: self.max_tx_amount.map(|amount| amount.to_string()),
+            volume_cap: self.volume_cap.map(|amount| amount.to_string()),
+            volume_cap_window: self.volume_cap_window,
+            window_volume: if self.window_volume.is_zero() {
+                None
+            } else {
+                Some(self.window_volume.to_string())
+            },
+            window_start: if self.window_start == 0 {
+                None
+            } else {
+                Some(self.window_start)
+            },
+            disabled: if self.disabled { Some(true) } else { None },
         }
     }
 
@@ -395,6 +430,13 @@ impl EthEvmTokenDictionaryEntry {
             accrued_fees,
             eth_token_decimals: json.eth_token_decimals,
             evm_token_decimals: json.evm_token_decimals,
+            min_peg_amount: parse_optional_decimal_amount(&json.min_peg_amount)?,
+            max_tx_amount: parse_optional_decimal_amount(&json.max_tx_amount)?,
+            volume_cap: parse_optional_decimal_amount(&json.volume_cap)?,
+            volume_cap_window: json.volume_cap_window,
+            window_volume: parse_optional_decimal_amount(&json.window_volume)?.unwrap_or_default(),
+            window_start: json.window_start.unwrap_or_default(),
+            disabled: json.disabled.unwrap_or_default(),
         })
     }
 
@@ -489,6 +531,21 @@ pub struct EthEvmTokenDictionaryEntryJson {
     last_withdrawal: Option<u64>,
     eth_token_decimals: Option<u16>,
     evm_token_decimals: Option<u16>,
+    // NOTE: The amounts below are decimal strings, since they may not fit in a `u128`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_peg_amount: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_tx_amount: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    volume_cap: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    volume_cap_window: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    window_volume: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    window_start: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    disabled: Option<bool>,
 }
 
 impl FromStr for EthEvmTokenDictionaryEntryJson {
@@ -1036,4 +1093,17 @@ mod tests {
             .unwrap();
         assert_eq!(result.accrued_fees, fee_to_set);
     }
+
+    #[test]
+    fn should_round_trip_entry_with_limits_larger_than_u128_via_json() {
+        let entry = get_sample_eth_evm_dictionary()[0].set_token_limits(
+            Some(U256::one()),
+            Some(U256::MAX),
+            Some(U256::MAX - 1),
+            Some(3600),
+        );
+        let json = entry.to_json();
+        let result = EthEvmTokenDictionaryEntry::from_json(&json).unwrap();
+        assert_eq!(result, entry);
+    }
 }
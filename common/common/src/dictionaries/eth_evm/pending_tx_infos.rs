@@ -0,0 +1,338 @@
+use derive_more::{Constructor, Deref, DerefMut};
+use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+
+use crate::{
+    bridge_side::BridgeSide,
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    crypto_utils::keccak_hash_bytes,
+    dictionaries::{
+        dictionary_constants::ETH_EVM_PENDING_TX_INFOS_KEY,
+        eth_evm::{EthEvmTokenDictionary, TokenLimitViolation},
+    },
+    traits::DatabaseInterface,
+    types::{Byte, Bytes, Result},
+};
+
+/// A tx info which broke one of its token's limits, held back from signing until it is released.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EthEvmPendingTxInfo {
+    pub id: EthHash,
+    pub side: BridgeSide,
+    pub token_address: EthAddress,
+    pub amount: U256,
+    pub reason: TokenLimitViolation,
+    pub timestamp: u64,
+    pub released: bool,
+    pub tx_info: JsonValue,
+}
+
+impl EthEvmPendingTxInfo {
+    fn new<T: Serialize>(
+        side: BridgeSide,
+        token_address: EthAddress,
+        amount: U256,
+        reason: TokenLimitViolation,
+        timestamp: u64,
+        tx_info: &T,
+    ) -> Result<Self> {
+        let tx_info = serde_json::to_value(tx_info)?;
+        let id = Self::get_id(&side, &tx_info)?;
+        Ok(Self {
+            id,
+            side,
+            token_address,
+            amount,
+            reason,
+            timestamp,
+            released: false,
+            tx_info,
+        })
+    }
+
+    fn get_id(side: &BridgeSide, tx_info: &JsonValue) -> Result<EthHash> {
+        Ok(keccak_hash_bytes(&serde_json::to_vec(
+            &json!({ "side": side, "tx_info": tx_info }),
+        )?))
+    }
+
+    fn is_releasable(&self, dictionary: &EthEvmTokenDictionary, timestamp: u64) -> bool {
+        // NOTE: Only volume cap violations can clear by themselves, once the window has rolled over.
+        self.released
+            || (self.reason == TokenLimitViolation::VolumeCapExceeded
+                && matches!(
+                    dictionary.get_token_limit_violation(&self.token_address, self.amount, timestamp),
+                    Ok(None)
+                ))
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Constructor, Deref, DerefMut, Serialize, Deserialize)]
+pub struct EthEvmPendingTxInfos(pub Vec<EthEvmPendingTxInfo>);
+
+impl EthEvmPendingTxInfos {
+    fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        db.put(
+            ETH_EVM_PENDING_TX_INFOS_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        info!("✔ Getting `EthEvmPendingTxInfos` from db...");
+        match db.get(ETH_EVM_PENDING_TX_INFOS_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => {
+                info!("✘ No `EthEvmPendingTxInfos` in db! Initializing new ones...");
+                Ok(Self::default())
+            },
+        }
+    }
+
+    /// Marks the pending tx info with the passed in ID as released, meaning it will be processed
+    /// along with the tx infos from the next block submitted to its side of the core.
+    pub fn release_and_update_in_db<D: DatabaseInterface>(db: &D, id: &EthHash) -> Result<EthEvmPendingTxInfo> {
+        let mut pending_tx_infos = Self::get_from_db(db)?;
+        match pending_tx_infos
+            .iter_mut()
+            .find(|pending_tx_info| &pending_tx_info.id == id)
+        {
+            Some(pending_tx_info) => {
+                info!("✔ Releasing pending tx info with id {}", id);
+                pending_tx_info.released = true;
+                let released = pending_tx_info.clone();
+                pending_tx_infos.save_in_db(db)?;
+                Ok(released)
+            },
+            None => Err(format!("No pending tx info exists with id: {}", id).into()),
+        }
+    }
+}
+
+/// Applies the token limits from the `EthEvmTokenDictionary` to the passed in tx infos, returning
+/// those which may proceed to be signed. Any that break a limit are held in the persisted pending
+/// queue instead. Pending tx infos for this side of the bridge which have been released via debug
+/// command, or whose volume cap window has since rolled over, are returned along with them. Tx
+/// infos which are already held in the queue, eg because their block is being reprocessed, are
+/// neither queued again nor returned.
+///
+/// NOTE: Only the bridges whose tokens live in the `EthEvmTokenDictionary` (`erc20_on_int` &
+/// `int_on_evm`) apply these limits. Bridges using other dictionaries, or none, do not.
+///
+/// The passed in function must return the token's ETH address and the amount in ETH token units.
+pub fn apply_token_limits<D, T>(
+    db: &D,
+    side: &BridgeSide,
+    tx_infos: Vec<T>,
+    get_token_address_and_amount: fn(&T) -> (EthAddress, U256),
+    timestamp: u64,
+) -> Result<Vec<T>>
+where
+    D: DatabaseInterface,
+    T: Serialize + DeserializeOwned,
+{
+    let original_dictionary = EthEvmTokenDictionary::get_from_db(db)?;
+    let original_pending_tx_infos = EthEvmPendingTxInfos::get_from_db(db)?;
+    let mut dictionary = original_dictionary.clone();
+    let mut pending_tx_infos = vec![];
+    let mut tx_infos_to_process = vec![];
+
+    for pending_tx_info in original_pending_tx_infos.iter().cloned() {
+        if &pending_tx_info.side == side && pending_tx_info.is_releasable(&dictionary, timestamp) {
+            info!("✔ Releasing pending tx info with id {}", pending_tx_info.id);
+            if let Ok(updated_dictionary) =
+                dictionary.add_to_window_volume(&pending_tx_info.token_address, pending_tx_info.amount, timestamp)
+            {
+                dictionary = updated_dictionary;
+            };
+            tx_infos_to_process.push(serde_json::from_value(pending_tx_info.tx_info)?);
+        } else {
+            pending_tx_infos.push(pending_tx_info);
+        }
+    }
+
+    for tx_info in tx_infos {
+        let id = EthEvmPendingTxInfo::get_id(side, &serde_json::to_value(&tx_info)?)?;
+        if original_pending_tx_infos.iter().any(|x| x.id == id) || pending_tx_infos.iter().any(|x| x.id == id) {
+            warn!("✘ Tx info with id {id} is already in the pending queue - skipping it!");
+            continue;
+        };
+        let (token_address, amount) = get_token_address_and_amount(&tx_info);
+        match dictionary.get_token_limit_violation(&token_address, amount, timestamp)? {
+            None => {
+                dictionary = dictionary.add_to_window_volume(&token_address, amount, timestamp)?;
+                tx_infos_to_process.push(tx_info);
+            },
+            Some(reason) => {
+                let pending_tx_info =
+                    EthEvmPendingTxInfo::new(*side, token_address, amount, reason, timestamp, &tx_info)?;
+                warn!(
+                    "✘ Holding tx info with id {} in pending queue ∵ {}!",
+                    pending_tx_info.id, reason
+                );
+                pending_tx_infos.push(pending_tx_info);
+            },
+        }
+    }
+
+    if dictionary != original_dictionary {
+        dictionary.save_in_db(db)?;
+    };
+    let pending_tx_infos = EthEvmPendingTxInfos::new(pending_tx_infos);
+    if pending_tx_infos != original_pending_tx_infos {
+        pending_tx_infos.save_in_db(db)?;
+    };
+    Ok(tx_infos_to_process)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dictionaries::eth_evm::test_utils::get_sample_eth_evm_dictionary, test_utils::get_test_database};
+
+    fn get_token_address_and_amount(tx_info: &(EthAddress, U256)) -> (EthAddress, U256) {
+        *tx_info
+    }
+
+    fn save_sample_dictionary_with_limits<D: DatabaseInterface>(db: &D) -> EthAddress {
+        let dictionary = get_sample_eth_evm_dictionary();
+        let entry = dictionary[0].clone();
+        let token_address = entry.eth_address;
+        dictionary
+            .replace_entry(
+                &entry,
+                entry.set_token_limits(
+                    Some(U256::from(10)),
+                    Some(U256::from(1000)),
+                    Some(U256::from(1500)),
+                    Some(3600),
+                ),
+            )
+            .save_in_db(db)
+            .unwrap();
+        token_address
+    }
+
+    #[test]
+    fn should_hold_tx_infos_breaking_limits_in_pending_queue() {
+        let db = get_test_database();
+        let token_address = save_sample_dictionary_with_limits(&db);
+        let timestamp = 1_000_000;
+        let tx_infos = vec![
+            (token_address, U256::from(1)),
+            (token_address, U256::from(1001)),
+            (token_address, U256::from(1000)),
+            (token_address, U256::from(1000)),
+        ];
+        let result = apply_token_limits(
+            &db,
+            &BridgeSide::Native,
+            tx_infos,
+            get_token_address_and_amount,
+            timestamp,
+        )
+        .unwrap();
+        assert_eq!(result, vec![(token_address, U256::from(1000))]);
+        let pending_tx_infos = EthEvmPendingTxInfos::get_from_db(&db).unwrap();
+        let reasons = pending_tx_infos.iter().map(|x| x.reason).collect::<Vec<_>>();
+        assert_eq!(reasons, vec![
+            TokenLimitViolation::BelowMinimum,
+            TokenLimitViolation::AboveMaximum,
+            TokenLimitViolation::VolumeCapExceeded,
+        ]);
+        let dictionary = EthEvmTokenDictionary::get_from_db(&db).unwrap();
+        let entry = dictionary.get_entry_via_eth_address(&token_address).unwrap();
+        assert_eq!(entry.window_volume, U256::from(1000));
+        assert_eq!(entry.window_start, timestamp);
+    }
+
+    #[test]
+    fn should_release_volume_capped_tx_info_once_window_rolls_over() {
+        let db = get_test_database();
+        let token_address = save_sample_dictionary_with_limits(&db);
+        let timestamp = 1_000_000;
+        let side = BridgeSide::Native;
+        let tx_infos = vec![(token_address, U256::from(1000)), (token_address, U256::from(1000))];
+        apply_token_limits(&db, &side, tx_infos, get_token_address_and_amount, timestamp).unwrap();
+        let result = apply_token_limits(&db, &side, vec![], get_token_address_and_amount, timestamp + 1).unwrap();
+        assert!(result.is_empty());
+        let result = apply_token_limits(&db, &side, vec![], get_token_address_and_amount, timestamp + 3600).unwrap();
+        assert_eq!(result, vec![(token_address, U256::from(1000))]);
+        assert!(EthEvmPendingTxInfos::get_from_db(&db).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_release_pending_tx_info_via_id_on_its_side_only() {
+        let db = get_test_database();
+        let token_address = save_sample_dictionary_with_limits(&db);
+        let timestamp = 1_000_000;
+        let tx_infos = vec![(token_address, U256::from(1))];
+        apply_token_limits(
+            &db,
+            &BridgeSide::Host,
+            tx_infos,
+            get_token_address_and_amount,
+            timestamp,
+        )
+        .unwrap();
+        let id = EthEvmPendingTxInfos::get_from_db(&db).unwrap()[0].id;
+        let released = EthEvmPendingTxInfos::release_and_update_in_db(&db, &id).unwrap();
+        assert!(released.released);
+        let result = apply_token_limits(
+            &db,
+            &BridgeSide::Native,
+            vec![],
+            get_token_address_and_amount,
+            timestamp,
+        )
+        .unwrap();
+        assert!(result.is_empty());
+        let result =
+            apply_token_limits(&db, &BridgeSide::Host, vec![], get_token_address_and_amount, timestamp).unwrap();
+        assert_eq!(result, vec![(token_address, U256::from(1))]);
+    }
+
+    #[test]
+    fn should_not_queue_tx_info_again_when_its_block_is_reprocessed() {
+        let db = get_test_database();
+        let token_address = save_sample_dictionary_with_limits(&db);
+        let timestamp = 1_000_000;
+        let side = BridgeSide::Native;
+        let tx_infos = vec![(token_address, U256::from(1))];
+        apply_token_limits(&db, &side, tx_infos.clone(), get_token_address_and_amount, timestamp).unwrap();
+        let result = apply_token_limits(&db, &side, tx_infos, get_token_address_and_amount, timestamp).unwrap();
+        assert!(result.is_empty());
+        assert_eq!(EthEvmPendingTxInfos::get_from_db(&db).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn should_not_process_reprocessed_tx_info_twice_when_releasing_it() {
+        let db = get_test_database();
+        let token_address = save_sample_dictionary_with_limits(&db);
+        let timestamp = 1_000_000;
+        let side = BridgeSide::Native;
+        let tx_infos = vec![(token_address, U256::from(1))];
+        apply_token_limits(&db, &side, tx_infos.clone(), get_token_address_and_amount, timestamp).unwrap();
+        let id = EthEvmPendingTxInfos::get_from_db(&db).unwrap()[0].id;
+        EthEvmPendingTxInfos::release_and_update_in_db(&db, &id).unwrap();
+        let result = apply_token_limits(&db, &side, tx_infos, get_token_address_and_amount, timestamp).unwrap();
+        assert_eq!(result, vec![(token_address, U256::from(1))]);
+        assert!(EthEvmPendingTxInfos::get_from_db(&db).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_release_non_existent_pending_tx_info() {
+        let db = get_test_database();
+        assert!(EthEvmPendingTxInfos::release_and_update_in_db(&db, &EthHash::zero()).is_err());
+    }
+}
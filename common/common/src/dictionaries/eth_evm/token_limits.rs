@@ -0,0 +1,151 @@
+use ethereum_types::{Address as EthAddress, U256};
+
+use crate::{
+    dictionaries::{
+        eth_evm::{EthEvmTokenDictionary, EthEvmTokenDictionaryEntry},
+        token_dictionary::{TokenLimitViolation, TokenLimits},
+    },
+    traits::DatabaseInterface,
+    types::Result,
+};
+
+impl EthEvmTokenDictionaryEntry {
+    pub fn get_token_limits(&self) -> TokenLimits {
+        TokenLimits {
+            min_peg_amount: self.min_peg_amount,
+            max_tx_amount: self.max_tx_amount,
+            volume_cap: self.volume_cap,
+            volume_cap_window: self.volume_cap_window,
+            window_volume: self.window_volume,
+            window_start: self.window_start,
+        }
+    }
+
+    pub(crate) fn set_token_limits_from(&self, limits: TokenLimits) -> Self {
+        let mut new_entry = self.clone();
+        new_entry.min_peg_amount = limits.min_peg_amount;
+        new_entry.max_tx_amount = limits.max_tx_amount;
+        new_entry.volume_cap = limits.volume_cap;
+        new_entry.volume_cap_window = limits.volume_cap_window;
+        new_entry.window_volume = limits.window_volume;
+        new_entry.window_start = limits.window_start;
+        new_entry
+    }
+
+    /// Checks the passed in amount, denominated in the ETH token's units, against any limits set in
+    /// this entry, returning the first violated limit if there is one. Every amount violates the
+    /// limits of a disabled entry.
+    pub fn get_limit_violation(&self, amount: U256, timestamp: u64) -> Option<TokenLimitViolation> {
+        if self.disabled {
+            Some(TokenLimitViolation::TokenDisabled)
+        } else {
+            self.get_token_limits().get_violation(amount, timestamp)
+        }
+    }
+
+    /// Adds the passed in amount to the volume of the current window, starting a new window first
+    /// if the current one has elapsed. Does nothing if the entry has no volume cap.
+    pub fn add_to_window_volume(&self, amount: U256, timestamp: u64) -> Self {
+        self.set_token_limits_from(self.get_token_limits().add_to_window_volume(amount, timestamp))
+    }
+
+    pub fn set_token_limits(
+        &self,
+        min_peg_amount: Option<U256>,
+        max_tx_amount: Option<U256>,
+        volume_cap: Option<U256>,
+        volume_cap_window: Option<u64>,
+    ) -> Self {
+        info!("✔ Setting token limits for address {}...", self.eth_address);
+        self.set_token_limits_from(self.get_token_limits().update(&TokenLimits::new(
+            min_peg_amount,
+            max_tx_amount,
+            volume_cap,
+            volume_cap_window,
+        )))
+    }
+}
+
+impl EthEvmTokenDictionary {
+    pub fn get_token_limit_violation(
+        &self,
+        address: &EthAddress,
+        amount: U256,
+        timestamp: u64,
+    ) -> Result<Option<TokenLimitViolation>> {
+        self.get_entry_via_address(address)
+            .map(|entry| entry.get_limit_violation(amount, timestamp))
+    }
+
+    pub fn add_to_window_volume(&self, address: &EthAddress, amount: U256, timestamp: u64) -> Result<Self> {
+        self.get_entry_via_address(address)
+            .map(|entry| self.replace_entry(&entry, entry.add_to_window_volume(amount, timestamp)))
+    }
+
+    pub fn set_token_limits_and_update_in_db<D: DatabaseInterface>(
+        &self,
+        db: &D,
+        address: &EthAddress,
+        min_peg_amount: Option<U256>,
+        max_tx_amount: Option<U256>,
+        volume_cap: Option<U256>,
+        volume_cap_window: Option<u64>,
+    ) -> Result<()> {
+        self.get_entry_via_address(address)
+            .map(|entry| {
+                self.replace_entry(
+                    &entry,
+                    entry.set_token_limits(min_peg_amount, max_tx_amount, volume_cap, volume_cap_window),
+                )
+            })
+            .and_then(|updated_dictionary| updated_dictionary.save_in_db(db))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionaries::eth_evm::test_utils::get_sample_eth_evm_dictionary;
+
+    fn get_sample_entry_with_limits() -> EthEvmTokenDictionaryEntry {
+        get_sample_eth_evm_dictionary()[0].set_token_limits(
+            Some(U256::from(10)),
+            Some(U256::from(1000)),
+            Some(U256::from(1500)),
+            Some(3600),
+        )
+    }
+
+    #[test]
+    fn should_not_find_violation_if_entry_has_no_limits() {
+        let entry = get_sample_eth_evm_dictionary()[0].clone();
+        let result = entry.get_limit_violation(U256::MAX, 1);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_find_violation_for_any_amount_if_entry_is_disabled() {
+        let mut entry = get_sample_eth_evm_dictionary()[0].clone();
+        entry.disabled = true;
+        let result = entry.get_limit_violation(U256::one(), 1);
+        assert_eq!(result, Some(TokenLimitViolation::TokenDisabled));
+    }
+
+    #[test]
+    fn should_track_window_volume_in_entry() {
+        let timestamp = 1_000_000;
+        let amount = U256::from(1000);
+        let entry = get_sample_entry_with_limits().add_to_window_volume(amount, timestamp);
+        assert_eq!(entry.window_start, timestamp);
+        assert_eq!(entry.window_volume, amount);
+        let result = entry.get_limit_violation(amount, timestamp + 3599);
+        assert_eq!(result, Some(TokenLimitViolation::VolumeCapExceeded));
+    }
+
+    #[test]
+    fn should_serde_entry_with_limits_via_json() {
+        let entry = get_sample_entry_with_limits().add_to_window_volume(U256::from(100), 1_000_000);
+        let result = EthEvmTokenDictionaryEntry::from_json(&entry.to_json()).unwrap();
+        assert_eq!(result, entry);
+    }
+}
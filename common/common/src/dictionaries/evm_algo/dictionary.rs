@@ -114,7 +114,7 @@ impl EvmAlgoTokenDictionary {
         }
     }
 
-    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+    pub(crate) fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
         db.put(
             EVM_ALGO_DICTIONARY_KEY.to_vec(),
             self.to_bytes()?,
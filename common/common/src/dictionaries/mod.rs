@@ -4,3 +4,4 @@ mod dictionary_utils;
 pub mod eos_eth;
 pub mod eth_evm;
 pub mod evm_algo;
+pub mod token_dictionary;
@@ -0,0 +1,324 @@
+use std::str::FromStr;
+
+use derive_more::{Constructor, Deref, DerefMut};
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bridge_side::BridgeSide,
+    dictionaries::{
+        dictionary_traits::DictionaryDecimalConverter,
+        token_dictionary::{
+            DictionaryAddress,
+            TokenDictionaryEntry,
+            TokenDictionaryEntryJson,
+            TokenLimitViolation,
+            TokenLimits,
+        },
+    },
+    errors::AppError,
+    types::{Byte, Bytes, Result},
+};
+
+const TOKEN_DICTIONARY_JSON_VERSION: u8 = 1;
+
+/// A dictionary of token pairings between the native and host sides of a bridge, whose tokens are
+/// identified via addresses of types `N` and `H` respectively.
+///
+/// NOTE: This is a unified view over the persisted `EthEvm`, `EosEth` & `EvmAlgo` dictionaries (see
+/// `dictionary_storage`), backing the single family of generic dictionary debug commands. Token limits
+/// (`TokenLimits`) & decimal conversion (`DictionaryDecimalConverter`) are shared with those legacy
+/// dictionaries, but the bridges' submission pipelines still use their own fee & entry logic, which this
+/// type does not replace.
+#[derive(Debug, Clone, Eq, PartialEq, Constructor, Deref, DerefMut)]
+pub struct TokenDictionary<N, H>(pub Vec<TokenDictionaryEntry<N, H>>);
+
+impl<N, H> Default for TokenDictionary<N, H> {
+    fn default() -> Self {
+        Self(vec![])
+    }
+}
+
+impl<N: DictionaryAddress, H: DictionaryAddress> TokenDictionary<N, H> {
+    pub fn get_entry_via_native_address(&self, address: &N) -> Result<TokenDictionaryEntry<N, H>> {
+        match self.iter().find(|entry| &entry.native_address == address) {
+            Some(entry) => Ok(entry.clone()),
+            None => Err(format!(
+                "No `TokenDictionaryEntry` exists with native address: {}",
+                address.to_dictionary_string()
+            )
+            .into()),
+        }
+    }
+
+    pub fn get_entry_via_host_address(&self, address: &H) -> Result<TokenDictionaryEntry<N, H>> {
+        match self.iter().find(|entry| &entry.host_address == address) {
+            Some(entry) => Ok(entry.clone()),
+            None => Err(format!(
+                "No `TokenDictionaryEntry` exists with host address: {}",
+                address.to_dictionary_string()
+            )
+            .into()),
+        }
+    }
+
+    /// Gets the entry whose native, or failing that host, address is encoded in the passed in
+    /// string, along with the side of the bridge that address was found on.
+    pub fn get_entry_via_address_str(&self, s: &str) -> Result<(BridgeSide, TokenDictionaryEntry<N, H>)> {
+        N::from_dictionary_string(s)
+            .and_then(|address| self.get_entry_via_native_address(&address))
+            .map(|entry| (BridgeSide::Native, entry))
+            .or_else(|_| {
+                H::from_dictionary_string(s)
+                    .and_then(|address| self.get_entry_via_host_address(&address))
+                    .map(|entry| (BridgeSide::Host, entry))
+            })
+            .map_err(|_| format!("No `TokenDictionaryEntry` exists with address: {}", s).into())
+    }
+
+    pub fn add(&self, entry: TokenDictionaryEntry<N, H>) -> Result<Self> {
+        if self.get_entry_via_native_address(&entry.native_address).is_ok()
+            || self.get_entry_via_host_address(&entry.host_address).is_ok()
+        {
+            Err("Cannot add `TokenDictionaryEntry` ∵ an entry with one of its addresses already exists!".into())
+        } else {
+            info!("✔ Adding `TokenDictionaryEntry`: {:?}...", entry);
+            let mut new_self = self.clone();
+            new_self.push(entry);
+            Ok(new_self)
+        }
+    }
+
+    pub fn remove(&self, entry: &TokenDictionaryEntry<N, H>) -> Self {
+        let mut new_self = self.clone();
+        if self.contains(entry) {
+            info!("✔ Removing `TokenDictionaryEntry`: {:?}", entry);
+            new_self.retain(|x| x != entry);
+        } else {
+            info!("✘ Not removing `TokenDictionaryEntry` ∵ it's not in the dictionary!");
+        };
+        new_self
+    }
+
+    pub fn replace_entry(
+        &self,
+        entry_to_remove: &TokenDictionaryEntry<N, H>,
+        entry_to_add: TokenDictionaryEntry<N, H>,
+    ) -> Self {
+        self.iter()
+            .map(|entry| {
+                if entry == entry_to_remove {
+                    entry_to_add.clone()
+                } else {
+                    entry.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn update_entry_via_address_str(
+        &self,
+        s: &str,
+        f: impl FnOnce(&BridgeSide, &TokenDictionaryEntry<N, H>) -> TokenDictionaryEntry<N, H>,
+    ) -> Result<Self> {
+        self.get_entry_via_address_str(s)
+            .map(|(side, entry)| self.replace_entry(&entry, f(&side, &entry)))
+    }
+
+    /// Sets the fee basis points for the side of the bridge on which the passed in address is found.
+    pub fn set_fee_basis_points(&self, address: &str, new_fee: u64) -> Result<Self> {
+        self.update_entry_via_address_str(address, |side, entry| entry.set_fee_basis_points(side, new_fee))
+    }
+
+    pub fn set_accrued_fees(&self, address: &str, fee: U256) -> Result<Self> {
+        self.update_entry_via_address_str(address, |_, entry| entry.set_accrued_fees(fee))
+    }
+
+    pub fn increment_accrued_fee(&self, address: &str, addend: U256) -> Result<Self> {
+        self.update_entry_via_address_str(address, |_, entry| entry.add_to_accrued_fees(addend))
+    }
+
+    /// Zeroes the accrued fees in the entry for the passed in address, returning the updated
+    /// dictionary along with the native address and amount of the withdrawn fees.
+    pub fn withdraw_fees(&self, address: &str, timestamp: u64) -> Result<(Self, N, U256)> {
+        let (_, entry) = self.get_entry_via_address_str(address)?;
+        Ok((
+            self.replace_entry(&entry, entry.withdraw_fees(timestamp)),
+            entry.native_address,
+            entry.accrued_fees,
+        ))
+    }
+
+    pub fn set_limits(&self, address: &str, limits: &TokenLimits) -> Result<Self> {
+        self.update_entry_via_address_str(address, |_, entry| entry.set_limits(limits))
+    }
+
+    pub fn set_enabled(&self, address: &str, enabled: bool) -> Result<Self> {
+        self.update_entry_via_address_str(address, |_, entry| entry.set_enabled(enabled))
+    }
+
+    pub fn get_limit_violation(
+        &self,
+        address: &N,
+        amount: U256,
+        timestamp: u64,
+    ) -> Result<Option<TokenLimitViolation>> {
+        self.get_entry_via_native_address(address)
+            .map(|entry| entry.get_limit_violation(amount, timestamp))
+    }
+
+    pub fn convert_native_amount_to_host_amount(&self, address: &N, amount: U256) -> Result<U256> {
+        self.get_entry_via_native_address(address)
+            .and_then(|entry| entry.convert_native_amount_to_host_amount(amount))
+    }
+
+    pub fn convert_host_amount_to_native_amount(&self, address: &H, amount: U256) -> Result<U256> {
+        self.get_entry_via_host_address(address)
+            .and_then(|entry| entry.convert_host_amount_to_native_amount(amount))
+    }
+
+    pub fn swap_sides(&self) -> TokenDictionary<H, N> {
+        TokenDictionary::new(self.iter().map(TokenDictionaryEntry::swap_sides).collect())
+    }
+
+    pub fn to_json(&self) -> TokenDictionaryJson {
+        TokenDictionaryJson {
+            version: TOKEN_DICTIONARY_JSON_VERSION,
+            entries: self.iter().map(TokenDictionaryEntry::to_json).collect(),
+        }
+    }
+
+    pub fn from_json(json: &TokenDictionaryJson) -> Result<Self> {
+        if json.version > TOKEN_DICTIONARY_JSON_VERSION {
+            return Err(format!(
+                "Cannot parse `TokenDictionaryJson` of version {} ∵ latest known version is {}!",
+                json.version, TOKEN_DICTIONARY_JSON_VERSION
+            )
+            .into());
+        };
+        Ok(Self::new(
+            json.entries
+                .iter()
+                .map(TokenDictionaryEntry::from_json)
+                .collect::<Result<Vec<_>>>()?,
+        ))
+    }
+
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(&self.to_json())?)
+    }
+
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Self::from_json(&TokenDictionaryJson::from_bytes(bytes)?)
+    }
+}
+
+impl<N, H> From<Vec<TokenDictionaryEntry<N, H>>> for TokenDictionary<N, H> {
+    fn from(entries: Vec<TokenDictionaryEntry<N, H>>) -> Self {
+        Self(entries)
+    }
+}
+
+impl<N: DictionaryAddress, H: DictionaryAddress> FromStr for TokenDictionary<N, H> {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+/// The versioned JSON encoding of a `TokenDictionary`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TokenDictionaryJson {
+    pub version: u8,
+    pub entries: Vec<TokenDictionaryEntryJson>,
+}
+
+impl TokenDictionaryJson {
+    /// NOTE: A bare list of entries is treated as version 0 of the format, so it can be migrated.
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        serde_json::from_slice::<Self>(bytes).or_else(|_| {
+            Ok(Self {
+                version: 0,
+                entries: serde_json::from_slice(bytes)?,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+
+    fn get_sample_dictionary() -> TokenDictionary<EthAddress, u64> {
+        TokenDictionary::new(vec![
+            TokenDictionaryEntry::new("TKN", "pTKN", EthAddress::from_low_u64_be(1), 42),
+            TokenDictionaryEntry::new("ABC", "pABC", EthAddress::from_low_u64_be(2), 666),
+        ])
+    }
+
+    #[test]
+    fn should_get_entry_via_address_str_from_either_side() {
+        let dictionary = get_sample_dictionary();
+        let (side, entry) = dictionary
+            .get_entry_via_address_str("0x0000000000000000000000000000000000000002")
+            .unwrap();
+        assert_eq!(side, BridgeSide::Native);
+        assert_eq!(entry, dictionary[1]);
+        let (side, entry) = dictionary.get_entry_via_address_str("42").unwrap();
+        assert_eq!(side, BridgeSide::Host);
+        assert_eq!(entry, dictionary[0]);
+        assert!(dictionary.get_entry_via_address_str("1337").is_err());
+    }
+
+    #[test]
+    fn should_not_add_entry_with_extant_address() {
+        let dictionary = get_sample_dictionary();
+        let entry = TokenDictionaryEntry::new("XYZ", "pXYZ", EthAddress::from_low_u64_be(3), 42);
+        assert!(dictionary.add(entry).is_err());
+    }
+
+    #[test]
+    fn should_set_fee_basis_points_for_side_of_address() {
+        let dictionary = get_sample_dictionary().set_fee_basis_points("666", 25).unwrap();
+        assert_eq!(dictionary[1].host_fee_basis_points, 25);
+        assert_eq!(dictionary[1].native_fee_basis_points, 0);
+    }
+
+    #[test]
+    fn should_withdraw_fees() {
+        let dictionary = get_sample_dictionary()
+            .increment_accrued_fee("42", U256::from(1337))
+            .unwrap();
+        let (result, address, amount) = dictionary.withdraw_fees("42", 1_000_000).unwrap();
+        assert_eq!(address, EthAddress::from_low_u64_be(1));
+        assert_eq!(amount, U256::from(1337));
+        assert_eq!(result[0].accrued_fees, U256::zero());
+        assert_eq!(result[0].last_withdrawal, 1_000_000);
+    }
+
+    #[test]
+    fn should_perform_dictionary_bytes_roundtrip() {
+        let dictionary = get_sample_dictionary().set_enabled("42", false).unwrap();
+        let result = TokenDictionary::from_bytes(&dictionary.to_bytes().unwrap()).unwrap();
+        assert_eq!(result, dictionary);
+    }
+
+    #[test]
+    fn should_migrate_unversioned_dictionary_bytes() {
+        let dictionary = get_sample_dictionary();
+        let bytes = serde_json::to_vec(&dictionary.to_json().entries).unwrap();
+        let result = TokenDictionary::from_bytes(&bytes).unwrap();
+        assert_eq!(result, dictionary);
+    }
+
+    #[test]
+    fn should_fail_to_parse_dictionary_json_from_the_future() {
+        let mut json = get_sample_dictionary().to_json();
+        json.version = TOKEN_DICTIONARY_JSON_VERSION + 1;
+        assert!(TokenDictionary::<EthAddress, u64>::from_json(&json).is_err());
+    }
+}
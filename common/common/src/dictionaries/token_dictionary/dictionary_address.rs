@@ -0,0 +1,81 @@
+use std::{fmt::Debug, str::FromStr};
+
+use eos_chain::AccountName as EosAccountName;
+use ethereum_types::Address as EthAddress;
+
+use crate::{types::Result, utils::strip_hex_prefix};
+
+/// An address type which may be used to identify a token on one side of a `TokenDictionary`,
+/// along with its string encoding as used in the dictionary's JSON.
+pub trait DictionaryAddress: Clone + Debug + Eq {
+    fn to_dictionary_string(&self) -> String;
+
+    fn from_dictionary_string(s: &str) -> Result<Self>;
+}
+
+impl DictionaryAddress for EthAddress {
+    fn to_dictionary_string(&self) -> String {
+        hex::encode(self)
+    }
+
+    fn from_dictionary_string(s: &str) -> Result<Self> {
+        let bytes = hex::decode(strip_hex_prefix(s))?;
+        if bytes.len() == EthAddress::len_bytes() {
+            Ok(EthAddress::from_slice(&bytes))
+        } else {
+            Err(format!("Cannot get ETH address from '{}' ∵ it's the wrong length!", s).into())
+        }
+    }
+}
+
+impl DictionaryAddress for EosAccountName {
+    fn to_dictionary_string(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_dictionary_string(s: &str) -> Result<Self> {
+        Ok(EosAccountName::from_str(s)?)
+    }
+}
+
+/// NOTE: Tokens on Algorand are identified by their asset ID.
+impl DictionaryAddress for u64 {
+    fn to_dictionary_string(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_dictionary_string(s: &str) -> Result<Self> {
+        Ok(s.parse::<u64>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_perform_eth_address_dictionary_string_roundtrip() {
+        let address = EthAddress::from_low_u64_be(1337);
+        let result = EthAddress::from_dictionary_string(&address.to_dictionary_string()).unwrap();
+        assert_eq!(result, address);
+    }
+
+    #[test]
+    fn should_fail_to_get_eth_address_from_wrong_length_string() {
+        assert!(EthAddress::from_dictionary_string("0xc0ffee").is_err());
+    }
+
+    #[test]
+    fn should_perform_eos_account_name_dictionary_string_roundtrip() {
+        let address = EosAccountName::from_str("sampletokens").unwrap();
+        let result = EosAccountName::from_dictionary_string(&address.to_dictionary_string()).unwrap();
+        assert_eq!(result, address);
+    }
+
+    #[test]
+    fn should_perform_asset_id_dictionary_string_roundtrip() {
+        let asset_id = 666u64;
+        let result = u64::from_dictionary_string(&asset_id.to_dictionary_string()).unwrap();
+        assert_eq!(result, asset_id);
+    }
+}
@@ -0,0 +1,295 @@
+use std::str::FromStr;
+
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bridge_side::BridgeSide,
+    dictionaries::{
+        dictionary_traits::DictionaryDecimalConverter,
+        token_dictionary::{DictionaryAddress, TokenLimitViolation, TokenLimits},
+    },
+    errors::AppError,
+    types::Result,
+};
+
+/// A token pairing between the native and host sides of a bridge, whose tokens are identified via
+/// addresses of types `N` and `H` respectively. Fees are accrued in the native token's units.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TokenDictionaryEntry<N, H> {
+    pub native_symbol: String,
+    pub host_symbol: String,
+    pub native_address: N,
+    pub host_address: H,
+    pub native_decimals: Option<u16>,
+    pub host_decimals: Option<u16>,
+    pub native_fee_basis_points: u64,
+    pub host_fee_basis_points: u64,
+    pub accrued_fees: U256,
+    pub last_withdrawal: u64,
+    pub limits: TokenLimits,
+    pub enabled: bool,
+}
+
+impl<N: DictionaryAddress, H: DictionaryAddress> DictionaryDecimalConverter for TokenDictionaryEntry<N, H> {
+    fn requires_decimal_conversion(&self) -> Result<bool> {
+        Ok(
+            self.native_decimals.is_some()
+                && self.host_decimals.is_some()
+                && self.native_decimals != self.host_decimals,
+        )
+    }
+
+    fn get_host_decimals(&self) -> Result<u16> {
+        self.host_decimals
+            .ok_or_else(|| format!("Dictionary entry does NOT have host token decimals set! {:?}", self).into())
+    }
+
+    fn get_native_decimals(&self) -> Result<u16> {
+        self.native_decimals
+            .ok_or_else(|| format!("Dictionary entry does NOT have native token decimals set! {:?}", self).into())
+    }
+}
+
+impl<N: DictionaryAddress, H: DictionaryAddress> TokenDictionaryEntry<N, H> {
+    pub fn new(native_symbol: &str, host_symbol: &str, native_address: N, host_address: H) -> Self {
+        Self {
+            native_symbol: native_symbol.to_string(),
+            host_symbol: host_symbol.to_string(),
+            native_address,
+            host_address,
+            native_decimals: None,
+            host_decimals: None,
+            native_fee_basis_points: 0,
+            host_fee_basis_points: 0,
+            accrued_fees: U256::zero(),
+            last_withdrawal: 0,
+            limits: TokenLimits::default(),
+            enabled: true,
+        }
+    }
+
+    /// Swaps the native and host sides of this entry, for use where the same persisted dictionary
+    /// format backs bridges running in both directions.
+    pub fn swap_sides(&self) -> TokenDictionaryEntry<H, N> {
+        TokenDictionaryEntry {
+            native_symbol: self.host_symbol.clone(),
+            host_symbol: self.native_symbol.clone(),
+            native_address: self.host_address.clone(),
+            host_address: self.native_address.clone(),
+            native_decimals: self.host_decimals,
+            host_decimals: self.native_decimals,
+            native_fee_basis_points: self.host_fee_basis_points,
+            host_fee_basis_points: self.native_fee_basis_points,
+            accrued_fees: self.accrued_fees,
+            last_withdrawal: self.last_withdrawal,
+            limits: self.limits.clone(),
+            enabled: self.enabled,
+        }
+    }
+
+    pub fn get_fee_basis_points(&self, side: &BridgeSide) -> u64 {
+        match side {
+            BridgeSide::Native => self.native_fee_basis_points,
+            BridgeSide::Host => self.host_fee_basis_points,
+        }
+    }
+
+    pub fn set_fee_basis_points(&self, side: &BridgeSide, new_fee: u64) -> Self {
+        info!("✔ Setting {} fee basis points to {}...", side, new_fee);
+        let mut new_entry = self.clone();
+        match side {
+            BridgeSide::Native => new_entry.native_fee_basis_points = new_fee,
+            BridgeSide::Host => new_entry.host_fee_basis_points = new_fee,
+        };
+        new_entry
+    }
+
+    pub fn set_accrued_fees(&self, fee: U256) -> Self {
+        info!("✔ Setting accrued fees to {}...", fee);
+        let mut new_entry = self.clone();
+        new_entry.accrued_fees = fee;
+        new_entry
+    }
+
+    pub fn add_to_accrued_fees(&self, addend: U256) -> Self {
+        self.set_accrued_fees(self.accrued_fees + addend)
+    }
+
+    pub fn withdraw_fees(&self, timestamp: u64) -> Self {
+        info!("✔ Withdrawing {} accrued fees at {}...", self.accrued_fees, timestamp);
+        let mut new_entry = self.clone();
+        new_entry.accrued_fees = U256::zero();
+        new_entry.last_withdrawal = timestamp;
+        new_entry
+    }
+
+    pub fn set_limits(&self, limits: &TokenLimits) -> Self {
+        let mut new_entry = self.clone();
+        new_entry.limits = self.limits.update(limits);
+        new_entry
+    }
+
+    pub fn set_enabled(&self, enabled: bool) -> Self {
+        info!(
+            "✔ {} token {}...",
+            if enabled { "Enabling" } else { "Disabling" },
+            self.native_address.to_dictionary_string()
+        );
+        let mut new_entry = self.clone();
+        new_entry.enabled = enabled;
+        new_entry
+    }
+
+    /// Checks the passed in amount, denominated in the native token's units, against this entry's
+    /// limits, returning the first violated limit if there is one.
+    pub fn get_limit_violation(&self, amount: U256, timestamp: u64) -> Option<TokenLimitViolation> {
+        if self.enabled {
+            self.limits.get_violation(amount, timestamp)
+        } else {
+            Some(TokenLimitViolation::TokenDisabled)
+        }
+    }
+
+    pub fn add_to_window_volume(&self, amount: U256, timestamp: u64) -> Self {
+        let mut new_entry = self.clone();
+        new_entry.limits = self.limits.add_to_window_volume(amount, timestamp);
+        new_entry
+    }
+
+    pub fn to_json(&self) -> TokenDictionaryEntryJson {
+        TokenDictionaryEntryJson {
+            native_symbol: self.native_symbol.clone(),
+            host_symbol: self.host_symbol.clone(),
+            native_address: self.native_address.to_dictionary_string(),
+            host_address: self.host_address.to_dictionary_string(),
+            native_decimals: self.native_decimals,
+            host_decimals: self.host_decimals,
+            native_fee_basis_points: Some(self.native_fee_basis_points),
+            host_fee_basis_points: Some(self.host_fee_basis_points),
+            accrued_fees: Some(self.accrued_fees.to_string()),
+            last_withdrawal: Some(self.last_withdrawal),
+            limits: if self.limits.is_empty() {
+                None
+            } else {
+                Some(self.limits.clone())
+            },
+            enabled: Some(self.enabled),
+        }
+    }
+
+    pub fn from_json(json: &TokenDictionaryEntryJson) -> Result<Self> {
+        Ok(Self {
+            native_symbol: json.native_symbol.clone(),
+            host_symbol: json.host_symbol.clone(),
+            native_address: N::from_dictionary_string(&json.native_address)?,
+            host_address: H::from_dictionary_string(&json.host_address)?,
+            native_decimals: json.native_decimals,
+            host_decimals: json.host_decimals,
+            native_fee_basis_points: json.native_fee_basis_points.unwrap_or_default(),
+            host_fee_basis_points: json.host_fee_basis_points.unwrap_or_default(),
+            accrued_fees: match json.accrued_fees {
+                Some(ref s) => U256::from_dec_str(s)?,
+                None => U256::zero(),
+            },
+            last_withdrawal: json.last_withdrawal.unwrap_or_default(),
+            limits: json.limits.clone().unwrap_or_default(),
+            enabled: json.enabled.unwrap_or(true),
+        })
+    }
+}
+
+impl<N: DictionaryAddress, H: DictionaryAddress> FromStr for TokenDictionaryEntry<N, H> {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_json(&serde_json::from_str(s)?)
+    }
+}
+
+/// The JSON encoding of a `TokenDictionaryEntry`, where only the symbols and addresses are required.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TokenDictionaryEntryJson {
+    pub native_symbol: String,
+    pub host_symbol: String,
+    pub native_address: String,
+    pub host_address: String,
+    #[serde(default)]
+    pub native_decimals: Option<u16>,
+    #[serde(default)]
+    pub host_decimals: Option<u16>,
+    #[serde(default)]
+    pub native_fee_basis_points: Option<u64>,
+    #[serde(default)]
+    pub host_fee_basis_points: Option<u64>,
+    #[serde(default)]
+    pub accrued_fees: Option<String>,
+    #[serde(default)]
+    pub last_withdrawal: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<TokenLimits>,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+
+    fn get_sample_entry() -> TokenDictionaryEntry<EthAddress, u64> {
+        let mut entry = TokenDictionaryEntry::new("TKN", "pTKN", EthAddress::from_low_u64_be(1), 666);
+        entry.native_decimals = Some(18);
+        entry.host_decimals = Some(10);
+        entry
+    }
+
+    #[test]
+    fn should_perform_entry_json_roundtrip() {
+        let entry = get_sample_entry()
+            .set_fee_basis_points(&BridgeSide::Host, 25)
+            .add_to_accrued_fees(U256::from(1337))
+            .set_limits(&TokenLimits::new(Some(U256::one()), None, None, None))
+            .set_enabled(false);
+        let result = TokenDictionaryEntry::from_json(&entry.to_json()).unwrap();
+        assert_eq!(result, entry);
+    }
+
+    #[test]
+    fn should_get_entry_from_minimal_json_str() {
+        let s = r#"{"native_symbol":"TKN","host_symbol":"pTKN","native_address":"0x0000000000000000000000000000000000000001","host_address":"666"}"#;
+        let result = TokenDictionaryEntry::<EthAddress, u64>::from_str(s).unwrap();
+        assert_eq!(
+            result,
+            TokenDictionaryEntry::new("TKN", "pTKN", EthAddress::from_low_u64_be(1), 666)
+        );
+        assert!(result.enabled);
+    }
+
+    #[test]
+    fn should_convert_amounts_between_decimals() {
+        let entry = get_sample_entry();
+        let native_amount = U256::from_dec_str("1000000000000000000").unwrap();
+        let host_amount = entry.convert_native_amount_to_host_amount(native_amount).unwrap();
+        assert_eq!(host_amount, U256::from(10_000_000_000u64));
+        let result = entry.convert_host_amount_to_native_amount(host_amount).unwrap();
+        assert_eq!(result, native_amount);
+    }
+
+    #[test]
+    fn should_find_violation_if_entry_is_disabled() {
+        let entry = get_sample_entry().set_enabled(false);
+        let result = entry.get_limit_violation(U256::one(), 1);
+        assert_eq!(result, Some(TokenLimitViolation::TokenDisabled));
+    }
+
+    #[test]
+    fn should_swap_sides_of_entry() {
+        let entry = get_sample_entry().set_fee_basis_points(&BridgeSide::Native, 10);
+        let swapped = entry.swap_sides();
+        assert_eq!(swapped.native_address, 666);
+        assert_eq!(swapped.host_fee_basis_points, 10);
+        assert_eq!(swapped.swap_sides(), entry);
+    }
+}
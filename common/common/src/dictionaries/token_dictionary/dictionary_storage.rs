@@ -0,0 +1,309 @@
+use std::str::FromStr;
+
+use eos_chain::AccountName as EosAccountName;
+use ethereum_types::{Address as EthAddress, U256};
+
+use crate::{
+    dictionaries::{
+        dictionary_utils::get_last_withdrawal_date_as_human_readable_string,
+        eos_eth::{EosEthTokenDictionary, EosEthTokenDictionaryEntry},
+        eth_evm::{EthEvmTokenDictionary, EthEvmTokenDictionaryEntry},
+        evm_algo::{EvmAlgoTokenDictionary, EvmAlgoTokenDictionaryEntry},
+        token_dictionary::{DictionaryAddress, TokenDictionary, TokenDictionaryEntry},
+    },
+    errors::AppError,
+    traits::DatabaseInterface,
+    types::Result,
+};
+
+/// Persists a `TokenDictionary` in the database. Each pairing of address types is stored under the
+/// key, and in the format, of the dictionary which that pairing has always used, so that the
+/// bridges' pipelines and the generic debug functions operate upon the same data.
+pub trait TokenDictionaryStorage: Sized {
+    fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self>;
+
+    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()>;
+}
+
+/// Marks a `TokenDictionary` whose storage format can persist fee basis points & accrued fees.
+pub trait TokenDictionaryFeesStorage: TokenDictionaryStorage {}
+
+/// Marks a `TokenDictionary` whose storage format can persist token limits & disabled tokens.
+pub trait TokenDictionaryLimitsStorage: TokenDictionaryStorage {}
+
+fn check_entry_is_persistable<N: DictionaryAddress, H: DictionaryAddress>(
+    entry: &TokenDictionaryEntry<N, H>,
+    format: &str,
+    supports_fees: bool,
+) -> Result<()> {
+    let unsupported = if !entry.limits.is_empty() {
+        Some("token limits")
+    } else if !entry.enabled {
+        Some("disabling tokens")
+    } else if !supports_fees
+        && (entry.native_fee_basis_points != 0 || entry.host_fee_basis_points != 0 || !entry.accrued_fees.is_zero())
+    {
+        Some("fees")
+    } else {
+        None
+    };
+    match unsupported {
+        Some(feature) => Err(format!("`{}` does not support {}!", format, feature).into()),
+        None => Ok(()),
+    }
+}
+
+fn get_required_decimals<N: DictionaryAddress, H: DictionaryAddress>(
+    entry: &TokenDictionaryEntry<N, H>,
+    decimals: Option<u16>,
+    format: &str,
+) -> Result<u16> {
+    decimals.ok_or_else(|| {
+        format!(
+            "`{}` requires decimals for both tokens in entry for {}!",
+            format,
+            entry.native_address.to_dictionary_string()
+        )
+        .into()
+    })
+}
+
+impl From<&EthEvmTokenDictionaryEntry> for TokenDictionaryEntry<EthAddress, EthAddress> {
+    fn from(entry: &EthEvmTokenDictionaryEntry) -> Self {
+        Self {
+            native_symbol: entry.eth_symbol.clone(),
+            host_symbol: entry.evm_symbol.clone(),
+            native_address: entry.eth_address,
+            host_address: entry.evm_address,
+            native_decimals: entry.eth_token_decimals,
+            host_decimals: entry.evm_token_decimals,
+            native_fee_basis_points: entry.eth_fee_basis_points,
+            host_fee_basis_points: entry.evm_fee_basis_points,
+            accrued_fees: entry.accrued_fees,
+            last_withdrawal: entry.last_withdrawal,
+            limits: entry.get_token_limits(),
+            enabled: !entry.disabled,
+        }
+    }
+}
+
+impl From<&TokenDictionaryEntry<EthAddress, EthAddress>> for EthEvmTokenDictionaryEntry {
+    fn from(entry: &TokenDictionaryEntry<EthAddress, EthAddress>) -> Self {
+        Self {
+            eth_symbol: entry.native_symbol.clone(),
+            evm_symbol: entry.host_symbol.clone(),
+            eth_address: entry.native_address,
+            evm_address: entry.host_address,
+            eth_token_decimals: entry.native_decimals,
+            evm_token_decimals: entry.host_decimals,
+            eth_fee_basis_points: entry.native_fee_basis_points,
+            evm_fee_basis_points: entry.host_fee_basis_points,
+            accrued_fees: entry.accrued_fees,
+            accrued_fees_human_readable: entry.accrued_fees.as_u128(),
+            last_withdrawal: entry.last_withdrawal,
+            last_withdrawal_human_readable: get_last_withdrawal_date_as_human_readable_string(entry.last_withdrawal),
+            disabled: !entry.enabled,
+            ..Default::default()
+        }
+        .set_token_limits_from(entry.limits.clone())
+    }
+}
+
+impl TokenDictionaryFeesStorage for TokenDictionary<EthAddress, EthAddress> {}
+
+impl TokenDictionaryLimitsStorage for TokenDictionary<EthAddress, EthAddress> {}
+
+impl TokenDictionaryStorage for TokenDictionary<EthAddress, EthAddress> {
+    fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        EthEvmTokenDictionary::get_from_db(db).map(|dictionary| Self::new(dictionary.iter().map(Into::into).collect()))
+    }
+
+    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        EthEvmTokenDictionary::new(self.iter().map(Into::into).collect()).save_in_db(db)
+    }
+}
+
+impl TryFrom<&EosEthTokenDictionaryEntry> for TokenDictionaryEntry<EthAddress, EosAccountName> {
+    type Error = AppError;
+
+    fn try_from(entry: &EosEthTokenDictionaryEntry) -> Result<Self> {
+        Ok(Self {
+            native_symbol: entry.eth_symbol.clone(),
+            host_symbol: entry.eos_symbol.clone(),
+            native_address: entry.eth_address,
+            host_address: EosAccountName::from_str(&entry.eos_address)?,
+            native_decimals: Some(u16::try_from(entry.eth_token_decimals)?),
+            host_decimals: Some(u16::try_from(entry.eos_token_decimals)?),
+            native_fee_basis_points: entry.eth_fee_basis_points,
+            host_fee_basis_points: entry.eos_fee_basis_points,
+            accrued_fees: entry.accrued_fees,
+            last_withdrawal: entry.last_withdrawal,
+            limits: Default::default(),
+            enabled: true,
+        })
+    }
+}
+
+impl TryFrom<&TokenDictionaryEntry<EthAddress, EosAccountName>> for EosEthTokenDictionaryEntry {
+    type Error = AppError;
+
+    fn try_from(entry: &TokenDictionaryEntry<EthAddress, EosAccountName>) -> Result<Self> {
+        let format = "EosEthTokenDictionary";
+        check_entry_is_persistable(entry, format, true)?;
+        Ok(Self {
+            eth_token_decimals: get_required_decimals(entry, entry.native_decimals, format)? as usize,
+            eos_token_decimals: get_required_decimals(entry, entry.host_decimals, format)? as usize,
+            eth_symbol: entry.native_symbol.clone(),
+            eos_symbol: entry.host_symbol.clone(),
+            eth_address: entry.native_address,
+            eos_address: entry.host_address.to_string(),
+            eth_fee_basis_points: entry.native_fee_basis_points,
+            eos_fee_basis_points: entry.host_fee_basis_points,
+            accrued_fees: entry.accrued_fees,
+            accrued_fees_human_readable: entry.accrued_fees.as_u128(),
+            last_withdrawal: entry.last_withdrawal,
+            last_withdrawal_human_readable: get_last_withdrawal_date_as_human_readable_string(entry.last_withdrawal),
+        })
+    }
+}
+
+impl TokenDictionaryFeesStorage for TokenDictionary<EthAddress, EosAccountName> {}
+
+impl TokenDictionaryStorage for TokenDictionary<EthAddress, EosAccountName> {
+    fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        EosEthTokenDictionary::get_from_db(db).and_then(|dictionary| {
+            Ok(Self::new(
+                dictionary.iter().map(TryInto::try_into).collect::<Result<Vec<_>>>()?,
+            ))
+        })
+    }
+
+    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        EosEthTokenDictionary::new(self.iter().map(TryInto::try_into).collect::<Result<Vec<_>>>()?).save_to_db(db)
+    }
+}
+
+// NOTE: EOS native bridges use the same persisted dictionary, with its sides swapped.
+impl TokenDictionaryFeesStorage for TokenDictionary<EosAccountName, EthAddress> {}
+
+impl TokenDictionaryStorage for TokenDictionary<EosAccountName, EthAddress> {
+    fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        TokenDictionary::<EthAddress, EosAccountName>::get_from_db(db).map(|dictionary| dictionary.swap_sides())
+    }
+
+    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        self.swap_sides().save_in_db(db)
+    }
+}
+
+impl From<&EvmAlgoTokenDictionaryEntry> for TokenDictionaryEntry<EthAddress, u64> {
+    fn from(entry: &EvmAlgoTokenDictionaryEntry) -> Self {
+        Self {
+            native_symbol: entry.evm_symbol.clone(),
+            host_symbol: entry.algo_symbol.clone(),
+            native_address: entry.evm_address,
+            host_address: entry.algo_asset_id,
+            native_decimals: Some(entry.evm_decimals),
+            host_decimals: Some(entry.algo_decimals),
+            native_fee_basis_points: 0,
+            host_fee_basis_points: 0,
+            accrued_fees: U256::zero(),
+            last_withdrawal: 0,
+            limits: Default::default(),
+            enabled: true,
+        }
+    }
+}
+
+impl TryFrom<&TokenDictionaryEntry<EthAddress, u64>> for EvmAlgoTokenDictionaryEntry {
+    type Error = AppError;
+
+    fn try_from(entry: &TokenDictionaryEntry<EthAddress, u64>) -> Result<Self> {
+        let format = "EvmAlgoTokenDictionary";
+        check_entry_is_persistable(entry, format, false)?;
+        Ok(Self {
+            evm_decimals: get_required_decimals(entry, entry.native_decimals, format)?,
+            algo_decimals: get_required_decimals(entry, entry.host_decimals, format)?,
+            algo_asset_id: entry.host_address,
+            evm_symbol: entry.native_symbol.clone(),
+            algo_symbol: entry.host_symbol.clone(),
+            evm_address: entry.native_address,
+        })
+    }
+}
+
+impl TokenDictionaryStorage for TokenDictionary<EthAddress, u64> {
+    fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        EvmAlgoTokenDictionary::get_from_db(db).map(|dictionary| Self::new(dictionary.iter().map(Into::into).collect()))
+    }
+
+    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        EvmAlgoTokenDictionary::new(self.iter().map(TryInto::try_into).collect::<Result<Vec<_>>>()?).save_in_db(db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dictionaries::{
+            eos_eth::test_utils::get_sample_eos_eth_token_dictionary,
+            eth_evm::test_utils::get_sample_eth_evm_dictionary,
+            token_dictionary::TokenLimits,
+        },
+        test_utils::get_test_database,
+    };
+
+    #[test]
+    fn should_read_and_write_eth_evm_dictionary_via_token_dictionary() {
+        let db = get_test_database();
+        let legacy_dictionary = get_sample_eth_evm_dictionary();
+        legacy_dictionary.save_in_db(&db).unwrap();
+        let dictionary = TokenDictionary::<EthAddress, EthAddress>::get_from_db(&db).unwrap();
+        assert_eq!(dictionary.len(), legacy_dictionary.len());
+        let address = dictionary[0].native_address.to_dictionary_string();
+        let limits = TokenLimits::new(Some(U256::one()), None, None, None);
+        dictionary
+            .set_limits(&address, &limits)
+            .and_then(|dictionary| dictionary.set_enabled(&address, false))
+            .and_then(|dictionary| dictionary.save_in_db(&db))
+            .unwrap();
+        let result = EthEvmTokenDictionary::get_from_db(&db).unwrap();
+        assert_eq!(result[0].min_peg_amount, Some(U256::one()));
+        assert!(result[0].disabled);
+        assert_eq!(result[1..], legacy_dictionary[1..]);
+    }
+
+    #[test]
+    fn should_read_and_write_eos_eth_dictionary_via_token_dictionary_in_both_directions() {
+        let db = get_test_database();
+        let legacy_dictionary = get_sample_eos_eth_token_dictionary();
+        legacy_dictionary.save_to_db(&db).unwrap();
+        let dictionary = TokenDictionary::<EosAccountName, EthAddress>::get_from_db(&db).unwrap();
+        let address = dictionary[0].native_address.to_dictionary_string();
+        dictionary
+            .set_fee_basis_points(&address, 25)
+            .and_then(|dictionary| dictionary.save_in_db(&db))
+            .unwrap();
+        let result = EosEthTokenDictionary::get_from_db(&db).unwrap();
+        assert_eq!(result[0].eos_fee_basis_points, 25);
+        let dictionary = TokenDictionary::<EthAddress, EosAccountName>::get_from_db(&db).unwrap();
+        assert_eq!(dictionary[0].host_fee_basis_points, 25);
+    }
+
+    #[test]
+    fn should_not_save_features_unsupported_by_evm_algo_dictionary() {
+        let db = get_test_database();
+        let dictionary = TokenDictionary::new(vec![TokenDictionaryEntry {
+            native_decimals: Some(18),
+            host_decimals: Some(10),
+            ..TokenDictionaryEntry::new("TKN", "pTKN", EthAddress::from_low_u64_be(1), 42)
+        }]);
+        dictionary.save_in_db(&db).unwrap();
+        assert_eq!(EvmAlgoTokenDictionary::get_from_db(&db).unwrap().len(), 1);
+        let result = dictionary.set_fee_basis_points("42", 10).unwrap().save_in_db(&db);
+        assert!(result.is_err());
+        let result = dictionary.set_enabled("42", false).unwrap().save_in_db(&db);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,13 @@
+mod dictionary;
+mod dictionary_address;
+mod dictionary_entry;
+mod dictionary_storage;
+mod token_limits;
+
+pub use self::{
+    dictionary::{TokenDictionary, TokenDictionaryJson},
+    dictionary_address::DictionaryAddress,
+    dictionary_entry::{TokenDictionaryEntry, TokenDictionaryEntryJson},
+    dictionary_storage::{TokenDictionaryFeesStorage, TokenDictionaryLimitsStorage, TokenDictionaryStorage},
+    token_limits::{TokenLimitViolation, TokenLimits},
+};
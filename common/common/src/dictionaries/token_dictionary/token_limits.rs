@@ -0,0 +1,177 @@
+use std::fmt;
+
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TokenLimitViolation {
+    BelowMinimum,
+    AboveMaximum,
+    VolumeCapExceeded,
+    TokenDisabled,
+}
+
+impl fmt::Display for TokenLimitViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BelowMinimum => write!(f, "amount is below the minimum peg amount"),
+            Self::AboveMaximum => write!(f, "amount is above the maximum tx amount"),
+            Self::VolumeCapExceeded => write!(f, "amount would exceed the volume cap for the current window"),
+            Self::TokenDisabled => write!(f, "token is disabled"),
+        }
+    }
+}
+
+/// The optional limits on the amounts of a token which may be pegged, along with the running volume
+/// of the current volume cap window. All amounts are denominated in the native token's units.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TokenLimits {
+    pub min_peg_amount: Option<U256>,
+    pub max_tx_amount: Option<U256>,
+    pub volume_cap: Option<U256>,
+    pub volume_cap_window: Option<u64>,
+    pub window_volume: U256,
+    pub window_start: u64,
+}
+
+impl TokenLimits {
+    pub fn new(
+        min_peg_amount: Option<U256>,
+        max_tx_amount: Option<U256>,
+        volume_cap: Option<U256>,
+        volume_cap_window: Option<u64>,
+    ) -> Self {
+        Self {
+            min_peg_amount,
+            max_tx_amount,
+            volume_cap,
+            volume_cap_window,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    fn volume_cap_window_has_elapsed(&self, timestamp: u64) -> bool {
+        match self.volume_cap_window {
+            Some(window) => timestamp >= self.window_start.saturating_add(window),
+            None => true,
+        }
+    }
+
+    fn get_current_window_volume(&self, timestamp: u64) -> U256 {
+        if self.volume_cap_window_has_elapsed(timestamp) {
+            U256::zero()
+        } else {
+            self.window_volume
+        }
+    }
+
+    /// Checks the passed in amount against these limits, returning the first violated limit if
+    /// there is one.
+    pub fn get_violation(&self, amount: U256, timestamp: u64) -> Option<TokenLimitViolation> {
+        if matches!(self.min_peg_amount, Some(min) if amount < min) {
+            Some(TokenLimitViolation::BelowMinimum)
+        } else if matches!(self.max_tx_amount, Some(max) if amount > max) {
+            Some(TokenLimitViolation::AboveMaximum)
+        } else if matches!(
+            self.volume_cap,
+            Some(cap) if self.get_current_window_volume(timestamp).saturating_add(amount) > cap
+        ) {
+            Some(TokenLimitViolation::VolumeCapExceeded)
+        } else {
+            None
+        }
+    }
+
+    /// Adds the passed in amount to the volume of the current window, starting a new window first
+    /// if the current one has elapsed. Does nothing if there is no volume cap.
+    pub fn add_to_window_volume(&self, amount: U256, timestamp: u64) -> Self {
+        let mut new_limits = self.clone();
+        if self.volume_cap.is_none() {
+            return new_limits;
+        };
+        if self.volume_cap_window_has_elapsed(timestamp) {
+            info!("✔ Starting new volume cap window at {}", timestamp);
+            new_limits.window_start = timestamp;
+            new_limits.window_volume = U256::zero();
+        };
+        new_limits.window_volume = new_limits.window_volume.saturating_add(amount);
+        new_limits
+    }
+
+    /// Replaces the limits themselves, whilst keeping the running volume of the current window.
+    pub fn update(&self, new_limits: &Self) -> Self {
+        Self {
+            window_volume: self.window_volume,
+            window_start: self.window_start,
+            ..new_limits.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_limits() -> TokenLimits {
+        TokenLimits::new(
+            Some(U256::from(10)),
+            Some(U256::from(1000)),
+            Some(U256::from(1500)),
+            Some(3600),
+        )
+    }
+
+    #[test]
+    fn should_not_find_violation_if_there_are_no_limits() {
+        let result = TokenLimits::default().get_violation(U256::MAX, 1);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_find_below_minimum_violation() {
+        let result = get_sample_limits().get_violation(U256::from(9), 1);
+        assert_eq!(result, Some(TokenLimitViolation::BelowMinimum));
+    }
+
+    #[test]
+    fn should_find_above_maximum_violation() {
+        let result = get_sample_limits().get_violation(U256::from(1001), 1);
+        assert_eq!(result, Some(TokenLimitViolation::AboveMaximum));
+    }
+
+    #[test]
+    fn should_find_volume_cap_violation_until_window_elapses() {
+        let timestamp = 1_000_000;
+        let amount = U256::from(1000);
+        let limits = get_sample_limits().add_to_window_volume(amount, timestamp);
+        assert_eq!(limits.window_start, timestamp);
+        assert_eq!(limits.window_volume, amount);
+        let result = limits.get_violation(amount, timestamp + 3599);
+        assert_eq!(result, Some(TokenLimitViolation::VolumeCapExceeded));
+        let result = limits.get_violation(amount, timestamp + 3600);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_start_new_window_when_adding_volume_after_window_elapses() {
+        let timestamp = 1_000_000;
+        let limits = get_sample_limits()
+            .add_to_window_volume(U256::from(1000), timestamp)
+            .add_to_window_volume(U256::from(100), timestamp + 3600);
+        assert_eq!(limits.window_start, timestamp + 3600);
+        assert_eq!(limits.window_volume, U256::from(100));
+    }
+
+    #[test]
+    fn should_keep_window_volume_when_updating_limits() {
+        let limits = get_sample_limits().add_to_window_volume(U256::from(100), 1_000_000);
+        let result = limits.update(&TokenLimits::default());
+        assert_eq!(result.volume_cap, None);
+        assert_eq!(result.window_volume, U256::from(100));
+        assert_eq!(result.window_start, 1_000_000);
+    }
+}
@@ -144,6 +144,14 @@ pub enum AppError {
     LitecoinError(#[from] litecoin::Error),
 }
 
+impl AppError {
+    /// Whether this is the error a `DatabaseInterface` returns when getting a key it doesn't hold,
+    /// as opposed to one from failing to read or decrypt a value which _is_ held.
+    pub fn is_db_key_not_found(&self) -> bool {
+        matches!(self, Self::Custom(msg) if msg.contains(crate::constants::DB_KEY_NOT_FOUND_ERROR))
+    }
+}
+
 impl From<&str> for AppError {
     fn from(s: &str) -> Self {
         AppError::from(s.to_string())
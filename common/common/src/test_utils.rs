@@ -3,6 +3,7 @@ use std::{collections::HashMap, sync::Mutex};
 use rand::Rng;
 
 use crate::{
+    constants::DB_KEY_NOT_FOUND_ERROR,
     traits::DatabaseInterface,
     types::{Bytes, DataSensitivity, Result},
 };
@@ -48,7 +49,7 @@ impl DatabaseInterface for TestDB {
     fn get(&self, key: Bytes, _sensitivity: DataSensitivity) -> Result<Bytes> {
         match self.0.lock().expect(DB_LOCK_ERRROR).get(&key) {
             Some(value) => Ok(value.to_vec()),
-            None => Err("Cannot find item in database!".into()),
+            None => Err(DB_KEY_NOT_FOUND_ERROR.into()),
         }
     }
 }
@@ -6,4 +6,17 @@ pub trait DatabaseInterface {
     fn delete(&self, key: Bytes) -> Result<()>;
     fn get(&self, key: Bytes, data_sensitivity: DataSensitivity) -> Result<Bytes>;
     fn put(&self, key: Bytes, value: Bytes, data_sensitivity: DataSensitivity) -> Result<()>;
+
+    /// Discards any state the database caches in memory, including any writes not yet committed
+    /// via `end_transaction`. Long-running processes call this between core calls so that nothing
+    /// from one call, successful or otherwise, leaks into the next.
+    fn clear_cache(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Gets the values for multiple keys, failing if any of them are not found. Databases backed by
+    /// a remote store may override this in order to fetch them all in a single round trip.
+    fn get_multiple(&self, keys: Vec<Bytes>, data_sensitivity: DataSensitivity) -> Result<Vec<Bytes>> {
+        keys.into_iter().map(|key| self.get(key, data_sensitivity)).collect()
+    }
 }
@@ -0,0 +1,88 @@
+use common::{
+    core_type::CoreType,
+    dictionaries::eth_evm::EthEvmTokenDictionary,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::prepend_debug_output_marker_to_string,
+};
+use common_debug_signers::validate_debug_command_signature;
+use common_fees::{FeeDistributionPolicies, FeeDistributionPolicy};
+use function_name::named;
+use serde_json::json;
+
+use crate::debug_fee_schedules::convert_hex_to_token_address;
+
+fn get_policy_token<D: DatabaseInterface>(db: &D, token_address: &str) -> Result<String> {
+    let dictionary = EthEvmTokenDictionary::get_from_db(db)?;
+    let address = convert_hex_to_token_address(token_address)?;
+    dictionary
+        .get_entry_via_eth_address(&address)
+        .map(|entry| entry.eth_address)
+        .or_else(|_| dictionary.get_eth_address_from_evm_address(&address))
+        .map(|eth_address| format!("0x{}", hex::encode(eth_address)))
+}
+
+/// # Debug Set Fee Distribution Policy
+///
+/// This function sets the policy used to split the withdrawn fees for the dictionary entry found
+/// via the passed in token address between multiple beneficiaries. The passed in JSON is a list
+/// of ETH recipient addresses along with their shares in basis points, which must total 10000, eg:
+///
+/// ```no_compile
+/// [
+///     { "recipient": "0xfEDFe2616EB3661CB8FEd2782F5F0cC91D59DCaC", "share_basis_points": 6000 },
+///     { "recipient": "0x0e1c8524b1d1891b201ffc7bb58a82c96f8fc4f6", "share_basis_points": 4000 }
+/// ]
+/// ```
+///
+/// Any rounding dust carried forward by a previous policy for the same token is kept.
+#[named]
+pub fn debug_set_fee_distribution_policy<D: DatabaseInterface>(
+    db: &D,
+    token_address: &str,
+    beneficiaries_json: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), token_address, beneficiaries_json, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| FeeDistributionPolicy::from_json_str(&get_policy_token(db, token_address)?, beneficiaries_json))
+        .and_then(|policy| {
+            for beneficiary in policy.beneficiaries.iter() {
+                convert_hex_to_token_address(&beneficiary.recipient)?;
+            }
+            FeeDistributionPolicies::get_from_db(db)?
+                .set_policy(policy.clone())
+                .save_in_db(db)?;
+            Ok(policy)
+        })
+        .and_then(|policy| {
+            db.end_transaction()?;
+            Ok(json!({"success": true, "fee_distribution_policy": policy}).to_string())
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Remove Fee Distribution Policy
+///
+/// This function removes the fee distribution policy for the dictionary entry found via the passed
+/// in token address, along with any rounding dust it has carried forward.
+#[named]
+pub fn debug_remove_fee_distribution_policy<D: DatabaseInterface>(
+    db: &D,
+    token_address: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), token_address, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| FeeDistributionPolicies::get_from_db(db)?.remove_policy(&get_policy_token(db, token_address)?))
+        .and_then(|policies| policies.save_in_db(db))
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"success": true, "token_address": token_address}).to_string())
+        .map(prepend_debug_output_marker_to_string)
+}
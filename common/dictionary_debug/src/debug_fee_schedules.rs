@@ -0,0 +1,155 @@
+use std::str::FromStr;
+
+use common::{
+    core_type::CoreType,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::{prepend_debug_output_marker_to_string, strip_hex_prefix},
+};
+use common_debug_signers::validate_debug_command_signature;
+use common_fees::{FeeSchedule, FeeSchedules};
+use common_metadata::MetadataChainId;
+use ethereum_types::{Address as EthAddress, U256};
+use function_name::named;
+use serde_json::json;
+
+pub(crate) fn convert_hex_to_token_address(hex: &str) -> Result<EthAddress> {
+    let bytes = hex::decode(strip_hex_prefix(hex))?;
+    if bytes.len() == EthAddress::len_bytes() {
+        Ok(EthAddress::from_slice(&bytes))
+    } else {
+        Err(format!("✘ Token address must be {} bytes!", EthAddress::len_bytes()).into())
+    }
+}
+
+fn parse_destination_chain_id(s: &str) -> Result<Option<MetadataChainId>> {
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(MetadataChainId::from_str(s)?))
+    }
+}
+
+/// # Debug Set Fee Schedule
+///
+/// This function sets the fee schedule for the token with the passed in address when it's pegged
+/// to the passed in destination chain. Passing an empty destination chain ID instead sets the
+/// default schedule for that token, used for any destination without a schedule of its own.
+///
+/// The fee charged is the flat fee plus the basis points fee, raised to the minimum fee if below
+/// it and lowered to the maximum fee if above it. All amounts are in the units of the token on
+/// the side of the bridge whose address is passed in, and pegs whose amount does not exceed the
+/// fee are rejected. Tokens without a fee schedule continue to use the basis points in the token
+/// dictionary.
+///
+/// #### NOTE: Passing 0 for the maximum fee means there is no maximum.
+#[allow(clippy::too_many_arguments)]
+#[named]
+pub fn debug_set_fee_schedule<D: DatabaseInterface>(
+    db: &D,
+    token_address: &str,
+    destination_chain_id: &str,
+    flat_fee: &str,
+    basis_points: u64,
+    minimum_fee: &str,
+    maximum_fee: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| {
+            get_debug_command_hash!(
+                function_name!(),
+                token_address,
+                destination_chain_id,
+                flat_fee,
+                &basis_points,
+                minimum_fee,
+                maximum_fee,
+                core_type
+            )()
+        })
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| {
+            let maximum = U256::from_dec_str(maximum_fee)?;
+            FeeSchedule::new(
+                U256::from_dec_str(flat_fee)?,
+                basis_points,
+                U256::from_dec_str(minimum_fee)?,
+                if maximum.is_zero() { None } else { Some(maximum) },
+            )
+        })
+        .and_then(|schedule| {
+            FeeSchedules::get_from_db(db)?
+                .set_schedule(
+                    &convert_hex_to_token_address(token_address)?,
+                    &parse_destination_chain_id(destination_chain_id)?,
+                    schedule.clone(),
+                )
+                .save_in_db(db)?;
+            Ok(schedule)
+        })
+        .and_then(|schedule| {
+            db.end_transaction()?;
+            Ok(json!({
+                "success": true,
+                "token_address": token_address,
+                "destination_chain_id": destination_chain_id,
+                "fee_schedule": schedule,
+            })
+            .to_string())
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Remove Fee Schedule
+///
+/// This function removes the fee schedule for the token with the passed in address and the passed
+/// in destination chain. Passing an empty destination chain ID removes that token's default
+/// schedule.
+#[named]
+pub fn debug_remove_fee_schedule<D: DatabaseInterface>(
+    db: &D,
+    token_address: &str,
+    destination_chain_id: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), token_address, destination_chain_id, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| FeeSchedules::get_from_db(db))
+        .and_then(|schedules| {
+            schedules
+                .remove_schedule(
+                    &convert_hex_to_token_address(token_address)?,
+                    &parse_destination_chain_id(destination_chain_id)?,
+                )
+                .save_in_db(db)
+        })
+        .and_then(|_| db.end_transaction())
+        .map(|_| {
+            json!({
+                "success": true,
+                "token_address": token_address,
+                "destination_chain_id": destination_chain_id,
+            })
+            .to_string()
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Get Fee Schedules
+///
+/// This function returns all the fee schedules currently set in the core.
+pub fn get_fee_schedules<D: DatabaseInterface>(db: &D) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| FeeSchedules::get_from_db(db))
+        .and_then(|schedules| {
+            db.end_transaction()?;
+            Ok(json!(schedules).to_string())
+        })
+}
@@ -0,0 +1,58 @@
+use common::{
+    core_type::CoreType,
+    dictionaries::eth_evm::EthEvmPendingTxInfos,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::{prepend_debug_output_marker_to_string, strip_hex_prefix},
+};
+use common_debug_signers::validate_debug_command_signature;
+use ethereum_types::H256 as EthHash;
+use function_name::named;
+use serde_json::json;
+
+fn convert_hex_to_pending_tx_info_id(hex: &str) -> Result<EthHash> {
+    let bytes = hex::decode(strip_hex_prefix(hex))?;
+    if bytes.len() == EthHash::len_bytes() {
+        Ok(EthHash::from_slice(&bytes))
+    } else {
+        Err(format!("✘ Pending tx info id must be {} bytes!", EthHash::len_bytes()).into())
+    }
+}
+
+/// # Debug Release Pending Tx Info
+///
+/// This function releases the tx info with the passed in ID from the pending queue of tx infos
+/// which broke a token limit. The released tx info will be signed along with those from the next
+/// block submitted to the side of the core it originated from.
+#[named]
+pub fn debug_release_pending_tx_info<D: DatabaseInterface>(
+    db: &D,
+    id: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), id, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| EthEvmPendingTxInfos::release_and_update_in_db(db, &convert_hex_to_pending_tx_info_id(id)?))
+        .and_then(|released| {
+            db.end_transaction()?;
+            Ok(json!({"success": true, "released": released}).to_string())
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Get Pending Tx Infos
+///
+/// This function returns the queue of tx infos which have been held back from signing due to
+/// breaking one of their token's limits.
+pub fn get_pending_tx_infos<D: DatabaseInterface>(db: &D) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| EthEvmPendingTxInfos::get_from_db(db))
+        .and_then(|pending_tx_infos| {
+            db.end_transaction()?;
+            Ok(json!(pending_tx_infos).to_string())
+        })
+}
@@ -0,0 +1,354 @@
+use std::str::FromStr;
+
+use common::{
+    core_type::CoreType,
+    dictionaries::token_dictionary::{
+        DictionaryAddress,
+        TokenDictionary,
+        TokenDictionaryEntry,
+        TokenDictionaryFeesStorage,
+        TokenDictionaryLimitsStorage,
+        TokenDictionaryStorage,
+        TokenLimits,
+    },
+    traits::DatabaseInterface,
+    types::Result,
+    utils::prepend_debug_output_marker_to_string,
+};
+use common_debug_signers::validate_debug_command_signature;
+use common_fees::sanity_check_basis_points_value;
+use ethereum_types::U256;
+use function_name::named;
+use serde_json::json;
+
+fn parse_optional_amount(s: &str) -> Result<Option<U256>> {
+    let amount = U256::from_dec_str(s)?;
+    Ok(if amount.is_zero() { None } else { Some(amount) })
+}
+
+fn update_token_dictionary_in_db<D, N, H>(
+    db: &D,
+    f: impl FnOnce(TokenDictionary<N, H>) -> Result<TokenDictionary<N, H>>,
+) -> Result<()>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryStorage,
+{
+    TokenDictionary::<N, H>::get_from_db(db)
+        .and_then(f)
+        .and_then(|dictionary| dictionary.save_in_db(db))
+}
+
+/// # Debug Add Entry To Token Dictionary
+///
+/// This function adds the entry described by the passed in JSON to the token dictionary of any
+/// bridge, where the address types `N` & `H` are those of the bridge's native & host sides. Only
+/// the symbols and addresses are required in the entry JSON, eg:
+///
+/// ```no_compile
+/// {
+///     "native_symbol": "PNT",
+///     "host_symbol": "PNT",
+///     "native_address": "0x89ab32156e46f46d02ade3fecbe5fc4243b9aaed",
+///     "host_address": "0xdaacb0ab6fb34d24e8a67bfa14bf4d95d4c7af92",
+///     "native_decimals": 18,
+///     "host_decimals": 18
+/// }
+/// ```
+///
+/// #### NOTE: The function will fail if the bridge's dictionary format cannot store the entry.
+#[named]
+pub fn debug_add_entry_to_token_dictionary<D, N, H>(
+    db: &D,
+    entry_json: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryStorage,
+{
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), entry_json, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| TokenDictionaryEntry::<N, H>::from_str(entry_json))
+        .and_then(|entry| update_token_dictionary_in_db(db, |dictionary| dictionary.add(entry)))
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"add_entry_to_token_dictionary_success": true}).to_string())
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Remove Entry From Token Dictionary
+///
+/// This function removes the entry with the passed in address, from either side of the bridge,
+/// from the token dictionary of any bridge.
+#[named]
+pub fn debug_remove_entry_from_token_dictionary<D, N, H>(
+    db: &D,
+    address: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryStorage,
+{
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), address, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| {
+            update_token_dictionary_in_db::<D, N, H>(db, |dictionary| {
+                let (_, entry) = dictionary.get_entry_via_address_str(address)?;
+                Ok(dictionary.remove(&entry))
+            })
+        })
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"remove_entry_from_token_dictionary_success": true, "address": address}).to_string())
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Set Token Dictionary Fee Basis Points
+///
+/// This function sets the fee basis points for the side of the bridge on which the passed in
+/// address is found, in the token dictionary of any bridge whose dictionary format stores fees.
+///
+/// #### NOTE: Using a fee of 0 will mean no fees are taken.
+#[named]
+pub fn debug_set_token_dictionary_fee_basis_points<D, N, H>(
+    db: &D,
+    address: &str,
+    new_fee: u64,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryFeesStorage,
+{
+    db.start_transaction()
+        .and_then(|_| sanity_check_basis_points_value(new_fee))
+        .and_then(|_| get_debug_command_hash!(function_name!(), address, &new_fee, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| {
+            update_token_dictionary_in_db::<D, N, H>(db, |dictionary| dictionary.set_fee_basis_points(address, new_fee))
+        })
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"success": true, "address": address, "new_fee": new_fee}).to_string())
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Set Token Dictionary Accrued Fees
+///
+/// This function sets the accrued fees, in the native token's units, of the entry with the passed
+/// in address in the token dictionary of any bridge whose dictionary format stores fees.
+#[named]
+pub fn debug_set_token_dictionary_accrued_fees<D, N, H>(
+    db: &D,
+    address: &str,
+    fee_amount: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryFeesStorage,
+{
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), address, fee_amount, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| {
+            update_token_dictionary_in_db::<D, N, H>(db, |dictionary| {
+                dictionary.set_accrued_fees(address, U256::from_dec_str(fee_amount)?)
+            })
+        })
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"success": true, "address": address, "fee": fee_amount}).to_string())
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Set Token Dictionary Limits
+///
+/// This function sets the minimum peg amount, the maximum amount per tx, and the volume cap over a
+/// rolling window of `volume_cap_window` seconds for the entry with the passed in address in the
+/// token dictionary of any bridge whose dictionary format stores token limits. All amounts are in
+/// the native token's units.
+///
+/// #### NOTE: Passing 0 for any of the values removes that limit.
+#[named]
+#[allow(clippy::too_many_arguments)]
+pub fn debug_set_token_dictionary_limits<D, N, H>(
+    db: &D,
+    address: &str,
+    min_peg_amount: &str,
+    max_tx_amount: &str,
+    volume_cap: &str,
+    volume_cap_window: u64,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryLimitsStorage,
+{
+    db.start_transaction()
+        .and_then(|_| {
+            get_debug_command_hash!(
+                function_name!(),
+                address,
+                min_peg_amount,
+                max_tx_amount,
+                volume_cap,
+                &volume_cap_window,
+                core_type
+            )()
+        })
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| {
+            let limits = TokenLimits::new(
+                parse_optional_amount(min_peg_amount)?,
+                parse_optional_amount(max_tx_amount)?,
+                parse_optional_amount(volume_cap)?,
+                if volume_cap_window == 0 {
+                    None
+                } else {
+                    Some(volume_cap_window)
+                },
+            );
+            update_token_dictionary_in_db::<D, N, H>(db, |dictionary| dictionary.set_limits(address, &limits))
+        })
+        .and_then(|_| db.end_transaction())
+        .map(|_| {
+            json!({
+                "success": true,
+                "address": address,
+                "min_peg_amount": min_peg_amount,
+                "max_tx_amount": max_tx_amount,
+                "volume_cap": volume_cap,
+                "volume_cap_window": volume_cap_window,
+            })
+            .to_string()
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+fn set_token_dictionary_entry_enabled<D, N, H>(
+    db: &D,
+    address: &str,
+    enabled: bool,
+    core_type: &CoreType,
+    signature: &str,
+    hash: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryLimitsStorage,
+{
+    validate_debug_command_signature(db, core_type, signature, hash, cfg!(test))
+        .and_then(|_| {
+            update_token_dictionary_in_db::<D, N, H>(db, |dictionary| dictionary.set_enabled(address, enabled))
+        })
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"success": true, "address": address, "enabled": enabled}).to_string())
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Enable Token Dictionary Entry
+///
+/// This function re-enables a previously disabled entry in the token dictionary of any bridge
+/// whose dictionary format stores token limits.
+#[named]
+pub fn debug_enable_token_dictionary_entry<D, N, H>(
+    db: &D,
+    address: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryLimitsStorage,
+{
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), address, core_type)())
+        .and_then(|hash| set_token_dictionary_entry_enabled::<D, N, H>(db, address, true, core_type, signature, &hash))
+}
+
+/// # Debug Disable Token Dictionary Entry
+///
+/// This function disables an entry in the token dictionary of any bridge whose dictionary format
+/// stores token limits. Tx infos for a disabled token are held back from signing rather than
+/// processed.
+#[named]
+pub fn debug_disable_token_dictionary_entry<D, N, H>(
+    db: &D,
+    address: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryLimitsStorage,
+{
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), address, core_type)())
+        .and_then(|hash| set_token_dictionary_entry_enabled::<D, N, H>(db, address, false, core_type, signature, &hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+
+    const ENTRY_JSON: &str = r#"{"native_symbol":"TKN","host_symbol":"pTKN","native_address":"0x0000000000000000000000000000000000000001","host_address":"42","native_decimals":18,"host_decimals":10}"#;
+
+    #[test]
+    fn should_manage_token_dictionary_via_debug_functions() {
+        let db = get_test_database();
+        let core_type = CoreType::IntOnAlgo;
+        let address = "42";
+        debug_add_entry_to_token_dictionary::<_, EthAddress, u64>(&db, ENTRY_JSON, &core_type, "").unwrap();
+        let dictionary = TokenDictionary::<EthAddress, u64>::get_from_db(&db).unwrap();
+        assert_eq!(dictionary.len(), 1);
+        assert_eq!(dictionary[0].host_decimals, Some(10));
+        debug_remove_entry_from_token_dictionary::<_, EthAddress, u64>(&db, address, &core_type, "").unwrap();
+        let dictionary = TokenDictionary::<EthAddress, u64>::get_from_db(&db).unwrap();
+        assert!(dictionary.is_empty());
+    }
+
+    #[test]
+    fn should_set_limits_and_disable_token_via_debug_functions() {
+        let db = get_test_database();
+        let core_type = CoreType::IntOnEvm;
+        let entry_json = ENTRY_JSON.replace(r#""42""#, r#""0x0000000000000000000000000000000000000002""#);
+        let address = "0x0000000000000000000000000000000000000002";
+        debug_add_entry_to_token_dictionary::<_, EthAddress, EthAddress>(&db, &entry_json, &core_type, "").unwrap();
+        debug_set_token_dictionary_limits::<_, EthAddress, EthAddress>(&db, address, "10", "0", "0", 0, &core_type, "")
+            .unwrap();
+        debug_disable_token_dictionary_entry::<_, EthAddress, EthAddress>(&db, address, &core_type, "").unwrap();
+        let dictionary = TokenDictionary::<EthAddress, EthAddress>::get_from_db(&db).unwrap();
+        assert_eq!(dictionary[0].limits.min_peg_amount, Some(U256::from(10)));
+        assert!(!dictionary[0].enabled);
+        debug_enable_token_dictionary_entry::<_, EthAddress, EthAddress>(&db, address, &core_type, "").unwrap();
+        let dictionary = TokenDictionary::<EthAddress, EthAddress>::get_from_db(&db).unwrap();
+        assert!(dictionary[0].enabled);
+    }
+}
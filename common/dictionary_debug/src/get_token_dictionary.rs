@@ -0,0 +1,25 @@
+use common::{
+    dictionaries::token_dictionary::{DictionaryAddress, TokenDictionary, TokenDictionaryStorage},
+    traits::DatabaseInterface,
+    types::Result,
+};
+use serde_json::json;
+
+/// # Get Token Dictionary
+///
+/// This function returns the token dictionary of any bridge, in the JSON format shared by all of
+/// them, where the address types `N` & `H` are those of the bridge's native & host sides.
+pub fn get_token_dictionary<D, N, H>(db: &D) -> Result<String>
+where
+    D: DatabaseInterface,
+    N: DictionaryAddress,
+    H: DictionaryAddress,
+    TokenDictionary<N, H>: TokenDictionaryStorage,
+{
+    db.start_transaction()
+        .and_then(|_| TokenDictionary::<N, H>::get_from_db(db))
+        .and_then(|dictionary| {
+            db.end_transaction()?;
+            Ok(json!(dictionary.to_json()).to_string())
+        })
+}
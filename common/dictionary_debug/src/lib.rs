@@ -0,0 +1,26 @@
+mod debug_fee_distribution;
+mod debug_fee_schedules;
+mod debug_pending_tx_infos;
+mod debug_token_dictionary;
+mod get_token_dictionary;
+
+pub use self::{
+    debug_fee_distribution::{debug_remove_fee_distribution_policy, debug_set_fee_distribution_policy},
+    debug_fee_schedules::{debug_remove_fee_schedule, debug_set_fee_schedule, get_fee_schedules},
+    debug_pending_tx_infos::{debug_release_pending_tx_info, get_pending_tx_infos},
+    debug_token_dictionary::{
+        debug_add_entry_to_token_dictionary,
+        debug_disable_token_dictionary_entry,
+        debug_enable_token_dictionary_entry,
+        debug_remove_entry_from_token_dictionary,
+        debug_set_token_dictionary_accrued_fees,
+        debug_set_token_dictionary_fee_basis_points,
+        debug_set_token_dictionary_limits,
+    },
+    get_token_dictionary::get_token_dictionary,
+};
+
+#[macro_use]
+extern crate common;
+#[macro_use]
+extern crate log;
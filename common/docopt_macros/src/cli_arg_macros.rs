@@ -17,6 +17,23 @@ macro_rules! make_cli_args_struct {
             pub cmd_debugAddDebugSigners: bool,
             pub cmd_debugRemoveDebugSigner: bool,
             pub cmd_debugSetKeyInDbToValue: bool,
+            // NOTE: Defaulted since not all cores offer the JSON-RPC server & simulation modes.
+            #[serde(default)]
+            pub cmd_serve: bool,
+            #[serde(default)]
+            pub flag_port: u16,
+            #[serde(default)]
+            pub flag_socket: String,
+            #[serde(default)]
+            pub cmd_simulate: bool,
+            #[serde(default)]
+            pub arg_stepsDir: String,
+            #[serde(default)]
+            pub flag_fresh: bool,
+            #[serde(default)]
+            pub flag_persist: bool,
+            #[serde(default)]
+            pub flag_report: String,
             $( pub $name: $type,)*
         }
 
@@ -28,6 +45,13 @@ macro_rules! make_cli_args_struct {
                     .and_then(|cli_args| cli_args.maybe_update_block_json())
             }
 
+            // NOTE: Used when serving JSON-RPC requests, whose methods & params are converted into
+            // the argv the CLI would have received.
+            pub fn parse_from_argv(usage_info: &str, argv: Vec<String>) -> $crate::common::Result<Self> {
+                Ok($crate::docopt::Docopt::new(usage_info).and_then(|d| d.argv(argv).deserialize())?)
+                    .and_then(|cli_args: Self| cli_args.maybe_update_block_json())
+            }
+
             fn parse_from_usage_info(usage_info: &str) -> $crate::common::Result<Self> {
                 Ok($crate::docopt::Docopt::new(usage_info).and_then(|d| d.deserialize())?)
             }
@@ -79,7 +79,6 @@ impl EosPrivateKey {
         })
     }
 
-    #[cfg(not(feature = "ltc"))]
     pub fn from_wallet_import_format(wallet_import_formatted_key: &str) -> Result<EosPrivateKey> {
         let data = base58::from_check(wallet_import_formatted_key)?;
         let compressed = match data.len() {
@@ -99,26 +98,6 @@ impl EosPrivateKey {
         })
     }
 
-    #[cfg(feature = "ltc")]
-    pub fn from_wallet_import_format(wallet_import_formatted_key: &str) -> Result<EosPrivateKey> {
-        let data = base58::from_check(wallet_import_formatted_key)?;
-        let compressed = match data.len() {
-            33 => false,
-            34 => true,
-            _ => return Err(AppError::LitecoinBase58Error(base58::Error::InvalidLength(data.len()))),
-        };
-        let network = match data[0] {
-            128 => EosNetwork::Mainnet,
-            239 => EosNetwork::Testnet,
-            x => return Err(AppError::LitecoinBase58Error(base58::Error::InvalidAddressVersion(x))),
-        };
-        Ok(EosPrivateKey {
-            compressed,
-            network,
-            private_key: SecretKey::from_slice(&data[1..33])?,
-        })
-    }
-
     pub fn sign_hash(&self, hash: &[u8]) -> Result<EosSignature> {
         let msg = match Message::from_slice(hash) {
             Ok(msg) => msg,
@@ -65,7 +65,6 @@ impl EosPublicKey {
         format!("EOS{}", base58::encode_slice(&public_key))
     }
 
-    #[cfg(not(feature = "ltc"))]
     pub fn from_bytes(data: &[Byte]) -> Result<EosPublicKey> {
         let compressed: bool = match data.len() {
             33 => true,
@@ -78,19 +77,6 @@ impl EosPublicKey {
         })
     }
 
-    #[cfg(feature = "ltc")]
-    pub fn from_bytes(data: &[Byte]) -> Result<EosPublicKey> {
-        let compressed: bool = match data.len() {
-            33 => true,
-            64 => false,
-            len => return Err(AppError::LitecoinBase58Error(base58::Error::InvalidLength(len))),
-        };
-        Ok(EosPublicKey {
-            compressed,
-            public_key: PublicKey::from_slice(data)?,
-        })
-    }
-
     pub fn recover_from_digest(
         digest: &secp256k1::Message,
         recoverable_signature: &EosSignature,
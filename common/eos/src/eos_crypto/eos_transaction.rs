@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use common::{
+    crypto_utils::sha256_hash_bytes,
     traits::Serdable,
     types::{Byte, Bytes, Result},
 };
@@ -56,6 +57,21 @@ impl EosSignedTransaction {
             hex::encode(&unsigned_tx.to_serialize_data()?[..]),
         ))
     }
+
+    /// Gets the ID of the transaction, which is the sha256 hash of its serialized bytes.
+    pub fn get_tx_id(&self) -> Result<String> {
+        Ok(hex::encode(sha256_hash_bytes(&hex::decode(&self.transaction)?)))
+    }
+
+    /// Gets the expiration timestamp of the transaction, which is the first field of its serialized header.
+    pub fn get_expiration(&self) -> Result<u32> {
+        let bytes = hex::decode(&self.transaction)?;
+        if bytes.len() < 4 {
+            Err("Cannot get expiration from EOS tx - too few bytes!".into())
+        } else {
+            Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        }
+    }
 }
 
 fn get_eos_ptoken_mint_action_without_metadata(
@@ -253,4 +269,28 @@ mod tests {
         let result_without_timestamp = &result[8..];
         assert_eq!(result_without_timestamp, expected_result);
     }
+
+    #[test]
+    fn should_get_expiration_and_tx_id_from_signed_eos_tx() {
+        let pk = EosPrivateKey::from_slice(
+            &hex::decode("0bc331469a2c834b26ff3af7a72e3faab3ee806c368e7a8008f57904237c6057").unwrap(),
+        )
+        .unwrap();
+        let timestamp = 1337;
+        let tx = get_signed_eos_ptoken_issue_tx(
+            44391,
+            1355491504,
+            "provtestable",
+            "1.00000042 PFFF",
+            &EOS_JUNGLE_CHAIN_ID,
+            &pk,
+            "ptokensbtc1a",
+            timestamp,
+            None,
+        )
+        .unwrap();
+        assert_eq!(tx.get_expiration().unwrap(), timestamp);
+        let expected_tx_id = hex::encode(sha256_hash_bytes(&hex::decode(&tx.transaction).unwrap()));
+        assert_eq!(tx.get_tx_id().unwrap(), expected_tx_id);
+    }
 }
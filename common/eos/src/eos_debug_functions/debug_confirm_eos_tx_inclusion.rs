@@ -0,0 +1,43 @@
+use common::{
+    core_type::CoreType,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::prepend_debug_output_marker_to_string,
+};
+use common_debug_signers::validate_debug_command_signature;
+use function_name::named;
+use serde_json::json;
+
+use crate::eos_tx_records::EosTxRecords;
+
+/// # Debug Confirm EOS Tx Inclusion
+///
+/// This function marks the EOS tx with the passed in EOS account nonce as having been included in
+/// the EOS chain. The passed in tx ID must match the ID of the latest signed version of that tx.
+/// Once confirmed, a tx can no longer be re-signed via the `debug_resign_eos_tx` function.
+///
+/// ### NOTE:
+///
+/// Txs whose actions appear in a submitted EOS block's action proofs are confirmed automatically.
+#[named]
+pub fn debug_confirm_eos_tx_inclusion<D: DatabaseInterface>(
+    db: &D,
+    nonce: u64,
+    eos_tx_id: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    info!("✔ Debug confirming inclusion of EOS tx with nonce {nonce}...");
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), &nonce, eos_tx_id, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| EosTxRecords::get_from_db(db))
+        .and_then(|records| records.confirm_tx_inclusion(nonce, eos_tx_id, None))
+        .and_then(|records| records.save_in_db(db))
+        .and_then(|_| db.end_transaction())
+        .and(Ok(
+            json!({"confirmed": true, "nonce": nonce, "eos_tx_id": eos_tx_id}).to_string()
+        ))
+        .map(prepend_debug_output_marker_to_string)
+}
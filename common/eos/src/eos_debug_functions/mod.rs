@@ -1,6 +1,7 @@
 mod debug_add_global_sequences_to_processed_list;
 mod debug_add_new_eos_schedule;
 mod debug_add_token_dictionary_entry;
+mod debug_confirm_eos_tx_inclusion;
 mod debug_disable_protocol_feature;
 mod debug_enable_protocol_feature;
 mod debug_remove_global_sequences_from_processed_list;
@@ -12,6 +13,7 @@ pub use self::{
     debug_add_global_sequences_to_processed_list::debug_add_global_sequences_to_processed_list,
     debug_add_new_eos_schedule::debug_add_new_eos_schedule,
     debug_add_token_dictionary_entry::debug_add_token_dictionary_entry,
+    debug_confirm_eos_tx_inclusion::debug_confirm_eos_tx_inclusion,
     debug_disable_protocol_feature::debug_disable_eos_protocol_feature,
     debug_enable_protocol_feature::debug_enable_eos_protocol_feature,
     debug_remove_global_sequences_from_processed_list::debug_remove_global_sequences_from_processed_list,
@@ -0,0 +1,310 @@
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    traits::DatabaseInterface,
+    types::{Byte, Bytes, Result},
+    utils::get_prefixed_db_key,
+};
+use derive_more::{Constructor, Deref};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::{EosDbUtils, EosSignedTransaction, EosSignedTransactions, EosState, GlobalSequence, Incremerkles};
+
+lazy_static! {
+    pub static ref EOS_TX_RECORDS_KEY: [Byte; 32] = get_prefixed_db_key("eos_tx_records_key");
+}
+
+/// A record of a signed EOS tx, holding its ID, its expiration & the reference block it was signed
+/// with, along with the tx info required to re-sign it should it expire before being included.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EosTxRecord {
+    pub nonce: u64,
+    pub eos_tx_id: String,
+    pub expiration: u32,
+    pub ref_block_num: u16,
+    pub ref_block_prefix: u32,
+    pub confirmed: bool,
+    pub resign_count: u64,
+    pub global_sequence: Option<GlobalSequence>,
+    tx_info: JsonValue,
+}
+
+impl EosTxRecord {
+    pub fn new<T: Serialize>(
+        nonce: u64,
+        tx_info: &T,
+        eos_tx: &EosSignedTransaction,
+        ref_block_num: u16,
+        ref_block_prefix: u32,
+    ) -> Result<Self> {
+        Ok(Self {
+            nonce,
+            ref_block_num,
+            ref_block_prefix,
+            resign_count: 0,
+            confirmed: false,
+            global_sequence: None,
+            eos_tx_id: eos_tx.get_tx_id()?,
+            expiration: eos_tx.get_expiration()?,
+            tx_info: serde_json::to_value(tx_info)?,
+        })
+    }
+
+    pub fn to_resigned(
+        &self,
+        eos_tx: &EosSignedTransaction,
+        ref_block_num: u16,
+        ref_block_prefix: u32,
+    ) -> Result<Self> {
+        Ok(Self {
+            resign_count: self.resign_count + 1,
+            ..Self::new(self.nonce, &self.tx_info, eos_tx, ref_block_num, ref_block_prefix)?
+        })
+    }
+
+    pub fn get_tx_info<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_value(self.tx_info.clone())?)
+    }
+
+    pub fn has_expired(&self, timestamp: u32) -> bool {
+        timestamp > self.expiration
+    }
+
+    /// A tx may only be re-signed once it has expired, & only if no action from it has been seen in
+    /// a submitted EOS block, nor its inclusion otherwise confirmed.
+    pub fn check_can_be_resigned(&self, timestamp: u32) -> Result<()> {
+        if let Some(global_sequence) = self.global_sequence {
+            Err(format!(
+                "Cannot re-sign EOS tx with nonce {} ∵ an action with global sequence {} from it was included!",
+                self.nonce, global_sequence
+            )
+            .into())
+        } else if self.confirmed {
+            Err(format!(
+                "Cannot re-sign EOS tx with nonce {} ∵ its inclusion was confirmed!",
+                self.nonce
+            )
+            .into())
+        } else if !self.has_expired(timestamp) {
+            Err(format!(
+                "Cannot re-sign EOS tx with nonce {} ∵ it's valid until {} & it's now {}!",
+                self.nonce, self.expiration, timestamp
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Deref, Constructor)]
+pub struct EosTxRecords(Vec<EosTxRecord>);
+
+impl EosTxRecords {
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(&self.0)?)
+    }
+
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(Self(serde_json::from_slice(bytes)?))
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        match db.get(EOS_TX_RECORDS_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => {
+                debug!("✘ No `EosTxRecords` in db ∴ using empty ones!");
+                Ok(Self::default())
+            },
+        }
+    }
+
+    pub(crate) fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        db.put(
+            EOS_TX_RECORDS_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+
+    pub fn get_record(&self, nonce: u64) -> Result<EosTxRecord> {
+        self.iter()
+            .find(|record| record.nonce == nonce)
+            .cloned()
+            .ok_or_else(|| format!("No EOS tx record found with nonce {}!", nonce).into())
+    }
+
+    /// Adds the passed in records, replacing any existing records with the same nonce, which can
+    /// happen if the core's EOS account nonce is rolled back via a debug function.
+    pub fn add_records_and_save_in_db<D: DatabaseInterface>(db: &D, records: &[EosTxRecord]) -> Result<()> {
+        info!("✔ Adding {} EOS tx record(s) to db...", records.len());
+        let mut existing = Self::get_from_db(db)?
+            .iter()
+            .filter(|existing| !records.iter().any(|record| record.nonce == existing.nonce))
+            .cloned()
+            .collect::<Vec<_>>();
+        existing.extend_from_slice(records);
+        Self::new(existing).save_in_db(db)
+    }
+
+    /// Creates & saves a record for each of the passed in signed EOS txs, the first of which is
+    /// assumed to have been signed with the passed in EOS account nonce.
+    pub fn add_signed_txs_and_save_in_db<D: DatabaseInterface, T: Serialize>(
+        db: &D,
+        start_nonce: u64,
+        eos_txs: &EosSignedTransactions,
+        tx_infos: &[T],
+        ref_block_num: u16,
+        ref_block_prefix: u32,
+    ) -> Result<()> {
+        if tx_infos.len() != eos_txs.len() {
+            return Err("Number of EOS txs does not match number of tx infos!".into());
+        };
+        let records = eos_txs
+            .iter()
+            .zip(tx_infos.iter())
+            .enumerate()
+            .map(|(i, (eos_tx, tx_info))| {
+                EosTxRecord::new(start_nonce + i as u64, tx_info, eos_tx, ref_block_num, ref_block_prefix)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::add_records_and_save_in_db(db, &records)
+    }
+
+    pub(crate) fn confirm_tx_inclusion(
+        self,
+        nonce: u64,
+        eos_tx_id: &str,
+        global_sequence: Option<GlobalSequence>,
+    ) -> Result<Self> {
+        let eos_tx_id = eos_tx_id.trim_start_matches("0x").to_lowercase();
+        let record = self.get_record(nonce)?;
+        if record.eos_tx_id != eos_tx_id {
+            Err(format!(
+                "EOS tx ID '{}' does not match that of the EOS tx with nonce {}: '{}'!",
+                eos_tx_id, nonce, record.eos_tx_id
+            )
+            .into())
+        } else {
+            Ok(Self::new(
+                self.iter()
+                    .cloned()
+                    .map(|record| {
+                        if record.nonce == nonce {
+                            EosTxRecord {
+                                confirmed: true,
+                                global_sequence: global_sequence.or(record.global_sequence),
+                                ..record
+                            }
+                        } else {
+                            record
+                        }
+                    })
+                    .collect(),
+            ))
+        }
+    }
+
+    fn get_unconfirmed_nonce_for_tx_id(&self, eos_tx_id: &str) -> Option<u64> {
+        self.iter()
+            .find(|record| !record.confirmed && record.eos_tx_id == eos_tx_id)
+            .map(|record| record.nonce)
+    }
+}
+
+/// Gets the reference block with which to sign EOS txs from the latest block the core's EOS light
+/// client has validated, rather than trusting one supplied by the caller.
+pub fn get_eos_ref_block_from_db<D: DatabaseInterface>(db_utils: &EosDbUtils<D>) -> Result<(u16, u32)> {
+    let incremerkles = Incremerkles::get_from_db(db_utils)?;
+    let block_id = incremerkles.latest_block_id()?.to_bytes();
+    let ref_block_num = (incremerkles.latest_block_num() & 0xffff) as u16;
+    let ref_block_prefix = u32::from_le_bytes([block_id[8], block_id[9], block_id[10], block_id[11]]);
+    Ok((ref_block_num, ref_block_prefix))
+}
+
+/// Marks as confirmed any EOS tx records whose tx ID matches that of a validated action proof in
+/// state, noting the global sequence of that action, so that those txs can no longer be re-signed.
+pub fn maybe_confirm_eos_tx_records_and_return_state<D: DatabaseInterface>(state: EosState<D>) -> Result<EosState<D>> {
+    let mut records = EosTxRecords::get_from_db(state.db)?;
+    let mut num_confirmed = 0;
+    for proof in state.action_proofs.iter() {
+        if let Some(nonce) = records.get_unconfirmed_nonce_for_tx_id(&proof.tx_id.to_string()) {
+            info!("✔ Confirming inclusion of EOS tx with nonce {nonce}...");
+            records = records.confirm_tx_inclusion(
+                nonce,
+                &proof.tx_id.to_string(),
+                Some(proof.action_receipt.global_sequence),
+            )?;
+            num_confirmed += 1;
+        };
+    }
+    if num_confirmed == 0 {
+        info!("✔ No EOS tx records to confirm!");
+        Ok(state)
+    } else {
+        records.save_in_db(state.db).and(Ok(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+
+    fn get_sample_record(nonce: u64, expiration: u32) -> EosTxRecord {
+        EosTxRecord {
+            nonce,
+            expiration,
+            resign_count: 0,
+            confirmed: false,
+            ref_block_num: 1337,
+            ref_block_prefix: 1338,
+            global_sequence: None,
+            eos_tx_id: format!("{:064x}", nonce),
+            tx_info: JsonValue::Null,
+        }
+    }
+
+    #[test]
+    fn should_add_records_replacing_those_with_same_nonce() {
+        let db = get_test_database();
+        let record_1 = get_sample_record(0, 1000);
+        let record_2 = get_sample_record(1, 1000);
+        let record_3 = get_sample_record(1, 2000);
+        EosTxRecords::add_records_and_save_in_db(&db, &[record_1.clone(), record_2]).unwrap();
+        EosTxRecords::add_records_and_save_in_db(&db, &[record_3.clone()]).unwrap();
+        let result = EosTxRecords::get_from_db(&db).unwrap();
+        assert_eq!(*result, vec![record_1, record_3.clone()]);
+        assert_eq!(result.get_record(1).unwrap(), record_3);
+        assert!(result.get_record(2).is_err());
+    }
+
+    #[test]
+    fn should_only_be_resignable_once_expired_and_unconfirmed() {
+        let record = get_sample_record(0, 1000);
+        assert!(record.check_can_be_resigned(1000).is_err());
+        assert!(record.check_can_be_resigned(1001).is_ok());
+        let confirmed = EosTxRecord {
+            confirmed: true,
+            ..record.clone()
+        };
+        assert!(confirmed.check_can_be_resigned(1001).is_err());
+        let included = EosTxRecord {
+            global_sequence: Some(1337),
+            ..record
+        };
+        assert!(included.check_can_be_resigned(1001).is_err());
+    }
+
+    #[test]
+    fn should_only_confirm_tx_inclusion_with_matching_tx_id() {
+        let records = EosTxRecords::new(vec![get_sample_record(0, 1000), get_sample_record(1, 1000)]);
+        let tx_id = format!("0x{:064x}", 1);
+        assert!(records.clone().confirm_tx_inclusion(0, &tx_id, None).is_err());
+        let result = records.confirm_tx_inclusion(1, &tx_id, Some(1337)).unwrap();
+        assert!(!result.get_record(0).unwrap().confirmed);
+        assert!(result.get_record(1).unwrap().confirmed);
+        assert_eq!(result.get_record(1).unwrap().global_sequence, Some(1337));
+    }
+}
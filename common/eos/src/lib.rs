@@ -1,8 +1,4 @@
-// NOTE: This means we can (relatively) easily make this LTC based instead of BTC.
-#[cfg(not(feature = "ltc"))]
 use bitcoin as bitcoin_crate_alias;
-#[cfg(feature = "ltc")]
-use litecoin as bitcoin_crate_alias;
 
 mod add_schedule;
 mod core_initialization;
@@ -28,6 +24,7 @@ mod eos_producer_schedule;
 mod eos_state;
 mod eos_submission_material;
 pub mod eos_test_utils;
+mod eos_tx_records;
 mod eos_types;
 mod eos_unit_conversions;
 mod eos_utils;
@@ -69,6 +66,7 @@ pub use self::{
         debug_add_global_sequences_to_processed_list,
         debug_add_new_eos_schedule,
         debug_add_token_dictionary_entry,
+        debug_confirm_eos_tx_inclusion,
         debug_disable_eos_protocol_feature,
         debug_enable_eos_protocol_feature,
         debug_remove_global_sequences_from_processed_list,
@@ -91,6 +89,12 @@ pub use self::{
         EosSubmissionMaterial,
         EosSubmissionMaterialJson,
     },
+    eos_tx_records::{
+        get_eos_ref_block_from_db,
+        maybe_confirm_eos_tx_records_and_return_state,
+        EosTxRecord,
+        EosTxRecords,
+    },
     eos_unit_conversions::convert_eos_asset_to_u64,
     eos_utils::{
         convert_hex_to_checksum256,
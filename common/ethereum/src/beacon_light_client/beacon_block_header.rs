@@ -0,0 +1,62 @@
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    beacon_serde::u64_as_string,
+    ssz::{hash_to_chunk, merkleize, u64_to_chunk},
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeaconBlockHeader {
+    #[serde(with = "u64_as_string")]
+    pub slot: u64,
+    #[serde(with = "u64_as_string")]
+    pub proposer_index: u64,
+    pub parent_root: EthHash,
+    pub state_root: EthHash,
+    pub body_root: EthHash,
+}
+
+impl BeaconBlockHeader {
+    pub fn hash_tree_root(&self) -> EthHash {
+        EthHash::from(merkleize(
+            &[
+                u64_to_chunk(self.slot),
+                u64_to_chunk(self.proposer_index),
+                hash_to_chunk(&self.parent_root),
+                hash_to_chunk(&self.state_root),
+                hash_to_chunk(&self.body_root),
+            ],
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serde_beacon_block_header_from_beacon_api_json() {
+        let json = r#"{
+            "slot": "7403488",
+            "proposer_index": "1144",
+            "parent_root": "0x0101010101010101010101010101010101010101010101010101010101010101",
+            "state_root": "0x0202020202020202020202020202020202020202020202020202020202020202",
+            "body_root": "0x0303030303030303030303030303030303030303030303030303030303030303"
+        }"#;
+        let header: BeaconBlockHeader = serde_json::from_str(json).unwrap();
+        assert_eq!(header.slot, 7_403_488);
+        assert_eq!(header.proposer_index, 1144);
+        let result: BeaconBlockHeader = serde_json::from_str(&serde_json::to_string(&header).unwrap()).unwrap();
+        assert_eq!(result, header);
+    }
+
+    #[test]
+    fn hash_tree_root_should_change_with_slot() {
+        let header = BeaconBlockHeader::default();
+        let mut other_header = header.clone();
+        other_header.slot = 1;
+        assert_ne!(header.hash_tree_root(), other_header.hash_tree_root());
+    }
+}
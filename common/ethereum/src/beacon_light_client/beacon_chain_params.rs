@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use common_chain_ids::EthChainId;
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{ssz::hash_pair, LightClientError};
+
+pub(super) type ForkVersion = [u8; 4];
+
+pub(super) const SLOTS_PER_EPOCH: u64 = 32;
+pub(super) const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+pub(super) const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BeaconFork {
+    Phase0,
+    Altair,
+    Bellatrix,
+    Capella,
+    Deneb,
+    Electra,
+    Fulu,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeaconChainParams {
+    pub genesis_validators_root: EthHash,
+    pub genesis_fork_version: ForkVersion,
+    pub forks: Vec<(BeaconFork, ForkVersion, u64)>,
+}
+
+impl BeaconChainParams {
+    pub fn from_eth_chain_id(eth_chain_id: &EthChainId) -> Result<Self, LightClientError> {
+        match eth_chain_id {
+            EthChainId::Mainnet => Ok(Self {
+                genesis_validators_root: EthHash::from_str(
+                    "4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95",
+                )
+                .expect("✘ Invalid mainnet genesis validators root!"),
+                genesis_fork_version: [0x00, 0x00, 0x00, 0x00],
+                forks: vec![
+                    (BeaconFork::Altair, [0x01, 0x00, 0x00, 0x00], 74_240),
+                    (BeaconFork::Bellatrix, [0x02, 0x00, 0x00, 0x00], 144_896),
+                    (BeaconFork::Capella, [0x03, 0x00, 0x00, 0x00], 194_048),
+                    (BeaconFork::Deneb, [0x04, 0x00, 0x00, 0x00], 269_568),
+                    (BeaconFork::Electra, [0x05, 0x00, 0x00, 0x00], 364_032),
+                    (BeaconFork::Fulu, [0x06, 0x00, 0x00, 0x00], 411_392),
+                ],
+            }),
+            EthChainId::Sepolia => Ok(Self {
+                genesis_validators_root: EthHash::from_str(
+                    "d8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b8078",
+                )
+                .expect("✘ Invalid sepolia genesis validators root!"),
+                genesis_fork_version: [0x90, 0x00, 0x00, 0x69],
+                forks: vec![
+                    (BeaconFork::Altair, [0x90, 0x00, 0x00, 0x70], 50),
+                    (BeaconFork::Bellatrix, [0x90, 0x00, 0x00, 0x71], 100),
+                    (BeaconFork::Capella, [0x90, 0x00, 0x00, 0x72], 56_832),
+                    (BeaconFork::Deneb, [0x90, 0x00, 0x00, 0x73], 132_608),
+                    (BeaconFork::Electra, [0x90, 0x00, 0x00, 0x74], 222_464),
+                    (BeaconFork::Fulu, [0x90, 0x00, 0x00, 0x75], 272_640),
+                ],
+            }),
+            _ => Err(LightClientError::NoBeaconChainParams(eth_chain_id.to_string())),
+        }
+    }
+
+    pub fn compute_epoch_at_slot(slot: u64) -> u64 {
+        slot / SLOTS_PER_EPOCH
+    }
+
+    pub fn compute_sync_committee_period_at_slot(slot: u64) -> u64 {
+        Self::compute_epoch_at_slot(slot) / EPOCHS_PER_SYNC_COMMITTEE_PERIOD
+    }
+
+    pub fn get_fork_at_epoch(&self, epoch: u64) -> BeaconFork {
+        self.forks
+            .iter()
+            .rev()
+            .find(|(_, _, activation_epoch)| epoch >= *activation_epoch)
+            .map(|(fork, ..)| *fork)
+            .unwrap_or(BeaconFork::Phase0)
+    }
+
+    pub fn get_fork_at_slot(&self, slot: u64) -> BeaconFork {
+        self.get_fork_at_epoch(Self::compute_epoch_at_slot(slot))
+    }
+
+    fn get_fork_version_at_epoch(&self, epoch: u64) -> ForkVersion {
+        self.forks
+            .iter()
+            .rev()
+            .find(|(_, _, activation_epoch)| epoch >= *activation_epoch)
+            .map(|(_, version, _)| *version)
+            .unwrap_or(self.genesis_fork_version)
+    }
+
+    fn compute_fork_data_root(&self, fork_version: &ForkVersion) -> [u8; 32] {
+        let mut version_chunk = [0u8; 32];
+        version_chunk[..4].copy_from_slice(fork_version);
+        hash_pair(&version_chunk, &self.genesis_validators_root.to_fixed_bytes())
+    }
+
+    /// Computes the sync committee signing domain in effect for an update signed at the passed in
+    /// slot. NOTE: Per the spec, the fork version is that of the slot _before_ the signature slot.
+    pub fn compute_sync_committee_domain(&self, signature_slot: u64) -> [u8; 32] {
+        let epoch = Self::compute_epoch_at_slot(signature_slot.saturating_sub(1));
+        let fork_data_root = self.compute_fork_data_root(&self.get_fork_version_at_epoch(epoch));
+        let mut domain = [0u8; 32];
+        domain[..4].copy_from_slice(&DOMAIN_SYNC_COMMITTEE);
+        domain[4..].copy_from_slice(&fork_data_root[..28]);
+        domain
+    }
+
+    pub fn compute_signing_root(object_root: &[u8; 32], domain: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(object_root);
+        hasher.update(domain);
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_beacon_chain_params_for_mainnet_and_sepolia() {
+        assert!(BeaconChainParams::from_eth_chain_id(&EthChainId::Mainnet).is_ok());
+        assert!(BeaconChainParams::from_eth_chain_id(&EthChainId::Sepolia).is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_get_beacon_chain_params_for_chain_without_beacon_chain() {
+        let expected_error = LightClientError::NoBeaconChainParams(EthChainId::BscMainnet.to_string());
+        match BeaconChainParams::from_eth_chain_id(&EthChainId::BscMainnet) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_get_fork_at_slot() {
+        let params = BeaconChainParams::from_eth_chain_id(&EthChainId::Mainnet).unwrap();
+        assert_eq!(params.get_fork_at_slot(0), BeaconFork::Phase0);
+        assert_eq!(params.get_fork_at_slot(194_048 * SLOTS_PER_EPOCH), BeaconFork::Capella);
+        assert_eq!(
+            params.get_fork_at_slot(269_568 * SLOTS_PER_EPOCH - 1),
+            BeaconFork::Capella
+        );
+        assert_eq!(params.get_fork_at_slot(269_568 * SLOTS_PER_EPOCH), BeaconFork::Deneb);
+    }
+
+    #[test]
+    fn should_compute_sync_committee_period_at_slot() {
+        assert_eq!(BeaconChainParams::compute_sync_committee_period_at_slot(8191), 0);
+        assert_eq!(BeaconChainParams::compute_sync_committee_period_at_slot(8192), 1);
+    }
+
+    #[test]
+    fn sync_committee_domain_should_use_fork_version_of_previous_slot() {
+        let params = BeaconChainParams::from_eth_chain_id(&EthChainId::Mainnet).unwrap();
+        let deneb_slot = 269_568 * SLOTS_PER_EPOCH;
+        let domain_at_fork_boundary = params.compute_sync_committee_domain(deneb_slot);
+        let domain_before_fork_boundary = params.compute_sync_committee_domain(deneb_slot - 1);
+        let domain_after_fork_boundary = params.compute_sync_committee_domain(deneb_slot + 1);
+        assert_eq!(domain_at_fork_boundary, domain_before_fork_boundary);
+        assert_ne!(domain_at_fork_boundary, domain_after_fork_boundary);
+        assert_eq!(domain_at_fork_boundary[..4], DOMAIN_SYNC_COMMITTEE);
+    }
+}
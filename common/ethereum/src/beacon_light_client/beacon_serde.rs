@@ -0,0 +1,59 @@
+// NOTE: The beacon API encodes integers as decimal strings and byte arrays as `0x` prefixed hex,
+// so these are used via `#[serde(with = "...")]` on the light client types.
+
+pub(super) mod u64_as_string {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(u_64: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&u_64.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse::<u64>()
+            .map_err(D::Error::custom)
+    }
+}
+
+pub(super) mod prefixed_hex_bytes {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(D::Error::custom)
+    }
+}
+
+pub(super) mod u256_as_dec_string {
+    use ethereum_types::U256;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(u_256: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&u_256.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        U256::from_dec_str(&String::deserialize(deserializer)?).map_err(|e| D::Error::custom(format!("{e:?}")))
+    }
+}
+
+pub(super) mod option_u64_as_string {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(u_64: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match u_64 {
+            Some(u) => serializer.serialize_str(&u.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| s.parse::<u64>().map_err(D::Error::custom))
+            .transpose()
+    }
+}
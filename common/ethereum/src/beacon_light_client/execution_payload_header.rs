@@ -0,0 +1,152 @@
+use ethereum_types::{Address as EthAddress, Bloom, H256 as EthHash, U256};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    beacon_serde::{option_u64_as_string, prefixed_hex_bytes, u256_as_dec_string, u64_as_string},
+    ssz::{
+        hash_to_chunk,
+        hash_tree_root_of_byte_list,
+        hash_tree_root_of_byte_vector,
+        merkleize,
+        u256_to_chunk,
+        u64_to_chunk,
+        Chunk,
+    },
+};
+use crate::EthBlock;
+
+const MAX_EXTRA_DATA_BYTES: usize = 32;
+
+/// The execution payload header as committed to by a post-capella beacon block body. The two blob
+/// fields arrive with deneb, and their presence is what decides which container shape gets hashed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionPayloadHeader {
+    pub parent_hash: EthHash,
+    pub fee_recipient: EthAddress,
+    pub state_root: EthHash,
+    pub receipts_root: EthHash,
+    pub logs_bloom: Bloom,
+    pub prev_randao: EthHash,
+    #[serde(with = "u64_as_string")]
+    pub block_number: u64,
+    #[serde(with = "u64_as_string")]
+    pub gas_limit: u64,
+    #[serde(with = "u64_as_string")]
+    pub gas_used: u64,
+    #[serde(with = "u64_as_string")]
+    pub timestamp: u64,
+    #[serde(with = "prefixed_hex_bytes")]
+    pub extra_data: Vec<u8>,
+    #[serde(with = "u256_as_dec_string")]
+    pub base_fee_per_gas: U256,
+    pub block_hash: EthHash,
+    pub transactions_root: EthHash,
+    pub withdrawals_root: EthHash,
+    #[serde(default, with = "option_u64_as_string", skip_serializing_if = "Option::is_none")]
+    pub blob_gas_used: Option<u64>,
+    #[serde(default, with = "option_u64_as_string", skip_serializing_if = "Option::is_none")]
+    pub excess_blob_gas: Option<u64>,
+}
+
+impl ExecutionPayloadHeader {
+    pub fn hash_tree_root(&self) -> EthHash {
+        let mut chunks: Vec<Chunk> = vec![
+            hash_to_chunk(&self.parent_hash),
+            hash_tree_root_of_byte_vector(self.fee_recipient.as_bytes()),
+            hash_to_chunk(&self.state_root),
+            hash_to_chunk(&self.receipts_root),
+            hash_tree_root_of_byte_vector(self.logs_bloom.as_bytes()),
+            hash_to_chunk(&self.prev_randao),
+            u64_to_chunk(self.block_number),
+            u64_to_chunk(self.gas_limit),
+            u64_to_chunk(self.gas_used),
+            u64_to_chunk(self.timestamp),
+            hash_tree_root_of_byte_list(&self.extra_data, MAX_EXTRA_DATA_BYTES),
+            u256_to_chunk(&self.base_fee_per_gas),
+            hash_to_chunk(&self.block_hash),
+            hash_to_chunk(&self.transactions_root),
+            hash_to_chunk(&self.withdrawals_root),
+        ];
+        if let (Some(blob_gas_used), Some(excess_blob_gas)) = (self.blob_gas_used, self.excess_blob_gas) {
+            chunks.push(u64_to_chunk(blob_gas_used));
+            chunks.push(u64_to_chunk(excess_blob_gas));
+        };
+        EthHash::from(merkleize(&chunks, None))
+    }
+
+    /// Checks that the passed in execution layer block is the one this header commits to.
+    pub fn matches_block(&self, block: &EthBlock) -> bool {
+        self.block_hash == block.hash
+            && U256::from(self.block_number) == block.number
+            && self.parent_hash == block.parent_hash
+            && self.receipts_root == block.receipts_root
+            && self.state_root == block.state_root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_tree_root_should_depend_on_blob_fields() {
+        let capella_header = ExecutionPayloadHeader::default();
+        let deneb_header = ExecutionPayloadHeader {
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            ..Default::default()
+        };
+        assert_ne!(capella_header.hash_tree_root(), deneb_header.hash_tree_root());
+    }
+
+    #[test]
+    fn should_match_block_header_fields() {
+        let block = EthBlock {
+            hash: EthHash::from_low_u64_be(1),
+            number: U256::from(1337),
+            ..Default::default()
+        };
+        let header = ExecutionPayloadHeader {
+            block_hash: block.hash,
+            block_number: 1337,
+            ..Default::default()
+        };
+        assert!(header.matches_block(&block));
+        let other_header = ExecutionPayloadHeader {
+            block_number: 1338,
+            ..header
+        };
+        assert!(!other_header.matches_block(&block));
+    }
+
+    #[test]
+    fn should_deserialize_capella_execution_payload_header_from_beacon_api_json() {
+        let json = format!(
+            r#"{{
+                "parent_hash": "0x{h}",
+                "fee_recipient": "0x{a}",
+                "state_root": "0x{h}",
+                "receipts_root": "0x{h}",
+                "logs_bloom": "0x{b}",
+                "prev_randao": "0x{h}",
+                "block_number": "17034870",
+                "gas_limit": "30000000",
+                "gas_used": "11780462",
+                "timestamp": "1681338455",
+                "extra_data": "0x6265617665726275696c642e6f7267",
+                "base_fee_per_gas": "26614719052",
+                "block_hash": "0x{h}",
+                "transactions_root": "0x{h}",
+                "withdrawals_root": "0x{h}"
+            }}"#,
+            h = "11".repeat(32),
+            a = "22".repeat(20),
+            b = "00".repeat(256),
+        );
+        let header: ExecutionPayloadHeader = serde_json::from_str(&json).unwrap();
+        assert_eq!(header.block_number, 17_034_870);
+        assert_eq!(header.base_fee_per_gas, U256::from(26_614_719_052u64));
+        assert_eq!(header.extra_data, b"beaverbuild.org".to_vec());
+        assert!(header.blob_gas_used.is_none());
+    }
+}
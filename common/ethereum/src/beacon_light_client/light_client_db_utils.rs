@@ -0,0 +1,124 @@
+use common::{crypto_utils::keccak_hash_bytes, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use derive_getters::Getters;
+use derive_more::Constructor;
+use function_name::named;
+
+use super::{LightClientError, LightClientStore};
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Constructor)]
+pub struct LightClientDbUtils<'a, D: DatabaseInterface> {
+    db: &'a D,
+    is_for_eth: bool,
+}
+
+const DB_KEY_PREFIX: &str = "LightClientDbUtils::";
+
+impl<'a, D: DatabaseInterface> LightClientDbUtils<'a, D> {
+    fn side(&self) -> String {
+        if self.is_for_eth { "eth" } else { "evm" }.to_string()
+    }
+
+    #[named]
+    fn eth_light_client_store_db_key() -> Vec<u8> {
+        let s = format!("{DB_KEY_PREFIX}{}", function_name!());
+        keccak_hash_bytes(s.as_bytes()).as_bytes().to_vec()
+    }
+
+    #[named]
+    fn evm_light_client_store_db_key() -> Vec<u8> {
+        let s = format!("{DB_KEY_PREFIX}{}", function_name!());
+        keccak_hash_bytes(s.as_bytes()).as_bytes().to_vec()
+    }
+
+    fn store_db_key(&self) -> Vec<u8> {
+        if self.is_for_eth {
+            Self::eth_light_client_store_db_key()
+        } else {
+            Self::evm_light_client_store_db_key()
+        }
+    }
+
+    pub fn maybe_get_store(&self) -> Result<Option<LightClientStore>, LightClientError> {
+        match self.db().get(self.store_db_key(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => LightClientStore::from_bytes(&bytes).map(Some),
+            Err(e) if e.is_db_key_not_found() => Ok(None),
+            Err(e) => Err(LightClientError::Db(e.to_string())),
+        }
+    }
+
+    pub fn get_store(&self) -> Result<LightClientStore, LightClientError> {
+        self.maybe_get_store()?
+            .ok_or_else(|| LightClientError::NotInitialized(self.side()))
+    }
+
+    pub fn put_store(&self, store: &LightClientStore) -> Result<(), LightClientError> {
+        self.db()
+            .put(self.store_db_key(), store.to_bytes()?, MIN_DATA_SENSITIVITY_LEVEL)
+            .map_err(|e| LightClientError::Db(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        test_utils::get_test_database,
+        types::{Bytes, DataSensitivity, Result},
+    };
+
+    use super::*;
+
+    #[test]
+    fn eth_and_evm_light_client_store_db_keys_should_differ() {
+        let db = get_test_database();
+        let eth_db_utils = LightClientDbUtils::new(&db, true);
+        let evm_db_utils = LightClientDbUtils::new(&db, false);
+        assert_ne!(eth_db_utils.store_db_key(), evm_db_utils.store_db_key());
+    }
+
+    struct UnreadableDb;
+
+    impl DatabaseInterface for UnreadableDb {
+        fn end_transaction(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn start_transaction(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn delete(&self, _key: Bytes) -> Result<()> {
+            Ok(())
+        }
+
+        fn get(&self, _key: Bytes, _sensitivity: DataSensitivity) -> Result<Bytes> {
+            Err("Cannot decrypt value!".into())
+        }
+
+        fn put(&self, _key: Bytes, _value: Bytes, _sensitivity: DataSensitivity) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_not_treat_db_errors_as_light_client_not_initialized() {
+        let db = UnreadableDb;
+        let db_utils = LightClientDbUtils::new(&db, true);
+        let expected_error = LightClientError::Db("✘ Cannot decrypt value!".into());
+        match db_utils.maybe_get_store() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_get_store_if_not_initialized() {
+        let db = get_test_database();
+        let db_utils = LightClientDbUtils::new(&db, true);
+        assert!(db_utils.maybe_get_store().unwrap().is_none());
+        let expected_error = LightClientError::NotInitialized("eth".into());
+        match db_utils.get_store() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+}
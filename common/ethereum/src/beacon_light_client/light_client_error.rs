@@ -0,0 +1,85 @@
+use common::AppError;
+use ethereum_types::H256 as EthHash;
+use thiserror::Error;
+
+#[derive(Debug, Eq, PartialEq, Error, Clone)]
+pub enum LightClientError {
+    #[error("light client not initialized for {0} side")]
+    NotInitialized(String),
+
+    #[error("no beacon chain params for chain: {0}")]
+    NoBeaconChainParams(String),
+
+    #[error("invalid {0} merkle branch")]
+    InvalidBranch(String),
+
+    #[error("bootstrap header root {got} does not match trusted block root {expected}")]
+    UntrustedBootstrap { got: EthHash, expected: EthHash },
+
+    #[error("header has no execution payload header - pre-capella headers are not supported")]
+    NoExecutionPayloadHeader,
+
+    #[error("not enough sync committee participants: {got}, need at least {required}")]
+    NotEnoughParticipants { got: usize, required: usize },
+
+    #[error("invalid slot ordering - signature slot: {signature_slot}, attested slot: {attested_slot}, finalized slot: {finalized_slot}")]
+    InvalidSlotOrdering {
+        signature_slot: u64,
+        attested_slot: u64,
+        finalized_slot: u64,
+    },
+
+    #[error("signature period {signature_period} is not usable with store period {store_period}")]
+    InvalidSignaturePeriod { store_period: u64, signature_period: u64 },
+
+    #[error("update is not relevant to the light client store")]
+    IrrelevantUpdate,
+
+    #[error("next sync committee in update does not match the one in the store")]
+    NextSyncCommitteeMismatch,
+
+    #[error("next sync committee for period {0} is not known")]
+    UnknownNextSyncCommittee(u64),
+
+    #[error("update without finality proof cannot advance the light client")]
+    NoFinalityProof,
+
+    #[error("sync committee signature is invalid")]
+    InvalidSignature,
+
+    #[error("sync committee supermajority not reached: {got} of {total}")]
+    NoSupermajority { got: usize, total: usize },
+
+    #[error("bls error: {0}")]
+    Bls(String),
+
+    #[error("invalid {0} length - expected {1} bytes, got {2}")]
+    InvalidLength(String, usize, usize),
+
+    #[error("serde json error: {0}")]
+    SerdeJson(String),
+
+    #[error("hex error: {0}")]
+    Hex(String),
+
+    #[error("db error: {0}")]
+    Db(String),
+}
+
+impl From<serde_json::Error> for LightClientError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerdeJson(format!("{e}"))
+    }
+}
+
+impl From<hex::FromHexError> for LightClientError {
+    fn from(e: hex::FromHexError) -> Self {
+        Self::Hex(format!("{e}"))
+    }
+}
+
+impl From<LightClientError> for AppError {
+    fn from(e: LightClientError) -> AppError {
+        AppError::Custom(format!("{e}"))
+    }
+}
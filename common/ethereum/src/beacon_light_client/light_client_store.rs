@@ -0,0 +1,389 @@
+use common::types::{Byte, Bytes};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    sync_committee::SYNC_COMMITTEE_SIZE,
+    BeaconChainParams,
+    LightClientBootstrap,
+    LightClientError,
+    LightClientHeader,
+    LightClientUpdate,
+    SyncCommittee,
+};
+
+const MIN_SYNC_COMMITTEE_PARTICIPANTS: usize = 1;
+
+/// A minimal version of the consensus specs' `LightClientStore`. Unlike the spec's store this one
+/// only ever advances on finalized, supermajority-signed updates, since its purpose is to tell the
+/// core which execution layer blocks are final, not to track the optimistic head.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientStore {
+    pub params: BeaconChainParams,
+    pub finalized_header: LightClientHeader,
+    pub current_sync_committee: SyncCommittee,
+    pub next_sync_committee: Option<SyncCommittee>,
+}
+
+impl LightClientStore {
+    pub fn from_bootstrap(
+        params: BeaconChainParams,
+        bootstrap: LightClientBootstrap,
+        trusted_block_root: &EthHash,
+    ) -> Result<Self, LightClientError> {
+        info!("✔ Initializing light client store from bootstrap...");
+        bootstrap.validate(&params, trusted_block_root)?;
+        Ok(Self {
+            params,
+            finalized_header: bootstrap.header,
+            current_sync_committee: bootstrap.current_sync_committee,
+            next_sync_committee: None,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Bytes, LightClientError> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self, LightClientError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    pub fn get_finalized_execution_block_number(&self) -> Result<u64, LightClientError> {
+        self.finalized_header.get_execution().map(|e| e.block_number)
+    }
+
+    pub fn get_finalized_execution_block_hash(&self) -> Result<EthHash, LightClientError> {
+        self.finalized_header.get_execution().map(|e| e.block_hash)
+    }
+
+    fn get_store_period(&self) -> u64 {
+        BeaconChainParams::compute_sync_committee_period_at_slot(self.finalized_header.beacon.slot)
+    }
+
+    fn validate_update(&self, update: &LightClientUpdate) -> Result<(), LightClientError> {
+        update.sync_aggregate.validate()?;
+        let num_participants = update.sync_aggregate.get_num_participants();
+        if num_participants < MIN_SYNC_COMMITTEE_PARTICIPANTS {
+            return Err(LightClientError::NotEnoughParticipants {
+                got: num_participants,
+                required: MIN_SYNC_COMMITTEE_PARTICIPANTS,
+            });
+        };
+
+        update.attested_header.validate(&self.params)?;
+        let attested_slot = update.attested_header.beacon.slot;
+        let finalized_slot = update.get_finalized_header()?.beacon.slot;
+        if !(update.signature_slot > attested_slot && attested_slot >= finalized_slot) {
+            return Err(LightClientError::InvalidSlotOrdering {
+                signature_slot: update.signature_slot,
+                attested_slot,
+                finalized_slot,
+            });
+        };
+
+        let store_period = self.get_store_period();
+        let signature_period = BeaconChainParams::compute_sync_committee_period_at_slot(update.signature_slot);
+        let signature_period_is_valid = if self.next_sync_committee.is_some() {
+            signature_period == store_period || signature_period == store_period + 1
+        } else {
+            signature_period == store_period
+        };
+        if !signature_period_is_valid {
+            return Err(LightClientError::InvalidSignaturePeriod {
+                store_period,
+                signature_period,
+            });
+        };
+
+        let attested_period = BeaconChainParams::compute_sync_committee_period_at_slot(attested_slot);
+        let update_has_next_sync_committee =
+            self.next_sync_committee.is_none() && update.is_sync_committee_update() && attested_period == store_period;
+        if !(attested_slot > self.finalized_header.beacon.slot || update_has_next_sync_committee) {
+            return Err(LightClientError::IrrelevantUpdate);
+        };
+
+        update.validate_finality_branch(&self.params)?;
+
+        if let Some(next_sync_committee) = update.get_next_sync_committee() {
+            if attested_period == store_period {
+                if let Some(ref known_next_sync_committee) = self.next_sync_committee {
+                    if known_next_sync_committee != next_sync_committee {
+                        return Err(LightClientError::NextSyncCommitteeMismatch);
+                    }
+                }
+            };
+            update.validate_next_sync_committee_branch(&self.params)?;
+        };
+
+        let sync_committee = if signature_period == store_period {
+            &self.current_sync_committee
+        } else {
+            self.next_sync_committee
+                .as_ref()
+                .ok_or(LightClientError::UnknownNextSyncCommittee(signature_period))?
+        };
+        let domain = self.params.compute_sync_committee_domain(update.signature_slot);
+        let signing_root = BeaconChainParams::compute_signing_root(
+            &update.attested_header.beacon.hash_tree_root().to_fixed_bytes(),
+            &domain,
+        );
+        sync_committee.verify_sync_aggregate(&update.sync_aggregate, &signing_root)
+    }
+
+    fn apply_update(&self, update: &LightClientUpdate) -> Result<Self, LightClientError> {
+        let finalized_header = update.get_finalized_header()?;
+        let store_period = self.get_store_period();
+        let finalized_period = BeaconChainParams::compute_sync_committee_period_at_slot(finalized_header.beacon.slot);
+        let mut new_self = self.clone();
+        if self.next_sync_committee.is_none() {
+            if finalized_period != store_period {
+                return Err(LightClientError::UnknownNextSyncCommittee(finalized_period));
+            };
+            new_self.next_sync_committee = update.get_next_sync_committee().cloned();
+        } else if finalized_period == store_period + 1 {
+            info!("✔ Rotating light client sync committees for period {finalized_period}...");
+            new_self.current_sync_committee = self.next_sync_committee.clone().unwrap_or_default();
+            new_self.next_sync_committee = update.get_next_sync_committee().cloned();
+        };
+        if finalized_header.beacon.slot > self.finalized_header.beacon.slot {
+            new_self.finalized_header = finalized_header.clone();
+        };
+        Ok(new_self)
+    }
+
+    /// Validates the passed in update against the store, returning the updated store if it was
+    /// signed by a supermajority of the sync committee and carries a finality proof.
+    pub fn process_update(&self, update: &LightClientUpdate) -> Result<Self, LightClientError> {
+        info!(
+            "✔ Processing light client update for attested slot {}...",
+            update.attested_header.beacon.slot
+        );
+        self.validate_update(update)?;
+        let num_participants = update.sync_aggregate.get_num_participants();
+        if num_participants * 3 < SYNC_COMMITTEE_SIZE * 2 {
+            return Err(LightClientError::NoSupermajority {
+                got: num_participants,
+                total: SYNC_COMMITTEE_SIZE,
+            });
+        };
+        self.apply_update(update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use blst::min_pk::{AggregateSignature, SecretKey, Signature};
+    use common_chain_ids::EthChainId;
+
+    use super::*;
+    use crate::beacon_light_client::{
+        light_client_update::{get_current_sync_committee_gindex, get_finalized_root_gindex},
+        ssz::compute_merkle_root_from_branch,
+        BeaconBlockHeader,
+        BeaconFork,
+        BlsBytes,
+        ExecutionPayloadHeader,
+        SyncAggregate,
+    };
+
+    // NOTE: A deneb-era slot on mainnet, with the other slots in the tests in the same period.
+    const BOOTSTRAP_SLOT: u64 = 8_700_000;
+    const BLS_SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn get_sample_params() -> BeaconChainParams {
+        BeaconChainParams::from_eth_chain_id(&EthChainId::Mainnet).unwrap()
+    }
+
+    fn get_sample_secret_keys() -> Vec<SecretKey> {
+        (0..8u8)
+            .map(|i| SecretKey::key_gen(&[i + 1; 32], &[]).unwrap())
+            .collect()
+    }
+
+    fn get_sample_sync_committee(secret_keys: &[SecretKey]) -> SyncCommittee {
+        SyncCommittee {
+            pubkeys: (0..SYNC_COMMITTEE_SIZE)
+                .map(|i| BlsBytes::from(secret_keys[i % secret_keys.len()].sk_to_pk().to_bytes().to_vec()))
+                .collect(),
+            aggregate_pubkey: BlsBytes::from(vec![0u8; 48]),
+        }
+    }
+
+    fn get_sample_branch(depth: usize, seed: u8) -> Vec<EthHash> {
+        (0..depth).map(|i| EthHash::repeat_byte(seed + i as u8)).collect()
+    }
+
+    fn get_sample_light_client_header(slot: u64, block_number: u64, state_root: EthHash) -> LightClientHeader {
+        let execution = ExecutionPayloadHeader {
+            block_number,
+            block_hash: EthHash::from_low_u64_be(block_number),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            ..Default::default()
+        };
+        let execution_branch = get_sample_branch(4, 0x10);
+        let body_root =
+            compute_merkle_root_from_branch(&execution.hash_tree_root().to_fixed_bytes(), &execution_branch, 25);
+        LightClientHeader {
+            beacon: BeaconBlockHeader {
+                slot,
+                state_root,
+                body_root,
+                ..Default::default()
+            },
+            execution: Some(execution),
+            execution_branch,
+        }
+    }
+
+    fn get_sample_store(secret_keys: &[SecretKey]) -> LightClientStore {
+        let params = get_sample_params();
+        let current_sync_committee = get_sample_sync_committee(secret_keys);
+        let current_sync_committee_branch = get_sample_branch(5, 0x20);
+        let state_root = compute_merkle_root_from_branch(
+            &current_sync_committee.hash_tree_root().to_fixed_bytes(),
+            &current_sync_committee_branch,
+            get_current_sync_committee_gindex(BeaconFork::Deneb),
+        );
+        let header = get_sample_light_client_header(BOOTSTRAP_SLOT, 19_000_000, state_root);
+        let trusted_block_root = header.beacon.hash_tree_root();
+        let bootstrap = LightClientBootstrap {
+            header,
+            current_sync_committee,
+            current_sync_committee_branch,
+        };
+        LightClientStore::from_bootstrap(params, bootstrap, &trusted_block_root).unwrap()
+    }
+
+    fn get_sample_finality_update(secret_keys: &[SecretKey], num_participants: usize) -> LightClientUpdate {
+        let params = get_sample_params();
+        let finalized_header = get_sample_light_client_header(BOOTSTRAP_SLOT + 64, 19_000_064, EthHash::zero());
+        let finality_branch = get_sample_branch(6, 0x30);
+        let attested_state_root = compute_merkle_root_from_branch(
+            &finalized_header.beacon.hash_tree_root().to_fixed_bytes(),
+            &finality_branch,
+            get_finalized_root_gindex(BeaconFork::Deneb),
+        );
+        let attested_header = get_sample_light_client_header(BOOTSTRAP_SLOT + 128, 19_000_128, attested_state_root);
+        let signature_slot = BOOTSTRAP_SLOT + 129;
+        let signing_root = BeaconChainParams::compute_signing_root(
+            &attested_header.beacon.hash_tree_root().to_fixed_bytes(),
+            &params.compute_sync_committee_domain(signature_slot),
+        );
+        let signatures = secret_keys
+            .iter()
+            .map(|sk| sk.sign(&signing_root, BLS_SIGNATURE_DST, &[]))
+            .collect::<Vec<Signature>>();
+        let participant_signatures = (0..num_participants)
+            .map(|i| &signatures[i % signatures.len()])
+            .collect::<Vec<&Signature>>();
+        let aggregate_signature = AggregateSignature::aggregate(&participant_signatures, true)
+            .unwrap()
+            .to_signature();
+        let mut bits = vec![0u8; SYNC_COMMITTEE_SIZE / 8];
+        (0..num_participants).for_each(|i| bits[i / 8] |= 1 << (i % 8));
+        LightClientUpdate {
+            attested_header,
+            finalized_header: Some(finalized_header),
+            finality_branch,
+            signature_slot,
+            sync_aggregate: SyncAggregate {
+                sync_committee_bits: BlsBytes::from(bits),
+                sync_committee_signature: BlsBytes::from(aggregate_signature.to_bytes().to_vec()),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_init_light_client_store_from_bootstrap() {
+        let store = get_sample_store(&get_sample_secret_keys());
+        assert_eq!(store.get_finalized_execution_block_number().unwrap(), 19_000_000);
+        assert!(store.next_sync_committee.is_none());
+    }
+
+    #[test]
+    fn should_fail_to_init_light_client_store_from_untrusted_bootstrap() {
+        let store = get_sample_store(&get_sample_secret_keys());
+        let bootstrap = LightClientBootstrap {
+            header: store.finalized_header.clone(),
+            current_sync_committee: store.current_sync_committee.clone(),
+            current_sync_committee_branch: get_sample_branch(5, 0x20),
+        };
+        let trusted_block_root = EthHash::repeat_byte(0xff);
+        let expected_error = LightClientError::UntrustedBootstrap {
+            got: store.finalized_header.beacon.hash_tree_root(),
+            expected: trusted_block_root,
+        };
+        match LightClientStore::from_bootstrap(get_sample_params(), bootstrap, &trusted_block_root) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_process_valid_finality_update() {
+        let secret_keys = get_sample_secret_keys();
+        let store = get_sample_store(&secret_keys);
+        let update = get_sample_finality_update(&secret_keys, SYNC_COMMITTEE_SIZE);
+        let result = store.process_update(&update).unwrap();
+        assert_eq!(result.get_finalized_execution_block_number().unwrap(), 19_000_064);
+        assert_eq!(
+            result.get_finalized_execution_block_hash().unwrap(),
+            EthHash::from_low_u64_be(19_000_064)
+        );
+    }
+
+    #[test]
+    fn should_fail_to_process_update_without_supermajority() {
+        let secret_keys = get_sample_secret_keys();
+        let store = get_sample_store(&secret_keys);
+        let update = get_sample_finality_update(&secret_keys, 300);
+        let expected_error = LightClientError::NoSupermajority {
+            got: 300,
+            total: SYNC_COMMITTEE_SIZE,
+        };
+        match store.process_update(&update) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_process_update_with_tampered_finalized_header() {
+        let secret_keys = get_sample_secret_keys();
+        let store = get_sample_store(&secret_keys);
+        let mut update = get_sample_finality_update(&secret_keys, SYNC_COMMITTEE_SIZE);
+        update.finalized_header = Some(get_sample_light_client_header(
+            BOOTSTRAP_SLOT + 64,
+            19_000_065,
+            EthHash::zero(),
+        ));
+        let expected_error = LightClientError::InvalidBranch("finality".into());
+        match store.process_update(&update) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_process_update_with_bad_signature() {
+        let secret_keys = get_sample_secret_keys();
+        let store = get_sample_store(&secret_keys);
+        let mut update = get_sample_finality_update(&secret_keys, SYNC_COMMITTEE_SIZE);
+        update.attested_header.beacon.proposer_index = 1;
+        let expected_error = LightClientError::InvalidSignature;
+        match store.process_update(&update) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_make_light_client_store_bytes_roundtrip() {
+        let store = get_sample_store(&get_sample_secret_keys());
+        let result = LightClientStore::from_bytes(&store.to_bytes().unwrap()).unwrap();
+        assert_eq!(result, store);
+    }
+}
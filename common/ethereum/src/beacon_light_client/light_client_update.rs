@@ -0,0 +1,271 @@
+use std::str::FromStr;
+
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    beacon_serde::u64_as_string,
+    ssz::is_valid_merkle_branch,
+    BeaconBlockHeader,
+    BeaconChainParams,
+    BeaconFork,
+    ExecutionPayloadHeader,
+    LightClientError,
+    SyncAggregate,
+    SyncCommittee,
+};
+
+const EXECUTION_PAYLOAD_GINDEX: u64 = 25;
+
+// NOTE: Electra added fields to the beacon state, deepening the tree and thus moving these.
+const FINALIZED_ROOT_GINDEX: u64 = 105;
+const CURRENT_SYNC_COMMITTEE_GINDEX: u64 = 54;
+const NEXT_SYNC_COMMITTEE_GINDEX: u64 = 55;
+const FINALIZED_ROOT_GINDEX_ELECTRA: u64 = 169;
+const CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 86;
+const NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 87;
+
+fn is_electra_or_later(fork: BeaconFork) -> bool {
+    fork >= BeaconFork::Electra
+}
+
+pub(super) fn get_finalized_root_gindex(fork: BeaconFork) -> u64 {
+    if is_electra_or_later(fork) {
+        FINALIZED_ROOT_GINDEX_ELECTRA
+    } else {
+        FINALIZED_ROOT_GINDEX
+    }
+}
+
+pub(super) fn get_current_sync_committee_gindex(fork: BeaconFork) -> u64 {
+    if is_electra_or_later(fork) {
+        CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA
+    } else {
+        CURRENT_SYNC_COMMITTEE_GINDEX
+    }
+}
+
+pub(super) fn get_next_sync_committee_gindex(fork: BeaconFork) -> u64 {
+    if is_electra_or_later(fork) {
+        NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA
+    } else {
+        NEXT_SYNC_COMMITTEE_GINDEX
+    }
+}
+
+fn branch_is_empty(branch: &[EthHash]) -> bool {
+    branch.iter().all(|hash| hash.is_zero())
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientHeader {
+    pub beacon: BeaconBlockHeader,
+    #[serde(default)]
+    pub execution: Option<ExecutionPayloadHeader>,
+    #[serde(default)]
+    pub execution_branch: Vec<EthHash>,
+}
+
+impl LightClientHeader {
+    pub fn get_execution(&self) -> Result<&ExecutionPayloadHeader, LightClientError> {
+        self.execution
+            .as_ref()
+            .ok_or(LightClientError::NoExecutionPayloadHeader)
+    }
+
+    /// Checks the execution payload header is committed to by the beacon block body, and that its
+    /// shape matches the fork the beacon block is from.
+    pub fn validate(&self, params: &BeaconChainParams) -> Result<(), LightClientError> {
+        let fork = params.get_fork_at_slot(self.beacon.slot);
+        let execution = self.get_execution()?;
+        if fork < BeaconFork::Capella {
+            return Err(LightClientError::NoExecutionPayloadHeader);
+        };
+        let has_blob_fields = execution.blob_gas_used.is_some() && execution.excess_blob_gas.is_some();
+        if has_blob_fields != (fork >= BeaconFork::Deneb) {
+            return Err(LightClientError::InvalidBranch("execution payload".into()));
+        };
+        if is_valid_merkle_branch(
+            &execution.hash_tree_root().to_fixed_bytes(),
+            &self.execution_branch,
+            EXECUTION_PAYLOAD_GINDEX,
+            &self.beacon.body_root,
+        ) {
+            Ok(())
+        } else {
+            Err(LightClientError::InvalidBranch("execution payload".into()))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientBootstrap {
+    pub header: LightClientHeader,
+    pub current_sync_committee: SyncCommittee,
+    pub current_sync_committee_branch: Vec<EthHash>,
+}
+
+impl FromStr for LightClientBootstrap {
+    type Err = LightClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+impl LightClientBootstrap {
+    pub fn validate(&self, params: &BeaconChainParams, trusted_block_root: &EthHash) -> Result<(), LightClientError> {
+        self.header.validate(params)?;
+        let header_root = self.header.beacon.hash_tree_root();
+        if &header_root != trusted_block_root {
+            return Err(LightClientError::UntrustedBootstrap {
+                got: header_root,
+                expected: *trusted_block_root,
+            });
+        };
+        self.current_sync_committee.validate()?;
+        let gindex = get_current_sync_committee_gindex(params.get_fork_at_slot(self.header.beacon.slot));
+        if is_valid_merkle_branch(
+            &self.current_sync_committee.hash_tree_root().to_fixed_bytes(),
+            &self.current_sync_committee_branch,
+            gindex,
+            &self.header.beacon.state_root,
+        ) {
+            Ok(())
+        } else {
+            Err(LightClientError::InvalidBranch("current sync committee".into()))
+        }
+    }
+}
+
+/// A light client update as served by the beacon API's `light_client/updates` and
+/// `light_client/finality_update` endpoints. The latter has no next sync committee, and the
+/// former may carry zeroed branches in place of absent proofs, so both are treated as optional.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientUpdate {
+    pub attested_header: LightClientHeader,
+    #[serde(default)]
+    pub next_sync_committee: Option<SyncCommittee>,
+    #[serde(default)]
+    pub next_sync_committee_branch: Vec<EthHash>,
+    #[serde(default)]
+    pub finalized_header: Option<LightClientHeader>,
+    #[serde(default)]
+    pub finality_branch: Vec<EthHash>,
+    pub sync_aggregate: SyncAggregate,
+    #[serde(with = "u64_as_string")]
+    pub signature_slot: u64,
+}
+
+impl FromStr for LightClientUpdate {
+    type Err = LightClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+impl LightClientUpdate {
+    pub fn is_sync_committee_update(&self) -> bool {
+        self.next_sync_committee.is_some() && !branch_is_empty(&self.next_sync_committee_branch)
+    }
+
+    pub fn is_finality_update(&self) -> bool {
+        self.finalized_header.is_some() && !branch_is_empty(&self.finality_branch)
+    }
+
+    pub fn get_finalized_header(&self) -> Result<&LightClientHeader, LightClientError> {
+        match self.finalized_header {
+            Some(ref header) if self.is_finality_update() => Ok(header),
+            _ => Err(LightClientError::NoFinalityProof),
+        }
+    }
+
+    pub fn get_next_sync_committee(&self) -> Option<&SyncCommittee> {
+        if self.is_sync_committee_update() {
+            self.next_sync_committee.as_ref()
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn validate_finality_branch(&self, params: &BeaconChainParams) -> Result<(), LightClientError> {
+        let finalized_header = self.get_finalized_header()?;
+        finalized_header.validate(params)?;
+        let gindex = get_finalized_root_gindex(params.get_fork_at_slot(self.attested_header.beacon.slot));
+        if is_valid_merkle_branch(
+            &finalized_header.beacon.hash_tree_root().to_fixed_bytes(),
+            &self.finality_branch,
+            gindex,
+            &self.attested_header.beacon.state_root,
+        ) {
+            Ok(())
+        } else {
+            Err(LightClientError::InvalidBranch("finality".into()))
+        }
+    }
+
+    pub(super) fn validate_next_sync_committee_branch(
+        &self,
+        params: &BeaconChainParams,
+    ) -> Result<(), LightClientError> {
+        match self.get_next_sync_committee() {
+            None => Ok(()),
+            Some(next_sync_committee) => {
+                next_sync_committee.validate()?;
+                let gindex = get_next_sync_committee_gindex(params.get_fork_at_slot(self.attested_header.beacon.slot));
+                if is_valid_merkle_branch(
+                    &next_sync_committee.hash_tree_root().to_fixed_bytes(),
+                    &self.next_sync_committee_branch,
+                    gindex,
+                    &self.attested_header.beacon.state_root,
+                ) {
+                    Ok(())
+                } else {
+                    Err(LightClientError::InvalidBranch("next sync committee".into()))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_chain_ids::EthChainId;
+
+    use super::*;
+
+    #[test]
+    fn should_get_gindices_for_fork() {
+        assert_eq!(get_finalized_root_gindex(BeaconFork::Deneb), 105);
+        assert_eq!(get_finalized_root_gindex(BeaconFork::Electra), 169);
+        assert_eq!(get_current_sync_committee_gindex(BeaconFork::Capella), 54);
+        assert_eq!(get_next_sync_committee_gindex(BeaconFork::Fulu), 87);
+    }
+
+    #[test]
+    fn update_with_zeroed_finality_branch_should_not_be_finality_update() {
+        let update = LightClientUpdate {
+            finalized_header: Some(LightClientHeader::default()),
+            finality_branch: vec![EthHash::zero(); 6],
+            ..Default::default()
+        };
+        assert!(!update.is_finality_update());
+        let expected_error = LightClientError::NoFinalityProof;
+        match update.get_finalized_header() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_validate_header_without_execution_payload() {
+        let params = BeaconChainParams::from_eth_chain_id(&EthChainId::Mainnet).unwrap();
+        let header = LightClientHeader::default();
+        let expected_error = LightClientError::NoExecutionPayloadHeader;
+        match header.validate(&params) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+// NOTE: Post-merge execution layer blocks carry no proof of work, so a well-formed chain of headers
+// proves nothing about finality. This module implements the parts of the consensus-layer light
+// client protocol needed to verify that an execution block has been finalized by the beacon chain.
+// See: https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md
+
+mod beacon_block_header;
+mod beacon_chain_params;
+mod beacon_serde;
+mod execution_payload_header;
+mod light_client_db_utils;
+mod light_client_error;
+mod light_client_store;
+mod light_client_update;
+mod process_light_client_update;
+mod ssz;
+mod sync_committee;
+
+pub use self::{
+    beacon_block_header::BeaconBlockHeader,
+    beacon_chain_params::{BeaconChainParams, BeaconFork},
+    execution_payload_header::ExecutionPayloadHeader,
+    light_client_db_utils::LightClientDbUtils,
+    light_client_error::LightClientError,
+    light_client_store::LightClientStore,
+    light_client_update::{LightClientBootstrap, LightClientHeader, LightClientUpdate},
+    process_light_client_update::{
+        block_is_finalized_by_light_client,
+        maybe_process_eth_light_client_update_in_state,
+        maybe_process_evm_light_client_update_in_state,
+    },
+    sync_committee::{BlsBytes, SyncAggregate, SyncCommittee},
+};
@@ -0,0 +1,113 @@
+use common::{traits::DatabaseInterface, types::Result};
+
+use super::LightClientDbUtils;
+use crate::{EthDbUtilsExt, EthState, EthStateCompatible, EthSubmissionMaterial};
+
+fn maybe_process_light_client_update<D: DatabaseInterface>(
+    state: &impl EthStateCompatible<D>,
+    is_for_eth: bool,
+) -> Result<()> {
+    let symbol = if is_for_eth { "ETH" } else { "EVM" };
+    if cfg!(feature = "non-validating") {
+        info!("✔ Skipping {symbol} light client update processing!");
+        return Ok(());
+    };
+    let db = if is_for_eth {
+        state.get_eth_db_utils().get_db()
+    } else {
+        state.get_evm_db_utils().get_db()
+    };
+    let db_utils = LightClientDbUtils::new(db, is_for_eth);
+    let update = &state.get_sub_mat()?.light_client_update;
+    match (db_utils.maybe_get_store()?, update) {
+        (None, Some(_)) => {
+            warn!("✘ Ignoring light client update since no {symbol} light client is initialized!");
+            Ok(())
+        },
+        (None, None) | (Some(_), None) => {
+            info!("✔ No light client update in {symbol} submission material");
+            Ok(())
+        },
+        (Some(store), Some(update)) => {
+            info!("✔ Processing {symbol} light client update...");
+            let updated_store = store.process_update(update)?;
+            info!(
+                "✔ {symbol} light client finalized execution block number: {}",
+                updated_store.get_finalized_execution_block_number()?
+            );
+            Ok(db_utils.put_store(&updated_store)?)
+        },
+    }
+}
+
+/// Returns whether the passed in block, which must be an ancestor of the latest block, may become
+/// the canon block. If there's no light client in use there are no finality constraints, otherwise
+/// the block must be no higher than the light client's finalized execution block, and that block
+/// must be in the chain leading to the latest block.
+pub fn block_is_finalized_by_light_client<D: DatabaseInterface, E: EthDbUtilsExt<D>>(
+    db_utils: &E,
+    sub_mat: &EthSubmissionMaterial,
+) -> Result<bool> {
+    match LightClientDbUtils::new(db_utils.get_db(), db_utils.get_is_for_eth()).maybe_get_store()? {
+        None => Ok(true),
+        Some(store) => {
+            let finalized_block_number = store.get_finalized_execution_block_number()?;
+            let block_number = sub_mat.get_block_number()?.as_u64();
+            if block_number > finalized_block_number {
+                info!("✘ Block #{block_number} is not yet finalized (finalized: #{finalized_block_number})");
+                return Ok(false);
+            };
+            let latest_block = db_utils.get_eth_latest_block_from_db()?;
+            let latest_block_number = latest_block.get_block_number()?.as_u64();
+            if latest_block_number < finalized_block_number {
+                info!("✘ Chain has not yet reached finalized block #{finalized_block_number}");
+                return Ok(false);
+            };
+            match db_utils.maybe_get_nth_ancestor_eth_submission_material(
+                &latest_block.get_block_hash()?,
+                latest_block_number - finalized_block_number,
+            )? {
+                Some(ancestor) if ancestor.get_block_hash()? == store.get_finalized_execution_block_hash()? => Ok(true),
+                Some(_) => {
+                    warn!("✘ Chain does not contain finalized block #{finalized_block_number}!");
+                    Ok(false)
+                },
+                None => Ok(false),
+            }
+        },
+    }
+}
+
+pub fn maybe_process_eth_light_client_update_in_state<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
+    maybe_process_light_client_update(&state, true).and(Ok(state))
+}
+
+pub fn maybe_process_evm_light_client_update_in_state<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
+    maybe_process_light_client_update(&state, false).and(Ok(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+    use crate::{test_utils::get_sequential_eth_blocks_and_receipts, EthDbUtils};
+
+    #[test]
+    fn block_should_be_finalized_if_no_light_client_in_use() {
+        let db = get_test_database();
+        let db_utils = EthDbUtils::new(&db);
+        let sub_mat = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let result = block_is_finalized_by_light_client(&db_utils, &sub_mat).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn should_do_nothing_if_no_update_and_no_light_client_in_use() {
+        let db = get_test_database();
+        let sub_mat = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let state = EthState::init(&db).add_eth_submission_material(sub_mat).unwrap();
+        let result = maybe_process_eth_light_client_update_in_state(state);
+        assert!(result.is_ok());
+    }
+}
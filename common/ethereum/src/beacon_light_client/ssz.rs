@@ -0,0 +1,169 @@
+use ethereum_types::{H256 as EthHash, U256};
+use sha2::{Digest, Sha256};
+
+// NOTE: Just enough SSZ merkleization to hash the handful of consensus-layer containers the light
+// client deals with. See: https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md
+
+pub(super) type Chunk = [u8; 32];
+
+const BYTES_PER_CHUNK: usize = 32;
+const MAX_MERKLE_DEPTH: usize = 64;
+
+lazy_static! {
+    static ref ZERO_HASHES: Vec<Chunk> = {
+        let mut hashes = vec![[0u8; 32]];
+        for i in 0..MAX_MERKLE_DEPTH {
+            let h = hash_pair(&hashes[i], &hashes[i]);
+            hashes.push(h);
+        }
+        hashes
+    };
+}
+
+pub(super) fn hash_pair(a: &Chunk, b: &Chunk) -> Chunk {
+    let mut hasher = Sha256::new();
+    hasher.update(a);
+    hasher.update(b);
+    hasher.finalize().into()
+}
+
+pub(super) fn u64_to_chunk(u_64: u64) -> Chunk {
+    let mut chunk = [0u8; 32];
+    chunk[..8].copy_from_slice(&u_64.to_le_bytes());
+    chunk
+}
+
+pub(super) fn u256_to_chunk(u_256: &U256) -> Chunk {
+    let mut chunk = [0u8; 32];
+    u_256.to_little_endian(&mut chunk);
+    chunk
+}
+
+pub(super) fn hash_to_chunk(hash: &EthHash) -> Chunk {
+    hash.to_fixed_bytes()
+}
+
+pub(super) fn pack_bytes(bytes: &[u8]) -> Vec<Chunk> {
+    bytes
+        .chunks(BYTES_PER_CHUNK)
+        .map(|slice| {
+            let mut chunk = [0u8; 32];
+            chunk[..slice.len()].copy_from_slice(slice);
+            chunk
+        })
+        .collect()
+}
+
+fn next_power_of_two(n: usize) -> usize {
+    if n <= 1 {
+        1
+    } else {
+        n.next_power_of_two()
+    }
+}
+
+/// Merkleizes the passed in chunks, padding with zero chunks up to the next power of two of
+/// `limit` (or of the number of chunks, if no limit is given).
+pub(super) fn merkleize(chunks: &[Chunk], limit: Option<usize>) -> Chunk {
+    let width = next_power_of_two(limit.unwrap_or(chunks.len()).max(chunks.len()));
+    let depth = width.trailing_zeros() as usize;
+    if chunks.is_empty() {
+        return ZERO_HASHES[depth];
+    };
+    let mut layer = chunks.to_vec();
+    for zero_hash in ZERO_HASHES.iter().take(depth) {
+        if layer.len() % 2 == 1 {
+            layer.push(*zero_hash);
+        };
+        layer = layer.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect();
+    }
+    layer[0]
+}
+
+pub(super) fn mix_in_length(root: &Chunk, length: usize) -> Chunk {
+    hash_pair(root, &u64_to_chunk(length as u64))
+}
+
+pub(super) fn hash_tree_root_of_byte_vector(bytes: &[u8]) -> Chunk {
+    merkleize(&pack_bytes(bytes), None)
+}
+
+pub(super) fn hash_tree_root_of_byte_list(bytes: &[u8], max_length: usize) -> Chunk {
+    let limit = max_length.div_ceil(BYTES_PER_CHUNK);
+    mix_in_length(&merkleize(&pack_bytes(bytes), Some(limit)), bytes.len())
+}
+
+pub(super) fn get_depth_and_index_from_generalized_index(gindex: u64) -> (usize, u64) {
+    let depth = 63 - gindex.leading_zeros() as usize;
+    (depth, gindex - (1 << depth))
+}
+
+pub(super) fn compute_merkle_root_from_branch(leaf: &Chunk, branch: &[EthHash], gindex: u64) -> EthHash {
+    let (_, index) = get_depth_and_index_from_generalized_index(gindex);
+    EthHash::from(branch.iter().enumerate().fold(*leaf, |value, (i, sibling)| {
+        if (index >> i) & 1 == 1 {
+            hash_pair(&sibling.to_fixed_bytes(), &value)
+        } else {
+            hash_pair(&value, &sibling.to_fixed_bytes())
+        }
+    }))
+}
+
+pub(super) fn is_valid_merkle_branch(leaf: &Chunk, branch: &[EthHash], gindex: u64, root: &EthHash) -> bool {
+    let (depth, _) = get_depth_and_index_from_generalized_index(gindex);
+    branch.len() == depth && &compute_merkle_root_from_branch(leaf, branch, gindex) == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_merkleize_single_chunk_to_itself() {
+        let chunk = [1u8; 32];
+        let result = merkleize(&[chunk], None);
+        assert_eq!(result, chunk);
+    }
+
+    #[test]
+    fn should_pad_merkleization_with_zero_hashes() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        let c = [3u8; 32];
+        let result = merkleize(&[a, b, c], None);
+        let expected_result = hash_pair(&hash_pair(&a, &b), &hash_pair(&c, &[0u8; 32]));
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_merkleize_empty_list_with_limit_to_zero_hash() {
+        let result = merkleize(&[], Some(4));
+        let expected_result = ZERO_HASHES[2];
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_get_depth_and_index_from_generalized_index() {
+        assert_eq!(get_depth_and_index_from_generalized_index(105), (6, 41));
+        assert_eq!(get_depth_and_index_from_generalized_index(54), (5, 22));
+        assert_eq!(get_depth_and_index_from_generalized_index(25), (4, 9));
+        assert_eq!(get_depth_and_index_from_generalized_index(169), (7, 41));
+    }
+
+    #[test]
+    fn should_verify_valid_merkle_branch() {
+        let leaves = (0..8u8).map(|i| [i; 32]).collect::<Vec<Chunk>>();
+        let root = EthHash::from(merkleize(&leaves, None));
+        // NOTE: Leaf 5 lives at generalized index 8 + 5 = 13.
+        let branch = vec![
+            EthHash::from(leaves[4]),
+            EthHash::from(hash_pair(&leaves[6], &leaves[7])),
+            EthHash::from(hash_pair(
+                &hash_pair(&leaves[0], &leaves[1]),
+                &hash_pair(&leaves[2], &leaves[3]),
+            )),
+        ];
+        assert!(is_valid_merkle_branch(&leaves[5], &branch, 13, &root));
+        assert!(!is_valid_merkle_branch(&leaves[4], &branch, 13, &root));
+    }
+}
@@ -0,0 +1,224 @@
+use blst::{
+    min_pk::{PublicKey, Signature},
+    BLST_ERROR,
+};
+use derive_more::Deref;
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    beacon_serde::prefixed_hex_bytes,
+    ssz::{hash_tree_root_of_byte_vector, merkleize, Chunk},
+    LightClientError,
+};
+
+pub(super) const SYNC_COMMITTEE_SIZE: usize = 512;
+
+const BLS_PUBLIC_KEY_NUM_BYTES: usize = 48;
+const BLS_SIGNATURE_NUM_BYTES: usize = 96;
+const SYNC_COMMITTEE_BITS_NUM_BYTES: usize = SYNC_COMMITTEE_SIZE / 8;
+const BLS_SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Deref)]
+pub struct BlsBytes(#[serde(with = "prefixed_hex_bytes")] Vec<u8>);
+
+impl BlsBytes {
+    fn check_length(&self, name: &str, expected_length: usize) -> Result<(), LightClientError> {
+        if self.len() == expected_length {
+            Ok(())
+        } else {
+            Err(LightClientError::InvalidLength(
+                name.into(),
+                expected_length,
+                self.len(),
+            ))
+        }
+    }
+}
+
+impl From<Vec<u8>> for BlsBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncCommittee {
+    pub pubkeys: Vec<BlsBytes>,
+    pub aggregate_pubkey: BlsBytes,
+}
+
+impl SyncCommittee {
+    pub fn validate(&self) -> Result<(), LightClientError> {
+        if self.pubkeys.len() != SYNC_COMMITTEE_SIZE {
+            return Err(LightClientError::InvalidLength(
+                "sync committee".into(),
+                SYNC_COMMITTEE_SIZE,
+                self.pubkeys.len(),
+            ));
+        };
+        self.aggregate_pubkey
+            .check_length("aggregate public key", BLS_PUBLIC_KEY_NUM_BYTES)?;
+        self.pubkeys
+            .iter()
+            .try_for_each(|pubkey| pubkey.check_length("public key", BLS_PUBLIC_KEY_NUM_BYTES))
+    }
+
+    pub fn hash_tree_root(&self) -> EthHash {
+        let pubkey_roots = self
+            .pubkeys
+            .iter()
+            .map(|pubkey| hash_tree_root_of_byte_vector(pubkey))
+            .collect::<Vec<Chunk>>();
+        EthHash::from(merkleize(
+            &[
+                merkleize(&pubkey_roots, Some(SYNC_COMMITTEE_SIZE)),
+                hash_tree_root_of_byte_vector(&self.aggregate_pubkey),
+            ],
+            None,
+        ))
+    }
+
+    fn get_participant_pubkeys(&self, sync_aggregate: &SyncAggregate) -> Result<Vec<PublicKey>, LightClientError> {
+        self.pubkeys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| sync_aggregate.bit_is_set(*i))
+            .map(|(_, pubkey)| PublicKey::key_validate(pubkey).map_err(|e| LightClientError::Bls(format!("{e:?}"))))
+            .collect()
+    }
+
+    /// Verifies that the participants flagged in the sync aggregate signed the passed in signing root.
+    pub fn verify_sync_aggregate(
+        &self,
+        sync_aggregate: &SyncAggregate,
+        signing_root: &[u8; 32],
+    ) -> Result<(), LightClientError> {
+        let pubkeys = self.get_participant_pubkeys(sync_aggregate)?;
+        let signature = Signature::from_bytes(&sync_aggregate.sync_committee_signature)
+            .map_err(|e| LightClientError::Bls(format!("{e:?}")))?;
+        let pubkey_refs = pubkeys.iter().collect::<Vec<&PublicKey>>();
+        match signature.fast_aggregate_verify(true, signing_root, BLS_SIGNATURE_DST, &pubkey_refs) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            _ => Err(LightClientError::InvalidSignature),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncAggregate {
+    pub sync_committee_bits: BlsBytes,
+    pub sync_committee_signature: BlsBytes,
+}
+
+impl SyncAggregate {
+    pub fn validate(&self) -> Result<(), LightClientError> {
+        self.sync_committee_bits
+            .check_length("sync committee bits", SYNC_COMMITTEE_BITS_NUM_BYTES)?;
+        self.sync_committee_signature
+            .check_length("sync committee signature", BLS_SIGNATURE_NUM_BYTES)
+    }
+
+    fn bit_is_set(&self, i: usize) -> bool {
+        self.sync_committee_bits
+            .get(i / 8)
+            .map(|byte| (byte >> (i % 8)) & 1 == 1)
+            .unwrap_or(false)
+    }
+
+    pub fn get_num_participants(&self) -> usize {
+        self.sync_committee_bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use blst::min_pk::SecretKey;
+
+    use super::*;
+
+    fn get_sample_secret_keys(n: usize) -> Vec<SecretKey> {
+        (0..n)
+            .map(|i| SecretKey::key_gen(&[i as u8 + 1; 32], &[]).unwrap())
+            .collect()
+    }
+
+    fn get_sample_sync_committee(secret_keys: &[SecretKey]) -> SyncCommittee {
+        SyncCommittee {
+            pubkeys: secret_keys
+                .iter()
+                .map(|sk| BlsBytes::from(sk.sk_to_pk().to_bytes().to_vec()))
+                .collect(),
+            aggregate_pubkey: BlsBytes::from(vec![0u8; BLS_PUBLIC_KEY_NUM_BYTES]),
+        }
+    }
+
+    fn get_sample_sync_aggregate(secret_keys: &[SecretKey], participants: &[usize], msg: &[u8]) -> SyncAggregate {
+        let mut bits = vec![0u8; SYNC_COMMITTEE_BITS_NUM_BYTES];
+        participants.iter().for_each(|i| bits[i / 8] |= 1 << (i % 8));
+        let signatures = participants
+            .iter()
+            .map(|i| secret_keys[*i].sign(msg, BLS_SIGNATURE_DST, &[]))
+            .collect::<Vec<Signature>>();
+        let signature_refs = signatures.iter().collect::<Vec<&Signature>>();
+        let aggregate_signature = blst::min_pk::AggregateSignature::aggregate(&signature_refs, true)
+            .unwrap()
+            .to_signature();
+        SyncAggregate {
+            sync_committee_bits: BlsBytes::from(bits),
+            sync_committee_signature: BlsBytes::from(aggregate_signature.to_bytes().to_vec()),
+        }
+    }
+
+    #[test]
+    fn should_count_sync_aggregate_participants() {
+        let sync_aggregate = SyncAggregate {
+            sync_committee_bits: BlsBytes::from(vec![0b0000_0101, 0b1000_0000]),
+            ..Default::default()
+        };
+        assert_eq!(sync_aggregate.get_num_participants(), 3);
+        assert!(sync_aggregate.bit_is_set(0));
+        assert!(!sync_aggregate.bit_is_set(1));
+        assert!(sync_aggregate.bit_is_set(2));
+        assert!(sync_aggregate.bit_is_set(15));
+    }
+
+    #[test]
+    fn should_verify_valid_sync_aggregate() {
+        let secret_keys = get_sample_secret_keys(4);
+        let committee = get_sample_sync_committee(&secret_keys);
+        let signing_root = [0xab; 32];
+        let sync_aggregate = get_sample_sync_aggregate(&secret_keys, &[0, 2, 3], &signing_root);
+        let result = committee.verify_sync_aggregate(&sync_aggregate, &signing_root);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_verify_sync_aggregate_with_wrong_participants() {
+        let secret_keys = get_sample_secret_keys(4);
+        let committee = get_sample_sync_committee(&secret_keys);
+        let signing_root = [0xab; 32];
+        let mut sync_aggregate = get_sample_sync_aggregate(&secret_keys, &[0, 2, 3], &signing_root);
+        sync_aggregate.sync_committee_bits = get_sample_sync_aggregate(&secret_keys, &[0, 1, 3], &signing_root)
+            .sync_committee_bits
+            .clone();
+        let expected_error = LightClientError::InvalidSignature;
+        match committee.verify_sync_aggregate(&sync_aggregate, &signing_root) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_validate_sync_committee_of_wrong_size() {
+        let committee = get_sample_sync_committee(&get_sample_secret_keys(4));
+        let expected_error = LightClientError::InvalidLength("sync committee".into(), SYNC_COMMITTEE_SIZE, 4);
+        match committee.validate() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(e) => assert_eq!(e, expected_error),
+        }
+    }
+}
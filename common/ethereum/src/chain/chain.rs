@@ -1,5 +1,5 @@
 #![allow(unused)] // FIXME rm once it's in and working and we know we won't need the unused fxns
-use std::{collections::VecDeque, time::Duration};
+use std::{collections::VecDeque, str::FromStr, time::Duration};
 
 use common::{
     crypto_utils::keccak_hash_bytes,
@@ -16,8 +16,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     chain::{ChainDbUtils, ChainError, NoParentError},
+    BeaconChainParams,
     EthPrivateKey,
     EthSubmissionMaterial as EthSubMat,
+    LightClientBootstrap,
+    LightClientStore,
 };
 
 // TODO impl from for app errors where ones we care about get mapped, otherwise just stringified
@@ -77,6 +80,10 @@ impl DbKey {
 pub struct Chain {
     offset: u64,
     hub: EthAddress,
+    // NOTE: Any hubs in addition to the above whose logs we care about, eg whilst an old & a new
+    // hub coexist during a contract upgrade.
+    #[serde(default)]
+    other_hubs: Vec<EthAddress>,
     tail_length: u64,
     confirmations: u64,
     linker_hash: EthHash,
@@ -109,6 +116,22 @@ impl Chain {
         self.chain_id
     }
 
+    fn hubs(&self) -> Vec<EthAddress> {
+        [vec![self.hub], self.other_hubs.clone()].concat()
+    }
+
+    /// Sets the hubs, besides this chain's own, whose logs' receipts are retained when blocks are
+    /// saved. This is persisted along with the chain upon the next insertion.
+    pub fn set_other_hubs(&mut self, hubs: &[EthAddress]) {
+        let mut other_hubs = vec![];
+        for hub in hubs.iter().filter(|h| **h != self.hub) {
+            if !other_hubs.contains(hub) {
+                other_hubs.push(*hub)
+            }
+        }
+        self.other_hubs = other_hubs;
+    }
+
     pub fn get_latest_block_data(&self) -> Option<&Vec<ChainBlockData>> {
         self.chain.front()
     }
@@ -153,6 +176,25 @@ impl Chain {
         }
     }
 
+    /// Initializes (or re-anchors) a beacon chain light client for an already initialized chain
+    /// from a `LightClientBootstrap` and the trusted beacon block root it is for. Thereafter blocks
+    /// on this chain only become canonical once that light client has seen them finalized.
+    pub fn init_light_client<D: DatabaseInterface>(
+        db_utils: &ChainDbUtils<D>,
+        mcid: MetadataChainId,
+        bootstrap_json: &str,
+        trusted_block_root: &EthHash,
+    ) -> Result<LightClientStore, ChainError> {
+        if !Self::is_initialized(db_utils, mcid) {
+            return Err(ChainError::NotInitialized(mcid));
+        };
+        let params = BeaconChainParams::from_eth_chain_id(&mcid.to_eth_chain_id()?)?;
+        let bootstrap = LightClientBootstrap::from_str(bootstrap_json)?;
+        let store = LightClientStore::from_bootstrap(params, bootstrap, trusted_block_root)?;
+        db_utils.put_light_client_store(&mcid, &store)?;
+        Ok(store)
+    }
+
     // TODO factor out the pruning and the saving of the block since it's used in the insert fxn
     // too.
     pub fn init<D: DatabaseInterface>(
@@ -190,7 +232,9 @@ impl Chain {
         let c = Self::new(hub, tail_length, confirmations, sub_mat.clone(), mcid)?;
 
         // NOTE: Now we can prune the sub mat's receipts...
-        let pruned_sub_mat = sub_mat.remove_receipts_if_no_logs_from_addresses(&[hub]);
+        let pruned_sub_mat = sub_mat
+            .remove_receipts_if_no_logs_from_addresses(&[hub])
+            .remove_light_client_update_and_receipt_proofs();
 
         // NOTE: Now we save the block itself in the db...
         let sub_mat_bytes = serde_json::to_vec(&pruned_sub_mat)?;
@@ -218,6 +262,7 @@ impl Chain {
         Ok(Self {
             hub,
             chain_id,
+            other_hubs: vec![],
             offset: n,
             tail_length,
             confirmations,
@@ -303,11 +348,19 @@ impl Chain {
                 return Err(ChainError::InvalidBlock(*mcid, h, n));
             }
 
-            // NOTE Receipts may have bene prefiltered outside of the TEE
-            if !sub_mat.receipts.is_empty() {
-                if let Err(e) = sub_mat.receipts_are_valid() {
-                    error!("invalid receipts: {e}");
-                    return Err(ChainError::InvalidReceipts(*mcid, h, n));
+            // NOTE Receipts may have bene prefiltered outside of the TEE, in which case they're only
+            // validatable if they came with inclusion proofs.
+            if !sub_mat.receipts.is_empty() || sub_mat.receipt_proofs.is_some() {
+                match sub_mat.receipts_are_valid() {
+                    Ok(true) => (),
+                    Ok(false) => {
+                        error!("invalid receipts: receipts do not match receipts root");
+                        return Err(ChainError::InvalidReceipts(*mcid, h, n));
+                    },
+                    Err(e) => {
+                        error!("invalid receipts: {e}");
+                        return Err(ChainError::InvalidReceipts(*mcid, h, n));
+                    },
                 }
             }
             Ok(())
@@ -366,6 +419,9 @@ impl Chain {
         // NOTE: First lets validate the sub mat if we're required to
         Self::validate(&mcid, &sub_mat, validate)?;
 
+        // NOTE: Then advance this chain's light client, if it has one, with any update the sub mat carries.
+        Self::maybe_process_light_client_update(db_utils, &mcid, &sub_mat)?;
+
         let block_data = ChainBlockData::try_from(&sub_mat)?;
 
         // NOTE: Next we update our chain data...
@@ -394,8 +450,10 @@ impl Chain {
             }
         }?;
 
-        // NOTE: Now we prune receipts we don't care about
-        let pruned_sub_mat = sub_mat.remove_receipts_if_no_logs_from_addresses(&[self.hub]);
+        // NOTE: Now we prune receipts we don't care about, along with the submission-only fields
+        let pruned_sub_mat = sub_mat
+            .remove_receipts_if_no_logs_from_addresses(&self.hubs())
+            .remove_light_client_update_and_receipt_proofs();
         let sub_mat_bytes = serde_json::to_vec(&pruned_sub_mat)?;
 
         // NOTE: Now we save the block itself in the db...
@@ -434,6 +492,32 @@ impl Chain {
         self.save_in_db(db_utils)
     }
 
+    fn maybe_process_light_client_update<D: DatabaseInterface>(
+        db_utils: &ChainDbUtils<D>,
+        mcid: &MetadataChainId,
+        sub_mat: &EthSubMat,
+    ) -> Result<(), ChainError> {
+        match (
+            db_utils.maybe_get_light_client_store(mcid)?,
+            &sub_mat.light_client_update,
+        ) {
+            (None, Some(_)) => {
+                warn!("ignoring light client update since no light client is initialized for chain {mcid}");
+                Ok(())
+            },
+            (_, None) => Ok(()),
+            (Some(store), Some(update)) => {
+                debug!("processing light client update for chain {mcid}...");
+                let updated_store = store.process_update(update)?;
+                debug!(
+                    "light client for chain {mcid} finalized execution block num: {}",
+                    updated_store.get_finalized_execution_block_number()?
+                );
+                db_utils.put_light_client_store(mcid, &updated_store)
+            },
+        }
+    }
+
     fn sub_mat_to_db_key(&self, sub_mat: &EthSubMat) -> Result<DbKey, ChainError> {
         let block_num = Self::block_num(sub_mat)?;
         let block_hash = Self::block_hash(sub_mat)?;
@@ -508,7 +592,9 @@ impl Chain {
         let reset_block_data = ChainBlockData::try_from(&sub_mat)?;
 
         let key = chain.sub_mat_to_db_key(&sub_mat)?;
-        let pruned_sub_mat = sub_mat.remove_receipts_if_no_logs_from_addresses(&[chain.hub]);
+        let pruned_sub_mat = sub_mat
+            .remove_receipts_if_no_logs_from_addresses(&chain.hubs())
+            .remove_light_client_update_and_receipt_proofs();
         let value = serde_json::to_vec(&pruned_sub_mat)?;
         db_utils
             .db()
@@ -596,11 +682,73 @@ impl Chain {
         }
     }
 
+    /// Returns whether the canon block candidate may be treated as canonical. If this chain has no
+    /// light client there are no finality constraints, otherwise the candidate must be no higher
+    /// than the light client's finalized execution block, and must be an ancestor of that block
+    /// within this chain.
+    fn canon_block_is_finalized_by_light_client<D: DatabaseInterface>(
+        &self,
+        db_utils: &ChainDbUtils<D>,
+        canon_hash: &EthHash,
+    ) -> Result<bool, ChainError> {
+        let mcid = self.chain_id();
+        let store = match db_utils.maybe_get_light_client_store(mcid)? {
+            None => return Ok(true),
+            Some(store) => store,
+        };
+
+        let finalized_num = store.get_finalized_execution_block_number()?;
+        let finalized_hash = store.get_finalized_execution_block_hash()?;
+        let canon_index = (self.confirmations as usize).saturating_sub(1);
+        let canon_num = match self.offset.checked_sub(canon_index as u64) {
+            Some(n) => n,
+            None => {
+                debug!("chain {mcid} is not yet long enough to have a canon block");
+                return Ok(false);
+            },
+        };
+
+        if canon_num > finalized_num {
+            debug!("canon block num {canon_num} on chain {mcid} not yet finalized (finalized: {finalized_num})");
+            return Ok(false);
+        };
+        if finalized_num > self.offset {
+            debug!("chain {mcid} has not yet reached finalized block num {finalized_num}");
+            return Ok(false);
+        };
+
+        // NOTE: Walk back from the finalized block to the canon candidate's height via parent hashes.
+        let finalized_index = (self.offset - finalized_num) as usize;
+        let mut hash = finalized_hash;
+        for i in finalized_index..canon_index {
+            match self.chain.get(i).and_then(|ds| ds.iter().find(|d| d.hash() == &hash)) {
+                Some(d) => hash = *d.parent_hash(),
+                None => {
+                    warn!("chain {mcid} does not contain light client finalized block num {finalized_num}");
+                    return Ok(false);
+                },
+            }
+        }
+
+        let is_finalized = self
+            .chain
+            .get(canon_index)
+            .map(|ds| ds.iter().any(|d| d.hash() == &hash) && &hash == canon_hash)
+            .unwrap_or_default();
+        if !is_finalized {
+            warn!("canon block candidate on chain {mcid} is not an ancestor of the light client finalized block");
+        };
+        Ok(is_finalized)
+    }
+
     pub fn get_canonical_sub_mat<D: DatabaseInterface>(
         &self,
         db_utils: &ChainDbUtils<D>,
     ) -> Result<Option<EthSubMat>, ChainError> {
         if let Ok(Some(hash)) = self.get_canonical_block_hash() {
+            if !self.canon_block_is_finalized_by_light_client(db_utils, &hash)? {
+                return Ok(None);
+            };
             let key = DbKey::from(self.chain_id(), hash)?;
             let sub_mat = db_utils
                 .db()
@@ -620,9 +768,18 @@ impl Chain {
 #[cfg(test)]
 mod tests {
     use common::test_utils::get_test_database;
+    use common_chain_ids::EthChainId;
 
     use super::*;
-    use crate::{chain::ChainState, test_utils::get_sequential_eth_blocks_and_receipts};
+    use crate::{
+        chain::ChainState,
+        test_utils::get_sequential_eth_blocks_and_receipts,
+        EthReceiptProofs,
+        ExecutionPayloadHeader,
+        LightClientHeader,
+        LightClientUpdate,
+        SyncCommittee,
+    };
 
     #[test]
     fn should_init_core_correctly() {
@@ -638,6 +795,19 @@ mod tests {
         Chain::init(&db_utils, hub, tail_length, confirmations, sub_mat, mcid, validate).unwrap();
     }
 
+    #[test]
+    fn should_set_other_hubs_excluding_duplicates_and_own_hub() {
+        let sub_mat = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let mcid = MetadataChainId::EthereumMainnet;
+        let hub = EthAddress::random();
+        let other_hub = EthAddress::random();
+        let mut chain = Chain::new(hub, 2, 3, sub_mat, mcid).unwrap();
+        assert_eq!(chain.hubs(), vec![hub]);
+        chain.set_other_hubs(&[hub, other_hub, other_hub]);
+        assert_eq!(chain.other_hubs(), &vec![other_hub]);
+        assert_eq!(chain.hubs(), vec![hub, other_hub]);
+    }
+
     #[test]
     fn should_get_not_initted_error() {
         let db = get_test_database();
@@ -896,4 +1066,114 @@ mod tests {
         assert_eq!(*chain.confirmations(), new_confs);
         assert_eq!(chain.latest_block_timestamp(), &sub_mats[0].get_timestamp());
     }
+
+    fn get_light_client_store_finalizing(sub_mat: &EthSubMat) -> LightClientStore {
+        LightClientStore {
+            params: BeaconChainParams::from_eth_chain_id(&EthChainId::Mainnet).unwrap(),
+            finalized_header: LightClientHeader {
+                execution: Some(ExecutionPayloadHeader {
+                    block_number: sub_mat.get_block_number().unwrap().as_u64(),
+                    block_hash: sub_mat.get_block_hash().unwrap(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            current_sync_committee: SyncCommittee::default(),
+            next_sync_committee: None,
+        }
+    }
+
+    #[test]
+    fn should_only_get_canonical_sub_mat_once_finalized_by_light_client() {
+        let sub_mats = get_sequential_eth_blocks_and_receipts();
+        let mcid = MetadataChainId::EthereumMainnet;
+        let validate = true;
+        let hub = EthAddress::zero();
+        let db = get_test_database();
+        let db_utils = ChainDbUtils::new(&db);
+        let confirmations = 2;
+        let tail_length = 2;
+
+        Chain::init(
+            &db_utils,
+            hub,
+            tail_length,
+            confirmations,
+            sub_mats[0].clone(),
+            mcid,
+            validate,
+        )
+        .unwrap();
+        let mut chain = Chain::get(&db_utils, mcid).unwrap();
+        chain.insert(&db_utils, sub_mats[1].clone(), validate).unwrap();
+        chain.insert(&db_utils, sub_mats[2].clone(), validate).unwrap();
+        let expected_hash = sub_mats[1].get_block_hash().unwrap();
+        let canon_hash = |c: &Chain| {
+            c.get_canonical_sub_mat(&db_utils)
+                .unwrap()
+                .map(|m| m.get_block_hash().unwrap())
+        };
+        assert_eq!(canon_hash(&chain), Some(expected_hash));
+
+        db_utils
+            .put_light_client_store(&mcid, &get_light_client_store_finalizing(&sub_mats[0]))
+            .unwrap();
+        assert_eq!(canon_hash(&chain), None);
+
+        db_utils
+            .put_light_client_store(&mcid, &get_light_client_store_finalizing(&sub_mats[2]))
+            .unwrap();
+        assert_eq!(canon_hash(&chain), Some(expected_hash));
+    }
+
+    #[test]
+    fn should_not_finalize_canon_block_of_chain_shorter_than_confirmations() {
+        let sub_mats = get_sequential_eth_blocks_and_receipts();
+        let mcid = MetadataChainId::EthereumMainnet;
+        let db = get_test_database();
+        let db_utils = ChainDbUtils::new(&db);
+        Chain::init(&db_utils, EthAddress::zero(), 2, 2, sub_mats[0].clone(), mcid, true).unwrap();
+        db_utils
+            .put_light_client_store(&mcid, &get_light_client_store_finalizing(&sub_mats[0]))
+            .unwrap();
+        let mut chain = Chain::get(&db_utils, mcid).unwrap();
+        chain.confirmations = chain.offset + 2;
+        let hash = sub_mats[0].get_block_hash().unwrap();
+        let result = chain
+            .canon_block_is_finalized_by_light_client(&db_utils, &hash)
+            .unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_save_light_client_update_or_receipt_proofs_in_db() {
+        let sub_mats = get_sequential_eth_blocks_and_receipts();
+        let mcid = MetadataChainId::EthereumMainnet;
+        let validate = false;
+        let db = get_test_database();
+        let db_utils = ChainDbUtils::new(&db);
+        Chain::init(&db_utils, EthAddress::zero(), 2, 2, sub_mats[0].clone(), mcid, validate).unwrap();
+        let mut sub_mat = sub_mats[1].clone();
+        sub_mat.light_client_update = Some(LightClientUpdate::default());
+        sub_mat.receipt_proofs = Some(EthReceiptProofs::default());
+        let mut chain = Chain::get(&db_utils, mcid).unwrap();
+        chain.insert(&db_utils, sub_mat.clone(), validate).unwrap();
+        let key = DbKey::from(&mcid, sub_mat.get_block_hash().unwrap()).unwrap();
+        let bytes = db.get(key.to_vec(), MIN_DATA_SENSITIVITY_LEVEL).unwrap();
+        let result = serde_json::from_slice::<EthSubMat>(&bytes).unwrap();
+        assert!(result.light_client_update.is_none());
+        assert!(result.receipt_proofs.is_none());
+    }
+
+    #[test]
+    fn should_not_init_light_client_for_uninitialized_chain() {
+        let db = get_test_database();
+        let db_utils = ChainDbUtils::new(&db);
+        let mcid = MetadataChainId::EthereumMainnet;
+        match Chain::init_light_client(&db_utils, mcid, "{}", &EthHash::zero()) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(ChainError::NotInitialized(id)) => assert_eq!(id, mcid),
+            Err(e) => panic!("wrong error received {e}"),
+        }
+    }
 }
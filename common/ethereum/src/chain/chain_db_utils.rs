@@ -1,11 +1,17 @@
-use common::{crypto_utils::keccak_hash_bytes, DatabaseInterface, MAX_DATA_SENSITIVITY_LEVEL};
+use common::{
+    crypto_utils::keccak_hash_bytes,
+    DatabaseInterface,
+    MAX_DATA_SENSITIVITY_LEVEL,
+    MIN_DATA_SENSITIVITY_LEVEL,
+};
+use common_metadata::MetadataChainId;
 use derive_getters::Getters;
 use derive_more::Constructor;
 use ethereum_types::Address as EthAddress;
 use function_name::named;
 
 use super::ChainError;
-use crate::EthPrivateKey;
+use crate::{EthPrivateKey, LightClientStore};
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, Constructor)]
 pub struct ChainDbUtils<'a, D: DatabaseInterface> {
@@ -16,7 +22,7 @@ const DB_KEY_PREFIX: &str = "ChainDbUtils::";
 
 impl<'a, D: DatabaseInterface> ChainDbUtils<'a, D> {
     #[named]
-    fn pk_db_key(&self) -> Vec<u8> {
+    pub fn pk_db_key(&self) -> Vec<u8> {
         let s = format!("{DB_KEY_PREFIX}{}", function_name!());
         keccak_hash_bytes(s.as_bytes()).as_bytes().to_vec()
     }
@@ -42,6 +48,45 @@ impl<'a, D: DatabaseInterface> ChainDbUtils<'a, D> {
     pub fn get_signing_address(&self) -> Result<EthAddress, ChainError> {
         self.get_pk().map(|pk| pk.to_address())
     }
+
+    #[named]
+    pub fn light_client_store_db_key(&self, mcid: &MetadataChainId) -> Result<Vec<u8>, ChainError> {
+        let s = format!("{DB_KEY_PREFIX}{}", function_name!());
+        let mcid_bytes = mcid.to_bytes().map_err(|e| {
+            error!("{e}");
+            ChainError::CouldNotGetChainIdBytes(*mcid)
+        })?;
+        Ok(keccak_hash_bytes(&[s.as_bytes(), &mcid_bytes].concat())
+            .as_bytes()
+            .to_vec())
+    }
+
+    pub fn maybe_get_light_client_store(&self, mcid: &MetadataChainId) -> Result<Option<LightClientStore>, ChainError> {
+        match self
+            .db()
+            .get(self.light_client_store_db_key(mcid)?, MIN_DATA_SENSITIVITY_LEVEL)
+        {
+            Ok(bytes) => Ok(Some(LightClientStore::from_bytes(&bytes)?)),
+            Err(e) if e.is_db_key_not_found() => Ok(None),
+            Err(e) => {
+                error!("{e}");
+                Err(ChainError::DbGet(format!("light client store for chain {mcid}")))
+            },
+        }
+    }
+
+    pub fn put_light_client_store(&self, mcid: &MetadataChainId, store: &LightClientStore) -> Result<(), ChainError> {
+        self.db()
+            .put(
+                self.light_client_store_db_key(mcid)?,
+                store.to_bytes()?,
+                MIN_DATA_SENSITIVITY_LEVEL,
+            )
+            .map_err(|e| {
+                error!("{e}");
+                ChainError::DbInsert(format!("light client store for chain {mcid}"))
+            })
+    }
 }
 
 #[cfg(test)]
@@ -58,4 +103,27 @@ mod tests {
         let expected_key = "709a4eefe510dc3c3df2265c9d5a109ba8e666882e917c4aeb3df67fdfadefcf";
         assert_eq!(key, expected_key);
     }
+
+    #[test]
+    fn light_client_store_db_keys_should_differ_per_chain() {
+        let db = get_test_database();
+        let db_utils = ChainDbUtils::new(&db);
+        let eth_key = db_utils
+            .light_client_store_db_key(&MetadataChainId::EthereumMainnet)
+            .unwrap();
+        let sepolia_key = db_utils
+            .light_client_store_db_key(&MetadataChainId::EthereumSepolia)
+            .unwrap();
+        assert_ne!(eth_key, sepolia_key);
+    }
+
+    #[test]
+    fn should_get_no_light_client_store_if_none_initialized() {
+        let db = get_test_database();
+        let db_utils = ChainDbUtils::new(&db);
+        let result = db_utils
+            .maybe_get_light_client_store(&MetadataChainId::EthereumMainnet)
+            .unwrap();
+        assert!(result.is_none());
+    }
 }
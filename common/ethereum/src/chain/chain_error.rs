@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use thiserror::Error;
 
+use crate::LightClientError;
+
 #[derive(Debug, Eq, PartialEq, Error, Clone, Serialize, Deserialize)]
 pub enum ChainError {
     #[error("could not put pk in db")]
@@ -94,6 +96,9 @@ pub enum ChainError {
 
     #[error("no chain block data in chain vecdeque @ index: {0}")]
     NoChainBlockData(u64),
+
+    #[error("light client error: {0}")]
+    LightClient(String),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Constructor, Getters)]
@@ -109,6 +114,12 @@ impl fmt::Display for NoParentError {
     }
 }
 
+impl From<LightClientError> for ChainError {
+    fn from(e: LightClientError) -> ChainError {
+        ChainError::LightClient(format!("{e}"))
+    }
+}
+
 impl From<serde_json::Error> for ChainError {
     fn from(e: serde_json::Error) -> ChainError {
         ChainError::SerdeJson(format!("{e}"))
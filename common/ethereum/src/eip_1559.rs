@@ -8,20 +8,22 @@ pub struct Eip1559 {}
 
 impl Eip1559 {
     pub fn get_activation_block_number(&self, eth_chain_id: &EthChainId) -> Result<U256> {
-        match eth_chain_id {
-            EthChainId::Mainnet => Ok(U256::from(12_965_000)),
-            EthChainId::Ropsten => Ok(U256::from(10_499_401)),
-            _ => Err(format!("{} does not have an `EIP1559` activation block number! ", eth_chain_id).into()),
+        match eth_chain_id
+            .get_registry_entry()
+            .and_then(|entry| entry.eip_1559_activation_block)
+        {
+            Some(block_number) => Ok(U256::from(block_number)),
+            None => Err(format!("{} does not have an `EIP1559` activation block number! ", eth_chain_id).into()),
         }
     }
 
     pub fn is_active(&self, eth_chain_id: &EthChainId, block_number: U256) -> Result<bool> {
-        match eth_chain_id {
-            EthChainId::Mainnet | EthChainId::Ropsten => {
-                Ok(block_number >= self.get_activation_block_number(eth_chain_id)?)
-            },
-            EthChainId::Goerli | EthChainId::Sepolia => Ok(true),
-            _ => Ok(false),
+        match eth_chain_id
+            .get_registry_entry()
+            .and_then(|entry| entry.eip_1559_activation_block)
+        {
+            Some(activation_block_number) => Ok(block_number >= U256::from(activation_block_number)),
+            None => Ok(false),
         }
     }
 }
@@ -55,4 +57,20 @@ mod tests {
         let chain_id = EthChainId::Mainnet;
         eip_1559.is_active(&chain_id, block_number).unwrap();
     }
+
+    #[test]
+    fn eip_1559_should_always_be_active_on_chains_with_zero_activation_block() {
+        let eip_1559 = Eip1559::new();
+        let chain_id = EthChainId::Sepolia;
+        let result = eip_1559.is_active(&chain_id, U256::zero()).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn eip_1559_should_not_be_active_on_chains_without_activation_block() {
+        let eip_1559 = Eip1559::new();
+        let chain_id = EthChainId::InterimChain;
+        let result = eip_1559.is_active(&chain_id, U256::from(u64::MAX)).unwrap();
+        assert!(!result);
+    }
 }
@@ -1,4 +1,7 @@
+use common::types::Result;
+use common_chain_ids::EthChainId;
 use derive_more::Constructor;
+use ethereum_types::U256;
 
 use crate::EthBlock;
 
@@ -9,6 +12,16 @@ impl Eip4844 {
     pub fn is_active(&self, block: &EthBlock) -> bool {
         block.blob_gas_used.is_some()
     }
+
+    pub fn get_activation_block_number(&self, eth_chain_id: &EthChainId) -> Result<U256> {
+        match eth_chain_id
+            .get_registry_entry()
+            .and_then(|entry| entry.eip_4844_activation_block)
+        {
+            Some(block_number) => Ok(U256::from(block_number)),
+            None => Err(format!("{} does not have an `EIP4844` activation block number! ", eth_chain_id).into()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -24,6 +37,14 @@ mod tests {
         assert!(result);
     }
 
+    #[test]
+    fn should_get_eip_4844_activation_block_number() {
+        let eip_4844 = Eip4844::new();
+        let result = eip_4844.get_activation_block_number(&EthChainId::Sepolia).unwrap();
+        let expected_result = U256::from(5_187_023);
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn eip_4844_should_not_be_active() {
         let eip_4844 = Eip4844::new();
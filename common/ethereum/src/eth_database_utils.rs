@@ -21,6 +21,26 @@ pub fn get_u64_from_db<D: DatabaseInterface>(db: &D, key: &[Byte]) -> Result<u64
         .and_then(|ref bytes| convert_bytes_to_u64(bytes))
 }
 
+/// Gets the latest block numbers of both the ETH & EVM sides of a core, in two round trips to the db.
+pub fn get_latest_eth_and_evm_block_numbers<D: DatabaseInterface>(db: &D) -> Result<(usize, usize)> {
+    info!("✔ Getting latest ETH & EVM block numbers from db...");
+    let hash_keys = vec![
+        EthDbUtils::new(db).get_eth_latest_block_hash_key(),
+        EvmDbUtils::new(db).get_eth_latest_block_hash_key(),
+    ];
+    // NOTE: Block hashes are stored in the same form as the keys the blocks are stored under.
+    let block_keys = db.get_multiple(hash_keys, MIN_DATA_SENSITIVITY_LEVEL)?;
+    let block_numbers = db
+        .get_multiple(block_keys, MIN_DATA_SENSITIVITY_LEVEL)?
+        .iter()
+        .map(|bytes| Ok(EthSubmissionMaterial::from_bytes(bytes)?.get_block_number()?.as_usize()))
+        .collect::<Result<Vec<usize>>>()?;
+    match block_numbers[..] {
+        [eth_block_number, evm_block_number] => Ok((eth_block_number, evm_block_number)),
+        _ => Err("✘ Wrong number of latest block numbers retrieved from db!".into()),
+    }
+}
+
 create_db_utils_with_getters!(
     "Eth";
     "_CHAIN_ID_KEY" => "eth-chain-id",
@@ -363,8 +383,8 @@ pub trait EthDbUtilsExt<D: DatabaseInterface> {
         self.get_special_eth_hash_from_db("anchor")
     }
 
-    fn get_special_eth_hash_from_db(&self, hash_type: &str) -> Result<EthHash> {
-        let key = match hash_type {
+    fn get_special_eth_hash_key(&self, hash_type: &str) -> Result<Bytes> {
+        match hash_type {
             "linker" => Ok(self.get_eth_linker_hash_key()),
             "canon" => Ok(self.get_eth_canon_block_hash_key()),
             "tail" => Ok(self.get_eth_tail_block_hash_key()),
@@ -374,9 +394,49 @@ pub trait EthDbUtilsExt<D: DatabaseInterface> {
                 "✘ Cannot get ETH special hash of type: {}!",
                 hash_type
             ))),
-        }?;
+        }
+    }
+
+    fn get_special_eth_hash_from_db(&self, hash_type: &str) -> Result<EthHash> {
+        let key = self.get_special_eth_hash_key(hash_type)?;
         debug!("✔ Getting special ETH hash from db of type: {}", hash_type);
-        self.get_eth_hash_from_db(&key.to_vec())
+        self.get_eth_hash_from_db(&key)
+    }
+
+    fn get_special_eth_hashes_from_db(&self, hash_types: &[&str]) -> Result<Vec<EthHash>> {
+        debug!(
+            "✔ Getting special ETH hashes from db of types: {}",
+            hash_types.join(", ")
+        );
+        let keys = hash_types
+            .iter()
+            .map(|hash_type| self.get_special_eth_hash_key(hash_type))
+            .collect::<Result<Vec<Bytes>>>()?;
+        Ok(self
+            .get_db()
+            .get_multiple(keys, MIN_DATA_SENSITIVITY_LEVEL)?
+            .into_iter()
+            .map(|bytes| EthHash::from_slice(&self.normalize_key(bytes)))
+            .collect())
+    }
+
+    // NOTE: This takes two round trips to the db, rather than two per block as getting each
+    // special block individually would.
+    fn get_special_eth_blocks_from_db(&self, block_types: &[&str]) -> Result<Vec<EthSubmissionMaterial>> {
+        debug!(
+            "✔ Getting special ETH blocks from db of types: {}",
+            block_types.join(", ")
+        );
+        let keys = self
+            .get_special_eth_hashes_from_db(block_types)?
+            .into_iter()
+            .map(|block_hash| self.normalize_key(convert_h256_to_bytes(block_hash)))
+            .collect::<Vec<Bytes>>();
+        self.get_db()
+            .get_multiple(keys, MIN_DATA_SENSITIVITY_LEVEL)?
+            .iter()
+            .map(|bytes| EthSubmissionMaterial::from_bytes(bytes))
+            .collect()
     }
 
     #[cfg(test)]
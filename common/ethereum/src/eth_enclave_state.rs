@@ -8,7 +8,7 @@ use common_safe_addresses::{SAFE_ETH_ADDRESS, SAFE_EVM_ADDRESS};
 use ethereum_types::Address as EthAddress;
 use serde::{Deserialize, Serialize};
 
-use crate::{eth_constants::ETH_TAIL_LENGTH, eth_database_utils::EthDbUtilsExt};
+use crate::{eth_constants::ETH_TAIL_LENGTH, eth_database_utils::EthDbUtilsExt, EthSubmissionMaterial};
 
 macro_rules! make_enclave_state_struct {
     ($name:ident, $prefix:ident) => {
@@ -54,10 +54,15 @@ macro_rules! make_enclave_state_struct {
                     router_address: Option<EthAddress>,
                 ) -> Result<Self> {
                     info!("✔ Getting {} enclave state...", if db_utils.get_is_for_eth() { "ETH" } else { "EVM "});
-                    let [<$prefix:lower _tail_block>] = db_utils.get_eth_tail_block_from_db()?;
-                    let [<$prefix:lower _canon_block>] = db_utils.get_eth_canon_block_from_db()?;
-                    let [<$prefix:lower _anchor_block>] = db_utils.get_eth_anchor_block_from_db()?;
-                    let [<$prefix:lower _latest_block>] = db_utils.get_eth_latest_block_from_db()?;
+                    let [
+                        [<$prefix:lower _tail_block>],
+                        [<$prefix:lower _canon_block>],
+                        [<$prefix:lower _anchor_block>],
+                        [<$prefix:lower _latest_block>],
+                    ]: [EthSubmissionMaterial; 4] = db_utils
+                        .get_special_eth_blocks_from_db(&["tail", "canon", "anchor", "latest"])?
+                        .try_into()
+                        .map_err(|_| "✘ Wrong number of special blocks retrieved from db!")?;
                     let safe_address = if stringify!($prefix:lower) == "native" || stringify!($prefix:lower) == "eth" {
                         hex::encode(SAFE_ETH_ADDRESS.as_bytes())
                     } else {
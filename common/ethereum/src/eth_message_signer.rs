@@ -1,8 +1,10 @@
 use common::{
     traits::DatabaseInterface,
     types::Result,
-    utils::{decode_hex_with_err_msg, is_hex},
+    utils::{convert_hex_to_eth_address, decode_hex_with_err_msg, is_hex},
 };
+use eip_712::{hash_structured_data, EIP712};
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
 use serde_json::{json, Value as JsonValue};
 
 use crate::{EthDbUtils, EthDbUtilsExt, EthSignature, EthSigningCapabilities, EvmDbUtils};
@@ -12,6 +14,97 @@ fn encode_eth_signed_message_as_json(message: &str, signature: &EthSignature) ->
     json!({"message": message, "signature": format!("0x{}", hex::encode(&signature[..]))})
 }
 
+fn encode_eth_signed_typed_data_as_json(typed_data: JsonValue, hash: &EthHash, signature: &EthSignature) -> JsonValue {
+    info!("✔ Encoding eth signed typed data as json...");
+    json!({
+        "message": typed_data,
+        "hash": format!("0x{}", hex::encode(hash)),
+        "signature": format!("0x{}", hex::encode(&signature[..])),
+    })
+}
+
+/// Returns the EIP-712 hash of the passed in typed data, which is what gets signed.
+pub fn hash_eip_712_typed_data(typed_data: &JsonValue) -> Result<EthHash> {
+    let eip_712_typed_data: EIP712 = serde_json::from_value(typed_data.clone())?;
+    Ok(EthHash::from_slice(&hash_structured_data(eip_712_typed_data)?))
+}
+
+fn get_eip_712_domain_chain_id(domain: &JsonValue) -> Result<u64> {
+    let maybe_chain_id = match &domain["chainId"] {
+        JsonValue::Number(n) => n.as_u64(),
+        JsonValue::String(s) => match s.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => s.parse::<u64>().ok(),
+        },
+        _ => None,
+    };
+    maybe_chain_id.ok_or_else(|| "EIP-712 domain has no valid `chainId`!".into())
+}
+
+fn get_eip_712_domain_verifying_contract(domain: &JsonValue) -> Result<EthAddress> {
+    match &domain["verifyingContract"] {
+        JsonValue::String(s) => convert_hex_to_eth_address(s),
+        _ => Err("EIP-712 domain has no `verifyingContract`!".into()),
+    }
+}
+
+fn eip_712_domain_type_declares_field(typed_data: &JsonValue, name: &str, type_: &str) -> bool {
+    typed_data["types"]["EIP712Domain"]
+        .as_array()
+        .map(|fields| fields.iter().any(|f| f["name"] == name && f["type"] == type_))
+        .unwrap_or(false)
+}
+
+// NOTE: Domain values that aren't declared in the `EIP712Domain` type are not part of the hash that
+// gets signed, so checking them alone would let a signature be replayed on other chains & contracts.
+fn check_eip_712_domain_type(typed_data: &JsonValue) -> Result<()> {
+    if !eip_712_domain_type_declares_field(typed_data, "chainId", "uint256") {
+        Err("EIP-712 domain type does not declare `chainId` as a `uint256`!".into())
+    } else if !eip_712_domain_type_declares_field(typed_data, "verifyingContract", "address") {
+        Err("EIP-712 domain type does not declare `verifyingContract` as an `address`!".into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that the EIP-712 domain of the passed in typed data is for the passed in chain ID, & that
+/// its `verifyingContract` is one of the passed in allowed contracts, so that a key can only ever
+/// sign typed data that's meant for contracts it's supposed to be signing for. Both fields must
+/// also be declared in the `EIP712Domain` type, since otherwise they're not included in the hash.
+pub fn check_eip_712_domain(typed_data: &JsonValue, chain_id: u64, allowed_contracts: &[EthAddress]) -> Result<()> {
+    check_eip_712_domain_type(typed_data)?;
+    let domain = &typed_data["domain"];
+    let domain_chain_id = get_eip_712_domain_chain_id(domain)?;
+    let verifying_contract = get_eip_712_domain_verifying_contract(domain)?;
+    if domain_chain_id != chain_id {
+        Err(format!("EIP-712 domain chain ID {domain_chain_id} does not match expected chain ID {chain_id}!").into())
+    } else if !allowed_contracts.contains(&verifying_contract) {
+        Err(format!("EIP-712 domain verifying contract {verifying_contract:?} is not allowed!").into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Signs the passed in EIP-712 typed data with the private key from the passed in db utils, once
+/// its domain has been checked against that key's chain ID & the passed in allowed contracts.
+pub fn sign_typed_data<D: DatabaseInterface, E: EthDbUtilsExt<D>>(
+    db_utils: &E,
+    typed_data_json: &str,
+    allowed_contracts: &[EthAddress],
+) -> Result<String> {
+    let typed_data: JsonValue = serde_json::from_str(typed_data_json)?;
+    check_eip_712_domain(
+        &typed_data,
+        db_utils.get_eth_chain_id_from_db()?.to_u64(),
+        allowed_contracts,
+    )?;
+    let hash = hash_eip_712_typed_data(&typed_data)?;
+    db_utils
+        .get_eth_private_key_from_db()
+        .and_then(|key| key.sign_hash_and_set_eth_recovery_param(hash))
+        .map(|signature| encode_eth_signed_typed_data_as_json(typed_data, &hash, &signature).to_string())
+}
+
 fn sign_message_with_no_eth_prefix<D: DatabaseInterface, E: EthDbUtilsExt<D>>(
     db_utils: &E,
     message: &str,
@@ -168,9 +261,46 @@ pub fn sign_hex_msg_with_evm_key_with_prefix<D: DatabaseInterface>(db: &D, messa
 #[cfg(test)]
 mod tests {
     use common::{errors::AppError, test_utils::get_test_database};
+    use common_chain_ids::EthChainId;
 
     use super::*;
-    use crate::{eth_database_utils::EthDbUtils, test_utils::get_sample_eth_private_key};
+    use crate::{eth_database_utils::EthDbUtils, test_utils::get_sample_eth_private_key, EthPrivateKey};
+
+    // NOTE: The example from the EIP itself: https://eips.ethereum.org/EIPS/eip-712
+    fn get_sample_typed_data_json() -> String {
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Person": [
+                    { "name": "name", "type": "string" },
+                    { "name": "wallet", "type": "address" }
+                ],
+                "Mail": [
+                    { "name": "from", "type": "Person" },
+                    { "name": "to", "type": "Person" },
+                    { "name": "contents", "type": "string" }
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": "0x1",
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                "contents": "Hello, Bob!"
+            }
+        })
+        .to_string()
+    }
 
     #[test]
     fn ascii_signer_should_return_error_if_message_is_not_valid_ascii() {
@@ -290,4 +420,99 @@ mod tests {
         let result = sign_ascii_msg_with_prefix(&db, message, is_for_eth).unwrap();
         assert_eq!(result, expected_result, "✘ Message signature is invalid!")
     }
+
+    fn get_sample_verifying_contract() -> EthAddress {
+        convert_hex_to_eth_address("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC").unwrap()
+    }
+
+    #[test]
+    fn should_sign_typed_data() {
+        let db = get_test_database();
+        let eth_db_utils = EthDbUtils::new(&db);
+        let eth_private_key = EthPrivateKey::from_slice(
+            &hex::decode("c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4").unwrap(),
+        )
+        .unwrap();
+        eth_db_utils.put_eth_private_key_in_db(&eth_private_key).unwrap();
+        eth_db_utils.put_eth_chain_id_in_db(&EthChainId::Mainnet).unwrap();
+        let allowed_contracts = vec![get_sample_verifying_contract()];
+        let result: JsonValue = serde_json::from_str(
+            &sign_typed_data(&eth_db_utils, &get_sample_typed_data_json(), &allowed_contracts).unwrap(),
+        )
+        .unwrap();
+        let expected_hash = "0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2";
+        let expected_signature = "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c";
+        assert_eq!(result["hash"], expected_hash);
+        assert_eq!(result["signature"], expected_signature);
+    }
+
+    #[test]
+    fn should_fail_to_sign_invalid_typed_data() {
+        let db = get_test_database();
+        let eth_db_utils = EthDbUtils::new(&db);
+        eth_db_utils
+            .put_eth_private_key_in_db(&get_sample_eth_private_key())
+            .unwrap();
+        eth_db_utils.put_eth_chain_id_in_db(&EthChainId::Mainnet).unwrap();
+        let invalid_typed_data = json!({ "primaryType": "Mail", "message": {} }).to_string();
+        assert!(sign_typed_data(&eth_db_utils, &invalid_typed_data, &[get_sample_verifying_contract()]).is_err());
+    }
+
+    #[test]
+    fn should_pass_eip_712_domain_check() {
+        let typed_data: JsonValue = serde_json::from_str(&get_sample_typed_data_json()).unwrap();
+        let result = check_eip_712_domain(&typed_data, 1, &[get_sample_verifying_contract()]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_fail_eip_712_domain_check_if_chain_id_is_wrong() {
+        let typed_data: JsonValue = serde_json::from_str(&get_sample_typed_data_json()).unwrap();
+        let expected_error = "EIP-712 domain chain ID 1 does not match expected chain ID 137!";
+        match check_eip_712_domain(&typed_data, 137, &[get_sample_verifying_contract()]) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(AppError::Custom(e)) => assert_eq!(e, expected_error),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_eip_712_domain_check_if_verifying_contract_is_not_allowed() {
+        let typed_data: JsonValue = serde_json::from_str(&get_sample_typed_data_json()).unwrap();
+        match check_eip_712_domain(&typed_data, 1, &[EthAddress::zero()]) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(AppError::Custom(e)) => assert!(e.contains("is not allowed")),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_eip_712_domain_check_if_domain_type_omits_checked_fields() {
+        let mut typed_data: JsonValue = serde_json::from_str(&get_sample_typed_data_json()).unwrap();
+        typed_data["types"]["EIP712Domain"] = json!([
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" }
+        ]);
+        let expected_error = "EIP-712 domain type does not declare `chainId` as a `uint256`!";
+        match check_eip_712_domain(&typed_data, 1, &[get_sample_verifying_contract()]) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(AppError::Custom(e)) => assert_eq!(e, expected_error),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_eip_712_domain_check_if_domain_type_omits_verifying_contract() {
+        let mut typed_data: JsonValue = serde_json::from_str(&get_sample_typed_data_json()).unwrap();
+        typed_data["types"]["EIP712Domain"] = json!([
+            { "name": "name", "type": "string" },
+            { "name": "chainId", "type": "uint256" }
+        ]);
+        let expected_error = "EIP-712 domain type does not declare `verifyingContract` as an `address`!";
+        match check_eip_712_domain(&typed_data, 1, &[get_sample_verifying_contract()]) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(AppError::Custom(e)) => assert_eq!(e, expected_error),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
 }
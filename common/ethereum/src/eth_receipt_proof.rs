@@ -0,0 +1,165 @@
+use common::{
+    errors::AppError,
+    types::{Bytes, Result},
+    utils::decode_prefixed_hex,
+};
+use derive_more::{Constructor, Deref};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    merkle_patricia_trie::{get_merkle_patricia_proof, verify_merkle_patricia_proof},
+    EthReceipt,
+    EthReceipts,
+};
+
+/// The Merkle-Patricia trie nodes proving a single receipt's inclusion in a block's receipts trie,
+/// ordered from the root node down. (De)serialized as an array of `0x` prefixed hex strings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Constructor, Deref)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub struct EthReceiptProof(Vec<Bytes>);
+
+impl TryFrom<Vec<String>> for EthReceiptProof {
+    type Error = AppError;
+
+    fn try_from(hex_nodes: Vec<String>) -> Result<Self> {
+        Ok(Self(
+            hex_nodes
+                .iter()
+                .map(|hex| decode_prefixed_hex(hex))
+                .collect::<Result<Vec<Bytes>>>()?,
+        ))
+    }
+}
+
+impl From<EthReceiptProof> for Vec<String> {
+    fn from(proof: EthReceiptProof) -> Self {
+        proof.iter().map(|node| format!("0x{}", hex::encode(node))).collect()
+    }
+}
+
+impl EthReceiptProof {
+    pub fn verify(&self, receipt: &EthReceipt, receipts_root: &EthHash) -> Result<bool> {
+        let (key, value) = receipt.get_rlp_encoded_index_and_rlp_encoded_receipt_tuple()?;
+        Ok(verify_merkle_patricia_proof(receipts_root, &key, self)? == Some(value))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Constructor, Deref)]
+pub struct EthReceiptProofs(Vec<EthReceiptProof>);
+
+impl EthReceiptProofs {
+    /// Generates inclusion proofs for the `receipts_to_prove`, which must all be in the full set of
+    /// `receipts` for the block. Errors if that full set doesn't hash to the expected receipts root.
+    pub fn from_receipts(
+        receipts: &EthReceipts,
+        receipts_to_prove: &EthReceipts,
+        receipts_root: &EthHash,
+    ) -> Result<Self> {
+        let key_values = receipts.get_rlp_encoded_indicies_and_rlp_encoded_receipt_tuples()?;
+        receipts_to_prove
+            .iter()
+            .map(|receipt| {
+                let (key, _) = receipt.get_rlp_encoded_index_and_rlp_encoded_receipt_tuple()?;
+                let (root, proof) = get_merkle_patricia_proof(&key_values, &key);
+                if &root == receipts_root {
+                    Ok(EthReceiptProof::new(proof))
+                } else {
+                    Err(format!("✘ Cannot generate receipt proofs: calculated root {root} != {receipts_root}").into())
+                }
+            })
+            .collect::<Result<Vec<EthReceiptProof>>>()
+            .map(Self::new)
+    }
+
+    /// Checks that each receipt is proven by the proof at the same index. Receipts must be in
+    /// ascending transaction index order, which also ensures the same receipt can't appear twice.
+    pub fn verify(&self, receipts: &EthReceipts, receipts_root: &EthHash) -> Result<bool> {
+        if self.len() != receipts.len() {
+            warn!(
+                "✘ Number of receipt proofs ({}) != number of receipts ({})",
+                self.len(),
+                receipts.len()
+            );
+            return Ok(false);
+        };
+        if receipts
+            .windows(2)
+            .any(|pair| pair[0].transaction_index >= pair[1].transaction_index)
+        {
+            warn!("✘ Receipts to be proven are not in ascending transaction index order!");
+            return Ok(false);
+        };
+        for (proof, receipt) in self.iter().zip(receipts.iter()) {
+            if !proof.verify(receipt, receipts_root)? {
+                warn!("✘ Receipt proof for tx {} is invalid!", receipt.transaction_hash);
+                return Ok(false);
+            };
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_eth_submission_material_n;
+
+    fn get_sample_receipts_and_root() -> (EthReceipts, EthHash) {
+        let sub_mat = get_sample_eth_submission_material_n(1).unwrap();
+        (sub_mat.receipts.clone(), sub_mat.get_receipts_root().unwrap())
+    }
+
+    fn get_sample_receipts_to_prove(receipts: &EthReceipts) -> EthReceipts {
+        EthReceipts::new(vec![
+            receipts[0].clone(),
+            receipts[5].clone(),
+            receipts[receipts.len() - 1].clone(),
+        ])
+    }
+
+    #[test]
+    fn should_generate_and_verify_receipt_proofs() {
+        let (receipts, receipts_root) = get_sample_receipts_and_root();
+        let receipts_to_prove = get_sample_receipts_to_prove(&receipts);
+        let proofs = EthReceiptProofs::from_receipts(&receipts, &receipts_to_prove, &receipts_root).unwrap();
+        let result = proofs.verify(&receipts_to_prove, &receipts_root).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn should_fail_to_generate_proofs_if_receipts_root_is_wrong() {
+        let (receipts, _) = get_sample_receipts_and_root();
+        let receipts_to_prove = get_sample_receipts_to_prove(&receipts);
+        assert!(EthReceiptProofs::from_receipts(&receipts, &receipts_to_prove, &EthHash::zero()).is_err());
+    }
+
+    #[test]
+    fn should_not_verify_altered_receipt() {
+        let (receipts, receipts_root) = get_sample_receipts_and_root();
+        let receipts_to_prove = get_sample_receipts_to_prove(&receipts);
+        let proofs = EthReceiptProofs::from_receipts(&receipts, &receipts_to_prove, &receipts_root).unwrap();
+        let mut altered_receipts = receipts_to_prove.clone();
+        altered_receipts.0[1].status = !altered_receipts[1].status;
+        let result = proofs.verify(&altered_receipts, &receipts_root).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_duplicated_receipts() {
+        let (receipts, receipts_root) = get_sample_receipts_and_root();
+        let receipts_to_prove = EthReceipts::new(vec![receipts[0].clone(), receipts[0].clone()]);
+        let proofs = EthReceiptProofs::from_receipts(&receipts, &receipts_to_prove, &receipts_root).unwrap();
+        let result = proofs.verify(&receipts_to_prove, &receipts_root).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_serde_receipt_proofs_as_hex_strings() {
+        let proof = EthReceiptProof::new(vec![vec![0xde, 0xad], vec![0xbe, 0xef]]);
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(json, "[\"0xdead\",\"0xbeef\"]");
+        let result: EthReceiptProof = serde_json::from_str(&json).unwrap();
+        assert_eq!(result, proof);
+    }
+}
@@ -11,7 +11,16 @@ use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value as JsonValue};
 
-use crate::{EthBlock, EthBlockJson, EthReceipt, EthReceiptJson, EthReceipts, EthState};
+use crate::{
+    EthBlock,
+    EthBlockJson,
+    EthReceipt,
+    EthReceiptJson,
+    EthReceiptProofs,
+    EthReceipts,
+    EthState,
+    LightClientUpdate,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Deref, DerefMut, Constructor, Deserialize, Serialize)]
 pub struct EthSubmissionMaterials(Vec<EthSubmissionMaterial>);
@@ -96,6 +105,16 @@ pub struct EthSubmissionMaterial {
     pub receipts_root: Option<EthHash>,
     pub algo_first_valid_round: Option<u64>,
     pub timestamp: Option<U256>,
+    // NOTE: Only consumed during submission by cores with a beacon light client, and deliberately
+    // omitted from `to_json` so that it's never saved in the db with the rest of the material.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub light_client_update: Option<LightClientUpdate>,
+    // NOTE: When present, the submission material is in compact form: the `receipts` are only
+    // those pertinent to the core, and each is proven against the `receipts_root` by the proof at
+    // the same index. Note that this proves receipts are genuine, not that none were omitted. Like
+    // the light client update, it is never saved in the db.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipt_proofs: Option<EthReceiptProofs>,
 }
 
 impl PartialEq for EthSubmissionMaterial {
@@ -118,7 +137,7 @@ impl PartialEq for EthSubmissionMaterial {
             && self.eos_ref_block_num == other.eos_ref_block_num
             && self.eos_ref_block_prefix == other.eos_ref_block_prefix
             && self.algo_first_valid_round == other.algo_first_valid_round
-        // NOTE: We omit the timestamp!
+        // NOTE: We omit the timestamp, light client update & receipt proofs!
     }
 }
 
@@ -189,6 +208,8 @@ impl EthSubmissionMaterial {
             parent_hash: Some(block.parent_hash),
             receipts_root: Some(block.receipts_root),
             block: Some(block),
+            light_client_update: None,
+            receipt_proofs: None,
         }
     }
 
@@ -302,6 +323,8 @@ impl EthSubmissionMaterial {
                 eos_ref_block_prefix: json.eos_ref_block_prefix,
                 block: Some(block),
                 algo_first_valid_round: json.algo_first_valid_round,
+                light_client_update: json.light_client_update.clone(),
+                receipt_proofs: json.receipt_proofs.clone(),
             }),
             None => {
                 if json.hash.is_none() {
@@ -324,6 +347,8 @@ impl EthSubmissionMaterial {
                     eos_ref_block_num: json.eos_ref_block_num,
                     eos_ref_block_prefix: json.eos_ref_block_prefix,
                     algo_first_valid_round: json.algo_first_valid_round,
+                    light_client_update: json.light_client_update.clone(),
+                    receipt_proofs: json.receipt_proofs.clone(),
                 })
             },
         }
@@ -397,6 +422,10 @@ impl EthSubmissionMaterial {
     }
 
     pub fn receipts_are_valid(&self) -> Result<bool> {
+        if let Some(ref proofs) = self.receipt_proofs {
+            info!("✔ Verifying {} receipt proofs...", proofs.len());
+            return proofs.verify(&self.receipts, &self.get_receipts_root()?);
+        };
         self.receipts.get_merkle_root().and_then(|calculated_root| {
             let receipts_root = self.get_receipts_root()?;
             info!("✔    Block's receipts root: {}", receipts_root.to_string());
@@ -405,6 +434,45 @@ impl EthSubmissionMaterial {
         })
     }
 
+    /// Returns the compact form of this submission material, with only those receipts containing
+    /// logs from the passed in addresses, plus proofs of their inclusion in the block. Requires the
+    /// full set of receipts for the block in order to generate those proofs.
+    pub fn to_compact_with_receipts_from_addresses(&self, addresses: &[EthAddress]) -> Result<Self> {
+        if self.receipt_proofs.is_some() {
+            return Err("✘ Submission material is already in compact form!".into());
+        };
+        let pertinent_receipts = EthReceipts::new(
+            self.receipts
+                .iter()
+                .filter(|receipt| {
+                    addresses
+                        .iter()
+                        .any(|address| receipt.contains_log_from_address(address))
+                })
+                .cloned()
+                .collect(),
+        );
+        info!(
+            "✔ Compacting submission material from {} receipts to {}",
+            self.receipts.len(),
+            pertinent_receipts.len()
+        );
+        let proofs = EthReceiptProofs::from_receipts(&self.receipts, &pertinent_receipts, &self.get_receipts_root()?)?;
+        let mut mutable_self = self.clone();
+        mutable_self.receipts = pertinent_receipts;
+        mutable_self.receipt_proofs = Some(proofs);
+        Ok(mutable_self)
+    }
+
+    /// Removes the light client update & the receipt proofs, which are only consumed during
+    /// submission, so that neither is ever saved in the db along with the rest of the material.
+    pub fn remove_light_client_update_and_receipt_proofs(self) -> Self {
+        let mut mutable_self = self;
+        mutable_self.light_client_update = None;
+        mutable_self.receipt_proofs = None;
+        mutable_self
+    }
+
     pub fn remove_receipts(&self) -> Self {
         let mut mutable_self = self.clone();
         mutable_self.receipts = vec![].into();
@@ -451,6 +519,10 @@ pub struct EthSubmissionMaterialJson {
     pub parent_hash: Option<EthHash>,
     pub receipts_root: Option<EthHash>,
     pub algo_first_valid_round: Option<u64>,
+    #[serde(default)]
+    pub light_client_update: Option<LightClientUpdate>,
+    #[serde(default)]
+    pub receipt_proofs: Option<EthReceiptProofs>,
 }
 
 impl FromStr for EthSubmissionMaterialJson {
@@ -750,4 +822,51 @@ mod tests {
         let expected_result = Duration::from_secs(1567871882);
         assert_eq!(result, expected_result)
     }
+
+    #[test]
+    fn light_client_update_should_not_survive_bytes_roundtrip() {
+        let mut sub_mat = get_sample_eth_submission_material();
+        sub_mat.light_client_update = Some(LightClientUpdate::default());
+        let result = EthSubmissionMaterial::from_bytes(&sub_mat.to_bytes().unwrap()).unwrap();
+        assert!(result.light_client_update.is_none());
+    }
+
+    #[test]
+    fn compact_submission_material_receipts_should_be_valid() {
+        let sub_mat = get_sample_eth_submission_material_n(19).unwrap();
+        let addresses = vec![convert_hex_to_eth_address("0x37e1abc100676acbd5c581a9d60d914a10d08dd5").unwrap()];
+        let result = sub_mat.to_compact_with_receipts_from_addresses(&addresses).unwrap();
+        assert!(!result.receipts.is_empty());
+        assert!(result.receipts.len() < sub_mat.receipts.len());
+        assert!(result.receipts_are_valid().unwrap());
+    }
+
+    #[test]
+    fn compact_submission_material_with_no_pertinent_receipts_should_be_valid() {
+        let sub_mat = get_sample_eth_submission_material_n(19).unwrap();
+        let addresses = vec![convert_hex_to_eth_address("0xfEDFe2616EB3661CB8FEd2782F5F0cC91D59DCaC").unwrap()];
+        let result = sub_mat.to_compact_with_receipts_from_addresses(&addresses).unwrap();
+        assert!(result.receipts.is_empty());
+        assert!(result.receipts_are_valid().unwrap());
+    }
+
+    #[test]
+    fn compact_submission_material_with_omitted_proof_should_not_be_valid() {
+        let sub_mat = get_sample_eth_submission_material_n(19).unwrap();
+        let addresses = vec![convert_hex_to_eth_address("0x37e1abc100676acbd5c581a9d60d914a10d08dd5").unwrap()];
+        let mut result = sub_mat.to_compact_with_receipts_from_addresses(&addresses).unwrap();
+        result.receipt_proofs = Some(EthReceiptProofs::default());
+        assert!(!result.receipts_are_valid().unwrap());
+    }
+
+    #[test]
+    fn compact_submission_material_should_make_serde_roundtrip() {
+        let sub_mat = get_sample_eth_submission_material_n(19).unwrap();
+        let addresses = vec![convert_hex_to_eth_address("0x37e1abc100676acbd5c581a9d60d914a10d08dd5").unwrap()];
+        let compact_sub_mat = sub_mat.to_compact_with_receipts_from_addresses(&addresses).unwrap();
+        let json = serde_json::to_string(&compact_sub_mat).unwrap();
+        let result: EthSubmissionMaterial = serde_json::from_str(&json).unwrap();
+        assert_eq!(result.receipt_proofs, compact_sub_mat.receipt_proofs);
+        assert!(result.receipts_are_valid().unwrap());
+    }
 }
@@ -1,6 +1,7 @@
 mod add_block_and_receipts_to_db;
 mod any_sender;
 mod append_to_blockchain;
+mod beacon_light_client;
 mod calculate_linker_hash;
 mod chain;
 mod check_parent_exists;
@@ -21,6 +22,7 @@ mod eth_macros;
 mod eth_message_signer;
 mod eth_receipt;
 mod eth_receipt_from_json_rpc;
+mod eth_receipt_proof;
 mod eth_receipt_type;
 mod eth_state;
 mod eth_submission_material;
@@ -30,6 +32,7 @@ mod eth_utils;
 mod increment_eth_account_nonce;
 mod increment_evm_account_nonce;
 mod increment_int_account_nonce;
+mod merkle_patricia_trie;
 mod remove_old_eth_tail_block;
 mod remove_receipts_from_canon_block;
 mod update_eth_canon_block_hash;
@@ -49,6 +52,24 @@ pub use self::{
     },
     any_sender::{RelayTransaction, RelayTransactions},
     append_to_blockchain::append_to_blockchain,
+    beacon_light_client::{
+        block_is_finalized_by_light_client,
+        maybe_process_eth_light_client_update_in_state,
+        maybe_process_evm_light_client_update_in_state,
+        BeaconBlockHeader,
+        BeaconChainParams,
+        BeaconFork,
+        BlsBytes,
+        ExecutionPayloadHeader,
+        LightClientBootstrap,
+        LightClientDbUtils,
+        LightClientError,
+        LightClientHeader,
+        LightClientStore,
+        LightClientUpdate,
+        SyncAggregate,
+        SyncCommittee,
+    },
     chain::{Chain, ChainBlockData, ChainDbUtils, ChainError, ChainState, NoParentError},
     check_parent_exists::{check_for_parent_of_eth_block_in_state, check_for_parent_of_evm_block_in_state},
     core_initialization::{
@@ -130,6 +151,7 @@ pub use self::{
     },
     eth_database_transactions::{end_eth_db_transaction_and_return_state, start_eth_db_transaction_and_return_state},
     eth_database_utils::{
+        get_latest_eth_and_evm_block_numbers,
         EthDatabaseKeysJson,
         EthDbUtils,
         EthDbUtils as NativeDbUtils,
@@ -139,17 +161,21 @@ pub use self::{
         EvmDbUtils as HostDbUtils,
     },
     eth_enclave_state::{EthEnclaveState, EvmEnclaveState, HostCoreState, NativeCoreState},
-    eth_log::{EthLog, EthLogExt, EthLogs},
+    eth_log::{EthLog, EthLogExt, EthLogJson, EthLogs},
     eth_message_signer::{
+        check_eip_712_domain,
+        hash_eip_712_typed_data,
         sign_ascii_msg_with_eth_key_with_no_prefix,
         sign_ascii_msg_with_eth_key_with_prefix,
         sign_ascii_msg_with_evm_key_with_no_prefix,
         sign_ascii_msg_with_evm_key_with_prefix,
         sign_hex_msg_with_eth_key_with_prefix,
         sign_hex_msg_with_evm_key_with_prefix,
+        sign_typed_data,
     },
     eth_receipt::{EthReceipt, EthReceiptJson, EthReceipts},
     eth_receipt_from_json_rpc::EthReceiptFromJsonRpc,
+    eth_receipt_proof::{EthReceiptProof, EthReceiptProofs},
     eth_state::{EthState, EthStateCompatible},
     eth_submission_material::{
         parse_eth_submission_material_and_put_in_state,
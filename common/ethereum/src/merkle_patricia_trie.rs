@@ -0,0 +1,277 @@
+use common::{
+    crypto_utils::keccak_hash_bytes,
+    types::{Byte, Bytes, Result},
+};
+use ethereum_types::H256 as EthHash;
+use rlp::{Rlp, RlpStream};
+
+// NOTE: A minimal, in-memory Merkle-Patricia trie, sufficient for generating & verifying inclusion
+// proofs against a block's `receipts_root`. Nodes smaller than 32 bytes are embedded in their parent
+// rather than referenced by hash, which is why they never appear as elements of a proof.
+// See: https://ethereum.org/en/developers/docs/data-structures-and-encoding/patricia-merkle-trie/
+
+const BRANCH_NODE_ITEM_COUNT: usize = 17;
+const LEAF_OR_EXTENSION_NODE_ITEM_COUNT: usize = 2;
+
+fn to_nibbles(bytes: &[Byte]) -> Vec<Byte> {
+    bytes.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect()
+}
+
+fn hex_prefix_encode(nibbles: &[Byte], is_leaf: bool) -> Bytes {
+    let flag = if is_leaf { 2 } else { 0 };
+    let is_odd = nibbles.len() % 2 == 1;
+    let mut encoded = if is_odd {
+        vec![((flag + 1) << 4) | nibbles[0]]
+    } else {
+        vec![flag << 4]
+    };
+    let rest = if is_odd { &nibbles[1..] } else { nibbles };
+    rest.chunks(2).for_each(|pair| encoded.push((pair[0] << 4) | pair[1]));
+    encoded
+}
+
+fn hex_prefix_decode(bytes: &[Byte]) -> Result<(Vec<Byte>, bool)> {
+    let first = bytes.first().ok_or("✘ Cannot hex-prefix decode empty path!")?;
+    let is_leaf = first >> 4 >= 2;
+    let is_odd = (first >> 4) % 2 == 1;
+    let mut nibbles = if is_odd { vec![first & 0x0f] } else { vec![] };
+    nibbles.extend(to_nibbles(&bytes[1..]));
+    Ok((nibbles, is_leaf))
+}
+
+fn get_shared_prefix_length(a: &[Byte], b: &[Byte]) -> usize {
+    a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count()
+}
+
+fn append_node_reference(stream: &mut RlpStream, node: &[Byte]) {
+    if node.len() < 32 {
+        stream.append_raw(node, 1);
+    } else {
+        stream.append(&keccak_hash_bytes(node));
+    }
+}
+
+struct ProofBuilder<'a> {
+    target: &'a [Byte],
+    proof: Vec<Bytes>,
+}
+
+impl ProofBuilder<'_> {
+    // NOTE: Items are `(key_nibbles, value)` tuples, sorted by key. Returns the RLP encoded node
+    // for those items, recording it in the proof if it lies on the path to the target key.
+    fn build_node(&mut self, items: &[(Vec<Byte>, Bytes)], prefix_length: usize, is_on_path: bool) -> Bytes {
+        let node = if items.is_empty() {
+            rlp::NULL_RLP.to_vec()
+        } else if items.len() == 1 {
+            let mut stream = RlpStream::new_list(LEAF_OR_EXTENSION_NODE_ITEM_COUNT);
+            stream.append(&hex_prefix_encode(&items[0].0[prefix_length..], true));
+            stream.append(&items[0].1);
+            stream.out().to_vec()
+        } else {
+            let first_key = &items[0].0;
+            let shared_length = items.iter().skip(1).fold(first_key.len(), |length, (key, _)| {
+                length.min(get_shared_prefix_length(first_key, key))
+            });
+            if shared_length > prefix_length {
+                let path = &first_key[prefix_length..shared_length];
+                let child_is_on_path = is_on_path && self.target.get(prefix_length..shared_length) == Some(path);
+                let child = self.build_node(items, shared_length, child_is_on_path);
+                let mut stream = RlpStream::new_list(LEAF_OR_EXTENSION_NODE_ITEM_COUNT);
+                stream.append(&hex_prefix_encode(path, false));
+                append_node_reference(&mut stream, &child);
+                stream.out().to_vec()
+            } else {
+                let mut stream = RlpStream::new_list(BRANCH_NODE_ITEM_COUNT);
+                let mut value = None;
+                (0..16u8).for_each(|nibble| {
+                    let child_items = items
+                        .iter()
+                        .filter(|(key, _)| key.len() > prefix_length && key[prefix_length] == nibble)
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    if child_items.is_empty() {
+                        stream.append_empty_data();
+                    } else {
+                        let child_is_on_path = is_on_path && self.target.get(prefix_length) == Some(&nibble);
+                        let child = self.build_node(&child_items, prefix_length + 1, child_is_on_path);
+                        append_node_reference(&mut stream, &child);
+                    }
+                });
+                items
+                    .iter()
+                    .filter(|(key, _)| key.len() == prefix_length)
+                    .for_each(|(_, v)| value = Some(v.clone()));
+                match value {
+                    Some(v) => stream.append(&v),
+                    None => stream.append_empty_data(),
+                };
+                stream.out().to_vec()
+            }
+        };
+        // NOTE: Children are built before their parents, so the proof is reversed once complete.
+        if is_on_path && (prefix_length == 0 || node.len() >= 32) {
+            self.proof.push(node.clone());
+        };
+        node
+    }
+}
+
+/// Returns the trie root of the passed in key-value pairs, along with the nodes proving the
+/// inclusion of the target key, ordered from the root node down.
+pub fn get_merkle_patricia_proof(key_values: &[(Bytes, Bytes)], target_key: &[Byte]) -> (EthHash, Vec<Bytes>) {
+    let mut items = key_values
+        .iter()
+        .map(|(k, v)| (to_nibbles(k), v.clone()))
+        .collect::<Vec<_>>();
+    items.sort_by(|a, b| a.0.cmp(&b.0));
+    let target = to_nibbles(target_key);
+    let mut builder = ProofBuilder {
+        target: &target,
+        proof: vec![],
+    };
+    let root_node = builder.build_node(&items, 0, true);
+    let mut proof = builder.proof;
+    proof.reverse();
+    (keccak_hash_bytes(&root_node), proof)
+}
+
+enum NodeReference {
+    Hash(EthHash),
+    Inline(Bytes),
+}
+
+impl NodeReference {
+    fn from_rlp(rlp: &Rlp) -> Result<Self> {
+        if rlp.is_list() {
+            Ok(Self::Inline(rlp.as_raw().to_vec()))
+        } else {
+            let data = rlp.data()?;
+            if data.len() == 32 {
+                Ok(Self::Hash(EthHash::from_slice(data)))
+            } else {
+                Err(format!("✘ Invalid trie node reference: 0x{}", hex::encode(data)).into())
+            }
+        }
+    }
+}
+
+/// Walks the passed in proof from the root, returning the value stored under the key if the proof
+/// is valid and the key is included, `None` if the proof shows the key is absent, and an error if
+/// the proof itself is invalid.
+pub fn verify_merkle_patricia_proof(root: &EthHash, key: &[Byte], proof: &[Bytes]) -> Result<Option<Bytes>> {
+    let key_nibbles = to_nibbles(key);
+    let mut nibble_index = 0;
+    let mut proof_nodes = proof.iter();
+    let mut next_reference = NodeReference::Hash(*root);
+    loop {
+        let node = match next_reference {
+            NodeReference::Inline(bytes) => bytes,
+            NodeReference::Hash(ref hash) => {
+                let node = proof_nodes.next().ok_or("✘ Merkle-Patricia proof is incomplete!")?;
+                if &keccak_hash_bytes(node) != hash {
+                    return Err("✘ Merkle-Patricia proof node does not match its reference!".into());
+                };
+                node.clone()
+            },
+        };
+        let rlp = Rlp::new(&node);
+        match rlp.item_count()? {
+            BRANCH_NODE_ITEM_COUNT => {
+                if nibble_index == key_nibbles.len() {
+                    let value = rlp.at(16)?.data()?.to_vec();
+                    return Ok(if value.is_empty() { None } else { Some(value) });
+                };
+                let child = rlp.at(key_nibbles[nibble_index] as usize)?;
+                nibble_index += 1;
+                if child.is_empty() {
+                    return Ok(None);
+                };
+                next_reference = NodeReference::from_rlp(&child)?;
+            },
+            LEAF_OR_EXTENSION_NODE_ITEM_COUNT => {
+                let (path, is_leaf) = hex_prefix_decode(rlp.at(0)?.data()?)?;
+                let remaining = &key_nibbles[nibble_index..];
+                if is_leaf {
+                    return Ok(if remaining == path.as_slice() {
+                        Some(rlp.at(1)?.data()?.to_vec())
+                    } else {
+                        None
+                    });
+                } else if !remaining.starts_with(&path) {
+                    return Ok(None);
+                };
+                nibble_index += path.len();
+                next_reference = NodeReference::from_rlp(&rlp.at(1)?)?;
+            },
+            n => return Err(format!("✘ Invalid trie node with {n} items in Merkle-Patricia proof!").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use keccak_hasher::KeccakHasher;
+    use triehash::trie_root;
+
+    use super::*;
+
+    fn get_sample_key_values(n: usize) -> Vec<(Bytes, Bytes)> {
+        (0..n)
+            .map(|i| {
+                let mut key_stream = RlpStream::new();
+                key_stream.append(&i);
+                (key_stream.out().to_vec(), vec![i as u8; 40 + i])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_hex_prefix_encode_and_decode() {
+        let nibbles = vec![1, 2, 3];
+        let encoded = hex_prefix_encode(&nibbles, true);
+        assert_eq!(encoded, vec![0x31, 0x23]);
+        let (decoded, is_leaf) = hex_prefix_decode(&encoded).unwrap();
+        assert_eq!(decoded, nibbles);
+        assert!(is_leaf);
+        let encoded = hex_prefix_encode(&[1, 2], false);
+        assert_eq!(encoded, vec![0x00, 0x12]);
+    }
+
+    #[test]
+    fn should_calculate_same_root_as_triehash() {
+        [1, 2, 17, 130].iter().for_each(|n| {
+            let key_values = get_sample_key_values(*n);
+            let (root, _) = get_merkle_patricia_proof(&key_values, &key_values[0].0);
+            let expected_root = EthHash::from_slice(&trie_root::<KeccakHasher, _, _, _>(key_values.clone()));
+            assert_eq!(root, expected_root);
+        })
+    }
+
+    #[test]
+    fn should_generate_and_verify_proofs_for_all_keys() {
+        let key_values = get_sample_key_values(130);
+        key_values.iter().for_each(|(key, value)| {
+            let (root, proof) = get_merkle_patricia_proof(&key_values, key);
+            let result = verify_merkle_patricia_proof(&root, key, &proof).unwrap();
+            assert_eq!(result, Some(value.clone()));
+        })
+    }
+
+    #[test]
+    fn should_fail_to_verify_tampered_proof() {
+        let key_values = get_sample_key_values(20);
+        let (root, mut proof) = get_merkle_patricia_proof(&key_values, &key_values[3].0);
+        let last = proof.len() - 1;
+        let last_byte = proof[last].len() - 1;
+        proof[last][last_byte] ^= 1;
+        assert!(verify_merkle_patricia_proof(&root, &key_values[3].0, &proof).is_err());
+    }
+
+    #[test]
+    fn proof_for_one_key_should_not_prove_another() {
+        let key_values = get_sample_key_values(20);
+        let (root, proof) = get_merkle_patricia_proof(&key_values, &key_values[3].0);
+        let result = verify_merkle_patricia_proof(&root, &key_values[4].0, &proof);
+        assert!(!matches!(result, Ok(Some(_))));
+    }
+}
@@ -1,6 +1,7 @@
 use common::{traits::DatabaseInterface, types::Result};
 
 use crate::{
+    beacon_light_client::block_is_finalized_by_light_client,
     eth_database_utils::EthDbUtilsExt,
     eth_submission_material::EthSubmissionMaterial,
     EthState,
@@ -44,6 +45,10 @@ pub fn maybe_update_canon_block_hash<D: DatabaseInterface, E: EthDbUtilsExt<D>>(
         },
         Some(ancestor_block) => {
             info!("✔ {}th ancestor block found...", canon_to_tip_length);
+            if !block_is_finalized_by_light_client(db_utils, &ancestor_block)? {
+                info!("✔ Not updating canon block since light client has not finalized it yet");
+                return Ok(());
+            };
             match does_canon_block_require_updating(db_utils, &ancestor_block)? {
                 true => {
                     info!("✔ Updating canon block...");
@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use common::{
+    core_type::CoreType,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::prepend_debug_output_marker_to_string,
+};
+use common_debug_signers::validate_debug_command_signature;
+use common_eth::{
+    convert_hex_to_h256,
+    BeaconChainParams,
+    EthDbUtils,
+    EthDbUtilsExt,
+    EvmDbUtils,
+    LightClientBootstrap,
+    LightClientDbUtils,
+    LightClientStore,
+};
+use function_name::named;
+use serde_json::json;
+
+#[named]
+fn debug_init_light_client<D: DatabaseInterface>(
+    db: &D,
+    bootstrap_json: &str,
+    trusted_block_root: &str,
+    is_for_eth: bool,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    let symbol = if is_for_eth { "eth" } else { "evm" };
+    db.start_transaction()
+        .and_then(|_| {
+            get_debug_command_hash!(
+                function_name!(),
+                &bootstrap_json,
+                &trusted_block_root,
+                &is_for_eth,
+                core_type
+            )()
+        })
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| {
+            let chain_id = if is_for_eth {
+                EthDbUtils::new(db).get_eth_chain_id_from_db()?
+            } else {
+                EvmDbUtils::new(db).get_eth_chain_id_from_db()?
+            };
+            let params = BeaconChainParams::from_eth_chain_id(&chain_id)?;
+            let bootstrap = LightClientBootstrap::from_str(bootstrap_json)?;
+            let store = LightClientStore::from_bootstrap(params, bootstrap, &convert_hex_to_h256(trusted_block_root)?)?;
+            LightClientDbUtils::new(db, is_for_eth).put_store(&store)?;
+            Ok(store)
+        })
+        .and_then(|store| {
+            db.end_transaction()?;
+            Ok(json!({
+                format!("{symbol}_light_client_initialized"): true,
+                "finalized_slot": store.finalized_header.beacon.slot,
+                "finalized_execution_block_number": store.get_finalized_execution_block_number()?,
+                "finalized_execution_block_hash": store.get_finalized_execution_block_hash()?,
+            })
+            .to_string())
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// Debug Init ETH Light Client
+///
+/// This function initializes a beacon chain light client for the ETH side of the core from a
+/// `LightClientBootstrap` (as returned by a beacon node's `light_client/bootstrap` endpoint) and
+/// the beacon block root that bootstrap is for. Once initialized, submitted ETH blocks only become
+/// canon once the light client has seen them finalized, and submission material may carry light
+/// client updates in a `light_client_update` field to advance it.
+///
+/// ### BEWARE:
+/// The trusted block root is exactly that: trusted. It should be a recent finalized block root
+/// obtained from a source independent of whoever supplies the bootstrap. Calling this again
+/// replaces any existing light client, which is how to re-anchor one that has fallen more than a
+/// sync committee period behind.
+pub fn debug_init_eth_light_client<D: DatabaseInterface>(
+    db: &D,
+    bootstrap_json: &str,
+    trusted_block_root: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    info!("✔ Debug initializing ETH light client...");
+    debug_init_light_client(db, bootstrap_json, trusted_block_root, true, core_type, signature)
+}
+
+/// Debug Init EVM Light Client
+///
+/// This function initializes a beacon chain light client for the EVM side of the core. See
+/// `debug_init_eth_light_client` for more information.
+pub fn debug_init_evm_light_client<D: DatabaseInterface>(
+    db: &D,
+    bootstrap_json: &str,
+    trusted_block_root: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    info!("✔ Debug initializing EVM light client...");
+    debug_init_light_client(db, bootstrap_json, trusted_block_root, false, core_type, signature)
+}
@@ -0,0 +1,177 @@
+use common::{
+    core_type::CoreType,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::prepend_debug_output_marker_to_string,
+};
+use common_debug_signers::validate_debug_command_signature;
+use common_eth::{sign_typed_data, EthDbUtils, EthDbUtilsExt, EvmDbUtils};
+use ethereum_types::Address as EthAddress;
+use function_name::named;
+
+/// Gets the smart-contract addresses this side of the core knows about, which are the only ones
+/// whose EIP-712 domains its key may sign typed data for.
+fn get_allowed_verifying_contracts<D: DatabaseInterface, E: EthDbUtilsExt<D>>(db_utils: &E) -> Vec<EthAddress> {
+    vec![
+        db_utils.get_eth_router_smart_contract_address_from_db(),
+        db_utils.get_erc777_proxy_contract_address_from_db(),
+        db_utils.get_btc_on_eth_smart_contract_address_from_db(),
+        db_utils.get_eos_on_eth_smart_contract_address_from_db(),
+        db_utils.get_int_on_eos_smart_contract_address_from_db(),
+        db_utils.get_int_on_evm_smart_contract_address_from_db(),
+        db_utils.get_erc20_on_eos_smart_contract_address_from_db(),
+        db_utils.get_erc20_on_evm_smart_contract_address_from_db(),
+    ]
+    .into_iter()
+    .filter_map(|maybe_address| maybe_address.ok())
+    .filter(|address| !address.is_zero())
+    .collect()
+}
+
+#[named]
+fn debug_sign_typed_data<D: DatabaseInterface>(
+    db: &D,
+    typed_data_json: &str,
+    is_for_eth: bool,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), typed_data_json, &is_for_eth, core_type)())
+        .and_then(|hash| validate_debug_command_signature(db, core_type, signature, &hash, cfg!(test)))
+        .and_then(|_| {
+            if is_for_eth {
+                let db_utils = EthDbUtils::new(db);
+                sign_typed_data(&db_utils, typed_data_json, &get_allowed_verifying_contracts(&db_utils))
+            } else {
+                let db_utils = EvmDbUtils::new(db);
+                sign_typed_data(&db_utils, typed_data_json, &get_allowed_verifying_contracts(&db_utils))
+            }
+        })
+        .and_then(|output| {
+            db.end_transaction()?;
+            Ok(output)
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Sign Typed Data With ETH Key
+///
+/// Signs the passed in EIP-712 typed data with the ETH private key from the encrypted database. The
+/// typed data is a JSON string with `types`, `primaryType`, `domain` & `message` fields, per the
+/// `eth_signTypedData_v4` JSON-RPC method. The resulting hash is signed directly, with no further
+/// prefix, using a recoverable `secp256k1` signature scheme.
+///
+/// ### NOTE:
+///
+/// The typed data's domain must have the ETH chain ID the core was initialized with, & its
+/// `verifyingContract` must be one of the smart-contracts set in the core for that chain.
+pub fn debug_sign_typed_data_with_eth_key<D: DatabaseInterface>(
+    db: &D,
+    typed_data_json: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    info!("✔ Debug signing EIP-712 typed data with ETH key...");
+    debug_sign_typed_data(db, typed_data_json, true, core_type, signature)
+}
+
+/// # Debug Sign Typed Data With EVM Key
+///
+/// Signs the passed in EIP-712 typed data with the EVM private key from the encrypted database. The
+/// typed data is a JSON string with `types`, `primaryType`, `domain` & `message` fields, per the
+/// `eth_signTypedData_v4` JSON-RPC method. The resulting hash is signed directly, with no further
+/// prefix, using a recoverable `secp256k1` signature scheme.
+///
+/// ### NOTE:
+///
+/// The typed data's domain must have the EVM chain ID the core was initialized with, & its
+/// `verifyingContract` must be one of the smart-contracts set in the core for that chain.
+pub fn debug_sign_typed_data_with_evm_key<D: DatabaseInterface>(
+    db: &D,
+    typed_data_json: &str,
+    core_type: &CoreType,
+    signature: &str,
+) -> Result<String> {
+    info!("✔ Debug signing EIP-712 typed data with EVM key...");
+    debug_sign_typed_data(db, typed_data_json, false, core_type, signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        constants::DEBUG_OUTPUT_MARKER,
+        test_utils::{get_test_database, DUMMY_DEBUG_COMMAND_SIGNATURE},
+        utils::convert_hex_to_eth_address,
+    };
+    use common_chain_ids::EthChainId;
+    use common_eth::EthPrivateKey;
+    use serde_json::{json, Value as JsonValue};
+
+    use super::*;
+
+    fn get_sample_typed_data_json(verifying_contract: &str) -> String {
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Message": [{ "name": "contents", "type": "string" }]
+            },
+            "primaryType": "Message",
+            "domain": {
+                "name": "pNetwork",
+                "version": "1",
+                "chainId": "0x1",
+                "verifyingContract": verifying_contract
+            },
+            "message": { "contents": "Hello, Bob!" }
+        })
+        .to_string()
+    }
+
+    fn put_key_and_router_in_db<D: DatabaseInterface>(db: &D, router: &str) {
+        let db_utils = EthDbUtils::new(db);
+        let private_key = EthPrivateKey::from_slice(
+            &hex::decode("c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4").unwrap(),
+        )
+        .unwrap();
+        db_utils.put_eth_private_key_in_db(&private_key).unwrap();
+        db_utils.put_eth_chain_id_in_db(&EthChainId::Mainnet).unwrap();
+        db_utils
+            .put_eth_router_smart_contract_address_in_db(&convert_hex_to_eth_address(router).unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn should_debug_sign_typed_data_for_known_contract() {
+        let db = get_test_database();
+        let router = "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC";
+        put_key_and_router_in_db(&db, router);
+        let result = debug_sign_typed_data_with_eth_key(
+            &db,
+            &get_sample_typed_data_json(router),
+            &CoreType::BtcOnInt,
+            DUMMY_DEBUG_COMMAND_SIGNATURE,
+        )
+        .unwrap();
+        let json: JsonValue = serde_json::from_str(&result.replace(&format!("{DEBUG_OUTPUT_MARKER}_"), "")).unwrap();
+        assert!(json["signature"].is_string());
+    }
+
+    #[test]
+    fn should_not_debug_sign_typed_data_for_unknown_contract() {
+        let db = get_test_database();
+        put_key_and_router_in_db(&db, "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC");
+        let result = debug_sign_typed_data_with_eth_key(
+            &db,
+            &get_sample_typed_data_json("0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+            &CoreType::BtcOnInt,
+            DUMMY_DEBUG_COMMAND_SIGNATURE,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -1,10 +1,13 @@
 mod debug_gas_price_setters;
+mod debug_init_light_client;
 mod debug_nonce_setters;
 mod debug_reset_eth_chain;
+mod debug_sign_typed_data;
 mod test_utils;
 
 pub use self::{
     debug_gas_price_setters::{debug_set_eth_gas_price, debug_set_evm_gas_price},
+    debug_init_light_client::{debug_init_eth_light_client, debug_init_evm_light_client},
     debug_nonce_setters::{
         check_custom_nonce,
         debug_set_eth_account_nonce,
@@ -13,6 +16,7 @@ pub use self::{
         debug_set_evm_any_sender_nonce,
     },
     debug_reset_eth_chain::{debug_reset_eth_chain, debug_reset_evm_chain, reset_eth_chain},
+    debug_sign_typed_data::{debug_sign_typed_data_with_eth_key, debug_sign_typed_data_with_evm_key},
 };
 
 #[macro_use]
@@ -1,12 +1,20 @@
-use common::{core_type::CoreType, types::Bytes, utils::get_prefixed_db_key};
+use common::{
+    core_type::CoreType,
+    types::{Byte, Bytes},
+    utils::get_prefixed_db_key,
+};
 pub use serde_json::{json, Value as JsonValue};
 
 pub const DISABLE_FEES: bool = cfg!(feature = "disable-fees");
 pub const FEE_BASIS_POINTS_DIVISOR: u64 = 10_000;
+pub const MAX_FEE_WITHDRAWAL_AUDIT_LOG_RECORDS: usize = 100;
 
 lazy_static! {
     pub static ref BTC_ON_ETH_FEE_DB_KEYS: FeeConstantDbKeys = FeeConstantDbKeys::new_for_btc_on_eth();
     pub static ref BTC_ON_EOS_FEE_DB_KEYS: FeeConstantDbKeys = FeeConstantDbKeys::new_for_btc_on_eos();
+    pub static ref FEE_SCHEDULES_DB_KEY: [Byte; 32] = get_prefixed_db_key("fee-schedules-key");
+    pub static ref FEE_DISTRIBUTION_POLICIES_DB_KEY: [Byte; 32] = get_prefixed_db_key("fee-distribution-policies-key");
+    pub static ref FEE_WITHDRAWAL_AUDIT_LOG_DB_KEY: [Byte; 32] = get_prefixed_db_key("fee-withdrawal-audit-log-key");
 }
 
 #[derive(Clone)]
@@ -0,0 +1,300 @@
+use std::str::FromStr;
+
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    traits::DatabaseInterface,
+    types::{Bytes, Result},
+};
+use derive_more::{Constructor, Deref};
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bitcoin_crate_alias::{util::address::Address as BtcAddress, Network as BtcNetwork},
+    fee_constants::{FEE_BASIS_POINTS_DIVISOR, FEE_DISTRIBUTION_POLICIES_DB_KEY},
+};
+
+/// The token identifier used for the fee distribution policy of cores whose fees accrue in BTC.
+pub const BTC_FEE_DISTRIBUTION_TOKEN: &str = "BTC";
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeBeneficiary {
+    pub recipient: String,
+    pub share_basis_points: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeePayout {
+    pub recipient: String,
+    pub amount: U256,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeSplit {
+    pub payouts: Vec<FeePayout>,
+    pub dust: U256,
+}
+
+impl FeeSplit {
+    pub fn get_total_paid_out(&self) -> U256 {
+        self.payouts
+            .iter()
+            .fold(U256::zero(), |acc, payout| acc.saturating_add(payout.amount))
+    }
+}
+
+/// A policy describing how the withdrawn fees for a token are split between beneficiaries, whose
+/// shares are expressed in basis points and must sum to 100%. Any rounding dust left over after a
+/// split is carried forward and added to the next withdrawal for that token.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeDistributionPolicy {
+    pub token: String,
+    pub beneficiaries: Vec<FeeBeneficiary>,
+    #[serde(default)]
+    pub carried_dust: U256,
+}
+
+impl FeeDistributionPolicy {
+    pub fn new(token: &str, beneficiaries: Vec<FeeBeneficiary>) -> Result<Self> {
+        let total_shares = beneficiaries.iter().fold(0u64, |acc, beneficiary| {
+            acc.saturating_add(beneficiary.share_basis_points)
+        });
+        if beneficiaries.is_empty() {
+            Err("Cannot create fee distribution policy ∵ it has no beneficiaries!".into())
+        } else if beneficiaries
+            .iter()
+            .any(|beneficiary| beneficiary.share_basis_points == 0)
+        {
+            Err("Cannot create fee distribution policy ∵ a beneficiary has a share of zero!".into())
+        } else if total_shares != FEE_BASIS_POINTS_DIVISOR {
+            Err(format!(
+                "Cannot create fee distribution policy ∵ shares total {} basis points, not {}!",
+                total_shares, FEE_BASIS_POINTS_DIVISOR
+            )
+            .into())
+        } else {
+            Ok(Self {
+                beneficiaries,
+                token: token.to_lowercase(),
+                carried_dust: U256::zero(),
+            })
+        }
+    }
+
+    pub fn from_json_str(token: &str, beneficiaries_json: &str) -> Result<Self> {
+        Self::new(token, serde_json::from_str(beneficiaries_json)?)
+    }
+
+    /// Checks that every beneficiary of this policy is a BTC address for the passed in network, so
+    /// that a bad recipient is rejected when the policy is set rather than when fees are withdrawn.
+    pub fn validate_btc_recipients(&self, network: &BtcNetwork) -> Result<()> {
+        let is_mainnet = *network == BtcNetwork::Bitcoin;
+        self.beneficiaries.iter().try_for_each(|beneficiary| {
+            let address = BtcAddress::from_str(&beneficiary.recipient)?;
+            if (address.network == BtcNetwork::Bitcoin) == is_mainnet {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Fee distribution recipient '{}' is not a valid address for BTC network '{}'!",
+                    beneficiary.recipient, network
+                )
+                .into())
+            }
+        })
+    }
+
+    /// Splits the passed in amount, plus any dust carried forward from the previous split, between
+    /// this policy's beneficiaries. Beneficiaries whose share rounds down to zero are omitted.
+    pub fn split(&self, amount: U256) -> FeeSplit {
+        let total = amount.saturating_add(self.carried_dust);
+        let payouts = self
+            .beneficiaries
+            .iter()
+            .map(|beneficiary| FeePayout {
+                recipient: beneficiary.recipient.clone(),
+                amount: total.saturating_mul(U256::from(beneficiary.share_basis_points))
+                    / U256::from(FEE_BASIS_POINTS_DIVISOR),
+            })
+            .filter(|payout| !payout.amount.is_zero())
+            .collect::<Vec<_>>();
+        let mut split = FeeSplit {
+            payouts,
+            dust: U256::zero(),
+        };
+        split.dust = total.saturating_sub(split.get_total_paid_out());
+        debug!("✔ Split {} into {:?}", total, split);
+        split
+    }
+
+    fn set_carried_dust(&self, dust: U256) -> Self {
+        let mut new_self = self.clone();
+        new_self.carried_dust = dust;
+        new_self
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Deref, Constructor)]
+pub struct FeeDistributionPolicies(Vec<FeeDistributionPolicy>);
+
+impl FeeDistributionPolicies {
+    pub fn get_policy(&self, token: &str) -> Result<FeeDistributionPolicy> {
+        let token = token.to_lowercase();
+        self.iter()
+            .find(|policy| policy.token == token)
+            .cloned()
+            .ok_or_else(|| format!("No fee distribution policy set for token '{}'!", token).into())
+    }
+
+    fn remove(&self, token: &str) -> Self {
+        let token = token.to_lowercase();
+        Self::new(self.iter().filter(|policy| policy.token != token).cloned().collect())
+    }
+
+    fn add(&self, policy: FeeDistributionPolicy) -> Self {
+        let mut policies = self.remove(&policy.token).0;
+        policies.push(policy);
+        Self::new(policies)
+    }
+
+    /// Sets the passed in policy, replacing any existing policy for the same token whilst keeping
+    /// any dust that policy has carried forward.
+    pub fn set_policy(&self, policy: FeeDistributionPolicy) -> Self {
+        info!("✔ Setting fee distribution policy: {:?}", policy);
+        let carried_dust = self
+            .get_policy(&policy.token)
+            .map(|existing| existing.carried_dust)
+            .unwrap_or_default();
+        self.add(policy.set_carried_dust(carried_dust))
+    }
+
+    pub fn remove_policy(&self, token: &str) -> Result<Self> {
+        self.get_policy(token).map(|_| self.remove(token))
+    }
+
+    pub fn update_carried_dust(&self, token: &str, dust: U256) -> Result<Self> {
+        self.get_policy(token)
+            .map(|policy| self.add(policy.set_carried_dust(dust)))
+    }
+
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(&self.0)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(serde_json::from_slice(bytes)?))
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        match db.get(FEE_DISTRIBUTION_POLICIES_DB_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => {
+                debug!("✘ No `FeeDistributionPolicies` in db ∴ using empty ones!");
+                Ok(Self::default())
+            },
+        }
+    }
+
+    pub fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        db.put(
+            FEE_DISTRIBUTION_POLICIES_DB_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+
+    fn get_sample_beneficiaries() -> Vec<FeeBeneficiary> {
+        vec![
+            FeeBeneficiary {
+                recipient: "alice".to_string(),
+                share_basis_points: 5000,
+            },
+            FeeBeneficiary {
+                recipient: "bob".to_string(),
+                share_basis_points: 3333,
+            },
+            FeeBeneficiary {
+                recipient: "carol".to_string(),
+                share_basis_points: 1667,
+            },
+        ]
+    }
+
+    fn get_sample_policy() -> FeeDistributionPolicy {
+        FeeDistributionPolicy::new("0xC0FFEE", get_sample_beneficiaries()).unwrap()
+    }
+
+    #[test]
+    fn should_fail_to_create_policy_whose_shares_do_not_total_one_hundred_percent() {
+        let mut beneficiaries = get_sample_beneficiaries();
+        beneficiaries[0].share_basis_points = 4999;
+        assert!(FeeDistributionPolicy::new("0xc0ffee", beneficiaries).is_err());
+    }
+
+    #[test]
+    fn should_split_amount_and_return_dust() {
+        let result = get_sample_policy().split(U256::from(1001));
+        let amounts = result.payouts.iter().map(|payout| payout.amount).collect::<Vec<_>>();
+        assert_eq!(amounts, vec![U256::from(500), U256::from(333), U256::from(166)]);
+        assert_eq!(result.dust, U256::from(2));
+        assert_eq!(result.get_total_paid_out() + result.dust, U256::from(1001));
+    }
+
+    #[test]
+    fn should_include_carried_dust_in_next_split() {
+        let policy = get_sample_policy().set_carried_dust(U256::from(2));
+        let result = policy.split(U256::from(998));
+        assert_eq!(result.get_total_paid_out() + result.dust, U256::from(1000));
+        assert_eq!(result.payouts[0].amount, U256::from(500));
+    }
+
+    #[test]
+    fn should_keep_carried_dust_when_replacing_policy() {
+        let token = "0xc0ffee";
+        let policies = FeeDistributionPolicies::default()
+            .set_policy(get_sample_policy())
+            .update_carried_dust(token, U256::from(7))
+            .unwrap()
+            .set_policy(get_sample_policy());
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies.get_policy(token).unwrap().carried_dust, U256::from(7));
+    }
+
+    #[test]
+    fn should_validate_btc_recipients_for_network() {
+        let beneficiaries = vec![
+            FeeBeneficiary {
+                recipient: "msgbp2MiwL6M1qkhZx9N46ipPn12tzLzZ7".to_string(),
+                share_basis_points: 6000,
+            },
+            FeeBeneficiary {
+                recipient: "mwbtrpDGLWiMiq1TB7DhnrEN14B5Hydp28".to_string(),
+                share_basis_points: 4000,
+            },
+        ];
+        let policy = FeeDistributionPolicy::new(BTC_FEE_DISTRIBUTION_TOKEN, beneficiaries).unwrap();
+        assert!(policy.validate_btc_recipients(&BtcNetwork::Testnet).is_ok());
+        assert!(policy.validate_btc_recipients(&BtcNetwork::Bitcoin).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_validate_non_btc_recipients() {
+        assert!(get_sample_policy()
+            .validate_btc_recipients(&BtcNetwork::Testnet)
+            .is_err());
+    }
+
+    #[test]
+    fn should_save_and_get_policies_from_db() {
+        let db = get_test_database();
+        let policies = FeeDistributionPolicies::default().set_policy(get_sample_policy());
+        policies.save_in_db(&db).unwrap();
+        assert_eq!(FeeDistributionPolicies::get_from_db(&db).unwrap(), policies);
+    }
+}
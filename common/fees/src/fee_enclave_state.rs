@@ -5,13 +5,17 @@ use serde::{Deserialize, Serialize};
 use crate::{
     fee_constants::DISABLE_FEES,
     fee_database_utils::FeeDatabaseUtils,
+    fee_distribution::FeeDistributionPolicies,
     fee_utils::get_last_withdrawal_date_as_human_readable_string,
+    fee_withdrawal_audit::FeeWithdrawalAuditLog,
 };
 
 #[derive(Serialize, Deserialize)]
 pub struct FeesEnclaveState {
     fees_enabled: bool,
     fees: FeeStateForTokens,
+    fee_distribution_policies: FeeDistributionPolicies,
+    fee_withdrawals: FeeWithdrawalAuditLog,
 }
 
 impl FeesEnclaveState {
@@ -19,6 +23,8 @@ impl FeesEnclaveState {
         Ok(Self {
             fees_enabled: !DISABLE_FEES,
             fees: FeeStateForTokens::new_for_btc_on_eth(db)?,
+            fee_distribution_policies: FeeDistributionPolicies::get_from_db(db)?,
+            fee_withdrawals: FeeWithdrawalAuditLog::get_from_db(db)?,
         })
     }
 
@@ -26,6 +32,8 @@ impl FeesEnclaveState {
         Ok(Self {
             fees_enabled: !DISABLE_FEES,
             fees: FeeStateForTokens::new_for_btc_on_eos(db)?,
+            fee_distribution_policies: FeeDistributionPolicies::get_from_db(db)?,
+            fee_withdrawals: FeeWithdrawalAuditLog::get_from_db(db)?,
         })
     }
 }
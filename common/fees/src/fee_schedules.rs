@@ -0,0 +1,298 @@
+use std::cmp::{max, min};
+
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    traits::DatabaseInterface,
+    types::{Bytes, Result},
+};
+use common_metadata::MetadataChainId;
+use derive_more::{Deref, DerefMut};
+use ethereum_types::{Address as EthAddress, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fee_constants::{FEE_BASIS_POINTS_DIVISOR, FEE_SCHEDULES_DB_KEY},
+    fee_utils::sanity_check_basis_points_value,
+};
+
+/// A fee schedule combining a flat fee with a basis points based fee, the total of which is then
+/// bounded by an optional minimum and maximum. All amounts are denominated in the token's units.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeSchedule {
+    pub flat_fee: U256,
+    pub basis_points: u64,
+    pub minimum_fee: U256,
+    pub maximum_fee: Option<U256>,
+}
+
+/// The breakdown of a fee calculated via a `FeeSchedule`, suitable for including in output JSON.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeBreakdown {
+    pub flat_fee: U256,
+    pub basis_points: u64,
+    pub basis_points_fee: U256,
+    pub minimum_fee: U256,
+    pub maximum_fee: Option<U256>,
+    pub total_fee: U256,
+}
+
+impl FeeSchedule {
+    pub fn new(flat_fee: U256, basis_points: u64, minimum_fee: U256, maximum_fee: Option<U256>) -> Result<Self> {
+        let schedule = Self {
+            flat_fee,
+            minimum_fee,
+            maximum_fee,
+            basis_points: sanity_check_basis_points_value(basis_points)?,
+        };
+        match maximum_fee {
+            Some(maximum) if maximum < minimum_fee => Err(format!(
+                "Cannot create fee schedule ∵ maximum fee of {} is below minimum fee of {}!",
+                maximum, minimum_fee
+            )
+            .into()),
+            _ => Ok(schedule),
+        }
+    }
+
+    pub fn from_basis_points(basis_points: u64) -> Self {
+        Self {
+            basis_points,
+            ..Default::default()
+        }
+    }
+
+    fn get_basis_points_fee(&self, amount: U256) -> U256 {
+        amount.saturating_mul(U256::from(self.basis_points)) / U256::from(FEE_BASIS_POINTS_DIVISOR)
+    }
+
+    fn get_total_fee(&self, amount: U256) -> U256 {
+        let total_fee = max(
+            self.flat_fee.saturating_add(self.get_basis_points_fee(amount)),
+            self.minimum_fee,
+        );
+        match self.maximum_fee {
+            Some(maximum) => min(total_fee, maximum),
+            None => total_fee,
+        }
+    }
+
+    /// Whether the passed in amount is large enough for a peg of it to pay this schedule's fee.
+    pub fn amount_covers_fee(&self, amount: U256) -> bool {
+        let total_fee = self.get_total_fee(amount);
+        total_fee.is_zero() || total_fee < amount
+    }
+
+    /// Calculates the fee for the passed in amount, failing if the fee would consume the entire
+    /// amount, since a peg which cannot pay its fee should be rejected rather than undercharged.
+    pub fn calculate_fee(&self, amount: U256) -> Result<FeeBreakdown> {
+        let total_fee = self.get_total_fee(amount);
+        if !self.amount_covers_fee(amount) {
+            return Err(format!(
+                "Cannot calculate fee ∵ amount of {} does not exceed fee of {}!",
+                amount, total_fee
+            )
+            .into());
+        };
+        debug!("Calculated fee of {} for amount of {}", total_fee, amount);
+        Ok(FeeBreakdown {
+            total_fee,
+            basis_points_fee: self.get_basis_points_fee(amount),
+            flat_fee: self.flat_fee,
+            minimum_fee: self.minimum_fee,
+            maximum_fee: self.maximum_fee,
+            basis_points: self.basis_points,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeScheduleEntry {
+    pub token_address: EthAddress,
+    /// NOTE: A `None` here means this schedule is the default for this token, used for any
+    /// destination which doesn't have a schedule of its own.
+    pub destination_chain_id: Option<MetadataChainId>,
+    pub schedule: FeeSchedule,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Deref, DerefMut)]
+pub struct FeeSchedules(Vec<FeeScheduleEntry>);
+
+impl FeeSchedules {
+    pub fn new(entries: Vec<FeeScheduleEntry>) -> Self {
+        Self(entries)
+    }
+
+    fn get_entry(
+        &self,
+        token_address: &EthAddress,
+        destination_chain_id: &Option<MetadataChainId>,
+    ) -> Option<&FeeScheduleEntry> {
+        self.iter()
+            .find(|entry| &entry.token_address == token_address && &entry.destination_chain_id == destination_chain_id)
+    }
+
+    /// Gets the schedule for the passed in token and destination, falling back to that token's
+    /// default schedule if the destination doesn't have one of its own.
+    pub fn get_schedule(
+        &self,
+        token_address: &EthAddress,
+        destination_chain_id: &MetadataChainId,
+    ) -> Option<FeeSchedule> {
+        self.get_entry(token_address, &Some(*destination_chain_id))
+            .or_else(|| self.get_entry(token_address, &None))
+            .map(|entry| entry.schedule.clone())
+    }
+
+    pub fn set_schedule(
+        &self,
+        token_address: &EthAddress,
+        destination_chain_id: &Option<MetadataChainId>,
+        schedule: FeeSchedule,
+    ) -> Self {
+        info!(
+            "✔ Setting fee schedule for token {} & destination {:?} to {:?}",
+            token_address, destination_chain_id, schedule
+        );
+        let mut new_self = self.remove_schedule(token_address, destination_chain_id);
+        new_self.push(FeeScheduleEntry {
+            schedule,
+            token_address: *token_address,
+            destination_chain_id: *destination_chain_id,
+        });
+        new_self
+    }
+
+    pub fn remove_schedule(&self, token_address: &EthAddress, destination_chain_id: &Option<MetadataChainId>) -> Self {
+        Self::new(
+            self.iter()
+                .filter(|entry| {
+                    !(&entry.token_address == token_address && &entry.destination_chain_id == destination_chain_id)
+                })
+                .cloned()
+                .collect(),
+        )
+    }
+
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(&self.0)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(serde_json::from_slice(bytes)?))
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        debug!("✔ Getting `FeeSchedules` from db...");
+        match db.get(FEE_SCHEDULES_DB_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => {
+                debug!("✘ No `FeeSchedules` in db ∴ using empty ones!");
+                Ok(Self::default())
+            },
+        }
+    }
+
+    pub fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        db.put(
+            FEE_SCHEDULES_DB_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+
+    fn get_sample_schedule() -> FeeSchedule {
+        FeeSchedule::new(U256::from(10), 25, U256::from(50), Some(U256::from(1000))).unwrap()
+    }
+
+    #[test]
+    fn should_calculate_fee_from_flat_fee_and_basis_points() {
+        let result = get_sample_schedule().calculate_fee(U256::from(100_000)).unwrap();
+        assert_eq!(result.basis_points_fee, U256::from(250));
+        assert_eq!(result.total_fee, U256::from(260));
+    }
+
+    #[test]
+    fn should_apply_minimum_and_maximum_fees() {
+        let schedule = get_sample_schedule();
+        assert_eq!(
+            schedule.calculate_fee(U256::from(1000)).unwrap().total_fee,
+            U256::from(50)
+        );
+        assert_eq!(
+            schedule.calculate_fee(U256::from(10_000_000)).unwrap().total_fee,
+            U256::from(1000)
+        );
+    }
+
+    #[test]
+    fn should_fail_to_calculate_fee_which_would_consume_entire_amount() {
+        let schedule = get_sample_schedule();
+        assert!(!schedule.amount_covers_fee(U256::from(50)));
+        assert!(schedule.calculate_fee(U256::from(50)).is_err());
+        assert!(schedule.amount_covers_fee(U256::from(51)));
+    }
+
+    #[test]
+    fn zero_fee_should_be_covered_by_any_amount() {
+        assert!(FeeSchedule::default().amount_covers_fee(U256::zero()));
+    }
+
+    #[test]
+    fn should_fail_to_create_schedule_with_maximum_below_minimum() {
+        let result = FeeSchedule::new(U256::zero(), 25, U256::from(50), Some(U256::from(49)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_get_destination_schedule_falling_back_to_default() {
+        let token = EthAddress::from_low_u64_be(1);
+        let default_schedule = FeeSchedule::from_basis_points(10);
+        let bsc_schedule = get_sample_schedule();
+        let schedules = FeeSchedules::default()
+            .set_schedule(&token, &None, default_schedule.clone())
+            .set_schedule(&token, &Some(MetadataChainId::BscMainnet), bsc_schedule.clone());
+        assert_eq!(
+            schedules.get_schedule(&token, &MetadataChainId::BscMainnet),
+            Some(bsc_schedule)
+        );
+        assert_eq!(
+            schedules.get_schedule(&token, &MetadataChainId::EthereumMainnet),
+            Some(default_schedule)
+        );
+        assert!(schedules
+            .get_schedule(&EthAddress::zero(), &MetadataChainId::BscMainnet)
+            .is_none());
+    }
+
+    #[test]
+    fn should_replace_and_remove_schedules() {
+        let token = EthAddress::from_low_u64_be(1);
+        let destination = Some(MetadataChainId::BscMainnet);
+        let schedules = FeeSchedules::default()
+            .set_schedule(&token, &destination, FeeSchedule::from_basis_points(10))
+            .set_schedule(&token, &destination, FeeSchedule::from_basis_points(20));
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules[0].schedule.basis_points, 20);
+        assert!(schedules.remove_schedule(&token, &destination).is_empty());
+    }
+
+    #[test]
+    fn should_save_and_get_fee_schedules_from_db() {
+        let db = get_test_database();
+        assert!(FeeSchedules::get_from_db(&db).unwrap().is_empty());
+        let schedules = FeeSchedules::default().set_schedule(
+            &EthAddress::from_low_u64_be(1),
+            &Some(MetadataChainId::PolygonMainnet),
+            get_sample_schedule(),
+        );
+        schedules.save_in_db(&db).unwrap();
+        assert_eq!(FeeSchedules::get_from_db(&db).unwrap(), schedules);
+    }
+}
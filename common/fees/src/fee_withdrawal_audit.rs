@@ -0,0 +1,128 @@
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    traits::DatabaseInterface,
+    types::{Bytes, Result},
+    utils::get_unix_timestamp,
+};
+use derive_more::{Constructor, Deref};
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fee_constants::{FEE_WITHDRAWAL_AUDIT_LOG_DB_KEY, MAX_FEE_WITHDRAWAL_AUDIT_LOG_RECORDS},
+    fee_distribution::FeePayout,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeWithdrawalRecord {
+    pub token: String,
+    pub timestamp: u64,
+    pub amount_withdrawn: U256,
+    pub payouts: Vec<FeePayout>,
+    pub dust_carried_forward: U256,
+    pub tx_ids: Vec<String>,
+}
+
+impl FeeWithdrawalRecord {
+    pub fn new(
+        token: &str,
+        amount_withdrawn: U256,
+        payouts: Vec<FeePayout>,
+        dust_carried_forward: U256,
+        tx_ids: Vec<String>,
+    ) -> Result<Self> {
+        Ok(Self {
+            payouts,
+            tx_ids,
+            amount_withdrawn,
+            dust_carried_forward,
+            token: token.to_lowercase(),
+            timestamp: get_unix_timestamp()?,
+        })
+    }
+}
+
+/// An append-only record of the most recent fee withdrawals made by the core. Once the log holds
+/// `MAX_FEE_WITHDRAWAL_AUDIT_LOG_RECORDS` records, the oldest are dropped as new ones are added.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Deref, Constructor)]
+pub struct FeeWithdrawalAuditLog(Vec<FeeWithdrawalRecord>);
+
+impl FeeWithdrawalAuditLog {
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(&self.0)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(serde_json::from_slice(bytes)?))
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        match db.get(FEE_WITHDRAWAL_AUDIT_LOG_DB_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => {
+                debug!("✘ No `FeeWithdrawalAuditLog` in db ∴ using empty one!");
+                Ok(Self::default())
+            },
+        }
+    }
+
+    fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        db.put(
+            FEE_WITHDRAWAL_AUDIT_LOG_DB_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+
+    pub fn add_record_and_save_in_db<D: DatabaseInterface>(db: &D, record: FeeWithdrawalRecord) -> Result<()> {
+        info!("✔ Adding fee withdrawal record to audit log: {:?}", record);
+        let mut records = Self::get_from_db(db)?.0;
+        records.push(record);
+        if records.len() > MAX_FEE_WITHDRAWAL_AUDIT_LOG_RECORDS {
+            let num_to_drop = records.len() - MAX_FEE_WITHDRAWAL_AUDIT_LOG_RECORDS;
+            debug!(
+                "✔ Dropping {} oldest record(s) from fee withdrawal audit log",
+                num_to_drop
+            );
+            records.drain(..num_to_drop);
+        }
+        Self::new(records).save_in_db(db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+
+    #[test]
+    fn should_append_records_to_audit_log() {
+        let db = get_test_database();
+        let payouts = vec![FeePayout {
+            recipient: "alice".to_string(),
+            amount: U256::from(1337),
+        }];
+        let record_1 =
+            FeeWithdrawalRecord::new("BTC", U256::from(1337), payouts.clone(), U256::zero(), vec![]).unwrap();
+        let record_2 =
+            FeeWithdrawalRecord::new("0xc0ffee", U256::from(1338), payouts, U256::one(), vec!["0xabc".into()]).unwrap();
+        FeeWithdrawalAuditLog::add_record_and_save_in_db(&db, record_1.clone()).unwrap();
+        FeeWithdrawalAuditLog::add_record_and_save_in_db(&db, record_2.clone()).unwrap();
+        let result = FeeWithdrawalAuditLog::get_from_db(&db).unwrap();
+        assert_eq!(*result, vec![record_1, record_2]);
+    }
+
+    #[test]
+    fn should_drop_oldest_records_once_audit_log_is_full() {
+        let db = get_test_database();
+        let get_record =
+            |amount: usize| FeeWithdrawalRecord::new("BTC", U256::from(amount), vec![], U256::zero(), vec![]).unwrap();
+        let num_records = MAX_FEE_WITHDRAWAL_AUDIT_LOG_RECORDS + 2;
+        (0..num_records).for_each(|i| FeeWithdrawalAuditLog::add_record_and_save_in_db(&db, get_record(i)).unwrap());
+        let result = FeeWithdrawalAuditLog::get_from_db(&db).unwrap();
+        assert_eq!(result.len(), MAX_FEE_WITHDRAWAL_AUDIT_LOG_RECORDS);
+        assert_eq!(result[0].amount_withdrawn, U256::from(2));
+        assert_eq!(result[result.len() - 1].amount_withdrawn, U256::from(num_records - 1));
+    }
+}
@@ -8,6 +8,7 @@ use common_btc::{
     BtcRecipientAndAmount,
     BtcRecipientsAndAmounts,
 };
+use ethereum_types::U256;
 
 use crate::{
     bitcoin_crate_alias::{
@@ -15,14 +16,48 @@ use crate::{
         util::address::Address as BtcAddress,
     },
     fee_database_utils::FeeDatabaseUtils,
+    fee_distribution::{FeeDistributionPolicies, FeePayout, BTC_FEE_DISTRIBUTION_TOKEN},
+    fee_withdrawal_audit::{FeeWithdrawalAuditLog, FeeWithdrawalRecord},
 };
 
+fn get_signed_fee_withdrawal_btc_tx<D: DatabaseInterface>(
+    db: &D,
+    fee_db_utils: &FeeDatabaseUtils,
+    withdrawal_amount: u64,
+    recipients_and_amounts: BtcRecipientsAndAmounts,
+) -> Result<BtcTransaction> {
+    let btc_db_utils = BtcDbUtils::new(db);
+    let fee = btc_db_utils.get_btc_fee_from_db()?;
+    fee_db_utils
+        .put_last_fee_withdrawal_timestamp_in_db(db, get_unix_timestamp()?)
+        .and_then(|_| {
+            get_enough_utxos_to_cover_total(
+                db,
+                withdrawal_amount,
+                recipients_and_amounts.len() + 1, // NOTE: + 1 to account for the change output.
+                fee,
+            )
+        })
+        .and_then(|utxos| {
+            create_signed_raw_btc_tx_for_n_input_n_outputs(
+                fee,
+                recipients_and_amounts,
+                &btc_db_utils.get_btc_address_from_db()?,
+                &btc_db_utils.get_btc_private_key_from_db()?,
+                utxos,
+            )
+        })
+        .and_then(|signed_btc_tx| {
+            fee_db_utils.reset_accrued_fees(db)?;
+            Ok(signed_btc_tx)
+        })
+}
+
 pub fn get_fee_withdrawal_btc_tx_for_core_type<D: DatabaseInterface>(
     core_type: &CoreType,
     db: &D,
     btc_address: &str,
 ) -> Result<BtcTransaction> {
-    let btc_db_utils = BtcDbUtils::new(db);
     let fee_db_utils = FeeDatabaseUtils::new_for_core_type(core_type)?;
     let withdrawal_amount = fee_db_utils.get_accrued_fees_from_db(db)?;
     if withdrawal_amount == 0 {
@@ -32,34 +67,83 @@ pub fn get_fee_withdrawal_btc_tx_for_core_type<D: DatabaseInterface>(
         )
         .into())
     } else {
-        let fee = btc_db_utils.get_btc_fee_from_db()?;
         let recipients_and_amounts = BtcRecipientsAndAmounts::new(vec![BtcRecipientAndAmount {
             recipient: BtcAddress::from_str(btc_address)?,
             amount: withdrawal_amount,
         }]);
-        fee_db_utils
-            .put_last_fee_withdrawal_timestamp_in_db(db, get_unix_timestamp()?)
-            .and_then(|_| {
-                get_enough_utxos_to_cover_total(
+        get_signed_fee_withdrawal_btc_tx(db, &fee_db_utils, withdrawal_amount, recipients_and_amounts).and_then(
+            |signed_btc_tx| {
+                FeeWithdrawalAuditLog::add_record_and_save_in_db(
+                    db,
+                    FeeWithdrawalRecord::new(
+                        BTC_FEE_DISTRIBUTION_TOKEN,
+                        U256::from(withdrawal_amount),
+                        vec![FeePayout {
+                            recipient: btc_address.to_string(),
+                            amount: U256::from(withdrawal_amount),
+                        }],
+                        U256::zero(),
+                        vec![signed_btc_tx.txid().to_string()],
+                    )?,
+                )?;
+                Ok(signed_btc_tx)
+            },
+        )
+    }
+}
+
+/// Creates a single BTC transaction splitting the accrued fees between the beneficiaries of the
+/// BTC fee distribution policy, one output per beneficiary. Any rounding dust is carried forward
+/// to the next withdrawal.
+pub fn get_split_fee_withdrawal_btc_tx_for_core_type<D: DatabaseInterface>(
+    core_type: &CoreType,
+    db: &D,
+) -> Result<BtcTransaction> {
+    let fee_db_utils = FeeDatabaseUtils::new_for_core_type(core_type)?;
+    let accrued_fees = fee_db_utils.get_accrued_fees_from_db(db)?;
+    let policies = FeeDistributionPolicies::get_from_db(db)?;
+    let split = policies
+        .get_policy(BTC_FEE_DISTRIBUTION_TOKEN)?
+        .split(U256::from(accrued_fees));
+    if split.payouts.is_empty() {
+        Err(format!(
+            "Cannot get `{}` split withdrawal tx - there are no fees to withdraw!",
+            core_type
+        )
+        .into())
+    } else {
+        // NOTE: These amounts cannot exceed the accrued fees plus dust, both of which fit in a `u64`.
+        let withdrawal_amount = split.get_total_paid_out().low_u64();
+        let recipients_and_amounts = BtcRecipientsAndAmounts::new(
+            split
+                .payouts
+                .iter()
+                .map(|payout| {
+                    Ok(BtcRecipientAndAmount {
+                        recipient: BtcAddress::from_str(&payout.recipient)?,
+                        amount: payout.amount.low_u64(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+        );
+        get_signed_fee_withdrawal_btc_tx(db, &fee_db_utils, withdrawal_amount, recipients_and_amounts).and_then(
+            |signed_btc_tx| {
+                policies
+                    .update_carried_dust(BTC_FEE_DISTRIBUTION_TOKEN, split.dust)?
+                    .save_in_db(db)?;
+                FeeWithdrawalAuditLog::add_record_and_save_in_db(
                     db,
-                    withdrawal_amount,
-                    recipients_and_amounts.len() + 1, // NOTE: + 1 to account for the change output.
-                    fee,
-                )
-            })
-            .and_then(|utxos| {
-                create_signed_raw_btc_tx_for_n_input_n_outputs(
-                    fee,
-                    recipients_and_amounts,
-                    &btc_db_utils.get_btc_address_from_db()?,
-                    &btc_db_utils.get_btc_private_key_from_db()?,
-                    utxos,
-                )
-            })
-            .and_then(|signed_btc_tx| {
-                fee_db_utils.reset_accrued_fees(db)?;
+                    FeeWithdrawalRecord::new(
+                        BTC_FEE_DISTRIBUTION_TOKEN,
+                        U256::from(accrued_fees),
+                        split.payouts,
+                        split.dust,
+                        vec![signed_btc_tx.txid().to_string()],
+                    )?,
+                )?;
                 Ok(signed_btc_tx)
-            })
+            },
+        )
     }
 }
 
@@ -71,7 +155,15 @@ pub fn get_btc_on_eos_fee_withdrawal_tx<D: DatabaseInterface>(db: &D, btc_addres
     get_fee_withdrawal_btc_tx_for_core_type(&CoreType::BtcOnEos, db, btc_address)
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+pub fn get_btc_on_eth_split_fee_withdrawal_tx<D: DatabaseInterface>(db: &D) -> Result<BtcTransaction> {
+    get_split_fee_withdrawal_btc_tx_for_core_type(&CoreType::BtcOnEth, db)
+}
+
+pub fn get_btc_on_eos_split_fee_withdrawal_tx<D: DatabaseInterface>(db: &D) -> Result<BtcTransaction> {
+    get_split_fee_withdrawal_btc_tx_for_core_type(&CoreType::BtcOnEos, db)
+}
+
+#[cfg(test)]
 mod tests {
     use common::{errors::AppError, test_utils::get_test_database};
     use common_btc::{save_utxos_to_db, set_utxo_balance_to_zero};
@@ -79,6 +171,7 @@ mod tests {
     use super::*;
     use crate::{
         bitcoin_crate_alias::network::constants::Network as BtcNetwork,
+        fee_distribution::FeeDistributionPolicy,
         test_utils::{get_sample_btc_private_key, get_sample_utxo_and_values},
     };
 
@@ -124,4 +217,45 @@ mod tests {
             Err(_) => panic!("Wrong error received!"),
         }
     }
+
+    #[test]
+    fn should_get_btc_on_eth_split_fee_withdrawal_tx_and_carry_dust_forward() {
+        let btc_fee = 20;
+        let accrued_fees = 3;
+        let db = get_test_database();
+        set_utxo_balance_to_zero(&db).unwrap();
+        let db_utils = BtcDbUtils::new(&db);
+        save_utxos_to_db(&db, &get_sample_utxo_and_values()).unwrap();
+        FeeDatabaseUtils::new_for_btc_on_eth()
+            .increment_accrued_fees(&db, accrued_fees)
+            .unwrap();
+        db_utils.put_btc_fee_in_db(btc_fee).unwrap();
+        db_utils
+            .put_btc_address_in_db("mwbtrpDGLWiMiq1TB7DhnrEN14B5Hydp28")
+            .unwrap();
+        db_utils.put_btc_network_in_db(BtcNetwork::Testnet).unwrap();
+        db_utils
+            .put_btc_private_key_in_db(&get_sample_btc_private_key())
+            .unwrap();
+        let beneficiaries = r#"[
+            {"recipient":"msgbp2MiwL6M1qkhZx9N46ipPn12tzLzZ7","share_basis_points":5000},
+            {"recipient":"mwbtrpDGLWiMiq1TB7DhnrEN14B5Hydp28","share_basis_points":5000}
+        ]"#;
+        FeeDistributionPolicies::default()
+            .set_policy(FeeDistributionPolicy::from_json_str(BTC_FEE_DISTRIBUTION_TOKEN, beneficiaries).unwrap())
+            .save_in_db(&db)
+            .unwrap();
+        let result = get_btc_on_eth_split_fee_withdrawal_tx(&db).unwrap();
+        assert_eq!(result.output[0].value, 1);
+        assert_eq!(result.output[1].value, 1);
+        let policy = FeeDistributionPolicies::get_from_db(&db)
+            .unwrap()
+            .get_policy(BTC_FEE_DISTRIBUTION_TOKEN)
+            .unwrap();
+        assert_eq!(policy.carried_dust, U256::one());
+        let audit_log = FeeWithdrawalAuditLog::get_from_db(&db).unwrap();
+        assert_eq!(audit_log.len(), 1);
+        assert_eq!(audit_log[0].amount_withdrawn, U256::from(accrued_fees));
+        assert_eq!(audit_log[0].tx_ids, vec![result.txid().to_string()]);
+    }
 }
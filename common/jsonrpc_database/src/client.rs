@@ -0,0 +1,119 @@
+use std::{fs, thread, time::Duration};
+
+use common::{AppError as PTokensCoreError, Result as PTokensResult};
+use reqwest::{
+    blocking::{Client, Response},
+    Certificate,
+    Identity,
+    StatusCode,
+};
+use serde_json::Value as JsonValue;
+
+use crate::{config::JsonRpcDatabaseConfig, JsonRpcDatabaseError};
+
+// NOTE: Caps the backoff at 2^10 times the initial backoff.
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+/// A JSON-RPC client holding a single HTTP client, & thus a single connection pool, for the life
+/// of the database, which retries requests failing with transient errors using exponential backoff.
+#[derive(Debug, Default)]
+pub struct JsonRpcClient {
+    url: String,
+    client: Client,
+    max_retries: u32,
+    retry_backoff: Duration,
+    auth_token: Option<String>,
+}
+
+impl JsonRpcClient {
+    pub fn new(config: &JsonRpcDatabaseConfig) -> Result<Self, JsonRpcDatabaseError> {
+        let mut builder = Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout);
+        if let Some(path) = &config.ca_cert_path {
+            builder = builder.add_root_certificate(Certificate::from_pem(&fs::read(path)?)?);
+        };
+        if let Some(path) = &config.client_identity_path {
+            builder = builder.identity(Identity::from_pem(&fs::read(path)?)?);
+        };
+        Ok(Self {
+            client: builder.build()?,
+            url: config.url.clone(),
+            max_retries: config.max_retries,
+            retry_backoff: config.retry_backoff,
+            auth_token: config.auth_token.clone(),
+        })
+    }
+
+    fn is_transient_error(result: &Result<Response, reqwest::Error>) -> bool {
+        match result {
+            Ok(response) => response.status().is_server_error() || response.status() == StatusCode::TOO_MANY_REQUESTS,
+            Err(e) => e.is_timeout() || e.is_connect(),
+        }
+    }
+
+    fn get_backoff(&self, attempt: u32) -> Duration {
+        self.retry_backoff * 2u32.pow(attempt.saturating_sub(1).min(MAX_BACKOFF_EXPONENT))
+    }
+
+    // NOTE: All of the database's requests are safe to retry, since a retried `end_transaction`
+    // merely re-applies the same batch of writes.
+    fn post(&self, json: &JsonValue) -> Result<Response, reqwest::Error> {
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.post(&self.url).json(json);
+            if let Some(token) = &self.auth_token {
+                request = request.bearer_auth(token);
+            };
+            let result = request.send();
+            if attempt >= self.max_retries || !Self::is_transient_error(&result) {
+                return result;
+            };
+            attempt += 1;
+            let backoff = self.get_backoff(attempt);
+            warn!(
+                "✘ Transient JSON-RPC DB error, retrying in {}ms (attempt {attempt}/{})...",
+                backoff.as_millis(),
+                self.max_retries
+            );
+            thread::sleep(backoff);
+        }
+    }
+
+    /// Makes a call to the database, erroring with the passed in message if the response's status
+    /// code is not `200`.
+    pub fn call(&self, json: &JsonValue, err_msg: &str) -> PTokensResult<Response> {
+        match self.post(json) {
+            Err(e) => Err(PTokensCoreError::Custom(e.to_string())),
+            Ok(response) => match response.status() {
+                StatusCode::OK => Ok(response),
+                status => Err(PTokensCoreError::Custom(format!(
+                    "{} Json RPC status code: {}",
+                    err_msg, status
+                ))),
+            },
+        }
+    }
+
+    pub fn call_and_get_json(&self, json: &JsonValue, err_msg: &str) -> PTokensResult<JsonValue> {
+        self.call(json, err_msg)?
+            .json::<JsonValue>()
+            .map_err(|e| PTokensCoreError::Custom(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_double_backoff_up_to_cap() {
+        let client = JsonRpcClient {
+            retry_backoff: Duration::from_millis(100),
+            ..Default::default()
+        };
+        assert_eq!(client.get_backoff(1), Duration::from_millis(100));
+        assert_eq!(client.get_backoff(3), Duration::from_millis(400));
+        assert_eq!(client.get_backoff(50), Duration::from_millis(100 * 1024));
+    }
+}
@@ -0,0 +1,117 @@
+use std::{env, time::Duration};
+
+use common::Bytes;
+
+use crate::{json_rpc_database::maybe_strip_hex_prefix, JsonRpcDatabaseError};
+
+const HOST_ENV_VAR: &str = "JSON_RPC_HOST";
+const TIMEOUT_ENV_VAR: &str = "JSON_RPC_DB_TIMEOUT_MS";
+const CONNECT_TIMEOUT_ENV_VAR: &str = "JSON_RPC_DB_CONNECT_TIMEOUT_MS";
+const MAX_RETRIES_ENV_VAR: &str = "JSON_RPC_DB_MAX_RETRIES";
+const RETRY_BACKOFF_ENV_VAR: &str = "JSON_RPC_DB_RETRY_BACKOFF_MS";
+const AUTH_TOKEN_ENV_VAR: &str = "JSON_RPC_DB_AUTH_TOKEN";
+const CLIENT_IDENTITY_ENV_VAR: &str = "JSON_RPC_DB_CLIENT_IDENTITY";
+const CA_CERT_ENV_VAR: &str = "JSON_RPC_DB_CA_CERT";
+const CACHE_SIZE_ENV_VAR: &str = "JSON_RPC_DB_CACHE_SIZE";
+const CACHEABLE_KEY_PREFIXES_ENV_VAR: &str = "JSON_RPC_DB_CACHEABLE_KEY_PREFIXES";
+
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+const DEFAULT_CONNECT_TIMEOUT_MS: u64 = 5_000;
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_RETRY_BACKOFF_MS: u64 = 250;
+const DEFAULT_CACHE_SIZE: usize = 0;
+
+/// Configuration for the JSON-RPC database, read from the environment. Only the `JSON_RPC_HOST`
+/// env var is required:
+///
+///  - `JSON_RPC_DB_TIMEOUT_MS`: Timeout for each request. (Default: 30000)
+///  - `JSON_RPC_DB_CONNECT_TIMEOUT_MS`: Timeout for connecting to the host. (Default: 5000)
+///  - `JSON_RPC_DB_MAX_RETRIES`: Max retries of a request failing with a transient error. (Default: 3)
+///  - `JSON_RPC_DB_RETRY_BACKOFF_MS`: Backoff before the first retry, doubling thereafter. (Default: 250)
+///  - `JSON_RPC_DB_AUTH_TOKEN`: Bearer token sent with each request.
+///  - `JSON_RPC_DB_CLIENT_IDENTITY`: Path to a PEM file with the client cert & private key for mTLS.
+///  - `JSON_RPC_DB_CA_CERT`: Path to a PEM file with an extra CA cert to trust, eg a self-signed one.
+///  - `JSON_RPC_DB_CACHE_SIZE`: Max number of values in the read-through cache. (Default: 0, ie disabled)
+///  - `JSON_RPC_DB_CACHEABLE_KEY_PREFIXES`: Comma separated hex prefixes of the keys whose values may
+///    be cached. Only keys whose values never change should be covered. (Default: none, ie disabled)
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct JsonRpcDatabaseConfig {
+    pub url: String,
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    pub max_retries: u32,
+    pub retry_backoff: Duration,
+    pub auth_token: Option<String>,
+    pub client_identity_path: Option<String>,
+    pub ca_cert_path: Option<String>,
+    pub cache_size: usize,
+    pub cacheable_key_prefixes: Vec<Bytes>,
+}
+
+fn get_optional_env_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|s| !s.is_empty())
+}
+
+fn get_env_var_or_default<T>(name: &str, default: T) -> Result<T, JsonRpcDatabaseError>
+where
+    T: std::str::FromStr<Err = std::num::ParseIntError>,
+{
+    match get_optional_env_var(name) {
+        Some(s) => Ok(s.parse::<T>()?),
+        None => Ok(default),
+    }
+}
+
+fn get_cacheable_key_prefixes() -> Result<Vec<Bytes>, JsonRpcDatabaseError> {
+    match get_optional_env_var(CACHEABLE_KEY_PREFIXES_ENV_VAR) {
+        Some(s) => parse_key_prefixes(&s),
+        None => Ok(vec![]),
+    }
+}
+
+fn parse_key_prefixes(s: &str) -> Result<Vec<Bytes>, JsonRpcDatabaseError> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|prefix| !prefix.is_empty())
+        .map(|prefix| Ok(hex::decode(maybe_strip_hex_prefix(prefix))?))
+        .collect()
+}
+
+impl JsonRpcDatabaseConfig {
+    pub fn from_env() -> Result<Self, JsonRpcDatabaseError> {
+        Ok(Self {
+            url: env::var(HOST_ENV_VAR)?,
+            auth_token: get_optional_env_var(AUTH_TOKEN_ENV_VAR),
+            ca_cert_path: get_optional_env_var(CA_CERT_ENV_VAR),
+            client_identity_path: get_optional_env_var(CLIENT_IDENTITY_ENV_VAR),
+            cache_size: get_env_var_or_default(CACHE_SIZE_ENV_VAR, DEFAULT_CACHE_SIZE)?,
+            cacheable_key_prefixes: get_cacheable_key_prefixes()?,
+            max_retries: get_env_var_or_default(MAX_RETRIES_ENV_VAR, DEFAULT_MAX_RETRIES)?,
+            timeout: Duration::from_millis(get_env_var_or_default(TIMEOUT_ENV_VAR, DEFAULT_TIMEOUT_MS)?),
+            retry_backoff: Duration::from_millis(get_env_var_or_default(
+                RETRY_BACKOFF_ENV_VAR,
+                DEFAULT_RETRY_BACKOFF_MS,
+            )?),
+            connect_timeout: Duration::from_millis(get_env_var_or_default(
+                CONNECT_TIMEOUT_ENV_VAR,
+                DEFAULT_CONNECT_TIMEOUT_MS,
+            )?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_key_prefixes() {
+        let result = parse_key_prefixes("0xc0ffee, decaf0,").unwrap();
+        assert_eq!(result, vec![vec![0xc0, 0xff, 0xee], vec![0xde, 0xca, 0xf0]]);
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_key_prefixes() {
+        assert!(parse_key_prefixes("0xc0ffee,nope").is_err());
+    }
+}
@@ -5,7 +5,6 @@ quick_error! {
             from()
             display("✘ Env var error: {}", err)
         }
-        /*
         Custom(err: String) {
             from()
             from(err: &str) -> (err.into())
@@ -15,6 +14,19 @@ quick_error! {
             from()
             display("✘ I/O Error: {}", err)
         }
+        ParseIntError(err: std::num::ParseIntError) {
+            from()
+            display("✘ Parse Int error: {}", err)
+        }
+        HexError(err: hex::FromHexError) {
+            from()
+            display("✘ Hex error: {}", err)
+        }
+        ReqwestError(err: reqwest::Error) {
+            from()
+            display("✘ Reqwest error: {}", err)
+        }
+        /*
         SystemTimeError(err: std::time::SystemTimeError) {
             from()
             display("✘ System time error: {}", err)
@@ -27,10 +39,6 @@ quick_error! {
             from()
             display("✘ SetLogger error: {}", err)
         }
-        ParseIntError(err: std::num::ParseIntError) {
-            from()
-            display("✘ Parse Int error: {}", err)
-        }
         DocoptError(err: docopt::Error) {
             from()
             display("✘ Docopt error: {}", err)
@@ -1,9 +1,16 @@
-use std::{cell::RefCell, collections::HashMap, env};
+use std::{cell::RefCell, collections::HashMap};
 
-use common::{AppError as PTokensCoreError, Bytes, DatabaseInterface, Result as PTokensResult};
-use reqwest::blocking::Client;
+use common::{
+    constants::DB_KEY_NOT_FOUND_ERROR,
+    AppError as PTokensCoreError,
+    Bytes,
+    DatabaseInterface,
+    Result as PTokensResult,
+};
 use serde_json::{json, Value as JsonValue};
 
+use crate::{client::JsonRpcClient, config::JsonRpcDatabaseConfig, read_cache::ReadCache};
+
 type DataSensitivity = Option<u8>;
 
 pub fn maybe_strip_hex_prefix(hex: &str) -> &str {
@@ -19,7 +26,8 @@ pub fn maybe_strip_hex_prefix(hex: &str) -> &str {
 
 #[derive(Default)]
 pub struct Database {
-    pub url: String,
+    pub client: JsonRpcClient,
+    pub read_cache: RefCell<ReadCache>,
     pub batch_db_ops: RefCell<Vec<DbOp>>,
     pub keys_to_delete: RefCell<Vec<Bytes>>,
     pub hashmap: RefCell<HashMap<Bytes, Bytes>>,
@@ -46,8 +54,13 @@ impl DbOp {
 
 impl Database {
     pub fn open() -> Result<Self, crate::JsonRpcDatabaseError> {
+        Self::open_with_config(&JsonRpcDatabaseConfig::from_env()?)
+    }
+
+    pub fn open_with_config(config: &JsonRpcDatabaseConfig) -> Result<Self, crate::JsonRpcDatabaseError> {
         Ok(Self {
-            url: env::var("JSON_RPC_HOST")?,
+            client: JsonRpcClient::new(config)?,
+            read_cache: RefCell::new(ReadCache::new(config.cache_size, config.cacheable_key_prefixes.clone())),
             hashmap: RefCell::new(HashMap::new()),
             batch_db_ops: RefCell::new(Vec::new()),
             keys_to_delete: RefCell::new(Vec::new()),
@@ -55,20 +68,6 @@ impl Database {
     }
 }
 
-fn make_simple_json_rpc_call(url: &str, json: JsonValue, err_msg: &str) -> PTokensResult<()> {
-    match Client::new().post(url).json(&json).send() {
-        Err(e) => Err(PTokensCoreError::Custom(e.to_string())),
-        Ok(body) => match body.status() {
-            reqwest::StatusCode::OK => Ok(()),
-            _ => Err(PTokensCoreError::Custom(format!(
-                "{} Json RPC status code: {}",
-                err_msg,
-                body.status()
-            ))),
-        },
-    }
-}
-
 fn extract_bytes_from_json_result(json: JsonValue) -> PTokensResult<Bytes> {
     match json.get("result") {
         None => Err(PTokensCoreError::Custom("No `result` key in json!!".into())),
@@ -90,6 +89,53 @@ fn extract_sensitivity_value(sensitivity: Option<u8>) -> u8 {
     sensitivity.unwrap_or_default()
 }
 
+fn get_get_request_params(key: &[u8], sensitivity: DataSensitivity) -> JsonValue {
+    json!([{ "key": hex::encode(key), "sensitivity": extract_sensitivity_value(sensitivity) }])
+}
+
+// NOTE: Responses to a batch request may arrive in any order, so are matched to keys via their IDs,
+// which are the indices of the keys in the batch.
+fn extract_bytes_from_batch_json_result(json: JsonValue, num_keys: usize) -> PTokensResult<Vec<Bytes>> {
+    let responses = match json {
+        JsonValue::Array(responses) => responses,
+        _ => return Err(PTokensCoreError::Custom("Batch `get` response is not an array!".into())),
+    };
+    let mut results: Vec<Option<Bytes>> = vec![None; num_keys];
+    for response in responses {
+        let i = response
+            .get("id")
+            .and_then(JsonValue::as_u64)
+            .map(|id| id as usize)
+            .filter(|i| *i < num_keys)
+            .ok_or_else(|| PTokensCoreError::Custom("Invalid `id` in batch `get` response!".into()))?;
+        results[i] = Some(extract_bytes_from_json_result(response)?);
+    }
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(i, result)| {
+            result.ok_or_else(|| PTokensCoreError::Custom(format!("No response for key #{} in batch `get`!", i)))
+        })
+        .collect()
+}
+
+impl Database {
+    // NOTE: Returns the value from the uncommitted writes or the read cache, if it's there.
+    fn get_locally(&self, key: &[u8]) -> PTokensResult<Option<Bytes>> {
+        if self.keys_to_delete.borrow().iter().any(|k| k == key) {
+            info!("✔ Key already in delete list ∴ 'not found'!");
+            return Err(PTokensCoreError::Custom(format!("✘ {DB_KEY_NOT_FOUND_ERROR}")));
+        };
+        trace!("✔ Checking hashmap & read cache for key...");
+        Ok(self
+            .hashmap
+            .borrow()
+            .get(key)
+            .cloned()
+            .or_else(|| self.read_cache.borrow().get(key)))
+    }
+}
+
 impl DatabaseInterface for Database {
     fn end_transaction(&self) -> PTokensResult<()> {
         info!("✔ Ending DB transaction in app...");
@@ -99,7 +145,15 @@ impl DatabaseInterface for Database {
             "params": self.batch_db_ops.borrow().iter().map(|db_op| db_op.to_json()).collect::<Vec<JsonValue>>(),
         });
         info!("✔ Batch writing to DB...");
-        make_simple_json_rpc_call(&self.url, json, "Error ending DB transaction!")
+        self.client.call(&json, "Error ending DB transaction!").map(|_| ())
+    }
+
+    fn clear_cache(&self) -> PTokensResult<()> {
+        trace!("✔ Clearing DB cache...");
+        self.hashmap.borrow_mut().clear();
+        self.batch_db_ops.borrow_mut().clear();
+        self.keys_to_delete.borrow_mut().clear();
+        Ok(())
     }
 
     fn start_transaction(&self) -> PTokensResult<()> {
@@ -109,10 +163,11 @@ impl DatabaseInterface for Database {
             "method": "start_transaction",
             "params": [],
         });
-        make_simple_json_rpc_call(&self.url, json, "Error starting DB transaction!")
+        self.client.call(&json, "Error starting DB transaction!").map(|_| ())
     }
 
     fn put(&self, key: Bytes, value: Bytes, sensitivity: DataSensitivity) -> PTokensResult<()> {
+        self.read_cache.borrow_mut().remove(&key);
         trace!("✔ Putting key in hashmap...");
         self.hashmap.borrow_mut().insert(key.clone(), value.clone());
         trace!("✔ Checking if key is in delete list... ");
@@ -130,6 +185,7 @@ impl DatabaseInterface for Database {
     }
 
     fn delete(&self, key: Bytes) -> PTokensResult<()> {
+        self.read_cache.borrow_mut().remove(&key);
         trace!("✔ Removing key from hashmap...");
         self.hashmap.borrow_mut().remove(&key);
         trace!("✔ Adding key to `to_delete` list...");
@@ -139,43 +195,63 @@ impl DatabaseInterface for Database {
     }
 
     fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> PTokensResult<Bytes> {
-        let not_in_db_error = "✘ Cannot find item in database!".to_string();
-        match self.keys_to_delete.borrow().contains(&key) {
-            true => {
-                info!("✔ Key already in delete list ∴ 'not found'!");
-                Err(PTokensCoreError::Custom(not_in_db_error))
-            },
-            false => {
-                trace!("✔ Checking hashmap for key...");
-                match self.hashmap.borrow().get(&key) {
-                    Some(value) => Ok(value.to_vec()),
-                    None => {
-                        let json = json!({
-                            "jsonrpc": "2.0",
-                            "method": "get",
-                            "params": [ json!({
-                                "key": hex::encode(&key),
-                                "sensitivity": extract_sensitivity_value(sensitivity)
-                            })],
-                        });
-                        info!("✘ Key NOT in hashmap! Looking in underlying DB...");
-                        match Client::new().post(&self.url).json(&json).send() {
-                            Err(e) => Err(PTokensCoreError::Custom(e.to_string())),
-                            Ok(body) => match body.status() {
-                                reqwest::StatusCode::OK => match body.json::<JsonValue>() {
-                                    Ok(json) => extract_bytes_from_json_result(json),
-                                    Err(e) => Err(PTokensCoreError::Custom(e.to_string())),
-                                },
-                                _ => Err(PTokensCoreError::Custom(format!(
-                                    "Error getting bytes from db! Json RPC status code: {}",
-                                    body.status()
-                                ))),
-                            },
-                        }
-                    },
-                }
-            },
+        if let Some(value) = self.get_locally(&key)? {
+            return Ok(value);
+        };
+        info!("✘ Key NOT in hashmap or read cache! Looking in underlying DB...");
+        let json = json!({
+            "jsonrpc": "2.0",
+            "method": "get",
+            "params": get_get_request_params(&key, sensitivity),
+        });
+        let value =
+            extract_bytes_from_json_result(self.client.call_and_get_json(&json, "Error getting bytes from db!")?)?;
+        self.read_cache.borrow_mut().insert(key, value.clone());
+        Ok(value)
+    }
+
+    fn get_multiple(&self, keys: Vec<Bytes>, sensitivity: DataSensitivity) -> PTokensResult<Vec<Bytes>> {
+        let mut values = keys
+            .iter()
+            .map(|key| self.get_locally(key))
+            .collect::<PTokensResult<Vec<Option<Bytes>>>>()?;
+        let keys_to_fetch = keys
+            .iter()
+            .zip(values.iter())
+            .filter(|(_, value)| value.is_none())
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<Bytes>>();
+        if keys_to_fetch.is_empty() {
+            return Ok(values.into_iter().flatten().collect());
+        };
+        info!("✔ Batch getting {} key(s) from underlying DB...", keys_to_fetch.len());
+        let json = JsonValue::Array(
+            keys_to_fetch
+                .iter()
+                .enumerate()
+                .map(|(i, key)| {
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": i,
+                        "method": "get",
+                        "params": get_get_request_params(key, sensitivity),
+                    })
+                })
+                .collect(),
+        );
+        let fetched = extract_bytes_from_batch_json_result(
+            self.client
+                .call_and_get_json(&json, "Error batch getting bytes from db!")?,
+            keys_to_fetch.len(),
+        )?;
+        let mut fetched = keys_to_fetch.into_iter().zip(fetched.into_iter());
+        for value in values.iter_mut().filter(|value| value.is_none()) {
+            if let Some((key, fetched_value)) = fetched.next() {
+                self.read_cache.borrow_mut().insert(key, fetched_value.clone());
+                *value = Some(fetched_value);
+            };
         }
+        Ok(values.into_iter().flatten().collect())
     }
 }
 
@@ -187,4 +263,20 @@ mod tests {
     fn should_get_default_database() {
         Database::default();
     }
+
+    #[test]
+    fn should_extract_bytes_from_out_of_order_batch_json_result() {
+        let json = json!([
+            {"jsonrpc": "2.0", "id": 1, "result": "0xc0ffee"},
+            {"jsonrpc": "2.0", "id": 0, "result": "decaf0"},
+        ]);
+        let result = extract_bytes_from_batch_json_result(json, 2).unwrap();
+        assert_eq!(result, vec![vec![0xde, 0xca, 0xf0], vec![0xc0, 0xff, 0xee]]);
+    }
+
+    #[test]
+    fn should_fail_to_extract_bytes_from_incomplete_batch_json_result() {
+        let json = json!([{"jsonrpc": "2.0", "id": 0, "result": "decaf0"}]);
+        assert!(extract_bytes_from_batch_json_result(json, 2).is_err());
+    }
 }
@@ -3,7 +3,10 @@ extern crate quick_error;
 #[macro_use]
 extern crate log;
 
+mod client;
+mod config;
 mod errors;
+mod read_cache;
 mod types;
 
 mod json_rpc_database;
@@ -14,4 +17,4 @@ pub fn get_db() -> Result<Database, JsonRpcDatabaseError> {
     Database::open()
 }
 
-pub use crate::errors::JsonRpcDatabaseError;
+pub use crate::{config::JsonRpcDatabaseConfig, errors::JsonRpcDatabaseError};
@@ -0,0 +1,87 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::Bytes;
+
+/// A bounded cache of values read from the remote store, evicting the oldest first. Only keys
+/// starting with one of the cacheable prefixes are cached, which should be those of values that
+/// never change. Since only committed values are ever read from the remote store, & any key
+/// written to is evicted regardless, the cache never holds uncommitted state, so it outlives
+/// transactions.
+#[derive(Debug, Default)]
+pub struct ReadCache {
+    capacity: usize,
+    cacheable_key_prefixes: Vec<Bytes>,
+    keys: VecDeque<Bytes>,
+    values: HashMap<Bytes, Bytes>,
+}
+
+impl ReadCache {
+    pub fn new(capacity: usize, cacheable_key_prefixes: Vec<Bytes>) -> Self {
+        Self {
+            capacity,
+            cacheable_key_prefixes,
+            ..Default::default()
+        }
+    }
+
+    fn is_cacheable(&self, key: &[u8]) -> bool {
+        self.capacity > 0 && self.cacheable_key_prefixes.iter().any(|prefix| key.starts_with(prefix))
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<Bytes> {
+        self.values.get(key).cloned()
+    }
+
+    pub fn insert(&mut self, key: Bytes, value: Bytes) {
+        if !self.is_cacheable(&key) || self.values.contains_key(&key) {
+            return;
+        };
+        if self.keys.len() >= self.capacity {
+            if let Some(oldest) = self.keys.pop_front() {
+                self.values.remove(&oldest);
+            };
+        };
+        self.keys.push_back(key.clone());
+        self.values.insert(key, value);
+    }
+
+    pub fn remove(&mut self, key: &[u8]) {
+        if self.values.remove(key).is_some() {
+            self.keys.retain(|k| k != key);
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_evict_oldest_values_once_full() {
+        let mut cache = ReadCache::new(2, vec![vec![]]);
+        cache.insert(vec![1], vec![1]);
+        cache.insert(vec![2], vec![2]);
+        cache.insert(vec![3], vec![3]);
+        assert_eq!(cache.get(&[1]), None);
+        assert_eq!(cache.get(&[2]), Some(vec![2]));
+        cache.remove(&[2]);
+        assert_eq!(cache.get(&[2]), None);
+        assert_eq!(cache.get(&[3]), Some(vec![3]));
+    }
+
+    #[test]
+    fn should_not_cache_if_capacity_is_zero() {
+        let mut cache = ReadCache::new(0, vec![vec![]]);
+        cache.insert(vec![1], vec![1]);
+        assert_eq!(cache.get(&[1]), None);
+    }
+
+    #[test]
+    fn should_only_cache_keys_with_cacheable_prefixes() {
+        let mut cache = ReadCache::new(2, vec![vec![0xc0, 0xff]]);
+        cache.insert(vec![0xc0, 0xff, 0xee], vec![1]);
+        cache.insert(vec![0xde, 0xca, 0xf0], vec![2]);
+        assert_eq!(cache.get(&[0xc0, 0xff, 0xee]), Some(vec![1]));
+        assert_eq!(cache.get(&[0xde, 0xca, 0xf0]), None);
+    }
+}
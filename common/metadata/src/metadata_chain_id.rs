@@ -6,7 +6,7 @@ use common::{
     types::{Byte, Bytes, Result},
     AppError,
 };
-use common_chain_ids::{AlgoChainId, BtcChainId, EosChainId, EthChainId};
+use common_chain_ids::{AlgoChainId, BtcChainId, ChainRegistry, ChainRegistryProtocol, EosChainId, EthChainId};
 use ethereum_types::H256 as KeccakHash;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
@@ -51,6 +51,8 @@ pub enum MetadataChainId {
     EthereumGoerli,   // 0x00b4f6c5
     EthereumSepolia,  // 0x0030d6b5
     LitecoinMainnet,  // 0x01840435
+    // NOTE: An EVM chain, by chain ID, found only in the chain registry, which defines its bytes.
+    RegisteredEvm(u64),
 }
 
 impl Default for MetadataChainId {
@@ -86,44 +88,22 @@ impl FromStr for MetadataChainId {
 
             // Eth...
             "ethunknown" | "0x00000000" => Ok(Self::EthUnknown),
-            "goerli" | "ethereumgoerli" | "0x00b4f6c5" => Ok(Self::EthereumGoerli),
-            "polygon" | "polygonmainnet" | "0x0075dd4c" => Ok(Self::PolygonMainnet),
-            "binance" | "bsc" | "bscmainnet" | "0x00e4b170" => Ok(Self::BscMainnet),
-            "luxo" | "luxochainmainnet" | "0x00d5beb0" => Ok(Self::LuxochainMainnet),
-            "ropsten" | "ethereumropsten" | "0x0069c322" => Ok(Self::EthereumRopsten),
-            "sepolia" | "ethereumsepolia" | "0x0030d6b5" => Ok(Self::EthereumSepolia),
-            "xdai" | "gnosis" | "xdaimainnet" | "0x00f1918e" => Ok(Self::XDaiMainnet),
-            "rinkeby" | "ethereumrinkeby" | "0x00f34368" => Ok(Self::EthereumRinkeby),
-            "int" | "interim" | "interimchain" | "0xffffffff" => Ok(Self::InterimChain),
-            "fmt" | "fantom" | "fantommainnet" | "0x0022af98" => Ok(Self::FantomMainnet),
-            "arb" | "arbitrum" | "arbritrummainnet" | "0x00ce98c4" => Ok(Self::ArbitrumMainnet),
-            "eth" | "ethMainnet" | "ethereummainnet" | "0x005fe7f9" => Ok(Self::EthereumMainnet),
 
             // Ltc...
             "ltc" | "litecoin" | "litecoinmainnet" | "0x01840435" => Ok(Self::LitecoinMainnet),
 
-            _ => Err(format!("Unrecognised chain id: '{s}'").into()),
+            // NOTE: EVM chains are defined in the chain registry, found in the `chain_ids` crate.
+            _ => Self::from_chain_registry_str(s),
         }
     }
 }
 
 impl From<&EthChainId> for MetadataChainId {
     fn from(ecid: &EthChainId) -> Self {
-        match ecid {
-            EthChainId::Goerli => Self::EthereumGoerli,
-            EthChainId::Sepolia => Self::EthereumSepolia,
-            EthChainId::Mainnet => Self::EthereumMainnet,
-            EthChainId::Rinkeby => Self::EthereumRinkeby,
-            EthChainId::Ropsten => Self::EthereumRopsten,
-            EthChainId::BscMainnet => Self::BscMainnet,
-            EthChainId::XDaiMainnet => Self::XDaiMainnet,
-            EthChainId::InterimChain => Self::InterimChain,
-            EthChainId::FantomMainnet => Self::FantomMainnet,
-            EthChainId::PolygonMainnet => Self::PolygonMainnet,
-            EthChainId::ArbitrumMainnet => Self::ArbitrumMainnet,
-            EthChainId::LuxochainMainnet => Self::LuxochainMainnet,
-            EthChainId::Unknown(..) => Self::EthUnknown,
-        }
+        ecid.get_registry_entry()
+            .and_then(|entry| entry.get_metadata_chain_id_bytes().ok())
+            .and_then(|bytes| Self::from_bytes(&bytes).ok())
+            .unwrap_or(Self::EthUnknown)
     }
 }
 
@@ -155,25 +135,39 @@ impl MetadataChainId {
             | Self::EthereumRopsten
             | Self::ArbitrumMainnet
             | Self::LuxochainMainnet
-            | Self::PolygonMainnet => MetadataProtocolId::Ethereum,
+            | Self::PolygonMainnet
+            | Self::RegisteredEvm(_) => MetadataProtocolId::Ethereum,
         }
     }
 
     pub fn to_eth_chain_id(&self) -> std::result::Result<EthChainId, MetadataChainIdError> {
-        match self {
-            Self::BscMainnet => Ok(EthChainId::BscMainnet),
-            Self::EthereumGoerli => Ok(EthChainId::Goerli),
-            Self::EthereumMainnet => Ok(EthChainId::Mainnet),
-            Self::EthereumRopsten => Ok(EthChainId::Ropsten),
-            Self::EthereumRinkeby => Ok(EthChainId::Rinkeby),
-            Self::EthereumSepolia => Ok(EthChainId::Sepolia),
-            Self::XDaiMainnet => Ok(EthChainId::XDaiMainnet),
-            Self::InterimChain => Ok(EthChainId::InterimChain),
-            Self::FantomMainnet => Ok(EthChainId::FantomMainnet),
-            Self::PolygonMainnet => Ok(EthChainId::PolygonMainnet),
-            // NOTE: Important -> this catch all arm means that any NEW evm based metadata chain
-            // ids will fall into this arm, unless they're explicitly added above.
-            other => Err(MetadataChainIdError::CannotConvertTo(*other, "EthChainId".into())),
+        let err = MetadataChainIdError::CannotConvertTo(*self, "EthChainId".into());
+        if self.to_protocol_id() != MetadataProtocolId::Ethereum {
+            return Err(err);
+        };
+        // NOTE: Only chains found in the chain registry can be converted, which excludes `EthUnknown`.
+        let maybe_chain_id = self.to_bytes().ok().and_then(|bytes| {
+            ChainRegistry::global()
+                .get_by_metadata_chain_id_bytes(&bytes)
+                .map(|entry| entry.chain_id)
+        });
+        match maybe_chain_id {
+            Some(chain_id) => EthChainId::try_from(chain_id).map_err(|_| err),
+            None => Err(err),
+        }
+    }
+
+    fn from_chain_registry_str(s: &str) -> Result<Self> {
+        let maybe_bytes = {
+            let registry = ChainRegistry::global();
+            registry
+                .get_by_alias(s)
+                .or_else(|| registry.get_by_metadata_chain_id_hex(s))
+                .map(|entry| entry.get_metadata_chain_id_bytes())
+        };
+        match maybe_bytes {
+            Some(bytes) => Self::from_bytes(&bytes?),
+            None => Err(format!("Unrecognised chain id: '{s}'").into()),
         }
     }
 
@@ -207,6 +201,7 @@ impl MetadataChainId {
             Self::EosJungleTestnet => Box::new(EosChainId::EosJungleTestnet),
             // NOTE: This is how LTC is handled in the forked library it uses
             Self::LitecoinMainnet => Box::new(BtcChainId::Bitcoin),
+            Self::RegisteredEvm(chain_id) => Box::new(EthChainId::Unknown(chain_id)),
         }
     }
 
@@ -242,6 +237,10 @@ impl MetadataChainId {
                 let random_bytes = vec![0x84, 0x04, 0x35];
                 Ok([vec![self.to_protocol_id().to_byte()], random_bytes].concat())
             },
+            Self::RegisteredEvm(chain_id) => ChainRegistry::global()
+                .get_by_chain_id(ChainRegistryProtocol::Ethereum, chain_id)
+                .ok_or_else(|| format!("No chain with ID {chain_id} in chain registry!"))?
+                .get_metadata_chain_id_bytes(),
             _ => Ok([
                 vec![self.to_protocol_id().to_byte()],
                 self.to_first_three_bytes_of_keccak_hash()?,
@@ -275,7 +274,9 @@ impl MetadataChainId {
                 .collect::<Vec<Option<Self>>>();
             match maybe_self.len() {
                 1 => maybe_self[0].ok_or_else(|| "Failed to unwrap `maybe_self` from option!".into()),
-                0 => Err(format!("Unrecognized bytes for `MetadataChainId`: 0x{}", hex::encode(bytes)).into()),
+                0 => Self::from_chain_registry_bytes(&ChainRegistry::global(), bytes).ok_or_else(|| {
+                    format!("Unrecognized bytes for `MetadataChainId`: 0x{}", hex::encode(bytes)).into()
+                }),
                 _ => Err("`MetadataChainId` collision! > 1 chain ID has the same 1st 3 bytes when hashed!".into()),
             }
         }
@@ -286,9 +287,19 @@ impl MetadataChainId {
         Self::get_all().iter().for_each(|id| println!("{}", id))
     }
 
+    // NOTE: For EVM chains that were added to the chain registry without a variant of their own.
+    fn from_chain_registry_bytes(registry: &ChainRegistry, bytes: &[Byte]) -> Option<Self> {
+        registry
+            .get_by_metadata_chain_id_bytes(bytes)
+            .filter(|entry| entry.protocol == ChainRegistryProtocol::Ethereum)
+            .map(|entry| Self::RegisteredEvm(entry.chain_id))
+    }
+
     fn get_all() -> Vec<Self> {
         use strum::IntoEnumIterator;
-        Self::iter().collect()
+        Self::iter()
+            .filter(|id| !matches!(id, Self::RegisteredEvm(_)))
+            .collect()
     }
 }
 
@@ -323,6 +334,7 @@ impl fmt::Display for MetadataChainId {
             Self::LuxochainMainnet => write!(f, "Luxochain Mainnet: {}", hex),
             Self::EosJungleTestnet => write!(f, "EOS Jungle Testnet: {}", hex),
             Self::BscMainnet => write!(f, "Binance Chain (BSC) Mainnet: {}", hex),
+            Self::RegisteredEvm(chain_id) => write!(f, "{}: {}", EthChainId::Unknown(*chain_id), hex),
         }
     }
 }
@@ -399,4 +411,72 @@ mod tests {
             Err(_) => panic!("Wrong error received!"),
         };
     }
+
+    #[test]
+    fn chain_registry_metadata_bytes_should_match_evm_metadata_chain_ids() {
+        MetadataChainId::get_all()
+            .into_iter()
+            .filter(|id| id.to_protocol_id() == MetadataProtocolId::Ethereum && id != &MetadataChainId::EthUnknown)
+            .for_each(|id| {
+                let eth_chain_id = id.to_eth_chain_id().unwrap();
+                let entry = eth_chain_id.get_registry_entry().unwrap();
+                assert_eq!(entry.get_metadata_chain_id_bytes().unwrap(), id.to_bytes().unwrap());
+                assert_eq!(MetadataChainId::from(&eth_chain_id), id);
+            })
+    }
+
+    #[test]
+    fn chain_registry_should_know_bytes_of_all_non_evm_metadata_chain_ids() {
+        let mut expected_result = MetadataChainId::get_all()
+            .iter()
+            .filter(|id| id.to_protocol_id() != MetadataProtocolId::Ethereum)
+            .map(|id| id.to_bytes().unwrap())
+            .collect::<Vec<Bytes>>();
+        let mut result = ChainRegistry::get_built_in_non_evm_metadata_chain_id_bytes().unwrap();
+        expected_result.sort();
+        result.sort();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_get_registered_evm_metadata_chain_id_from_chain_registry_bytes() {
+        let json = r#"[{
+            "name": "BaseMainnet",
+            "chain_id": 8453,
+            "protocol": "ethereum",
+            "metadata_chain_id": "0x00123456",
+            "network_id_name": "base",
+            "network_id_disambiguator": 0,
+            "safe_address": "0x71A440EE9Fa7F99FB9a697e96eC7839B8A1643B8",
+            "eip_1559_activation_block": 0,
+            "eip_4844_activation_block": null,
+            "gas_limit_multiplier": 1,
+            "aliases": ["base"]
+        }]"#;
+        let registry = ChainRegistry::compiled_in()
+            .unwrap()
+            .extend_from_json_str(json)
+            .unwrap();
+        let bytes = hex::decode("00123456").unwrap();
+        let result = MetadataChainId::from_chain_registry_bytes(&registry, &bytes).unwrap();
+        assert_eq!(result, MetadataChainId::RegisteredEvm(8453));
+        assert_eq!(result.to_protocol_id(), MetadataProtocolId::Ethereum);
+        assert!(MetadataChainId::from_chain_registry_bytes(&ChainRegistry::global(), &bytes).is_none());
+    }
+
+    #[test]
+    fn should_get_evm_metadata_chain_ids_from_registry_aliases() {
+        assert_eq!(
+            MetadataChainId::from_str("gnosis").unwrap(),
+            MetadataChainId::XDaiMainnet
+        );
+        assert_eq!(
+            MetadataChainId::from_str("0x005fe7f9").unwrap(),
+            MetadataChainId::EthereumMainnet
+        );
+        assert_eq!(
+            MetadataChainId::from_str("ethereum").unwrap(),
+            MetadataChainId::EthereumMainnet
+        );
+    }
 }
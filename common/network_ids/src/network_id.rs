@@ -1,7 +1,7 @@
 use std::{fmt, str::FromStr};
 
 use common::{Byte, Bytes};
-use common_chain_ids::EthChainId;
+use common_chain_ids::{ChainRegistry, ChainRegistryEntry, EthChainId};
 use common_metadata::{MetadataChainId, MetadataChainIdError};
 use derive_more::{Constructor, Deref};
 use ethabi::{encode as ethabi_encode, Token};
@@ -109,18 +109,33 @@ impl TryFrom<&str> for NetworkId {
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s.to_lowercase().as_ref() {
-            "gor" | "goerli" | "0xb9286154" | "b9286154" => Ok(Self::new_v1_for_evm(EthChainId::Goerli)),
-            "sep" | "sepolia" | "0xe15503e4" | "e15503e4" => Ok(Self::new_v1_for_evm(EthChainId::Sepolia)),
-            "eth" | "ethereum" | "0x60ef5904" | "60ef5904" => Ok(Self::new_v1_for_evm(EthChainId::Mainnet)),
-            "bsc" | "binance" | "0x5aca268b" | "5aca268b" => Ok(Self::new_v1_for_evm(EthChainId::BscMainnet)),
-            "int" | "interim" | "0x57791abb" | "57791abb" => Ok(Self::new_v1_for_evm(EthChainId::InterimChain)),
-            "fan" | "fantom" | "0x14ffc6a2" | "14ffc6a2" => Ok(Self::new_v1_for_evm(EthChainId::FantomMainnet)),
-            "pol" | "polygon" | "0xf9b459a1" | "f9b459a1" => Ok(Self::new_v1_for_evm(EthChainId::PolygonMainnet)),
-            "arb" | "arbitrum" | "0xfc8ebb2b" | "fc8ebb2b" => Ok(Self::new_v1_for_evm(EthChainId::ArbitrumMainnet)),
-            "lux" | "luxochain" | "0x58920253" | "58920253" => Ok(Self::new_v1_for_evm(EthChainId::LuxochainMainnet)),
-            "gno" | "gnosis" | "xdai" | "0xd41b1c5b" | "d41b1c5b" => Ok(Self::new_v1_for_evm(EthChainId::XDaiMainnet)),
             "00000000" | "0x00000000" | "unknown" | "ethunknown" => Ok(Self::new_v1_for_evm(EthChainId::Unknown(0))),
-            other => Err(NetworkIdError::InvalidNetworkId(other.to_string())),
+            other => {
+                let registry = ChainRegistry::global();
+                match registry.get_by_alias(other) {
+                    Some(entry) => Ok(Self::from(entry)),
+                    None => registry
+                        .iter()
+                        .map(Self::from)
+                        .find(|id| {
+                            id.to_hex()
+                                .map(|hex| hex == other || hex[2..] == *other)
+                                .unwrap_or(false)
+                        })
+                        .ok_or_else(|| NetworkIdError::InvalidNetworkId(other.to_string())),
+                }
+            },
+        }
+    }
+}
+
+impl From<&ChainRegistryEntry> for NetworkId {
+    fn from(entry: &ChainRegistryEntry) -> Self {
+        Self {
+            chain_id: entry.chain_id,
+            protocol_id: ProtocolId::from(entry.protocol),
+            disambiguator: entry.network_id_disambiguator,
+            version: NetworkIdVersion::V1,
         }
     }
 }
@@ -135,7 +150,10 @@ impl NetworkId {
     }
 
     pub fn new_v1_for_evm(ecid: EthChainId) -> Self {
-        Self::new_v1(ecid.to_u64(), ProtocolId::Ethereum)
+        match ecid.get_registry_entry() {
+            Some(ref entry) => Self::from(entry),
+            None => Self::new_v1(ecid.to_u64(), ProtocolId::Ethereum),
+        }
     }
 
     fn new_v1(chain_id: u64, protocol_id: ProtocolId) -> Self {
@@ -223,19 +241,14 @@ impl fmt::Display for NetworkId {
             Err(e) => write!(f, "error converting network id to bytes: {e}"),
             Ok(b4) => {
                 let s = hex::encode(b4.to_vec());
-                let x = match s.to_lowercase().as_ref() {
-                    "b9286154" => "goerli".to_string(),
-                    "e15503e4" => "sepolia".to_string(),
-                    "60ef5904" => "eth".to_string(),
-                    "5aca268b" => "binance".to_string(),
-                    "57791abb" => "interim".to_string(),
-                    "14ffc6a2" => "fantom".to_string(),
-                    "f9b459a1" => "polygon".to_string(),
-                    "fc8ebb2b" => "arbitrum".to_string(),
-                    "58920253" => "luxochain".to_string(),
-                    "d41b1c5b" => "gnosis".to_string(),
-                    "00000000" => "ethUnknown".to_string(),
-                    other => format!("unknown: {other}"),
+                let x = if s == "00000000" {
+                    "ethUnknown".to_string()
+                } else {
+                    ChainRegistry::global()
+                        .iter()
+                        .find(|entry| NetworkId::from(*entry).to_bytes_4().map(|b| b == b4).unwrap_or(false))
+                        .map(|entry| entry.network_id_name.clone())
+                        .unwrap_or_else(|| format!("unknown: {s}"))
                 };
                 write!(f, "{x}")
             },
@@ -249,19 +262,10 @@ impl TryFrom<&MetadataChainId> for NetworkId {
     fn try_from(m: &MetadataChainId) -> Result<NetworkId, Self::Error> {
         match m {
             MetadataChainId::EthUnknown => Ok(NetworkId::new_v1_for_evm(EthChainId::Unknown(0))),
-            MetadataChainId::BscMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::BscMainnet)),
-            MetadataChainId::EthereumGoerli => Ok(NetworkId::new_v1_for_evm(EthChainId::Goerli)),
-            MetadataChainId::EthereumMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::Mainnet)),
-            MetadataChainId::EthereumRopsten => Ok(NetworkId::new_v1_for_evm(EthChainId::Ropsten)),
-            MetadataChainId::EthereumRinkeby => Ok(NetworkId::new_v1_for_evm(EthChainId::Rinkeby)),
-            MetadataChainId::EthereumSepolia => Ok(NetworkId::new_v1_for_evm(EthChainId::Sepolia)),
-            MetadataChainId::XDaiMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::XDaiMainnet)),
-            MetadataChainId::InterimChain => Ok(NetworkId::new_v1_for_evm(EthChainId::InterimChain)),
-            MetadataChainId::FantomMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::FantomMainnet)),
-            MetadataChainId::PolygonMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::PolygonMainnet)),
-            MetadataChainId::ArbitrumMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::ArbitrumMainnet)),
-            MetadataChainId::LuxochainMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::LuxochainMainnet)),
-            mcid => Err(Self::Error::CannotConvertTo(*mcid, "NetworkId".to_string())),
+            mcid => mcid
+                .to_eth_chain_id()
+                .map(NetworkId::new_v1_for_evm)
+                .map_err(|_| Self::Error::CannotConvertTo(*mcid, "NetworkId".to_string())),
         }
     }
 }
@@ -282,24 +286,13 @@ impl TryFrom<&NetworkId> for MetadataChainId {
             from: *m,
             to: "MetadataChainId".to_string(),
         };
-        if let Ok(ecid) = EthChainId::try_from(m.chain_id) {
-            match ecid {
-                EthChainId::BscMainnet => Ok(MetadataChainId::BscMainnet),
-                EthChainId::Goerli => Ok(MetadataChainId::EthereumGoerli),
-                EthChainId::Mainnet => Ok(MetadataChainId::EthereumMainnet),
-                EthChainId::Ropsten => Ok(MetadataChainId::EthereumRopsten),
-                EthChainId::Rinkeby => Ok(MetadataChainId::EthereumRinkeby),
-                EthChainId::Sepolia => Ok(MetadataChainId::EthereumSepolia),
-                EthChainId::XDaiMainnet => Ok(MetadataChainId::XDaiMainnet),
-                EthChainId::InterimChain => Ok(MetadataChainId::InterimChain),
-                EthChainId::FantomMainnet => Ok(MetadataChainId::FantomMainnet),
-                EthChainId::PolygonMainnet => Ok(MetadataChainId::PolygonMainnet),
-                EthChainId::ArbitrumMainnet => Ok(MetadataChainId::ArbitrumMainnet),
-                EthChainId::LuxochainMainnet => Ok(MetadataChainId::LuxochainMainnet),
-                EthChainId::Unknown(_) => Err(err),
-            }
-        } else {
-            Err(err)
+        let maybe_metadata_chain_id_bytes = ChainRegistry::global()
+            .iter()
+            .find(|entry| &NetworkId::from(*entry) == m)
+            .map(|entry| entry.get_metadata_chain_id_bytes());
+        match maybe_metadata_chain_id_bytes {
+            Some(Ok(bytes)) => MetadataChainId::from_bytes(&bytes).map_err(|_| err),
+            _ => Err(err),
         }
     }
 }
@@ -334,4 +327,30 @@ mod tests {
         let expected_result = "00000000";
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn network_id_strings_should_make_display_roundtrip() {
+        ChainRegistry::global().iter().for_each(|entry| {
+            let id = NetworkId::from(entry);
+            let s = id.to_string();
+            assert_eq!(s, entry.network_id_name);
+            assert_eq!(NetworkId::from_str(&s).unwrap(), id);
+        })
+    }
+
+    #[test]
+    fn should_get_network_id_from_hex() {
+        let expected_result = NetworkId::new_v1_for_evm(EthChainId::XDaiMainnet);
+        assert_eq!(NetworkId::from_str("0xd41b1c5b").unwrap(), expected_result);
+        assert_eq!(NetworkId::from_str("d41b1c5b").unwrap(), expected_result);
+    }
+
+    #[test]
+    fn should_convert_between_network_ids_and_metadata_chain_ids() {
+        let mcid = MetadataChainId::PolygonMainnet;
+        let network_id = NetworkId::try_from(&mcid).unwrap();
+        assert_eq!(network_id, NetworkId::new_v1_for_evm(EthChainId::PolygonMainnet));
+        let result = MetadataChainId::try_from(&network_id).unwrap();
+        assert_eq!(result, mcid);
+    }
 }
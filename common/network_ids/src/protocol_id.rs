@@ -1,5 +1,6 @@
 use std::{fmt, str::FromStr};
 
+use common_chain_ids::ChainRegistryProtocol;
 use common_metadata::MetadataProtocolId;
 use serde::{Deserialize, Serialize};
 #[cfg(test)]
@@ -55,6 +56,17 @@ impl From<MetadataProtocolId> for ProtocolId {
     }
 }
 
+impl From<ChainRegistryProtocol> for ProtocolId {
+    fn from(p: ChainRegistryProtocol) -> Self {
+        match p {
+            ChainRegistryProtocol::Eos => Self::Eos,
+            ChainRegistryProtocol::Bitcoin => Self::Bitcoin,
+            ChainRegistryProtocol::Algorand => Self::Algorand,
+            ChainRegistryProtocol::Ethereum => Self::Ethereum,
+        }
+    }
+}
+
 impl From<ProtocolId> for u8 {
     fn from(id: ProtocolId) -> u8 {
         match id {
@@ -0,0 +1,95 @@
+use docopt::Docopt;
+use rocksdb_database::{
+    get_unencrypted_db_at_path,
+    EncryptionKeySource,
+    RocksdbDatabaseError,
+    ENCRYPTION_KEY_FILE_ENV_VAR,
+    ENCRYPTION_PASSPHRASE_ENV_VAR,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+const NEW_KEY_FILE_ENV_VAR: &str = "ROCKSDB_ENCRYPTION_NEW_KEY_FILE";
+const NEW_PASSPHRASE_ENV_VAR: &str = "ROCKSDB_ENCRYPTION_NEW_PASSPHRASE";
+
+static USAGE_INFO: &str = "
+❍ RocksDB Encryption Tool ❍
+
+Encrypts, decrypts or rotates the encryption key of an existing RocksDB database used by a vanilla
+app. Each operation is written atomically. Stop the app using the database before running this tool.
+
+Keys are read from the environment so as to keep them out of the process list:
+
+    ROCKSDB_ENCRYPTION_KEY_FILE         ❍ Path to a file containing the current 32 byte hex key.
+    ROCKSDB_ENCRYPTION_PASSPHRASE       ❍ The current passphrase, used if there's no key file.
+    ROCKSDB_ENCRYPTION_NEW_KEY_FILE     ❍ Path to a file containing the new 32 byte hex key.
+    ROCKSDB_ENCRYPTION_NEW_PASSPHRASE   ❍ The new passphrase, used if there's no new key file.
+
+Usage:  rocksdb-encryption-tool [--help]
+        rocksdb-encryption-tool encrypt [--path=<path>]
+        rocksdb-encryption-tool decrypt [--path=<path>]
+        rocksdb-encryption-tool rotate [--path=<path>]
+
+Commands:
+    encrypt                             ❍ Encrypts all plaintext values in the database with the current key.
+
+    decrypt                             ❍ Decrypts all encrypted values in the database with the current key.
+
+    rotate                              ❍ Re-encrypts all encrypted values in the database, replacing the current
+                                          key with the new one.
+
+Options:
+    --help                              ❍ Show this message.
+
+    --path=<path>                       ❍ Path to the database [default: ./database]
+";
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+struct CliArgs {
+    cmd_encrypt: bool,
+    cmd_decrypt: bool,
+    cmd_rotate: bool,
+    flag_path: String,
+}
+
+fn get_key_source(
+    key_file_env_var: &str,
+    passphrase_env_var: &str,
+) -> Result<EncryptionKeySource, RocksdbDatabaseError> {
+    EncryptionKeySource::from_env_vars(key_file_env_var, passphrase_env_var)
+        .ok_or_else(|| format!("Please set either the `{key_file_env_var}` or `{passphrase_env_var}` env var!").into())
+}
+
+fn program() -> Result<String, RocksdbDatabaseError> {
+    let cli_args: CliArgs = Docopt::new(USAGE_INFO)
+        .and_then(|d| d.deserialize())
+        .unwrap_or_else(|e| e.exit());
+    let path = cli_args.flag_path.clone();
+    let db = get_unencrypted_db_at_path(&path)?;
+    let source = get_key_source(ENCRYPTION_KEY_FILE_ENV_VAR, ENCRYPTION_PASSPHRASE_ENV_VAR)?;
+    match cli_args {
+        CliArgs { cmd_encrypt: true, .. } => db
+            .encrypt_all_values(&source)
+            .map(|n| json!({"path": path, "num_values_encrypted": n}).to_string()),
+        CliArgs { cmd_decrypt: true, .. } => db
+            .decrypt_all_values(&source)
+            .map(|n| json!({"path": path, "num_values_decrypted": n}).to_string()),
+        CliArgs { cmd_rotate: true, .. } => {
+            let new_source = get_key_source(NEW_KEY_FILE_ENV_VAR, NEW_PASSPHRASE_ENV_VAR)?;
+            db.rotate_encryption_key(&source, &new_source)
+                .map(|n| json!({"path": path, "num_values_rotated": n}).to_string())
+        },
+        _ => Err(USAGE_INFO.into()),
+    }
+}
+
+fn main() {
+    match program() {
+        Ok(s) => println!("{s}"),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        },
+    }
+}
@@ -0,0 +1,154 @@
+use std::env;
+
+use common::{AppError as PTokensCoreError, Bytes, DatabaseInterface, Result as PTokensResult};
+
+use crate::{
+    encryption::{DbCipher, EncryptionKeySource, EncryptionParams},
+    rocks_db::Database,
+    RocksdbDatabaseError,
+};
+
+pub const ENCRYPTION_THRESHOLD_ENV_VAR: &str = "ROCKSDB_ENCRYPTION_THRESHOLD";
+
+// NOTE: By default only values stored with the `MAX_DATA_SENSITIVITY_LEVEL`, ie private keys, are
+// encrypted.
+const DEFAULT_ENCRYPTION_THRESHOLD: u8 = 255;
+
+type DataSensitivity = Option<u8>;
+
+/// A RocksDB database that encrypts any value whose data sensitivity is at or above the threshold.
+/// Encrypted values are decrypted when read regardless of the data sensitivity passed in, whilst
+/// plaintext ones are returned as is, meaning a database can be encrypted piecemeal.
+pub struct EncryptedDatabase {
+    db: Database,
+    threshold: u8,
+    cipher: Option<DbCipher>,
+}
+
+impl EncryptedDatabase {
+    /// Wraps the database, encrypting with the key from the passed in source if there is one. If
+    /// the database has not yet been encrypted, its encryption params are generated & saved.
+    pub fn new(db: Database, source: Option<EncryptionKeySource>, threshold: u8) -> Result<Self, RocksdbDatabaseError> {
+        let params = EncryptionParams::get_from_rocks_db(&db.rocks_db)?;
+        let cipher = match (source, params) {
+            (None, None) => None,
+            (None, Some(_)) => return Err("Database is encrypted but no encryption key was supplied!".into()),
+            (Some(source), Some(params)) => Some(DbCipher::new(&source, Some(&params))?),
+            (Some(source), None) => {
+                info!("✔ Generating database encryption params...");
+                let cipher = DbCipher::new(&source, None)?;
+                db.put_encryption_params(cipher.get_params())?;
+                Some(cipher)
+            },
+        };
+        Ok(Self { db, cipher, threshold })
+    }
+
+    /// Wraps the database using the encryption key source & threshold from the environment.
+    pub fn from_env(db: Database) -> Result<Self, RocksdbDatabaseError> {
+        let threshold = match env::var(ENCRYPTION_THRESHOLD_ENV_VAR) {
+            Ok(s) => s
+                .parse::<u8>()
+                .map_err(|e| format!("Invalid {ENCRYPTION_THRESHOLD_ENV_VAR} env var: {e}"))?,
+            Err(_) => DEFAULT_ENCRYPTION_THRESHOLD,
+        };
+        Self::new(db, EncryptionKeySource::from_env(), threshold)
+    }
+
+    fn should_encrypt(&self, sensitivity: DataSensitivity) -> bool {
+        sensitivity.unwrap_or_default() >= self.threshold
+    }
+}
+
+impl DatabaseInterface for EncryptedDatabase {
+    fn end_transaction(&self) -> PTokensResult<()> {
+        self.db.end_transaction()
+    }
+
+    fn clear_cache(&self) -> PTokensResult<()> {
+        self.db.clear_cache()
+    }
+
+    fn start_transaction(&self) -> PTokensResult<()> {
+        self.db.start_transaction()
+    }
+
+    fn put(&self, key: Bytes, value: Bytes, sensitivity: DataSensitivity) -> PTokensResult<()> {
+        match &self.cipher {
+            Some(cipher) if self.should_encrypt(sensitivity) => {
+                trace!("✔ Encrypting value before putting it in DB...");
+                let encrypted = cipher
+                    .encrypt(&key, &value)
+                    .map_err(|e| PTokensCoreError::Custom(e.to_string()))?;
+                self.db.put(key, encrypted, sensitivity)
+            },
+            _ => self.db.put(key, value, sensitivity),
+        }
+    }
+
+    fn delete(&self, key: Bytes) -> PTokensResult<()> {
+        self.db.delete(key)
+    }
+
+    fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> PTokensResult<Bytes> {
+        let value = self.db.get(key.clone(), sensitivity)?;
+        match &self.cipher {
+            _ if !DbCipher::is_encrypted(&value) => {
+                if self.cipher.is_some() && self.should_encrypt(sensitivity) {
+                    warn!("✘ Sensitive value in DB is not encrypted - consider migrating the DB!");
+                };
+                Ok(value)
+            },
+            Some(cipher) => {
+                trace!("✔ Decrypting value gotten from DB...");
+                cipher
+                    .decrypt(&key, &value)
+                    .map_err(|e| PTokensCoreError::Custom(e.to_string()))
+            },
+            None => Err(PTokensCoreError::Custom(
+                "Cannot decrypt value ∵ no database encryption key was supplied!".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDir;
+
+    #[test]
+    fn should_encrypt_only_sensitive_values_in_rocks_db() {
+        let dir = TestDir::new("encrypted-db");
+        let db = EncryptedDatabase::new(dir.open_db(), Some(dir.get_key_source(1)), 255).unwrap();
+        let (sensitive_key, sensitive_value) = (vec![1; 32], b"private key".to_vec());
+        let (key, value) = (vec![2; 32], b"public value".to_vec());
+        db.start_transaction().unwrap();
+        db.put(sensitive_key.clone(), sensitive_value.clone(), Some(255))
+            .unwrap();
+        db.put(key.clone(), value.clone(), None).unwrap();
+        db.end_transaction().unwrap();
+        db.clear_cache().unwrap();
+
+        let raw_sensitive_value = db.db.rocks_db.get(&sensitive_key).unwrap().unwrap();
+        assert!(DbCipher::is_encrypted(&raw_sensitive_value));
+        assert_eq!(db.db.rocks_db.get(&key).unwrap().unwrap(), value);
+        assert_eq!(db.get(sensitive_key, Some(255)).unwrap(), sensitive_value);
+        assert_eq!(db.get(key, None).unwrap(), value);
+    }
+
+    #[test]
+    fn should_require_correct_key_to_reopen_encrypted_rocks_db() {
+        let dir = TestDir::new("reopen-encrypted-db");
+        let (key, value) = (vec![1; 32], b"private key".to_vec());
+        let db = EncryptedDatabase::new(dir.open_db(), Some(dir.get_key_source(1)), 255).unwrap();
+        db.put(key.clone(), value.clone(), Some(255)).unwrap();
+        db.end_transaction().unwrap();
+        drop(db);
+
+        assert!(EncryptedDatabase::new(dir.open_db(), None, 255).is_err());
+        assert!(EncryptedDatabase::new(dir.open_db(), Some(dir.get_key_source(2)), 255).is_err());
+        let db = EncryptedDatabase::new(dir.open_db(), Some(dir.get_key_source(1)), 255).unwrap();
+        assert_eq!(db.get(key, Some(255)).unwrap(), value);
+    }
+}
@@ -0,0 +1,244 @@
+use std::{env, fs::read_to_string};
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    Key,
+    XChaCha20Poly1305,
+    XNonce,
+};
+use common::{utils::get_prefixed_db_key, Bytes};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::RocksdbDatabaseError;
+
+pub const ENCRYPTION_KEY_FILE_ENV_VAR: &str = "ROCKSDB_ENCRYPTION_KEY_FILE";
+pub const ENCRYPTION_PASSPHRASE_ENV_VAR: &str = "ROCKSDB_ENCRYPTION_PASSPHRASE";
+
+const KEY_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 24;
+const ENVELOPE_VERSION: u8 = 1;
+// NOTE: Prefixed to every encrypted value, which is how they're told apart from plaintext ones.
+const ENVELOPE_MAGIC_BYTES: [u8; 4] = *b"pENC";
+const ENVELOPE_HEADER_LENGTH: usize = ENVELOPE_MAGIC_BYTES.len() + 1 + NONCE_LENGTH;
+const KEY_CHECK_PLAINTEXT: &[u8] = b"rocksdb encryption key check";
+
+// NOTE: The OWASP recommended minimums for scrypt.
+const SCRYPT_LOG_N: u8 = 17;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+pub fn get_encryption_params_db_key() -> Bytes {
+    get_prefixed_db_key("rocksdb_encryption_params_key").to_vec()
+}
+
+/// The source of the key used to encrypt values in the database. Key files must contain a 32 byte
+/// key in hex, whilst passphrases are stretched into a key via scrypt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EncryptionKeySource {
+    KeyFile(String),
+    Passphrase(String),
+}
+
+impl EncryptionKeySource {
+    /// Gets the key source from the environment, preferring a key file over a passphrase.
+    pub fn from_env() -> Option<Self> {
+        Self::from_env_vars(ENCRYPTION_KEY_FILE_ENV_VAR, ENCRYPTION_PASSPHRASE_ENV_VAR)
+    }
+
+    pub fn from_env_vars(key_file_env_var: &str, passphrase_env_var: &str) -> Option<Self> {
+        match (env::var(key_file_env_var), env::var(passphrase_env_var)) {
+            (Ok(path), _) if !path.is_empty() => Some(Self::KeyFile(path)),
+            (_, Ok(passphrase)) if !passphrase.is_empty() => Some(Self::Passphrase(passphrase)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kdf", rename_all = "snake_case")]
+pub enum KeyDerivation {
+    KeyFile,
+    Scrypt { log_n: u8, r: u32, p: u32, salt: String },
+}
+
+impl KeyDerivation {
+    fn new(source: &EncryptionKeySource) -> Self {
+        match source {
+            EncryptionKeySource::KeyFile(_) => Self::KeyFile,
+            EncryptionKeySource::Passphrase(_) => {
+                let mut salt = [0u8; SALT_LENGTH];
+                rand::thread_rng().fill_bytes(&mut salt);
+                Self::Scrypt {
+                    r: SCRYPT_R,
+                    p: SCRYPT_P,
+                    log_n: SCRYPT_LOG_N,
+                    salt: hex::encode(salt),
+                }
+            },
+        }
+    }
+
+    fn derive_key(&self, source: &EncryptionKeySource) -> Result<Bytes, RocksdbDatabaseError> {
+        match (self, source) {
+            (Self::KeyFile, EncryptionKeySource::KeyFile(path)) => {
+                let key = hex::decode(read_to_string(path)?.trim().trim_start_matches("0x"))?;
+                if key.len() != KEY_LENGTH {
+                    Err(format!(
+                        "Encryption key in '{path}' must be {KEY_LENGTH} bytes, not {}!",
+                        key.len()
+                    )
+                    .into())
+                } else {
+                    Ok(key)
+                }
+            },
+            (Self::Scrypt { log_n, r, p, salt }, EncryptionKeySource::Passphrase(passphrase)) => {
+                let mut key = vec![0u8; KEY_LENGTH];
+                let params = scrypt::Params::new(*log_n, *r, *p, KEY_LENGTH)?;
+                scrypt::scrypt(passphrase.as_bytes(), &hex::decode(salt)?, &params, &mut key)?;
+                Ok(key)
+            },
+            (Self::KeyFile, _) => Err("Database was encrypted with a key file, not a passphrase!".into()),
+            (Self::Scrypt { .. }, _) => Err("Database was encrypted with a passphrase, not a key file!".into()),
+        }
+    }
+}
+
+/// The parameters required to re-derive a database's encryption key from its source, plus a value
+/// encrypted with that key, used to check the correct key has been supplied. These are stored in
+/// plaintext in the database itself.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionParams {
+    key_derivation: KeyDerivation,
+    key_check: String,
+}
+
+impl EncryptionParams {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RocksdbDatabaseError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    pub fn to_bytes(&self) -> Result<Bytes, RocksdbDatabaseError> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    pub fn get_from_rocks_db(db: &rocksdb::DB) -> Result<Option<Self>, RocksdbDatabaseError> {
+        match db.get(get_encryption_params_db_key())? {
+            Some(bytes) => Ok(Some(Self::from_bytes(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+pub struct DbCipher {
+    cipher: XChaCha20Poly1305,
+    params: EncryptionParams,
+}
+
+impl DbCipher {
+    /// Creates a cipher for a database encrypted with the passed in params, checking the key
+    /// derived from the passed in source is the correct one. If there are no params, fresh ones are
+    /// generated, which must then be saved in the database.
+    pub fn new(source: &EncryptionKeySource, params: Option<&EncryptionParams>) -> Result<Self, RocksdbDatabaseError> {
+        let key_derivation = match params {
+            Some(params) => params.key_derivation.clone(),
+            None => KeyDerivation::new(source),
+        };
+        let key = key_derivation.derive_key(source)?;
+        Self::from_key(&key, key_derivation, params.map(|params| params.key_check.clone()))
+    }
+
+    fn from_key(
+        key: &[u8],
+        key_derivation: KeyDerivation,
+        key_check: Option<String>,
+    ) -> Result<Self, RocksdbDatabaseError> {
+        let mut cipher = Self {
+            cipher: XChaCha20Poly1305::new(Key::from_slice(key)),
+            params: EncryptionParams {
+                key_derivation,
+                key_check: String::new(),
+            },
+        };
+        let key_check_db_key = get_encryption_params_db_key();
+        cipher.params.key_check = match key_check {
+            Some(key_check) => match cipher.decrypt(&key_check_db_key, &hex::decode(&key_check)?) {
+                Ok(plaintext) if plaintext == KEY_CHECK_PLAINTEXT => Ok(key_check),
+                _ => Err(RocksdbDatabaseError::from("Incorrect database encryption key!")),
+            },
+            None => Ok(hex::encode(cipher.encrypt(&key_check_db_key, KEY_CHECK_PLAINTEXT)?)),
+        }?;
+        Ok(cipher)
+    }
+
+    pub fn get_params(&self) -> &EncryptionParams {
+        &self.params
+    }
+
+    pub fn is_encrypted(value: &[u8]) -> bool {
+        value.len() > ENVELOPE_HEADER_LENGTH
+            && value[..ENVELOPE_MAGIC_BYTES.len()] == ENVELOPE_MAGIC_BYTES
+            && value[ENVELOPE_MAGIC_BYTES.len()] == ENVELOPE_VERSION
+    }
+
+    /// Encrypts the value, binding it to the database key it's stored under so that encrypted
+    /// values cannot be swapped between keys.
+    pub fn encrypt(&self, db_key: &[u8], value: &[u8]) -> Result<Bytes, RocksdbDatabaseError> {
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let ciphertext = self.cipher.encrypt(XNonce::from_slice(&nonce), Payload {
+            msg: value,
+            aad: db_key,
+        })?;
+        Ok([&ENVELOPE_MAGIC_BYTES[..], &[ENVELOPE_VERSION], &nonce, &ciphertext].concat())
+    }
+
+    pub fn decrypt(&self, db_key: &[u8], value: &[u8]) -> Result<Bytes, RocksdbDatabaseError> {
+        if !Self::is_encrypted(value) {
+            return Err("Cannot decrypt value ∵ it is not encrypted!".into());
+        };
+        let nonce = XNonce::from_slice(&value[ENVELOPE_MAGIC_BYTES.len() + 1..ENVELOPE_HEADER_LENGTH]);
+        Ok(self.cipher.decrypt(nonce, Payload {
+            msg: &value[ENVELOPE_HEADER_LENGTH..],
+            aad: db_key,
+        })?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_cipher(key_byte: u8) -> DbCipher {
+        DbCipher::from_key(&[key_byte; KEY_LENGTH], KeyDerivation::KeyFile, None).unwrap()
+    }
+
+    #[test]
+    fn should_encrypt_and_decrypt_value() {
+        let cipher = get_sample_cipher(1);
+        let value = b"some private key".to_vec();
+        let encrypted = cipher.encrypt(b"key", &value).unwrap();
+        assert!(DbCipher::is_encrypted(&encrypted));
+        assert!(!DbCipher::is_encrypted(&value));
+        assert_eq!(cipher.decrypt(b"key", &encrypted).unwrap(), value);
+    }
+
+    #[test]
+    fn should_fail_to_decrypt_with_wrong_db_key_or_cipher() {
+        let cipher = get_sample_cipher(1);
+        let encrypted = cipher.encrypt(b"key", b"value").unwrap();
+        assert!(cipher.decrypt(b"other key", &encrypted).is_err());
+        assert!(get_sample_cipher(2).decrypt(b"key", &encrypted).is_err());
+    }
+
+    #[test]
+    fn should_only_accept_correct_key_via_key_check() {
+        let params = get_sample_cipher(1).get_params().clone();
+        let result = DbCipher::from_key(&[1; KEY_LENGTH], KeyDerivation::KeyFile, Some(params.key_check.clone()));
+        assert!(result.is_ok());
+        let result = DbCipher::from_key(&[2; KEY_LENGTH], KeyDerivation::KeyFile, Some(params.key_check));
+        assert!(result.is_err());
+    }
+}
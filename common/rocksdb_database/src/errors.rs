@@ -1,9 +1,38 @@
 quick_error! {
     #[derive(Debug)]
     pub enum RocksdbDatabaseError {
+        Custom(err: String) {
+            from()
+            from(err: &str) -> (err.into())
+            display("✘ {}", err)
+        }
         RocksDbError(err: rocksdb::Error) {
             from()
             display("✘ Rocks DB error: {}", err)
         }
+        IoError(err: std::io::Error) {
+            from()
+            display("✘ I/O error: {}", err)
+        }
+        HexError(err: hex::FromHexError) {
+            from()
+            display("✘ Hex error: {}", err)
+        }
+        SerdeJsonError(err: serde_json::Error) {
+            from()
+            display("✘ Serde JSON error: {}", err)
+        }
+        AeadError(err: chacha20poly1305::aead::Error) {
+            from()
+            display("✘ Encryption error: {}", err)
+        }
+        ScryptParamsError(err: scrypt::errors::InvalidParams) {
+            from()
+            display("✘ Scrypt params error: {}", err)
+        }
+        ScryptOutputLenError(err: scrypt::errors::InvalidOutputLen) {
+            from()
+            display("✘ Scrypt output length error: {}", err)
+        }
     }
 }
@@ -1,19 +1,40 @@
+//! # The RocksDB Database
+//!
+//! A RocksDB implementation of the `DatabaseInterface`, optionally encrypting values at rest. To
+//! enable encryption, set either the `ROCKSDB_ENCRYPTION_KEY_FILE` env var to the path of a file
+//! containing a 32 byte hex key, or the `ROCKSDB_ENCRYPTION_PASSPHRASE` env var to a passphrase.
+//! Values stored with a data sensitivity at or above the `ROCKSDB_ENCRYPTION_THRESHOLD` env var
+//! (default: 255) are then encrypted. Existing databases may be encrypted, decrypted, or have their
+//! key rotated via the `rocksdb-encryption-tool` binary.
+
 #[macro_use]
 extern crate quick_error;
 #[macro_use]
 extern crate log;
 
+mod encrypted_db;
+mod encryption;
 mod errors;
+mod migration;
 mod rocks_db;
+#[cfg(test)]
+mod test_utils;
 
-use crate::rocks_db::Database;
+pub fn get_db() -> Result<EncryptedDatabase, RocksdbDatabaseError> {
+    Database::open().and_then(EncryptedDatabase::from_env)
+}
 
-pub fn get_db() -> Result<Database, RocksdbDatabaseError> {
-    Database::open()
+pub fn get_db_at_path(p: &str) -> Result<EncryptedDatabase, RocksdbDatabaseError> {
+    Database::open_at_path(p).and_then(EncryptedDatabase::from_env)
 }
 
-pub fn get_db_at_path(p: &str) -> Result<Database, RocksdbDatabaseError> {
+pub fn get_unencrypted_db_at_path(p: &str) -> Result<Database, RocksdbDatabaseError> {
     Database::open_at_path(p)
 }
 
-pub use crate::errors::RocksdbDatabaseError;
+pub use crate::{
+    encrypted_db::{EncryptedDatabase, ENCRYPTION_THRESHOLD_ENV_VAR},
+    encryption::{EncryptionKeySource, ENCRYPTION_KEY_FILE_ENV_VAR, ENCRYPTION_PASSPHRASE_ENV_VAR},
+    errors::RocksdbDatabaseError,
+    rocks_db::{Database, DATABASE_PATH},
+};
@@ -0,0 +1,208 @@
+use rocksdb::{IteratorMode, WriteBatch};
+
+use crate::{
+    encryption::{get_encryption_params_db_key, DbCipher, EncryptionKeySource, EncryptionParams},
+    rocks_db::Database,
+    RocksdbDatabaseError,
+};
+
+impl Database {
+    pub(crate) fn put_encryption_params(&self, params: &EncryptionParams) -> Result<(), RocksdbDatabaseError> {
+        Ok(self.rocks_db.put(get_encryption_params_db_key(), params.to_bytes()?)?)
+    }
+
+    fn get_cipher(&self, source: &EncryptionKeySource) -> Result<DbCipher, RocksdbDatabaseError> {
+        match EncryptionParams::get_from_rocks_db(&self.rocks_db)? {
+            Some(params) => DbCipher::new(source, Some(&params)),
+            None => Err("Database is not encrypted!".into()),
+        }
+    }
+
+    // NOTE: All of the value updates are written in a single atomic batch, so a migration either
+    // completes in full or not at all.
+    fn update_values<F>(&self, batch: &mut WriteBatch, f: F) -> Result<usize, RocksdbDatabaseError>
+    where
+        F: Fn(&[u8], &[u8]) -> Result<Option<Vec<u8>>, RocksdbDatabaseError>,
+    {
+        let params_key = get_encryption_params_db_key();
+        let mut num_updated = 0;
+        for item in self.rocks_db.iterator(IteratorMode::Start) {
+            let (key, value) = item?;
+            if *key == *params_key {
+                continue;
+            };
+            if let Some(updated_value) = f(&key, &value)? {
+                batch.put(&key, updated_value);
+                num_updated += 1;
+            };
+        }
+        Ok(num_updated)
+    }
+
+    /// Encrypts every plaintext value in the database, regardless of its data sensitivity, since
+    /// that is not stored alongside it. If the database is already partially encrypted, the
+    /// passed in key source must match the one it was encrypted with. Returns the number of values
+    /// encrypted.
+    pub fn encrypt_all_values(&self, source: &EncryptionKeySource) -> Result<usize, RocksdbDatabaseError> {
+        info!("✔ Encrypting all plaintext values in database...");
+        let mut batch = WriteBatch::default();
+        let cipher = match EncryptionParams::get_from_rocks_db(&self.rocks_db)? {
+            Some(params) => DbCipher::new(source, Some(&params))?,
+            None => {
+                let cipher = DbCipher::new(source, None)?;
+                batch.put(get_encryption_params_db_key(), cipher.get_params().to_bytes()?);
+                cipher
+            },
+        };
+        let num_encrypted = self.update_values(&mut batch, |key, value| {
+            if DbCipher::is_encrypted(value) {
+                Ok(None)
+            } else {
+                cipher.encrypt(key, value).map(Some)
+            }
+        })?;
+        self.rocks_db.write(batch)?;
+        Ok(num_encrypted)
+    }
+
+    /// Re-encrypts every encrypted value in the database with the key from the new source, which
+    /// then replaces the old one. Returns the number of values re-encrypted.
+    pub fn rotate_encryption_key(
+        &self,
+        old_source: &EncryptionKeySource,
+        new_source: &EncryptionKeySource,
+    ) -> Result<usize, RocksdbDatabaseError> {
+        info!("✔ Rotating database encryption key...");
+        let old_cipher = self.get_cipher(old_source)?;
+        let new_cipher = DbCipher::new(new_source, None)?;
+        let mut batch = WriteBatch::default();
+        batch.put(get_encryption_params_db_key(), new_cipher.get_params().to_bytes()?);
+        let num_rotated = self.update_values(&mut batch, |key, value| {
+            if DbCipher::is_encrypted(value) {
+                new_cipher.encrypt(key, &old_cipher.decrypt(key, value)?).map(Some)
+            } else {
+                Ok(None)
+            }
+        })?;
+        self.rocks_db.write(batch)?;
+        Ok(num_rotated)
+    }
+
+    /// Decrypts every encrypted value in the database & removes its encryption params, returning
+    /// the number of values decrypted.
+    pub fn decrypt_all_values(&self, source: &EncryptionKeySource) -> Result<usize, RocksdbDatabaseError> {
+        info!("✔ Decrypting all encrypted values in database...");
+        let cipher = self.get_cipher(source)?;
+        let mut batch = WriteBatch::default();
+        let num_decrypted = self.update_values(&mut batch, |key, value| {
+            if DbCipher::is_encrypted(value) {
+                cipher.decrypt(key, value).map(Some)
+            } else {
+                Ok(None)
+            }
+        })?;
+        batch.delete(get_encryption_params_db_key());
+        self.rocks_db.write(batch)?;
+        Ok(num_decrypted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDir;
+
+    fn put_sample_values(db: &Database) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let values = (0..5u8)
+            .map(|i| (vec![i; 32], format!("value {i}").into_bytes()))
+            .collect::<Vec<_>>();
+        values.iter().for_each(|(k, v)| db.rocks_db.put(k, v).unwrap());
+        values
+    }
+
+    fn get_raw_value(db: &Database, key: &[u8]) -> Vec<u8> {
+        db.rocks_db.get(key).unwrap().unwrap()
+    }
+
+    #[test]
+    fn should_encrypt_rotate_and_decrypt_all_values() {
+        let dir = TestDir::new("round-trip");
+        let db = dir.open_db();
+        let old_source = dir.get_key_source(1);
+        let new_source = dir.get_key_source(2);
+        let values = put_sample_values(&db);
+
+        assert_eq!(db.encrypt_all_values(&old_source).unwrap(), values.len());
+        let old_cipher = db.get_cipher(&old_source).unwrap();
+        for (k, v) in values.iter() {
+            let raw = get_raw_value(&db, k);
+            assert!(DbCipher::is_encrypted(&raw));
+            assert_eq!(old_cipher.decrypt(k, &raw).unwrap(), *v);
+        }
+
+        assert_eq!(
+            db.rotate_encryption_key(&old_source, &new_source).unwrap(),
+            values.len()
+        );
+        assert!(db.get_cipher(&old_source).is_err());
+        let new_cipher = db.get_cipher(&new_source).unwrap();
+        for (k, v) in values.iter() {
+            assert_eq!(new_cipher.decrypt(k, &get_raw_value(&db, k)).unwrap(), *v);
+        }
+
+        assert_eq!(db.decrypt_all_values(&new_source).unwrap(), values.len());
+        assert!(EncryptionParams::get_from_rocks_db(&db.rocks_db).unwrap().is_none());
+        for (k, v) in values.iter() {
+            assert_eq!(get_raw_value(&db, k), *v);
+        }
+    }
+
+    #[test]
+    fn should_only_encrypt_plaintext_values_of_partially_encrypted_db() {
+        let dir = TestDir::new("partial");
+        let db = dir.open_db();
+        let source = dir.get_key_source(1);
+        let values = put_sample_values(&db);
+        assert_eq!(db.encrypt_all_values(&source).unwrap(), values.len());
+        db.rocks_db.put([0xff; 32], b"new plaintext value").unwrap();
+        assert_eq!(db.encrypt_all_values(&source).unwrap(), 1);
+        assert!(db.encrypt_all_values(&dir.get_key_source(2)).is_err());
+    }
+
+    #[test]
+    fn should_leave_db_untouched_if_rotation_is_interrupted_part_way() {
+        let dir = TestDir::new("interrupted-rotation");
+        let db = dir.open_db();
+        let old_source = dir.get_key_source(1);
+        let new_source = dir.get_key_source(2);
+        let values = put_sample_values(&db);
+        db.encrypt_all_values(&old_source).unwrap();
+
+        // NOTE: A value which looks encrypted but can't be decrypted makes the rotation fail after
+        // some values have already been re-encrypted into the batch.
+        let old_cipher = db.get_cipher(&old_source).unwrap();
+        let corrupt_key = vec![0xff; 32];
+        let mut corrupt_value = old_cipher.encrypt(&corrupt_key, b"corrupt").unwrap();
+        let last_byte = corrupt_value.len() - 1;
+        corrupt_value[last_byte] ^= 1;
+        db.rocks_db.put(&corrupt_key, &corrupt_value).unwrap();
+        let raw_values_before = values.iter().map(|(k, _)| get_raw_value(&db, k)).collect::<Vec<_>>();
+
+        assert!(db.rotate_encryption_key(&old_source, &new_source).is_err());
+
+        let raw_values_after = values.iter().map(|(k, _)| get_raw_value(&db, k)).collect::<Vec<_>>();
+        assert_eq!(raw_values_after, raw_values_before);
+        assert!(db.get_cipher(&new_source).is_err());
+        let old_cipher = db.get_cipher(&old_source).unwrap();
+        for (k, v) in values.iter() {
+            assert_eq!(old_cipher.decrypt(k, &get_raw_value(&db, k)).unwrap(), *v);
+        }
+
+        // NOTE: Once the bad value is dealt with, the rotation can simply be re-run.
+        db.rocks_db.delete(&corrupt_key).unwrap();
+        assert_eq!(
+            db.rotate_encryption_key(&old_source, &new_source).unwrap(),
+            values.len()
+        );
+    }
+}
@@ -1,6 +1,12 @@
 use std::{cell::RefCell, collections::HashMap};
 
-use common::{AppError as PTokensCoreError, Bytes, DatabaseInterface, Result as PTokensResult};
+use common::{
+    constants::DB_KEY_NOT_FOUND_ERROR,
+    AppError as PTokensCoreError,
+    Bytes,
+    DatabaseInterface,
+    Result as PTokensResult,
+};
 use rocksdb::{WriteBatch, DB};
 
 use crate::RocksdbDatabaseError;
@@ -76,6 +82,14 @@ impl DatabaseInterface for Database {
         }
     }
 
+    fn clear_cache(&self) -> PTokensResult<()> {
+        trace!("✔ Clearing DB cache...");
+        self.hashmap.borrow_mut().clear();
+        self.batch_db_ops.borrow_mut().clear();
+        self.keys_to_delete.borrow_mut().clear();
+        Ok(())
+    }
+
     fn start_transaction(&self) -> PTokensResult<()> {
         info!("✔ Starting DB transaction in app...");
         Ok(())
@@ -109,7 +123,7 @@ impl DatabaseInterface for Database {
 
     fn get(&self, key: Bytes, _sensitivity: DataSensitivity) -> PTokensResult<Bytes> {
         trace!("✔ Getting key: {}", hex::encode(&key));
-        let not_in_db_error = "Cannot find item in database!".to_string();
+        let not_in_db_error = DB_KEY_NOT_FOUND_ERROR.to_string();
         if self.keys_to_delete.borrow().contains(&key) {
             trace!("✔ Key already in delete list ∴ 'not found'!");
             Err(PTokensCoreError::Custom(not_in_db_error))
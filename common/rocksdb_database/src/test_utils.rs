@@ -0,0 +1,37 @@
+use std::{
+    env,
+    fs::{create_dir_all, remove_dir_all, write},
+    path::PathBuf,
+    process,
+};
+
+use crate::{encryption::EncryptionKeySource, rocks_db::Database};
+
+/// A uniquely named directory holding a test's RocksDB database & key files, which is removed
+/// once the test is done with it.
+pub struct TestDir(PathBuf);
+
+impl TestDir {
+    pub fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("rocksdb-database-test-{}-{name}", process::id()));
+        let _ = remove_dir_all(&path);
+        create_dir_all(&path).expect("Cannot create test dir!");
+        Self(path)
+    }
+
+    pub fn open_db(&self) -> Database {
+        Database::open_at_path(&self.0.join("db").to_string_lossy()).expect("Cannot open test db!")
+    }
+
+    pub fn get_key_source(&self, key_byte: u8) -> EncryptionKeySource {
+        let path = self.0.join(format!("key-{key_byte}"));
+        write(&path, hex::encode([key_byte; 32])).expect("Cannot write test key file!");
+        EncryptionKeySource::KeyFile(path.to_string_lossy().to_string())
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = remove_dir_all(&self.0);
+    }
+}
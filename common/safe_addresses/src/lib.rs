@@ -8,6 +8,7 @@ use std::str::FromStr;
 
 use bitcoin::Address as BtcAddress;
 use common::utils::convert_hex_to_eth_address;
+use common_chain_ids::EthChainId;
 use eos_chain::AccountName as EosAddress;
 use ethereum_types::Address as EthAddress;
 use litecoin::Address as LtcAddress;
@@ -40,6 +41,16 @@ pub fn safely_convert_str_to_eth_address(s: &str) -> EthAddress {
     }
 }
 
+pub fn get_safe_evm_address_for_chain(chain_id: &EthChainId) -> EthAddress {
+    match chain_id.get_registry_entry().map(|entry| entry.get_safe_address()) {
+        Some(Ok(address)) => address,
+        _ => {
+            info!("✘ No safe address in chain registry for {chain_id} - defaulting to safe EVM address!");
+            *SAFE_EVM_ADDRESS
+        },
+    }
+}
+
 pub fn safely_convert_str_to_btc_address(s: &str) -> BtcAddress {
     info!("✔ Safely converting str to BTC address...");
     match BtcAddress::from_str(s) {
@@ -104,6 +115,20 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_get_safe_evm_address_for_chain() {
+        let result = get_safe_evm_address_for_chain(&EthChainId::PolygonMainnet);
+        let expected_result = *SAFE_EVM_ADDRESS;
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_default_to_safe_evm_address_for_unregistered_chain() {
+        let result = get_safe_evm_address_for_chain(&EthChainId::Unknown(1_234_567_890));
+        let expected_result = *SAFE_EVM_ADDRESS;
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_safely_convert_btc_str_to_btc_address() {
         let s = "bc1qwqdg6squsna38e46795at95yu9atm8azzmyvckulcc7kytlcckxswvvzej";
@@ -123,11 +123,28 @@ impl ActorInclusionProof {
         }
     }
 
-    #[cfg(test)]
     fn hash_size() -> usize {
         Sha256WithOrderingAlgorithm::hash_size()
     }
 
+    /// Verifies that the passed in actor is included in the tree with the passed in root. Because
+    /// sibling hashes are sorted before concatenation, the proof needs no left/right information.
+    pub fn verify(&self, actor: &Actor, root: &EthHash) -> Result<bool, ActorsError> {
+        let hash_size = Self::hash_size();
+        let computed_root = self.proof().iter().try_fold(actor.to_leaf(), |acc, sibling| {
+            let sibling: Hash = sibling
+                .as_slice()
+                .try_into()
+                .map_err(|_| ActorsError::InvalidHashSizeInProof {
+                    got: sibling.len(),
+                    expected: hash_size,
+                    element: format!("0x{}", hex::encode(sibling)),
+                })?;
+            Ok::<Hash, ActorsError>(Sha256WithOrderingAlgorithm::concat_and_hash(&acc, Some(&sibling)))
+        })?;
+        Ok(computed_root == root.0)
+    }
+
     pub fn empty() -> Self {
         Self::default()
     }
@@ -311,6 +328,19 @@ mod tests {
         assert_eq!(proof, expected_proof);
     }
 
+    #[test]
+    fn should_verify_inclusion_proofs_against_actors_root() {
+        let actors = get_sample_actors();
+        let root = EthHash::from(actors.root());
+        actors.actors().iter().for_each(|actor| {
+            let proof = actors.get_inclusion_proof_for_actor(actor).unwrap();
+            assert!(proof.verify(actor, &root).unwrap());
+        });
+        let proof = actors.get_inclusion_proof_for_actor(&actors.actors()[0]).unwrap();
+        assert!(!proof.verify(&actors.actors()[1], &root).unwrap());
+        assert!(!proof.verify(&Actor::random(), &root).unwrap());
+    }
+
     #[test]
     fn should_get_inclusion_proof_from_web_socket_message() {
         let proof = get_sample_proof();
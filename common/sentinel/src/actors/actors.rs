@@ -1,5 +1,6 @@
 use std::fmt;
 
+use common::{DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
 use common_eth::{EthReceipts, EthSubmissionMaterial};
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
@@ -8,6 +9,9 @@ use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
 use serde::{Deserialize, Serialize};
 
 use super::{type_aliases::Hash, Actor, ActorsError, ActorsPropagatedEvent, ACTORS_PROPAGATED_EVENT_TOPIC};
+use crate::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils, SentinelError, WebSocketMessagesEncodable};
+
+type Byte = u8;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Constructor, Getters, Serialize, Deserialize)]
 pub struct Actors {
@@ -89,6 +93,51 @@ impl Actors {
     }
 }
 
+impl DbUtilsT for Actors {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_actors_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for Actors {
+    type Error = ActorsError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        match m {
+            WebSocketMessagesEncodable::Success(j) => Ok(serde_json::from_value(j)?),
+            other => Err(Self::Error::CannotCreateActorsFrom(other.to_string())),
+        }
+    }
+}
+
+impl Actors {
+    pub fn get<D: DatabaseInterface>(db_utils: &SentinelDbUtils<D>) -> Self {
+        Self::get_from_db(db_utils, &SentinelDbKeys::get_actors_db_key()).unwrap_or_default()
+    }
+
+    pub fn update_actors_in_db<D: DatabaseInterface>(
+        &self,
+        db_utils: &SentinelDbUtils<D>,
+    ) -> Result<(), SentinelError> {
+        debug!("maybe updating actors in db");
+        let existing = Self::get(db_utils);
+        if existing.epoch() == &U256::zero() || self.epoch() > existing.epoch() {
+            self.update_in_db(db_utils)
+        } else {
+            warn!("not updating actors because the epoch is not greater than the existing one");
+            Ok(())
+        }
+    }
+}
+
 impl fmt::Display for Actors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match serde_json::to_string_pretty(self) {
@@ -102,6 +151,8 @@ impl fmt::Display for Actors {
 mod tests {
     use std::str::FromStr;
 
+    use common::get_test_database;
+
     use super::*;
     use crate::actors::test_utils::{get_sample_actors, get_sample_actors_propagated_sub_mat};
 
@@ -118,6 +169,22 @@ mod tests {
         assert_eq!(actors, Some(expected_actors))
     }
 
+    #[test]
+    fn should_only_update_actors_in_db_if_epoch_is_greater() {
+        let db = get_test_database();
+        let db_utils = SentinelDbUtils::new(&db);
+        assert_eq!(Actors::get(&db_utils), Actors::default());
+        let actors = get_sample_actors();
+        actors.update_actors_in_db(&db_utils).unwrap();
+        assert_eq!(Actors::get(&db_utils), actors);
+        let older_actors = Actors {
+            epoch: *actors.epoch() - U256::one(),
+            ..Actors::default()
+        };
+        older_actors.update_actors_in_db(&db_utils).unwrap();
+        assert_eq!(Actors::get(&db_utils), actors);
+    }
+
     #[test]
     fn should_not_get_actors_from_sub_mat_if_not_extant() {
         let sub_mat = get_sample_actors_propagated_sub_mat();
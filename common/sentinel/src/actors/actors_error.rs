@@ -22,6 +22,9 @@ pub enum ActorsError {
     #[error("wrong topic for actors propagated event: {topic}")]
     WrongTopic { topic: EthHash },
 
+    #[error("cannot create actors from {0}")]
+    CannotCreateActorsFrom(String),
+
     #[error("cannot create actors inlcusion proof from {0}")]
     CannotCreateProofFrom(String),
 
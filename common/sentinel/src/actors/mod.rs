@@ -4,7 +4,7 @@ mod actor_type;
 mod actors;
 mod actors_error;
 mod actors_propagated_event;
-mod test_utils;
+pub(crate) mod test_utils;
 mod type_aliases;
 
 use self::actors_propagated_event::{ActorsPropagatedEvent, ACTORS_PROPAGATED_EVENT_TOPIC};
@@ -0,0 +1,325 @@
+use std::{result::Result, str::FromStr};
+
+use base64::{engine::general_purpose, Engine};
+use common::Bytes;
+use derive_getters::Getters;
+use derive_more::{Constructor, Deref};
+use ring::signature::{self, UnparsedPublicKey, VerificationAlgorithm};
+use serde_json::{json, Value as Json};
+use x509_parser::{
+    certificate::X509Certificate,
+    oid_registry::{OID_EC_P256, OID_KEY_TYPE_EC_PUBLIC_KEY, OID_NIST_EC_P384, OID_PKCS1_RSAENCRYPTION},
+    pem::Pem,
+    prelude::{FromDer, SubjectPublicKeyInfo},
+    time::ASN1Time,
+};
+
+use super::{AttestationError, AttestationRoots, KeyDescription};
+use crate::{SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
+
+const KEY_DESCRIPTION_OID: &str = "1.3.6.1.4.1.11129.2.1.17";
+
+/// An attestation certificate chain, as DER encoded certificates ordered from the attested key's
+/// certificate up to the root.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Constructor, Deref)]
+pub struct AttestationCertificateChain(Vec<Bytes>);
+
+impl FromStr for AttestationCertificateChain {
+    type Err = AttestationError;
+
+    /// Accepts either concatenated PEM certificates, or DER certificates encoded as hex or base64
+    /// & separated by commas or whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let certs = if s.contains("-----BEGIN") {
+            Pem::iter_from_buffer(s.as_bytes())
+                .map(|pem| {
+                    pem.map(|p| p.contents)
+                        .map_err(|e| AttestationError::Pem(e.to_string()))
+                })
+                .collect::<Result<Vec<Bytes>, AttestationError>>()?
+        } else {
+            s.split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|x| !x.is_empty())
+                .map(|x| {
+                    let without_prefix = x.trim_start_matches("0x");
+                    if without_prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+                        Ok(hex::decode(without_prefix)?)
+                    } else {
+                        Ok(general_purpose::STANDARD.decode(x)?)
+                    }
+                })
+                .collect::<Result<Vec<Bytes>, AttestationError>>()?
+        };
+        Ok(Self::new(certs))
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for AttestationCertificateChain {
+    type Error = SentinelError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        let s = match m {
+            WebSocketMessagesEncodable::Success(ref json) => json
+                .get("attestationCertificate")
+                .and_then(Json::as_str)
+                .map(String::from),
+            _ => None,
+        }
+        .ok_or_else(|| WebSocketMessagesError::CannotConvert {
+            from: m.to_string(),
+            to: "AttestationCertificateChain".to_string(),
+        })?;
+        Ok(Self::from_str(&s)?)
+    }
+}
+
+impl AttestationCertificateChain {
+    fn parse(&self) -> Result<Vec<X509Certificate<'_>>, AttestationError> {
+        if self.is_empty() {
+            return Err(AttestationError::EmptyChain);
+        };
+        self.iter()
+            .enumerate()
+            .map(|(index, der)| {
+                X509Certificate::from_der(der).map(|(_, cert)| cert).map_err(|e| {
+                    AttestationError::CannotParseCertificate {
+                        index,
+                        reason: e.to_string(),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn is_certificate_authority(cert: &X509Certificate) -> bool {
+        let is_ca = matches!(cert.basic_constraints(), Ok(Some(ext)) if ext.value.ca);
+        let can_sign_certs = matches!(cert.key_usage(), Ok(Some(ext)) if ext.value.key_cert_sign());
+        is_ca && can_sign_certs
+    }
+
+    fn get_key_description<'a>(cert: &'a X509Certificate) -> Option<&'a [u8]> {
+        cert.extensions()
+            .iter()
+            .find(|e| e.oid.to_id_string() == KEY_DESCRIPTION_OID)
+            .map(|e| e.value)
+    }
+
+    /// Checks that each certificate is signed by the next & valid at the given timestamp, that every
+    /// certificate but the first is a CA allowed to sign certificates, that the last is a self
+    /// signed trusted root, & that only the first carries a key description, which is returned.
+    ///
+    /// NOTE: Google's attestation revocation status list is not checked, so a chain from a device
+    /// whose keys have since been revoked will still verify. Rotate the trusted roots via
+    /// `AttestationRoots::from_file` if that is a concern.
+    pub fn verify(&self, roots: &AttestationRoots, timestamp: u64) -> Result<VerifiedAttestation, AttestationError> {
+        if roots.is_empty() {
+            return Err(AttestationError::NoRoots);
+        };
+
+        let certs = self.parse()?;
+        let time = ASN1Time::from_timestamp(timestamp as i64)
+            .map_err(|_| AttestationError::CertificateNotValidAt { index: 0, timestamp })?;
+
+        for (index, cert) in certs.iter().enumerate() {
+            // NOTE: The root signs itself.
+            let issuer = certs.get(index + 1).unwrap_or(cert);
+            if cert.issuer().as_raw() != issuer.subject().as_raw() {
+                return Err(AttestationError::IssuerMismatch { index });
+            };
+            cert.verify_signature(Some(issuer.public_key())).map_err(|e| {
+                AttestationError::InvalidCertificateSignature {
+                    index,
+                    reason: e.to_string(),
+                }
+            })?;
+            if !cert.validity().is_valid_at(time) {
+                return Err(AttestationError::CertificateNotValidAt { index, timestamp });
+            };
+            // NOTE: Otherwise any attested key could sign a forged leaf w/ whatever key description
+            // it likes, & the chain would still lead back to a trusted root.
+            if index > 0 && !Self::is_certificate_authority(cert) {
+                return Err(AttestationError::NotCertificateAuthority { index });
+            };
+        }
+
+        let num_key_descriptions = certs.iter().filter(|c| Self::get_key_description(c).is_some()).count();
+        if num_key_descriptions > 1 {
+            return Err(AttestationError::MultipleKeyDescriptions(num_key_descriptions));
+        };
+
+        let root = certs.last().ok_or(AttestationError::EmptyChain)?;
+        if !roots.includes(root.public_key().raw) {
+            return Err(AttestationError::UntrustedRoot);
+        };
+
+        // NOTE: The key description is in the attested key's own certificate, at the front.
+        let leaf = &certs[0];
+        let key_description = Self::get_key_description(leaf).ok_or(AttestationError::NoKeyDescription)?;
+
+        Ok(VerifiedAttestation::new(
+            KeyDescription::from_der(key_description)?,
+            leaf.public_key().raw.to_vec(),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Getters, Constructor)]
+pub struct VerifiedAttestation {
+    key_description: KeyDescription,
+    public_key: Bytes,
+}
+
+impl VerifiedAttestation {
+    fn verification_algorithm(
+        spki: &SubjectPublicKeyInfo,
+    ) -> Result<&'static dyn VerificationAlgorithm, AttestationError> {
+        let algorithm = &spki.algorithm.algorithm;
+        if *algorithm == OID_KEY_TYPE_EC_PUBLIC_KEY {
+            match spki.algorithm.parameters.as_ref().and_then(|p| p.as_oid().ok()) {
+                Some(curve) if curve == OID_EC_P256 => Ok(&signature::ECDSA_P256_SHA256_ASN1),
+                Some(curve) if curve == OID_NIST_EC_P384 => Ok(&signature::ECDSA_P384_SHA384_ASN1),
+                other => Err(AttestationError::UnsupportedPublicKey(format!(
+                    "ec key w/ curve {}",
+                    other.map(|c| c.to_id_string()).unwrap_or_else(|| "none".into())
+                ))),
+            }
+        } else if *algorithm == OID_PKCS1_RSAENCRYPTION {
+            Ok(&signature::RSA_PKCS1_2048_8192_SHA256)
+        } else {
+            Err(AttestationError::UnsupportedPublicKey(algorithm.to_id_string()))
+        }
+    }
+
+    /// Checks a signature, such as one from the strongbox's `getAttestationSignature`, was made by
+    /// the attested key.
+    pub fn verify_signature(&self, message: &[u8], sig: &[u8]) -> Result<(), AttestationError> {
+        let (_, spki) = SubjectPublicKeyInfo::from_der(&self.public_key)
+            .map_err(|e| AttestationError::UnsupportedPublicKey(e.to_string()))?;
+        UnparsedPublicKey::new(Self::verification_algorithm(&spki)?, &spki.subject_public_key.data)
+            .verify(message, sig)
+            .map_err(|_| AttestationError::InvalidSignature)
+    }
+
+    pub fn to_json(&self) -> Json {
+        json!({
+            "publicKey": format!("0x{}", hex::encode(&self.public_key)),
+            "keyDescription": self.key_description.to_json(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestation::{
+        test_utils::{
+            get_sample_attestation_chain,
+            get_sample_attestation_message_and_signature,
+            get_sample_attestation_roots,
+            get_sample_forged_attestation_chain_and_roots,
+            get_sample_multiple_key_descriptions_attestation_chain_and_roots,
+            get_sample_other_attestation_roots,
+            SAMPLE_TIMESTAMP,
+        },
+        SecurityLevel,
+    };
+
+    #[test]
+    fn should_verify_attestation_chain() {
+        let chain = get_sample_attestation_chain();
+        assert_eq!(chain.len(), 3);
+        let verified = chain.verify(&get_sample_attestation_roots(), SAMPLE_TIMESTAMP).unwrap();
+        let kd = verified.key_description();
+        assert_eq!(*kd.attestation_security_level(), SecurityLevel::StrongBox);
+        assert_eq!(kd.attestation_challenge(), b"sentinel-attestation-challenge");
+    }
+
+    #[test]
+    fn should_parse_chain_from_hex_and_base64() {
+        let chain = get_sample_attestation_chain();
+        let hex_chain = chain.iter().map(hex::encode).collect::<Vec<String>>().join(",");
+        assert_eq!(AttestationCertificateChain::from_str(&hex_chain).unwrap(), chain);
+        let base64_chain = chain
+            .iter()
+            .map(|c| general_purpose::STANDARD.encode(c))
+            .collect::<Vec<String>>()
+            .join("\n");
+        assert_eq!(AttestationCertificateChain::from_str(&base64_chain).unwrap(), chain);
+    }
+
+    #[test]
+    fn should_fail_to_verify_chain_with_untrusted_root() {
+        let chain = get_sample_attestation_chain();
+        match chain.verify(&get_sample_other_attestation_roots(), SAMPLE_TIMESTAMP) {
+            Err(AttestationError::UntrustedRoot) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_chain_without_roots() {
+        let chain = get_sample_attestation_chain();
+        match chain.verify(&AttestationRoots::default(), SAMPLE_TIMESTAMP) {
+            Err(AttestationError::NoRoots) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_chain_with_missing_intermediate() {
+        let chain = get_sample_attestation_chain();
+        let broken = AttestationCertificateChain::new(vec![chain[0].clone(), chain[2].clone()]);
+        match broken.verify(&get_sample_attestation_roots(), SAMPLE_TIMESTAMP) {
+            Err(AttestationError::IssuerMismatch { index: 0 }) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_chain_outside_validity_period() {
+        let chain = get_sample_attestation_chain();
+        match chain.verify(&get_sample_attestation_roots(), 0) {
+            Err(AttestationError::CertificateNotValidAt { .. }) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_verify_signature_from_attested_key() {
+        let verified = get_sample_attestation_chain()
+            .verify(&get_sample_attestation_roots(), SAMPLE_TIMESTAMP)
+            .unwrap();
+        let (message, sig) = get_sample_attestation_message_and_signature();
+        assert!(verified.verify_signature(&message, &sig).is_ok());
+        match verified.verify_signature(b"some other message", &sig) {
+            Err(AttestationError::InvalidSignature) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_chain_with_leaf_signed_by_attested_key() {
+        let (chain, roots) = get_sample_forged_attestation_chain_and_roots();
+        assert_eq!(chain.len(), 4);
+        match chain.verify(&roots, SAMPLE_TIMESTAMP) {
+            Err(AttestationError::NotCertificateAuthority { index: 1 }) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_chain_with_multiple_key_descriptions() {
+        let (chain, roots) = get_sample_multiple_key_descriptions_attestation_chain_and_roots();
+        match chain.verify(&roots, SAMPLE_TIMESTAMP) {
+            Err(AttestationError::MultipleKeyDescriptions(2)) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
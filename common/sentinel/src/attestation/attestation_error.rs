@@ -0,0 +1,90 @@
+use thiserror::Error;
+
+use super::{SecurityLevel, VerifiedBootState};
+
+#[derive(Debug, Error)]
+pub enum AttestationError {
+    #[error("attestation certificate chain is empty")]
+    EmptyChain,
+
+    #[error("no trusted attestation roots - bundle or configure some to verify attestations against")]
+    NoRoots,
+
+    #[error("cannot parse attestation pem: {0}")]
+    Pem(String),
+
+    #[error("cannot parse attestation certificate {index}: {reason}")]
+    CannotParseCertificate { index: usize, reason: String },
+
+    #[error("attestation certificate {index} is not issued by the certificate after it in the chain")]
+    IssuerMismatch { index: usize },
+
+    #[error("attestation certificate {index} has an invalid signature: {reason}")]
+    InvalidCertificateSignature { index: usize, reason: String },
+
+    #[error("attestation certificate {index} is not valid at timestamp {timestamp}")]
+    CertificateNotValidAt { index: usize, timestamp: u64 },
+
+    #[error("attestation certificate {index} is not a certificate authority allowed to sign certificates")]
+    NotCertificateAuthority { index: usize },
+
+    #[error("attestation certificate chain has {0} key descriptions, expected only the attested key's")]
+    MultipleKeyDescriptions(usize),
+
+    #[error("attestation certificate chain's root is not a trusted attestation root")]
+    UntrustedRoot,
+
+    #[error("no key description extension in the attestation certificate")]
+    NoKeyDescription,
+
+    #[error("malformed attestation key description: {0}")]
+    MalformedKeyDescription(String),
+
+    #[error("unrecognized attestation security level: {0}")]
+    UnrecognizedSecurityLevel(u64),
+
+    #[error("unrecognized verified boot state: {0}")]
+    UnrecognizedVerifiedBootState(u64),
+
+    #[error("attested key is not in strongbox - attestation security level: {attestation}, keymint security level: {keymint}")]
+    NotInStrongbox {
+        attestation: SecurityLevel,
+        keymint: SecurityLevel,
+    },
+
+    #[error("no hardware enforced root of trust in attestation")]
+    NoRootOfTrust,
+
+    #[error("device boot is not verified - boot state: {state}, device locked: {device_locked}")]
+    UnverifiedBoot {
+        state: VerifiedBootState,
+        device_locked: bool,
+    },
+
+    #[error("no attestation application id in attestation")]
+    NoAttestationApplicationId,
+
+    #[error("attested application is not package '{expected}', got packages: {got:?}")]
+    PackageName { expected: String, got: Vec<String> },
+
+    #[error("none of the attested application's signature digests are allowed")]
+    SignatureDigest,
+
+    #[error("attestation challenge mismatch - expected: 0x{expected}, got: 0x{got}")]
+    Challenge { expected: String, got: String },
+
+    #[error("unsupported attested public key algorithm: {0}")]
+    UnsupportedPublicKey(String),
+
+    #[error("signature does not verify against the attested key")]
+    InvalidSignature,
+
+    #[error("attestation hex error: {0}")]
+    Hex(#[from] hex::FromHexError),
+
+    #[error("attestation base64 error: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("attestation io error: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,148 @@
+use common::Bytes;
+use derive_getters::Getters;
+use derive_more::Constructor;
+
+use super::{AttestationError, KeyDescription, SecurityLevel};
+
+/// What an attested key's description must show for the sentinel to trust the core holding it.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Getters, Constructor)]
+pub struct AttestationPolicy {
+    require_strongbox: bool,
+    require_verified_boot: bool,
+    package_name: Option<String>,
+    // NOTE: The attested app must be signed by (at least) one of these, if any are given.
+    signature_digests: Vec<Bytes>,
+    challenge: Option<Bytes>,
+}
+
+impl AttestationPolicy {
+    fn check_security_level(&self, kd: &KeyDescription) -> Result<(), AttestationError> {
+        let attestation = *kd.attestation_security_level();
+        let keymint = *kd.keymint_security_level();
+        if self.require_strongbox && (attestation != SecurityLevel::StrongBox || keymint != SecurityLevel::StrongBox) {
+            Err(AttestationError::NotInStrongbox { attestation, keymint })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_boot(&self, kd: &KeyDescription) -> Result<(), AttestationError> {
+        if !self.require_verified_boot {
+            return Ok(());
+        };
+        let root_of_trust = kd.root_of_trust().ok_or(AttestationError::NoRootOfTrust)?;
+        if root_of_trust.is_verified() {
+            Ok(())
+        } else {
+            Err(AttestationError::UnverifiedBoot {
+                state: *root_of_trust.verified_boot_state(),
+                device_locked: *root_of_trust.device_locked(),
+            })
+        }
+    }
+
+    fn check_application(&self, kd: &KeyDescription) -> Result<(), AttestationError> {
+        if self.package_name.is_none() && self.signature_digests.is_empty() {
+            return Ok(());
+        };
+        let app_id = kd
+            .attestation_application_id()
+            .ok_or(AttestationError::NoAttestationApplicationId)?;
+        if let Some(ref expected) = self.package_name {
+            if !app_id.package_names().contains(expected) {
+                return Err(AttestationError::PackageName {
+                    expected: expected.clone(),
+                    got: app_id.package_names().clone(),
+                });
+            };
+        };
+        if !self.signature_digests.is_empty()
+            && !app_id
+                .signature_digests()
+                .iter()
+                .any(|d| self.signature_digests.contains(d))
+        {
+            return Err(AttestationError::SignatureDigest);
+        };
+        Ok(())
+    }
+
+    fn check_challenge(&self, kd: &KeyDescription) -> Result<(), AttestationError> {
+        match self.challenge {
+            Some(ref expected) if expected != kd.attestation_challenge() => Err(AttestationError::Challenge {
+                expected: hex::encode(expected),
+                got: hex::encode(kd.attestation_challenge()),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check(&self, kd: &KeyDescription) -> Result<(), AttestationError> {
+        self.check_security_level(kd)?;
+        self.check_boot(kd)?;
+        self.check_application(kd)?;
+        self.check_challenge(kd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestation::test_utils::get_sample_key_description_bytes;
+
+    fn get_sample_key_description() -> KeyDescription {
+        KeyDescription::from_der(&get_sample_key_description_bytes()).unwrap()
+    }
+
+    fn get_sample_digest() -> Bytes {
+        hex::decode("5f2b3a4c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9011223344556677aa").unwrap()
+    }
+
+    #[test]
+    fn should_pass_matching_policy() {
+        let policy = AttestationPolicy::new(
+            true,
+            true,
+            Some("com.ptokens.sentinel".into()),
+            vec![vec![0u8; 32], get_sample_digest()],
+            Some(b"sentinel-attestation-challenge".to_vec()),
+        );
+        assert!(policy.check(&get_sample_key_description()).is_ok());
+        assert!(AttestationPolicy::default()
+            .check(&get_sample_key_description())
+            .is_ok());
+    }
+
+    #[test]
+    fn should_fail_policy_with_wrong_package_name() {
+        let policy = AttestationPolicy::new(false, false, Some("com.some.other.app".into()), vec![], None);
+        match policy.check(&get_sample_key_description()) {
+            Err(AttestationError::PackageName { expected, got }) => {
+                assert_eq!(expected, "com.some.other.app");
+                assert_eq!(got, vec!["com.ptokens.sentinel".to_string()]);
+            },
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_policy_with_unknown_signature_digest() {
+        let policy = AttestationPolicy::new(false, false, None, vec![vec![0u8; 32]], None);
+        match policy.check(&get_sample_key_description()) {
+            Err(AttestationError::SignatureDigest) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_policy_with_wrong_challenge() {
+        let policy = AttestationPolicy::new(false, false, None, vec![], Some(vec![1, 3, 3, 7]));
+        match policy.check(&get_sample_key_description()) {
+            Err(AttestationError::Challenge { .. }) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::{fs::read_to_string, result::Result};
+
+use common::Bytes;
+use derive_more::{Constructor, Deref};
+use x509_parser::pem::Pem;
+
+use super::AttestationError;
+
+// NOTE: Google's hardware attestation roots, as published at
+// https://developer.android.com/privacy-and-security/security-key-attestation#root_certificate
+const BUNDLED_ROOTS: &str = include_str!("google-hardware-attestation-roots.pem");
+
+/// The public keys (as DER encoded subject public key infos) of the roots which attestation chains
+/// must lead back to. Roots are matched on their keys rather than their certificates, since that
+/// is what Google commit to.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Constructor, Deref)]
+pub struct AttestationRoots(Vec<Bytes>);
+
+impl AttestationRoots {
+    pub fn bundled() -> Result<Self, AttestationError> {
+        Self::from_pem(BUNDLED_ROOTS)
+    }
+
+    /// For updating the roots without a new release, eg if Google rotate them.
+    pub fn from_file(path: &str) -> Result<Self, AttestationError> {
+        Self::from_pem(&read_to_string(path)?)
+    }
+
+    pub fn from_pem(s: &str) -> Result<Self, AttestationError> {
+        let roots = Pem::iter_from_buffer(s.as_bytes())
+            .enumerate()
+            .map(|(index, pem)| {
+                let pem = pem.map_err(|e| AttestationError::Pem(e.to_string()))?;
+                let cert = pem.parse_x509().map_err(|e| AttestationError::CannotParseCertificate {
+                    index,
+                    reason: e.to_string(),
+                })?;
+                Ok(cert.public_key().raw.to_vec())
+            })
+            .collect::<Result<Vec<Bytes>, AttestationError>>()?;
+        Ok(Self::new(roots))
+    }
+
+    pub(super) fn includes(&self, public_key: &[u8]) -> bool {
+        self.iter().any(|k| k == public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestation::test_utils::get_sample_attestation_root_pem;
+
+    #[test]
+    fn should_get_roots_from_pem() {
+        let roots = AttestationRoots::from_pem(&get_sample_attestation_root_pem()).unwrap();
+        assert_eq!(roots.len(), 1);
+    }
+
+    #[test]
+    fn should_ignore_text_around_pem_blocks() {
+        let s = format!("# some comment\n{}\n# another", get_sample_attestation_root_pem());
+        assert_eq!(AttestationRoots::from_pem(&s).unwrap().len(), 1);
+        assert!(AttestationRoots::from_pem("# no roots here").unwrap().is_empty());
+    }
+
+    #[test]
+    fn bundled_roots_should_not_be_empty() {
+        assert!(!AttestationRoots::bundled().unwrap().is_empty());
+    }
+}
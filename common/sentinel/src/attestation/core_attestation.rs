@@ -0,0 +1,111 @@
+use std::result::Result;
+
+use common::Bytes;
+use rand::RngCore;
+use serde_json::Value as Json;
+
+use super::{AttestationCertificateChain, AttestationPolicy, AttestationRoots, VerifiedAttestation};
+use crate::{SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
+
+const NONCE_LENGTH: usize = 32;
+
+/// A fresh message for the core to sign with its attested key, so that it can't replay an old
+/// signature from some other device.
+pub fn get_attestation_nonce() -> Bytes {
+    let mut nonce = vec![0u8; NONCE_LENGTH];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    nonce
+}
+
+fn get_signature_from_response(r: WebSocketMessagesEncodable) -> Result<Bytes, SentinelError> {
+    let s = match r {
+        WebSocketMessagesEncodable::Success(ref json) => json
+            .get("attestationSignature")
+            .and_then(Json::as_str)
+            .map(|s| s.trim_start_matches("0x").to_string()),
+        _ => None,
+    }
+    .ok_or_else(|| WebSocketMessagesError::CannotConvert {
+        from: r.to_string(),
+        to: "attestation signature".to_string(),
+    })?;
+    Ok(hex::decode(s)?)
+}
+
+/// Verifies a core's responses to the `GetAttestationCertificate` & `GetAttestationSignature`
+/// messages, the latter being over the given nonce, meaning the core holds a key attested to be
+/// in secure hardware, as well as meeting the given policy.
+pub fn verify_core_attestation(
+    roots: &AttestationRoots,
+    policy: &AttestationPolicy,
+    certificate_response: WebSocketMessagesEncodable,
+    nonce: &[u8],
+    signature_response: WebSocketMessagesEncodable,
+    timestamp: u64,
+) -> Result<VerifiedAttestation, SentinelError> {
+    let verified = AttestationCertificateChain::try_from(certificate_response)?.verify(roots, timestamp)?;
+    policy.check(verified.key_description())?;
+    verified.verify_signature(nonce, &get_signature_from_response(signature_response)?)?;
+    Ok(verified)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        attestation::test_utils::{
+            get_sample_attestation_chain,
+            get_sample_attestation_message_and_signature,
+            get_sample_attestation_roots,
+            SAMPLE_TIMESTAMP,
+        },
+        AttestationError,
+    };
+
+    fn get_sample_certificate_response() -> WebSocketMessagesEncodable {
+        let chain = get_sample_attestation_chain()
+            .iter()
+            .map(hex::encode)
+            .collect::<Vec<String>>()
+            .join(",");
+        WebSocketMessagesEncodable::Success(json!({ "attestationCertificate": chain }))
+    }
+
+    fn get_sample_signature_response(sig: &[u8]) -> WebSocketMessagesEncodable {
+        WebSocketMessagesEncodable::Success(json!({ "attestationSignature": format!("0x{}", hex::encode(sig)) }))
+    }
+
+    #[test]
+    fn should_verify_core_attestation() {
+        let (message, sig) = get_sample_attestation_message_and_signature();
+        let result = verify_core_attestation(
+            &get_sample_attestation_roots(),
+            &AttestationPolicy::new(true, true, Some("com.ptokens.sentinel".into()), vec![], None),
+            get_sample_certificate_response(),
+            &message,
+            get_sample_signature_response(&sig),
+            SAMPLE_TIMESTAMP,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_verify_core_attestation_if_nonce_not_signed() {
+        let (_, sig) = get_sample_attestation_message_and_signature();
+        let result = verify_core_attestation(
+            &get_sample_attestation_roots(),
+            &AttestationPolicy::default(),
+            get_sample_certificate_response(),
+            &get_attestation_nonce(),
+            get_sample_signature_response(&sig),
+            SAMPLE_TIMESTAMP,
+        );
+        match result {
+            Err(SentinelError::Attestation(AttestationError::InvalidSignature)) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use super::AttestationError;
+
+// NOTE: Just enough DER to walk the attestation key description, whose context specific tags go
+// beyond those x509 parsers generally cater for.
+
+pub(super) const UNIVERSAL: u8 = 0;
+pub(super) const CONTEXT_SPECIFIC: u8 = 2;
+
+pub(super) const BOOLEAN: u64 = 1;
+pub(super) const INTEGER: u64 = 2;
+pub(super) const OCTET_STRING: u64 = 4;
+pub(super) const ENUMERATED: u64 = 10;
+pub(super) const SEQUENCE: u64 = 16;
+pub(super) const SET: u64 = 17;
+
+fn malformed(s: &str) -> AttestationError {
+    AttestationError::MalformedKeyDescription(s.into())
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(super) struct DerElement<'a> {
+    class: u8,
+    tag: u64,
+    contents: &'a [u8],
+}
+
+impl<'a> DerElement<'a> {
+    pub(super) fn class(&self) -> u8 {
+        self.class
+    }
+
+    pub(super) fn tag(&self) -> u64 {
+        self.tag
+    }
+
+    pub(super) fn is(&self, class: u8, tag: u64) -> bool {
+        self.class == class && self.tag == tag
+    }
+
+    fn expect(&self, class: u8, tag: u64) -> Result<&'a [u8], AttestationError> {
+        if self.is(class, tag) {
+            Ok(self.contents)
+        } else {
+            Err(AttestationError::MalformedKeyDescription(format!(
+                "expected tag {tag} of class {class}, got tag {} of class {}",
+                self.tag, self.class
+            )))
+        }
+    }
+
+    fn unsigned(bytes: &[u8]) -> Result<u64, AttestationError> {
+        match bytes {
+            [] => Err(malformed("empty integer")),
+            [first, ..] if first & 0x80 != 0 => Err(malformed("negative integer")),
+            // NOTE: A leading zero byte is allowed for positive numbers with their top bit set.
+            [0, rest @ ..] if rest.len() <= 8 => Ok(rest.iter().fold(0, |acc, b| (acc << 8) | *b as u64)),
+            _ if bytes.len() <= 8 => Ok(bytes.iter().fold(0, |acc, b| (acc << 8) | *b as u64)),
+            _ => Err(malformed("integer too large")),
+        }
+    }
+
+    pub(super) fn integer(&self) -> Result<u64, AttestationError> {
+        Self::unsigned(self.expect(UNIVERSAL, INTEGER)?)
+    }
+
+    pub(super) fn enumerated(&self) -> Result<u64, AttestationError> {
+        Self::unsigned(self.expect(UNIVERSAL, ENUMERATED)?)
+    }
+
+    pub(super) fn boolean(&self) -> Result<bool, AttestationError> {
+        match self.expect(UNIVERSAL, BOOLEAN)? {
+            [0] => Ok(false),
+            [_] => Ok(true),
+            _ => Err(malformed("boolean must be a single byte")),
+        }
+    }
+
+    pub(super) fn octet_string(&self) -> Result<&'a [u8], AttestationError> {
+        self.expect(UNIVERSAL, OCTET_STRING)
+    }
+
+    pub(super) fn sequence(&self) -> Result<DerReader<'a>, AttestationError> {
+        self.expect(UNIVERSAL, SEQUENCE).map(DerReader::new)
+    }
+
+    pub(super) fn set(&self) -> Result<DerReader<'a>, AttestationError> {
+        self.expect(UNIVERSAL, SET).map(DerReader::new)
+    }
+
+    /// The single element wrapped by an explicitly tagged, context specific element.
+    pub(super) fn explicit(&self, tag: u64) -> Result<DerElement<'a>, AttestationError> {
+        DerReader::new(self.expect(CONTEXT_SPECIFIC, tag)?).single()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(super) struct DerReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take_byte(&mut self) -> Result<u8, AttestationError> {
+        let (first, rest) = self
+            .bytes
+            .split_first()
+            .ok_or_else(|| malformed("unexpected end of input"))?;
+        self.bytes = rest;
+        Ok(*first)
+    }
+
+    pub(super) fn next_element(&mut self) -> Result<DerElement<'a>, AttestationError> {
+        let identifier = self.take_byte()?;
+        let class = identifier >> 6;
+        let mut tag = (identifier & 0x1f) as u64;
+        if tag == 0x1f {
+            // NOTE: High tag number form, base 128 w/ the top bit flagging continuation.
+            tag = 0;
+            loop {
+                let b = self.take_byte()?;
+                if tag > (u64::MAX >> 7) {
+                    return Err(malformed("tag number too large"));
+                };
+                tag = (tag << 7) | (b & 0x7f) as u64;
+                if b & 0x80 == 0 {
+                    break;
+                };
+            }
+        };
+
+        let first_len_byte = self.take_byte()?;
+        let len = if first_len_byte & 0x80 == 0 {
+            first_len_byte as usize
+        } else {
+            let num_len_bytes = (first_len_byte & 0x7f) as usize;
+            if num_len_bytes == 0 || num_len_bytes > std::mem::size_of::<usize>() {
+                return Err(malformed("unsupported length encoding"));
+            };
+            let mut len = 0usize;
+            for _ in 0..num_len_bytes {
+                len = (len << 8) | self.take_byte()? as usize;
+            }
+            len
+        };
+
+        if len > self.bytes.len() {
+            return Err(malformed("length exceeds input"));
+        };
+        let (contents, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(DerElement { class, tag, contents })
+    }
+
+    /// Reads the one & only element in this reader's input.
+    pub(super) fn single(mut self) -> Result<DerElement<'a>, AttestationError> {
+        let element = self.next_element()?;
+        if self.is_empty() {
+            Ok(element)
+        } else {
+            Err(malformed("trailing bytes after element"))
+        }
+    }
+}
+
+impl<'a> Iterator for DerReader<'a> {
+    type Item = Result<DerElement<'a>, AttestationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            None
+        } else {
+            let r = self.next_element();
+            if r.is_err() {
+                // NOTE: Stop iterating after an error, since the rest of the input can't be trusted.
+                self.bytes = &[];
+            };
+            Some(r)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_high_tag_numbers_and_long_lengths() {
+        let contents = vec![0xaa; 200];
+        let mut bytes = vec![0xbf, 0x85, 0x40, 0x81, 200];
+        bytes.extend_from_slice(&contents);
+        let element = DerReader::new(&bytes).single().unwrap();
+        assert_eq!(element.class(), CONTEXT_SPECIFIC);
+        assert_eq!(element.tag(), 704);
+        assert_eq!(element.contents, &contents[..]);
+    }
+
+    #[test]
+    fn should_read_unsigned_integers() {
+        let element = DerReader::new(&[0x02, 0x02, 0x00, 0xc8]).single().unwrap();
+        assert_eq!(element.integer().unwrap(), 200);
+        let element = DerReader::new(&[0x02, 0x01, 0x80]).single().unwrap();
+        assert!(element.integer().is_err());
+    }
+
+    #[test]
+    fn should_fail_to_read_truncated_input() {
+        assert!(DerReader::new(&[0x04, 0x05, 0x01]).single().is_err());
+    }
+}
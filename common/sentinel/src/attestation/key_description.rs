@@ -0,0 +1,300 @@
+use std::fmt;
+
+use common::Bytes;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::{
+    der::{DerElement, DerReader, CONTEXT_SPECIFIC, OCTET_STRING, UNIVERSAL},
+    AttestationError,
+};
+
+/* Reference:
+From: https://source.android.com/docs/security/features/keystore/attestation#schema
+    KeyDescription ::= SEQUENCE {
+        attestationVersion         INTEGER,
+        attestationSecurityLevel   SecurityLevel,
+        keyMintVersion             INTEGER,
+        keyMintSecurityLevel       SecurityLevel,
+        attestationChallenge       OCTET_STRING,
+        uniqueId                   OCTET_STRING,
+        softwareEnforced           AuthorizationList,
+        hardwareEnforced           AuthorizationList,
+    }
+*/
+
+const ROOT_OF_TRUST_TAG: u64 = 704;
+const ATTESTATION_APPLICATION_ID_TAG: u64 = 709;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SecurityLevel {
+    Software,
+    TrustedEnvironment,
+    StrongBox,
+}
+
+impl TryFrom<u64> for SecurityLevel {
+    type Error = AttestationError;
+
+    fn try_from(n: u64) -> Result<Self, Self::Error> {
+        match n {
+            0 => Ok(Self::Software),
+            1 => Ok(Self::TrustedEnvironment),
+            2 => Ok(Self::StrongBox),
+            n => Err(AttestationError::UnrecognizedSecurityLevel(n)),
+        }
+    }
+}
+
+impl fmt::Display for SecurityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Software => "software",
+            Self::TrustedEnvironment => "trusted environment",
+            Self::StrongBox => "strongbox",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum VerifiedBootState {
+    Verified,
+    SelfSigned,
+    Unverified,
+    Failed,
+}
+
+impl TryFrom<u64> for VerifiedBootState {
+    type Error = AttestationError;
+
+    fn try_from(n: u64) -> Result<Self, Self::Error> {
+        match n {
+            0 => Ok(Self::Verified),
+            1 => Ok(Self::SelfSigned),
+            2 => Ok(Self::Unverified),
+            3 => Ok(Self::Failed),
+            n => Err(AttestationError::UnrecognizedVerifiedBootState(n)),
+        }
+    }
+}
+
+impl fmt::Display for VerifiedBootState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Verified => "verified",
+            Self::SelfSigned => "self signed",
+            Self::Unverified => "unverified",
+            Self::Failed => "failed",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Getters)]
+pub struct RootOfTrust {
+    verified_boot_key: Bytes,
+    device_locked: bool,
+    verified_boot_state: VerifiedBootState,
+    // NOTE: Only present from attestation version 3 onwards.
+    verified_boot_hash: Option<Bytes>,
+}
+
+impl RootOfTrust {
+    fn from_element(element: &DerElement) -> Result<Self, AttestationError> {
+        let mut seq = element.sequence()?;
+        let verified_boot_key = seq.next_element()?.octet_string()?.to_vec();
+        let device_locked = seq.next_element()?.boolean()?;
+        let verified_boot_state = VerifiedBootState::try_from(seq.next_element()?.enumerated()?)?;
+        let verified_boot_hash = seq
+            .next()
+            .transpose()?
+            .map(|e| e.octet_string().map(|h| h.to_vec()))
+            .transpose()?;
+        Ok(Self {
+            verified_boot_key,
+            device_locked,
+            verified_boot_state,
+            verified_boot_hash,
+        })
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.device_locked && self.verified_boot_state == VerifiedBootState::Verified
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Getters)]
+pub struct AttestationApplicationId {
+    package_names: Vec<String>,
+    signature_digests: Vec<Bytes>,
+}
+
+impl AttestationApplicationId {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, AttestationError> {
+        let mut seq = DerReader::new(bytes).single()?.sequence()?;
+        let package_names = seq
+            .next_element()?
+            .set()?
+            .map(|info| {
+                let mut info = info?.sequence()?;
+                let name = info.next_element()?.octet_string()?;
+                String::from_utf8(name.to_vec())
+                    .map_err(|_| AttestationError::MalformedKeyDescription("non utf8 package name".into()))
+            })
+            .collect::<Result<Vec<String>, AttestationError>>()?;
+        let signature_digests = seq
+            .next_element()?
+            .set()?
+            .map(|digest| Ok(digest?.octet_string()?.to_vec()))
+            .collect::<Result<Vec<Bytes>, AttestationError>>()?;
+        Ok(Self {
+            package_names,
+            signature_digests,
+        })
+    }
+}
+
+/// Those fields of an authorization list the sentinel cares about. Everything else is skipped.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Getters)]
+pub struct AuthorizationList {
+    root_of_trust: Option<RootOfTrust>,
+    attestation_application_id: Option<AttestationApplicationId>,
+}
+
+impl AuthorizationList {
+    fn from_element(element: &DerElement) -> Result<Self, AttestationError> {
+        let mut list = Self::default();
+        for field in element.sequence()? {
+            let field = field?;
+            if field.class() != CONTEXT_SPECIFIC {
+                continue;
+            };
+            match field.tag() {
+                ROOT_OF_TRUST_TAG => {
+                    list.root_of_trust = Some(RootOfTrust::from_element(&field.explicit(ROOT_OF_TRUST_TAG)?)?)
+                },
+                ATTESTATION_APPLICATION_ID_TAG => {
+                    // NOTE: The application id is itself DER encoded, & wrapped in an octet string.
+                    let inner = field.explicit(ATTESTATION_APPLICATION_ID_TAG)?;
+                    if !inner.is(UNIVERSAL, OCTET_STRING) {
+                        return Err(AttestationError::MalformedKeyDescription(
+                            "attestation application id is not an octet string".into(),
+                        ));
+                    };
+                    list.attestation_application_id = Some(AttestationApplicationId::from_bytes(inner.octet_string()?)?)
+                },
+                _ => (),
+            }
+        }
+        Ok(list)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Getters)]
+pub struct KeyDescription {
+    attestation_version: u64,
+    attestation_security_level: SecurityLevel,
+    keymint_version: u64,
+    keymint_security_level: SecurityLevel,
+    attestation_challenge: Bytes,
+    software_enforced: AuthorizationList,
+    hardware_enforced: AuthorizationList,
+}
+
+impl KeyDescription {
+    pub fn from_der(bytes: &[u8]) -> Result<Self, AttestationError> {
+        let mut seq = DerReader::new(bytes).single()?.sequence()?;
+        let attestation_version = seq.next_element()?.integer()?;
+        let attestation_security_level = SecurityLevel::try_from(seq.next_element()?.enumerated()?)?;
+        let keymint_version = seq.next_element()?.integer()?;
+        let keymint_security_level = SecurityLevel::try_from(seq.next_element()?.enumerated()?)?;
+        let attestation_challenge = seq.next_element()?.octet_string()?.to_vec();
+        let _unique_id = seq.next_element()?.octet_string()?;
+        let software_enforced = AuthorizationList::from_element(&seq.next_element()?)?;
+        let hardware_enforced = AuthorizationList::from_element(&seq.next_element()?)?;
+        Ok(Self {
+            attestation_version,
+            attestation_security_level,
+            keymint_version,
+            keymint_security_level,
+            attestation_challenge,
+            software_enforced,
+            hardware_enforced,
+        })
+    }
+
+    /// Only a root of trust enforced by the secure hardware itself is meaningful.
+    pub fn root_of_trust(&self) -> Option<&RootOfTrust> {
+        self.hardware_enforced.root_of_trust.as_ref()
+    }
+
+    /// NOTE: Android always puts this in the software enforced list, since it's supplied by the OS.
+    pub fn attestation_application_id(&self) -> Option<&AttestationApplicationId> {
+        self.software_enforced
+            .attestation_application_id
+            .as_ref()
+            .or(self.hardware_enforced.attestation_application_id.as_ref())
+    }
+
+    pub fn to_json(&self) -> Json {
+        json!({
+            "attestationVersion": self.attestation_version,
+            "attestationSecurityLevel": self.attestation_security_level,
+            "keymintVersion": self.keymint_version,
+            "keymintSecurityLevel": self.keymint_security_level,
+            "attestationChallenge": format!("0x{}", hex::encode(&self.attestation_challenge)),
+            "rootOfTrust": self.root_of_trust().map(|r| json!({
+                "verifiedBootKey": format!("0x{}", hex::encode(r.verified_boot_key())),
+                "deviceLocked": r.device_locked(),
+                "verifiedBootState": r.verified_boot_state(),
+                "verifiedBootHash": r.verified_boot_hash().as_ref().map(|h| format!("0x{}", hex::encode(h))),
+            })),
+            "attestationApplicationId": self.attestation_application_id().map(|a| json!({
+                "packageNames": a.package_names(),
+                "signatureDigests": a
+                    .signature_digests()
+                    .iter()
+                    .map(|d| format!("0x{}", hex::encode(d)))
+                    .collect::<Vec<String>>(),
+            })),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestation::test_utils::get_sample_key_description_bytes;
+
+    #[test]
+    fn should_parse_key_description() {
+        let kd = KeyDescription::from_der(&get_sample_key_description_bytes()).unwrap();
+        assert_eq!(*kd.attestation_version(), 200);
+        assert_eq!(*kd.attestation_security_level(), SecurityLevel::StrongBox);
+        assert_eq!(*kd.keymint_version(), 200);
+        assert_eq!(*kd.keymint_security_level(), SecurityLevel::StrongBox);
+        assert_eq!(kd.attestation_challenge(), b"sentinel-attestation-challenge");
+
+        let rot = kd.root_of_trust().unwrap();
+        assert!(rot.device_locked());
+        assert_eq!(*rot.verified_boot_state(), VerifiedBootState::Verified);
+        assert_eq!(rot.verified_boot_key(), &(1..=32).collect::<Vec<u8>>());
+        assert!(rot.verified_boot_hash().is_some());
+        assert!(kd.software_enforced().root_of_trust().is_none());
+
+        let app_id = kd.attestation_application_id().unwrap();
+        assert_eq!(app_id.package_names(), &vec!["com.ptokens.sentinel".to_string()]);
+        assert_eq!(app_id.signature_digests(), &vec![hex::decode(
+            "5f2b3a4c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9011223344556677aa"
+        )
+        .unwrap()]);
+    }
+
+    #[test]
+    fn should_fail_to_parse_truncated_key_description() {
+        let bytes = get_sample_key_description_bytes();
+        assert!(KeyDescription::from_der(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+mod attestation_chain;
+mod attestation_error;
+mod attestation_policy;
+mod attestation_roots;
+mod core_attestation;
+mod der;
+mod key_description;
+mod test_utils;
+
+pub use self::{
+    attestation_chain::{AttestationCertificateChain, VerifiedAttestation},
+    attestation_error::AttestationError,
+    attestation_policy::AttestationPolicy,
+    attestation_roots::AttestationRoots,
+    core_attestation::{get_attestation_nonce, verify_core_attestation},
+    key_description::{
+        AttestationApplicationId,
+        AuthorizationList,
+        KeyDescription,
+        RootOfTrust,
+        SecurityLevel,
+        VerifiedBootState,
+    },
+};
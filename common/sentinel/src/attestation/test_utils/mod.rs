@@ -0,0 +1,54 @@
+#![cfg(test)]
+use std::{fs::read_to_string, str::FromStr};
+
+use common::Bytes;
+
+use super::{AttestationCertificateChain, AttestationRoots};
+
+// NOTE: The sample chain's certificates are valid from 2026-10-18 for a hundred years.
+pub const SAMPLE_TIMESTAMP: u64 = 1_800_000_000;
+
+pub fn get_sample_attestation_root_pem() -> String {
+    read_to_string("src/attestation/test_utils/sample-attestation-root.pem").unwrap()
+}
+
+pub fn get_sample_attestation_roots() -> AttestationRoots {
+    AttestationRoots::from_pem(&get_sample_attestation_root_pem()).unwrap()
+}
+
+pub fn get_sample_other_attestation_roots() -> AttestationRoots {
+    AttestationRoots::from_file("src/attestation/test_utils/sample-other-attestation-root.pem").unwrap()
+}
+
+pub fn get_sample_attestation_chain() -> AttestationCertificateChain {
+    let s = read_to_string("src/attestation/test_utils/sample-attestation-chain.pem").unwrap();
+    AttestationCertificateChain::from_str(&s).unwrap()
+}
+
+fn get_sample_chain_and_roots(name: &str) -> (AttestationCertificateChain, AttestationRoots) {
+    let path = format!("src/attestation/test_utils/sample-{name}-attestation");
+    let chain = AttestationCertificateChain::from_str(&read_to_string(format!("{path}-chain.pem")).unwrap());
+    let roots = AttestationRoots::from_file(&format!("{path}-root.pem"));
+    (chain.unwrap(), roots.unwrap())
+}
+
+// NOTE: A chain whose attested key has been used to sign a further leaf claiming its own key description.
+pub fn get_sample_forged_attestation_chain_and_roots() -> (AttestationCertificateChain, AttestationRoots) {
+    get_sample_chain_and_roots("forged")
+}
+
+// NOTE: As above, but where the attested key's certificate also claims to be a CA.
+pub fn get_sample_multiple_key_descriptions_attestation_chain_and_roots(
+) -> (AttestationCertificateChain, AttestationRoots) {
+    get_sample_chain_and_roots("multiple-key-descriptions")
+}
+
+pub fn get_sample_key_description_bytes() -> Bytes {
+    hex::decode("3081e7020200c80a0102020200c80a0102041e73656e74696e656c2d6174746573746174696f6e2d6368616c6c656e676504003055bf853d080206018b25d99f48bf85454504433041311b30190414636f6d2e70746f6b656e732e73656e74696e656c020101312204205f2b3a4c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9011223344556677aa305ea1053103020102bf853e03020100bf85404c304a04200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f200101ff0a01000420a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0").unwrap()
+}
+
+pub fn get_sample_attestation_message_and_signature() -> (Bytes, Bytes) {
+    let message = b"sentinel attestation message".to_vec();
+    let sig = hex::decode("30450220784d44eed8a9170545548fbd010e89831b46cf85e15dbd06c42ab8e2800a8485022100c9f71d77e98a09a4e770cc3be6ac7f5b9bbd2a9300616db00c19adac3f5e0357").unwrap();
+    (message, sig)
+}
@@ -1,4 +1,7 @@
+use std::fmt;
+
 use common_network_ids::NetworkId;
+use derive_getters::Getters;
 use derive_more::{Constructor, Deref, DerefMut};
 use ethereum_types::U256;
 use serde::{Deserialize, Serialize};
@@ -26,6 +29,77 @@ impl Balance {
     }
 }
 
+/// How many broadcaster txs, ie cancellations or challenge responses, a balance can pay for at a
+/// given gas price & limit.
+#[serde_as]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceRunway {
+    // NOTE: These are included in the signed status json, whose keys must be in alphabetical order.
+    balance: String,
+    gas_limit: usize,
+    gas_price: u64,
+    min_runway: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    network_id: NetworkId,
+    runway: u64,
+}
+
+impl BalanceRunway {
+    pub fn new(network_id: NetworkId, balance: U256, gas_price: u64, gas_limit: usize, min_runway: u64) -> Self {
+        let cost = U256::from(gas_limit as u64).saturating_mul(U256::from(gas_price));
+        let runway = if cost.is_zero() {
+            u64::MAX
+        } else {
+            let r = balance / cost;
+            if r > U256::from(u64::MAX) {
+                u64::MAX
+            } else {
+                r.as_u64()
+            }
+        };
+        Self {
+            runway,
+            gas_limit,
+            gas_price,
+            min_runway,
+            network_id,
+            balance: balance.to_string(),
+        }
+    }
+
+    pub fn is_low(&self) -> bool {
+        self.runway < self.min_runway
+    }
+}
+
+impl fmt::Display for BalanceRunway {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} broadcaster balance of {} covers {} txs at gas price {} & limit {} (minimum runway: {})",
+            self.network_id, self.balance, self.runway, self.gas_price, self.gas_limit, self.min_runway
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Deref, Constructor)]
+pub struct BalanceRunways(Vec<BalanceRunway>);
+
+impl BalanceRunways {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn low(&self) -> Self {
+        Self(self.iter().filter(|r| r.is_low()).cloned().collect())
+    }
+
+    pub fn is_low(&self, network_id: &NetworkId) -> bool {
+        self.iter().any(|r| r.network_id() == network_id && r.is_low())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -41,4 +115,21 @@ mod tests {
         assert!(j.contains(b));
         assert!(!j.contains("0x"));
     }
+
+    #[test]
+    fn should_calculate_balance_runway() {
+        let nid = NetworkId::default();
+        let gas_price = 20_000_000_000;
+        let gas_limit = 1_000_000;
+        let cost = U256::from(gas_price) * U256::from(gas_limit);
+        let min_runway = 10;
+        let r = BalanceRunway::new(nid, cost * 9 + 1, gas_price, gas_limit, min_runway);
+        assert_eq!(*r.runway(), 9);
+        assert!(r.is_low());
+        let r = BalanceRunway::new(nid, cost * 10, gas_price, gas_limit, min_runway);
+        assert_eq!(*r.runway(), 10);
+        assert!(!r.is_low());
+        let r = BalanceRunway::new(nid, U256::zero(), 0, gas_limit, min_runway);
+        assert!(!r.is_low());
+    }
 }
@@ -8,7 +8,7 @@ use jsonrpsee::ws_client::WsClient;
 use serde_json::Value as Json;
 use thiserror::Error;
 
-use crate::{endpoints::Endpoints, Bpm, ProcessorOutput, SentinelConfig, SentinelError};
+use crate::{endpoints::Endpoints, Bpm, PnetworkHubs, ProcessorOutput, SentinelConfig, SentinelError};
 
 #[derive(Debug, Clone, Getters)]
 pub struct Batch {
@@ -20,7 +20,7 @@ pub struct Batch {
     batch_duration: u64,
     endpoints: Endpoints,
     network_id: NetworkId,
-    pnetwork_hub: EthAddress,
+    pnetwork_hubs: PnetworkHubs,
     pre_filter_receipts: bool,
     batching_is_disabled: bool,
     single_submissions_flag: bool,
@@ -45,7 +45,7 @@ impl Default for Batch {
             single_submissions_flag: false,
             endpoints: Endpoints::default(),
             network_id: NetworkId::default(),
-            pnetwork_hub: EthAddress::default(),
+            pnetwork_hubs: PnetworkHubs::default(),
             receipt_filtering_addresses: vec![],
             batch: EthSubmissionMaterials::default(),
             last_submitted_timestamp: SystemTime::now(),
@@ -117,14 +117,14 @@ impl Batch {
 
         let pre_filter_receipts = config.pre_filter_receipts(&network_id)?;
 
-        let pnetwork_hub = config.pnetwork_hub(&network_id)?;
+        let pnetwork_hubs = config.pnetwork_hubs(&network_id)?;
 
         let governance_address = config.governance_address(&network_id);
 
         let receipt_filtering_addresses = if let Some(ref address) = governance_address {
-            vec![*address, pnetwork_hub]
+            [vec![*address], pnetwork_hubs.addresses()].concat()
         } else {
-            vec![pnetwork_hub]
+            pnetwork_hubs.addresses()
         };
 
         let sleep_duration = config.sleep_duration(&network_id)?;
@@ -134,7 +134,7 @@ impl Batch {
         let res = Self {
             endpoints,
             network_id,
-            pnetwork_hub,
+            pnetwork_hubs,
             sleep_duration,
             governance_address,
             pre_filter_receipts,
@@ -152,6 +152,21 @@ impl Batch {
         }
     }
 
+    /// Applies any runtime changeable config to this batch, leaving its sync progress intact.
+    pub fn update_from_config(&mut self, config: &SentinelConfig) -> Result<(), SentinelError> {
+        let network_id = self.network_id;
+        let endpoints = config.endpoints(&network_id)?;
+        if endpoints.is_empty() {
+            return Err(SentinelError::Batching(BatchingError::NoEndpoint(network_id)));
+        };
+        self.endpoints = endpoints;
+        self.batch_size = config.batch_size(&network_id)?;
+        self.sleep_duration = config.sleep_duration(&network_id)?;
+        self.batch_duration = config.batch_duration(&network_id)?;
+        self.pre_filter_receipts = config.pre_filter_receipts(&network_id)?;
+        Ok(())
+    }
+
     pub fn enable_batching(&mut self) {
         self.batching_is_disabled = false;
     }
@@ -174,15 +189,24 @@ impl Batch {
 
     pub fn push(&mut self, sub_mat: EthSubmissionMaterial) {
         if self.pre_filter_receipts {
-            self.batch
-                .push(sub_mat.remove_receipts_if_no_logs_from_addresses(&self.receipt_filtering_addresses));
+            // NOTE: The compact form proves the remaining receipts' inclusion in the block, so the
+            // core can still validate them. It requires the full set of receipts however, so we fall
+            // back to simply removing the non-pertinent ones if it can't be created.
+            match sub_mat.to_compact_with_receipts_from_addresses(&self.receipt_filtering_addresses) {
+                Ok(compact_sub_mat) => self.batch.push(compact_sub_mat),
+                Err(e) => {
+                    warn!("could not compact sub mat, removing non-pertinent receipts instead: {e}");
+                    self.batch
+                        .push(sub_mat.remove_receipts_if_no_logs_from_addresses(&self.receipt_filtering_addresses));
+                },
+            }
         } else {
             self.batch.push(sub_mat)
         }
     }
 
-    pub fn get_pnetwork_hub(&self) -> &EthAddress {
-        &self.pnetwork_hub
+    pub fn get_pnetwork_hubs(&self) -> &PnetworkHubs {
+        &self.pnetwork_hubs
     }
 
     pub fn is_empty(&self) -> bool {
@@ -301,7 +325,7 @@ mod tests {
     use common_eth::{convert_hex_to_eth_address, EthLog, EthLogs, EthReceipt, EthReceipts};
 
     use super::*;
-    use crate::test_utils::get_sample_batch;
+    use crate::test_utils::{get_sample_batch, get_sample_sub_mat_n};
 
     #[test]
     fn should_enable_batching() {
@@ -444,6 +468,21 @@ mod tests {
         assert_eq!(batch.batch[0].receipts, receipts);
     }
 
+    #[test]
+    fn pushed_block_should_be_compacted_if_pre_filtering_is_enabled() {
+        let sub_mat = get_sample_sub_mat_n(1);
+        let address = convert_hex_to_eth_address("0x8eb3492af47e66b4ba9c7788132cf85952aa5eac").unwrap();
+        let mut batch = Batch {
+            pre_filter_receipts: true,
+            receipt_filtering_addresses: vec![address],
+            ..Default::default()
+        };
+        batch.push(sub_mat.clone());
+        assert!(batch.batch[0].receipt_proofs.is_some());
+        assert!(batch.batch[0].receipts.len() < sub_mat.receipts.len());
+        assert!(batch.batch[0].receipts_are_valid().unwrap());
+    }
+
     #[test]
     fn should_pass_is_chained_check_if_batch_is_empty() {
         let nid = NetworkId::try_from("eth").unwrap();
@@ -2,7 +2,6 @@ use common::MIN_DATA_SENSITIVITY_LEVEL;
 use common_eth::{EthPrivateKey, EthSignature, EthSigningCapabilities};
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
-use derive_more::Constructor;
 use ethereum_types::{Address as EthAddress, U256};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
@@ -24,16 +23,42 @@ From: https://github.com/pnetwork-association/pnetwork/blob/14d11b116da6abf70cba
 
 // FIXME Do we want/need to track the `ChallengeState` in here?
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Getters, Constructor)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
 pub struct Challenge {
     nonce: U256,
     actor: Actor,
     timestamp: u64,
     network_id: NetworkId,
     challenger_address: EthAddress,
+    // NOTE: The hub which emitted this challenge, & thus to which responses etc must be sent. This
+    // is not part of the on-chain challenge struct, so plays no part in its ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pnetwork_hub: Option<EthAddress>,
 }
 
 impl Challenge {
+    pub fn new(
+        nonce: U256,
+        actor: Actor,
+        timestamp: u64,
+        network_id: NetworkId,
+        challenger_address: EthAddress,
+    ) -> Self {
+        Self {
+            nonce,
+            actor,
+            timestamp,
+            network_id,
+            challenger_address,
+            pnetwork_hub: None,
+        }
+    }
+
+    pub(super) fn with_pnetwork_hub(mut self, pnetwork_hub: EthAddress) -> Self {
+        self.pnetwork_hub = Some(pnetwork_hub);
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn random() -> Self {
         use rand::Rng;
@@ -75,6 +100,7 @@ impl From<&ChallengeEvent> for Challenge {
             network_id: *event.network_id(),
             challenger_address: *event.challenger_address(),
             actor: Actor::new(*event.actor_type(), *event.actor_address()),
+            pnetwork_hub: None,
         }
     }
 }
@@ -0,0 +1,74 @@
+use common::Bytes;
+use common_chain_ids::EthChainId;
+use common_eth::{encode_fxn_call, EthPrivateKey, EthTransaction};
+use common_network_ids::NetworkId;
+use ethereum_types::Address as EthAddress;
+
+use super::{Challenge, ChallengesError};
+
+const SLASH_BY_CHALLENGE_FXN_ABI: &str = "[{\"inputs\":[{\"components\":[{\"internalType\":\"uint256\",\"name\":\"nonce\",\"type\":\"uint256\"},{\"internalType\":\"address\",\"name\":\"actor\",\"type\":\"address\"},{\"internalType\":\"address\",\"name\":\"challenger\",\"type\":\"address\"},{\"internalType\":\"enum IPNetworkHub.ActorTypes\",\"name\":\"actorType\",\"type\":\"uint8\"},{\"internalType\":\"uint64\",\"name\":\"timestamp\",\"type\":\"uint64\"},{\"internalType\":\"bytes4\",\"name\":\"networkId\",\"type\":\"bytes4\"}],\"internalType\":\"struct IPNetworkHub.Challenge\",\"name\":\"challenge\",\"type\":\"tuple\"}],\"name\":\"slashByChallenge\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"}]";
+
+impl Challenge {
+    /// A challenge can only be used to slash its actor once it has gone unsolved for at least the
+    /// challenge duration.
+    pub fn has_expired(&self, challenge_duration: u64, now: u64) -> bool {
+        now > self.timestamp().saturating_add(challenge_duration)
+    }
+
+    fn encode_slash_by_challenge_fxn_data(&self) -> Result<Bytes, ChallengesError> {
+        debug!("encoding `slashByChallenge` fxn data...");
+        Ok(encode_fxn_call(SLASH_BY_CHALLENGE_FXN_ABI, "slashByChallenge", &[
+            self.to_eth_abi_token()?,
+        ])?)
+    }
+
+    pub fn to_slash_by_challenge_tx(
+        self,
+        nonce: u64,
+        gas_price: u64,
+        gas_limit: usize,
+        network_id: &NetworkId,
+        pnetwork_hub: &EthAddress,
+        broadcaster_pk: &EthPrivateKey,
+    ) -> Result<EthTransaction, ChallengesError> {
+        let value = 0;
+        let ecid = EthChainId::try_from(network_id)?;
+        let data = self.encode_slash_by_challenge_fxn_data()?;
+        debug!("nonce: {nonce}");
+        debug!("gas_price: {gas_price}");
+        debug!("gas_limit: {gas_limit}");
+        debug!("pnetwork_hub: {pnetwork_hub}");
+        debug!("eth_chain_id: {ecid}");
+        debug!("tx signer: {}", broadcaster_pk.to_address());
+        Ok(
+            EthTransaction::new_unsigned(data, nonce, value, *pnetwork_hub, &ecid, gas_limit, gas_price)
+                .sign(broadcaster_pk)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::crypto_utils::keccak_hash_bytes;
+
+    use super::*;
+    use crate::challenges::test_utils::get_sample_challenge;
+
+    #[test]
+    fn should_encode_slash_by_challenge_data() {
+        let challenge = get_sample_challenge();
+        let result = challenge.encode_slash_by_challenge_fxn_data().unwrap();
+        let expected_selector = keccak_hash_bytes(b"slashByChallenge((uint256,address,address,uint8,uint64,bytes4))");
+        assert_eq!(result[..4], expected_selector[..4]);
+        assert_eq!(result[4..], challenge.abi_encode().unwrap());
+    }
+
+    #[test]
+    fn should_only_expire_after_challenge_duration() {
+        let challenge = get_sample_challenge();
+        let duration = 3600;
+        let timestamp = *challenge.timestamp();
+        assert!(!challenge.has_expired(duration, timestamp + duration));
+        assert!(challenge.has_expired(duration, timestamp + duration + 1));
+    }
+}
@@ -0,0 +1,101 @@
+use common::Bytes;
+use common_chain_ids::EthChainId;
+use common_eth::{encode_fxn_call, EthPrivateKey, EthTransaction};
+use common_network_ids::NetworkId;
+use ethabi::Token as EthAbiToken;
+use ethereum_types::{Address as EthAddress, U256};
+
+use super::ChallengesError;
+use crate::{Actor, ActorInclusionProof};
+
+const START_CHALLENGE_FXN_ABI: &str = "[{\"inputs\":[{\"internalType\":\"address\",\"name\":\"actor\",\"type\":\"address\"},{\"internalType\":\"enum IPNetworkHub.ActorTypes\",\"name\":\"actorType\",\"type\":\"uint8\"},{\"internalType\":\"bytes32[]\",\"name\":\"proof\",\"type\":\"bytes32[]\"}],\"name\":\"startChallenge\",\"outputs\":[],\"stateMutability\":\"payable\",\"type\":\"function\"}]";
+
+impl Actor {
+    pub fn check_challenge_affordability(
+        balance: U256,
+        lock_amount: u64,
+        gas_limit: usize,
+        gas_price: u64,
+    ) -> Result<(), ChallengesError> {
+        // NOTE: Starting a challenge requires locking some amount in the hub, on top of the gas.
+        let cost = U256::from(gas_limit as u64) * U256::from(gas_price) + U256::from(lock_amount);
+        if balance > cost {
+            Ok(())
+        } else {
+            Err(ChallengesError::InsufficientBalance {
+                have: balance,
+                need: cost,
+            })
+        }
+    }
+
+    fn encode_start_challenge_fxn_data(&self, proof: &ActorInclusionProof) -> Result<Bytes, ChallengesError> {
+        debug!("encoding `startChallenge` fxn data...");
+        let actor_type: u8 = self.actor_type().into();
+        Ok(encode_fxn_call(START_CHALLENGE_FXN_ABI, "startChallenge", &[
+            EthAbiToken::Address(*self.actor_address()),
+            EthAbiToken::Uint(U256::from(actor_type)),
+            EthAbiToken::from(proof),
+        ])?)
+    }
+
+    pub fn to_start_challenge_tx(
+        &self,
+        proof: &ActorInclusionProof,
+        lock_amount: u64,
+        nonce: u64,
+        gas_price: u64,
+        gas_limit: usize,
+        network_id: &NetworkId,
+        pnetwork_hub: &EthAddress,
+        broadcaster_pk: &EthPrivateKey,
+    ) -> Result<EthTransaction, ChallengesError> {
+        let value = lock_amount as usize;
+        let ecid = EthChainId::try_from(network_id)?;
+        let data = self.encode_start_challenge_fxn_data(proof)?;
+        debug!("nonce: {nonce}");
+        debug!("gas_price: {gas_price}");
+        debug!("gas_limit: {gas_limit}");
+        debug!("lock_amount: {lock_amount}");
+        debug!("pnetwork_hub: {pnetwork_hub}");
+        debug!("eth_chain_id: {ecid}");
+        debug!("challenged actor: {}", self.actor_address());
+        debug!("tx signer: {}", broadcaster_pk.to_address());
+        Ok(
+            EthTransaction::new_unsigned(data, nonce, value, *pnetwork_hub, &ecid, gas_limit, gas_price)
+                .sign(broadcaster_pk)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::crypto_utils::keccak_hash_bytes;
+
+    use super::*;
+    use crate::actors::test_utils::get_sample_actors;
+
+    #[test]
+    fn should_encode_start_challenge_data() {
+        let actors = get_sample_actors();
+        let actor = actors.actors()[1];
+        let proof = actors.get_inclusion_proof_for_actor(&actor).unwrap();
+        let result = actor.encode_start_challenge_fxn_data(&proof).unwrap();
+        let expected_selector = keccak_hash_bytes(b"startChallenge(address,uint8,bytes32[])");
+        assert_eq!(result[..4], expected_selector[..4]);
+        // NOTE: Selector + address + actor type + array offset + array length + 3 proof elements
+        assert_eq!(result.len(), 4 + 32 * 7);
+    }
+
+    #[test]
+    fn should_fail_affordability_check_if_lock_amount_cannot_be_covered() {
+        let gas_limit = 100;
+        let gas_price = 1;
+        let lock_amount = 1000;
+        let balance = U256::from(1000);
+        let result = Actor::check_challenge_affordability(balance, lock_amount, gas_limit, gas_price);
+        assert!(matches!(result, Err(ChallengesError::InsufficientBalance { .. })));
+        let balance = U256::from(1101);
+        assert!(Actor::check_challenge_affordability(balance, lock_amount, gas_limit, gas_price).is_ok());
+    }
+}
@@ -7,26 +7,43 @@ use serde_json::Value as Json;
 use super::{Challenge, ChallengePendingEvents, ChallengesError};
 use crate::WebSocketMessagesEncodable;
 
-#[derive(Debug, Clone, Eq, PartialEq, Constructor, Deref, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Constructor, Deref, Serialize, Deserialize)]
 pub struct Challenges(Vec<Challenge>);
 
 impl Challenges {
-    pub fn from_sub_mat(
+    fn from_sub_mat_with_filter<F: Fn(&Challenge) -> bool>(
         sub_mat: &EthSubmissionMaterial,
         pnetwork_hub: &EthAddress,
-        sentinel_address: &EthAddress,
+        filter: F,
     ) -> Result<Self, ChallengesError> {
         debug!("parsing challenges from sub mat...");
         ChallengePendingEvents::from_sub_mat(sub_mat, pnetwork_hub)
             .map(|events| {
                 events
                     .iter()
-                    .map(Challenge::from)
-                    .filter(|c| c.actor().actor_address() == sentinel_address)
+                    .map(|e| Challenge::from(e).with_pnetwork_hub(*pnetwork_hub))
+                    .filter(|c| filter(c))
                     .collect()
             })
             .map(Self::new)
     }
+
+    pub fn from_sub_mat(
+        sub_mat: &EthSubmissionMaterial,
+        pnetwork_hub: &EthAddress,
+        sentinel_address: &EthAddress,
+    ) -> Result<Self, ChallengesError> {
+        Self::from_sub_mat_with_filter(sub_mat, pnetwork_hub, |c| c.actor().actor_address() == sentinel_address)
+    }
+
+    /// Gets those challenges from the sub mat which were started by the passed in challenger.
+    pub fn from_sub_mat_started_by(
+        sub_mat: &EthSubmissionMaterial,
+        pnetwork_hub: &EthAddress,
+        challenger_address: &EthAddress,
+    ) -> Result<Self, ChallengesError> {
+        Self::from_sub_mat_with_filter(sub_mat, pnetwork_hub, |c| c.challenger_address() == challenger_address)
+    }
 }
 
 impl TryFrom<WebSocketMessagesEncodable> for Challenges {
@@ -75,7 +92,7 @@ mod tests {
         let pnetwork_hub = EthAddress::from_str("0x6153ec976A5B3886caF3A88D8d994c4CEC24203E").unwrap();
         let events = Challenges::from_sub_mat(&sub_mat, &pnetwork_hub, &sentinel_address).unwrap();
         assert_eq!(events.len(), 1);
-        let expected_challenge = get_expected_challenge();
+        let expected_challenge = get_expected_challenge().with_pnetwork_hub(pnetwork_hub);
         assert_eq!(events[0], expected_challenge);
     }
 
@@ -96,7 +113,8 @@ mod tests {
             1698667343,
             NetworkId::try_from("polygon").unwrap(),
             EthAddress::from_str("0xe5de26b691d615353a03285405b6ee08c7974926").unwrap(),
-        );
+        )
+        .with_pnetwork_hub(pnetwork_hub);
         assert_eq!(challenge, expected_challenge);
         let expected_encoded = "000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000073659a0f105905121edbf44fb476b97c785688ec000000000000000000000000e5de26b691d615353a03285405b6ee08c7974926000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000653f9b4ff9b459a100000000000000000000000000000000000000000000000000000000";
         let encoded = hex::encode(challenge.abi_encode().unwrap());
@@ -106,4 +124,17 @@ mod tests {
         let id = challenge.id().unwrap();
         assert_eq!(id, expected_id);
     }
+
+    #[test]
+    fn should_get_challenges_started_by_challenger_from_sub_mat() {
+        let sub_mat = get_sample_sub_mat_with_challenge_pending_event_2();
+        let pnetwork_hub = EthAddress::from_str("0xf28910cc8f21e9314ed50627c11de36bc0b7338f").unwrap();
+        let challenger = EthAddress::from_str("0xe5de26b691d615353a03285405b6ee08c7974926").unwrap();
+        let challenges = Challenges::from_sub_mat_started_by(&sub_mat, &pnetwork_hub, &challenger).unwrap();
+        assert_eq!(challenges.len(), 1);
+        assert_eq!(challenges[0].challenger_address(), &challenger);
+        let other_challenger = EthAddress::random();
+        let challenges = Challenges::from_sub_mat_started_by(&sub_mat, &pnetwork_hub, &other_challenger).unwrap();
+        assert!(challenges.is_empty());
+    }
 }
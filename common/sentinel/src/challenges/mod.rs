@@ -4,11 +4,14 @@ mod challenge_id;
 mod challenge_list_entry;
 mod challenge_response_signature;
 mod challenge_response_tx;
+mod challenge_slash_tx;
+mod challenge_start_tx;
 mod challenge_state;
 mod challenges;
 mod challenges_error;
 mod challenges_list;
 mod events;
+mod started_challenges_list;
 mod test_utils;
 
 pub(crate) use self::events::ChallengeSolvedEvents;
@@ -20,6 +23,7 @@ pub use self::{
     challenges::Challenges,
     challenges_error::ChallengesError,
     challenges_list::ChallengesList,
+    started_challenges_list::{StartedChallenge, StartedChallenges, StartedChallengesList},
 };
 use self::{
     challenge_list_entry::ChallengesListEntry,
@@ -0,0 +1,173 @@
+use std::fmt;
+
+use common::{DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use derive_getters::Getters;
+use derive_more::{Constructor, Deref, DerefMut};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::{Challenge, ChallengeState, Challenges, ChallengesError, ChallengesListEntry};
+use crate::{db_utils::SentinelDbKeys, DbKey, DbUtilsT, SentinelDbUtils, SentinelError, WebSocketMessagesEncodable};
+
+/// A challenge started by this sentinel against some other actor, along with its latest known
+/// state.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters, Constructor)]
+pub struct StartedChallenge {
+    challenge: Challenge,
+    status: ChallengeState,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref)]
+pub struct StartedChallenges(Vec<StartedChallenge>);
+
+impl TryFrom<WebSocketMessagesEncodable> for StartedChallenges {
+    type Error = ChallengesError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        let j = Json::try_from(m)?;
+        Ok(serde_json::from_value(j)?)
+    }
+}
+
+/// The list of challenges this sentinel has started, kept separately from the `ChallengesList`
+/// which tracks those challenges made _against_ this sentinel.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref, DerefMut)]
+pub struct StartedChallengesList(Vec<ChallengesListEntry>);
+
+impl fmt::Display for StartedChallengesList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl StartedChallengesList {
+    pub fn get<D: DatabaseInterface>(db_utils: &SentinelDbUtils<D>) -> Self {
+        if let Ok(x) = Self::get_from_db(db_utils, &SentinelDbKeys::get_started_challenges_list_db_key()) {
+            x
+        } else {
+            debug!("no `StartedChallengesList` in db, defaulting to empty list");
+            Self::default()
+        }
+    }
+
+    fn entry_idx(&self, needle: &EthHash) -> Option<usize> {
+        self.iter().position(|entry| entry.hash() == needle)
+    }
+
+    fn add_challenge<D: DatabaseInterface>(
+        &mut self,
+        db_utils: &SentinelDbUtils<D>,
+        challenge: Challenge,
+    ) -> Result<(), SentinelError> {
+        let hash = challenge.hash()?;
+        if self.entry_idx(&hash).is_some() {
+            warn!("not adding started challenge to list, it already exists!");
+            Ok(())
+        } else {
+            debug!("adding challenge to started challenges list");
+            challenge.put_in_db(db_utils)?;
+            self.push(ChallengesListEntry::try_from(challenge)?);
+            self.update_in_db(db_utils)
+        }
+    }
+
+    pub fn add_challenges<D: DatabaseInterface>(
+        mut self,
+        db_utils: &SentinelDbUtils<D>,
+        challenges: Challenges,
+    ) -> Result<(), SentinelError> {
+        debug!("adding {} challenges to started list...", challenges.len());
+        challenges
+            .iter()
+            .cloned()
+            .try_for_each(|c| self.add_challenge(db_utils, c))
+    }
+
+    pub fn update_challenge_status<D: DatabaseInterface>(
+        &mut self,
+        db_utils: &SentinelDbUtils<D>,
+        hash: &EthHash,
+        status: ChallengeState,
+    ) -> Result<(), SentinelError> {
+        match self.entry_idx(hash) {
+            None => Err(ChallengesError::NotInList(*hash).into()),
+            Some(idx) => {
+                let existing_status = self[idx].status().clone();
+                if existing_status < status {
+                    debug!("updating started challenge status from {existing_status} to {status}");
+                    self[idx].status = status;
+                    self.update_in_db(db_utils)
+                } else {
+                    warn!("cannot update started challenge status from {existing_status} to {status}");
+                    Ok(())
+                }
+            },
+        }
+    }
+
+    pub fn get_started_challenges<D: DatabaseInterface>(
+        db_utils: &SentinelDbUtils<D>,
+    ) -> Result<StartedChallenges, SentinelError> {
+        let list = Self::get(db_utils);
+        Ok(StartedChallenges::new(
+            list.iter()
+                .map(|entry| {
+                    let challenge = Challenge::get_from_db(db_utils, &(*entry.hash()).into())?;
+                    Ok(StartedChallenge::new(challenge, entry.status().clone()))
+                })
+                .collect::<Result<Vec<_>, SentinelError>>()?,
+        ))
+    }
+}
+
+impl DbUtilsT for StartedChallengesList {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_started_challenges_list_db_key())
+    }
+
+    fn sensitivity() -> Option<u8> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+    use crate::challenges::test_utils::get_n_random_challenges;
+
+    #[test]
+    fn started_challenges_list_should_work_correctly() {
+        let db = get_test_database();
+        let db_utils = SentinelDbUtils::new(&db);
+        assert!(StartedChallengesList::get(&db_utils).is_empty());
+
+        let challenges = get_n_random_challenges(3);
+        StartedChallengesList::get(&db_utils)
+            .add_challenges(&db_utils, challenges.clone())
+            .unwrap();
+        let started = StartedChallengesList::get_started_challenges(&db_utils).unwrap();
+        assert_eq!(started.len(), 3);
+        assert!(started.iter().all(|c| c.status() == &ChallengeState::Pending));
+        assert_eq!(started[1].challenge(), &challenges[1]);
+
+        // NOTE: Started challenges are kept separately from those made against this sentinel.
+        assert!(crate::ChallengesList::get(&db_utils).is_empty());
+
+        let hash = challenges[1].hash().unwrap();
+        let mut list = StartedChallengesList::get(&db_utils);
+        list.update_challenge_status(&db_utils, &hash, ChallengeState::Unsolved)
+            .unwrap();
+        // NOTE: Statuses cannot go backwards
+        list.update_challenge_status(&db_utils, &hash, ChallengeState::Pending)
+            .unwrap();
+        let started = StartedChallengesList::get_started_challenges(&db_utils).unwrap();
+        assert_eq!(started[1].status(), &ChallengeState::Unsolved);
+    }
+}
@@ -0,0 +1,73 @@
+use std::result::Result;
+
+use common::Bytes;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{AttestationError, AttestationPolicy, AttestationRoots, SentinelError};
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct AttestationToml {
+    enabled: bool,
+    roots_path: Option<String>,
+    require_strongbox: bool,
+    require_verified_boot: bool,
+    package_name: Option<String>,
+    #[serde(default)]
+    signature_digests: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AttestationConfig {
+    enabled: bool,
+    roots_path: Option<String>,
+    require_strongbox: bool,
+    require_verified_boot: bool,
+    package_name: Option<String>,
+    signature_digests: Vec<String>,
+}
+
+impl TryFrom<&AttestationToml> for AttestationConfig {
+    type Error = SentinelError;
+
+    fn try_from(toml: &AttestationToml) -> Result<Self, Self::Error> {
+        let config = Self {
+            enabled: toml.enabled,
+            roots_path: toml.roots_path.clone(),
+            require_strongbox: toml.require_strongbox,
+            require_verified_boot: toml.require_verified_boot,
+            package_name: toml.package_name.clone(),
+            signature_digests: toml.signature_digests.clone(),
+        };
+        // NOTE: So that a bad roots file or digest fails at startup, rather than when a core connects.
+        config.policy()?;
+        if config.enabled && config.roots()?.is_empty() {
+            return Err(AttestationError::NoRoots.into());
+        };
+        Ok(config)
+    }
+}
+
+impl AttestationConfig {
+    pub fn roots(&self) -> Result<AttestationRoots, AttestationError> {
+        match self.roots_path {
+            Some(ref path) => AttestationRoots::from_file(path),
+            None => AttestationRoots::bundled(),
+        }
+    }
+
+    pub fn policy(&self) -> Result<AttestationPolicy, AttestationError> {
+        let signature_digests = self
+            .signature_digests
+            .iter()
+            .map(|d| Ok(hex::decode(d.trim_start_matches("0x"))?))
+            .collect::<Result<Vec<Bytes>, AttestationError>>()?;
+        Ok(AttestationPolicy::new(
+            self.require_strongbox,
+            self.require_verified_boot,
+            self.package_name.clone(),
+            signature_digests,
+            None, // NOTE: The challenge is chosen by the strongbox when it generates its key.
+        ))
+    }
+}
@@ -0,0 +1,51 @@
+use std::result::Result;
+
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{MAX_FREQUENCY, MIN_FREQUENCY},
+    SentinelError,
+};
+
+const DEFAULT_MIN_RUNWAY: u64 = 10;
+const DEFAULT_CHECK_FREQUENCY: u64 = 300;
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct BalancesToml {
+    min_runway: u64,
+    check_frequency: u64,
+}
+
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BalancesConfig {
+    min_runway: u64,
+    check_frequency: u64,
+}
+
+impl Default for BalancesConfig {
+    fn default() -> Self {
+        Self {
+            min_runway: DEFAULT_MIN_RUNWAY,
+            check_frequency: DEFAULT_CHECK_FREQUENCY,
+        }
+    }
+}
+
+impl TryFrom<&BalancesToml> for BalancesConfig {
+    type Error = SentinelError;
+
+    fn try_from(toml: &BalancesToml) -> Result<Self, Self::Error> {
+        if !(MIN_FREQUENCY..=MAX_FREQUENCY).contains(&toml.check_frequency) {
+            return Err(SentinelError::InvalidFrequency {
+                frequency: toml.check_frequency,
+                min: MIN_FREQUENCY,
+                max: MAX_FREQUENCY,
+            });
+        };
+        Ok(Self {
+            min_runway: toml.min_runway,
+            check_frequency: toml.check_frequency,
+        })
+    }
+}
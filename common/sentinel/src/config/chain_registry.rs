@@ -0,0 +1,116 @@
+use std::{fs::read, result::Result, str::FromStr};
+
+use common::{crypto_utils::keccak_hash_bytes, Byte};
+use common_chain_ids::ChainRegistry;
+use common_eth::EthSignature;
+use derive_getters::Getters;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::SentinelConfigError, SentinelError};
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct ChainRegistryToml {
+    path: String,
+    signer: String,
+    signature: String,
+}
+
+/// A JSON file of extra chains to add to the compiled-in chain registry, along with a signature
+/// over the keccak hash of that file's bytes, made with the eth message prefix by the given signer.
+#[derive(Debug, Clone, Default, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChainRegistryConfig {
+    path: String,
+    signer: EthAddress,
+    signature: String,
+}
+
+impl TryFrom<&ChainRegistryToml> for ChainRegistryConfig {
+    type Error = SentinelError;
+
+    fn try_from(toml: &ChainRegistryToml) -> Result<Self, Self::Error> {
+        Ok(Self {
+            path: toml.path.clone(),
+            signer: EthAddress::from_str(&toml.signer)?,
+            signature: toml.signature.clone(),
+        })
+    }
+}
+
+impl ChainRegistryConfig {
+    fn get_eth_prefixed_hash(bytes: &[Byte]) -> EthHash {
+        let prefix: &[u8] = b"\x19Ethereum Signed Message:\n32";
+        keccak_hash_bytes(&[prefix, keccak_hash_bytes(bytes).as_bytes()].concat())
+    }
+
+    fn verify(&self, bytes: &[Byte]) -> Result<(), SentinelError> {
+        let hash = Self::get_eth_prefixed_hash(bytes);
+        let recovered = EthSignature::from_str(&self.signature)?.recover_signer_address(&hash)?;
+        if recovered == self.signer {
+            Ok(())
+        } else {
+            Err(SentinelConfigError::ChainRegistrySigner {
+                expected: self.signer,
+                recovered,
+            }
+            .into())
+        }
+    }
+
+    /// Adds the chains in the registry file to the process-wide chain registry, once the file's
+    /// signature has been verified. Registering the same file again is a no-op.
+    pub fn register(&self) -> Result<(), SentinelError> {
+        info!("registering extra chains from chain registry file at '{}'", self.path);
+        let bytes = read(&self.path)?;
+        self.verify(&bytes)?;
+        Ok(ChainRegistry::register_from_json_str(std::str::from_utf8(&bytes)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_chain_registry_config() -> ChainRegistryConfig {
+        ChainRegistryConfig {
+            path: "src/config/test_utils/sample-chain-registry.json".to_string(),
+            signer: EthAddress::from_str("0x00a329c0648769a73afac7f9381e08fb43dbea72").unwrap(),
+            signature: "0x0d8c168b412fc9f8f5c6f89df027b22d2177645cd0aa985b607f0d26d5c1c764619b1de23be1b6a27e44150db28bd8d60e180e6619386ef96009ea81ae2cb8361c".to_string(),
+        }
+    }
+
+    #[test]
+    fn should_verify_chain_registry_signature() {
+        let config = get_sample_chain_registry_config();
+        let bytes = read(config.path()).unwrap();
+        let result = config.verify(&bytes);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_verify_chain_registry_with_wrong_signer() {
+        let mut config = get_sample_chain_registry_config();
+        let bytes = read(config.path()).unwrap();
+        let expected = EthAddress::random();
+        config.signer = expected;
+        match config.verify(&bytes) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(SentinelError::SentinelConfig(SentinelConfigError::ChainRegistrySigner { expected: e, .. })) => {
+                assert_eq!(e, expected)
+            },
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_modified_chain_registry() {
+        let config = get_sample_chain_registry_config();
+        let mut bytes = read(config.path()).unwrap();
+        bytes.push(b'\n');
+        match config.verify(&bytes) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(SentinelError::SentinelConfig(SentinelConfigError::ChainRegistrySigner { .. })) => (),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
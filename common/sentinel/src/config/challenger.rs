@@ -0,0 +1,55 @@
+use std::result::Result;
+
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{MAX_FREQUENCY, MIN_FREQUENCY},
+    SentinelError,
+};
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct ChallengerToml {
+    enabled: bool,
+    frequency: u64,
+    network_id: String,
+    lock_amount: u64,
+    gas_limit: usize,
+    challenge_duration: u64,
+    heartbeat_threshold: u64,
+}
+
+#[derive(Debug, Clone, Default, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChallengerConfig {
+    enabled: bool,
+    frequency: u64,
+    network_id: NetworkId,
+    lock_amount: u64,
+    gas_limit: usize,
+    challenge_duration: u64,
+    heartbeat_threshold: u64,
+}
+
+impl TryFrom<&ChallengerToml> for ChallengerConfig {
+    type Error = SentinelError;
+
+    fn try_from(toml: &ChallengerToml) -> Result<Self, Self::Error> {
+        if !(MIN_FREQUENCY..=MAX_FREQUENCY).contains(&toml.frequency) {
+            return Err(SentinelError::InvalidFrequency {
+                frequency: toml.frequency,
+                min: MIN_FREQUENCY,
+                max: MAX_FREQUENCY,
+            });
+        };
+        Ok(Self {
+            enabled: toml.enabled,
+            frequency: toml.frequency,
+            gas_limit: toml.gas_limit,
+            lock_amount: toml.lock_amount,
+            challenge_duration: toml.challenge_duration,
+            heartbeat_threshold: toml.heartbeat_threshold,
+            network_id: NetworkId::try_from(toml.network_id())?,
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, result::Result};
+use std::{
+    collections::{HashMap, HashSet},
+    result::Result,
+};
 
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
@@ -8,6 +11,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     config::{
+        AttestationConfig,
+        AttestationToml,
+        BalancesConfig,
+        BalancesToml,
+        ChainRegistryConfig,
+        ChainRegistryToml,
+        ChallengerConfig,
+        ChallengerToml,
         GovernanceConfig,
         GovernanceToml,
         IpfsConfig,
@@ -15,13 +26,19 @@ use crate::{
         LogToml,
         NetworkConfig,
         NetworkToml,
+        NotificationsConfig,
+        NotificationsToml,
         SentinelConfigError,
         SentinelCoreConfig,
     },
+    sanity_check_frequency,
     Endpoints,
+    PnetworkHubs,
     SentinelError,
 };
 
+const CONFIG_TEMPLATE: &str = include_str!("sentinel-config-template.toml");
+
 #[derive(Debug, Clone, Deserialize)]
 struct SentinelConfigToml {
     log: LogToml,
@@ -29,6 +46,11 @@ struct SentinelConfigToml {
     core: SentinelCoreConfig,
     governance: GovernanceToml,
     networks: HashMap<String, NetworkToml>,
+    challenger: Option<ChallengerToml>,
+    notifications: Option<NotificationsToml>,
+    balances: Option<BalancesToml>,
+    attestation: Option<AttestationToml>,
+    chain_registry: Option<ChainRegistryToml>,
 }
 
 impl SentinelConfigToml {
@@ -47,6 +69,11 @@ pub struct SentinelConfig {
     core: SentinelCoreConfig,
     governance: GovernanceConfig,
     networks: HashMap<NetworkId, NetworkConfig>,
+    challenger: ChallengerConfig,
+    notifications: NotificationsConfig,
+    balances: BalancesConfig,
+    attestation: AttestationConfig,
+    chain_registry: Option<ChainRegistryConfig>,
 }
 
 impl SentinelConfig {
@@ -56,7 +83,21 @@ impl SentinelConfig {
         Ok(res)
     }
 
+    /// A commented config file, with placeholder values, to start a new sentinel's config from.
+    pub fn template() -> &'static str {
+        CONFIG_TEMPLATE
+    }
+
     fn from_toml(toml: &SentinelConfigToml) -> Result<Self, SentinelError> {
+        let chain_registry = match toml.chain_registry {
+            Some(ref c) => Some(ChainRegistryConfig::try_from(c)?),
+            None => None,
+        };
+        if let Some(ref c) = chain_registry {
+            // NOTE: Extra chains must be registered before any network IDs are parsed below.
+            c.register()?;
+        };
+
         let mut networks: HashMap<NetworkId, NetworkConfig> = HashMap::new();
         for (k, v) in toml.networks.iter() {
             let nid = NetworkId::try_from(k)?;
@@ -64,15 +105,88 @@ impl SentinelConfig {
             networks.insert(nid, config);
         }
 
+        sanity_check_frequency(*toml.core.user_op_cancellation_frequency())?;
+
         Ok(Self {
             networks,
             ipfs: toml.ipfs.clone(),
             core: toml.core.clone(),
             log: LogConfig::from_toml(&toml.log)?,
             governance: GovernanceConfig::try_from(&toml.governance)?,
+            challenger: match toml.challenger {
+                // NOTE: The challenger is opt-in, so a missing section means it's disabled.
+                Some(ref c) => ChallengerConfig::try_from(c)?,
+                None => ChallengerConfig::default(),
+            },
+            notifications: match toml.notifications {
+                // NOTE: Without a section there are no webhooks, though websocket subscribers still get events.
+                Some(ref n) => NotificationsConfig::try_from(n)?,
+                None => NotificationsConfig::default(),
+            },
+            balances: match toml.balances {
+                // NOTE: Balance monitoring is always on, a missing section just means using the defaults.
+                Some(ref b) => BalancesConfig::try_from(b)?,
+                None => BalancesConfig::default(),
+            },
+            attestation: match toml.attestation {
+                // NOTE: Verifying the core's attestation is opt-in, so a missing section means it's disabled.
+                Some(ref a) => AttestationConfig::try_from(a)?,
+                None => AttestationConfig::default(),
+            },
+            chain_registry,
         })
     }
 
+    /// Lists those differences between this & the passed in config which the running sentinel
+    /// cannot pick up, such as adding or removing a network. Everything else, eg endpoints, gas
+    /// prices & limits, batching, the canceller frequency & the balance monitoring, can be changed
+    /// whilst running.
+    pub fn get_changes_requiring_restart(&self, other: &Self) -> Vec<String> {
+        let mut changes = vec![];
+        let nids = self.networks.keys().collect::<HashSet<_>>();
+        if nids != other.networks.keys().collect::<HashSet<_>>() {
+            changes.push("networks".to_string());
+        } else {
+            nids.into_iter()
+                .filter(|nid| self.pnetwork_hubs(nid).ok() != other.pnetwork_hubs(nid).ok())
+                .for_each(|nid| changes.push(format!("{nid} pnetwork hubs")));
+        };
+        if self.core.differs_other_than_cancellation_frequency(&other.core) {
+            changes.push("core".to_string());
+        };
+        if self.log != other.log {
+            changes.push("log".to_string());
+        };
+        if self.ipfs != other.ipfs {
+            changes.push("ipfs".to_string());
+        };
+        if self.governance != other.governance {
+            changes.push("governance".to_string());
+        };
+        if self.challenger != other.challenger {
+            changes.push("challenger".to_string());
+        };
+        if self.notifications != other.notifications {
+            changes.push("notifications".to_string());
+        };
+        if self.attestation != other.attestation {
+            changes.push("attestation".to_string());
+        };
+        if self.chain_registry != other.chain_registry {
+            changes.push("chain registry".to_string());
+        };
+        changes
+    }
+
+    pub fn check_is_hot_reloadable(&self, other: &Self) -> Result<(), SentinelConfigError> {
+        let changes = self.get_changes_requiring_restart(other);
+        if changes.is_empty() {
+            Ok(())
+        } else {
+            Err(SentinelConfigError::RestartRequired(changes.join(", ")))
+        }
+    }
+
     pub fn log_level(&self) -> LogLevel {
         self.log.level()
     }
@@ -119,13 +233,33 @@ impl SentinelConfig {
         }
     }
 
-    pub fn pnetwork_hub(&self, nid: &NetworkId) -> Result<EthAddress, SentinelConfigError> {
+    pub fn pnetwork_hubs(&self, nid: &NetworkId) -> Result<PnetworkHubs, SentinelConfigError> {
         self.networks
             .get(nid)
-            .map(|c| *c.pnetwork_hub())
+            .map(|c| c.pnetwork_hubs().clone())
             .ok_or_else(|| SentinelConfigError::NoConfig(*nid))
     }
 
+    /// The hub on the given network to which any new txs should be sent.
+    pub fn pnetwork_hub(&self, nid: &NetworkId) -> Result<EthAddress, SentinelConfigError> {
+        self.pnetwork_hubs(nid)?
+            .current()
+            .ok_or(SentinelConfigError::NoPnetworkHub(*nid))
+    }
+
+    /// For routing txs pertaining to an event to the hub which emitted it, falling back to the
+    /// network's current hub if the event's hub isn't known, eg if it predates hub tracking.
+    pub fn pnetwork_hub_or_current(
+        &self,
+        nid: &NetworkId,
+        maybe_hub: Option<EthAddress>,
+    ) -> Result<EthAddress, SentinelConfigError> {
+        match maybe_hub {
+            Some(hub) => Ok(hub),
+            None => self.pnetwork_hub(nid),
+        }
+    }
+
     pub fn pre_filter_receipts(&self, nid: &NetworkId) -> Result<bool, SentinelConfigError> {
         self.networks
             .get(nid)
@@ -157,6 +291,8 @@ impl SentinelConfig {
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use super::*;
 
     #[test]
@@ -165,4 +301,71 @@ mod tests {
         let result = SentinelConfig::new(path);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn should_get_config_with_chain_registry_more_than_once() {
+        let path = "src/config/test_utils/sample-config-with-chain-registry";
+        let config = SentinelConfig::new(path).unwrap();
+        assert!(config.chain_registry().is_some());
+        assert!(NetworkId::try_from("base").is_ok());
+        let result = SentinelConfig::new(path).unwrap();
+        assert_eq!(result, config);
+    }
+
+    #[test]
+    fn config_template_should_be_valid() {
+        let path = "src/config/sentinel-config-template";
+        let config = SentinelConfig::new(path).unwrap();
+        assert_eq!(config.network_ids(), vec![NetworkId::try_from("polygon").unwrap()]);
+    }
+
+    #[test]
+    fn should_only_allow_hot_reloading_of_runtime_changeable_fields() {
+        let path = "src/config/test_utils/sample-config";
+        let config = SentinelConfig::new(path).unwrap();
+        let mut new_config = config.clone();
+        new_config.core.user_op_cancellation_frequency += 1;
+        assert!(config.check_is_hot_reloadable(&new_config).is_ok());
+
+        new_config.ipfs = IpfsConfig::new("/some/other/path".into(), 60).unwrap();
+        new_config.networks.remove(&NetworkId::try_from("polygon").unwrap());
+        let expected_changes = vec!["networks".to_string(), "ipfs".to_string()];
+        assert_eq!(config.get_changes_requiring_restart(&new_config), expected_changes);
+        assert!(matches!(
+            config.check_is_hot_reloadable(&new_config),
+            Err(SentinelConfigError::RestartRequired(_))
+        ));
+    }
+
+    #[test]
+    fn should_get_multiple_pnetwork_hubs_from_config() {
+        let path = "src/config/test_utils/sample-config";
+        let config = SentinelConfig::new(path).unwrap();
+        let nid = NetworkId::try_from("polygon").unwrap();
+        let hubs = config.pnetwork_hubs(&nid).unwrap();
+        let new_hub = EthAddress::from_str("0x578E916A4064c32F2eF44614Ff9B04B6D2546A13").unwrap();
+        let old_hub = EthAddress::from_str("0x6153ec976A5B3886caF3A88D8d994c4CEC24203E").unwrap();
+        assert_eq!(hubs.addresses(), vec![new_hub, old_hub]);
+        assert_eq!(hubs.active_at(1_000_000), vec![new_hub, old_hub]);
+        assert_eq!(hubs.active_at(1_000_001), vec![new_hub]);
+        assert_eq!(config.pnetwork_hub(&nid).unwrap(), new_hub);
+        let bsc = NetworkId::try_from("bsc").unwrap();
+        assert_eq!(config.pnetwork_hubs(&bsc).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn should_get_attestation_config() {
+        let path = "src/config/test_utils/sample-config";
+        let config = SentinelConfig::new(path).unwrap();
+        let attestation = config.attestation();
+        assert!(*attestation.enabled());
+        assert_eq!(attestation.roots().unwrap().len(), 1);
+        let policy = attestation.policy().unwrap();
+        assert!(*policy.require_strongbox());
+        assert_eq!(policy.package_name(), &Some("com.ptokens.sentinel".to_string()));
+        assert_eq!(policy.signature_digests().len(), 1);
+
+        let template = SentinelConfig::new("src/config/sentinel-config-template").unwrap();
+        assert!(!*template.attestation().enabled());
+    }
 }
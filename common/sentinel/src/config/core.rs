@@ -1,8 +1,28 @@
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
+// NOTE: Matches the frequency the user op canceller used before it was configurable.
+const DEFAULT_USER_OP_CANCELLATION_FREQUENCY: u64 = 120;
+
+fn default_user_op_cancellation_frequency() -> u64 {
+    DEFAULT_USER_OP_CANCELLATION_FREQUENCY
+}
+
 #[derive(Debug, Default, Clone, Getters, Deserialize, Eq, PartialEq, Serialize)]
 pub struct SentinelCoreConfig {
     timeout: u64,
     challenge_response_frequency: u64,
+    #[serde(default = "default_user_op_cancellation_frequency")]
+    user_op_cancellation_frequency: u64,
+    // NOTE: A debug signature over the core's `migrate_db_commitment` command, used once to start a
+    // commitment for a db from before they existed, which would otherwise be refused.
+    #[serde(default)]
+    db_commitment_migration_signature: Option<String>,
+}
+
+impl SentinelCoreConfig {
+    /// Whether the two configs differ in any field that cannot be changed whilst running.
+    pub(super) fn differs_other_than_cancellation_frequency(&self, other: &Self) -> bool {
+        self.timeout != other.timeout || self.challenge_response_frequency != other.challenge_response_frequency
+    }
 }
@@ -1,4 +1,5 @@
 use common_network_ids::NetworkId;
+use ethereum_types::Address as EthAddress;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -26,4 +27,21 @@ pub enum SentinelConfigError {
 
     #[error("no config for network id {0}")]
     NoConfig(NetworkId),
+
+    #[error("no pnetwork hub for network {0}")]
+    NoPnetworkHub(NetworkId),
+
+    #[error("pnetwork hub {address} on network {network_id} is active from block {from}, which is after its last active block {until}")]
+    PnetworkHubActiveRange {
+        network_id: NetworkId,
+        address: String,
+        from: u64,
+        until: u64,
+    },
+
+    #[error("chain registry signed by {recovered} instead of {expected}")]
+    ChainRegistrySigner { expected: EthAddress, recovered: EthAddress },
+
+    #[error("config changes require a restart: {0}")]
+    RestartRequired(String),
 }
@@ -1,3 +1,7 @@
+mod attestation;
+mod balances;
+mod chain_registry;
+mod challenger;
 mod config;
 mod core;
 mod error;
@@ -5,8 +9,13 @@ mod governance;
 mod ipfs;
 mod log;
 mod network;
+mod notifications;
 
 pub use self::{
+    attestation::{AttestationConfig, AttestationToml},
+    balances::{BalancesConfig, BalancesToml},
+    chain_registry::ChainRegistryConfig,
+    challenger::ChallengerConfig,
     config::SentinelConfig,
     core::SentinelCoreConfig,
     error::SentinelConfigError,
@@ -14,5 +23,13 @@ pub use self::{
     ipfs::IpfsConfig,
     log::LogConfig,
     network::NetworkConfig,
+    notifications::NotificationsConfig,
+};
+use self::{
+    chain_registry::ChainRegistryToml,
+    challenger::ChallengerToml,
+    governance::GovernanceToml,
+    log::LogToml,
+    network::NetworkToml,
+    notifications::NotificationsToml,
 };
-use self::{governance::GovernanceToml, log::LogToml, network::NetworkToml};
@@ -1,11 +1,17 @@
 use common_eth::convert_hex_to_eth_address;
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
-use ethereum_types::Address as EthAddress;
 use serde::{Deserialize, Serialize};
 
 use super::SentinelConfigError;
-use crate::{Endpoints, SentinelError};
+use crate::{Endpoints, PnetworkHub, PnetworkHubs, SentinelError};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PnetworkHubToml {
+    address: String,
+    active_from: Option<u64>,
+    active_until: Option<u64>,
+}
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct NetworkToml {
@@ -14,7 +20,9 @@ pub struct NetworkToml {
     gas_limit: usize,
     batch_duration: u64,
     sleep_duration: u64,
-    pnetwork_hub: String,
+    pnetwork_hub: Option<String>,
+    #[serde(default)]
+    pnetwork_hubs: Vec<PnetworkHubToml>,
     endpoints: Vec<String>,
     gas_price: Option<u64>,
     pre_filter_receipts: bool,
@@ -30,7 +38,7 @@ pub struct NetworkConfig {
     #[getter(skip)]
     endpoints: Endpoints,
     gas_price: Option<u64>,
-    pnetwork_hub: EthAddress,
+    pnetwork_hubs: PnetworkHubs,
     pre_filter_receipts: bool,
 }
 
@@ -46,7 +54,7 @@ impl NetworkConfig {
             gas_limit: toml.gas_limit,
             pre_filter_receipts: toml.pre_filter_receipts,
             batch_size: Self::sanity_check_batch_size(toml.batch_size)?,
-            pnetwork_hub: convert_hex_to_eth_address(&toml.pnetwork_hub)?,
+            pnetwork_hubs: Self::get_pnetwork_hubs(network_id, toml)?,
             batch_duration: Self::sanity_check_batch_duration(toml.batch_duration)?,
         })
     }
@@ -59,6 +67,41 @@ impl NetworkConfig {
         self.sleep_duration
     }
 
+    fn get_pnetwork_hubs(network_id: NetworkId, toml: &NetworkToml) -> Result<PnetworkHubs, SentinelError> {
+        // NOTE: A lone `pnetwork_hub` is active at all blocks, & may be used alongside a list of
+        // `pnetwork_hubs` with their own active ranges, eg whilst old & new hubs coexist.
+        let mut hubs = vec![];
+        if let Some(ref address) = toml.pnetwork_hub {
+            hubs.push(PnetworkHub::new(convert_hex_to_eth_address(address)?, None, None));
+        };
+        for hub in toml.pnetwork_hubs.iter() {
+            if let (Some(from), Some(until)) = (hub.active_from, hub.active_until) {
+                if from > until {
+                    return Err(SentinelError::SentinelConfig(
+                        SentinelConfigError::PnetworkHubActiveRange {
+                            network_id,
+                            address: hub.address.clone(),
+                            from,
+                            until,
+                        },
+                    ));
+                };
+            };
+            hubs.push(PnetworkHub::new(
+                convert_hex_to_eth_address(&hub.address)?,
+                hub.active_from,
+                hub.active_until,
+            ));
+        }
+        if hubs.is_empty() {
+            Err(SentinelError::SentinelConfig(SentinelConfigError::NoPnetworkHub(
+                network_id,
+            )))
+        } else {
+            Ok(PnetworkHubs::new(hubs))
+        }
+    }
+
     fn sanity_check_batch_size(batch_size: u64) -> Result<u64, SentinelError> {
         info!("sanity checking batch size...");
         const MIN: u64 = 0;
@@ -0,0 +1,54 @@
+use std::result::Result;
+
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{MAX_FREQUENCY, MIN_FREQUENCY},
+    SentinelError,
+};
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct NotificationsToml {
+    webhooks: Vec<String>,
+    max_attempts: u32,
+    retry_frequency: u64,
+    timeout: u64,
+}
+
+#[derive(Debug, Clone, Default, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NotificationsConfig {
+    webhooks: Vec<String>,
+    max_attempts: u32,
+    retry_frequency: u64,
+    timeout: u64,
+}
+
+impl NotificationsConfig {
+    pub fn has_webhooks(&self) -> bool {
+        !self.webhooks.is_empty()
+    }
+}
+
+impl TryFrom<&NotificationsToml> for NotificationsConfig {
+    type Error = SentinelError;
+
+    fn try_from(toml: &NotificationsToml) -> Result<Self, Self::Error> {
+        if !(MIN_FREQUENCY..=MAX_FREQUENCY).contains(&toml.retry_frequency) {
+            return Err(SentinelError::InvalidFrequency {
+                frequency: toml.retry_frequency,
+                min: MIN_FREQUENCY,
+                max: MAX_FREQUENCY,
+            });
+        };
+        if let Some(url) = toml.webhooks.iter().find(|u| !u.starts_with("http")) {
+            return Err(SentinelError::Custom(format!("invalid webhook url: '{url}'")));
+        };
+        Ok(Self {
+            timeout: toml.timeout,
+            webhooks: toml.webhooks.clone(),
+            max_attempts: toml.max_attempts,
+            retry_frequency: toml.retry_frequency,
+        })
+    }
+}
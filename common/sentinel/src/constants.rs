@@ -4,6 +4,7 @@ pub const MAX_FREQUENCY: u64 = 60 * 10;
 pub const DEFAULT_SLEEP_TIME: u64 = 15_000;
 pub const MAX_CHANNEL_CAPACITY: usize = 1337;
 pub const MILLISECONDS_MULTIPLIER: u64 = 1000;
+pub const CHALLENGE_RESPONSE_GAS_LIMIT: usize = 1_000_000;
 
 lazy_static! {
     pub static ref ACTOR_TYPE: crate::ActorType = crate::ActorType::Sentinel;
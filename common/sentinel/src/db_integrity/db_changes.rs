@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+use common::{types::DataSensitivity, Byte, Bytes};
+use derive_more::Deref;
+
+use super::DbCommitmentEntry;
+use crate::SentinelDbKeys;
+
+/// The puts & deletes made to the db during a transaction, with the values recorded only as
+/// hashes, to be folded into the db commitment when that transaction ends.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deref)]
+pub struct DbChanges(BTreeMap<Bytes, Option<DbCommitmentEntry>>);
+
+impl DbChanges {
+    fn is_commitment_key(key: &[Byte]) -> bool {
+        // NOTE: The commitment can't commit to itself, nor to its tree's nodes, which are stored
+        // under keys prefixed with its own.
+        key.starts_with(SentinelDbKeys::get_db_commitment_db_key().as_slice())
+    }
+
+    pub fn record_put(&mut self, key: &[Byte], value: &[Byte], sensitivity: DataSensitivity) {
+        if !Self::is_commitment_key(key) {
+            self.0
+                .insert(key.to_vec(), Some(DbCommitmentEntry::from_value(value, sensitivity)));
+        }
+    }
+
+    pub fn record_delete(&mut self, key: &[Byte]) {
+        if !Self::is_commitment_key(key) {
+            self.0.insert(key.to_vec(), None);
+        }
+    }
+}
+
+impl IntoIterator for DbChanges {
+    type IntoIter = std::collections::btree_map::IntoIter<Bytes, Option<DbCommitmentEntry>>;
+    type Item = (Bytes, Option<DbCommitmentEntry>);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_record_latest_change_per_key() {
+        let mut changes = DbChanges::default();
+        let key = vec![1, 3, 3, 7];
+        changes.record_put(&key, b"some value", None);
+        changes.record_delete(&key);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes.get(&key), Some(&None));
+        changes.record_put(&key, b"some other value", Some(255));
+        assert_eq!(
+            changes.get(&key),
+            Some(&Some(DbCommitmentEntry::from_value(b"some other value", Some(255))))
+        );
+    }
+
+    #[test]
+    fn should_not_record_changes_to_commitment_itself() {
+        let mut changes = DbChanges::default();
+        let key = SentinelDbKeys::get_db_commitment_db_key().to_vec();
+        changes.record_put(&key, b"some value", None);
+        changes.record_delete(&key);
+        let node_key = [key, vec![0, 1]].concat();
+        changes.record_put(&node_key, b"some node", None);
+        assert!(changes.is_empty());
+    }
+}
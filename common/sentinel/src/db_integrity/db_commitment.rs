@@ -0,0 +1,452 @@
+use std::str::FromStr;
+
+use common::{
+    crypto_utils::keccak_hash_bytes,
+    types::DataSensitivity,
+    Byte,
+    DatabaseInterface,
+    MIN_DATA_SENSITIVITY_LEVEL,
+};
+use common_eth::{ChainDbUtils, EthPrivateKey, EthSignature, EthSigningCapabilities};
+use derive_getters::Getters;
+use derive_more::Constructor;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use serde::{Deserialize, Serialize};
+
+use super::{db_commitment_tree::DbCommitmentTree, DbChanges, DbIntegrity, DbIntegrityError, DbIntegrityProof};
+use crate::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils, SentinelError};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters, Constructor)]
+pub struct DbCommitmentEntry {
+    value_hash: EthHash,
+    // NOTE: Required in order to read the value back out of the db when checking it.
+    sensitivity: DataSensitivity,
+}
+
+impl DbCommitmentEntry {
+    pub fn from_value(value: &[Byte], sensitivity: DataSensitivity) -> Self {
+        Self::new(keccak_hash_bytes(value), sensitivity)
+    }
+
+    pub(super) fn to_leaf(&self, key: &[Byte]) -> EthHash {
+        // NOTE: The prefix keeps leaves distinct from branches, which are prefixed with a one.
+        keccak_hash_bytes(&[&[0u8][..], key, self.value_hash.as_bytes()].concat())
+    }
+}
+
+const DB_COMMITMENT_MIGRATION_MARKER: &[Byte] = b"migrated";
+
+pub(super) fn get_eth_prefixed_hash(root: &EthHash) -> EthHash {
+    // NOTE: The root is signed with the eth message prefix, same as the core's other signatures.
+    let prefix: &[u8] = b"\x19Ethereum Signed Message:\n";
+    let msg = root.as_bytes();
+    keccak_hash_bytes(&[prefix, format!("{}", msg.len()).as_bytes(), msg].concat())
+}
+
+pub(super) fn recover_signer(root: &EthHash, signature: &str) -> Result<EthAddress, DbIntegrityError> {
+    EthSignature::from_str(signature)
+        .and_then(|sig| sig.recover_signer_address(&get_eth_prefixed_hash(root)))
+        .map_err(|e| DbIntegrityError::VerificationError(format!("{e}")))
+}
+
+/// A merkle commitment over every key & value the core has written to its db, signed by the core's
+/// key so that any edit made to the db outside of the core can be detected. Only the signed root is
+/// kept here, the tree's nodes are stored individually so that it can be updated incrementally.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct DbCommitment {
+    root: EthHash,
+    num_entries: usize,
+    signer: Option<EthAddress>,
+    signature: Option<String>,
+}
+
+impl DbUtilsT for DbCommitment {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_db_commitment_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl DbCommitment {
+    pub fn len(&self) -> usize {
+        self.num_entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_entries == 0
+    }
+
+    fn tree<'a, D: DatabaseInterface>(&self, db: &'a D) -> DbCommitmentTree<'a, D> {
+        DbCommitmentTree::new(db, self.root, self.num_entries)
+    }
+
+    fn sign(&mut self, pk: &EthPrivateKey) -> Result<(), SentinelError> {
+        let sig = pk.hash_and_sign_msg_with_eth_prefix(self.root.as_bytes())?;
+        self.signer = Some(pk.to_address());
+        self.signature = Some(format!("0x{sig}"));
+        Ok(())
+    }
+
+    /// Checks that this commitment's root is signed by the core's key if it has one.
+    fn check_signature(&self, pk: Option<&EthPrivateKey>) -> DbIntegrity {
+        match (&self.signature, pk) {
+            // NOTE: Until the core is initialized it has no key to sign with.
+            (None, None) => DbIntegrity::FirstRun,
+            (None, Some(_)) => DbIntegrity::NoSignature,
+            (Some(_), None) => DbIntegrity::Unverifiable,
+            (Some(sig), Some(pk)) => match recover_signer(&self.root, sig) {
+                Ok(signer) if signer == pk.to_address() && self.signer == Some(signer) => DbIntegrity::Valid,
+                Ok(signer) => {
+                    warn!(
+                        "db commitment signed by {signer} but core's address is {}",
+                        pk.to_address()
+                    );
+                    DbIntegrity::Invalid
+                },
+                Err(e) => {
+                    warn!("{e}");
+                    DbIntegrity::Invalid
+                },
+            },
+        }
+    }
+
+    fn get<D: DatabaseInterface>(db_utils: &SentinelDbUtils<D>) -> Result<Option<Self>, SentinelError> {
+        let key = SentinelDbKeys::get_db_commitment_db_key();
+        if db_utils.key_exists::<Self>(&key) {
+            // NOTE: A commitment which exists but can't be parsed has been tampered with.
+            Self::get_from_db(db_utils, &key)
+                .map(Some)
+                .map_err(|_| SentinelError::InvalidDbIntegrity(DbIntegrity::Invalid))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Folds the given changes into the commitment in the db & re-signs it. The existing
+    /// commitment is checked first, so that one which has been tampered with is never re-signed.
+    pub fn update<D: DatabaseInterface>(db: &D, changes: DbChanges) -> Result<(), SentinelError> {
+        if changes.is_empty() {
+            return Ok(());
+        };
+
+        let db_utils = SentinelDbUtils::new(db);
+        let chain_db_utils = ChainDbUtils::new(db);
+        let pk = chain_db_utils.get_pk().ok();
+        // NOTE: The only time an unsigned commitment may be signed, or a missing one started once
+        // the core has a key, is when that key is created during the core's initialization.
+        let pk_is_new = changes.contains_key(&chain_db_utils.pk_db_key());
+        let mut commitment = match Self::get(&db_utils)? {
+            Some(c) => {
+                let integrity = c.check_signature(pk.as_ref());
+                let first_signing = integrity == DbIntegrity::NoSignature && pk_is_new;
+                if !(integrity.is_valid() || first_signing) {
+                    return Err(SentinelError::InvalidDbIntegrity(integrity));
+                };
+                c
+            },
+            None if pk.is_none() || pk_is_new => Self::default(),
+            None => {
+                warn!("core has a key but no db commitment - a db from before commitments must be migrated");
+                return Err(SentinelError::InvalidDbIntegrity(DbIntegrity::Invalid));
+            },
+        };
+
+        let mut tree = commitment.tree(db);
+        for (key, maybe_entry) in changes {
+            match maybe_entry {
+                Some(entry) => tree.insert(&key, entry)?,
+                None => tree.remove(&key)?,
+            };
+        }
+        commitment.root = tree.root();
+        commitment.num_entries = tree.num_entries();
+
+        if let Some(ref pk) = pk {
+            commitment.sign(pk)?;
+        };
+        commitment.update_in_db(&db_utils)?;
+        debug!("db commitment with {} entries written", commitment.len());
+        Ok(())
+    }
+
+    /// Starts a fresh, signed commitment for a db from before commitments existed. An existing
+    /// commitment is never replaced, since that would re-baseline over whatever edits made it
+    /// invalid.
+    ///
+    /// ### NOTE:
+    ///
+    /// Entries written before the migration are NOT protected, only those (re)written since. So
+    /// that this is never mistaken for a full commitment, a migration marker is committed to
+    /// alongside them, & the db is checked as `DbIntegrity::ValidSinceMigration` rather than
+    /// `DbIntegrity::Valid` from then on.
+    pub fn migrate<D: DatabaseInterface>(db: &D) -> Result<Self, SentinelError> {
+        let db_utils = SentinelDbUtils::new(db);
+        if Self::get(&db_utils)?.is_some() {
+            let msg = "db already has a commitment, cannot migrate it";
+            return Err(SentinelError::Custom(msg.into()));
+        };
+        let mut commitment = Self::default();
+        let key = SentinelDbKeys::get_db_commitment_migration_db_key();
+        db.put(
+            key.to_vec(),
+            DB_COMMITMENT_MIGRATION_MARKER.to_vec(),
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )?;
+        let mut tree = commitment.tree(db);
+        tree.insert(
+            &key,
+            DbCommitmentEntry::from_value(DB_COMMITMENT_MIGRATION_MARKER, MIN_DATA_SENSITIVITY_LEVEL),
+        )?;
+        commitment.root = tree.root();
+        commitment.num_entries = tree.num_entries();
+        commitment.sign(&ChainDbUtils::new(db).get_pk()?)?;
+        commitment.update_in_db(&db_utils)?;
+        warn!("fresh db commitment started via migration");
+        Ok(commitment)
+    }
+
+    /// Checks the commitment in the db, & that every value it commits to is unchanged.
+    pub fn check<D: DatabaseInterface>(db: &D) -> Result<DbIntegrity, SentinelError> {
+        let db_utils = SentinelDbUtils::new(db);
+        let pk = ChainDbUtils::new(db).get_pk().ok();
+        let commitment = match Self::get(&db_utils) {
+            Ok(Some(c)) => c,
+            Ok(None) if pk.is_some() => {
+                // NOTE: Otherwise deleting the commitment would hide any other edit made to the db.
+                warn!("core has a key but no db commitment - a db from before commitments must be migrated");
+                return Ok(DbIntegrity::Invalid);
+            },
+            Ok(None) => {
+                // NOTE: The core is not yet initialized. One is created on the next write.
+                warn!("no db commitment found");
+                return Ok(DbIntegrity::NoHash);
+            },
+            Err(SentinelError::InvalidDbIntegrity(integrity)) => return Ok(integrity),
+            Err(e) => return Err(e),
+        };
+
+        let integrity = commitment.check_signature(pk.as_ref());
+        if !integrity.is_valid() {
+            return Ok(integrity);
+        };
+
+        let tree = commitment.tree(db);
+        let is_migrated = tree
+            .get_proof(&SentinelDbKeys::get_db_commitment_migration_db_key())
+            .is_ok();
+        match tree.check_values() {
+            Ok(true) if is_migrated => {
+                warn!("db commitment was migrated - entries not written since are not protected by it");
+                Ok(DbIntegrity::ValidSinceMigration)
+            },
+            Ok(true) => Ok(integrity),
+            Ok(false) => Ok(DbIntegrity::Invalid),
+            Err(SentinelError::InvalidDbIntegrity(integrity)) => Ok(integrity),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn get_proof<D: DatabaseInterface>(&self, db: &D, key: &[Byte]) -> Result<DbIntegrityProof, SentinelError> {
+        let (entry, siblings) = self.tree(db).get_proof(key)?;
+        Ok(DbIntegrityProof::new(
+            key.to_vec(),
+            entry,
+            siblings.iter().map(|h| h.as_bytes().to_vec()).collect(),
+            self.root,
+            self.num_entries,
+            self.signer,
+            self.signature.clone(),
+        ))
+    }
+
+    pub fn get_proof_from_db<D: DatabaseInterface>(db: &D, key: &[Byte]) -> Result<DbIntegrityProof, SentinelError> {
+        match Self::get(&SentinelDbUtils::new(db))? {
+            Some(commitment) => commitment.get_proof(db, key),
+            None => Err(SentinelError::InvalidDbIntegrity(DbIntegrity::NoHash)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+
+    use super::*;
+    use crate::db_integrity::db_commitment_tree::{get_node_key, get_path, DbCommitmentNode};
+
+    fn get_sample_pk() -> EthPrivateKey {
+        EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap()
+    }
+
+    fn put_and_record<D: DatabaseInterface>(db: &D, changes: &mut DbChanges, k: &[Byte], v: &[Byte]) {
+        db.put(k.to_vec(), v.to_vec(), None).unwrap();
+        changes.record_put(k, v, None);
+    }
+
+    fn get_sample_initialized_db<D: DatabaseInterface>(db: &D) {
+        let mut changes = DbChanges::default();
+        put_and_record(db, &mut changes, &[1], b"one");
+        put_and_record(db, &mut changes, &[2], b"two");
+        put_and_record(db, &mut changes, &[3], b"three");
+        let chain_db_utils = ChainDbUtils::new(db);
+        chain_db_utils.put_pk(&get_sample_pk()).unwrap();
+        let pk_bytes = db.get(chain_db_utils.pk_db_key(), None).unwrap();
+        changes.record_put(&chain_db_utils.pk_db_key(), &pk_bytes, None);
+        DbCommitment::update(db, changes).unwrap();
+    }
+
+    fn get_commitment<D: DatabaseInterface>(db: &D) -> DbCommitment {
+        DbCommitment::get(&SentinelDbUtils::new(db)).unwrap().unwrap()
+    }
+
+    fn put_node<D: DatabaseInterface>(db: &D, depth: usize, key: &[Byte], node: &DbCommitmentNode) {
+        db.put(
+            get_node_key(depth, &get_path(key)),
+            serde_json::to_vec(node).unwrap(),
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn should_check_untampered_db_as_valid() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::Valid);
+
+        let mut changes = DbChanges::default();
+        put_and_record(&db, &mut changes, &[2], b"new two");
+        db.delete(vec![3]).unwrap();
+        changes.record_delete(&[3]);
+        DbCommitment::update(&db, changes).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::Valid);
+        assert_eq!(get_commitment(&db).len(), 3);
+    }
+
+    #[test]
+    fn should_check_db_with_out_of_band_edit_as_invalid() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        db.put(vec![2], b"edited".to_vec(), None).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::Invalid);
+    }
+
+    #[test]
+    fn should_check_db_with_out_of_band_delete_as_invalid() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        db.delete(vec![1]).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::Invalid);
+    }
+
+    #[test]
+    fn should_check_db_with_tampered_commitment_as_invalid() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        db.put(vec![2], b"edited".to_vec(), None).unwrap();
+        let depth = get_commitment(&db).get_proof(&db, &[2]).unwrap().proof().len();
+        let tampered = DbCommitmentNode::Leaf {
+            key: vec![2],
+            entry: DbCommitmentEntry::from_value(b"edited", None),
+        };
+        put_node(&db, depth, &[2], &tampered);
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::Invalid);
+    }
+
+    #[test]
+    fn should_not_re_sign_tampered_commitment() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        let tampered = DbCommitmentNode::Leaf {
+            key: vec![1],
+            entry: DbCommitmentEntry::from_value(b"one", None),
+        };
+        put_node(&db, 0, &[1], &tampered);
+        let mut changes = DbChanges::default();
+        put_and_record(&db, &mut changes, &[4], b"four");
+        match DbCommitment::update(&db, changes) {
+            Err(SentinelError::InvalidDbIntegrity(DbIntegrity::Invalid)) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_treat_missing_commitment_as_invalid_once_core_has_key() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        db.put(vec![2], b"edited".to_vec(), None).unwrap();
+        db.delete(SentinelDbKeys::get_db_commitment_db_key().to_vec()).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::Invalid);
+        let mut changes = DbChanges::default();
+        put_and_record(&db, &mut changes, &[4], b"four");
+        match DbCommitment::update(&db, changes.clone()) {
+            Err(SentinelError::InvalidDbIntegrity(DbIntegrity::Invalid)) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+
+        DbCommitment::migrate(&db).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::ValidSinceMigration);
+        DbCommitment::update(&db, changes).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::ValidSinceMigration);
+        // NOTE: The migration marker & the one entry written since.
+        assert_eq!(get_commitment(&db).len(), 2);
+    }
+
+    #[test]
+    fn should_check_migrated_db_with_migration_marker_deleted_as_invalid() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        db.delete(SentinelDbKeys::get_db_commitment_db_key().to_vec()).unwrap();
+        DbCommitment::migrate(&db).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::ValidSinceMigration);
+        db.delete(SentinelDbKeys::get_db_commitment_migration_db_key().to_vec())
+            .unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::Invalid);
+    }
+
+    #[test]
+    fn should_not_migrate_db_with_commitment() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        assert!(DbCommitment::migrate(&db).is_err());
+    }
+
+    #[test]
+    fn should_check_db_before_initialization_as_first_run() {
+        let db = get_test_database();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::NoHash);
+        let mut changes = DbChanges::default();
+        put_and_record(&db, &mut changes, &[1], b"one");
+        DbCommitment::update(&db, changes).unwrap();
+        assert_eq!(DbCommitment::check(&db).unwrap(), DbIntegrity::FirstRun);
+    }
+
+    #[test]
+    fn should_get_and_verify_db_integrity_proofs() {
+        let db = get_test_database();
+        get_sample_initialized_db(&db);
+        let commitment = get_commitment(&db);
+        for k in [[1u8], [2], [3]] {
+            let proof = commitment.get_proof(&db, &k).unwrap();
+            assert_eq!(proof.root(), commitment.root());
+            assert_eq!(proof.verify(Some(b"one".as_slice())).unwrap(), k == [1]);
+            assert!(proof.verify(None).unwrap());
+            assert_eq!(proof.signer_address().unwrap(), get_sample_pk().to_address());
+        }
+        match commitment.get_proof(&db, &[4]) {
+            Err(SentinelError::DbIntegrity(DbIntegrityError::NoEntry(k))) => assert_eq!(k, "04"),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use common::{crypto_utils::keccak_hash_bytes, Byte, Bytes, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use super::{DbCommitmentEntry, DbIntegrity, DbIntegrityError};
+use crate::{SentinelDbKeys, SentinelError};
+
+// NOTE: Paths are keccak hashes of db keys, so no two leaves can share a path this long.
+const MAX_DEPTH: usize = 256;
+
+pub(super) fn get_path(key: &[Byte]) -> EthHash {
+    keccak_hash_bytes(key)
+}
+
+pub(super) fn get_bit(path: &EthHash, depth: usize) -> bool {
+    (path.as_bytes()[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+fn set_bit(path: &EthHash, depth: usize, bit: bool) -> EthHash {
+    let mut p = *path;
+    let mask = 1 << (7 - depth % 8);
+    if bit {
+        p.0[depth / 8] |= mask
+    } else {
+        p.0[depth / 8] &= !mask
+    };
+    p
+}
+
+fn get_prefix(path: &EthHash, depth: usize) -> EthHash {
+    let mut prefix = *path;
+    for (i, byte) in prefix.0.iter_mut().enumerate() {
+        let num_bits_kept = depth.saturating_sub(i * 8).min(8);
+        *byte &= 0xffu16.checked_shl(8 - num_bits_kept as u32).unwrap_or(0) as u8;
+    }
+    prefix
+}
+
+/// Nodes are stored under the commitment's own key, extended w/ their position in the tree.
+pub(super) fn get_node_key(depth: usize, path: &EthHash) -> Bytes {
+    [
+        SentinelDbKeys::get_db_commitment_db_key().as_slice(),
+        &(depth as u16).to_be_bytes(),
+        get_prefix(path, depth).as_bytes(),
+    ]
+    .concat()
+}
+
+pub(super) fn hash_branch(left: &EthHash, right: &EthHash) -> EthHash {
+    keccak_hash_bytes(&[&[1u8][..], left.as_bytes(), right.as_bytes()].concat())
+}
+
+/// A node in the commitment's sparse merkle tree. Each leaf sits at the shallowest depth along its
+/// path at which it is alone in its subtree, so the tree's shape depends only on the keys in it.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub(super) enum DbCommitmentNode {
+    Leaf {
+        #[serde_as(as = "serde_with::hex::Hex")]
+        key: Bytes,
+        entry: DbCommitmentEntry,
+    },
+    Branch {
+        left: EthHash,
+        right: EthHash,
+    },
+}
+
+impl DbCommitmentNode {
+    fn branch(bit: bool, child: EthHash, sibling: EthHash) -> Self {
+        if bit {
+            Self::Branch {
+                left: sibling,
+                right: child,
+            }
+        } else {
+            Self::Branch {
+                left: child,
+                right: sibling,
+            }
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        matches!(self, Self::Leaf { .. })
+    }
+
+    fn leaf_key(&self) -> Option<&[Byte]> {
+        match self {
+            Self::Leaf { key, .. } => Some(key),
+            Self::Branch { .. } => None,
+        }
+    }
+
+    fn hash(&self) -> EthHash {
+        match self {
+            Self::Leaf { key, entry } => entry.to_leaf(key),
+            Self::Branch { left, right } => hash_branch(left, right),
+        }
+    }
+
+    fn hash_or_zero(node: &Option<Self>) -> EthHash {
+        node.as_ref().map(Self::hash).unwrap_or_default()
+    }
+}
+
+/// The commitment's tree, whose nodes are each stored in the db under their position, so that
+/// changing an entry only touches the nodes along its path. Every node read is checked against
+/// the hash its parent holds for it, starting from the signed root, so a node edited outside of
+/// the core is never built upon.
+pub(super) struct DbCommitmentTree<'a, D: DatabaseInterface> {
+    db: &'a D,
+    root: EthHash,
+    num_entries: usize,
+}
+
+impl<'a, D: DatabaseInterface> DbCommitmentTree<'a, D> {
+    pub fn new(db: &'a D, root: EthHash, num_entries: usize) -> Self {
+        Self { db, root, num_entries }
+    }
+
+    pub fn root(&self) -> EthHash {
+        self.root
+    }
+
+    pub fn num_entries(&self) -> usize {
+        self.num_entries
+    }
+
+    fn get_node(
+        &self,
+        depth: usize,
+        path: &EthHash,
+        expected: &EthHash,
+    ) -> Result<Option<DbCommitmentNode>, SentinelError> {
+        if expected.is_zero() {
+            return Ok(None);
+        };
+        // NOTE: A node that's missing, unparseable or doesn't hash to what its parent expects has
+        // been tampered with.
+        let node = self
+            .db
+            .get(get_node_key(depth, path), MIN_DATA_SENSITIVITY_LEVEL)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<DbCommitmentNode>(&bytes).ok())
+            .filter(|node| node.hash() == *expected);
+        match node {
+            Some(node) => Ok(Some(node)),
+            None => {
+                warn!("db commitment node at depth {depth} on path {path:?} does not match its parent");
+                Err(SentinelError::InvalidDbIntegrity(DbIntegrity::Invalid))
+            },
+        }
+    }
+
+    fn put_node(&self, depth: usize, path: &EthHash, node: &DbCommitmentNode) -> Result<(), SentinelError> {
+        Ok(self.db.put(
+            get_node_key(depth, path),
+            serde_json::to_vec(node)?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )?)
+    }
+
+    fn delete_node(&self, depth: usize, path: &EthHash) -> Result<(), SentinelError> {
+        Ok(self.db.delete(get_node_key(depth, path))?)
+    }
+
+    pub fn insert(&mut self, key: &[Byte], entry: DbCommitmentEntry) -> Result<(), SentinelError> {
+        let leaf = DbCommitmentNode::Leaf {
+            key: key.to_vec(),
+            entry,
+        };
+        let root = self.root;
+        self.root = self.insert_at(0, &get_path(key), &root, leaf)?;
+        Ok(())
+    }
+
+    fn insert_at(
+        &mut self,
+        depth: usize,
+        path: &EthHash,
+        expected: &EthHash,
+        leaf: DbCommitmentNode,
+    ) -> Result<EthHash, SentinelError> {
+        if depth >= MAX_DEPTH {
+            let msg = format!("db commitment tree exceeded max depth on path {path:?}");
+            return Err(SentinelError::Custom(msg));
+        };
+
+        let (left, right) = match self.get_node(depth, path, expected)? {
+            None => {
+                self.put_node(depth, path, &leaf)?;
+                self.num_entries += 1;
+                return Ok(leaf.hash());
+            },
+            Some(DbCommitmentNode::Branch { left, right }) => (left, right),
+            Some(existing) if existing.leaf_key() == leaf.leaf_key() => {
+                self.put_node(depth, path, &leaf)?;
+                return Ok(leaf.hash());
+            },
+            Some(existing) => {
+                // NOTE: The existing leaf is no longer alone in this subtree, so it moves down a
+                // level beneath a new branch, & the new leaf is inserted beneath that.
+                let existing_path = get_path(existing.leaf_key().unwrap_or_default());
+                self.put_node(depth + 1, &existing_path, &existing)?;
+                if get_bit(&existing_path, depth) {
+                    (EthHash::zero(), existing.hash())
+                } else {
+                    (existing.hash(), EthHash::zero())
+                }
+            },
+        };
+
+        let bit = get_bit(path, depth);
+        let (child, sibling) = if bit { (right, left) } else { (left, right) };
+        let new_child = self.insert_at(depth + 1, path, &child, leaf)?;
+        let branch = DbCommitmentNode::branch(bit, new_child, sibling);
+        self.put_node(depth, path, &branch)?;
+        Ok(branch.hash())
+    }
+
+    pub fn remove(&mut self, key: &[Byte]) -> Result<(), SentinelError> {
+        if let Some(node) = self.remove_at(0, &get_path(key), &self.root, key)? {
+            self.root = DbCommitmentNode::hash_or_zero(&node);
+            self.num_entries -= 1;
+        };
+        Ok(())
+    }
+
+    /// Returns `None` if the key is not in this subtree, else the node now at this position.
+    fn remove_at(
+        &self,
+        depth: usize,
+        path: &EthHash,
+        expected: &EthHash,
+        key: &[Byte],
+    ) -> Result<Option<Option<DbCommitmentNode>>, SentinelError> {
+        let (left, right) = match self.get_node(depth, path, expected)? {
+            None => return Ok(None),
+            Some(DbCommitmentNode::Leaf { key: ref k, .. }) if k == key => {
+                self.delete_node(depth, path)?;
+                return Ok(Some(None));
+            },
+            Some(DbCommitmentNode::Leaf { .. }) => return Ok(None),
+            Some(DbCommitmentNode::Branch { left, right }) => (left, right),
+        };
+
+        let bit = get_bit(path, depth);
+        let (child, sibling_hash) = if bit { (right, left) } else { (left, right) };
+        let new_child = match self.remove_at(depth + 1, path, &child, key)? {
+            Some(node) => node,
+            None => return Ok(None),
+        };
+
+        // NOTE: A leaf left alone in this subtree moves up to take this branch's place.
+        let sibling_path = set_bit(path, depth, !bit);
+        let new_node = match new_child {
+            Some(node) if !node.is_leaf() => Some(DbCommitmentNode::branch(bit, node.hash(), sibling_hash)),
+            new_child => match (new_child, self.get_node(depth + 1, &sibling_path, &sibling_hash)?) {
+                (None, None) => None,
+                (None, Some(sibling)) if sibling.is_leaf() => {
+                    self.delete_node(depth + 1, &sibling_path)?;
+                    Some(sibling)
+                },
+                (Some(leaf), None) => {
+                    self.delete_node(depth + 1, path)?;
+                    Some(leaf)
+                },
+                (new_child, _) => Some(DbCommitmentNode::branch(
+                    bit,
+                    DbCommitmentNode::hash_or_zero(&new_child),
+                    sibling_hash,
+                )),
+            },
+        };
+
+        match new_node {
+            Some(ref node) => self.put_node(depth, path, node)?,
+            None => self.delete_node(depth, path)?,
+        };
+        Ok(Some(new_node))
+    }
+
+    /// Returns the entry for the key along with the sibling hashes on its path, from the leaf up.
+    pub fn get_proof(&self, key: &[Byte]) -> Result<(DbCommitmentEntry, Vec<EthHash>), SentinelError> {
+        let path = get_path(key);
+        let mut expected = self.root;
+        let mut siblings = vec![];
+        for depth in 0..MAX_DEPTH {
+            match self.get_node(depth, &path, &expected)? {
+                Some(DbCommitmentNode::Leaf { key: ref k, entry }) if k == key => {
+                    siblings.reverse();
+                    return Ok((entry, siblings));
+                },
+                Some(DbCommitmentNode::Branch { left, right }) => {
+                    let bit = get_bit(&path, depth);
+                    let (child, sibling) = if bit { (right, left) } else { (left, right) };
+                    siblings.push(sibling);
+                    expected = child;
+                },
+                _ => break,
+            }
+        }
+        Err(DbIntegrityError::NoEntry(hex::encode(key)).into())
+    }
+
+    /// Walks the whole tree, checking that every value it commits to is unchanged in the db.
+    pub fn check_values(&self) -> Result<bool, SentinelError> {
+        self.check_values_at(0, &EthHash::zero(), &self.root)
+    }
+
+    fn check_values_at(&self, depth: usize, path: &EthHash, expected: &EthHash) -> Result<bool, SentinelError> {
+        match self.get_node(depth, path, expected)? {
+            None => Ok(true),
+            Some(DbCommitmentNode::Leaf { key, entry }) => match self.db.get(key.clone(), *entry.sensitivity()) {
+                Ok(ref v) if keccak_hash_bytes(v) == *entry.value_hash() => Ok(true),
+                Ok(_) => {
+                    warn!("value for db key 0x{} does not match its commitment", hex::encode(key));
+                    Ok(false)
+                },
+                Err(_) => {
+                    warn!("value for db key 0x{} is missing from the db", hex::encode(key));
+                    Ok(false)
+                },
+            },
+            Some(DbCommitmentNode::Branch { left, right }) => {
+                let left_is_valid = self.check_values_at(depth + 1, &set_bit(path, depth, false), &left)?;
+                Ok(left_is_valid && self.check_values_at(depth + 1, &set_bit(path, depth, true), &right)?)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+
+    use super::*;
+
+    fn get_sample_entries() -> Vec<(Bytes, DbCommitmentEntry)> {
+        (0..50u8)
+            .map(|i| (vec![i], DbCommitmentEntry::from_value(&[i, i], None)))
+            .collect()
+    }
+
+    #[test]
+    fn should_get_prefix_of_path() {
+        let path = EthHash::repeat_byte(0xff);
+        assert_eq!(get_prefix(&path, 0), EthHash::zero());
+        assert_eq!(get_prefix(&path, 256), path);
+        let prefix = get_prefix(&path, 12);
+        assert_eq!(&prefix.as_bytes()[..3], &[0xff, 0xf0, 0x00]);
+    }
+
+    #[test]
+    fn should_insert_and_remove_entries_independent_of_order() {
+        let db = get_test_database();
+        let entries = get_sample_entries();
+        let mut tree = DbCommitmentTree::new(&db, EthHash::zero(), 0);
+        entries.iter().try_for_each(|(k, e)| tree.insert(k, e.clone())).unwrap();
+        assert_eq!(tree.num_entries(), entries.len());
+        entries.iter().step_by(2).try_for_each(|(k, _)| tree.remove(k)).unwrap();
+
+        let other_db = get_test_database();
+        let mut other_tree = DbCommitmentTree::new(&other_db, EthHash::zero(), 0);
+        entries
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .rev()
+            .try_for_each(|(k, e)| other_tree.insert(k, e.clone()))
+            .unwrap();
+        assert_eq!(tree.root(), other_tree.root());
+        assert_eq!(tree.num_entries(), other_tree.num_entries());
+
+        entries.iter().try_for_each(|(k, _)| tree.remove(k)).unwrap();
+        assert_eq!(tree.root(), EthHash::zero());
+        assert_eq!(tree.num_entries(), 0);
+    }
+
+    #[test]
+    fn should_replace_existing_entry() {
+        let db = get_test_database();
+        let mut tree = DbCommitmentTree::new(&db, EthHash::zero(), 0);
+        tree.insert(&[1], DbCommitmentEntry::from_value(b"one", None)).unwrap();
+        tree.insert(&[2], DbCommitmentEntry::from_value(b"two", None)).unwrap();
+        let root = tree.root();
+        tree.insert(&[2], DbCommitmentEntry::from_value(b"new two", None))
+            .unwrap();
+        assert_ne!(tree.root(), root);
+        assert_eq!(tree.num_entries(), 2);
+    }
+
+    #[test]
+    fn should_fail_to_build_on_tampered_node() {
+        let db = get_test_database();
+        let mut tree = DbCommitmentTree::new(&db, EthHash::zero(), 0);
+        get_sample_entries()
+            .into_iter()
+            .try_for_each(|(k, e)| tree.insert(&k, e))
+            .unwrap();
+        let tampered = DbCommitmentNode::Branch {
+            left: EthHash::zero(),
+            right: EthHash::zero(),
+        };
+        db.put(
+            get_node_key(0, &EthHash::zero()),
+            serde_json::to_vec(&tampered).unwrap(),
+            None,
+        )
+        .unwrap();
+        match tree.insert(&[100], DbCommitmentEntry::from_value(b"hundred", None)) {
+            Err(SentinelError::InvalidDbIntegrity(DbIntegrity::Invalid)) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
+
+use super::DbIntegrityError;
+use crate::{SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DbIntegrity {
+    Valid               = 0,
+    Invalid             = 1,
+    NoHash              = 2,
+    NoState             = 3,
+    FirstRun            = 4,
+    Unverifiable        = 5,
+    HashWritten         = 6,
+    NoSignature         = 7,
+    // NOTE: Valid, but only for what's been written since a db from before commitments was migrated.
+    ValidSinceMigration = 8,
+}
+
+impl DbIntegrity {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid | Self::FirstRun | Self::ValidSinceMigration)
+    }
+}
+
+impl Default for DbIntegrity {
+    fn default() -> Self {
+        Self::Invalid
+    }
+}
+
+impl fmt::Display for DbIntegrity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Valid => "Valid",
+            Self::NoHash => "NoHash",
+            Self::NoState => "NoState",
+            Self::Invalid => "Invalid",
+            Self::FirstRun => "FirstRun",
+            Self::NoSignature => "NoSignature",
+            Self::HashWritten => "HashWritten",
+            Self::Unverifiable => "Unverifiable",
+            Self::ValidSinceMigration => "ValidSinceMigration",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for DbIntegrity {
+    type Err = DbIntegrityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "valid" => Ok(Self::Valid),
+            "invalid" => Ok(Self::Invalid),
+            "nohash" | "no_hash" => Ok(Self::NoHash),
+            "unverifiable" => Ok(Self::Unverifiable),
+            "nostate" | "no_state" => Ok(Self::NoState),
+            "firstrun" | "first_run" => Ok(Self::FirstRun),
+            "hashwritten" | "hash_written" => Ok(Self::HashWritten),
+            "nosignature" | "no_signature" => Ok(Self::NoSignature),
+            "validsincemigration" | "valid_since_migration" => Ok(Self::ValidSinceMigration),
+            other => Err(DbIntegrityError::FromStr(other.into())),
+        }
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for DbIntegrity {
+    type Error = SentinelError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        let s = match m {
+            WebSocketMessagesEncodable::Success(ref json) => {
+                json.get("dbIntegrity").and_then(Json::as_str).map(String::from)
+            },
+            _ => None,
+        }
+        .ok_or_else(|| WebSocketMessagesError::CannotConvert {
+            from: m.to_string(),
+            to: "DbIntegrity".to_string(),
+        })?;
+        Ok(Self::from_str(&s)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_get_db_integrity_from_web_socket_message() {
+        let m = WebSocketMessagesEncodable::Success(json!({ "dbIntegrity": DbIntegrity::HashWritten }));
+        assert_eq!(DbIntegrity::try_from(m).unwrap(), DbIntegrity::HashWritten);
+    }
+}
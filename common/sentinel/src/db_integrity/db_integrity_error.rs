@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DbIntegrityError {
+    #[error("cannot parse `DbIntegrity` from str '{0}'")]
+    FromStr(String),
+
+    #[error("no entry in the db commitment for key '0x{0}'")]
+    NoEntry(String),
+
+    #[error("invalid hash size in db integrity proof - got {got}, expected {expected} for element {element}")]
+    InvalidHashSizeInProof {
+        got: usize,
+        expected: usize,
+        element: String,
+    },
+
+    #[error("cannot verify db integrity proof, no signature present")]
+    NoSignature,
+
+    #[error("db integrity proof verification error: {0}")]
+    VerificationError(String),
+
+    #[error("cannot create db integrity proof from: {0}")]
+    CannotCreateProofFrom(String),
+
+    #[error("hex error: {0}")]
+    Hex(#[from] hex::FromHexError),
+
+    #[error("serde json error: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+}
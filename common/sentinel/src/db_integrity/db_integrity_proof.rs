@@ -0,0 +1,103 @@
+use std::fmt;
+
+use common::{crypto_utils::keccak_hash_bytes, Byte, Bytes};
+use derive_getters::Getters;
+use derive_more::Constructor;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::{
+    db_commitment::recover_signer,
+    db_commitment_tree::{get_bit, get_path, hash_branch},
+    DbCommitmentEntry,
+    DbIntegrityError,
+};
+use crate::WebSocketMessagesEncodable;
+
+/// Proves that a single db entry is included in the core's signed db commitment.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters, Constructor)]
+pub struct DbIntegrityProof {
+    #[serde_as(as = "serde_with::hex::Hex")]
+    key: Bytes,
+    entry: DbCommitmentEntry,
+    #[serde_as(as = "Vec<serde_with::hex::Hex>")]
+    proof: Vec<Bytes>,
+    root: EthHash,
+    num_entries: usize,
+    signer: Option<EthAddress>,
+    signature: Option<String>,
+}
+
+impl TryFrom<Json> for DbIntegrityProof {
+    type Error = DbIntegrityError;
+
+    fn try_from(j: Json) -> Result<Self, Self::Error> {
+        Ok(serde_json::from_value(j)?)
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for DbIntegrityProof {
+    type Error = DbIntegrityError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        match m {
+            WebSocketMessagesEncodable::Success(j) => Self::try_from(j),
+            other => Err(Self::Error::CannotCreateProofFrom(other.to_string())),
+        }
+    }
+}
+
+impl DbIntegrityProof {
+    /// Verifies that the entry, with the given value if any, is included in the tree with this
+    /// proof's root. The proof's sibling hashes run from the entry's leaf up to the root, & the
+    /// bits of the hash of the entry's key give which side of each branch the path takes.
+    pub fn verify(&self, value: Option<&[Byte]>) -> Result<bool, DbIntegrityError> {
+        if let Some(v) = value {
+            if keccak_hash_bytes(v) != *self.entry.value_hash() {
+                return Ok(false);
+            }
+        };
+        let path = get_path(&self.key);
+        let depth = self.proof.len();
+        let computed_root =
+            self.proof
+                .iter()
+                .enumerate()
+                .try_fold(self.entry.to_leaf(&self.key), |acc, (i, sibling)| {
+                    if sibling.len() != EthHash::len_bytes() {
+                        return Err(DbIntegrityError::InvalidHashSizeInProof {
+                            got: sibling.len(),
+                            expected: EthHash::len_bytes(),
+                            element: format!("0x{}", hex::encode(sibling)),
+                        });
+                    };
+                    let sibling = EthHash::from_slice(sibling);
+                    Ok(if get_bit(&path, depth - 1 - i) {
+                        hash_branch(&sibling, &acc)
+                    } else {
+                        hash_branch(&acc, &sibling)
+                    })
+                })?;
+        Ok(computed_root == self.root)
+    }
+
+    /// Returns the address which signed this proof's root, so long as it's the one claimed.
+    pub fn signer_address(&self) -> Result<EthAddress, DbIntegrityError> {
+        let signature = self.signature.as_ref().ok_or(DbIntegrityError::NoSignature)?;
+        let signer = recover_signer(&self.root, signature)?;
+        match self.signer {
+            Some(claimed) if claimed == signer => Ok(signer),
+            claimed => Err(DbIntegrityError::VerificationError(format!(
+                "root signed by {signer} but claims to be from {claimed:?}"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for DbIntegrityProof {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
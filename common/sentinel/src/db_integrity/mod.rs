@@ -0,0 +1,14 @@
+mod db_changes;
+mod db_commitment;
+mod db_commitment_tree;
+mod db_integrity;
+mod db_integrity_error;
+mod db_integrity_proof;
+
+pub use self::{
+    db_changes::DbChanges,
+    db_commitment::{DbCommitment, DbCommitmentEntry},
+    db_integrity::DbIntegrity,
+    db_integrity_error::DbIntegrityError,
+    db_integrity_proof::DbIntegrityProof,
+};
@@ -183,7 +183,16 @@ macro_rules! create_db_keys {
     }
 }
 
-create_db_keys!(USER_OP_LIST, ACTOR_INCLUSION_PROOF, CHALLENGES_LIST);
+create_db_keys!(
+    USER_OP_LIST,
+    ACTOR_INCLUSION_PROOF,
+    CHALLENGES_LIST,
+    ACTORS,
+    STARTED_CHALLENGES_LIST,
+    WEBHOOK_QUEUE,
+    DB_COMMITMENT,
+    DB_COMMITMENT_MIGRATION
+);
 
 pub struct SentinelDbUtils<'a, D: DatabaseInterface>(&'a D);
 
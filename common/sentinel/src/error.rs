@@ -7,9 +7,12 @@ use thiserror::Error;
 use crate::{
     BroadcastChannelMessages,
     ChallengeResponderMessages,
+    ChallengerMessages,
+    ConfigWatcherMessages,
     DbIntegrity,
     DbKey,
     EthRpcMessages,
+    NotifierMessages,
     StatusPublisherMessages,
     SyncerMessages,
     UserOpCancellerMessages,
@@ -57,6 +60,9 @@ pub enum SentinelError {
     #[error("{0}")]
     Ipfs(#[from] crate::IpfsError),
 
+    #[error("{0}")]
+    Notifications(#[from] crate::NotificationsError),
+
     #[error("{0}")]
     SentinelStatusError(#[from] crate::status::SentinelStatusError),
 
@@ -186,6 +192,9 @@ pub enum SentinelError {
     #[error("sentinel config error {0}")]
     SentinelConfig(#[from] crate::config::SentinelConfigError),
 
+    #[error("attestation error: {0}")]
+    Attestation(#[from] crate::AttestationError),
+
     #[error("{0}")]
     BlockAlreadyInDb(common::BlockAlreadyInDbError),
 
@@ -207,6 +216,15 @@ pub enum SentinelError {
     #[error("challenge responder channel error: {0}")]
     ChallengeResponderChannel(Box<tokio::sync::mpsc::error::SendError<ChallengeResponderMessages>>),
 
+    #[error("challenger channel error: {0}")]
+    ChallengerChannel(Box<tokio::sync::mpsc::error::SendError<ChallengerMessages>>),
+
+    #[error("notifier channel error: {0}")]
+    NotifierChannel(Box<tokio::sync::mpsc::error::SendError<NotifierMessages>>),
+
+    #[error("config watcher channel error: {0}")]
+    ConfigWatcherChannel(Box<tokio::sync::mpsc::error::SendError<ConfigWatcherMessages>>),
+
     #[error("syncer channel error: {0}")]
     SyncerChannel(Box<tokio::sync::broadcast::error::SendError<SyncerMessages>>),
 
@@ -253,6 +271,24 @@ impl From<tokio::sync::mpsc::error::SendError<ChallengeResponderMessages>> for S
     }
 }
 
+impl From<tokio::sync::mpsc::error::SendError<ChallengerMessages>> for SentinelError {
+    fn from(e: tokio::sync::mpsc::error::SendError<ChallengerMessages>) -> Self {
+        Self::ChallengerChannel(Box::new(e))
+    }
+}
+
+impl From<tokio::sync::mpsc::error::SendError<NotifierMessages>> for SentinelError {
+    fn from(e: tokio::sync::mpsc::error::SendError<NotifierMessages>) -> Self {
+        Self::NotifierChannel(Box::new(e))
+    }
+}
+
+impl From<tokio::sync::mpsc::error::SendError<ConfigWatcherMessages>> for SentinelError {
+    fn from(e: tokio::sync::mpsc::error::SendError<ConfigWatcherMessages>) -> Self {
+        Self::ConfigWatcherChannel(Box::new(e))
+    }
+}
+
 impl From<tokio::sync::broadcast::error::SendError<BroadcastChannelMessages>> for SentinelError {
     fn from(e: tokio::sync::broadcast::error::SendError<BroadcastChannelMessages>) -> Self {
         Self::BroadcastChannelMessages(Box::new(e))
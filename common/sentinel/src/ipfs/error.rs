@@ -14,6 +14,9 @@ pub enum IpfsError {
     #[error("serde json: {0}")]
     SerdeJson(#[from] serde_json::Error),
 
+    #[error("base64 decode error: {0}")]
+    Base64(#[from] base64::DecodeError),
+
     #[error("utf8 error: {0}")]
     Utf8Error(#[from] std::str::Utf8Error),
 }
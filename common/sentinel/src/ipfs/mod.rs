@@ -1,5 +1,13 @@
 mod check_daemon;
 mod error;
 mod publish;
+mod subscribe;
 
-pub use self::{check_daemon::check_ipfs_daemon_is_running, error::IpfsError, publish::publish_status};
+pub(crate) const IPFS_TOPIC: &str = "pnetwork-v3";
+
+pub use self::{
+    check_daemon::check_ipfs_daemon_is_running,
+    error::IpfsError,
+    publish::publish_status,
+    subscribe::{parse_status_from_pubsub_line, subscribe_to_statuses},
+};
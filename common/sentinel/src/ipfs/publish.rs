@@ -1,9 +1,8 @@
 use std::{fs::File, io::prelude::*, process::Command, str::from_utf8};
 
-use super::IpfsError;
+use super::{IpfsError, IPFS_TOPIC};
 use crate::SentinelStatus;
 
-const IPFS_TOPIC: &str = "pnetwork-v3";
 const IPFS_STATUS_PATH: &str = "./.temp.json";
 
 fn write_temp_file(status: &SentinelStatus) -> Result<(), IpfsError> {
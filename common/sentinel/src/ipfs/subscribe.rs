@@ -0,0 +1,91 @@
+use std::{process::Stdio, str::FromStr};
+
+use base64::{engine::general_purpose, Engine};
+use serde::{Deserialize, Serialize};
+use tokio::process::{Child, Command};
+
+use super::{IpfsError, IPFS_TOPIC};
+use crate::SentinelStatus;
+
+/// A single message as output (one per line) by `ipfs pubsub sub --enc=json`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct IpfsPubsubMessage {
+    from: Option<String>,
+    data: String,
+}
+
+impl FromStr for IpfsPubsubMessage {
+    type Err = IpfsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+impl IpfsPubsubMessage {
+    fn decode_data(&self) -> Result<Vec<u8>, IpfsError> {
+        // NOTE: Newer ipfs versions multibase encode the data, prefixing it with `u` to denote
+        // unpadded base64url. Older ones use plain base64. A json payload in plain base64 can
+        // never start with `u`, so we can safely tell the two apart.
+        match self.data.strip_prefix('u') {
+            Some(s) => Ok(general_purpose::URL_SAFE_NO_PAD.decode(s)?),
+            None => Ok(general_purpose::STANDARD.decode(&self.data)?),
+        }
+    }
+
+    fn to_status(&self) -> Result<SentinelStatus, IpfsError> {
+        Ok(serde_json::from_slice(&self.decode_data()?)?)
+    }
+}
+
+/// Parses a line of `ipfs pubsub sub --enc=json` output into the `SentinelStatus` it carries.
+pub fn parse_status_from_pubsub_line(line: &str) -> Result<SentinelStatus, IpfsError> {
+    IpfsPubsubMessage::from_str(line)?.to_status()
+}
+
+/// Spawns an `ipfs pubsub sub` process on the status topic, whose stdout yields one json encoded
+/// message per line. The process is killed when the returned handle is dropped.
+pub fn subscribe_to_statuses(ipfs_bin_path: &str) -> Result<Child, IpfsError> {
+    debug!("subscribing to statuses...");
+    Ok(Command::new(ipfs_bin_path)
+        .arg("pubsub")
+        .arg("sub")
+        .arg("--enc=json")
+        .arg(IPFS_TOPIC)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use common_eth::EthPrivateKey;
+
+    use super::*;
+
+    fn get_sample_status() -> SentinelStatus {
+        let pk = EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap();
+        SentinelStatus::new(&pk, vec![], crate::BalanceRunways::default()).unwrap()
+    }
+
+    #[test]
+    fn should_parse_status_from_multibase_encoded_pubsub_line() {
+        let status = get_sample_status();
+        let data = general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(&status).unwrap());
+        let line = format!("{{\"from\":\"12D3KooW\",\"data\":\"u{data}\",\"seqno\":\"u\",\"topicIDs\":[]}}");
+        let result = parse_status_from_pubsub_line(&line).unwrap();
+        assert_eq!(result, status);
+    }
+
+    #[test]
+    fn should_parse_status_from_base64_encoded_pubsub_line() {
+        let status = get_sample_status();
+        let data = general_purpose::STANDARD.encode(serde_json::to_vec(&status).unwrap());
+        let line = format!("{{\"from\":\"12D3KooW\",\"data\":\"{data}\"}}");
+        let result = parse_status_from_pubsub_line(&line).unwrap();
+        assert_eq!(result, status);
+    }
+}
@@ -1,4 +1,5 @@
 mod actors;
+mod attestation;
 mod balances;
 mod batching;
 mod bpm;
@@ -19,6 +20,8 @@ mod ipfs;
 mod latest_block_info;
 mod logging;
 mod messages;
+mod notifications;
+mod pnetwork_hubs;
 mod processor;
 mod registration;
 mod sanity_check_frequency;
@@ -30,7 +33,22 @@ mod utils;
 
 pub use self::{
     actors::{Actor, ActorInclusionProof, ActorType, Actors, ActorsError},
-    balances::{Balance, Balances},
+    attestation::{
+        get_attestation_nonce,
+        verify_core_attestation,
+        AttestationApplicationId,
+        AttestationCertificateChain,
+        AttestationError,
+        AttestationPolicy,
+        AttestationRoots,
+        AuthorizationList,
+        KeyDescription,
+        RootOfTrust,
+        SecurityLevel,
+        VerifiedAttestation,
+        VerifiedBootState,
+    },
+    balances::{Balance, BalanceRunway, BalanceRunways, Balances},
     batching::Batch,
     bpm::{Bpm, BpmInfo, Bpms},
     call_core::call_core,
@@ -42,9 +60,24 @@ pub use self::{
         Challenges,
         ChallengesError,
         ChallengesList,
+        StartedChallenge,
+        StartedChallenges,
+        StartedChallengesList,
+    },
+    config::{
+        AttestationConfig,
+        BalancesConfig,
+        ChallengerConfig,
+        IpfsConfig,
+        LogConfig,
+        NetworkConfig,
+        NotificationsConfig,
+        SentinelConfig,
+        SentinelConfigError,
+        SentinelCoreConfig,
     },
-    config::{IpfsConfig, LogConfig, NetworkConfig, SentinelConfig, SentinelConfigError, SentinelCoreConfig},
     constants::{
+        CHALLENGE_RESPONSE_GAS_LIMIT,
         DEFAULT_SLEEP_TIME,
         HOST_PROTOCOL_ID,
         MAX_CHANNEL_CAPACITY,
@@ -52,7 +85,7 @@ pub use self::{
         NATIVE_PROTOCOL_ID,
     },
     core_state::CoreState,
-    db_integrity::{DbIntegrity, DbIntegrityError},
+    db_integrity::{DbChanges, DbCommitment, DbCommitmentEntry, DbIntegrity, DbIntegrityError, DbIntegrityProof},
     db_utils::{DbKey, DbUtilsT, SentinelDbUtils},
     endpoints::{EndpointError, Endpoints},
     env::Env,
@@ -73,14 +106,27 @@ pub use self::{
     },
     eth_rpc_channels::{EthRpcChannels, EthRpcSenders},
     flatten_join_handle::flatten_join_handle,
-    ipfs::{check_ipfs_daemon_is_running, publish_status, IpfsError},
+    ipfs::{
+        check_ipfs_daemon_is_running,
+        parse_status_from_pubsub_line,
+        publish_status,
+        subscribe_to_statuses,
+        IpfsError,
+    },
     latest_block_info::{LatestBlockInfo, LatestBlockInfos},
     logging::{init_logger, LogLevel},
     messages::{
+        BalanceMonitorBroadcastChannelMessages,
         BroadcastChannelMessages,
         ChallengeResponderBroadcastChannelMessages,
         ChallengeResponderMessages,
+        ChallengerBroadcastChannelMessages,
+        ChallengerMessages,
+        ConfigWatcherMessages,
+        EthRpcBroadcastChannelMessages,
         EthRpcMessages,
+        NotifierBroadcastChannelMessages,
+        NotifierMessages,
         Responder,
         RpcServerBroadcastChannelMessages,
         StatusPublisherBroadcastChannelMessages,
@@ -98,18 +144,32 @@ pub use self::{
         WebSocketMessagesProcessBatchArgs,
         WebSocketMessagesResetChainArgs,
     },
+    notifications::{
+        deliver_webhook,
+        Notification,
+        NotificationsError,
+        SentinelEvent,
+        SentinelEvents,
+        WebhookDelivery,
+        WebhookQueue,
+    },
+    pnetwork_hubs::{PnetworkHub, PnetworkHubs},
     processor::{process_batch, ProcessorOutput},
     registration::{get_registration_extension_tx, get_registration_signature},
     sanity_check_frequency::sanity_check_frequency,
-    status::{SentinelStatus, SentinelStatusError},
+    status::{Heartbeats, SentinelStatus, SentinelStatusError},
     sync_state::SyncState,
     user_ops::{
+        BackfillReport,
         CancellableUserOp,
         CancellableUserOps,
         UserOp,
         UserOpCancellationSignature,
+        UserOpChange,
         UserOpError,
         UserOpList,
+        UserOpRoute,
+        UserOpRouteHop,
         UserOpSmartContractState,
         UserOpUniqueId,
         UserOps,
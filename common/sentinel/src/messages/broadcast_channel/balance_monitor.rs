@@ -0,0 +1,18 @@
+use std::fmt;
+
+use crate::SentinelConfig;
+
+#[derive(Debug, Clone)]
+pub enum BalanceMonitorBroadcastChannelMessages {
+    ConfigUpdated(Box<SentinelConfig>),
+}
+
+impl fmt::Display for BalanceMonitorBroadcastChannelMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = "balance monitor broadcast channel message:";
+        let s = match self {
+            Self::ConfigUpdated(_) => "config updated",
+        };
+        write!(f, "{prefix} {s}")
+    }
+}
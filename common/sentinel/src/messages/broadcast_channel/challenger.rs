@@ -0,0 +1,26 @@
+use std::fmt;
+
+use crate::SentinelConfig;
+
+#[derive(Debug, Clone)]
+pub enum ChallengerBroadcastChannelMessages {
+    Stop,
+    Start,
+    CoreConnected,
+    CoreDisconnected,
+    ConfigUpdated(Box<SentinelConfig>),
+}
+
+impl fmt::Display for ChallengerBroadcastChannelMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = "challenger broadcast channel message:";
+        let s = match self {
+            Self::Stop => "stop",
+            Self::Start => "start",
+            Self::CoreConnected => "core connected",
+            Self::CoreDisconnected => "core disconnected",
+            Self::ConfigUpdated(_) => "config updated",
+        };
+        write!(f, "{prefix} {s}")
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt;
+
+use crate::SentinelConfig;
+
+#[derive(Debug, Clone)]
+pub enum EthRpcBroadcastChannelMessages {
+    ConfigUpdated(Box<SentinelConfig>),
+}
+
+impl fmt::Display for EthRpcBroadcastChannelMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = "eth rpc broadcast channel message:";
+        let s = match self {
+            Self::ConfigUpdated(_) => "config updated",
+        };
+        write!(f, "{prefix} {s}")
+    }
+}
@@ -1,4 +1,8 @@
+mod balance_monitor;
 mod challenge_responder;
+mod challenger;
+mod eth_rpc;
+mod notifier;
 mod rpc_server;
 mod status_publisher;
 mod syncer;
@@ -13,10 +17,18 @@ pub enum BroadcastChannelMessages {
     StatusPublisher(StatusPublisherBroadcastChannelMessages),
     UserOpCanceller(UserOpCancellerBroadcastChannelMessages),
     ChallengeResponder(ChallengeResponderBroadcastChannelMessages),
+    Challenger(ChallengerBroadcastChannelMessages),
+    Notifier(NotifierBroadcastChannelMessages),
+    EthRpc(EthRpcBroadcastChannelMessages),
+    BalanceMonitor(BalanceMonitorBroadcastChannelMessages),
 }
 
 pub use self::{
+    balance_monitor::BalanceMonitorBroadcastChannelMessages,
     challenge_responder::ChallengeResponderBroadcastChannelMessages,
+    challenger::ChallengerBroadcastChannelMessages,
+    eth_rpc::EthRpcBroadcastChannelMessages,
+    notifier::NotifierBroadcastChannelMessages,
     rpc_server::RpcServerBroadcastChannelMessages,
     status_publisher::StatusPublisherBroadcastChannelMessages,
     syncer::SyncerBroadcastChannelMessages,
@@ -0,0 +1,18 @@
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub enum NotifierBroadcastChannelMessages {
+    CoreConnected,
+    CoreDisconnected,
+}
+
+impl fmt::Display for NotifierBroadcastChannelMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = "notifier broadcast channel message:";
+        let s = match self {
+            Self::CoreConnected => "core connected",
+            Self::CoreDisconnected => "core disconnected",
+        };
+        write!(f, "{prefix} {s}")
+    }
+}
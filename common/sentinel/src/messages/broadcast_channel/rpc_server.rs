@@ -1,9 +1,12 @@
 use std::fmt;
 
+use crate::SentinelConfig;
+
 #[derive(Debug, Clone)]
 pub enum RpcServerBroadcastChannelMessages {
     CoreConnected,
     CoreDisconnected,
+    ConfigUpdated(Box<SentinelConfig>),
 }
 
 impl fmt::Display for RpcServerBroadcastChannelMessages {
@@ -12,6 +15,7 @@ impl fmt::Display for RpcServerBroadcastChannelMessages {
         let s = match self {
             Self::CoreConnected => "core connected",
             Self::CoreDisconnected => "core disconnected",
+            Self::ConfigUpdated(_) => "config updated",
         };
         write!(f, "{prefix} {s}")
     }
@@ -1,11 +1,14 @@
 use std::fmt;
 
+use crate::BalanceRunways;
+
 #[derive(Debug, Clone)]
 pub enum StatusPublisherBroadcastChannelMessages {
     Stop,
     Start,
     CoreConnected,
     CoreDisconnected,
+    BalanceRunways(BalanceRunways),
 }
 
 impl fmt::Display for StatusPublisherBroadcastChannelMessages {
@@ -16,6 +19,7 @@ impl fmt::Display for StatusPublisherBroadcastChannelMessages {
             Self::Start => "start".to_string(),
             Self::CoreConnected => "core connected".to_string(),
             Self::CoreDisconnected => "core disconnected".to_string(),
+            Self::BalanceRunways(r) => format!("{} balance runways, {} low", r.len(), r.low().len()),
         };
         write!(f, "{prefix} {s}")
     }
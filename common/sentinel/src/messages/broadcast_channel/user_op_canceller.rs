@@ -1,11 +1,14 @@
 use std::fmt;
 
+use crate::SentinelConfig;
+
 #[derive(Debug, Clone)]
 pub enum UserOpCancellerBroadcastChannelMessages {
     Stop,
     Start,
     CoreConnected,
     CoreDisconnected,
+    ConfigUpdated(Box<SentinelConfig>),
 }
 
 impl fmt::Display for UserOpCancellerBroadcastChannelMessages {
@@ -16,6 +19,7 @@ impl fmt::Display for UserOpCancellerBroadcastChannelMessages {
             Self::Start => "start",
             Self::CoreConnected => "core connected",
             Self::CoreDisconnected => "core disconnected",
+            Self::ConfigUpdated(_) => "config updated",
         };
         write!(f, "{prefix} {s}")
     }
@@ -0,0 +1,15 @@
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub enum ChallengerMessages {
+    ChallengeStaleActors,
+}
+
+impl fmt::Display for ChallengerMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::ChallengeStaleActors => "challenge any stale actors",
+        };
+        write!(f, "{s}")
+    }
+}
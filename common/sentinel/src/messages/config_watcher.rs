@@ -0,0 +1,27 @@
+use std::fmt;
+
+use serde_json::Value as Json;
+use tokio::sync::{oneshot, oneshot::Receiver};
+
+use crate::{Responder, SentinelError};
+
+#[derive(Debug)]
+pub enum ConfigWatcherMessages {
+    Reload(Responder<Json>),
+}
+
+impl ConfigWatcherMessages {
+    pub fn get_reload_msg() -> (Self, Receiver<Result<Json, SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::Reload(tx), rx)
+    }
+}
+
+impl fmt::Display for ConfigWatcherMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Reload(_) => "reload config",
+        };
+        write!(f, "{s}")
+    }
+}
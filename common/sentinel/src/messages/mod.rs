@@ -1,6 +1,9 @@
 mod broadcast_channel;
 mod challenge_responder;
+mod challenger;
+mod config_watcher;
 mod eth_rpc;
+mod notifier;
 mod responder;
 mod status_publisher;
 mod syncer;
@@ -9,15 +12,22 @@ mod websocket;
 
 pub use self::{
     broadcast_channel::{
+        BalanceMonitorBroadcastChannelMessages,
         BroadcastChannelMessages,
         ChallengeResponderBroadcastChannelMessages,
+        ChallengerBroadcastChannelMessages,
+        EthRpcBroadcastChannelMessages,
+        NotifierBroadcastChannelMessages,
         RpcServerBroadcastChannelMessages,
         StatusPublisherBroadcastChannelMessages,
         SyncerBroadcastChannelMessages,
         UserOpCancellerBroadcastChannelMessages,
     },
     challenge_responder::ChallengeResponderMessages,
+    challenger::ChallengerMessages,
+    config_watcher::ConfigWatcherMessages,
     eth_rpc::EthRpcMessages,
+    notifier::NotifierMessages,
     responder::Responder,
     status_publisher::StatusPublisherMessages,
     syncer::SyncerMessages,
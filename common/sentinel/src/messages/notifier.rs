@@ -0,0 +1,19 @@
+use std::fmt;
+
+use crate::SentinelEvents;
+
+#[derive(Debug, Clone)]
+pub enum NotifierMessages {
+    Notify(SentinelEvents),
+    RetryWebhooks,
+}
+
+impl fmt::Display for NotifierMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Notify(events) => format!("notify {} events", events.len()),
+            Self::RetryWebhooks => "retry webhooks".to_string(),
+        };
+        write!(f, "{s}")
+    }
+}
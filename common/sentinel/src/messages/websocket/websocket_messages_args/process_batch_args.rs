@@ -5,24 +5,31 @@ use derive_more::Constructor;
 use ethereum_types::Address as EthAddress;
 use serde::{Deserialize, Serialize};
 
+use crate::PnetworkHubs;
+
 #[derive(Debug, Clone, PartialEq, Constructor, Serialize, Deserialize, Getters, Dissolve)]
 pub struct WebSocketMessagesProcessBatchArgs {
     validate: bool,
     dry_run: bool,
     reprocess: bool,
     network_id: NetworkId,
-    pnetwork_hub: EthAddress,
+    pnetwork_hubs: PnetworkHubs,
     sub_mat_batch: EthSubmissionMaterials,
     governance_address: Option<EthAddress>,
+    // NOTE: The address from which this sentinel broadcasts any challenges it starts, if its
+    // challenger is enabled, so that the core can track those challenges.
+    #[serde(default)]
+    challenger_address: Option<EthAddress>,
 }
 
 impl WebSocketMessagesProcessBatchArgs {
     pub fn new_for_syncer(
         validate: bool,
         network_id: NetworkId,
-        pnetwork_hub: EthAddress,
+        pnetwork_hubs: PnetworkHubs,
         sub_mat_batch: EthSubmissionMaterials,
         governance_address: Option<EthAddress>,
+        challenger_address: Option<EthAddress>,
     ) -> Box<Self> {
         let dry_run = false;
         let reprocess = false;
@@ -31,9 +38,10 @@ impl WebSocketMessagesProcessBatchArgs {
             dry_run,
             reprocess,
             network_id,
-            pnetwork_hub,
+            pnetwork_hubs,
             sub_mat_batch,
             governance_address,
+            challenger_address,
         ))
     }
 }
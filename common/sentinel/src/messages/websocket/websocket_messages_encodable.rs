@@ -9,6 +9,8 @@ use serde_json::Value as Json;
 
 use super::WebSocketMessagesEncodableDbOps;
 use crate::{
+    BalanceRunways,
+    ChallengeState,
     SentinelError,
     UserOpUniqueId,
     WebSocketMessagesCancelUserOpArgs,
@@ -16,6 +18,7 @@ use crate::{
     WebSocketMessagesInitArgs,
     WebSocketMessagesProcessBatchArgs,
     WebSocketMessagesResetChainArgs,
+    WebhookQueue,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -24,25 +27,36 @@ pub enum WebSocketMessagesEncodable {
     GetUserOps,
     Success(Json),
     GetUserOpList,
+    GetActors,
     GetChallengesList,
     GetInclusionProof,
+    GetStartedChallenges,
+    GetWebhookQueue,
+    CheckDbIntegrity,
     CheckInit(NetworkId),
     GetChallenge(EthHash),
     GetUnsolvedChallenges,
     HardReset(DebugSignature),
-    GetStatus(Vec<NetworkId>),
+    AuthorizeSignTypedData(EthHash, DebugSignature),
+    MigrateDbCommitment(DebugSignature),
+    GetStatus(Vec<NetworkId>, BalanceRunways),
     GetAttestationCertificate,
     GetUserOp(UserOpUniqueId),
     GetUserOpByTxHash(EthHash),
+    GetUserOpRoute(UserOpUniqueId),
     GetCoreState(Vec<NetworkId>),
     Error(WebSocketMessagesError),
     GetAttestationSignature(Vec<u8>),
+    GetDbIntegrityProof(Vec<u8>),
     PurgeUserOps(usize, DebugSignature),
     SetChallengesToSolved(Vec<EthHash>),
+    SetStartedChallengeState(EthHash, ChallengeState),
+    SetWebhookQueue(WebhookQueue),
     GetLatestBlockInfos(Vec<NetworkId>),
     GetCancellableUserOps(Vec<NetworkId>),
     DbOps(WebSocketMessagesEncodableDbOps),
     RemoveChallenge(EthHash, DebugSignature),
+    InitLightClient(NetworkId, String, EthHash, DebugSignature),
     RemoveDebugSigner(String, DebugSignature),
     Initialize(Box<WebSocketMessagesInitArgs>),
     RemoveUserOp(UserOpUniqueId, DebugSignature),
@@ -92,15 +106,21 @@ impl fmt::Display for WebSocketMessagesEncodable {
             Self::GetUserOps => "GetUserOps".to_string(),
             Self::GetUserOp(_) => "GetUserOp".to_string(),
             Self::CheckInit(..) => "CheckIni".to_string(),
+            Self::CheckDbIntegrity => "CheckDbIntegrity".to_string(),
+            Self::GetDbIntegrityProof(..) => "GetDbIntegrityProof".to_string(),
             Self::GetStatus(..) => "GetStatus".to_string(),
             Self::HardReset(..) => "HardReset".to_string(),
+            Self::AuthorizeSignTypedData(..) => "AuthorizeSignTypedData".to_string(),
+            Self::MigrateDbCommitment(..) => "MigrateDbCommitment".to_string(),
             Self::Initialize(_) => "Initialize".to_string(),
             Self::ResetChain(_) => "ResetChain".to_string(),
+            Self::InitLightClient(..) => "InitLightClient".to_string(),
             Self::GetUserOpList => "GetUserOpList".to_string(),
             Self::RemoveUserOp(..) => "RemoveUserOp".to_string(),
             Self::PurgeUserOps(..) => "PurgeUserOps".to_string(),
             Self::GetCoreState(..) => "GetCoreState".to_string(),
             Self::ProcessBatch(..) => "ProcessBatch".to_string(),
+            Self::GetActors => "GetActors".to_string(),
             Self::GetChallenge(..) => "GetChallenge".to_string(),
             Self::AddDebugSigners(..) => "AddDebugSigners".to_string(),
             Self::GetChallengesList => "GetChallengesList".to_string(),
@@ -108,8 +128,13 @@ impl fmt::Display for WebSocketMessagesEncodable {
             Self::GetInclusionProof => "GetInclusionProof".to_string(),
             Self::RemoveDebugSigner(..) => "RemoveDebugSigner".to_string(),
             Self::GetUserOpByTxHash(..) => "GetUserOpByTxHash".to_string(),
+            Self::GetUserOpRoute(..) => "GetUserOpRoute".to_string(),
             Self::GetLatestBlockInfos(..) => "GetLatestBlockInfos".to_string(),
             Self::GetUnsolvedChallenges => "GetUnsolvedChallenges".to_string(),
+            Self::GetStartedChallenges => "GetStartedChallenges".to_string(),
+            Self::SetStartedChallengeState(..) => "SetStartedChallengeState".to_string(),
+            Self::GetWebhookQueue => "GetWebhookQueue".to_string(),
+            Self::SetWebhookQueue(..) => "SetWebhookQueue".to_string(),
             Self::GetCancellableUserOps(_) => "GetCancellableUserOps".to_string(),
             Self::SetChallengesToSolved(..) => "SetChallengesToSolved".to_string(),
             Self::GetAttestationSignature(..) => "GetAttestationSignature".to_string(),
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use common_eth::EthPrivateKey;
+use reqwest::Client;
+
+use super::{NotificationsError, WebhookDelivery};
+
+const SIGNATURE_HEADER: &str = "x-sentinel-signature";
+const SIGNER_HEADER: &str = "x-sentinel-signer";
+const NOTIFICATION_ID_HEADER: &str = "x-sentinel-notification-id";
+
+/// Posts the delivery's notification json to its webhook, along with an eth-prefixed signature
+/// over that exact body from the passed in key. Any non-2xx response is treated as a failure.
+pub async fn deliver_webhook(
+    client: &Client,
+    delivery: &WebhookDelivery,
+    pk: &EthPrivateKey,
+    timeout: u64,
+) -> Result<(), NotificationsError> {
+    let notification = delivery.notification();
+    let body = notification.to_json_string()?;
+    let signature = notification.sign(pk)?;
+    let response = client
+        .post(delivery.url())
+        .timeout(Duration::from_secs(timeout))
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, format!("0x{signature}"))
+        .header(SIGNER_HEADER, format!("0x{}", hex::encode(pk.to_address())))
+        .header(NOTIFICATION_ID_HEADER, format!("0x{}", hex::encode(notification.id())))
+        .body(body)
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status.is_success() {
+        debug!("delivered {notification} to webhook '{}'", delivery.url());
+        Ok(())
+    } else {
+        Err(NotificationsError::WebhookStatus {
+            url: delivery.url().clone(),
+            status: status.as_u16(),
+        })
+    }
+}
@@ -0,0 +1,13 @@
+mod deliver_webhook;
+mod notification;
+mod notifications_error;
+mod sentinel_event;
+mod webhook_queue;
+
+pub use self::{
+    deliver_webhook::deliver_webhook,
+    notification::Notification,
+    notifications_error::NotificationsError,
+    sentinel_event::{SentinelEvent, SentinelEvents},
+    webhook_queue::{WebhookDelivery, WebhookQueue},
+};
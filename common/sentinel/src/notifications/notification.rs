@@ -0,0 +1,48 @@
+use std::fmt;
+
+use common_eth::{EthPrivateKey, EthSignature, EthSigningCapabilities};
+use derive_getters::Getters;
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{NotificationsError, SentinelEvent};
+use crate::{get_utc_timestamp, SentinelError};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct Notification {
+    id: EthHash,
+    timestamp: u64,
+    event: SentinelEvent,
+}
+
+impl Notification {
+    pub fn new(event: SentinelEvent) -> Result<Self, SentinelError> {
+        let timestamp = get_utc_timestamp()?;
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::to_vec(&event)?);
+        hasher.update(timestamp.to_be_bytes());
+        Ok(Self {
+            event,
+            timestamp,
+            id: EthHash::from_slice(&hasher.finalize()),
+        })
+    }
+
+    pub fn to_json_string(&self) -> Result<String, NotificationsError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Signs the exact json body that is posted to webhooks, so receivers can verify it via the
+    /// usual eth personal-message recovery.
+    pub fn sign(&self, pk: &EthPrivateKey) -> Result<EthSignature, NotificationsError> {
+        pk.hash_and_sign_msg_with_eth_prefix(self.to_json_string()?.as_bytes())
+            .map_err(|e| NotificationsError::SigningError(format!("{e}")))
+    }
+}
+
+impl fmt::Display for Notification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "notification 0x{}: {}", hex::encode(self.id), self.event)
+    }
+}
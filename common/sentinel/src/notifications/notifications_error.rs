@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NotificationsError {
+    #[error("notifications serde json error: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+
+    #[error("notifications reqwest error: {0}")]
+    Reqwest(#[from] reqwest::Error),
+
+    #[error("notification signing error: {0}")]
+    SigningError(String),
+
+    #[error("webhook '{url}' responded with status {status}")]
+    WebhookStatus { url: String, status: u16 },
+
+    #[error("cannot create webhook queue from: '{0}'")]
+    CannotCreateWebhookQueueFrom(String),
+}
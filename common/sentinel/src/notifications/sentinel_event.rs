@@ -0,0 +1,133 @@
+use std::fmt;
+
+use common_network_ids::NetworkId;
+use derive_more::{Constructor, Deref};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
+
+use crate::{user_ops::UserOpState, BalanceRunway, Challenge, ProcessorOutput, SentinelError};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+pub enum SentinelEvent {
+    UserOp {
+        uid: EthHash,
+        state: UserOpState,
+    },
+    ChallengePending {
+        id: EthHash,
+        challenge: Challenge,
+    },
+    ChallengeSolved {
+        id: EthHash,
+    },
+    CancellationTxPushed {
+        uid: EthHash,
+        network_id: NetworkId,
+        tx_hash: EthHash,
+    },
+    CancellationTxFailed {
+        uid: EthHash,
+        network_id: NetworkId,
+        error: String,
+    },
+    LowBalance {
+        runway: BalanceRunway,
+    },
+}
+
+impl fmt::Display for SentinelEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UserOp { uid, state } => write!(f, "user op 0x{} {state}", hex::encode(uid)),
+            Self::ChallengePending { id, .. } => write!(f, "challenge 0x{} pending", hex::encode(id)),
+            Self::ChallengeSolved { id } => write!(f, "challenge 0x{} solved", hex::encode(id)),
+            Self::CancellationTxPushed {
+                uid,
+                network_id,
+                tx_hash,
+            } => write!(
+                f,
+                "cancellation tx 0x{} pushed on {network_id} for user op 0x{}",
+                hex::encode(tx_hash),
+                hex::encode(uid)
+            ),
+            Self::CancellationTxFailed { uid, network_id, error } => write!(
+                f,
+                "cancellation tx failed on {network_id} for user op 0x{}: {error}",
+                hex::encode(uid)
+            ),
+            Self::LowBalance { runway } => write!(f, "low balance: {runway}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Constructor, Deref, Serialize, Deserialize)]
+pub struct SentinelEvents(Vec<SentinelEvent>);
+
+impl TryFrom<&ProcessorOutput> for SentinelEvents {
+    type Error = SentinelError;
+
+    fn try_from(o: &ProcessorOutput) -> Result<Self, Self::Error> {
+        let user_op_events = o.processed_user_ops().iter().map(|op| {
+            Ok(SentinelEvent::UserOp {
+                uid: op.uid()?,
+                state: *op.state(),
+            })
+        });
+        let pending_challenge_events = o.pending_challenges().iter().map(|c| {
+            Ok(SentinelEvent::ChallengePending {
+                id: c.id()?,
+                challenge: *c,
+            })
+        });
+        let solved_challenge_events = o
+            .solved_challenge_ids()
+            .iter()
+            .map(|id| Ok(SentinelEvent::ChallengeSolved { id: *id }));
+
+        user_op_events
+            .chain(pending_challenge_events)
+            .chain(solved_challenge_events)
+            .collect::<Result<Vec<_>, SentinelError>>()
+            .map(Self::new)
+    }
+}
+
+impl TryFrom<Json> for SentinelEvents {
+    type Error = SentinelError;
+
+    fn try_from(j: Json) -> Result<Self, Self::Error> {
+        Self::try_from(&ProcessorOutput::try_from(j)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Challenges, UserOps};
+
+    #[test]
+    fn should_get_sentinel_events_from_processor_output() {
+        let challenge = Challenge::random();
+        let solved_id = EthHash::random();
+        let output = ProcessorOutput::new(
+            NetworkId::default(),
+            1337,
+            UserOps::empty(),
+            Challenges::new(vec![challenge]),
+            vec![solved_id],
+        )
+        .unwrap();
+        let result = SentinelEvents::try_from(serde_json::to_value(&output).unwrap()).unwrap();
+        let expected_result = SentinelEvents::new(vec![
+            SentinelEvent::ChallengePending {
+                id: challenge.id().unwrap(),
+                challenge,
+            },
+            SentinelEvent::ChallengeSolved { id: solved_id },
+        ]);
+        assert_eq!(result, expected_result);
+    }
+}
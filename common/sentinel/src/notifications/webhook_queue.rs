@@ -0,0 +1,185 @@
+use std::fmt;
+
+use common::{DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use derive_getters::Getters;
+use derive_more::{Constructor, Deref};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::{Notification, NotificationsError};
+use crate::{db_utils::SentinelDbKeys, DbKey, DbUtilsT, SentinelDbUtils, SentinelError, WebSocketMessagesEncodable};
+
+// NOTE: Caps the queue so an unreachable webhook cannot grow the db without bound.
+const MAX_WEBHOOK_QUEUE_SIZE: usize = 10_000;
+// NOTE: Caps the exponential backoff at 2^8 times the retry frequency.
+const MAX_BACKOFF_EXPONENT: u32 = 8;
+
+/// A pending delivery of a single notification to a single webhook.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct WebhookDelivery {
+    url: String,
+    attempts: u32,
+    next_attempt_at: u64,
+    notification: Notification,
+}
+
+impl WebhookDelivery {
+    pub fn new(url: String, notification: Notification) -> Self {
+        Self {
+            url,
+            notification,
+            attempts: 0,
+            next_attempt_at: 0,
+        }
+    }
+
+    pub fn is_due(&self, now: u64) -> bool {
+        self.next_attempt_at <= now
+    }
+
+    fn is_same_as(&self, other: &Self) -> bool {
+        self.url == other.url && self.notification.id() == other.notification.id()
+    }
+
+    fn backoff(&self, retry_frequency: u64) -> u64 {
+        retry_frequency.saturating_mul(2u64.saturating_pow(self.attempts.min(MAX_BACKOFF_EXPONENT)))
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref)]
+pub struct WebhookQueue(Vec<WebhookDelivery>);
+
+impl fmt::Display for WebhookQueue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl WebhookQueue {
+    pub fn get<D: DatabaseInterface>(db_utils: &SentinelDbUtils<D>) -> Self {
+        if let Ok(x) = Self::get_from_db(db_utils, &SentinelDbKeys::get_webhook_queue_db_key()) {
+            x
+        } else {
+            debug!("no `WebhookQueue` in db, defaulting to empty queue");
+            Self::default()
+        }
+    }
+
+    fn push(&mut self, delivery: WebhookDelivery) {
+        if self.0.iter().any(|d| d.is_same_as(&delivery)) {
+            return;
+        };
+        if self.0.len() >= MAX_WEBHOOK_QUEUE_SIZE {
+            warn!("webhook queue is full, dropping oldest delivery");
+            self.0.remove(0);
+        };
+        self.0.push(delivery);
+    }
+
+    pub fn enqueue(&mut self, notification: &Notification, urls: &[String]) {
+        urls.iter()
+            .for_each(|url| self.push(WebhookDelivery::new(url.clone(), notification.clone())))
+    }
+
+    /// Removes & returns those deliveries which are due to be attempted.
+    pub fn take_due(&mut self, now: u64) -> Vec<WebhookDelivery> {
+        let (due, not_due) = self.0.drain(..).partition(|d| d.is_due(now));
+        self.0 = not_due;
+        due
+    }
+
+    /// Returns a failed delivery to the queue with an exponentially backed off next attempt time,
+    /// unless it has run out of attempts, in which case it is dropped.
+    pub fn requeue_failed(&mut self, mut delivery: WebhookDelivery, now: u64, retry_frequency: u64, max_attempts: u32) {
+        delivery.attempts += 1;
+        if delivery.attempts >= max_attempts {
+            warn!(
+                "dropping {} for webhook '{}' after {} attempts",
+                delivery.notification, delivery.url, delivery.attempts
+            );
+        } else {
+            delivery.next_attempt_at = now + delivery.backoff(retry_frequency);
+            self.push(delivery);
+        }
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        other.0.into_iter().for_each(|d| self.push(d))
+    }
+}
+
+impl DbUtilsT for WebhookQueue {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_webhook_queue_db_key())
+    }
+
+    fn sensitivity() -> Option<u8> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for WebhookQueue {
+    type Error = NotificationsError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        match m {
+            WebSocketMessagesEncodable::Success(j) => Ok(serde_json::from_value::<Self>(j)?),
+            other => Err(NotificationsError::CannotCreateWebhookQueueFrom(other.to_string())),
+        }
+    }
+}
+
+impl TryFrom<Json> for WebhookQueue {
+    type Error = NotificationsError;
+
+    fn try_from(j: Json) -> Result<Self, Self::Error> {
+        Ok(serde_json::from_value(j)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+    use ethereum_types::H256 as EthHash;
+
+    use super::*;
+    use crate::SentinelEvent;
+
+    fn get_test_notification() -> Notification {
+        Notification::new(SentinelEvent::ChallengeSolved { id: EthHash::random() }).unwrap()
+    }
+
+    #[test]
+    fn webhook_queue_should_back_off_and_drop_failed_deliveries() {
+        let db = get_test_database();
+        let db_utils = SentinelDbUtils::new(&db);
+        let urls = vec!["http://a.com".to_string(), "http://b.com".to_string()];
+        let notification = get_test_notification();
+        let mut queue = WebhookQueue::get(&db_utils);
+        queue.enqueue(&notification, &urls);
+        queue.enqueue(&notification, &urls);
+        assert_eq!(queue.len(), 2);
+
+        let now = 1000;
+        let retry_frequency = 30;
+        let max_attempts = 2;
+        let mut due = queue.take_due(now);
+        assert_eq!(due.len(), 2);
+        assert!(queue.is_empty());
+
+        queue.requeue_failed(due.remove(0), now, retry_frequency, max_attempts);
+        assert_eq!(queue[0].next_attempt_at(), &(now + retry_frequency * 2));
+        assert!(queue.take_due(now).is_empty());
+        queue.update_in_db(&db_utils).unwrap();
+
+        let mut queue = WebhookQueue::get(&db_utils);
+        let mut due = queue.take_due(now + retry_frequency * 2);
+        assert_eq!(due.len(), 1);
+        queue.requeue_failed(due.remove(0), now, retry_frequency, max_attempts);
+        assert!(queue.is_empty());
+    }
+}
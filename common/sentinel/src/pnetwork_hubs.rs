@@ -0,0 +1,74 @@
+use derive_getters::Getters;
+use derive_more::{Constructor, Deref};
+use ethereum_types::Address as EthAddress;
+use serde::{Deserialize, Serialize};
+
+/// A pNetwork hub contract, along with the (inclusive) range of blocks in which it's active. A
+/// missing bound means the range is open on that side.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize, Getters, Constructor)]
+pub struct PnetworkHub {
+    address: EthAddress,
+    active_from: Option<u64>,
+    active_until: Option<u64>,
+}
+
+impl PnetworkHub {
+    pub fn is_active_at(&self, block_num: u64) -> bool {
+        self.active_from.map_or(true, |n| block_num >= n) && self.active_until.map_or(true, |n| block_num <= n)
+    }
+}
+
+/// The hubs of a network. There may be more than one during contract upgrades, when the old & new
+/// hubs coexist for a while & both emit events.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize, Deref, Constructor)]
+pub struct PnetworkHubs(Vec<PnetworkHub>);
+
+impl From<EthAddress> for PnetworkHubs {
+    fn from(address: EthAddress) -> Self {
+        Self::new(vec![PnetworkHub::new(address, None, None)])
+    }
+}
+
+impl PnetworkHubs {
+    pub fn addresses(&self) -> Vec<EthAddress> {
+        self.iter().map(|h| h.address).collect()
+    }
+
+    pub fn active_at(&self, block_num: u64) -> Vec<EthAddress> {
+        self.iter()
+            .filter(|h| h.is_active_at(block_num))
+            .map(|h| h.address)
+            .collect()
+    }
+
+    /// The hub to send any new txs to, being the most recently activated of those hubs with no end
+    /// block, else the last listed hub.
+    pub fn current(&self) -> Option<EthAddress> {
+        self.iter()
+            .filter(|h| h.active_until.is_none())
+            .max_by_key(|h| h.active_from.unwrap_or_default())
+            .or_else(|| self.last())
+            .map(|h| h.address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_active_and_current_hubs() {
+        let old_hub = EthAddress::random();
+        let new_hub = EthAddress::random();
+        let hubs = PnetworkHubs::new(vec![
+            PnetworkHub::new(old_hub, None, Some(200)),
+            PnetworkHub::new(new_hub, Some(100), None),
+        ]);
+        assert_eq!(hubs.active_at(99), vec![old_hub]);
+        assert_eq!(hubs.active_at(100), vec![old_hub, new_hub]);
+        assert_eq!(hubs.active_at(200), vec![old_hub, new_hub]);
+        assert_eq!(hubs.active_at(201), vec![new_hub]);
+        assert_eq!(hubs.current(), Some(new_hub));
+        assert_eq!(PnetworkHubs::default().current(), None);
+    }
+}
@@ -22,24 +22,29 @@ pub(super) fn maybe_handle_actors_propagated_events<D: DatabaseInterface>(
     debug!("checking receipts for actors propagated events...");
     match Actors::from_sub_mat(sub_mat, *governance_address, *network_id)? {
         None => Ok(()),
-        Some(actors) => match actors.get_inclusion_proof_for_actor(&Actor::from(sentinel_address)) {
-            Err(e) => {
-                error!("{e}");
-                warn!("failed to create proof for this sentinel amongst actors!");
-                // NOTE: We failed to get a proof for some reason - likely this sentinel not being
-                // amongst the actors found in the event. We show the error in the logs here, but
-                // continue as normal since the sentinel may not yet be registered, but may want to
-                // continue syncing as usual.
-                Ok(())
-            },
-            Ok(proof) => {
-                // NOTE: We found a proof. Let's update in the db. (The update function first
-                // checks that the proof for an epoch later than whatever one is currently stored
-                // [if any] in the db).
-                info!("successfully created sentinel inclusion proof: {proof}");
-                proof.update_proof_in_db(db_utils)?;
-                Ok(())
-            },
+        Some(actors) => {
+            // NOTE: We store the actors themselves too, so that we can challenge any of them that
+            // go quiet. (As with the proof below, this only updates for a later epoch.)
+            actors.update_actors_in_db(db_utils)?;
+            match actors.get_inclusion_proof_for_actor(&Actor::from(sentinel_address)) {
+                Err(e) => {
+                    error!("{e}");
+                    warn!("failed to create proof for this sentinel amongst actors!");
+                    // NOTE: We failed to get a proof for some reason - likely this sentinel not being
+                    // amongst the actors found in the event. We show the error in the logs here, but
+                    // continue as normal since the sentinel may not yet be registered, but may want to
+                    // continue syncing as usual.
+                    Ok(())
+                },
+                Ok(proof) => {
+                    // NOTE: We found a proof. Let's update in the db. (The update function first
+                    // checks that the proof for an epoch later than whatever one is currently stored
+                    // [if any] in the db).
+                    info!("successfully created sentinel inclusion proof: {proof}");
+                    proof.update_proof_in_db(db_utils)?;
+                    Ok(())
+                },
+            }
         },
     }
 }
@@ -4,26 +4,41 @@ use common::DatabaseInterface;
 use common_eth::EthSubmissionMaterial;
 use ethereum_types::Address as EthAddress;
 
-use crate::{Challenges, ChallengesList, SentinelDbUtils, SentinelError};
+use crate::{Challenges, ChallengesList, SentinelDbUtils, SentinelError, StartedChallengesList};
 
 pub(super) fn maybe_handle_challenge_pending_events<D: DatabaseInterface>(
     db_utils: &SentinelDbUtils<D>,
-    pnetwork_hub: &EthAddress,
+    pnetwork_hubs: &[EthAddress],
     sub_mat: &EthSubmissionMaterial,
     sentinel_address: &EthAddress,
-) -> Result<(), SentinelError> {
+    challenger_address: &Option<EthAddress>,
+) -> Result<Challenges, SentinelError> {
     if sub_mat.receipts.is_empty() {
         debug!("no receipts in sub mat so not checking for new challenges");
-        return Ok(());
+        return Ok(Challenges::default());
     }
 
-    let challenges = Challenges::from_sub_mat(sub_mat, pnetwork_hub, sentinel_address)?;
+    let mut challenges = vec![];
+
+    for pnetwork_hub in pnetwork_hubs.iter() {
+        if let Some(ref challenger) = challenger_address {
+            let started = Challenges::from_sub_mat_started_by(sub_mat, pnetwork_hub, challenger)?;
+            if started.is_empty() {
+                debug!("no challenges started by {challenger} found in sub mat from hub {pnetwork_hub}");
+            } else {
+                StartedChallengesList::get(db_utils).add_challenges(db_utils, started)?;
+            }
+        };
+        challenges.extend(Challenges::from_sub_mat(sub_mat, pnetwork_hub, sentinel_address)?.to_vec());
+    }
+
+    let challenges = Challenges::new(challenges);
     if challenges.is_empty() {
         debug!("no challenges found in sub mat");
-        return Ok(());
+        return Ok(challenges);
     }
 
     let list = ChallengesList::get(db_utils);
-    list.add_challenges(db_utils, challenges)?;
-    Ok(())
+    list.add_challenges(db_utils, challenges.clone())?;
+    Ok(challenges)
 }
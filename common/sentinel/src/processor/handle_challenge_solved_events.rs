@@ -2,31 +2,35 @@ use std::result::Result;
 
 use common::DatabaseInterface;
 use common_eth::EthSubmissionMaterial;
-use ethereum_types::Address as EthAddress;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
 
 use crate::{ChallengeSolvedEvents, ChallengesList, SentinelDbUtils, SentinelError};
 
 pub(super) fn maybe_handle_challenge_solved_events<D: DatabaseInterface>(
     db_utils: &SentinelDbUtils<D>,
-    pnetwork_hub: &EthAddress,
+    pnetwork_hubs: &[EthAddress],
     sub_mat: &EthSubmissionMaterial,
     sentinel_address: &EthAddress,
-) -> Result<(), SentinelError> {
+) -> Result<Vec<EthHash>, SentinelError> {
     if sub_mat.receipts.is_empty() {
         debug!("no receipts in sub mat so not checking for new challenges");
-        return Ok(());
+        return Ok(vec![]);
     }
 
     // FIXME needs filtering by actor address etc
-    let ids = ChallengeSolvedEvents::from_sub_mat(sub_mat, pnetwork_hub, sentinel_address)?.to_ids()?;
+    let ids = pnetwork_hubs
+        .iter()
+        .map(|pnetwork_hub| Ok(ChallengeSolvedEvents::from_sub_mat(sub_mat, pnetwork_hub, sentinel_address)?.to_ids()?))
+        .collect::<Result<Vec<Vec<EthHash>>, SentinelError>>()?
+        .concat();
 
     if ids.is_empty() {
         debug!("no solved challenges found in sub mat");
-        return Ok(());
+        return Ok(ids);
     }
 
     let mut list = ChallengesList::get(db_utils);
-    list.update_challenge_statuses_to_solved(db_utils, ids)?;
+    list.update_challenge_statuses_to_solved(db_utils, ids.clone())?;
 
-    Ok(())
+    Ok(ids)
 }
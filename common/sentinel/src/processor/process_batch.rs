@@ -3,7 +3,7 @@ use std::result::Result;
 use common::DatabaseInterface;
 use common_eth::{Chain, ChainDbUtils, EthSubmissionMaterials};
 use common_network_ids::NetworkId;
-use ethereum_types::Address as EthAddress;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
 
 use super::{
     maybe_handle_actors_propagated_events,
@@ -11,11 +11,11 @@ use super::{
     maybe_handle_challenge_solved_events,
     process_single,
 };
-use crate::{ProcessorOutput, SentinelDbUtils, SentinelError, UserOps};
+use crate::{Challenges, PnetworkHubs, ProcessorOutput, SentinelDbUtils, SentinelError, UserOps};
 
 pub fn process_batch<D: DatabaseInterface>(
     db: &D,
-    pnetwork_hub: &EthAddress,
+    pnetwork_hubs: &PnetworkHubs,
     batch: &EthSubmissionMaterials,
     validate: bool,
     network_id: &NetworkId,
@@ -23,6 +23,7 @@ pub fn process_batch<D: DatabaseInterface>(
     dry_run: bool,
     maybe_governance_address: Option<EthAddress>,
     sentinel_address: EthAddress,
+    challenger_address: Option<EthAddress>,
 ) -> Result<ProcessorOutput, SentinelError> {
     info!("processing {network_id} batch of submission material...");
 
@@ -30,6 +31,8 @@ pub fn process_batch<D: DatabaseInterface>(
     let s_db_utils = SentinelDbUtils::new(db);
 
     let mut chain = Chain::get(&c_db_utils, network_id.try_into()?)?;
+    // NOTE: So that the chain retains the receipts pertaining to all of this network's hubs.
+    chain.set_other_hubs(&pnetwork_hubs.addresses());
 
     let use_db_tx = !dry_run;
 
@@ -50,13 +53,36 @@ pub fn process_batch<D: DatabaseInterface>(
         })?
     };
 
-    batch
-        .iter()
-        .try_for_each(|m| maybe_handle_challenge_pending_events(&s_db_utils, pnetwork_hub, m, &sentinel_address))?;
+    let pending_challenges = Challenges::new(
+        batch
+            .iter()
+            .map(|m| {
+                maybe_handle_challenge_pending_events(
+                    &s_db_utils,
+                    &pnetwork_hubs.active_at(m.get_block_number()?.as_u64()),
+                    m,
+                    &sentinel_address,
+                    &challenger_address,
+                )
+            })
+            .collect::<Result<Vec<Challenges>, SentinelError>>()?
+            .into_iter()
+            .flat_map(|cs| cs.to_vec())
+            .collect(),
+    );
 
-    batch
+    let solved_challenge_ids = batch
         .iter()
-        .try_for_each(|m| maybe_handle_challenge_solved_events(&s_db_utils, pnetwork_hub, m, &sentinel_address))?;
+        .map(|m| {
+            maybe_handle_challenge_solved_events(
+                &s_db_utils,
+                &pnetwork_hubs.active_at(m.get_block_number()?.as_u64()),
+                m,
+                &sentinel_address,
+            )
+        })
+        .collect::<Result<Vec<Vec<EthHash>>, SentinelError>>()?
+        .concat();
 
     let processed_user_ops = UserOps::from(
         batch
@@ -65,7 +91,7 @@ pub fn process_batch<D: DatabaseInterface>(
                 process_single(
                     db,
                     sub_mat.clone(),
-                    pnetwork_hub,
+                    pnetwork_hubs,
                     validate,
                     use_db_tx,
                     dry_run,
@@ -78,6 +104,12 @@ pub fn process_batch<D: DatabaseInterface>(
     );
 
     info!("finished processing {network_id} submission material");
-    let r = ProcessorOutput::new(*network_id, batch.get_last_block_num()?, processed_user_ops)?;
+    let r = ProcessorOutput::new(
+        *network_id,
+        batch.get_last_block_num()?,
+        processed_user_ops,
+        pending_challenges,
+        solved_challenge_ids,
+    )?;
     Ok(r)
 }
@@ -3,14 +3,13 @@ use std::result::Result;
 use common::DatabaseInterface;
 use common_eth::{Chain, ChainDbUtils, EthSubmissionMaterial};
 use common_network_ids::NetworkId;
-use ethereum_types::Address as EthAddress;
 
-use crate::{SentinelDbUtils, SentinelError, UserOpList, UserOps};
+use crate::{PnetworkHubs, SentinelDbUtils, SentinelError, UserOpList, UserOps};
 
 pub(super) fn process_single<D: DatabaseInterface>(
     db: &D,
     sub_mat: EthSubmissionMaterial,
-    pnetwork_hub: &EthAddress,
+    pnetwork_hubs: &PnetworkHubs,
     validate: bool,
     _use_db_tx: bool,
     dry_run: bool,
@@ -69,7 +68,16 @@ pub(super) fn process_single<D: DatabaseInterface>(
         return Ok(UserOps::empty());
     }
 
-    let ops = UserOps::from_sub_mat(network_id, pnetwork_hub, &canonical_sub_mat)?;
+    // NOTE: The canonical block may be older than the one just inserted, so we check which hubs
+    // were active as of its own block number.
+    let canonical_block_num = canonical_sub_mat.get_block_number()?.as_u64();
+    let ops = UserOps::from(
+        pnetwork_hubs
+            .active_at(canonical_block_num)
+            .iter()
+            .map(|pnetwork_hub| UserOps::from_sub_mat(network_id, pnetwork_hub, &canonical_sub_mat))
+            .collect::<Result<Vec<UserOps>, SentinelError>>()?,
+    );
     debug!("found user ops: {ops}");
 
     let sentinel_db_utils = SentinelDbUtils::new(db);
@@ -2,10 +2,11 @@ use std::fmt;
 
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
+use ethereum_types::H256 as EthHash;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
 
-use crate::{get_utc_timestamp, SentinelError, UserOps};
+use crate::{get_utc_timestamp, Challenges, SentinelError, UserOps};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Getters)]
 pub struct ProcessorOutput {
@@ -13,6 +14,11 @@ pub struct ProcessorOutput {
     network_id: NetworkId,
     latest_block_num: u64,
     processed_user_ops: UserOps,
+    // NOTE: Challenges against this sentinel found in the batch, used for event notifications.
+    #[serde(default)]
+    pending_challenges: Challenges,
+    #[serde(default)]
+    solved_challenge_ids: Vec<EthHash>,
 }
 
 impl ProcessorOutput {
@@ -20,11 +26,15 @@ impl ProcessorOutput {
         network_id: NetworkId,
         latest_block_num: u64,
         processed_user_ops: UserOps,
+        pending_challenges: Challenges,
+        solved_challenge_ids: Vec<EthHash>,
     ) -> Result<Self, SentinelError> {
         Ok(Self {
             network_id,
             latest_block_num,
             processed_user_ops,
+            pending_challenges,
+            solved_challenge_ids,
             timestamp: get_utc_timestamp()?,
         })
     }
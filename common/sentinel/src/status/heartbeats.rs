@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use derive_more::{Constructor, Deref};
+use ethereum_types::Address as EthAddress;
+
+use super::SentinelStatus;
+
+/// Tracks the latest status heartbeat timestamp seen from each actor, keyed by the actor's signing
+/// address.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Constructor, Deref)]
+pub struct Heartbeats(HashMap<EthAddress, u64>);
+
+impl Heartbeats {
+    /// Records the passed in status' timestamp against its signer, so long as the status verifies
+    /// and is newer than any heartbeat already seen from that signer.
+    pub fn record(&mut self, status: &SentinelStatus) {
+        match status.verify() {
+            Err(e) => warn!("not recording heartbeat from unverifiable status: {e}"),
+            Ok(signer) => {
+                let timestamp = status.timestamp();
+                let latest = self.0.entry(signer).or_default();
+                if timestamp > *latest {
+                    *latest = timestamp;
+                }
+            },
+        }
+    }
+
+    pub fn last_seen(&self, address: &EthAddress) -> Option<u64> {
+        self.0.get(address).cloned()
+    }
+
+    /// An actor is stale if it's never been heard from, or if its last heartbeat is older than the
+    /// passed in threshold.
+    pub fn is_stale(&self, address: &EthAddress, threshold: u64, now: u64) -> bool {
+        match self.last_seen(address) {
+            Some(t) => now.saturating_sub(t) > threshold,
+            None => true,
+        }
+    }
+
+    /// Whether a non-stale heartbeat has been seen from any actor other than those passed in. If
+    /// not, it's more likely that the heartbeat listener isn't receiving statuses than that every
+    /// other actor has gone quiet.
+    pub fn has_recent_heartbeat_from_others(&self, own_addresses: &[EthAddress], threshold: u64, now: u64) -> bool {
+        self.0
+            .keys()
+            .filter(|address| !own_addresses.contains(address))
+            .any(|address| !self.is_stale(address, threshold, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use common_eth::EthPrivateKey;
+
+    use super::*;
+
+    #[test]
+    fn should_record_heartbeat_and_determine_staleness() {
+        let pk = EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap();
+        let address = pk.to_address();
+        let status = SentinelStatus::new(&pk, vec![], crate::BalanceRunways::default()).unwrap();
+        let mut heartbeats = Heartbeats::default();
+        let threshold = 60;
+        assert!(heartbeats.is_stale(&address, threshold, status.timestamp()));
+        heartbeats.record(&status);
+        assert_eq!(heartbeats.last_seen(&address), Some(status.timestamp()));
+        assert!(!heartbeats.is_stale(&address, threshold, status.timestamp() + threshold));
+        assert!(heartbeats.is_stale(&address, threshold, status.timestamp() + threshold + 1));
+    }
+
+    #[test]
+    fn should_only_have_recent_heartbeat_from_others_if_non_own_actor_is_not_stale() {
+        let pk = EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap();
+        let address = pk.to_address();
+        let status = SentinelStatus::new(&pk, vec![], crate::BalanceRunways::default()).unwrap();
+        let mut heartbeats = Heartbeats::default();
+        let threshold = 60;
+        let now = status.timestamp();
+        assert!(!heartbeats.has_recent_heartbeat_from_others(&[], threshold, now));
+        heartbeats.record(&status);
+        assert!(heartbeats.has_recent_heartbeat_from_others(&[], threshold, now));
+        assert!(!heartbeats.has_recent_heartbeat_from_others(&[address], threshold, now));
+        assert!(!heartbeats.has_recent_heartbeat_from_others(&[], threshold, now + threshold + 1));
+    }
+}
@@ -1,3 +1,7 @@
+mod heartbeats;
 mod status;
 
-pub use self::status::{SentinelStatus, SentinelStatusError};
+pub use self::{
+    heartbeats::Heartbeats,
+    status::{SentinelStatus, SentinelStatusError},
+};
@@ -1,8 +1,10 @@
 use std::{
     collections::{BTreeMap, HashMap},
     fmt,
+    str::FromStr,
 };
 
+use common::crypto_utils::keccak_hash_bytes;
 use common_eth::{Chain, ChainBlockData, EthPrivateKey, EthSignature, EthSigningCapabilities};
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
@@ -12,7 +14,7 @@ use serde::{Deserialize, Serialize, Serializer};
 use serde_json::json;
 use thiserror::Error;
 
-use crate::{get_utc_timestamp, SentinelError, WebSocketMessagesEncodable};
+use crate::{get_utc_timestamp, BalanceRunways, SentinelError, WebSocketMessagesEncodable};
 
 /* JSON Reference:
 {
@@ -53,6 +55,15 @@ pub enum SentinelStatusError {
 
     #[error("status metadata chain id error: {0}")]
     MetadataChainId(#[from] common_metadata::MetadataChainIdError),
+
+    #[error("cannot verify status, no signature present")]
+    NoSignature,
+
+    #[error("could not verify `SentinelStatus`: {0}")]
+    VerificationError(String),
+
+    #[error("status signed by {signer} but claims to be from {claimed}")]
+    WrongSigner { signer: EthAddress, claimed: EthAddress },
 }
 
 #[derive(Clone, Default, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
@@ -107,6 +118,10 @@ impl TryFrom<&Chain> for SyncStatus {
 #[serde(rename_all = "camelCase")]
 pub struct SentinelStatus {
     actor_type: String,
+    // NOTE: Only present when a broadcaster balance is running low, so that statuses otherwise
+    // remain identical to those of other actors.
+    #[serde(default, skip_serializing_if = "BalanceRunways::is_empty")]
+    balance_warnings: BalanceRunways,
     signer_address: String,
     #[serde(serialize_with = "ordered_map")]
     software_versions: HashMap<String, String>,
@@ -141,7 +156,12 @@ impl TryFrom<WebSocketMessagesEncodable> for SentinelStatus {
 }
 
 impl SentinelStatus {
-    fn init(signer: EthAddress, git_commit_hash: String, chains: Vec<Chain>) -> Result<Self, SentinelError> {
+    fn init(
+        signer: EthAddress,
+        git_commit_hash: String,
+        chains: Vec<Chain>,
+        balance_warnings: BalanceRunways,
+    ) -> Result<Self, SentinelError> {
         let version = 0;
         let signature = None;
         let timestamp = get_utc_timestamp()?;
@@ -164,6 +184,7 @@ impl SentinelStatus {
             sync_state,
             timestamp,
             actor_type,
+            balance_warnings,
             signer_address,
             signature,
             software_versions,
@@ -190,10 +211,55 @@ impl SentinelStatus {
         }
     }
 
-    pub fn new(pk: &EthPrivateKey, chains: Vec<Chain>) -> Result<Self, SentinelError> {
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn balance_warnings(&self) -> &BalanceRunways {
+        &self.balance_warnings
+    }
+
+    pub fn signer_address(&self) -> Result<EthAddress, SentinelStatusError> {
+        EthAddress::from_str(&self.signer_address).map_err(|e| SentinelStatusError::VerificationError(format!("{e}")))
+    }
+
+    fn get_eth_prefixed_hash(&self) -> EthHash {
+        // NOTE: The signature is over the status json _without_ a signature field, which is the
+        // pre-image the ethers js signing fxn (& ours) prefixes with the eth message prefix.
+        let unsigned = Self {
+            signature: None,
+            ..self.clone()
+        };
+        let msg = unsigned.to_string();
+        let prefix: &[u8] = b"\x19Ethereum Signed Message:\n";
+        keccak_hash_bytes(&[prefix, format!("{}", msg.len()).as_bytes(), msg.as_bytes()].concat())
+    }
+
+    /// Verifies that this status was signed by the address it claims to be from, returning that
+    /// address if so.
+    pub fn verify(&self) -> Result<EthAddress, SentinelStatusError> {
+        let sig = match self.signature {
+            Some(ref s) => {
+                EthSignature::from_str(s).map_err(|e| SentinelStatusError::VerificationError(format!("{e}")))?
+            },
+            None => return Err(SentinelStatusError::NoSignature),
+        };
+        let claimed = self.signer_address()?;
+        let signer = sig
+            .recover_signer_address(&self.get_eth_prefixed_hash())
+            .map_err(|e| SentinelStatusError::VerificationError(format!("{e}")))?;
+        if signer == claimed {
+            Ok(signer)
+        } else {
+            Err(SentinelStatusError::WrongSigner { signer, claimed })
+        }
+    }
+
+    /// Creates a signed status, including any of the passed in balance runways which are running low.
+    pub fn new(pk: &EthPrivateKey, chains: Vec<Chain>, balance_runways: BalanceRunways) -> Result<Self, SentinelError> {
         let git_commit_hash = GitCommitHashStruct {}.get_build_commit_long().to_string();
         let signer = pk.to_address();
-        let mut status = Self::init(signer, git_commit_hash, chains)?;
+        let mut status = Self::init(signer, git_commit_hash, chains, balance_runways.low())?;
         let sig = status.sign(pk)?;
         status.add_signature(sig);
         Ok(status)
@@ -217,7 +283,8 @@ mod tests {
         let git_commit_hash = "some static string so we can assert the signature".to_string();
         let pk = EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap();
         let chains = vec![];
-        let mut status = SentinelStatus::init(pk.to_address(), git_commit_hash, chains).unwrap();
+        let mut status =
+            SentinelStatus::init(pk.to_address(), git_commit_hash, chains, BalanceRunways::default()).unwrap();
         status.timestamp = 1337; // NOTE: So the signature becomes deterministic
         let sig = status.sign(&pk).unwrap();
         status.add_signature(sig);
@@ -226,6 +293,36 @@ mod tests {
         assert_eq!(status.signature, expected_signature);
     }
 
+    #[test]
+    fn should_verify_signed_status() {
+        let pk = EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap();
+        let status = SentinelStatus::new(&pk, vec![], BalanceRunways::default()).unwrap();
+        assert_eq!(status.verify().unwrap(), pk.to_address());
+    }
+
+    #[test]
+    fn should_fail_to_verify_tampered_status() {
+        let pk = EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap();
+        let mut status = SentinelStatus::new(&pk, vec![], BalanceRunways::default()).unwrap();
+        status.timestamp += 1;
+        assert!(matches!(status.verify(), Err(SentinelStatusError::WrongSigner { .. })));
+    }
+
+    #[test]
+    fn should_only_include_low_balance_runways_in_signed_status() {
+        let pk = EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap();
+        let nid = NetworkId::default();
+        let low = crate::BalanceRunway::new(nid, ethereum_types::U256::from(1), 1, 2, 1);
+        let fine = crate::BalanceRunway::new(nid, ethereum_types::U256::from(10), 1, 2, 1);
+        let runways = BalanceRunways::new(vec![low.clone(), fine]);
+        let status = SentinelStatus::new(&pk, vec![], runways).unwrap();
+        assert_eq!(status.balance_warnings(), &BalanceRunways::new(vec![low]));
+        assert!(status.to_string().contains("balanceWarnings"));
+        assert_eq!(status.verify().unwrap(), pk.to_address());
+        let status = SentinelStatus::new(&pk, vec![], BalanceRunways::default()).unwrap();
+        assert!(!status.to_string().contains("balanceWarnings"));
+    }
+
     #[test]
     fn should_match_javascript_signatures_made_by_guardians() {
         // NOTE: See reference json at top of file
@@ -245,6 +342,7 @@ mod tests {
 
         let status = SentinelStatus {
             actor_type: "guardian".to_string(),
+            balance_warnings: BalanceRunways::default(),
             signer_address: "0x89E8cf56bc3B6C492098e46Da2686c9B5D56951f".to_string(),
             software_versions,
             sync_state,
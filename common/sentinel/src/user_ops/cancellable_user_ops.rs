@@ -3,11 +3,18 @@ use common_chain_ids::EthChainId;
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
 use derive_more::{Constructor, Deref, DerefMut};
-use ethereum_types::H256 as EthHash;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
 use serde::{Deserialize, Serialize};
 
 use super::{UserOp, UserOpError, UserOpList, UserOpState, UserOpStates, UserOps, USER_OP_CANCEL_TX_GAS_LIMIT};
-use crate::{LatestBlockInfos, SentinelDbUtils, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
+use crate::{
+    DbUtilsT,
+    LatestBlockInfos,
+    SentinelDbUtils,
+    SentinelError,
+    WebSocketMessagesEncodable,
+    WebSocketMessagesError,
+};
 
 #[derive(Clone, Debug, Eq, Default, PartialEq, Serialize, Deserialize, Constructor, Deref, DerefMut)]
 pub struct CancellableUserOps(Vec<CancellableUserOp>);
@@ -70,6 +77,10 @@ impl CancellableUserOp {
         self.enqueued_state().map(|s| s.network_id())
     }
 
+    pub fn pnetwork_hub_to_cancel_on(&self) -> Result<Option<EthAddress>, UserOpError> {
+        self.enqueued_state().map(|s| s.pnetwork_hub())
+    }
+
     pub fn network_id_to_cancel_on(&self) -> Result<NetworkId, UserOpError> {
         self.enqueued_network_id()
     }
@@ -78,6 +89,32 @@ impl CancellableUserOp {
         self.enqueued_state().and_then(|s| s.block_timestamp())
     }
 
+    /// A forwarded op's hops are linked once the sentinel has seen both, which can only happen
+    /// once the earlier hop has been executed. Thus an op with a next hop has already moved its
+    /// assets on downstream, whilst one with an executed previous hop has a proven origin, even if
+    /// its own user send has yet to be seen, so neither should be cancelled.
+    fn hops_permit_cancellation<D: DatabaseInterface>(&self, db_utils: &SentinelDbUtils<D>) -> bool {
+        let uid = self.op().uid().unwrap_or_default();
+        if let Some(next_hop) = self.op().next_hop() {
+            warn!("cannot cancel user op {uid} since its next hop {next_hop} has been seen downstream");
+            return false;
+        };
+        match self.op().previous_hop() {
+            None => true,
+            Some(previous_hop) => match UserOp::get_from_db(db_utils, &(*previous_hop).into()) {
+                Ok(op) if op.has_not_been_executed() => true,
+                Ok(_) => {
+                    warn!("cannot cancel user op {uid} since its previous hop {previous_hop} has been executed");
+                    false
+                },
+                Err(e) => {
+                    warn!("cannot cancel user op {uid} since its previous hop {previous_hop} cannot be got: {e}");
+                    false
+                },
+            },
+        }
+    }
+
     fn origin_chain_is_in_sync(&self, latest_block_infos: &LatestBlockInfos) -> bool {
         info!("checking if user op is cancellable w/r/t origin chain sync status...");
         // NOTE: To account for block timestamps not being entirely reliable & help during race conditions
@@ -137,6 +174,7 @@ impl CancellableUserOps {
             Self::from(list.get_up_to_last_x_ops(db_utils, NUM_PAST_OPS_TO_CHECK_FOR_CANCELLABILITY)?)
                 .iter()
                 .filter(|cancellable_op| cancellable_op.origin_chain_is_in_sync(&latest_block_infos))
+                .filter(|cancellable_op| cancellable_op.hops_permit_cancellation(db_utils))
                 .cloned()
                 .collect::<Vec<CancellableUserOp>>()
                 .into(),
@@ -277,7 +315,13 @@ mod tests {
     use ethereum_types::H256 as EthHash;
 
     use super::{UserOp, UserOpList, *};
-    use crate::{get_utc_timestamp, ActorType, LatestBlockInfo, SentinelDbUtils};
+    use crate::{
+        get_utc_timestamp,
+        user_ops::test_utils::get_sample_linked_hops,
+        ActorType,
+        LatestBlockInfo,
+        SentinelDbUtils,
+    };
 
     #[test]
     fn should_get_cancellable_user_ops() {
@@ -352,6 +396,33 @@ mod tests {
         assert!(r5.is_empty());
     }
 
+    #[test]
+    fn should_not_get_cancellable_op_whose_previous_hop_has_been_executed() {
+        let db = get_test_database();
+        let db_utils = SentinelDbUtils::new(&db);
+        let (first_hop, mut second_hop) = get_sample_linked_hops();
+        let enqueued_timestamp = get_utc_timestamp().unwrap();
+        let enqueued_network_id = NetworkId::try_from("ethereum").unwrap();
+        second_hop.state = UserOpState::enqueued(enqueued_network_id, EthHash::random(), enqueued_timestamp);
+        let polygon_latest_block_info = LatestBlockInfo {
+            network_id: *second_hop.origin_network_id(),
+            block_timestamp: enqueued_timestamp + (60 * 60),
+            ..Default::default()
+        };
+        let latest_block_infos = LatestBlockInfos::new(vec![polygon_latest_block_info]);
+        let mut list = UserOpList::default();
+
+        // NOTE: Alone, the enqueued but not witnessed second hop is cancellable...
+        list.process_op(second_hop.clone(), &db_utils).unwrap();
+        let r1 = CancellableUserOps::get(&db_utils, latest_block_infos.clone()).unwrap();
+        assert_eq!(r1.len(), 1);
+
+        // NOTE: ...but not once it's linked to the executed first hop it was forwarded from.
+        list.process_op(first_hop, &db_utils).unwrap();
+        let r2 = CancellableUserOps::get(&db_utils, latest_block_infos).unwrap();
+        assert!(r2.is_empty());
+    }
+
     #[test]
     fn should_get_multiple_cancellabe_ops_for_single_user_op_queued_on_multiple_chains() {
         let db = get_test_database();
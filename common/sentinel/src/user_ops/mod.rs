@@ -2,12 +2,14 @@ mod cancel_user_op;
 mod cancellable_user_ops;
 pub(crate) mod test_utils;
 mod user_op;
+mod user_op_backfill_report;
 mod user_op_cancellation_signature;
 mod user_op_constants;
 mod user_op_error;
 mod user_op_flag;
 mod user_op_list;
 mod user_op_log;
+mod user_op_route;
 mod user_op_smart_contract_state;
 mod user_op_state;
 mod user_op_uid;
@@ -18,9 +20,11 @@ pub(crate) use self::user_op_constants::CANCELLED_USER_OP_TOPIC;
 pub use self::{
     cancellable_user_ops::{CancellableUserOp, CancellableUserOps},
     user_op::UserOp,
+    user_op_backfill_report::{BackfillReport, UserOpChange},
     user_op_cancellation_signature::UserOpCancellationSignature,
     user_op_error::UserOpError,
     user_op_list::UserOpList,
+    user_op_route::{UserOpRoute, UserOpRouteHop},
     user_op_smart_contract_state::UserOpSmartContractState,
     user_op_uid::UserOpUniqueId,
     user_ops::UserOps,
@@ -2,6 +2,10 @@
 use std::{fs::read_to_string, str::FromStr};
 
 use common_eth::{EthLog, EthSubmissionMaterial};
+use common_network_ids::NetworkId;
+use ethereum_types::{H256 as EthHash, U256};
+
+use super::{UserOp, UserOpState};
 
 pub fn get_sample_sub_mat_n(n: usize) -> EthSubmissionMaterial {
     let suffix = match n {
@@ -50,3 +54,33 @@ pub(crate) fn get_log_with_protocol_cancellation_log() -> EthLog {
 pub fn get_sub_mat_with_protocol_cancellation_log() -> EthSubmissionMaterial {
     get_sample_sub_mat_n(5)
 }
+
+// NOTE: Returns the ops for the two hops of an op forwarded from bsc via polygon to ethereum,
+// where the second hop's op originates in the tx which executed the first's.
+pub(crate) fn get_sample_linked_hops() -> (UserOp, UserOp) {
+    let bsc = NetworkId::try_from("bsc").unwrap();
+    let polygon = NetworkId::try_from("polygon").unwrap();
+    let ethereum = NetworkId::try_from("ethereum").unwrap();
+    let executed_tx_hash = EthHash::from_low_u64_be(3);
+
+    let mut first_hop = UserOp::default();
+    first_hop.origin_network_id = bsc;
+    first_hop.user_op_log.origin_network_id = bsc;
+    first_hop.user_op_log.destination_network_id = polygon;
+    first_hop.user_op_log.forward_destination_network_id = ethereum;
+    first_hop.user_op_log.origin_transaction_hash = Some(EthHash::from_low_u64_be(1));
+    first_hop.previous_states = vec![UserOpState::witnessed(bsc, EthHash::from_low_u64_be(1), 1)];
+    first_hop.state = UserOpState::executed(polygon, executed_tx_hash, 2);
+    first_hop.uid = first_hop.uid().unwrap();
+
+    let mut second_hop = UserOp::default();
+    second_hop.origin_network_id = polygon;
+    second_hop.user_op_log.nonce = U256::from(1);
+    second_hop.user_op_log.origin_network_id = polygon;
+    second_hop.user_op_log.destination_network_id = ethereum;
+    second_hop.user_op_log.origin_transaction_hash = Some(executed_tx_hash);
+    second_hop.state = UserOpState::witnessed(polygon, executed_tx_hash, 2);
+    second_hop.uid = second_hop.uid().unwrap();
+
+    (first_hop, second_hop)
+}
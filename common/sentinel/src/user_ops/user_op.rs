@@ -11,7 +11,7 @@ use serde_with::{serde_as, DisplayFromStr};
 use sha2::{Digest, Sha256};
 
 use super::{UserOpError, UserOpFlag, UserOpLog, UserOpState, UserOpVersion};
-use crate::{DbKey, DbUtilsT, SentinelError};
+use crate::{DbKey, DbUtilsT, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
 
 impl DbUtilsT for UserOp {
     fn key(&self) -> Result<DbKey, SentinelError> {
@@ -51,6 +51,11 @@ pub struct UserOp {
     #[serde_as(as = "DisplayFromStr")]
     pub(super) origin_network_id: NetworkId,
     pub(super) previous_states: Vec<UserOpState>,
+    // NOTE: The uids of the ops either side of this one in a multi-hop forwarded route, if any.
+    #[serde(default)]
+    pub(super) previous_hop: Option<EthHash>,
+    #[serde(default)]
+    pub(super) next_hop: Option<EthHash>,
 }
 
 impl PartialEq for UserOp {
@@ -108,6 +113,31 @@ impl UserOp {
         Ok("".to_string())
     }
 
+    fn was_executed_in_tx(&self, h: &EthHash) -> bool {
+        (self.state.is_executed() && self.state.tx_hash() == *h)
+            || self
+                .previous_states
+                .iter()
+                .any(|s| s.is_executed() && s.tx_hash() == *h)
+    }
+
+    pub fn is_forwarded(&self) -> bool {
+        self.user_op_log.forward_destination_network_id != NetworkId::default()
+    }
+
+    /// A forwarded op's execution on its destination emits a user send there, which becomes the
+    /// op for the next hop, & which thus originates in the tx that executed this one.
+    pub(super) fn is_previous_hop_of(&self, other: &Self) -> bool {
+        self.is_forwarded()
+            && other.origin_network_id == self.destination_network_id()
+            && other.destination_network_id() == self.user_op_log.forward_destination_network_id
+            && other
+                .user_op_log
+                .origin_transaction_hash()
+                .map(|h| self.was_executed_in_tx(&h))
+                .unwrap_or_default()
+    }
+
     fn has_been_executed(&self) -> bool {
         if self.state.is_executed() {
             true
@@ -120,6 +150,14 @@ impl UserOp {
         !self.has_been_executed()
     }
 
+    /// The hub which emitted the most recent event for this op on the given network, if known.
+    pub fn pnetwork_hub_on(&self, nid: &NetworkId) -> Option<EthAddress> {
+        std::iter::once(&self.state)
+            .chain(self.previous_states.iter().rev())
+            .filter(|s| s.network_id() == *nid)
+            .find_map(|s| s.pnetwork_hub())
+    }
+
     pub fn to_flag(&self) -> UserOpFlag {
         self.into()
     }
@@ -128,6 +166,13 @@ impl UserOp {
         *self.user_op_log().destination_network_id()
     }
 
+    /// Whether the uid of the user op in the passed in log depends on the hashes of the block & tx
+    /// the log is from, which is the case for witnessed user ops since their logs lack them.
+    pub fn uid_requires_origin_hashes(log: &EthLog) -> Result<bool, UserOpError> {
+        let user_op_log = UserOpLog::try_from(log)?;
+        Ok(user_op_log.origin_block_hash().is_err() || user_op_log.origin_transaction_hash().is_err())
+    }
+
     pub fn from_log(
         witnessed_timestamp: u64,
         block_timestamp: u64,
@@ -156,6 +201,8 @@ impl UserOp {
             origin_network_id: *user_op_log.origin_network_id(),
             state: UserOpState::try_from_log(*origin_network_id, tx_hash, block_timestamp, log)?,
             user_op_log,
+            previous_hop: None,
+            next_hop: None,
         };
 
         let uid = op.uid()?;
@@ -320,6 +367,21 @@ impl UserOp {
     }
 }
 
+impl TryFrom<WebSocketMessagesEncodable> for UserOp {
+    type Error = SentinelError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        match m {
+            WebSocketMessagesEncodable::Success(json) => Ok(serde_json::from_value(json)?),
+            other => Err(WebSocketMessagesError::CannotConvert {
+                from: format!("{other}"),
+                to: "UserOp".to_string(),
+            }
+            .into()),
+        }
+    }
+}
+
 impl fmt::Display for UserOp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match serde_json::to_string_pretty(self) {
@@ -338,6 +400,8 @@ mod tests {
     use super::*;
     use crate::user_ops::{
         test_utils::{
+            get_sample_log_with_protocol_queue,
+            get_sample_log_with_user_send,
             get_sample_submission_material_with_protocol_queue_2,
             get_sample_submission_material_with_user_send,
             get_sub_mat_with_protocol_cancellation_log,
@@ -373,6 +437,8 @@ mod tests {
         let uid = op.uid_hex().unwrap();
         let expected_uid = "0x50d0d882be1781e777469cd07322c84fd4652d7ee3cbd323bb3539164a3708e9";
         assert_eq!(uid, expected_uid);
+        assert_eq!(op.pnetwork_hub_on(&origin_network_id), Some(pnetwork_hub));
+        assert_eq!(op.pnetwork_hub_on(&NetworkId::default()), None);
         let r = op.to_tuple_string();
         assert!(r.is_ok());
     }
@@ -402,4 +468,10 @@ mod tests {
         let uid = ops[0].uid().unwrap();
         assert_eq!(uid, *expected_uid);
     }
+
+    #[test]
+    fn should_only_require_origin_hashes_for_uid_of_witnessed_user_op() {
+        assert!(UserOp::uid_requires_origin_hashes(&get_sample_log_with_user_send()).unwrap());
+        assert!(!UserOp::uid_requires_origin_hashes(&get_sample_log_with_protocol_queue()).unwrap());
+    }
 }
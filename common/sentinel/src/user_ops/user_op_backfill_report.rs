@@ -0,0 +1,103 @@
+use std::fmt;
+
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use super::{UserOpError, UserOpState, UserOps};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct UserOpChange {
+    uid: EthHash,
+    previous_state: UserOpState,
+    state: UserOpState,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct BackfillReport {
+    network_id: NetworkId,
+    from: u64,
+    to: u64,
+    num_user_ops: usize,
+    added: Vec<EthHash>,
+    changed: Vec<UserOpChange>,
+}
+
+impl BackfillReport {
+    pub fn new(network_id: NetworkId, from: u64, to: u64) -> Self {
+        Self {
+            network_id,
+            from,
+            to,
+            num_user_ops: 0,
+            added: vec![],
+            changed: vec![],
+        }
+    }
+
+    /// Compares the core's copies of some user ops from before & after they were reprocessed,
+    /// recording any that were missing from the core beforehand, or whose state has since changed.
+    pub fn update(&mut self, before: &UserOps, after: &UserOps) -> Result<(), UserOpError> {
+        for op in after.iter() {
+            let uid = op.uid()?;
+            self.num_user_ops += 1;
+            match before.get(&uid.into()) {
+                Err(UserOpError::NoUserOp(_)) => self.added.push(uid),
+                Err(e) => return Err(e),
+                Ok(previous) if previous.state() != op.state() => self.changed.push(UserOpChange {
+                    uid,
+                    previous_state: *previous.state(),
+                    state: *op.state(),
+                }),
+                Ok(_) => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BackfillReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match serde_json::to_string_pretty(self) {
+            Ok(s) => s,
+            Err(e) => format!("could not fmt `BackfillReport` {e}"),
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_ops::{UserOp, UserOpStateInfo};
+
+    fn get_sample_op(s: &str) -> UserOp {
+        let mut op = UserOp::default();
+        op.set_destination_account(s.into());
+        op
+    }
+
+    #[test]
+    fn should_classify_added_changed_and_unchanged_user_ops() {
+        let unchanged = get_sample_op("unchanged");
+        let added = get_sample_op("added");
+        let changed_before = get_sample_op("changed");
+        let mut changed_after = changed_before.clone();
+        changed_after.state = UserOpState::Enqueued(UserOpStateInfo::default());
+        assert_ne!(changed_before.state(), changed_after.state());
+
+        let before = UserOps::new(vec![unchanged.clone(), changed_before.clone()]);
+        let after = UserOps::new(vec![unchanged, added.clone(), changed_after.clone()]);
+        let mut report = BackfillReport::new(NetworkId::default(), 1, 10);
+        report.update(&before, &after).unwrap();
+
+        assert_eq!(*report.num_user_ops(), 3);
+        assert_eq!(report.added(), &vec![added.uid().unwrap()]);
+        assert_eq!(report.changed(), &vec![UserOpChange {
+            uid: changed_after.uid().unwrap(),
+            previous_state: *changed_before.state(),
+            state: *changed_after.state(),
+        }]);
+    }
+}
@@ -12,6 +12,8 @@ use crate::{
     SentinelDbUtils,
     SentinelError,
     UserOpUniqueId,
+    WebSocketMessagesEncodable,
+    WebSocketMessagesError,
 };
 
 #[derive(Clone, Copy, Debug, Default, Eq, Serialize, Deserialize, Constructor)]
@@ -70,6 +72,21 @@ impl fmt::Display for UserOpList {
     }
 }
 
+impl TryFrom<WebSocketMessagesEncodable> for UserOpList {
+    type Error = SentinelError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        match m {
+            WebSocketMessagesEncodable::Success(json) => Ok(serde_json::from_value(json)?),
+            other => Err(WebSocketMessagesError::CannotConvert {
+                from: format!("{other}"),
+                to: "UserOpList".to_string(),
+            }
+            .into()),
+        }
+    }
+}
+
 impl DbUtilsT for UserOpList {
     fn key(&self) -> Result<DbKey, SentinelError> {
         Ok(USER_OP_LIST.clone())
@@ -193,17 +210,62 @@ impl UserOpList {
         Ok(())
     }
 
+    fn link_hops<D: DatabaseInterface>(
+        db_utils: &SentinelDbUtils<D>,
+        mut previous_hop: UserOp,
+        mut next_hop: UserOp,
+    ) -> Result<(), UserOpError> {
+        let previous_uid = previous_hop.uid()?;
+        let next_uid = next_hop.uid()?;
+        if previous_hop.next_hop == Some(next_uid) && next_hop.previous_hop == Some(previous_uid) {
+            return Ok(());
+        };
+        info!("linking user op {previous_uid} to its next hop {next_uid}");
+        previous_hop.next_hop = Some(next_uid);
+        next_hop.previous_hop = Some(previous_uid);
+        previous_hop.update_in_db(db_utils)?;
+        next_hop.update_in_db(db_utils)?;
+        Ok(())
+    }
+
+    fn maybe_link_hops<D: DatabaseInterface>(
+        &self,
+        db_utils: &SentinelDbUtils<D>,
+        uid: &EthHash,
+    ) -> Result<(), UserOpError> {
+        // NOTE: The op for a hop may be seen before or after that of the hop preceding it, since the
+        // former's user send & the latter's execution are emitted in the same tx, so we check both
+        // directions, but only amongst recent ops since hops are processed close together.
+        const NUM_PAST_OPS_TO_CHECK_FOR_HOPS: usize = 100;
+        let op = UserOp::get_from_db(db_utils, &(*uid).into())?;
+        for other in self
+            .get_up_to_last_x_ops(db_utils, NUM_PAST_OPS_TO_CHECK_FOR_HOPS)?
+            .iter()
+        {
+            if &other.uid()? == uid {
+                continue;
+            } else if other.is_previous_hop_of(&op) {
+                Self::link_hops(db_utils, other.clone(), UserOp::get_from_db(db_utils, &(*uid).into())?)?;
+            } else if op.is_previous_hop_of(other) {
+                Self::link_hops(db_utils, UserOp::get_from_db(db_utils, &(*uid).into())?, other.clone())?;
+            };
+        }
+        Ok(())
+    }
+
     // NOTE: Public to the crate for use in tests
     pub(crate) fn process_op<D: DatabaseInterface>(
         &mut self,
         op: UserOp,
         db_utils: &SentinelDbUtils<D>,
     ) -> Result<(), UserOpError> {
-        if let Some(entry) = self.get_entry(&op.uid()?) {
-            self.handle_is_in_list(db_utils, op, entry)
+        let uid = op.uid()?;
+        if let Some(entry) = self.get_entry(&uid) {
+            self.handle_is_in_list(db_utils, op, entry)?;
         } else {
-            self.handle_is_not_in_list(db_utils, op)
-        }
+            self.handle_is_not_in_list(db_utils, op)?;
+        };
+        self.maybe_link_hops(db_utils, &uid)
     }
 
     pub fn process_ops<D: DatabaseInterface>(
@@ -282,7 +344,7 @@ mod tests {
     use common::get_test_database;
 
     use super::*;
-    use crate::SentinelDbUtils;
+    use crate::{user_ops::test_utils::get_sample_linked_hops, SentinelDbUtils};
 
     #[test]
     fn should_put_and_get_user_op_list_in_db() {
@@ -299,6 +361,26 @@ mod tests {
         assert_eq!(list_from_db, list);
     }
 
+    #[test]
+    fn should_link_hops_of_forwarded_op_regardless_of_processing_order() {
+        let db = get_test_database();
+        let db_utils = SentinelDbUtils::new(&db);
+        let (first_hop, second_hop) = get_sample_linked_hops();
+        let first_uid = first_hop.uid().unwrap();
+        let second_uid = second_hop.uid().unwrap();
+        assert!(first_hop.is_previous_hop_of(&second_hop));
+        assert!(!second_hop.is_previous_hop_of(&first_hop));
+        let mut list = UserOpList::get(&db_utils);
+        list.process_op(first_hop, &db_utils).unwrap();
+        list.process_op(second_hop, &db_utils).unwrap();
+        let first_hop_from_db = UserOp::get_from_db(&db_utils, &first_uid.into()).unwrap();
+        let second_hop_from_db = UserOp::get_from_db(&db_utils, &second_uid.into()).unwrap();
+        assert_eq!(first_hop_from_db.next_hop, Some(second_uid));
+        assert_eq!(first_hop_from_db.previous_hop, None);
+        assert_eq!(second_hop_from_db.previous_hop, Some(first_uid));
+        assert_eq!(second_hop_from_db.next_hop, None);
+    }
+
     #[test]
     fn should_be_equal_if_uid_equal_but_not_flags() {
         let mut op_1 = UserOpListEntry::default();
@@ -0,0 +1,138 @@
+use std::fmt;
+
+use common::DatabaseInterface;
+use common_network_ids::NetworkId;
+use derive_more::{Constructor, Deref};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use super::{UserOp, UserOpError, UserOpFlag, UserOpList, UserOpState};
+use crate::{DbUtilsT, SentinelDbUtils, SentinelError, UserOpUniqueId};
+
+// NOTE: Guards against a cycle of links, which should never exist, causing an infinite loop.
+const MAX_NUM_HOPS: usize = 16;
+
+#[serde_as]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UserOpRouteHop {
+    uid: EthHash,
+    flag: UserOpFlag,
+    state: UserOpState,
+    previous_states: Vec<UserOpState>,
+    #[serde_as(as = "DisplayFromStr")]
+    origin_network_id: NetworkId,
+    #[serde_as(as = "DisplayFromStr")]
+    destination_network_id: NetworkId,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    forward_destination_network_id: Option<NetworkId>,
+}
+
+impl TryFrom<&UserOp> for UserOpRouteHop {
+    type Error = UserOpError;
+
+    fn try_from(op: &UserOp) -> Result<Self, Self::Error> {
+        Ok(Self {
+            uid: op.uid()?,
+            flag: op.to_flag(),
+            state: *op.state(),
+            previous_states: op.previous_states().clone(),
+            origin_network_id: *op.origin_network_id(),
+            destination_network_id: op.destination_network_id(),
+            forward_destination_network_id: if op.is_forwarded() {
+                Some(*op.user_op_log().forward_destination_network_id())
+            } else {
+                None
+            },
+        })
+    }
+}
+
+/// The route of a (possibly multi-hop forwarded) user op, from the op on its first hop through to
+/// that on its last known one.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref)]
+pub struct UserOpRoute(Vec<UserOpRouteHop>);
+
+impl fmt::Display for UserOpRoute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match serde_json::to_string_pretty(self) {
+            Ok(s) => write!(f, "{s}"),
+            Err(e) => write!(f, "Error converting `UserOpRoute` to string: {e}"),
+        }
+    }
+}
+
+impl UserOpRoute {
+    fn from_ops(ops: &[UserOp]) -> Result<Self, UserOpError> {
+        Ok(Self::new(
+            ops.iter()
+                .map(UserOpRouteHop::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+
+    fn get_hop<D: DatabaseInterface>(db_utils: &SentinelDbUtils<D>, uid: &EthHash) -> Result<UserOp, SentinelError> {
+        UserOp::get_from_db(db_utils, &(*uid).into())
+    }
+
+    /// Gets the full route of which the op with the passed in uid is a part, regardless of which
+    /// hop that op is.
+    pub fn get<D: DatabaseInterface>(
+        uid: &UserOpUniqueId,
+        db_utils: &SentinelDbUtils<D>,
+    ) -> Result<Self, SentinelError> {
+        let mut first_hop = UserOpList::user_op(uid, db_utils)?;
+        for _ in 0..MAX_NUM_HOPS {
+            match first_hop.previous_hop {
+                Some(ref h) => first_hop = Self::get_hop(db_utils, h)?,
+                None => break,
+            }
+        }
+
+        let mut hops = vec![first_hop];
+        for _ in 0..MAX_NUM_HOPS {
+            match hops[hops.len() - 1].next_hop {
+                Some(ref h) => hops.push(Self::get_hop(db_utils, h)?),
+                None => break,
+            }
+        }
+
+        Ok(Self::from_ops(&hops)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+
+    use super::*;
+    use crate::user_ops::test_utils::get_sample_linked_hops;
+
+    #[test]
+    fn should_get_full_route_from_any_hop() {
+        let db = get_test_database();
+        let db_utils = SentinelDbUtils::new(&db);
+        let (first_hop, second_hop) = get_sample_linked_hops();
+        let mut list = UserOpList::get(&db_utils);
+        list.process_op(second_hop.clone(), &db_utils).unwrap();
+        list.process_op(first_hop.clone(), &db_utils).unwrap();
+        let expected_uids = vec![first_hop.uid().unwrap(), second_hop.uid().unwrap()];
+        for op in [first_hop, second_hop] {
+            let route = UserOpRoute::get(&op.uid().unwrap().into(), &db_utils).unwrap();
+            let uids = route.iter().map(|hop| hop.uid).collect::<Vec<_>>();
+            assert_eq!(uids, expected_uids);
+        }
+    }
+
+    #[test]
+    fn should_get_single_hop_route_for_op_that_is_not_forwarded() {
+        let db = get_test_database();
+        let db_utils = SentinelDbUtils::new(&db);
+        let op = UserOp::default();
+        let mut list = UserOpList::get(&db_utils);
+        list.process_op(op.clone(), &db_utils).unwrap();
+        let route = UserOpRoute::get(&op.uid().unwrap().into(), &db_utils).unwrap();
+        assert_eq!(route.len(), 1);
+        assert_eq!(route[0].forward_destination_network_id, None);
+    }
+}
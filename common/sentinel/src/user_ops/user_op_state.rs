@@ -38,6 +38,9 @@ pub struct UserOpStateInfo {
     sentinel_timestamp: u64,
     #[getter(skip)]
     block_timestamp: Option<u64>,
+    // NOTE: The hub whose event gave rise to this state. Older states won't have one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pnetwork_hub: Option<EthAddress>,
 }
 
 impl UserOpStateInfo {
@@ -47,9 +50,15 @@ impl UserOpStateInfo {
             network_id,
             block_timestamp: Some(block_timestamp),
             sentinel_timestamp: get_utc_timestamp().unwrap_or_default(),
+            pnetwork_hub: None,
         }
     }
 
+    fn with_pnetwork_hub(mut self, pnetwork_hub: EthAddress) -> Self {
+        self.pnetwork_hub = Some(pnetwork_hub);
+        self
+    }
+
     pub fn block_timestamp(&self) -> Result<u64, UserOpError> {
         self.block_timestamp.ok_or(UserOpError::NoBlockTimestampInUserOpState)
     }
@@ -151,6 +160,7 @@ impl fmt::Display for UserOpState {
             tx_hash,
             sentinel_timestamp,
             block_timestamp,
+            ..
         } = self.state();
         write!(
             f,
@@ -203,6 +213,10 @@ impl UserOpState {
         }
     }
 
+    pub fn pnetwork_hub(&self) -> Option<EthAddress> {
+        *self.state().pnetwork_hub()
+    }
+
     pub(crate) fn tx_hash(&self) -> EthHash {
         *self.state().tx_hash()
     }
@@ -221,7 +235,7 @@ impl UserOpState {
             return Err(UserOpError::NoTopics);
         };
 
-        let state = UserOpStateInfo::new(tx_hash, nid, block_timestamp);
+        let state = UserOpStateInfo::new(tx_hash, nid, block_timestamp).with_pnetwork_hub(log.address);
 
         if log.topics[0] == *WITNESSED_USER_OP_TOPIC {
             Ok(Self::Witnessed(state))
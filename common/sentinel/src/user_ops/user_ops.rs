@@ -1,10 +1,10 @@
 use std::{convert::TryFrom, fmt, str::FromStr};
 
 use common::{Byte, Bytes};
-use common_eth::EthSubmissionMaterial;
+use common_eth::{EthReceipt, EthSubmissionMaterial};
 use common_network_ids::NetworkId;
 use derive_more::{Constructor, Deref};
-use ethereum_types::{Address as EthAddress, U256};
+use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
 use serde::{Deserialize, Serialize};
 
 use super::{
@@ -54,31 +54,52 @@ impl UserOps {
         let block_hash = sub_mat.get_block_hash()?;
         let block_timestamp = sub_mat.get_timestamp().as_secs();
         let witnessed_timestamp = get_utc_timestamp()?;
+        let mut user_ops = Self::empty();
+        for receipt in sub_mat.receipts.iter() {
+            user_ops.add(Self::from_receipt(
+                origin_nid,
+                Some(pnetwork_hub),
+                receipt,
+                block_hash,
+                block_timestamp,
+                witnessed_timestamp,
+            )?);
+        }
+        Ok(user_ops)
+    }
+
+    /// Parses the user ops from the passed in receipt's logs. If no pNetwork hub is passed in, logs
+    /// emitted from any address are parsed, so long as they have a user op topic.
+    pub fn from_receipt(
+        origin_nid: &NetworkId,
+        pnetwork_hub: Option<&EthAddress>,
+        receipt: &EthReceipt,
+        block_hash: EthHash,
+        block_timestamp: u64,
+        witnessed_timestamp: u64,
+    ) -> Result<Self, SentinelError> {
         let topics = [
             *CANCELLED_USER_OP_TOPIC,
             *ENQUEUED_USER_OP_TOPIC,
             *EXECUTED_USER_OP_TOPIC,
             *WITNESSED_USER_OP_TOPIC,
         ];
-        let addresses = [pnetwork_hub];
+        let tx_hash = receipt.transaction_hash;
         let mut user_ops: Vec<UserOp> = vec![];
-        for receipt in sub_mat.receipts.iter() {
-            let tx_hash = receipt.transaction_hash;
-            for log in receipt.logs.iter() {
-                if addresses.contains(&&log.address) {
-                    for topic in log.topics.iter() {
-                        if topics.contains(topic) {
-                            let op = UserOp::from_log(
-                                witnessed_timestamp,
-                                block_timestamp,
-                                block_hash,
-                                tx_hash,
-                                origin_nid,
-                                log,
-                            )?;
-                            user_ops.push(op);
-                        };
-                    }
+        for log in receipt.logs.iter() {
+            if pnetwork_hub.map_or(true, |a| *a == log.address) {
+                for topic in log.topics.iter() {
+                    if topics.contains(topic) {
+                        let op = UserOp::from_log(
+                            witnessed_timestamp,
+                            block_timestamp,
+                            block_hash,
+                            tx_hash,
+                            origin_nid,
+                            log,
+                        )?;
+                        user_ops.push(op);
+                    };
                 }
             }
         }
@@ -0,0 +1,32 @@
+use common::{
+    constants::DB_KEY_NOT_FOUND_ERROR,
+    types::{Bytes, DataSensitivity, Result},
+    DatabaseInterface,
+};
+
+/// A database with nothing in it which cannot be written to, used as the base of simulations that
+/// start from scratch, eg from a core's initialization blocks.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmptyDatabase;
+
+impl DatabaseInterface for EmptyDatabase {
+    fn end_transaction(&self) -> Result<()> {
+        Err("Cannot write to the empty database!".into())
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn delete(&self, _key: Bytes) -> Result<()> {
+        Err("Cannot write to the empty database!".into())
+    }
+
+    fn get(&self, _key: Bytes, _sensitivity: DataSensitivity) -> Result<Bytes> {
+        Err(DB_KEY_NOT_FOUND_ERROR.into())
+    }
+
+    fn put(&self, _key: Bytes, _value: Bytes, _sensitivity: DataSensitivity) -> Result<()> {
+        Err("Cannot write to the empty database!".into())
+    }
+}
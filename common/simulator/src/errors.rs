@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SimulatorError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("serde json error: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+
+    #[error("core error: {0}")]
+    Core(#[from] common::AppError),
+
+    #[error("cannot get command for step from file '{0}' - name it `<index>_<command>.json`")]
+    NoCommandForStep(String),
+
+    #[error("cannot call `{0}` from within a simulation")]
+    ForbiddenCommand(String),
+
+    #[error("no steps found in '{0}'")]
+    NoSteps(String),
+}
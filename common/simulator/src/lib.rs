@@ -0,0 +1,26 @@
+//! # The Bridge Core Simulator
+//!
+//! Replays a directory of steps, such as captured submission material, through a vanilla app's
+//! CLI command handler against a simulated database, reporting each step's output & the changes it
+//! made to the database. The simulated database layers every write over a base database, which
+//! is only ever read from unless the simulation is explicitly persisted, simulations being dry runs by
+//! default.
+
+#[macro_use]
+extern crate log;
+
+mod empty_db;
+mod errors;
+mod report;
+mod simulation_db;
+mod simulator;
+mod step;
+
+pub use self::{
+    empty_db::EmptyDatabase,
+    errors::SimulatorError,
+    report::{DbChange, SimulationReport, StepReport},
+    simulation_db::SimulationDatabase,
+    simulator::{Simulator, SimulatorOptions},
+    step::Step,
+};
@@ -0,0 +1,121 @@
+use common::{constants::MAX_DATA_SENSITIVITY_LEVEL, types::DataSensitivity};
+use serde::Serialize;
+use serde_json::{json, Value as JsonValue};
+
+use crate::Step;
+
+// NOTE: Values stored with the max data sensitivity, ie private keys, never appear in reports.
+const REDACTED_VALUE: &str = "<redacted>";
+
+fn to_hex_or_redacted(value: &[u8], sensitivity: DataSensitivity) -> String {
+    if sensitivity == MAX_DATA_SENSITIVITY_LEVEL {
+        REDACTED_VALUE.to_string()
+    } else {
+        hex::encode(value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum DbChange {
+    Added {
+        key: String,
+        value: String,
+    },
+    Updated {
+        key: String,
+        old_value: String,
+        new_value: String,
+    },
+    Deleted {
+        key: String,
+    },
+}
+
+impl DbChange {
+    /// Describes the change from the old value to the new one, where a new value of `None` means
+    /// the key was deleted. Returns `None` if nothing actually changed.
+    pub fn new(key: &[u8], old_value: Option<&[u8]>, new_value: Option<(&[u8], DataSensitivity)>) -> Option<Self> {
+        let key = hex::encode(key);
+        match (old_value, new_value) {
+            (None, None) => None,
+            (Some(_), None) => Some(Self::Deleted { key }),
+            (None, Some((value, sensitivity))) => Some(Self::Added {
+                key,
+                value: to_hex_or_redacted(value, sensitivity),
+            }),
+            (Some(old_value), Some((new_value, _))) if old_value == new_value => None,
+            (Some(old_value), Some((new_value, sensitivity))) => Some(Self::Updated {
+                key,
+                old_value: to_hex_or_redacted(old_value, sensitivity),
+                new_value: to_hex_or_redacted(new_value, sensitivity),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StepReport {
+    pub index: usize,
+    pub file_name: String,
+    pub command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub db_changes: Vec<DbChange>,
+}
+
+impl StepReport {
+    pub fn new(index: usize, step: &Step, output: anyhow::Result<String>, db_changes: Vec<DbChange>) -> Self {
+        let (output, error) = match output {
+            Ok(s) => (Some(serde_json::from_str(&s).unwrap_or(JsonValue::String(s))), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Self {
+            index,
+            output,
+            error,
+            db_changes,
+            file_name: step.file_name.clone(),
+            command: step.request.method.clone(),
+        }
+    }
+
+    pub fn is_err(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct SimulationReport {
+    pub app: String,
+    pub steps_dir: String,
+    pub persisted: bool,
+    pub num_steps: usize,
+    pub num_failed_steps: usize,
+    pub steps: Vec<StepReport>,
+}
+
+impl SimulationReport {
+    pub fn new(app: &str, steps_dir: &str, persisted: bool, steps: Vec<StepReport>) -> Self {
+        Self {
+            persisted,
+            num_steps: steps.len(),
+            app: app.to_string(),
+            steps_dir: steps_dir.to_string(),
+            num_failed_steps: steps.iter().filter(|step| step.is_err()).count(),
+            steps,
+        }
+    }
+
+    pub fn to_summary_json(&self) -> JsonValue {
+        json!({
+            "app": self.app,
+            "persisted": self.persisted,
+            "steps_dir": self.steps_dir,
+            "num_steps": self.num_steps,
+            "num_failed_steps": self.num_failed_steps,
+        })
+    }
+}
@@ -0,0 +1,188 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use common::{
+    constants::DB_KEY_NOT_FOUND_ERROR,
+    types::{Bytes, DataSensitivity, Result},
+    DatabaseInterface,
+};
+
+use crate::DbChange;
+
+// NOTE: A write of `None` is a deletion.
+type DbWrite = Option<(Bytes, DataSensitivity)>;
+
+/// A database which layers every write over a base database, which is only ever read from until
+/// the simulation's writes are explicitly persisted to it. Like the real databases, writes are
+/// only committed at the end of a transaction.
+pub struct SimulationDatabase<'a, B> {
+    base: &'a B,
+    pending: RefCell<Vec<(Bytes, DbWrite)>>,
+    committed: RefCell<HashMap<Bytes, DbWrite>>,
+    changes: RefCell<Vec<(Bytes, Option<Bytes>, DbWrite)>>,
+}
+
+impl<'a, B: DatabaseInterface> SimulationDatabase<'a, B> {
+    pub fn new(base: &'a B) -> Self {
+        Self {
+            base,
+            pending: RefCell::new(vec![]),
+            changes: RefCell::new(vec![]),
+            committed: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn write_to_result(key: &[u8], write: &DbWrite) -> Result<Bytes> {
+        match write {
+            Some((value, _)) => Ok(value.clone()),
+            None => Err(format!("{DB_KEY_NOT_FOUND_ERROR} Key: {}", hex::encode(key)).into()),
+        }
+    }
+
+    fn get_committed(&self, key: Bytes, sensitivity: DataSensitivity) -> Result<Bytes> {
+        match self.committed.borrow().get(&key) {
+            Some(write) => Self::write_to_result(&key, write),
+            None => self.base.get(key, sensitivity),
+        }
+    }
+
+    fn record_change(&self, key: &[u8], old_value: Option<Bytes>, write: &DbWrite) {
+        let mut changes = self.changes.borrow_mut();
+        match changes.iter_mut().find(|(k, ..)| k == key) {
+            Some((_, _, existing_write)) => *existing_write = write.clone(),
+            None => changes.push((key.to_vec(), old_value, write.clone())),
+        }
+    }
+
+    /// Returns the changes committed since this was last called, coalescing multiple changes to
+    /// the same key into one.
+    pub fn take_changes(&self) -> Vec<DbChange> {
+        self.changes
+            .borrow_mut()
+            .drain(..)
+            .filter_map(|(key, old_value, write)| {
+                DbChange::new(
+                    &key,
+                    old_value.as_deref(),
+                    write
+                        .as_ref()
+                        .map(|(value, sensitivity)| (value.as_slice(), *sensitivity)),
+                )
+            })
+            .collect()
+    }
+
+    /// Writes every committed change to the base database in a single transaction, returning the
+    /// number of keys written.
+    pub fn persist(&self) -> Result<usize> {
+        info!("✔ Persisting simulation's changes to the base database...");
+        let committed = self.committed.borrow();
+        self.base.start_transaction()?;
+        for (key, write) in committed.iter() {
+            match write {
+                Some((value, sensitivity)) => self.base.put(key.clone(), value.clone(), *sensitivity)?,
+                None => self.base.delete(key.clone())?,
+            }
+        }
+        self.base.end_transaction()?;
+        Ok(committed.len())
+    }
+}
+
+impl<'a, B: DatabaseInterface> DatabaseInterface for SimulationDatabase<'a, B> {
+    fn start_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn end_transaction(&self) -> Result<()> {
+        let pending = self.pending.replace(vec![]);
+        for (key, write) in pending {
+            let sensitivity = write.as_ref().and_then(|(_, sensitivity)| *sensitivity);
+            let old_value = self.get_committed(key.clone(), sensitivity).ok();
+            self.record_change(&key, old_value, &write);
+            self.committed.borrow_mut().insert(key, write);
+        }
+        Ok(())
+    }
+
+    fn clear_cache(&self) -> Result<()> {
+        self.pending.borrow_mut().clear();
+        Ok(())
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        self.pending.borrow_mut().push((key, None));
+        Ok(())
+    }
+
+    fn put(&self, key: Bytes, value: Bytes, sensitivity: DataSensitivity) -> Result<()> {
+        self.pending.borrow_mut().push((key, Some((value, sensitivity))));
+        Ok(())
+    }
+
+    fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> Result<Bytes> {
+        let pending_write = self
+            .pending
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map(|(_, write)| write.clone());
+        match pending_write {
+            Some(write) => Self::write_to_result(&key, &write),
+            None => self.get_committed(key, sensitivity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{constants::MAX_DATA_SENSITIVITY_LEVEL, test_utils::get_test_database};
+
+    use super::*;
+
+    #[test]
+    fn should_only_commit_writes_at_end_of_transaction_and_never_touch_base() {
+        let base = get_test_database();
+        base.put(vec![1], vec![1], None).unwrap();
+        let db = SimulationDatabase::new(&base);
+        db.put(vec![1], vec![2], None).unwrap();
+        db.put(vec![2], vec![2], MAX_DATA_SENSITIVITY_LEVEL).unwrap();
+        assert_eq!(db.get(vec![1], None).unwrap(), vec![2]);
+        db.clear_cache().unwrap();
+        assert_eq!(db.get(vec![1], None).unwrap(), vec![1]);
+        db.put(vec![1], vec![2], None).unwrap();
+        db.put(vec![2], vec![2], MAX_DATA_SENSITIVITY_LEVEL).unwrap();
+        db.delete(vec![3]).unwrap();
+        db.end_transaction().unwrap();
+        db.clear_cache().unwrap();
+        assert_eq!(db.get(vec![1], None).unwrap(), vec![2]);
+        assert_eq!(base.get(vec![1], None).unwrap(), vec![1]);
+        assert!(base.get(vec![2], None).is_err());
+        let expected_changes = vec![
+            DbChange::Updated {
+                key: "01".into(),
+                old_value: "01".into(),
+                new_value: "02".into(),
+            },
+            DbChange::Added {
+                key: "02".into(),
+                value: "<redacted>".into(),
+            },
+        ];
+        assert_eq!(db.take_changes(), expected_changes);
+        assert!(db.take_changes().is_empty());
+    }
+
+    #[test]
+    fn should_persist_committed_writes_to_base() {
+        let base = get_test_database();
+        base.put(vec![1], vec![1], None).unwrap();
+        let db = SimulationDatabase::new(&base);
+        db.delete(vec![1]).unwrap();
+        db.put(vec![2], vec![2], None).unwrap();
+        db.end_transaction().unwrap();
+        assert_eq!(db.persist().unwrap(), 2);
+        assert!(base.get(vec![1], None).is_err());
+        assert_eq!(base.get(vec![2], None).unwrap(), vec![2]);
+    }
+}
@@ -0,0 +1,127 @@
+use std::fs;
+
+use common::DatabaseInterface;
+
+use crate::{SimulationDatabase, SimulationReport, SimulatorError, Step, StepReport};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SimulatorOptions {
+    /// Persists the simulation's changes to the base database. Off by default, making every
+    /// simulation a dry run unless this is explicitly set.
+    pub persist: bool,
+    /// Where to write the full report, if anywhere.
+    pub report_path: Option<String>,
+}
+
+pub struct Simulator {
+    app_name: String,
+    steps_dir: String,
+    steps: Vec<Step>,
+    options: SimulatorOptions,
+}
+
+impl Simulator {
+    pub fn new(app_name: &str, steps_dir: &str, options: SimulatorOptions) -> Result<Self, SimulatorError> {
+        Ok(Self {
+            options,
+            app_name: app_name.to_string(),
+            steps_dir: steps_dir.to_string(),
+            steps: Step::get_all_from_dir(steps_dir)?,
+        })
+    }
+
+    /// Runs each step in turn through the handler, which takes the database & the argv the CLI
+    /// would have received, returning what the CLI would have printed. A failing step does not
+    /// stop the simulation, since its uncommitted writes are discarded just as they would be in
+    /// the real core. Only if the `persist` option is set are the simulation's changes then
+    /// persisted to the base database.
+    pub fn run<B, F>(&self, base: &B, handler: F) -> Result<SimulationReport, SimulatorError>
+    where
+        B: DatabaseInterface,
+        F: Fn(&SimulationDatabase<B>, Vec<String>) -> anyhow::Result<String>,
+    {
+        let db = SimulationDatabase::new(base);
+        let step_reports = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| {
+                info!("✔ Simulating step #{i} from '{}'...", step.file_name);
+                db.clear_cache()?;
+                let output = handler(&db, step.request.to_argv(&self.app_name));
+                db.clear_cache()?;
+                Ok(StepReport::new(i, step, output, db.take_changes()))
+            })
+            .collect::<Result<Vec<_>, SimulatorError>>()?;
+        if self.options.persist {
+            db.persist()?;
+        } else {
+            info!("✔ Dry run ∴ not persisting simulation's changes!");
+        };
+        let report = SimulationReport::new(&self.app_name, &self.steps_dir, self.options.persist, step_reports);
+        if let Some(path) = &self.options.report_path {
+            info!("✔ Writing simulation report to '{path}'...");
+            fs::write(path, serde_json::to_string_pretty(&report)?)?;
+        };
+        Ok(report)
+    }
+
+    /// Runs the simulation, returning the full report as a JSON string, or just its summary if the
+    /// full report was written to a file.
+    pub fn run_and_get_output<B, F>(&self, base: &B, handler: F) -> Result<String, SimulatorError>
+    where
+        B: DatabaseInterface,
+        F: Fn(&SimulationDatabase<B>, Vec<String>) -> anyhow::Result<String>,
+    {
+        let report = self.run(base, handler)?;
+        match self.options.report_path {
+            Some(_) => Ok(report.to_summary_json().to_string()),
+            None => Ok(serde_json::to_string(&report)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+
+    fn get_sample_simulator(options: SimulatorOptions) -> Simulator {
+        Simulator {
+            options,
+            app_name: "app".to_string(),
+            steps_dir: "steps".to_string(),
+            steps: vec![Step::from_str("0001_someCommand.json", "{}").unwrap()],
+        }
+    }
+
+    fn sample_handler<B: DatabaseInterface>(db: &SimulationDatabase<B>, _argv: Vec<String>) -> anyhow::Result<String> {
+        db.put(vec![1], vec![1], None)?;
+        db.end_transaction()?;
+        Ok("done".to_string())
+    }
+
+    #[test]
+    fn should_not_persist_simulation_by_default() {
+        let base = get_test_database();
+        let report = get_sample_simulator(SimulatorOptions::default())
+            .run(&base, sample_handler)
+            .unwrap();
+        assert!(!report.persisted);
+        assert_eq!(report.steps[0].db_changes.len(), 1);
+        assert!(base.get(vec![1], None).is_err());
+    }
+
+    #[test]
+    fn should_persist_simulation_if_option_set() {
+        let base = get_test_database();
+        let options = SimulatorOptions {
+            persist: true,
+            ..Default::default()
+        };
+        let report = get_sample_simulator(options).run(&base, sample_handler).unwrap();
+        assert!(report.persisted);
+        assert_eq!(base.get(vec![1], None).unwrap(), vec![1]);
+    }
+}
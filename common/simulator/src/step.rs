@@ -0,0 +1,104 @@
+use std::{fs, path::Path};
+
+use common_vanilla_server::JsonRpcRequest;
+use serde_json::Value as JsonValue;
+
+use crate::SimulatorError;
+
+const FORBIDDEN_COMMANDS: [&str; 2] = ["serve", "simulate"];
+
+/// A single step of a simulation, read from a JSON file. Files containing a JSON-RPC style
+/// request, ie an object with a `method` & optional `params`, are run as that command. Any other
+/// file is passed as the sole arg to the command in its name, which must be of the form
+/// `<index>_<command>.json`, eg `0001_submitIntBlock.json`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Step {
+    pub file_name: String,
+    pub request: JsonRpcRequest,
+}
+
+impl Step {
+    pub(crate) fn from_str(file_name: &str, contents: &str) -> Result<Self, SimulatorError> {
+        let request = match serde_json::from_str::<JsonValue>(contents) {
+            Ok(json) if json.get("method").is_some() => serde_json::from_value::<JsonRpcRequest>(json)?,
+            _ => {
+                let command = Path::new(file_name)
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.split_once('_'))
+                    .map(|(_, command)| command.to_string())
+                    .filter(|command| !command.is_empty())
+                    .ok_or_else(|| SimulatorError::NoCommandForStep(file_name.into()))?;
+                JsonRpcRequest {
+                    id: JsonValue::Null,
+                    method: command,
+                    params: vec![JsonValue::String(contents.trim().to_string())],
+                }
+            },
+        };
+        if FORBIDDEN_COMMANDS.contains(&request.method.as_str()) {
+            Err(SimulatorError::ForbiddenCommand(request.method))
+        } else {
+            Ok(Self {
+                request,
+                file_name: file_name.to_string(),
+            })
+        }
+    }
+
+    /// Gets the steps from every JSON file in the directory, in order of their file names.
+    pub fn get_all_from_dir(dir: &str) -> Result<Vec<Self>, SimulatorError> {
+        let mut paths = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|path| path.is_file() && path.extension().map(|ext| ext == "json").unwrap_or(false))
+            .collect::<Vec<_>>();
+        paths.sort();
+        if paths.is_empty() {
+            return Err(SimulatorError::NoSteps(dir.into()));
+        };
+        paths
+            .iter()
+            .map(|path| {
+                let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+                Self::from_str(file_name, &fs::read_to_string(path)?)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_step_from_file_name_and_contents() {
+        let result = Step::from_str("0001_submitIntBlock.json", "{\"block\":{}}\n").unwrap();
+        assert_eq!(result.request.to_argv("app"), vec![
+            "app",
+            "submitIntBlock",
+            "{\"block\":{}}"
+        ]);
+        let contents = "{\"method\":\"debugSetIntGasPrice\",\"params\":[\"1337\",\"--sig=0xc0ffee\"]}";
+        let result = Step::from_str("0002.json", contents).unwrap();
+        assert_eq!(result.request.to_argv("app"), vec![
+            "app",
+            "debugSetIntGasPrice",
+            "1337",
+            "--sig=0xc0ffee"
+        ]);
+    }
+
+    #[test]
+    fn should_fail_to_get_step_without_command_or_with_forbidden_command() {
+        assert!(matches!(
+            Step::from_str("0001.json", "{}"),
+            Err(SimulatorError::NoCommandForStep(_))
+        ));
+        assert!(matches!(
+            Step::from_str("0001_serve.json", "{}"),
+            Err(SimulatorError::ForbiddenCommand(_))
+        ));
+    }
+}
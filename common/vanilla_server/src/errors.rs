@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum VanillaServerError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("warp error: {0}")]
+    Warp(#[from] warp::Error),
+
+    #[error("cannot serve on port {0}")]
+    InvalidPort(u16),
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+const JSON_RPC_VERSION: &str = "2.0";
+
+pub(crate) const PARSE_ERROR_CODE: i64 = -32700;
+pub(crate) const INVALID_REQUEST_CODE: i64 = -32600;
+pub(crate) const METHOD_NOT_FOUND_CODE: i64 = -32601;
+pub(crate) const CORE_ERROR_CODE: i64 = -32000;
+
+/// A JSON-RPC request, whose method is the name of a CLI command & whose params are that
+/// command's positional args & flags, eg:
+///
+/// `{"jsonrpc":"2.0","id":1,"method":"debugSetIntGasPrice","params":["20000000000","--sig=0x..."]}`
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct JsonRpcRequest {
+    #[serde(default)]
+    pub id: JsonValue,
+    pub method: String,
+    #[serde(default)]
+    pub params: Vec<JsonValue>,
+}
+
+impl JsonRpcRequest {
+    /// Converts this request into the argv the CLI would have received. Params that are not
+    /// strings, such as block JSON passed as an object, are passed as their JSON string.
+    pub fn to_argv(&self, app_name: &str) -> Vec<String> {
+        vec![app_name.to_string(), self.method.clone()]
+            .into_iter()
+            .chain(self.params.iter().map(|param| match param {
+                JsonValue::String(s) => s.clone(),
+                other => other.to_string(),
+            }))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonRpcResponse {
+    jsonrpc: &'static str,
+    id: JsonValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<JsonRpcError>,
+}
+
+impl JsonRpcResponse {
+    pub fn error(id: JsonValue, code: i64, message: &str) -> Self {
+        Self {
+            id,
+            result: None,
+            jsonrpc: JSON_RPC_VERSION,
+            error: Some(JsonRpcError {
+                code,
+                message: message.to_string(),
+            }),
+        }
+    }
+
+    /// Wraps the output of a core call. Output that is valid JSON is returned as such, whilst any
+    /// other output, eg that prefixed with the debug output marker, is returned as a string.
+    pub fn from_core_output(id: JsonValue, output: anyhow::Result<String>) -> Self {
+        match output {
+            Ok(s) => Self {
+                id,
+                error: None,
+                jsonrpc: JSON_RPC_VERSION,
+                result: Some(serde_json::from_str(&s).unwrap_or(JsonValue::String(s))),
+            },
+            Err(e) => Self::error(id, CORE_ERROR_CODE, &e.to_string()),
+        }
+    }
+
+    pub fn get_result(&self) -> Option<&JsonValue> {
+        self.result.as_ref()
+    }
+
+    pub fn get_error(&self) -> Option<&JsonRpcError> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_convert_request_to_argv() {
+        let request: JsonRpcRequest = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "submitIntBlock",
+            "params": [{"block": {}}, "--sig=0xc0ffee"],
+        }))
+        .unwrap();
+        let expected_result = vec!["app", "submitIntBlock", "{\"block\":{}}", "--sig=0xc0ffee"];
+        assert_eq!(request.to_argv("app"), expected_result);
+    }
+
+    #[test]
+    fn should_return_json_output_as_json_and_other_output_as_string() {
+        let response = JsonRpcResponse::from_core_output(json!(1), Ok("{\"a\":1}".to_string()));
+        assert_eq!(response.get_result().unwrap(), &json!({"a": 1}));
+        let response = JsonRpcResponse::from_core_output(json!(1), Ok("not json".to_string()));
+        assert_eq!(response.get_result().unwrap(), &json!("not json"));
+        let response = JsonRpcResponse::from_core_output(json!(1), Err(anyhow::anyhow!("boom")));
+        assert_eq!(response.get_error().unwrap().code, CORE_ERROR_CODE);
+    }
+}
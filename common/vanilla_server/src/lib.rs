@@ -0,0 +1,22 @@
+//! # The Vanilla App JSON-RPC Server
+//!
+//! A long-running server for the vanilla apps, which keeps the database open & exposes each of an
+//! app's CLI commands as a JSON-RPC method, over either HTTP or a Unix socket. Requests are
+//! converted into the argv the CLI would have received, meaning each method returns exactly what
+//! the CLI would have printed. Core calls are serialized, & the server shuts down gracefully upon
+//! receipt of a `SIGINT` or `SIGTERM`, finishing any in-flight call first.
+
+#[macro_use]
+extern crate log;
+
+mod errors;
+mod json_rpc;
+mod server;
+mod server_config;
+
+pub use self::{
+    errors::VanillaServerError,
+    json_rpc::{JsonRpcRequest, JsonRpcResponse},
+    server::VanillaServer,
+    server_config::VanillaServerConfig,
+};
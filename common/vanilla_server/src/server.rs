@@ -0,0 +1,214 @@
+use std::{
+    convert::Infallible,
+    future,
+    sync::{Arc, Mutex},
+};
+
+use common::DatabaseInterface;
+use futures::stream;
+use serde_json::{json, Value as JsonValue};
+use tokio::{
+    net::UnixListener,
+    signal::unix::{signal, SignalKind},
+};
+use warp::{hyper::body::Bytes, Filter};
+
+use crate::{
+    json_rpc::{CORE_ERROR_CODE, INVALID_REQUEST_CODE, METHOD_NOT_FOUND_CODE, PARSE_ERROR_CODE},
+    JsonRpcRequest,
+    JsonRpcResponse,
+    VanillaServerConfig,
+    VanillaServerError,
+};
+
+// NOTE: Large enough for a batch of host chain blocks complete with their receipts.
+const MAX_REQUEST_SIZE_IN_BYTES: u64 = 64 * 1024 * 1024;
+const SERVE_COMMAND: &str = "serve";
+
+type CoreHandler<D> = dyn Fn(&D, Vec<String>) -> anyhow::Result<String> + Send + Sync;
+
+/// A JSON-RPC server wrapping a vanilla app's database & its CLI command handler, which takes the
+/// database & the argv the CLI would have received, returning what the CLI would have printed.
+pub struct VanillaServer<D> {
+    app_name: String,
+    db: Mutex<D>,
+    handler: Box<CoreHandler<D>>,
+}
+
+impl<D: DatabaseInterface + Send + 'static> VanillaServer<D> {
+    pub fn new<F>(app_name: &str, db: D, handler: F) -> Self
+    where
+        F: Fn(&D, Vec<String>) -> anyhow::Result<String> + Send + Sync + 'static,
+    {
+        Self {
+            db: Mutex::new(db),
+            handler: Box::new(handler),
+            app_name: app_name.to_string(),
+        }
+    }
+
+    fn call_core(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
+        if request.method == SERVE_COMMAND {
+            return JsonRpcResponse::error(
+                request.id.clone(),
+                METHOD_NOT_FOUND_CODE,
+                "Cannot call `serve` via the JSON-RPC server!",
+            );
+        };
+        info!("✔ Handling JSON-RPC request for method '{}'...", request.method);
+        // NOTE: Holding the lock for the duration of the call serializes all core calls. A poisoned
+        // lock means a previous call panicked, which is safe to ignore since the cache is cleared.
+        let db = self.db.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let output = db
+            .clear_cache()
+            .map_err(anyhow::Error::from)
+            .and_then(|_| (self.handler)(&db, request.to_argv(&self.app_name)));
+        if let Err(e) = db.clear_cache() {
+            warn!("✘ Error clearing DB cache after core call: {e}");
+        };
+        JsonRpcResponse::from_core_output(request.id.clone(), output)
+    }
+
+    fn handle_json(&self, json: JsonValue) -> JsonRpcResponse {
+        match serde_json::from_value::<JsonRpcRequest>(json) {
+            Ok(request) => self.call_core(&request),
+            Err(e) => JsonRpcResponse::error(JsonValue::Null, INVALID_REQUEST_CODE, &e.to_string()),
+        }
+    }
+
+    /// Handles the body of an HTTP request, which may contain either a single JSON-RPC request or
+    /// a batch of them, the latter being handled in order.
+    pub fn handle_request(&self, body: &[u8]) -> JsonValue {
+        match serde_json::from_slice::<JsonValue>(body) {
+            Err(e) => json!(JsonRpcResponse::error(
+                JsonValue::Null,
+                PARSE_ERROR_CODE,
+                &e.to_string()
+            )),
+            Ok(JsonValue::Array(requests)) => JsonValue::Array(
+                requests
+                    .into_iter()
+                    .map(|request| json!(self.handle_json(request)))
+                    .collect(),
+            ),
+            Ok(request) => json!(self.handle_json(request)),
+        }
+    }
+
+    /// Serves JSON-RPC requests until a `SIGINT` or `SIGTERM` is received, at which point the
+    /// server stops accepting connections & returns once any in-flight core call is complete.
+    pub fn serve(self, config: &VanillaServerConfig) -> Result<String, VanillaServerError> {
+        tokio::runtime::Runtime::new()?.block_on(Arc::new(self).serve_async(config.clone()))
+    }
+
+    async fn serve_async(self: Arc<Self>, config: VanillaServerConfig) -> Result<String, VanillaServerError> {
+        let rpc = warp::post()
+            .and(warp::path::end())
+            .and(warp::body::content_length_limit(MAX_REQUEST_SIZE_IN_BYTES))
+            .and(warp::body::bytes())
+            .and_then(move |body: Bytes| {
+                let server = self.clone();
+                async move {
+                    // NOTE: Core calls block, so are made off of the async runtime's worker threads.
+                    let json = match tokio::task::spawn_blocking(move || server.handle_request(&body)).await {
+                        Ok(json) => json,
+                        Err(e) => {
+                            error!("✘ Core call failed to complete: {e}");
+                            json!(JsonRpcResponse::error(
+                                JsonValue::Null,
+                                CORE_ERROR_CODE,
+                                "Core call failed to complete!"
+                            ))
+                        },
+                    };
+                    Ok::<_, Infallible>(warp::reply::json(&json))
+                }
+            });
+
+        match config {
+            VanillaServerConfig::Http(address) => {
+                let (address, server) = warp::serve(rpc).try_bind_with_graceful_shutdown(address, shutdown_signal())?;
+                info!("✔ JSON-RPC server listening on http://{address}");
+                server.await;
+            },
+            VanillaServerConfig::UnixSocket(path) => {
+                let listener = UnixListener::bind(&path)?;
+                info!("✔ JSON-RPC server listening on unix socket at '{path}'");
+                let incoming = stream::unfold(listener, |listener| async move {
+                    let connection = listener.accept().await.map(|(stream, _)| stream);
+                    Some((connection, listener))
+                });
+                warp::serve(rpc)
+                    .serve_incoming_with_graceful_shutdown(incoming, shutdown_signal())
+                    .await;
+                std::fs::remove_file(&path)?;
+            },
+        };
+        Ok(json!({"server_shut_down": true}).to_string())
+    }
+}
+
+async fn shutdown_signal() {
+    let terminate = async {
+        match signal(SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            },
+            Err(e) => {
+                warn!("✘ Cannot listen for SIGTERM: {e}");
+                future::pending::<()>().await
+            },
+        }
+    };
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => warn!("✔ SIGINT received, shutting down JSON-RPC server..."),
+        _ = terminate => warn!("✔ SIGTERM received, shutting down JSON-RPC server..."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{test_utils::get_test_database, types::Bytes as CoreBytes, AppError};
+
+    use super::*;
+
+    fn get_test_server() -> VanillaServer<impl DatabaseInterface + Send + 'static> {
+        VanillaServer::new("app", get_test_database(), |db, argv| {
+            let key: CoreBytes = vec![1, 3, 3, 7];
+            match argv[1].as_ref() {
+                "put" => {
+                    db.put(key, argv[2].as_bytes().to_vec(), None)?;
+                    Ok(json!({"success": true}).to_string())
+                },
+                "get" => Ok(String::from_utf8(db.get(key, None)?)?),
+                _ => Err(AppError::Custom("unknown command".into()).into()),
+            }
+        })
+    }
+
+    #[test]
+    fn should_handle_single_and_batched_requests() {
+        let server = get_test_server();
+        let request = json!({"jsonrpc": "2.0", "id": 1, "method": "put", "params": ["value"]});
+        let result = server.handle_request(request.to_string().as_bytes());
+        assert_eq!(result, json!({"jsonrpc": "2.0", "id": 1, "result": {"success": true}}));
+        let batch = json!([
+            {"jsonrpc": "2.0", "id": 2, "method": "get"},
+            {"jsonrpc": "2.0", "id": 3, "method": "nope"},
+        ]);
+        let result = server.handle_request(batch.to_string().as_bytes());
+        assert_eq!(result[0], json!({"jsonrpc": "2.0", "id": 2, "result": "value"}));
+        assert_eq!(result[1]["error"]["code"], json!(CORE_ERROR_CODE));
+    }
+
+    #[test]
+    fn should_reject_malformed_requests_and_serve_method() {
+        let server = get_test_server();
+        let result = server.handle_request(b"not json");
+        assert_eq!(result["error"]["code"], json!(PARSE_ERROR_CODE));
+        let result = server.handle_request(json!({"id": 1}).to_string().as_bytes());
+        assert_eq!(result["error"]["code"], json!(INVALID_REQUEST_CODE));
+        let result = server.handle_request(json!({"id": 1, "method": "serve"}).to_string().as_bytes());
+        assert_eq!(result["error"]["code"], json!(METHOD_NOT_FOUND_CODE));
+    }
+}
@@ -0,0 +1,42 @@
+use std::net::SocketAddr;
+
+use crate::VanillaServerError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VanillaServerConfig {
+    Http(SocketAddr),
+    UnixSocket(String),
+}
+
+impl VanillaServerConfig {
+    /// Creates the server config from the `--port` & `--socket` CLI flags, serving on the Unix
+    /// socket at the given path if one is provided, else via HTTP on localhost at the given port.
+    pub fn new(port: u16, socket_path: &str) -> Result<Self, VanillaServerError> {
+        if !socket_path.is_empty() {
+            Ok(Self::UnixSocket(socket_path.to_string()))
+        } else if port == 0 {
+            Err(VanillaServerError::InvalidPort(port))
+        } else {
+            Ok(Self::Http(SocketAddr::from(([127, 0, 0, 1], port))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_prefer_unix_socket_over_http() {
+        let path = "/tmp/vanilla.sock";
+        assert_eq!(
+            VanillaServerConfig::new(3030, path).unwrap(),
+            VanillaServerConfig::UnixSocket(path.to_string())
+        );
+        assert_eq!(
+            VanillaServerConfig::new(3030, "").unwrap(),
+            VanillaServerConfig::Http(SocketAddr::from(([127, 0, 0, 1], 3030)))
+        );
+        assert!(VanillaServerConfig::new(0, "").is_err());
+    }
+}
@@ -299,7 +299,7 @@ impl ToMetadata for BtcOnEosEosTxInfo {
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::errors::AppError;
     use common_chain_ids::BtcChainId;
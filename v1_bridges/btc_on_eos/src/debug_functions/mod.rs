@@ -1,5 +1,6 @@
 mod btc_block_reprocessor;
 mod debug_add_utxo_to_db;
+mod debug_fee_distribution;
 mod debug_get_all_db_keys;
 mod debug_put_basis_points_in_db;
 mod debug_withdraw_fees;
@@ -11,11 +12,12 @@ pub use self::{
         debug_reprocess_btc_block_for_stale_eos_tx_with_fee_accrual,
     },
     debug_add_utxo_to_db::debug_maybe_add_utxo_to_db,
+    debug_fee_distribution::{debug_remove_fee_distribution_policy, debug_set_fee_distribution_policy},
     debug_get_all_db_keys::debug_get_all_db_keys,
     debug_put_basis_points_in_db::{
         debug_put_btc_on_eos_peg_in_basis_points_in_db,
         debug_put_btc_on_eos_peg_out_basis_points_in_db,
     },
-    debug_withdraw_fees::debug_get_fee_withdrawal_tx,
+    debug_withdraw_fees::{debug_get_fee_withdrawal_tx, debug_get_split_fee_withdrawal_tx},
     eos_block_reprocessor::{debug_reprocess_eos_block, debug_reprocess_eos_block_with_fee_accrual},
 };
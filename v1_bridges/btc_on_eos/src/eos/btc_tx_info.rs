@@ -218,7 +218,7 @@ pub fn maybe_filter_out_already_processed_tx_ids_from_state<D: DatabaseInterface
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
@@ -5,11 +5,7 @@
 //! consists of two light clients that manage the state of the two chains, along
 //! with the creation and signing of transactions related to each chain.
 
-// NOTE: This means we can (relatively) easily make this LTC based instead of BTC.
-#[cfg(not(feature = "ltc"))]
 use bitcoin as bitcoin_crate_alias;
-#[cfg(feature = "ltc")]
-use litecoin as bitcoin_crate_alias;
 
 mod btc;
 mod constants;
@@ -52,13 +48,16 @@ pub use self::{
     debug_functions::{
         debug_get_all_db_keys,
         debug_get_fee_withdrawal_tx,
+        debug_get_split_fee_withdrawal_tx,
         debug_maybe_add_utxo_to_db,
         debug_put_btc_on_eos_peg_in_basis_points_in_db,
         debug_put_btc_on_eos_peg_out_basis_points_in_db,
+        debug_remove_fee_distribution_policy,
         debug_reprocess_btc_block_for_stale_eos_tx,
         debug_reprocess_btc_block_for_stale_eos_tx_with_fee_accrual,
         debug_reprocess_eos_block,
         debug_reprocess_eos_block_with_fee_accrual,
+        debug_set_fee_distribution_policy,
     },
     eos::{maybe_initialize_eos_core, submit_eos_block_to_core},
     get_enclave_state::get_enclave_state,
@@ -124,7 +124,7 @@ pub fn maybe_account_for_fees<D: DatabaseInterface>(state: BtcState<D>) -> Resul
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use common::{errors::AppError, test_utils::get_test_database};
     use common_fees::FeeDatabaseUtils;
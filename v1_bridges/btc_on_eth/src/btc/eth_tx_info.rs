@@ -79,7 +79,7 @@ impl ToMetadata for BtcOnEthEthTxInfo {
     }
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
@@ -100,7 +100,7 @@ pub fn parse_eth_tx_infos_from_p2sh_deposits_and_add_to_state<D: DatabaseInterfa
     .map(|bytes| state.add_tx_infos(bytes))
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
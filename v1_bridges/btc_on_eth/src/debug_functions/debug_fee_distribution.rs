@@ -0,0 +1,64 @@
+use common::{traits::DatabaseInterface, types::Result, utils::prepend_debug_output_marker_to_string};
+use common_btc::BtcDbUtils;
+use common_debug_signers::validate_debug_command_signature;
+use common_fees::{FeeDistributionPolicies, FeeDistributionPolicy, BTC_FEE_DISTRIBUTION_TOKEN};
+use function_name::named;
+use serde_json::json;
+
+use crate::constants::CORE_TYPE;
+
+/// # Debug Set Fee Distribution Policy
+///
+/// This function sets the policy used by split fee withdrawals to divide the accrued fees between
+/// multiple beneficiaries. The passed in JSON is a list of BTC recipient addresses along with their
+/// shares in basis points, which must total 10000, eg:
+///
+/// ```no_compile
+/// [
+///     { "recipient": "msgbp2MiwL6M1qkhZx9N46ipPn12tzLzZ7", "share_basis_points": 6000 },
+///     { "recipient": "mwbtrpDGLWiMiq1TB7DhnrEN14B5Hydp28", "share_basis_points": 4000 }
+/// ]
+/// ```
+///
+/// The recipients must be valid addresses for the core's BTC network. Any rounding dust carried
+/// forward by a previous policy is kept.
+#[named]
+pub fn debug_set_fee_distribution_policy<D: DatabaseInterface>(
+    db: &D,
+    beneficiaries_json: &str,
+    signature: &str,
+) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!(), beneficiaries_json)())
+        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
+        .and_then(|_| FeeDistributionPolicy::from_json_str(BTC_FEE_DISTRIBUTION_TOKEN, beneficiaries_json))
+        .and_then(|policy| {
+            policy.validate_btc_recipients(&BtcDbUtils::new(db).get_btc_network_from_db()?)?;
+            FeeDistributionPolicies::get_from_db(db)?
+                .set_policy(policy.clone())
+                .save_in_db(db)?;
+            Ok(policy)
+        })
+        .and_then(|policy| {
+            db.end_transaction()?;
+            Ok(json!({"success": true, "fee_distribution_policy": policy}).to_string())
+        })
+        .map(prepend_debug_output_marker_to_string)
+}
+
+/// # Debug Remove Fee Distribution Policy
+///
+/// This function removes the fee distribution policy, along with any rounding dust it has carried
+/// forward.
+#[named]
+pub fn debug_remove_fee_distribution_policy<D: DatabaseInterface>(db: &D, signature: &str) -> Result<String> {
+    db.start_transaction()
+        .and_then(|_| get_debug_command_hash!(function_name!())())
+        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
+        .and_then(|_| FeeDistributionPolicies::get_from_db(db))
+        .and_then(|policies| policies.remove_policy(BTC_FEE_DISTRIBUTION_TOKEN))
+        .and_then(|policies| policies.save_in_db(db))
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"success": true}).to_string())
+        .map(prepend_debug_output_marker_to_string)
+}
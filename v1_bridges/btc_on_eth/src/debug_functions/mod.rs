@@ -1,6 +1,7 @@
 mod btc_block_reprocessor;
 mod debug_add_utxo_to_db;
 mod debug_change_pnetwork;
+mod debug_fee_distribution;
 mod debug_get_all_db_keys;
 mod debug_mint_pbtc;
 mod debug_set_accrued_fees;
@@ -20,6 +21,7 @@ pub use self::{
         debug_get_signed_erc777_proxy_change_pnetwork_by_proxy_tx,
         debug_get_signed_erc777_proxy_change_pnetwork_tx,
     },
+    debug_fee_distribution::{debug_remove_fee_distribution_policy, debug_set_fee_distribution_policy},
     debug_get_all_db_keys::debug_get_all_db_keys,
     debug_mint_pbtc::debug_mint_pbtc,
     debug_set_accrued_fees::debug_set_accrued_fees,
@@ -27,6 +29,6 @@ pub use self::{
         debug_put_btc_on_eth_peg_in_basis_points_in_db,
         debug_put_btc_on_eth_peg_out_basis_points_in_db,
     },
-    debug_withdraw_fees::debug_get_fee_withdrawal_tx,
+    debug_withdraw_fees::{debug_get_fee_withdrawal_tx, debug_get_split_fee_withdrawal_tx},
     eth_block_reprocessor::{debug_reprocess_eth_block, debug_reprocess_eth_block_with_fee_accrual},
 };
@@ -5,11 +5,7 @@
 //! consists of two light clients that manage the state of the two chains, along
 //! with the creation and signing of transactions related to each chain.
 
-// NOTE: This means we can (relatively) easily make this LTC based instead of BTC.
-#[cfg(not(feature = "ltc"))]
 use bitcoin as bitcoin_crate_alias;
-#[cfg(feature = "ltc")]
-use litecoin as bitcoin_crate_alias;
 
 mod btc;
 mod constants;
@@ -56,16 +52,19 @@ pub use self::{
         debug_get_signed_erc777_change_pnetwork_tx,
         debug_get_signed_erc777_proxy_change_pnetwork_by_proxy_tx,
         debug_get_signed_erc777_proxy_change_pnetwork_tx,
+        debug_get_split_fee_withdrawal_tx,
         debug_maybe_add_utxo_to_db,
         debug_mint_pbtc,
         debug_put_btc_on_eth_peg_in_basis_points_in_db,
         debug_put_btc_on_eth_peg_out_basis_points_in_db,
+        debug_remove_fee_distribution_policy,
         debug_reprocess_btc_block,
         debug_reprocess_btc_block_with_fee_accrual,
         debug_reprocess_btc_block_with_nonce,
         debug_reprocess_eth_block,
         debug_reprocess_eth_block_with_fee_accrual,
         debug_set_accrued_fees,
+        debug_set_fee_distribution_policy,
     },
     eth::{maybe_add_erc777_contract_address, maybe_initialize_eth_enclave, submit_eth_block_to_enclave},
     get_enclave_state::get_enclave_state,
@@ -1,5 +1,5 @@
 use common::{core_type::CoreType, traits::DatabaseInterface, types::Result};
-use common_eth::{EthDbUtils, EthDbUtilsExt, EvmDbUtils};
+use common_eth::get_latest_eth_and_evm_block_numbers;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -14,10 +14,12 @@ struct BlockNumbers {
 /// blockchains this instance manages.
 pub fn get_latest_block_numbers<D: DatabaseInterface>(db: &D) -> Result<String> {
     info!("✔ Getting latest `ERC20-on-EVM` block numbers...");
-    CoreType::check_is_initialized(db).and_then(|_| {
-        Ok(serde_json::to_string(&BlockNumbers {
-            eth_latest_block_number: EthDbUtils::new(db).get_latest_eth_block_number()?,
-            evm_latest_block_number: EvmDbUtils::new(db).get_latest_eth_block_number()?,
-        })?)
-    })
+    CoreType::check_is_initialized(db)
+        .and_then(|_| get_latest_eth_and_evm_block_numbers(db))
+        .and_then(|(eth_latest_block_number, evm_latest_block_number)| {
+            Ok(serde_json::to_string(&BlockNumbers {
+                eth_latest_block_number,
+                evm_latest_block_number,
+            })?)
+        })
 }
@@ -14,6 +14,7 @@ make_cli_args_struct!(
     arg_eosJson => String,
     arg_basisPoints => u64,
     arg_utxosJson => String,
+    arg_beneficiariesJson => String,
     arg_ethAddress => String,
     arg_accountName => String,
     arg_featureHash => String,
@@ -31,6 +32,9 @@ make_cli_args_struct!(
     cmd_submitBtcBlock => bool,
     cmd_getEnclaveState => bool,
     cmd_debugWithdrawFees => bool,
+    cmd_debugGetSplitFeeWithdrawalTx => bool,
+    cmd_debugSetFeeDistributionPolicy => bool,
+    cmd_debugRemoveFeeDistributionPolicy => bool,
     cmd_getLatestBlockNumbers => bool,
     cmd_enableEosProtocolFeature => bool,
     cmd_disableEosProtocolFeature => bool,
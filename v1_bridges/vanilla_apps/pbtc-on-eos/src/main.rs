@@ -23,10 +23,12 @@ use btc_on_eos::{
     debug_get_child_pays_for_parent_btc_tx,
     debug_get_fee_withdrawal_tx,
     debug_get_key_from_db,
+    debug_get_split_fee_withdrawal_tx,
     debug_maybe_add_utxo_to_db,
     debug_put_btc_on_eos_peg_in_basis_points_in_db,
     debug_put_btc_on_eos_peg_out_basis_points_in_db,
     debug_remove_debug_signer,
+    debug_remove_fee_distribution_policy,
     debug_remove_utxo,
     debug_reprocess_btc_block_for_stale_eos_tx,
     debug_reprocess_btc_block_for_stale_eos_tx_with_fee_accrual,
@@ -35,6 +37,7 @@ use btc_on_eos::{
     debug_set_btc_account_nonce,
     debug_set_btc_fee,
     debug_set_eos_account_nonce,
+    debug_set_fee_distribution_policy,
     debug_set_key_in_db_to_value,
     debug_update_incremerkle,
     get_all_utxos,
@@ -410,6 +413,31 @@ fn program() -> Result<String> {
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugGetSplitFeeWithdrawalTx: true,
+            ..
+        } => {
+            info!("✔ Debug getting split fee withdrawal tx...");
+            Ok(debug_get_split_fee_withdrawal_tx(&db, &cli_args.flag_sig)?)
+        },
+        CliArgs {
+            cmd_debugSetFeeDistributionPolicy: true,
+            ..
+        } => {
+            info!("✔ Debug setting fee distribution policy...");
+            Ok(debug_set_fee_distribution_policy(
+                &db,
+                &cli_args.arg_beneficiariesJson,
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugRemoveFeeDistributionPolicy: true,
+            ..
+        } => {
+            info!("✔ Debug removing fee distribution policy...");
+            Ok(debug_remove_fee_distribution_policy(&db, &cli_args.flag_sig)?)
+        },
         CliArgs {
             cmd_debugSetEosAccountNonce: true,
             ..
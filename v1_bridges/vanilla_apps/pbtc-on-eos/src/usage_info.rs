@@ -26,6 +26,9 @@ Usage:  pbtc-on-eos [--help]
         pbtc-on-eos debugSetBtcFee <fee> [--sig=<hex>]
         pbtc-on-eos debugGetKeyFromDb <key> [--sig=<hex>]
         pbtc-on-eos debugWithdrawFees <address> [--sig=<hex>]
+        pbtc-on-eos debugGetSplitFeeWithdrawalTx [--sig=<hex>]
+        pbtc-on-eos debugRemoveFeeDistributionPolicy [--sig=<hex>]
+        pbtc-on-eos debugSetFeeDistributionPolicy <beneficiariesJson> [--sig=<hex>]
         pbtc-on-eos debugRemoveUtxo <txId> <vOut> [--sig=<hex>]
         pbtc-on-eos debugSetPegInFee <basisPoints> [--sig=<hex>]
         pbtc-on-eos debugSetEosAccountNonce <nonce> [--sig=<hex>]
@@ -164,6 +167,20 @@ Commands:
     debugWithdrawFees         ❍ Creates a BTC transaction to the passed in address for the amount of the total accrued
                                 fees in the core.
 
+    debugGetSplitFeeWithdrawalTx ❍ Creates a single BTC transaction splitting the total accrued fees in the core
+                                between the beneficiaries of the fee distribution policy.
+
+    debugSetFeeDistributionPolicy ❍ Sets the policy used to split withdrawn fees between beneficiaries.
+                                ➔ beneficiariesJson Format:
+                                [
+                                  {
+                                    `recipient`: A BTC address.
+                                    `share_basis_points`: The recipient's share, all of which must total 10000.
+                                  }
+                                ]
+
+    debugRemoveFeeDistributionPolicy ❍ Removes the fee distribution policy.
+
     debugReprocessBtcBlock    ❍ Re-process a BTC block without updating any chain data in the database.
 
     debugReprocessEosBlock    ❍ Re-process an EOS block.
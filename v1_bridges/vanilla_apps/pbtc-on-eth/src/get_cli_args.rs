@@ -26,6 +26,7 @@ make_cli_args_struct!(
     arg_address => String,
     arg_basisPoints => u64,
     arg_utxosJson => String,
+    arg_beneficiariesJson => String,
     cmd_initializeEth => bool,
     cmd_initializeBtc => bool,
     cmd_submitBtcBlock => bool,
@@ -40,6 +41,9 @@ make_cli_args_struct!(
     cmd_debugSetPegInFee => bool,
     cmd_debugSetPegOutFee => bool,
     cmd_debugWithdrawFees => bool,
+    cmd_debugGetSplitFeeWithdrawalTx => bool,
+    cmd_debugSetFeeDistributionPolicy => bool,
+    cmd_debugRemoveFeeDistributionPolicy => bool,
     cmd_debugClearAllUtxos => bool,
     cmd_debugSetAccruedFees => bool,
     cmd_debugResetEthChain => bool,
@@ -110,6 +114,7 @@ pub fn get_cli_args(usage_info: &str) -> Result<CliArgs> {
 // pub arg_basisPoints: u64,
 // pub arg_blockJson: String,
 // pub arg_utxosJson: String,
+// pub arg_beneficiariesJson: String,
 // pub cmd_initializeEth: bool,
 // pub cmd_initializeBtc: bool,
 // pub cmd_submitBtcBlock: bool,
@@ -124,6 +129,9 @@ pub fn get_cli_args(usage_info: &str) -> Result<CliArgs> {
 // pub cmd_debugSetPegInFee: bool,
 // pub cmd_debugSetPegOutFee: bool,
 // pub cmd_debugWithdrawFees: bool,
+// pub cmd_debugGetSplitFeeWithdrawalTx: bool,
+// pub cmd_debugSetFeeDistributionPolicy: bool,
+// pub cmd_debugRemoveFeeDistributionPolicy: bool,
 // pub cmd_debugGetAllDbKeys: bool,
 // pub cmd_debugGetKeyFromDb: bool,
 // pub cmd_debugClearAllUtxos: bool,
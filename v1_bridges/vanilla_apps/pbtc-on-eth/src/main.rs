@@ -23,10 +23,12 @@ use btc_on_eth::{
     debug_get_signed_erc777_change_pnetwork_tx,
     debug_get_signed_erc777_proxy_change_pnetwork_by_proxy_tx,
     debug_get_signed_erc777_proxy_change_pnetwork_tx,
+    debug_get_split_fee_withdrawal_tx,
     debug_maybe_add_utxo_to_db,
     debug_put_btc_on_eth_peg_in_basis_points_in_db,
     debug_put_btc_on_eth_peg_out_basis_points_in_db,
     debug_remove_debug_signer,
+    debug_remove_fee_distribution_policy,
     debug_remove_utxo,
     debug_reprocess_btc_block,
     debug_reprocess_btc_block_with_fee_accrual,
@@ -39,6 +41,7 @@ use btc_on_eth::{
     debug_set_btc_fee,
     debug_set_eth_account_nonce,
     debug_set_eth_gas_price,
+    debug_set_fee_distribution_policy,
     debug_set_key_in_db_to_value,
     get_all_utxos,
     get_enclave_state,
@@ -374,6 +377,31 @@ fn program() -> Result<String> {
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugGetSplitFeeWithdrawalTx: true,
+            ..
+        } => {
+            info!("✔ Debug getting split fee withdrawal tx...");
+            Ok(debug_get_split_fee_withdrawal_tx(&db, &cli_args.flag_sig)?)
+        },
+        CliArgs {
+            cmd_debugSetFeeDistributionPolicy: true,
+            ..
+        } => {
+            info!("✔ Debug setting fee distribution policy...");
+            Ok(debug_set_fee_distribution_policy(
+                &db,
+                &cli_args.arg_beneficiariesJson,
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugRemoveFeeDistributionPolicy: true,
+            ..
+        } => {
+            info!("✔ Debug removing fee distribution policy...");
+            Ok(debug_remove_fee_distribution_policy(&db, &cli_args.flag_sig)?)
+        },
         CliArgs {
             cmd_debugErc777ProxyChangePNetworkByProxy: true,
             ..
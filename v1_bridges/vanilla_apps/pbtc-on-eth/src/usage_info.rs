@@ -27,6 +27,9 @@ Usage:  pbtc-on-eth [--help]
         pbtc-on-eth debugGetKeyFromDb <key> [--sig=<hex>]
         pbtc-on-eth debugSetEthGasPrice <wei> [--sig=<hex>]
         pbtc-on-eth debugWithdrawFees <address> [--sig=<hex>]
+        pbtc-on-eth debugGetSplitFeeWithdrawalTx [--sig=<hex>]
+        pbtc-on-eth debugRemoveFeeDistributionPolicy [--sig=<hex>]
+        pbtc-on-eth debugSetFeeDistributionPolicy <beneficiariesJson> [--sig=<hex>]
         pbtc-on-eth debugSetAccruedFees <amount> [--sig=<hex>]
         pbtc-on-eth debugRemoveUtxo <txId> <vOut> [--sig=<hex>]
         pbtc-on-eth debugSetPegInFee <basisPoints> [--sig=<hex>]
@@ -165,6 +168,20 @@ Commands:
     debugWithdrawFees                   ❍ Creates a BTC transaction to the passed in address for the amount of the total
                                           accrued fees in the core.
 
+    debugGetSplitFeeWithdrawalTx        ❍ Creates a single BTC transaction splitting the total accrued fees in the core
+                                          between the beneficiaries of the fee distribution policy.
+
+    debugSetFeeDistributionPolicy       ❍ Sets the policy used to split withdrawn fees between beneficiaries.
+                                          ➔ beneficiariesJson Format:
+                                          [
+                                            {
+                                              `recipient`: A BTC address.
+                                              `share_basis_points`: The recipient's share, all of which must total 10000.
+                                            }
+                                          ]
+
+    debugRemoveFeeDistributionPolicy    ❍ Removes the fee distribution policy.
+
     debugSetKeyInDbToValue              ❍ Set a given <key> in the database to a given <value>. This function can only
                                           be called if the `debug` flag is set to true when the core is built. Note that
                                           there are zero checks on what is passed in to the database: Use at own risk!
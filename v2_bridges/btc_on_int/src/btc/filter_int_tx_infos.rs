@@ -1,15 +1,15 @@
 use common::{traits::DatabaseInterface, types::Result};
-use common_btc::{convert_satoshis_to_wei, BtcState, MINIMUM_REQUIRED_SATOSHIS};
+use common_btc::{convert_satoshis_to_wei, BtcState, UtxoChainParams};
 
 use crate::btc::{BtcOnIntIntTxInfo, BtcOnIntIntTxInfos};
 
 impl BtcOnIntIntTxInfos {
-    pub fn filter_out_value_too_low(&self) -> Result<Self> {
+    pub fn filter_out_value_too_low(&self, minimum_required_satoshis: u64) -> Result<Self> {
         info!(
             "✔ Filtering out any `BtcOnIntIntTxInfos` below a minimum of {} Satoshis...",
-            MINIMUM_REQUIRED_SATOSHIS
+            minimum_required_satoshis
         );
-        let threshold = convert_satoshis_to_wei(MINIMUM_REQUIRED_SATOSHIS);
+        let threshold = convert_satoshis_to_wei(minimum_required_satoshis);
         Ok(BtcOnIntIntTxInfos::new(
             self.iter()
                 .filter(|params| match params.host_token_amount >= threshold {
@@ -28,8 +28,9 @@ impl BtcOnIntIntTxInfos {
 pub fn maybe_filter_out_value_too_low_btc_on_int_int_tx_infos_in_state<D: DatabaseInterface>(
     state: BtcState<D>,
 ) -> Result<BtcState<D>> {
+    let minimum_required_satoshis = UtxoChainParams::get_from_db(state.db)?.minimum_required_satoshis;
     BtcOnIntIntTxInfos::from_bytes(&state.tx_infos)
-        .and_then(|infos| infos.filter_out_value_too_low())
+        .and_then(|infos| infos.filter_out_value_too_low(minimum_required_satoshis))
         .and_then(|filtered| filtered.to_bytes())
         .map(|bytes| state.add_tx_infos(bytes))
 }
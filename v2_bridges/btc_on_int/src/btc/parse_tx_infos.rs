@@ -20,17 +20,10 @@ use crate::{
 };
 
 impl BtcOnIntIntTxInfos {
-    #[cfg(not(feature = "ltc"))]
     fn get_chain_id_from_network(network: &BtcNetwork) -> Result<BtcChainId> {
         BtcChainId::from_btc_network(network)
     }
 
-    #[cfg(feature = "ltc")]
-    fn get_chain_id_from_network(_network: &BtcNetwork) -> Result<BtcChainId> {
-        // NOTE: We only support Litecoin mainnet, which this is an alias of.
-        Ok(BtcChainId::Bitcoin)
-    }
-
     fn from_btc_tx(
         tx: &BtcTransaction,
         deposit_info: &DepositInfoHashMap,
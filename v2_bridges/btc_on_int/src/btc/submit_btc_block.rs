@@ -84,7 +84,7 @@ pub fn submit_btc_block_to_core<D: DatabaseInterface>(db: &D, block_json_string:
         .and_then(get_btc_output_as_string)
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
@@ -274,10 +274,10 @@ mod tests {
     }
 }
 
-#[cfg(all(test, feature = "ltc"))]
-mod tests {
+#[cfg(test)]
+mod ltc_tests {
     use common::test_utils::get_test_database;
-    use common_btc::init_btc_core;
+    use common_btc::init_btc_core_for_chain;
     use common_eth::{convert_hex_to_eth_address, EthState};
 
     use super::*;
@@ -295,13 +295,14 @@ mod tests {
         let btc_network = "Bitcoin";
         let btc_canon_to_tip_length = 0;
         let btc_block_0 = get_sample_btc_submission_material_json_str_n(4);
-        init_btc_core(
+        init_btc_core_for_chain(
             btc_state,
             &btc_block_0,
             btc_fee,
             btc_difficulty,
             btc_network,
             btc_canon_to_tip_length,
+            "litecoin",
         )
         .unwrap();
 
@@ -4,7 +4,7 @@ use common::{
     types::Result,
     utils::prepend_debug_output_marker_to_string,
 };
-use common_btc::{BtcDbUtils, BtcTransactions};
+use common_btc::{BtcDbUtils, BtcTransactions, UtxoChainParams};
 use common_debug_signers::validate_debug_command_signature;
 use common_eth::{
     end_eth_db_transaction_and_return_state,
@@ -44,6 +44,7 @@ fn reprocess_int_block<D: DatabaseInterface>(db: &D, block_json: &str, signature
                     BtcOnIntBtcTxInfos::from_eth_submission_material(
                         material,
                         &state.eth_db_utils.get_btc_on_eth_smart_contract_address_from_db()?,
+                        &UtxoChainParams::get_from_db(state.db)?,
                     )
                 })
                 .and_then(|params| params.to_bytes())
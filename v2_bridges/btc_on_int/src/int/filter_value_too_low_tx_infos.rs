@@ -1,19 +1,13 @@
-#[cfg(not(test))]
-use common_btc::MINIMUM_REQUIRED_SATOSHIS;
-
 use crate::int::{BtcOnIntBtcTxInfo, BtcOnIntBtcTxInfos};
 
 impl BtcOnIntBtcTxInfos {
-    pub fn filter_out_any_whose_value_is_too_low(&self) -> Self {
+    pub fn filter_out_any_whose_value_is_too_low(&self, minimum_required_satoshis: u64) -> Self {
         info!("✘ Filtering out `BtcOnIntBtcTxInfo` whose amounts are too low...");
+        let threshold = if cfg!(test) { 100 } else { minimum_required_satoshis };
         Self::new(
             self.iter()
                 .filter(|redeem_info| {
-                    #[cfg(not(test))]
-                    let amount_is_too_low = redeem_info.amount_in_satoshis < MINIMUM_REQUIRED_SATOSHIS;
-                    #[cfg(test)]
-                    let amount_is_too_low = redeem_info.amount_in_satoshis < 100;
-                    if amount_is_too_low {
+                    if redeem_info.amount_in_satoshis < threshold {
                         info!(
                             "✘ Filtering out `BtcOnIntBtcTxInfo` ∵ amount too low: {:?}",
                             redeem_info
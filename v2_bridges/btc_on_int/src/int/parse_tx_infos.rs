@@ -6,6 +6,7 @@ use common_btc::{
     BtcPrivateKey,
     BtcRecipientAndAmount,
     BtcRecipientsAndAmounts,
+    UtxoChainParams,
     MAX_NUM_OUTPUTS,
 };
 use common_eth::{
@@ -18,7 +19,6 @@ use common_eth::{
     EthSubmissionMaterial,
     ERC777_REDEEM_EVENT_TOPIC_V2,
 };
-use common_safe_addresses::{safely_convert_str_to_btc_address, safely_convert_str_to_ltc_address};
 use ethereum_types::Address as EthAddress;
 
 use crate::{
@@ -60,7 +60,11 @@ impl BtcOnIntBtcTxInfos {
         Ok(log.is_from_address(erc777_smart_contract_address) && log.contains_topic(&ERC777_REDEEM_EVENT_TOPIC_V2))
     }
 
-    fn from_eth_receipt(receipt: &EthReceipt, erc777_smart_contract_address: &EthAddress) -> Result<Self> {
+    fn from_eth_receipt(
+        receipt: &EthReceipt,
+        erc777_smart_contract_address: &EthAddress,
+        chain_params: &UtxoChainParams,
+    ) -> Result<Self> {
         info!("✔ Getting redeem `BtcOnIntBtcTxInfos` from receipt...");
         Ok(Self::new(
             receipt
@@ -75,15 +79,9 @@ impl BtcOnIntBtcTxInfos {
                 })
                 .map(|log| {
                     let event_params = Erc777RedeemEvent::from_eth_log(log)?;
-
-                    let recipient = if cfg!(feature = "ltc") {
-                        safely_convert_str_to_ltc_address(&event_params.underlying_asset_recipient).to_string()
-                    } else {
-                        safely_convert_str_to_btc_address(&event_params.underlying_asset_recipient).to_string()
-                    };
-
                     Ok(BtcOnIntBtcTxInfo {
-                        recipient,
+                        recipient: chain_params
+                            .safely_convert_str_to_canonical_address(&event_params.underlying_asset_recipient),
                         to: EthAddress::zero(), // NOTE: Because this is a redeem, the token is burnt.
                         from: event_params.redeemer,
                         amount_in_wei: event_params.value,
@@ -99,13 +97,14 @@ impl BtcOnIntBtcTxInfos {
     pub fn from_eth_submission_material(
         submission_material: &EthSubmissionMaterial,
         erc777_smart_contract_address: &EthAddress,
+        chain_params: &UtxoChainParams,
     ) -> Result<Self> {
         info!("✔ Getting `BtcOnIntBtcTxInfos` from ETH submission material...");
         Ok(Self::new(
             submission_material
                 .get_receipts()
                 .iter()
-                .map(|receipt| Ok(Self::from_eth_receipt(receipt, erc777_smart_contract_address)?.0))
+                .map(|receipt| Ok(Self::from_eth_receipt(receipt, erc777_smart_contract_address, chain_params)?.0))
                 .collect::<Result<Vec<Vec<BtcOnIntBtcTxInfo>>>>()?
                 .concat(),
         ))
@@ -128,6 +127,7 @@ pub fn maybe_parse_btc_on_int_tx_infos_and_add_to_state<D: DatabaseInterface>(
                 BtcOnIntBtcTxInfos::from_eth_submission_material(
                     &submission_material,
                     &state.eth_db_utils.get_btc_on_int_smart_contract_address_from_db()?,
+                    &UtxoChainParams::get_from_db(state.db)?,
                 )
                 .and_then(|infos| infos.to_bytes())
                 .map(|bytes| state.add_tx_infos(bytes))
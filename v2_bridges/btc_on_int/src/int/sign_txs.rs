@@ -9,6 +9,7 @@ use common_btc::{
     BtcDbUtils,
     BtcPrivateKey,
     BtcTransactions,
+    UtxoChainParams,
 };
 use common_eth::EthState;
 
@@ -34,7 +35,7 @@ fn to_btc_txs_whilst_extracting_change_outputs<D: DatabaseInterface>(
 ) -> Result<BtcTransactions> {
     Ok(BtcTransactions::new(
         redeem_infos
-            .filter_out_any_whose_value_is_too_low()
+            .filter_out_any_whose_value_is_too_low(UtxoChainParams::get_from_db(db)?.minimum_required_satoshis)
             .iter()
             .map(|redeem_info| {
                 debug!("Signing BTC tx...");
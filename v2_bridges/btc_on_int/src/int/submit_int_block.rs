@@ -4,6 +4,7 @@ use common::{core_type::CoreType, traits::DatabaseInterface, types::Result, AppE
 use common_eth::{
     check_for_parent_of_eth_block_in_state,
     maybe_add_eth_block_and_receipts_to_db_and_return_state,
+    maybe_process_eth_light_client_update_in_state,
     maybe_remove_old_eth_tail_block_and_return_state,
     maybe_remove_receipts_from_eth_canon_block_and_return_state,
     maybe_update_eth_canon_block_hash_and_return_state,
@@ -30,6 +31,7 @@ use crate::int::{
 fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJson) -> Result<IntOutput> {
     parse_eth_submission_material_json_and_put_in_state(json, EthState::init(db))
         .and_then(validate_eth_block_in_state)
+        .and_then(maybe_process_eth_light_client_update_in_state)
         .and_then(check_for_parent_of_eth_block_in_state)
         .and_then(validate_receipts_in_state)
         .and_then(filter_receipts_for_btc_on_int_redeem_events_in_state)
@@ -101,7 +103,7 @@ pub fn submit_int_blocks_to_core<D: DatabaseInterface>(db: &D, blocks: &str) ->
         })
 }
 
-#[cfg(all(test, not(feature = "ltc")))]
+#[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
@@ -5,11 +5,9 @@
 //! consists of two light clients that manage the state of the two chains, along
 //! with the creation and signing of transactions related to each chain.
 
-// NOTE: This means we can (relatively) easily make this LTC based instead of BTC.
-#[cfg(not(feature = "ltc"))]
+// NOTE: The UTXO chain this core tracks is selected at initialization time via the `chain` arg
+// to `maybe_initialize_btc_core_for_chain`, meaning this is always the bitcoin library.
 use bitcoin as bitcoin_crate_alias;
-#[cfg(feature = "ltc")]
-use litecoin as bitcoin_crate_alias;
 
 mod btc;
 mod constants;
@@ -29,8 +27,10 @@ pub use common_btc::{
     debug_set_btc_account_nonce,
     debug_set_btc_fee,
     debug_set_btc_utxo_nonce,
+    debug_set_utxo_chain,
     get_all_utxos_as_json_string as get_all_utxos,
     maybe_initialize_btc_core,
+    maybe_initialize_btc_core_for_chain,
 };
 pub use common_database_utils::{debug_get_key_from_db, debug_set_key_in_db_to_value};
 pub use common_debug_signers::{debug_add_debug_signer, debug_add_multiple_debug_signers, debug_remove_debug_signer};
@@ -40,9 +40,11 @@ pub use common_eth::{
     sign_hex_msg_with_eth_key_with_prefix as sign_hex_msg_with_int_key_with_prefix,
 };
 pub use common_eth_debug::{
+    debug_init_eth_light_client as debug_init_int_light_client,
     debug_reset_eth_chain as debug_reset_int_chain,
     debug_set_eth_account_nonce as debug_set_int_account_nonce,
     debug_set_eth_gas_price as debug_set_int_gas_price,
+    debug_sign_typed_data_with_eth_key as debug_sign_typed_data_with_int_key,
 };
 
 pub use self::{
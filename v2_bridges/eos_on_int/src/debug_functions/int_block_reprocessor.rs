@@ -19,6 +19,7 @@ use crate::{
         maybe_filter_out_int_tx_info_with_value_too_low_in_state,
         maybe_filter_out_zero_eos_asset_amounts_in_state,
         maybe_increment_eos_account_nonce_and_return_state,
+        maybe_save_eos_tx_records_and_return_state,
         maybe_sign_eos_txs_and_add_to_eth_state,
         EosOnIntEosTxInfos,
     },
@@ -60,6 +61,7 @@ fn reprocess_int_block<D: DatabaseInterface>(db: &D, block_json: &str, signature
         .and_then(maybe_filter_out_zero_eos_asset_amounts_in_state)
         .and_then(maybe_divert_txs_to_safe_address_if_destination_is_token_address)
         .and_then(maybe_sign_eos_txs_and_add_to_eth_state)
+        .and_then(maybe_save_eos_tx_records_and_return_state)
         .and_then(maybe_increment_eos_account_nonce_and_return_state)
         .and_then(end_eth_db_transaction_and_return_state)
         .and_then(get_int_output)
@@ -1,9 +1,11 @@
 mod debug_get_all_db_keys;
+mod debug_resign_eos_tx;
 mod eos_block_reprocessor;
 mod int_block_reprocessor;
 
 pub use self::{
     debug_get_all_db_keys::debug_get_all_db_keys,
+    debug_resign_eos_tx::debug_resign_eos_tx,
     eos_block_reprocessor::{debug_reprocess_eos_block, debug_reprocess_eos_block_with_nonce},
     int_block_reprocessor::debug_reprocess_int_block,
 };
@@ -1,5 +1,6 @@
 mod divert_to_safe_address;
 mod eos_tx_info;
+mod eos_tx_records;
 mod filter_receipts_in_state;
 mod filter_tx_info;
 mod get_int_output;
@@ -13,14 +14,15 @@ mod submit_int_block;
 pub(super) use self::{
     divert_to_safe_address::maybe_divert_txs_to_safe_address_if_destination_is_token_address,
     eos_tx_info::{EosOnIntEosTxInfo, EosOnIntEosTxInfos},
+    eos_tx_records::maybe_save_eos_tx_records_and_return_state,
     filter_receipts_in_state::filter_receipts_for_eos_on_int_eos_tx_info_in_state,
     filter_tx_info::{
         maybe_filter_out_int_tx_info_with_value_too_low_in_state,
         maybe_filter_out_zero_eos_asset_amounts_in_state,
     },
-    get_int_output::get_int_output,
+    get_int_output::{get_int_output, EosTxInfo, IntOutput},
     increment_eos_nonce::maybe_increment_eos_account_nonce_and_return_state,
-    sign_txs::maybe_sign_eos_txs_and_add_to_eth_state,
+    sign_txs::{maybe_sign_eos_txs_and_add_to_eth_state, ZERO_ETH_ASSET_STR},
 };
 pub use self::{
     initialize_int_core::maybe_initialize_int_core,
@@ -18,7 +18,7 @@ use eos_chain::{AccountName as EosAccountName, Action as EosAction, PermissionLe
 
 use crate::int::eos_tx_info::{EosOnIntEosTxInfo, EosOnIntEosTxInfos};
 
-const ZERO_ETH_ASSET_STR: &str = "0.0000 EOS";
+pub(crate) const ZERO_ETH_ASSET_STR: &str = "0.0000 EOS";
 
 impl EosOnIntEosTxInfos {
     pub fn to_eos_signed_txs(
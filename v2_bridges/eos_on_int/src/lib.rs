@@ -15,14 +15,20 @@ mod test_utils;
 
 pub use common_database_utils::{debug_get_key_from_db, debug_set_key_in_db_to_value};
 pub use common_debug_signers::{debug_add_debug_signer, debug_add_multiple_debug_signers, debug_remove_debug_signer};
+pub use common_dictionary_debug::{
+    debug_add_entry_to_token_dictionary,
+    debug_remove_entry_from_token_dictionary,
+    debug_set_token_dictionary_accrued_fees,
+    debug_set_token_dictionary_fee_basis_points,
+    get_token_dictionary,
+};
 pub use common_eos::{
     debug_add_global_sequences_to_processed_list,
     debug_add_new_eos_schedule,
-    debug_add_token_dictionary_entry,
+    debug_confirm_eos_tx_inclusion,
     debug_disable_eos_protocol_feature,
     debug_enable_eos_protocol_feature,
     debug_remove_global_sequences_from_processed_list,
-    debug_remove_token_dictionary_entry,
     debug_set_eos_account_nonce,
     debug_update_incremerkle,
 };
@@ -32,9 +38,11 @@ pub use common_eth::{
     sign_hex_msg_with_eth_key_with_prefix as sign_hex_msg_with_int_key_with_prefix,
 };
 pub use common_eth_debug::{
+    debug_init_eth_light_client as debug_init_int_light_client,
     debug_reset_eth_chain as debug_reset_int_chain,
     debug_set_eth_account_nonce as debug_set_int_account_nonce,
     debug_set_eth_gas_price as debug_set_int_gas_price,
+    debug_sign_typed_data_with_eth_key as debug_sign_typed_data_with_int_key,
 };
 
 pub use self::{
@@ -44,6 +52,7 @@ pub use self::{
         debug_reprocess_eos_block,
         debug_reprocess_eos_block_with_nonce,
         debug_reprocess_int_block,
+        debug_resign_eos_tx,
     },
     eos::{maybe_initialize_eos_core, submit_eos_block_to_core, EosOnIntIntTxInfos},
     get_enclave_state::get_enclave_state,
@@ -54,6 +63,8 @@ pub use self::{
 #[macro_use]
 extern crate common;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 extern crate log;
 #[macro_use]
 extern crate paste;
@@ -1,6 +1,6 @@
 use common::{
     constants::DB_KEY_PREFIX,
-    dictionaries::dictionary_constants::ETH_EVM_DICTIONARY_KEY,
+    dictionaries::dictionary_constants::{ETH_EVM_DICTIONARY_KEY, ETH_EVM_PENDING_TX_INFOS_KEY},
     traits::DatabaseInterface,
     types::Result,
 };
@@ -26,6 +26,7 @@ pub fn debug_get_all_db_keys<D: DatabaseInterface>(db: &D, signature: &str) -> R
                 "eth": EthDatabaseKeysJson::new(),
                 "db_key_prefix": DB_KEY_PREFIX.to_string(),
                 "dictionary": hex::encode(ETH_EVM_DICTIONARY_KEY.to_vec()),
+                "pending_tx_infos": hex::encode(ETH_EVM_PENDING_TX_INFOS_KEY.to_vec()),
                 "debug_signatories": format!("0x{}", hex::encode(*DEBUG_SIGNATORIES_DB_KEY)),
             })
             .to_string())
@@ -11,19 +11,42 @@ use common_eth::{
     EthDbUtils,
     EthDbUtilsExt,
     EthTransaction,
+    EthTxInfoCompatible,
 };
+use common_fees::{FeeDistributionPolicies, FeePayout, FeeWithdrawalAuditLog, FeeWithdrawalRecord};
+use ethereum_types::{Address as EthAddress, U256};
 use function_name::named;
 use serde_json::json;
 
 use crate::constants::CORE_TYPE;
 
+fn get_signed_fee_withdrawal_tx<D: DatabaseInterface>(
+    eth_db_utils: &EthDbUtils<D>,
+    token_address: EthAddress,
+    recipient_address: EthAddress,
+    amount: U256,
+    nonce: u64,
+) -> Result<EthTransaction> {
+    let chain_id = eth_db_utils.get_eth_chain_id_from_db()?;
+    EthTransaction::new_unsigned(
+        encode_erc20_vault_peg_out_fxn_data_without_user_data(recipient_address, token_address, amount)?,
+        nonce,
+        0,
+        eth_db_utils.get_erc20_on_evm_smart_contract_address_from_db()?,
+        &chain_id,
+        chain_id.get_erc20_vault_pegout_without_user_data_gas_limit(),
+        eth_db_utils.get_eth_gas_price_from_db()?,
+    )
+    .sign(&eth_db_utils.get_eth_private_key_from_db()?)
+}
+
 /// # Debug Withdraw Fees
 ///
 /// This function takes an address and uses it to search through the token dictionary to find a
 /// corresponding entry. Once found, that entry's accrued fees are zeroed, a timestamp set in that
 /// entry to mark the withdrawal date and the dictionary saved back in the database. Finally, an
 /// ETH transaction is created to transfer the `<accrued_fees>` amount of tokens to the passed in
-/// recipient address.
+/// recipient address, and the withdrawal is recorded in the fee withdrawal audit log.
 ///
 /// #### NOTE: This function will increment the ETH nonce and so the output transation MUST be
 /// broadcast otherwise future transactions are liable to fail.
@@ -42,26 +65,110 @@ pub fn debug_withdraw_fees_and_save_in_db<D: DatabaseInterface>(
         .and_then(|_| EthEvmTokenDictionary::get_from_db(db))
         .and_then(|dictionary| dictionary.withdraw_fees_and_save_in_db(db, &convert_hex_to_eth_address(token_address)?))
         .and_then(|(token_address, fee_amount)| {
-            let chain_id = eth_db_utils.get_eth_chain_id_from_db()?;
-            Ok(EthTransaction::new_unsigned(
-                encode_erc20_vault_peg_out_fxn_data_without_user_data(
-                    convert_hex_to_eth_address(recipient_address)?,
-                    token_address,
+            let signed_tx = get_signed_fee_withdrawal_tx(
+                &eth_db_utils,
+                token_address,
+                convert_hex_to_eth_address(recipient_address)?,
+                fee_amount,
+                eth_db_utils.get_eth_account_nonce_from_db()?,
+            )?;
+            FeeWithdrawalAuditLog::add_record_and_save_in_db(
+                db,
+                FeeWithdrawalRecord::new(
+                    &format!("0x{}", hex::encode(token_address)),
                     fee_amount,
+                    vec![FeePayout {
+                        amount: fee_amount,
+                        recipient: recipient_address.to_string(),
+                    }],
+                    U256::zero(),
+                    vec![format!("0x{}", signed_tx.get_tx_hash())],
                 )?,
-                eth_db_utils.get_eth_account_nonce_from_db()?,
-                0,
-                eth_db_utils.get_erc20_on_evm_smart_contract_address_from_db()?,
-                &chain_id,
-                chain_id.get_erc20_vault_pegout_without_user_data_gas_limit(),
-                eth_db_utils.get_eth_gas_price_from_db()?,
-            ))
+            )?;
+            Ok(signed_tx.serialize_hex())
         })
-        .and_then(|unsigned_tx| unsigned_tx.sign(&eth_db_utils.get_eth_private_key_from_db()?))
-        .map(|signed_tx| signed_tx.serialize_hex())
         .and_then(|hex_tx| {
             eth_db_utils.increment_eth_account_nonce_in_db(1)?;
             db.end_transaction()?;
             Ok(json!({"success": true, "eth_signed_tx": hex_tx}).to_string())
         })
 }
+
+/// # Debug Withdraw Fees To Beneficiaries
+///
+/// This function withdraws the accrued fees for the dictionary entry found via the passed in token
+/// address, splitting them between the beneficiaries of that token's fee distribution policy. One
+/// ETH transaction is created per beneficiary, any rounding dust is carried forward to the next
+/// withdrawal, and the withdrawal is recorded in the fee withdrawal audit log.
+///
+/// #### NOTE: This function will increment the ETH nonce once per transaction and so ALL the output
+/// transactions MUST be broadcast otherwise future transactions are liable to fail.
+#[named]
+pub fn debug_withdraw_fees_to_beneficiaries<D: DatabaseInterface>(
+    db: &D,
+    token_address: &str,
+    signature: &str,
+) -> Result<String> {
+    let eth_db_utils = EthDbUtils::new(db);
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), token_address)())
+        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
+        .and_then(|_| EthEvmTokenDictionary::get_from_db(db))
+        .and_then(|dictionary| dictionary.withdraw_fees_and_save_in_db(db, &convert_hex_to_eth_address(token_address)?))
+        .and_then(|(token_address, fee_amount)| {
+            let token = format!("0x{}", hex::encode(token_address));
+            let policies = FeeDistributionPolicies::get_from_db(db)?;
+            let split = policies.get_policy(&token)?.split(fee_amount);
+            if split.payouts.is_empty() {
+                return Err(format!(
+                    "Cannot withdraw fees for token {} - there are no fees to withdraw!",
+                    token
+                )
+                .into());
+            };
+            let start_nonce = eth_db_utils.get_eth_account_nonce_from_db()?;
+            let signed_txs = split
+                .payouts
+                .iter()
+                .enumerate()
+                .map(|(i, payout)| {
+                    get_signed_fee_withdrawal_tx(
+                        &eth_db_utils,
+                        token_address,
+                        convert_hex_to_eth_address(&payout.recipient)?,
+                        payout.amount,
+                        start_nonce + i as u64,
+                    )
+                })
+                .collect::<Result<Vec<EthTransaction>>>()?;
+            policies.update_carried_dust(&token, split.dust)?.save_in_db(db)?;
+            FeeWithdrawalAuditLog::add_record_and_save_in_db(
+                db,
+                FeeWithdrawalRecord::new(
+                    &token,
+                    fee_amount,
+                    split.payouts.clone(),
+                    split.dust,
+                    signed_txs.iter().map(|tx| format!("0x{}", tx.get_tx_hash())).collect(),
+                )?,
+            )?;
+            eth_db_utils.increment_eth_account_nonce_in_db(signed_txs.len() as u64)?;
+            db.end_transaction()?;
+            Ok(json!({
+                "success": true,
+                "dust_carried_forward": split.dust.to_string(),
+                "payouts": split
+                    .payouts
+                    .iter()
+                    .zip(signed_txs.iter())
+                    .map(|(payout, tx)| json!({
+                        "recipient": payout.recipient,
+                        "amount": payout.amount.to_string(),
+                        "eth_signed_tx": tx.serialize_hex(),
+                    }))
+                    .collect::<Vec<_>>(),
+            })
+            .to_string())
+        })
+}
@@ -15,7 +15,7 @@ pub use self::{
     debug_get_weth_unwrapper_tx::debug_get_add_weth_unwrapper_address_tx,
     debug_set_accrued_fees::debug_set_accrued_fees_in_dictionary,
     debug_set_fee_basis_points::debug_set_fee_basis_points,
-    debug_withdraw_fees::debug_withdraw_fees_and_save_in_db,
+    debug_withdraw_fees::{debug_withdraw_fees_and_save_in_db, debug_withdraw_fees_to_beneficiaries},
     eth_block_reprocessor::{
         debug_reprocess_eth_block,
         debug_reprocess_eth_block_with_fee_accrual,
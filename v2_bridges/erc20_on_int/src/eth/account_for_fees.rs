@@ -1,6 +1,7 @@
 use common::{dictionaries::eth_evm::EthEvmTokenDictionary, traits::DatabaseInterface, types::Result};
 use common_eth::EthState;
-use common_fees::DISABLE_FEES;
+use common_fees::{FeeBreakdown, FeeSchedules, DISABLE_FEES};
+use common_metadata::MetadataChainId;
 use ethereum_types::{Address as EthAddress, U256};
 
 use crate::{
@@ -27,6 +28,16 @@ impl FeeCalculator for Erc20OnIntIntTxInfo {
         self.eth_token_address
     }
 
+    fn get_destination_chain_id(&self) -> MetadataChainId {
+        MetadataChainId::from(&self.destination_chain_id)
+    }
+
+    fn update_fee_breakdown(&self, fee_breakdown: FeeBreakdown) -> Self {
+        let mut new_self = self.clone();
+        new_self.fee_breakdown = Some(fee_breakdown);
+        new_self
+    }
+
     fn subtract_amount(&self, subtrahend: U256) -> Result<Self> {
         if subtrahend >= self.native_token_amount {
             Err(format!("Cannot subtract amount from `{}`: subtrahend too large!", TX_INFO_TYPE).into())
@@ -42,29 +53,42 @@ impl FeeCalculator for Erc20OnIntIntTxInfo {
 }
 
 impl FeesCalculator for Erc20OnIntIntTxInfos {
-    fn get_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Vec<(EthAddress, U256)>> {
+    fn get_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Vec<(EthAddress, U256)>> {
         debug!("Calculating fees in `{}`...", TX_INFO_TYPE);
         self.iter()
-            .map(|info| info.calculate_fee_via_dictionary(dictionary))
+            .map(|info| info.calculate_fee_via_schedules_or_dictionary(dictionary, fee_schedules))
             .collect()
     }
 
-    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Self> {
-        self.get_fees(dictionary).and_then(|fee_tuples| {
-            Ok(Self::new(
-                self.iter()
-                    .zip(fee_tuples.iter())
-                    .map(|(info, (_, fee))| {
-                        if *fee == U256::zero() {
-                            debug!("Not subtracting fee because `fee` is 0!");
-                            Ok(info.clone())
-                        } else {
-                            info.subtract_amount(*fee)
-                        }
-                    })
-                    .collect::<Result<Vec<Erc20OnIntIntTxInfo>>>()?,
-            ))
-        })
+    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary, fee_schedules: &FeeSchedules) -> Result<Self> {
+        Ok(Self::new(
+            self.iter()
+                .map(|info| info.subtract_fee_via_schedules_or_dictionary(dictionary, fee_schedules))
+                .collect::<Result<Vec<Erc20OnIntIntTxInfo>>>()?,
+        ))
+    }
+
+    fn filter_out_those_not_covering_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Self> {
+        let mut filtered = vec![];
+        for info in self.iter() {
+            if info.amount_covers_fee(dictionary, fee_schedules)? {
+                filtered.push(info.clone())
+            } else {
+                warn!(
+                    "✘ Filtering out `Erc20OnIntIntTxInfo` ∵ its amount does not cover its fee: {:?}",
+                    info
+                )
+            }
+        }
+        Ok(Self::new(filtered))
     }
 }
 
@@ -91,9 +115,10 @@ pub fn update_accrued_fees_in_dictionary_and_return_state<D: DatabaseInterface>(
     } else {
         info!("✔ Accruing fees during ETH block submission...");
         let dictionary = EthEvmTokenDictionary::get_from_db(state.db)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
         let tx_infos = Erc20OnIntIntTxInfos::from_bytes(&state.tx_infos)?;
         tx_infos
-            .get_fees(&dictionary)
+            .get_fees(&dictionary, &fee_schedules)
             .and_then(|fees| dictionary.increment_accrued_fees_and_save_in_db(state.db, fees))
             .and(Ok(state))
     }
@@ -115,26 +140,49 @@ pub fn account_for_fees_in_evm_tx_infos_in_state<D: DatabaseInterface>(state: Et
             TX_INFO_TYPE
         );
         let dictionary = EthEvmTokenDictionary::get_from_db(state.db)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
         let tx_infos = Erc20OnIntIntTxInfos::from_bytes(&state.tx_infos)?;
         tx_infos
-            .subtract_fees(&dictionary)
+            .subtract_fees(&dictionary, &fee_schedules)
             .and_then(|tx_infos| tx_infos.to_bytes())
             .map(|bytes| state.add_tx_infos(bytes))
     }
 }
 
+/// Rejects any pegs whose amount does not exceed the fee they'd be charged, so they're neither
+/// charged nor signed.
+pub fn filter_out_tx_infos_not_covering_fees_from_state<D: DatabaseInterface>(
+    state: EthState<D>,
+) -> Result<EthState<D>> {
+    if DISABLE_FEES || state.tx_infos.is_empty() {
+        Ok(state)
+    } else {
+        info!("✔ Filtering out `Erc20OnIntIntTxInfos` not covering their fees...");
+        let tx_infos = Erc20OnIntIntTxInfos::from_bytes(&state.tx_infos)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
+        EthEvmTokenDictionary::get_from_db(state.db)
+            .and_then(|ref dictionary| tx_infos.filter_out_those_not_covering_fees(dictionary, &fee_schedules))
+            .and_then(|filtered_infos| filtered_infos.to_bytes())
+            .map(|bytes| state.add_tx_infos(bytes))
+    }
+}
+
 pub fn maybe_account_for_fees<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
     info!(
         "✔ Accounting for fees in `{}` during ETH block submission...",
         TX_INFO_TYPE
     );
-    update_accrued_fees_in_dictionary_and_return_state(state).and_then(account_for_fees_in_evm_tx_infos_in_state)
+    filter_out_tx_infos_not_covering_fees_from_state(state)
+        .and_then(update_accrued_fees_in_dictionary_and_return_state)
+        .and_then(account_for_fees_in_evm_tx_infos_in_state)
 }
 
 #[cfg(test)]
 mod tests {
+    use common_fees::FeeSchedule;
+
     use super::*;
-    use crate::test_utils::get_sample_int_tx_info;
+    use crate::test_utils::{get_sample_int_tx_info, get_sample_token_dictionary};
 
     #[test]
     fn should_calculate_eth_on_evm_evm_tx_info_fee() {
@@ -161,4 +209,49 @@ mod tests {
         let result = info.subtract_amount(subtrahend);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn should_subtract_fee_via_fee_schedule_and_set_fee_breakdown() {
+        let info = get_sample_int_tx_info();
+        let dictionary = get_sample_token_dictionary();
+        let schedule = FeeSchedule::new(U256::from(100), 0, U256::zero(), None).unwrap();
+        let fee_schedules =
+            FeeSchedules::default().set_schedule(&info.eth_token_address, &Some(info.destination_chain_id), schedule);
+        let result = info
+            .subtract_fee_via_schedules_or_dictionary(&dictionary, &fee_schedules)
+            .unwrap();
+        assert_eq!(result.native_token_amount, info.native_token_amount - U256::from(100));
+        assert_eq!(result.fee_breakdown.unwrap().total_fee, U256::from(100));
+    }
+
+    #[test]
+    fn should_set_fee_breakdown_when_falling_back_to_dictionary_basis_points() {
+        let info = get_sample_int_tx_info();
+        let dictionary = get_sample_token_dictionary();
+        let basis_points = dictionary.get_fee_basis_points(&info.eth_token_address).unwrap();
+        let result = info
+            .subtract_fee_via_schedules_or_dictionary(&dictionary, &FeeSchedules::default())
+            .unwrap();
+        let fee_breakdown = result.fee_breakdown.unwrap();
+        assert_eq!(fee_breakdown.basis_points, basis_points);
+        assert_eq!(fee_breakdown.total_fee, info.calculate_fee(basis_points));
+    }
+
+    #[test]
+    fn should_filter_out_tx_infos_not_covering_fees() {
+        let info = get_sample_int_tx_info();
+        let dictionary = get_sample_token_dictionary();
+        let schedule = FeeSchedule::new(U256::zero(), 0, info.native_token_amount, None).unwrap();
+        let fee_schedules =
+            FeeSchedules::default().set_schedule(&info.eth_token_address, &Some(info.destination_chain_id), schedule);
+        let infos = Erc20OnIntIntTxInfos::new(vec![info]);
+        let result = infos
+            .filter_out_those_not_covering_fees(&dictionary, &FeeSchedules::default())
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(infos
+            .filter_out_those_not_covering_fees(&dictionary, &fee_schedules)
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -13,6 +13,7 @@ use common_eth::{
     EthTxInfoCompatible,
     RelayTransaction,
 };
+use common_fees::FeeBreakdown;
 
 use crate::eth::int_tx_info::{Erc20OnIntIntTxInfo as EthOnIntEvmTxInfo, Erc20OnIntIntTxInfos as EthOnIntEvmTxInfos};
 
@@ -38,6 +39,7 @@ make_struct_with_test_assertions_on_equality_check!(
         broadcast_tx_hash: Option<String>,
         broadcast_timestamp: Option<String>,
         any_sender_tx: Option<RelayTransaction>,
+        fee_breakdown: Option<FeeBreakdown>,
     }
 );
 
@@ -57,6 +59,7 @@ impl IntTxInfo {
             broadcast_timestamp: None,
             int_signed_tx: tx.eth_tx_hex(),
             any_sender_tx: tx.any_sender_tx(),
+            fee_breakdown: tx_info.fee_breakdown.clone(),
             _id: if tx.is_any_sender() {
                 format!("perc20-on-int-int-any-sender-{}", nonce)
             } else {
@@ -1,4 +1,5 @@
 mod account_for_fees;
+mod apply_token_limits;
 mod divert_to_safe_address;
 mod filter_submission_material;
 mod filter_tx_info_with_no_erc20_transfer_event;
@@ -17,6 +18,7 @@ pub(super) use int_tx_info::Erc20OnIntIntTxInfo;
 pub(super) use self::{
     account_for_fees::{
         account_for_fees_in_evm_tx_infos_in_state,
+        filter_out_tx_infos_not_covering_fees_from_state,
         update_accrued_fees_in_dictionary_and_return_state as update_accrued_fees_in_dictionary_and_return_eth_state,
     },
     divert_to_safe_address::{
@@ -56,6 +56,7 @@ impl Erc20OnIntIntTxInfos {
                         destination_address: event_params.destination_address.clone(),
                         destination_chain_id: event_params.get_destination_chain_id()?,
                         evm_token_address: dictionary.get_evm_address_from_eth_address(&event_params.token_address)?,
+                        fee_breakdown: None,
                     };
                     info!("✔ Parsed tx info: {:?}", tx_info);
                     Ok(tx_info)
@@ -1,5 +1,6 @@
 use common::{dictionaries::eth_evm::EthEvmTokenDictionary, types::Result};
-use common_fees::{sanity_check_basis_points_value, FEE_BASIS_POINTS_DIVISOR};
+use common_fees::{sanity_check_basis_points_value, FeeBreakdown, FeeSchedule, FeeSchedules, FEE_BASIS_POINTS_DIVISOR};
+use common_metadata::MetadataChainId;
 use ethereum_types::{Address as EthAddress, U256};
 
 pub trait FeeCalculator {
@@ -7,10 +8,16 @@ pub trait FeeCalculator {
 
     fn get_token_address(&self) -> EthAddress;
 
+    fn get_destination_chain_id(&self) -> MetadataChainId;
+
     fn subtract_amount(&self, subtrahend: U256) -> Result<Self>
     where
         Self: Sized;
 
+    fn update_fee_breakdown(&self, fee_breakdown: FeeBreakdown) -> Self
+    where
+        Self: Sized;
+
     fn calculate_fee(&self, fee_basis_points: u64) -> U256 {
         if fee_basis_points > 0 {
             debug!("Calculating fee using `fee_basis_points` of {}", fee_basis_points);
@@ -21,21 +28,82 @@ pub trait FeeCalculator {
         }
     }
 
-    fn calculate_fee_via_dictionary(&self, dictionary: &EthEvmTokenDictionary) -> Result<(EthAddress, U256)> {
+    /// Gets the fee schedule for this token & destination if there is one, otherwise falling back
+    /// to a schedule of just the basis points in the dictionary.
+    fn get_fee_schedule(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<FeeSchedule> {
         let token_address = self.get_token_address();
-        Ok((
-            token_address,
-            self.calculate_fee(sanity_check_basis_points_value(
+        match fee_schedules.get_schedule(&token_address, &self.get_destination_chain_id()) {
+            Some(schedule) => Ok(schedule),
+            None => Ok(FeeSchedule::from_basis_points(sanity_check_basis_points_value(
                 dictionary.get_fee_basis_points(&token_address)?,
-            )?),
-        ))
+            )?)),
+        }
+    }
+
+    fn amount_covers_fee(&self, dictionary: &EthEvmTokenDictionary, fee_schedules: &FeeSchedules) -> Result<bool> {
+        self.get_fee_schedule(dictionary, fee_schedules)
+            .map(|schedule| schedule.amount_covers_fee(self.get_amount()))
+    }
+
+    fn calculate_fee_breakdown(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<FeeBreakdown> {
+        self.get_fee_schedule(dictionary, fee_schedules)
+            .and_then(|schedule| schedule.calculate_fee(self.get_amount()))
+    }
+
+    fn calculate_fee_via_schedules_or_dictionary(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<(EthAddress, U256)> {
+        self.calculate_fee_breakdown(dictionary, fee_schedules)
+            .map(|fee_breakdown| (self.get_token_address(), fee_breakdown.total_fee))
+    }
+
+    fn subtract_fee_via_schedules_or_dictionary(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let fee_breakdown = self.calculate_fee_breakdown(dictionary, fee_schedules)?;
+        debug!("Calculated fee breakdown: {:?}", fee_breakdown);
+        let fee = fee_breakdown.total_fee;
+        let info = self.update_fee_breakdown(fee_breakdown);
+        if fee == U256::zero() {
+            debug!("Not subtracting fee because `fee` is 0!");
+            Ok(info)
+        } else {
+            info.subtract_amount(fee)
+        }
     }
 }
 
 pub trait FeesCalculator {
-    fn get_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Vec<(EthAddress, U256)>>;
+    fn get_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Vec<(EthAddress, U256)>>;
+
+    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary, fee_schedules: &FeeSchedules) -> Result<Self>
+    where
+        Self: Sized;
 
-    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Self>
+    fn filter_out_those_not_covering_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Self>
     where
         Self: Sized;
 }
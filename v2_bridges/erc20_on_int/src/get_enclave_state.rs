@@ -6,6 +6,7 @@ use common::{
 };
 use common_enclave_info::EnclaveInfo;
 use common_eth::{EthDbUtils, EthDbUtilsExt, EthEnclaveState, EvmDbUtils, EvmEnclaveState};
+use common_fees::{FeeDistributionPolicies, FeeWithdrawalAuditLog};
 use serde::{Deserialize, Serialize};
 
 use super::constants::CORE_TYPE;
@@ -16,6 +17,8 @@ struct EnclaveState {
     eth: EthEnclaveState,
     int: EvmEnclaveState,
     token_dictionary: EthEvmTokenDictionary,
+    fee_distribution_policies: FeeDistributionPolicies,
+    fee_withdrawals: FeeWithdrawalAuditLog,
 }
 
 impl EnclaveState {
@@ -33,6 +36,8 @@ impl EnclaveState {
                 Some(eth_db_utils.get_eth_router_smart_contract_address_from_db()?),
             )?,
             token_dictionary: EthEvmTokenDictionary::get_from_db(eth_db_utils.get_db())?,
+            fee_distribution_policies: FeeDistributionPolicies::get_from_db(eth_db_utils.get_db())?,
+            fee_withdrawals: FeeWithdrawalAuditLog::get_from_db(eth_db_utils.get_db())?,
         })
     }
 
@@ -1,6 +1,7 @@
 use common::{dictionaries::eth_evm::EthEvmTokenDictionary, traits::DatabaseInterface, types::Result};
 use common_eth::EthState;
-use common_fees::DISABLE_FEES;
+use common_fees::{FeeBreakdown, FeeSchedules, DISABLE_FEES};
+use common_metadata::MetadataChainId;
 use ethereum_types::{Address as EthAddress, U256};
 
 use crate::{
@@ -27,6 +28,16 @@ impl FeeCalculator for Erc20OnIntEthTxInfo {
         self.native_token_amount
     }
 
+    fn get_destination_chain_id(&self) -> MetadataChainId {
+        MetadataChainId::from(&self.destination_chain_id)
+    }
+
+    fn update_fee_breakdown(&self, fee_breakdown: FeeBreakdown) -> Self {
+        let mut new_self = self.clone();
+        new_self.fee_breakdown = Some(fee_breakdown);
+        new_self
+    }
+
     fn subtract_amount(&self, subtrahend: U256) -> Result<Self> {
         if subtrahend >= self.native_token_amount {
             Err(format!("Cannot subtract amount from `{}`: subtrahend too large!", TX_INFO_TYPE).into())
@@ -42,29 +53,42 @@ impl FeeCalculator for Erc20OnIntEthTxInfo {
 }
 
 impl FeesCalculator for Erc20OnIntEthTxInfos {
-    fn get_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Vec<(EthAddress, U256)>> {
+    fn get_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Vec<(EthAddress, U256)>> {
         debug!("Calculating fees in `{}`...", TX_INFO_TYPE);
         self.iter()
-            .map(|info| info.calculate_fee_via_dictionary(dictionary))
+            .map(|info| info.calculate_fee_via_schedules_or_dictionary(dictionary, fee_schedules))
             .collect()
     }
 
-    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Self> {
-        self.get_fees(dictionary).and_then(|fee_tuples| {
-            Ok(Self::new(
-                self.iter()
-                    .zip(fee_tuples.iter())
-                    .map(|(info, (_, fee))| {
-                        if *fee == U256::zero() {
-                            debug!("Not subtracting fee because `fee` is 0!");
-                            Ok(info.clone())
-                        } else {
-                            info.subtract_amount(*fee)
-                        }
-                    })
-                    .collect::<Result<Vec<Erc20OnIntEthTxInfo>>>()?,
-            ))
-        })
+    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary, fee_schedules: &FeeSchedules) -> Result<Self> {
+        Ok(Self::new(
+            self.iter()
+                .map(|info| info.subtract_fee_via_schedules_or_dictionary(dictionary, fee_schedules))
+                .collect::<Result<Vec<Erc20OnIntEthTxInfo>>>()?,
+        ))
+    }
+
+    fn filter_out_those_not_covering_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Self> {
+        let mut filtered = vec![];
+        for info in self.iter() {
+            if info.amount_covers_fee(dictionary, fee_schedules)? {
+                filtered.push(info.clone())
+            } else {
+                warn!(
+                    "✘ Filtering out `Erc20OnIntEthTxInfo` ∵ its amount does not cover its fee: {:?}",
+                    info
+                )
+            }
+        }
+        Ok(Self::new(filtered))
     }
 }
 
@@ -88,9 +112,10 @@ pub fn update_accrued_fees_in_dictionary_and_return_state<D: DatabaseInterface>(
     } else {
         info!("✔ Accruing fees during INT block submission...");
         let dictionary = EthEvmTokenDictionary::get_from_db(state.db)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
         let tx_infos = Erc20OnIntEthTxInfos::from_bytes(&state.tx_infos)?;
         dictionary
-            .increment_accrued_fees_and_save_in_db(state.db, tx_infos.get_fees(&dictionary)?)
+            .increment_accrued_fees_and_save_in_db(state.db, tx_infos.get_fees(&dictionary, &fee_schedules)?)
             .and(Ok(state))
     }
 }
@@ -108,20 +133,41 @@ pub fn account_for_fees_in_eth_tx_infos_in_state<D: DatabaseInterface>(state: Et
             TX_INFO_TYPE
         );
         let dictionary = EthEvmTokenDictionary::get_from_db(state.db)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
         let tx_infos = Erc20OnIntEthTxInfos::from_bytes(&state.tx_infos)?;
         tx_infos
-            .subtract_fees(&dictionary)
+            .subtract_fees(&dictionary, &fee_schedules)
             .and_then(|updated_tx_infos| updated_tx_infos.to_bytes())
             .map(|bytes| state.add_tx_infos(bytes))
     }
 }
 
+/// Rejects any pegs whose amount does not exceed the fee they'd be charged, so they're neither
+/// charged nor signed.
+pub fn filter_out_tx_infos_not_covering_fees_from_state<D: DatabaseInterface>(
+    state: EthState<D>,
+) -> Result<EthState<D>> {
+    if DISABLE_FEES || state.tx_infos.is_empty() {
+        Ok(state)
+    } else {
+        info!("✔ Filtering out `Erc20OnIntEthTxInfos` not covering their fees...");
+        let tx_infos = Erc20OnIntEthTxInfos::from_bytes(&state.tx_infos)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
+        EthEvmTokenDictionary::get_from_db(state.db)
+            .and_then(|ref dictionary| tx_infos.filter_out_those_not_covering_fees(dictionary, &fee_schedules))
+            .and_then(|filtered_infos| filtered_infos.to_bytes())
+            .map(|bytes| state.add_tx_infos(bytes))
+    }
+}
+
 pub fn maybe_account_for_fees<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
     info!(
         "✔ Accounting for fees in `{}` during INT block submission...",
         TX_INFO_TYPE
     );
-    update_accrued_fees_in_dictionary_and_return_state(state).and_then(account_for_fees_in_eth_tx_infos_in_state)
+    filter_out_tx_infos_not_covering_fees_from_state(state)
+        .and_then(update_accrued_fees_in_dictionary_and_return_state)
+        .and_then(account_for_fees_in_eth_tx_infos_in_state)
 }
 
 #[cfg(test)]
@@ -0,0 +1,35 @@
+use common::{dictionaries::eth_evm::apply_token_limits, traits::DatabaseInterface, types::Result, BridgeSide};
+use common_eth::EthState;
+use ethereum_types::{Address as EthAddress, U256};
+
+use crate::int::eth_tx_info::{Erc20OnIntEthTxInfo, Erc20OnIntEthTxInfos};
+
+fn get_token_address_and_amount(tx_info: &Erc20OnIntEthTxInfo) -> (EthAddress, U256) {
+    (tx_info.eth_token_address, tx_info.native_token_amount)
+}
+
+pub fn apply_token_limits_to_eth_tx_infos_in_state<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
+    info!("✔ Applying token limits to `Erc20OnIntEthTxInfos`...");
+    let timestamp = state.get_eth_submission_material()?.get_timestamp().as_secs();
+    let tx_infos = if state.tx_infos.is_empty() {
+        vec![]
+    } else {
+        Erc20OnIntEthTxInfos::from_bytes(&state.tx_infos)?.0
+    };
+    apply_token_limits(
+        state.db,
+        &BridgeSide::Host,
+        tx_infos,
+        get_token_address_and_amount,
+        timestamp,
+    )
+    .and_then(|tx_infos| {
+        debug!("✔ Num `Erc20OnIntEthTxInfos` to process: {}", tx_infos.len());
+        if tx_infos.is_empty() {
+            Ok(vec![])
+        } else {
+            Erc20OnIntEthTxInfos::new(tx_infos).to_bytes()
+        }
+    })
+    .map(|bytes| state.add_tx_infos(bytes))
+}
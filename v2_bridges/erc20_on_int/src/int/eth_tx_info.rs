@@ -7,6 +7,7 @@ use common::{
 };
 use common_chain_ids::EthChainId;
 use common_eth::{convert_eth_address_to_string, convert_eth_hash_to_string, PTokensRouterMetadataEvent};
+use common_fees::FeeBreakdown;
 use common_safe_addresses::SAFE_ETH_ADDRESS_STR;
 use derive_more::{Constructor, Deref, IntoIterator};
 use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
@@ -28,6 +29,8 @@ pub struct Erc20OnIntEthTxInfo {
     pub eth_vault_address: EthAddress,
     pub eth_token_address: EthAddress,
     pub(crate) metadata_event: Option<PTokensRouterMetadataEvent>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_breakdown: Option<FeeBreakdown>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Constructor, Deref, IntoIterator, Serialize, Deserialize)]
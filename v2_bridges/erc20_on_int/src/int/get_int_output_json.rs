@@ -9,6 +9,7 @@ use common::{
 };
 use common_chain_ids::EthChainId;
 use common_eth::{EthDbUtilsExt, EthState, EthTransaction, EthTxInfoCompatible, RelayTransaction};
+use common_fees::FeeBreakdown;
 use common_metadata::MetadataChainId;
 
 use crate::int::eth_tx_info::{Erc20OnIntEthTxInfo, Erc20OnIntEthTxInfos};
@@ -35,6 +36,7 @@ make_struct_with_test_assertions_on_equality_check!(
         broadcast_tx_hash: Option<String>,
         broadcast_timestamp: Option<String>,
         any_sender_tx: Option<RelayTransaction>,
+        fee_breakdown: Option<FeeBreakdown>,
     }
 );
 
@@ -54,6 +56,7 @@ impl EthTxInfo {
             broadcast_timestamp: None,
             eth_signed_tx: tx.eth_tx_hex(),
             any_sender_tx: tx.any_sender_tx(),
+            fee_breakdown: tx_info.fee_breakdown.clone(),
             _id: if tx.is_any_sender() {
                 format!("perc20-on-int-eth-any-sender-{}", nonce)
             } else {
@@ -82,6 +82,7 @@ impl Erc20OnIntEthTxInfos {
                         native_token_amount: dictionary
                             .convert_evm_amount_to_eth_amount(&log.address, event_params.value)?,
                         metadata_event: PTokensRouterMetadataEvent::try_from(receipt).ok(),
+                        fee_breakdown: None,
                     };
                     info!("✔ Parsed tx info: {:?}", tx_info);
                     Ok(tx_info)
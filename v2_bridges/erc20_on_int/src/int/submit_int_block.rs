@@ -5,6 +5,7 @@ use common_eth::{
     check_for_parent_of_evm_block_in_state,
     maybe_add_evm_block_and_receipts_to_db_and_return_state,
     maybe_increment_eth_account_nonce_and_return_state,
+    maybe_process_evm_light_client_update_in_state,
     maybe_remove_old_evm_tail_block_and_return_state,
     maybe_remove_receipts_from_evm_canon_block_and_return_state,
     maybe_update_evm_canon_block_hash_and_return_state,
@@ -21,6 +22,7 @@ use common_eth::{
 
 use crate::int::{
     account_for_fees::maybe_account_for_fees,
+    apply_token_limits::apply_token_limits_to_eth_tx_infos_in_state,
     divert_to_safe_address::{
         divert_tx_infos_to_safe_address_if_destination_is_router_address,
         divert_tx_infos_to_safe_address_if_destination_is_token_address,
@@ -39,6 +41,7 @@ fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJs
     parse_eth_submission_material_json_and_put_in_state(json, EthState::init(db))
         .and_then(validate_evm_block_in_state)
         .and_then(|state| state.get_eth_evm_token_dictionary_and_add_to_state())
+        .and_then(maybe_process_evm_light_client_update_in_state)
         .and_then(check_for_parent_of_evm_block_in_state)
         .and_then(validate_receipts_in_state)
         .and_then(filter_submission_material_for_redeem_events_in_state)
@@ -50,6 +53,7 @@ fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJs
         .and_then(maybe_parse_tx_info_from_canon_block_and_add_to_state)
         .and_then(filter_tx_info_with_no_erc20_transfer_event)
         .and_then(filter_out_zero_value_eth_tx_infos_from_state)
+        .and_then(apply_token_limits_to_eth_tx_infos_in_state)
         .and_then(maybe_account_for_fees)
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_zero_address)
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_vault_address)
@@ -16,6 +16,23 @@ mod test_utils;
 
 pub use common_database_utils::{debug_get_key_from_db, debug_set_key_in_db_to_value};
 pub use common_debug_signers::{debug_add_debug_signer, debug_add_multiple_debug_signers, debug_remove_debug_signer};
+pub use common_dictionary_debug::{
+    debug_add_entry_to_token_dictionary,
+    debug_disable_token_dictionary_entry,
+    debug_enable_token_dictionary_entry,
+    debug_release_pending_tx_info,
+    debug_remove_entry_from_token_dictionary,
+    debug_remove_fee_distribution_policy,
+    debug_remove_fee_schedule,
+    debug_set_fee_distribution_policy,
+    debug_set_fee_schedule,
+    debug_set_token_dictionary_accrued_fees,
+    debug_set_token_dictionary_fee_basis_points,
+    debug_set_token_dictionary_limits,
+    get_fee_schedules,
+    get_pending_tx_infos,
+    get_token_dictionary,
+};
 pub use common_eth::{
     sign_ascii_msg_with_eth_key_with_no_prefix,
     sign_ascii_msg_with_eth_key_with_prefix,
@@ -25,6 +42,8 @@ pub use common_eth::{
     sign_hex_msg_with_evm_key_with_prefix as sign_hex_msg_with_int_key_with_prefix,
 };
 pub use common_eth_debug::{
+    debug_init_eth_light_client,
+    debug_init_evm_light_client as debug_init_int_light_client,
     debug_reset_eth_chain,
     debug_reset_evm_chain as debug_reset_int_chain,
     debug_set_eth_account_nonce,
@@ -33,6 +52,8 @@ pub use common_eth_debug::{
     debug_set_evm_account_nonce as debug_set_int_account_nonce,
     debug_set_evm_any_sender_nonce as debug_set_int_any_sender_nonce,
     debug_set_evm_gas_price as debug_set_int_gas_price,
+    debug_sign_typed_data_with_eth_key,
+    debug_sign_typed_data_with_evm_key as debug_sign_typed_data_with_int_key,
 };
 
 pub use self::{
@@ -53,6 +74,7 @@ pub use self::{
         debug_set_accrued_fees_in_dictionary,
         debug_set_fee_basis_points,
         debug_withdraw_fees_and_save_in_db,
+        debug_withdraw_fees_to_beneficiaries,
     },
     eth::{maybe_initialize_eth_core, submit_eth_block_to_core, submit_eth_blocks_to_core},
     get_enclave_state::get_enclave_state,
@@ -0,0 +1,86 @@
+use std::cmp::max;
+
+use common::{core_type::CoreType, traits::DatabaseInterface, types::Result};
+use common_algo::{AlgoDbUtils, AlgoTxParams};
+use common_debug_signers::validate_debug_command_signature;
+use common_eth::{EthDbUtils, EthDbUtilsExt};
+use function_name::named;
+
+use crate::{
+    constants::CORE_TYPE,
+    int::{AlgoGroupTxParams, AlgoTxInfo, IntOnAlgoAlgoTxRecord, IntOutput},
+};
+
+/// # Debug Resign ALGO Tx
+///
+/// This function re-signs the peg-in group with the passed in ALGO account nonce, using the current
+/// ALGO fee, eg in order to replace a group whose fee was too low for it to be confirmed. The core's
+/// ALGO account nonce is NOT incremented.
+///
+/// Whilst the group is still valid, the re-signed group uses the same lease & the same last valid
+/// round as the original. Since ALGO holds a lease until the last valid round of whichever tx using
+/// it gets confirmed first, & both versions of the group share that round, at most one of them can
+/// ever be confirmed.
+///
+/// Once the group's validity window has passed, it can never be confirmed, & so it may instead be
+/// re-issued with a fresh validity window starting at the core's latest ALGO round. Each peg-in may
+/// only be re-issued ONCE, which is recorded in its ALGO tx record. Re-signing the re-issued group
+/// whilst its fresh window remains open works as above.
+///
+/// ### NOTES:
+///
+///  - Only groups signed whilst ALGO tx leases were enabled via the `debug_set_algo_tx_params` function may be
+///    re-signed, since a leased re-signing of an un-leased group would NOT prevent both from being confirmed.
+///
+///  - The core does not track the confirmation of its ALGO txs, so before re-issuing an expired group, it is up to
+///    the signer of this debug command to check that the original group was NOT confirmed during its validity
+///    window, lest the peg-in be minted twice.
+#[named]
+pub fn debug_resign_algo_tx<D: DatabaseInterface>(db: &D, nonce: u64, signature: &str) -> Result<String> {
+    info!("✔ Debug re-signing ALGO tx with nonce {nonce}...");
+    let algo_db_utils = AlgoDbUtils::new(db);
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), &nonce)())
+        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
+        .and_then(|_| IntOnAlgoAlgoTxRecord::get_from_db(db, nonce))
+        .and_then(|record| {
+            let tx_params = AlgoTxParams::get_from_db(db)?;
+            if !tx_params.use_leases {
+                return Err("Cannot re-sign ALGO tx ∵ ALGO tx leases are not enabled!".into());
+            };
+            let lease = record
+                .get_lease()?
+                .ok_or_else(|| format!("Cannot re-sign ALGO tx with nonce {nonce} ∵ it was signed without a lease!"))?;
+            let latest_round = algo_db_utils.get_latest_block_number()?;
+            let is_reissue = record.has_expired(latest_round);
+            if is_reissue && record.reissued {
+                return Err(format!(
+                    "Cannot re-issue ALGO tx with nonce {} ∵ it has already been re-issued once & expired at round {}!",
+                    nonce, record.last_valid_round
+                )
+                .into());
+            };
+            let params = if is_reissue {
+                info!("✔ ALGO tx with nonce {nonce} has expired ∴ re-issuing it with a fresh validity window...");
+                AlgoGroupTxParams::new(&algo_db_utils, &tx_params, latest_round, Some(lease))?
+            } else {
+                let first_valid = max(latest_round, record.first_valid_round);
+                AlgoGroupTxParams::new(&algo_db_utils, &tx_params, first_valid, Some(lease))?
+                    .with_last_valid(record.last_valid_round)
+            };
+            let tx_info = record.get_tx_info()?;
+            let (signed_tx, group_tx, params) =
+                tx_info.to_algo_signed_group_tx(&params, &tx_params, &algo_db_utils.get_algo_private_key()?)?;
+            if is_reissue {
+                record.to_reissued(&params, &group_tx)?.save_in_db(db)?;
+            } else {
+                record.to_resigned(&params, &group_tx)?.save_in_db(db)?;
+            };
+            let output = IntOutput::new(EthDbUtils::new(db).get_latest_eth_block_number()?, vec![
+                AlgoTxInfo::new((signed_tx, group_tx), &tx_info, nonce, latest_round)?,
+            ]);
+            db.end_transaction()?;
+            Ok(output.to_string())
+        })
+}
@@ -1,21 +1,21 @@
 mod algo_block_reprocessor;
-mod debug_change_dictionary;
 mod debug_change_supported_tokens;
 mod debug_get_algo_pay_tx;
 mod debug_get_all_db_keys;
 mod debug_opt_in_to_application;
 mod debug_opt_in_to_asset;
+mod debug_resign_algo_tx;
 mod debug_set_algo_account_nonce;
 mod int_block_reprocessor;
 
 pub use self::{
     algo_block_reprocessor::{debug_reprocess_algo_block, debug_reprocess_algo_block_with_nonce},
-    debug_change_dictionary::{debug_add_dictionary_entry, debug_remove_dictionary_entry},
     debug_change_supported_tokens::debug_get_add_supported_token_tx,
     debug_get_algo_pay_tx::debug_get_algo_pay_tx,
     debug_get_all_db_keys::debug_get_all_db_keys,
     debug_opt_in_to_application::debug_opt_in_to_application,
     debug_opt_in_to_asset::debug_opt_in_to_asset,
+    debug_resign_algo_tx::debug_resign_algo_tx,
     debug_set_algo_account_nonce::debug_set_algo_account_nonce,
     int_block_reprocessor::debug_reprocess_int_block,
 };
@@ -68,7 +68,7 @@ impl IntOnAlgoAlgoTxInfoSerdable {
         }
     }
 
-    fn to_tx_info(&self) -> Result<IntOnAlgoAlgoTxInfo> {
+    pub fn to_tx_info(&self) -> Result<IntOnAlgoAlgoTxInfo> {
         Ok(IntOnAlgoAlgoTxInfo {
             user_data: self.user_data.clone(),
             algo_asset_id: self.algo_asset_id,
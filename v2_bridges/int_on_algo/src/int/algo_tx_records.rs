@@ -0,0 +1,172 @@
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    crypto_utils::sha256_hash_bytes,
+    traits::DatabaseInterface,
+    types::{Byte, Bytes, Result},
+    utils::get_prefixed_db_key,
+    AppError,
+};
+use ethereum_types::H256 as EthHash;
+use rust_algorand::{AlgorandHash, AlgorandTxGroup};
+use serde::{Deserialize, Serialize};
+
+use crate::int::{
+    algo_tx_info::{IntOnAlgoAlgoTxInfo, IntOnAlgoAlgoTxInfoSerdable},
+    sign_txs::AlgoGroupTxParams,
+};
+
+// NOTE: Each record lives under its own key so that the records don't grow into one ever larger
+// db value, & so that expired records remain available should their peg-in need re-issuing.
+fn get_algo_tx_record_db_key(nonce: u64) -> [Byte; 32] {
+    get_prefixed_db_key(&format!("int_on_algo_algo_tx_record_{nonce}"))
+}
+
+/// Gets the lease for a peg-in's signed group, derived from that peg-in's originating tx hash and
+/// its ALGO account nonce so that it's unique to that peg-in and stable across re-signings.
+pub fn get_algo_lease(originating_tx_hash: &EthHash, nonce: u64) -> Result<AlgorandHash> {
+    Ok(AlgorandHash::from_bytes(&sha256_hash_bytes(
+        &[originating_tx_hash.as_bytes(), &nonce.to_be_bytes()].concat(),
+    ))?)
+}
+
+/// A record of a signed peg-in group, holding the validity window, fee & lease it was signed with,
+/// along with the tx info required to re-sign it with a higher fee whilst it remains valid, or to
+/// re-issue it once with a fresh validity window should it expire without being confirmed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IntOnAlgoAlgoTxRecord {
+    pub nonce: u64,
+    pub fee: u64,
+    pub first_valid_round: u64,
+    pub last_valid_round: u64,
+    pub lease: Option<String>,
+    pub algo_tx_hash: String,
+    pub resign_count: u64,
+    pub reissued: bool,
+    tx_info: IntOnAlgoAlgoTxInfoSerdable,
+}
+
+impl IntOnAlgoAlgoTxRecord {
+    pub fn new(
+        nonce: u64,
+        tx_info: &IntOnAlgoAlgoTxInfo,
+        params: &AlgoGroupTxParams,
+        group_tx: &AlgorandTxGroup,
+    ) -> Result<Self> {
+        Ok(Self {
+            nonce,
+            resign_count: 0,
+            reissued: false,
+            fee: params.fee.to_algos(),
+            algo_tx_hash: group_tx.to_id()?,
+            first_valid_round: params.first_valid,
+            last_valid_round: params.last_valid,
+            lease: params.lease.map(|lease| hex::encode(lease.to_bytes())),
+            tx_info: IntOnAlgoAlgoTxInfoSerdable::from_tx_info(tx_info),
+        })
+    }
+
+    pub fn to_resigned(&self, params: &AlgoGroupTxParams, group_tx: &AlgorandTxGroup) -> Result<Self> {
+        Ok(Self {
+            resign_count: self.resign_count + 1,
+            reissued: self.reissued,
+            ..Self::new(self.nonce, &self.get_tx_info()?, params, group_tx)?
+        })
+    }
+
+    pub fn to_reissued(&self, params: &AlgoGroupTxParams, group_tx: &AlgorandTxGroup) -> Result<Self> {
+        Ok(Self {
+            reissued: true,
+            ..self.to_resigned(params, group_tx)?
+        })
+    }
+
+    pub fn get_tx_info(&self) -> Result<IntOnAlgoAlgoTxInfo> {
+        self.tx_info.to_tx_info()
+    }
+
+    pub fn get_lease(&self) -> Result<Option<AlgorandHash>> {
+        match self.lease {
+            Some(ref lease) => Ok(Some(AlgorandHash::from_bytes(&hex::decode(lease)?)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn has_expired(&self, latest_round: u64) -> bool {
+        latest_round > self.last_valid_round
+    }
+
+    fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D, nonce: u64) -> Result<Self> {
+        db.get(get_algo_tx_record_db_key(nonce).to_vec(), MIN_DATA_SENSITIVITY_LEVEL)
+            .map_err(|_| AppError::Custom(format!("No ALGO tx record found with nonce {nonce}!")))
+            .and_then(|bytes| Self::from_bytes(&bytes))
+    }
+
+    /// Saves this record in the db, replacing any existing record with the same nonce, which can
+    /// happen if the core's ALGO account nonce is rolled back via a debug function.
+    pub fn save_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        debug!("✔ Saving ALGO tx record with nonce {} in db...", self.nonce);
+        db.put(
+            get_algo_tx_record_db_key(self.nonce).to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+
+    fn get_sample_record(nonce: u64, last_valid_round: u64) -> IntOnAlgoAlgoTxRecord {
+        IntOnAlgoAlgoTxRecord {
+            nonce,
+            fee: 1000,
+            resign_count: 0,
+            reissued: false,
+            last_valid_round,
+            first_valid_round: last_valid_round - 1000,
+            lease: Some(hex::encode([nonce as u8; 32])),
+            algo_tx_hash: "hash".to_string(),
+            tx_info: IntOnAlgoAlgoTxInfoSerdable::from_tx_info(&IntOnAlgoAlgoTxInfo::default()),
+        }
+    }
+
+    #[test]
+    fn should_get_unique_but_deterministic_leases() {
+        let hash = EthHash::from_low_u64_be(1337);
+        assert_eq!(get_algo_lease(&hash, 0).unwrap(), get_algo_lease(&hash, 0).unwrap());
+        assert_ne!(get_algo_lease(&hash, 0).unwrap(), get_algo_lease(&hash, 1).unwrap());
+    }
+
+    #[test]
+    fn should_save_records_under_their_own_keys_replacing_those_with_same_nonce() {
+        let db = get_test_database();
+        let record_1 = get_sample_record(0, 2000);
+        let record_2 = get_sample_record(1, 2000);
+        let record_3 = get_sample_record(1, 3000);
+        record_1.save_in_db(&db).unwrap();
+        record_2.save_in_db(&db).unwrap();
+        record_3.save_in_db(&db).unwrap();
+        assert_eq!(IntOnAlgoAlgoTxRecord::get_from_db(&db, 0).unwrap(), record_1);
+        assert_eq!(IntOnAlgoAlgoTxRecord::get_from_db(&db, 1).unwrap(), record_3);
+        assert!(IntOnAlgoAlgoTxRecord::get_from_db(&db, 2).is_err());
+    }
+
+    #[test]
+    fn should_only_have_expired_after_last_valid_round() {
+        let record = get_sample_record(0, 2000);
+        assert!(!record.has_expired(2000));
+        assert!(record.has_expired(2001));
+        assert_eq!(record.get_lease().unwrap().unwrap().to_bytes(), vec![0u8; 32]);
+    }
+}
@@ -1,4 +1,5 @@
 mod algo_tx_info;
+mod algo_tx_records;
 mod filter_submission_material;
 mod filter_tx_info_with_no_erc20_transfer_event;
 mod filter_zero_value_tx_infos;
@@ -12,12 +13,13 @@ mod submit_int_block;
 
 pub(super) use self::{
     algo_tx_info::IntOnAlgoAlgoTxInfos,
+    algo_tx_records::IntOnAlgoAlgoTxRecord,
     filter_submission_material::filter_submission_material_for_peg_in_events_in_state,
     filter_tx_info_with_no_erc20_transfer_event::debug_filter_tx_info_with_no_erc20_transfer_event,
     filter_zero_value_tx_infos::filter_out_zero_value_tx_infos_from_state,
-    get_int_output_json::get_int_output_json,
+    get_int_output_json::{get_int_output_json, AlgoTxInfo, IntOutput},
     increment_algo_account_nonce::maybe_increment_algo_account_nonce_and_return_eth_state,
-    sign_txs::maybe_sign_algo_txs_and_add_to_state,
+    sign_txs::{maybe_sign_algo_txs_and_add_to_state, AlgoGroupTxParams},
 };
 pub use self::{
     initialize_int_core::maybe_initialize_int_core,
@@ -1,8 +1,10 @@
+use std::cmp::max;
+
 use common::{
     traits::DatabaseInterface,
     types::{Bytes, Result},
 };
-use common_algo::{AlgoDbUtils, AlgoUserData, ALGO_MAX_FOREIGN_ITEMS};
+use common_algo::{AlgoDbUtils, AlgoTxParams, AlgoUserData, ALGO_MAX_FOREIGN_ITEMS};
 use common_eth::EthState;
 use rust_algorand::{
     AlgorandAddress,
@@ -14,7 +16,73 @@ use rust_algorand::{
     MicroAlgos,
 };
 
-use crate::int::algo_tx_info::{IntOnAlgoAlgoTxInfo, IntOnAlgoAlgoTxInfos};
+use crate::int::{
+    algo_tx_info::{IntOnAlgoAlgoTxInfo, IntOnAlgoAlgoTxInfos},
+    algo_tx_records::{get_algo_lease, IntOnAlgoAlgoTxRecord},
+};
+
+// NOTE: Every peg-in group consists of an asset transfer tx followed by an application call tx.
+const NUM_TXS_PER_PEG_IN_GROUP: usize = 2;
+
+// NOTE: Raising the fee can grow a tx's encoded fee field, so we account for that when sizing.
+const ALGO_FEE_FIELD_SIZE_MARGIN_IN_BYTES: usize = 8;
+
+/// The parameters with which a peg-in group is signed.
+#[derive(Clone, Debug)]
+pub struct AlgoGroupTxParams {
+    pub fee: MicroAlgos,
+    pub first_valid: u64,
+    pub last_valid: u64,
+    pub lease: Option<AlgorandHash>,
+    pub sender: AlgorandAddress,
+    pub genesis_hash: AlgorandHash,
+}
+
+impl AlgoGroupTxParams {
+    pub fn new<D: DatabaseInterface>(
+        algo_db_utils: &AlgoDbUtils<D>,
+        tx_params: &AlgoTxParams,
+        first_valid: u64,
+        lease: Option<AlgorandHash>,
+    ) -> Result<Self> {
+        Ok(Self {
+            first_valid,
+            lease: if tx_params.use_leases { lease } else { None },
+            fee: algo_db_utils.get_algo_fee()?,
+            sender: algo_db_utils.get_redeem_address()?,
+            genesis_hash: algo_db_utils.get_genesis_hash()?,
+            last_valid: tx_params.get_last_valid_round(first_valid),
+        })
+    }
+
+    fn with_fee(&self, fee: MicroAlgos) -> Self {
+        Self { fee, ..self.clone() }
+    }
+
+    pub fn with_last_valid(&self, last_valid: u64) -> Self {
+        Self {
+            last_valid,
+            ..self.clone()
+        }
+    }
+}
+
+fn maybe_add_lease(mut tx: AlgorandTransaction, lease: &Option<AlgorandHash>) -> AlgorandTransaction {
+    if lease.is_some() {
+        tx.lease = *lease;
+    };
+    tx
+}
+
+/// Gets the first valid round for signed ALGO txs, which is never allowed to be older than the
+/// core's ALGO canon block, since a stale round would shorten the txs' validity window.
+fn get_first_valid_round<D: DatabaseInterface>(algo_db_utils: &AlgoDbUtils<D>, submitted_round: u64) -> Result<u64> {
+    let canon_round = algo_db_utils.get_canon_block_number()?;
+    if submitted_round < canon_round {
+        warn!("✘ Submitted first valid round {submitted_round} is older than ALGO canon round {canon_round}!");
+    };
+    Ok(max(submitted_round, canon_round))
+}
 
 impl IntOnAlgoAlgoTxInfo {
     fn maybe_to_metadata_bytes(&self) -> Result<Option<Bytes>> {
@@ -32,43 +100,37 @@ impl IntOnAlgoAlgoTxInfo {
         }
     }
 
-    fn get_asset_transfer_tx(
-        &self,
-        fee: &MicroAlgos,
-        first_valid: u64,
-        genesis_hash: &AlgorandHash,
-        sender: &AlgorandAddress,
-        last_valid: Option<u64>,
-    ) -> Result<AlgorandTransaction> {
-        Ok(AlgorandTransaction::asset_transfer(
-            self.algo_asset_id,
-            *fee,
-            self.host_token_amount.as_u64(),
-            self.maybe_to_metadata_bytes()?,
-            first_valid,
-            *sender,
-            *genesis_hash,
-            last_valid,
-            self.issuance_manager_app_id.to_address()?,
-        )?)
+    // NOTE: Only the asset transfer tx, which is the first in each peg-in group, carries the lease,
+    // since ALGO rejects a tx whose (sender, lease) pair is already in use, which would include
+    // another tx in the same group. Groups are atomic, so leasing one tx suffices for all of them.
+    fn get_asset_transfer_tx(&self, params: &AlgoGroupTxParams) -> Result<AlgorandTransaction> {
+        Ok(maybe_add_lease(
+            AlgorandTransaction::asset_transfer(
+                self.algo_asset_id,
+                params.fee,
+                self.host_token_amount.as_u64(),
+                self.maybe_to_metadata_bytes()?,
+                params.first_valid,
+                params.sender,
+                params.genesis_hash,
+                Some(params.last_valid),
+                self.issuance_manager_app_id.to_address()?,
+            )?,
+            &params.lease,
+        ))
     }
 
     fn to_user_peg_in_signed_group_tx(
         &self,
-        fee: &MicroAlgos,
-        first_valid: u64,
-        genesis_hash: &AlgorandHash,
-        sender: &AlgorandAddress,
+        params: &AlgoGroupTxParams,
         private_key: &AlgorandKeys,
     ) -> Result<(String, AlgorandTxGroup)> {
         info!(
             "✔ Signing ALGO group transaction for a user peg-in with tx info: {:?}",
             self
         );
-        let last_valid = None;
-
         // NOTE: First we transfer the asset in question to the issuance manager app...
-        let asset_transfer_tx = self.get_asset_transfer_tx(fee, first_valid, genesis_hash, sender, last_valid)?;
+        let asset_transfer_tx = self.get_asset_transfer_tx(params)?;
 
         // NOTE: Next we call the issuance manager app, with the ASA in question as one of
         // the foreign assets, and the final destination (as set by the user) as an account.
@@ -84,11 +146,11 @@ impl IntOnAlgoAlgoTxInfo {
         ]);
         let app_call_tx = AlgorandTransaction::application_call_noop(
             self.issuance_manager_app_id.to_u64(),
-            *fee,
-            first_valid,
-            *sender,
-            *genesis_hash,
-            last_valid,
+            params.fee,
+            params.first_valid,
+            params.sender,
+            params.genesis_hash,
+            Some(params.last_valid),
             application_args,
             accounts,
             foreign_apps,
@@ -102,20 +164,15 @@ impl IntOnAlgoAlgoTxInfo {
 
     fn to_application_peg_in_signed_group_tx(
         &self,
-        fee: &MicroAlgos,
-        first_valid: u64,
-        genesis_hash: &AlgorandHash,
-        sender: &AlgorandAddress,
+        params: &AlgoGroupTxParams,
         private_key: &AlgorandKeys,
     ) -> Result<(String, AlgorandTxGroup)> {
         info!(
             "✔ Signing ALGO group transaction for an application peg-in with tx info: {:?}",
             self
         );
-        let last_valid = None;
-
         // NOTE: First we transfer the asset in question to the issuance manager app...
-        let asset_transfer_tx = self.get_asset_transfer_tx(fee, first_valid, genesis_hash, sender, last_valid)?;
+        let asset_transfer_tx = self.get_asset_transfer_tx(params)?;
 
         // NOTE: Now we assemble the ingredients for the application call tx...
         let destination_app_id = self.get_destination_app_id()?;
@@ -148,11 +205,11 @@ impl IntOnAlgoAlgoTxInfo {
         // and call a hook in that application with the provided metadata (if extant).
         let app_call_tx = AlgorandTransaction::application_call_noop(
             self.issuance_manager_app_id.to_u64(),
-            *fee,
-            first_valid,
-            *sender,
-            *genesis_hash,
-            last_valid,
+            params.fee,
+            params.first_valid,
+            params.sender,
+            params.genesis_hash,
+            Some(params.last_valid),
             application_args,
             if foreign_accounts.is_empty() {
                 None
@@ -176,36 +233,67 @@ impl IntOnAlgoAlgoTxInfo {
         Ok((group_tx.sign_transactions(&[private_key])?, group_tx))
     }
 
-    pub fn to_algo_signed_group_tx(
+    fn to_algo_signed_group_tx_with_params(
         &self,
-        fee: &MicroAlgos,
-        first_valid: u64,
-        genesis_hash: &AlgorandHash,
-        sender: &AlgorandAddress,
+        params: &AlgoGroupTxParams,
         private_key: &AlgorandKeys,
     ) -> Result<(String, AlgorandTxGroup)> {
         if self.destination_is_app() {
-            self.to_application_peg_in_signed_group_tx(fee, first_valid, genesis_hash, sender, private_key)
+            self.to_application_peg_in_signed_group_tx(params, private_key)
         } else {
-            self.to_user_peg_in_signed_group_tx(fee, first_valid, genesis_hash, sender, private_key)
+            self.to_user_peg_in_signed_group_tx(params, private_key)
         }
     }
-}
 
-impl IntOnAlgoAlgoTxInfos {
+    /// Signs the peg-in group, first with the minimum fee in the passed in params in order to size
+    /// it, then again with a fee derived from that size if the tx params' fee per byte demands a
+    /// higher one. Returns the signed group along with the params it was finally signed with.
     pub fn to_algo_signed_group_tx(
         &self,
-        fee: &MicroAlgos,
-        first_valid: u64,
-        genesis_hash: &AlgorandHash,
-        sender: &AlgorandAddress,
+        params: &AlgoGroupTxParams,
+        tx_params: &AlgoTxParams,
         private_key: &AlgorandKeys,
-    ) -> Result<Vec<(String, AlgorandTxGroup)>> {
-        info!("✔ Signing `erc20-on-int` INT transactions...");
+    ) -> Result<(String, AlgorandTxGroup, AlgoGroupTxParams)> {
+        let (signed_tx, group_tx) = self.to_algo_signed_group_tx_with_params(params, private_key)?;
+        let group_size_in_bytes = hex::decode(&signed_tx)?.len();
+        let tx_size_in_bytes =
+            group_size_in_bytes.div_ceil(NUM_TXS_PER_PEG_IN_GROUP) + ALGO_FEE_FIELD_SIZE_MARGIN_IN_BYTES;
+        let fee = tx_params.get_fee(&params.fee, tx_size_in_bytes);
+        if fee.to_algos() == params.fee.to_algos() {
+            Ok((signed_tx, group_tx, params.clone()))
+        } else {
+            info!("✔ Re-signing ALGO group tx with size-derived fee of {fee}...");
+            let params = params.with_fee(fee);
+            let (signed_tx, group_tx) = self.to_algo_signed_group_tx_with_params(&params, private_key)?;
+            Ok((signed_tx, group_tx, params))
+        }
+    }
+}
+
+impl IntOnAlgoAlgoTxInfos {
+    pub fn to_algo_signed_group_txs<D: DatabaseInterface>(
+        &self,
+        algo_db_utils: &AlgoDbUtils<D>,
+        submitted_first_valid_round: u64,
+    ) -> Result<Vec<(String, AlgorandTxGroup, IntOnAlgoAlgoTxRecord)>> {
+        info!("✔ Signing `int-on-algo` ALGO transactions...");
+        let tx_params = AlgoTxParams::get_from_db(algo_db_utils.get_db())?;
+        let private_key = algo_db_utils.get_algo_private_key()?;
+        let start_nonce = algo_db_utils.get_algo_account_nonce()?;
+        let first_valid = get_first_valid_round(algo_db_utils, submitted_first_valid_round)?;
         self.iter()
             .enumerate()
             .map(|(i, info)| {
-                info.to_algo_signed_group_tx(fee, first_valid + i as u64, genesis_hash, sender, private_key)
+                let nonce = start_nonce + i as u64;
+                let params = AlgoGroupTxParams::new(
+                    algo_db_utils,
+                    &tx_params,
+                    first_valid + i as u64,
+                    Some(get_algo_lease(&info.originating_tx_hash, nonce)?),
+                )?;
+                let (signed_tx, group_tx, params) = info.to_algo_signed_group_tx(&params, &tx_params, &private_key)?;
+                let record = IntOnAlgoAlgoTxRecord::new(nonce, info, &params, &group_tx)?;
+                Ok((signed_tx, group_tx, record))
             })
             .collect::<Result<Vec<_>>>()
     }
@@ -219,17 +307,20 @@ pub fn maybe_sign_algo_txs_and_add_to_state<D: DatabaseInterface>(state: EthStat
         let algo_db_utils = AlgoDbUtils::new(state.db);
         IntOnAlgoAlgoTxInfos::from_bytes(&state.tx_infos)
             .and_then(|tx_infos| {
-                tx_infos.to_algo_signed_group_tx(
-                    &algo_db_utils.get_algo_fee()?,
+                tx_infos.to_algo_signed_group_txs(
+                    &algo_db_utils,
                     state.get_eth_submission_material()?.get_algo_first_valid_round()?,
-                    &algo_db_utils.get_genesis_hash()?,
-                    &algo_db_utils.get_redeem_address()?,
-                    &algo_db_utils.get_algo_private_key()?,
                 )
             })
-            .map(|signed_txs| {
+            .and_then(|signed_txs_and_records| {
+                let (signed_txs, records): (Vec<_>, Vec<_>) = signed_txs_and_records
+                    .into_iter()
+                    .map(|(signed_tx, group_tx, record)| ((signed_tx, group_tx), record))
+                    .unzip();
+                info!("✔ Saving {} ALGO tx record(s) in db...", records.len());
+                records.iter().try_for_each(|record| record.save_in_db(state.db))?;
                 debug!("✔ Signed transactions: {:?}", signed_txs);
-                state.add_algo_txs(signed_txs)
+                Ok(state.add_algo_txs(signed_txs))
             })
     }
 }
@@ -4,6 +4,7 @@ use common::{core_type::CoreType, traits::DatabaseInterface, types::Result, AppE
 use common_eth::{
     check_for_parent_of_eth_block_in_state,
     maybe_add_eth_block_and_receipts_to_db_and_return_state,
+    maybe_process_eth_light_client_update_in_state,
     maybe_remove_old_eth_tail_block_and_return_state,
     maybe_remove_receipts_from_eth_canon_block_and_return_state,
     maybe_update_eth_canon_block_hash_and_return_state,
@@ -32,6 +33,7 @@ fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJs
     parse_eth_submission_material_json_and_put_in_state(json, EthState::init(db))
         .and_then(validate_eth_block_in_state)
         .and_then(|state| state.get_evm_algo_token_dictionary_and_add_to_state())
+        .and_then(maybe_process_eth_light_client_update_in_state)
         .and_then(check_for_parent_of_eth_block_in_state)
         .and_then(validate_receipts_in_state)
         .and_then(filter_submission_material_for_peg_in_events_in_state)
@@ -0,0 +1,81 @@
+use common::{
+    core_type::CoreType,
+    dictionaries::eos_eth::EosEthTokenDictionary,
+    traits::DatabaseInterface,
+    types::Result,
+    utils::get_unix_timestamp_as_u32,
+};
+use common_debug_signers::validate_debug_command_signature;
+use common_eos::{
+    get_eos_ref_block_from_db,
+    get_eos_tx_expiration_timestamp_with_offset,
+    EosDbUtils,
+    EosPrivateKey,
+    EosTxRecords,
+    Incremerkles,
+};
+use common_eth::{EthDbUtils, EthDbUtilsExt};
+use function_name::named;
+
+use crate::{
+    constants::CORE_TYPE,
+    int::{EosTxInfo, IntOnEosEosTxInfo, IntOutput},
+};
+
+/// # Debug Resign EOS Tx
+///
+/// This function re-signs the EOS tx with the passed in EOS account nonce, giving it a fresh
+/// expiration. It may only be used once the existing tx's expiration has passed, meaning the
+/// original can no longer be included in the EOS chain, and only if the tx's inclusion has not been
+/// confirmed, either via the `debug_confirm_eos_tx_inclusion` function, or by an action from it
+/// having been seen in the action proofs of a submitted EOS block.
+///
+/// The core's EOS account nonce is NOT incremented, and no processed global sequences are altered,
+/// meaning the output of this function is a direct replacement for the original tx's report.
+///
+/// ### NOTES:
+///
+///  - Before using this function, ensure the original tx was not included before it expired, since an included tx
+///    whose actions were never submitted to the core is indistinguishable from an expired one.
+///
+///  - The tx's reference block is the latest EOS block the core's light client has validated, so the EOS side
+///    of the core should be synced before using this function.
+#[named]
+pub fn debug_resign_eos_tx<D: DatabaseInterface>(db: &D, nonce: u64, signature: &str) -> Result<String> {
+    info!("✔ Debug re-signing EOS tx with nonce {nonce}...");
+    let eos_db_utils = EosDbUtils::new(db);
+    db.start_transaction()
+        .and_then(|_| CoreType::check_is_initialized(db))
+        .and_then(|_| get_debug_command_hash!(function_name!(), &nonce)())
+        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
+        .and_then(|_| EosTxRecords::get_from_db(db))
+        .and_then(|records| records.get_record(nonce))
+        .and_then(|record| {
+            record.check_can_be_resigned(get_unix_timestamp_as_u32()?)?;
+            let (ref_block_num, ref_block_prefix) = get_eos_ref_block_from_db(&eos_db_utils)?;
+            let tx_info: IntOnEosEosTxInfo = record.get_tx_info()?;
+            let eos_tx = tx_info.to_eos_signed_tx(
+                ref_block_num,
+                ref_block_prefix,
+                &eos_db_utils.get_eos_chain_id_from_db()?,
+                &EosPrivateKey::get_from_db(db)?,
+                get_eos_tx_expiration_timestamp_with_offset(0)?,
+                &EosEthTokenDictionary::get_from_db(db)?,
+            )?;
+            EosTxRecords::add_records_and_save_in_db(db, &[record.to_resigned(
+                &eos_tx,
+                ref_block_num,
+                ref_block_prefix,
+            )?])?;
+            let output = IntOutput::new(EthDbUtils::new(db).get_latest_eth_block_number()?, vec![
+                EosTxInfo::new(
+                    &eos_tx,
+                    &tx_info,
+                    nonce,
+                    Incremerkles::get_from_db(&eos_db_utils)?.latest_block_num(),
+                )?,
+            ]);
+            db.end_transaction()?;
+            Ok(output.to_string())
+        })
+}
@@ -24,6 +24,7 @@ use crate::{
         filter_submission_material_for_relevant_receipts_in_state,
         get_output_json,
         maybe_increment_eos_account_nonce_and_return_state,
+        maybe_save_eos_tx_records_and_return_state,
         maybe_sign_eos_txs_and_add_to_eth_state,
         IntOnEosEosTxInfos,
     },
@@ -69,6 +70,7 @@ fn reprocess_int_block<D: DatabaseInterface>(db: &D, block_json: &str, signature
         .and_then(filter_out_zero_value_eos_tx_infos_from_state)
         .and_then(debug_filter_tx_info_with_no_erc20_transfer_event)
         .and_then(maybe_sign_eos_txs_and_add_to_eth_state)
+        .and_then(maybe_save_eos_tx_records_and_return_state)
         .and_then(maybe_increment_eos_account_nonce_and_return_state)
         .and_then(end_eth_db_transaction_and_return_state)
         .and_then(get_output_json)
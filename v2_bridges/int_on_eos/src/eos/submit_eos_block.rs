@@ -6,6 +6,7 @@ use common_eos::{
     get_processed_global_sequences_and_add_to_state,
     maybe_add_global_sequences_to_processed_list_and_return_state,
     maybe_add_new_eos_schedule_to_db_and_return_state,
+    maybe_confirm_eos_tx_records_and_return_state,
     maybe_filter_duplicate_proofs_from_state,
     maybe_filter_out_action_proof_receipt_mismatches_and_return_state,
     maybe_filter_out_invalid_action_receipt_digests,
@@ -59,6 +60,7 @@ pub fn submit_eos_block_to_core<D: DatabaseInterface>(db: &D, block_json: &str)
         .and_then(maybe_filter_out_invalid_action_receipt_digests)
         .and_then(maybe_filter_out_proofs_with_invalid_merkle_proofs)
         .and_then(maybe_filter_out_proofs_with_wrong_action_mroot)
+        .and_then(maybe_confirm_eos_tx_records_and_return_state)
         .and_then(maybe_filter_for_relevant_redeem_actions)
         .and_then(maybe_parse_int_tx_infos_and_put_in_state)
         .and_then(maybe_filter_out_already_processed_tx_infos_from_state)
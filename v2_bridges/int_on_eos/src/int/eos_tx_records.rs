@@ -0,0 +1,23 @@
+use common::{traits::DatabaseInterface, types::Result};
+use common_eos::{EosDbUtils, EosSignedTransactions, EosTxRecords};
+use common_eth::EthState;
+
+use crate::int::eos_tx_info::IntOnEosEosTxInfos;
+
+pub fn maybe_save_eos_tx_records_and_return_state<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
+    if state.signed_txs.is_empty() {
+        info!("✔ No signed EOS txs in state ∴ no EOS tx records to save!");
+        Ok(state)
+    } else {
+        let submission_material = state.get_eth_submission_material()?;
+        EosTxRecords::add_signed_txs_and_save_in_db(
+            state.db,
+            EosDbUtils::new(state.db).get_eos_account_nonce_from_db()?,
+            &EosSignedTransactions::from_bytes(&state.signed_txs)?,
+            &IntOnEosEosTxInfos::from_bytes(&state.tx_infos)?,
+            submission_material.get_eos_ref_block_num()?,
+            submission_material.get_eos_ref_block_prefix()?,
+        )
+        .and(Ok(state))
+    }
+}
@@ -1,5 +1,6 @@
 mod divert_to_safe_address;
 mod eos_tx_info;
+mod eos_tx_records;
 mod filter_out_zero_tx_infos;
 mod filter_submission_material;
 mod filter_tx_info_with_no_erc20_transfer_event;
@@ -12,11 +13,12 @@ mod sign_txs;
 mod submit_int_block;
 
 pub(super) use self::{
-    eos_tx_info::IntOnEosEosTxInfos,
+    eos_tx_info::{IntOnEosEosTxInfo, IntOnEosEosTxInfos},
+    eos_tx_records::maybe_save_eos_tx_records_and_return_state,
     filter_out_zero_tx_infos::filter_out_zero_value_eos_tx_infos_from_state,
     filter_submission_material::filter_submission_material_for_relevant_receipts_in_state,
     filter_tx_info_with_no_erc20_transfer_event::debug_filter_tx_info_with_no_erc20_transfer_event,
-    get_output_json::get_output_json,
+    get_output_json::{get_output_json, EosTxInfo, IntOutput},
     increment_eos_nonce::maybe_increment_eos_account_nonce_and_return_state,
     sign_txs::maybe_sign_eos_txs_and_add_to_eth_state,
 };
@@ -4,6 +4,7 @@ use common::{core_type::CoreType, traits::DatabaseInterface, types::Result, AppE
 use common_eth::{
     check_for_parent_of_eth_block_in_state,
     maybe_add_eth_block_and_receipts_to_db_and_return_state,
+    maybe_process_eth_light_client_update_in_state,
     maybe_remove_old_eth_tail_block_and_return_state,
     maybe_remove_receipts_from_eth_canon_block_and_return_state,
     maybe_update_eth_canon_block_hash_and_return_state,
@@ -20,6 +21,7 @@ use common_eth::{
 
 use crate::int::{
     divert_to_safe_address::maybe_divert_txs_to_safe_address_if_destination_is_token_address,
+    eos_tx_records::maybe_save_eos_tx_records_and_return_state,
     filter_out_zero_tx_infos::filter_out_zero_value_eos_tx_infos_from_state,
     filter_submission_material::filter_submission_material_for_relevant_receipts_in_state,
     filter_tx_info_with_no_erc20_transfer_event::filter_tx_info_with_no_erc20_transfer_event,
@@ -33,6 +35,7 @@ fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJs
     parse_eth_submission_material_json_and_put_in_state(json, EthState::init(db))
         .and_then(validate_eth_block_in_state)
         .and_then(|state| state.get_eos_eth_token_dictionary_from_db_and_add_to_state())
+        .and_then(maybe_process_eth_light_client_update_in_state)
         .and_then(check_for_parent_of_eth_block_in_state)
         .and_then(validate_receipts_in_state)
         .and_then(filter_submission_material_for_relevant_receipts_in_state)
@@ -46,6 +49,7 @@ fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJs
         .and_then(filter_tx_info_with_no_erc20_transfer_event)
         .and_then(maybe_divert_txs_to_safe_address_if_destination_is_token_address)
         .and_then(maybe_sign_eos_txs_and_add_to_eth_state)
+        .and_then(maybe_save_eos_tx_records_and_return_state)
         .and_then(maybe_increment_eos_account_nonce_and_return_state)
         .and_then(maybe_remove_old_eth_tail_block_and_return_state)
         .and_then(maybe_remove_receipts_from_eth_canon_block_and_return_state)
@@ -1,17 +1,15 @@
 use std::str::FromStr;
 
 use common::{
-    core_type::CoreType,
-    dictionaries::eth_evm::{EthEvmTokenDictionary, EthEvmTokenDictionaryEntry},
+    dictionaries::{eth_evm::EthEvmTokenDictionaryEntry, token_dictionary::TokenDictionaryEntry},
     traits::DatabaseInterface,
     types::Result,
 };
-use common_debug_signers::validate_debug_command_signature;
-use common_eth::convert_hex_to_eth_address;
-use function_name::named;
-use serde_json::json;
+use common_dictionary_debug::{debug_add_entry_to_token_dictionary, debug_remove_entry_from_token_dictionary};
+use ethereum_types::Address as EthAddress;
 
 use crate::constants::CORE_TYPE;
+
 /// # Debug Add Dictionary Entry
 ///
 /// This function will add an entry to the `EthEvmTokenDictionary` held in the encrypted database. The
@@ -25,37 +23,29 @@ use crate::constants::CORE_TYPE;
 ///     "eth_address": <address>,
 ///     "evm_address": <address>,
 /// }
-#[named]
+///
+/// #### NOTE: This wraps `debug_add_entry_to_token_dictionary`, & so the signature required is that of the
+/// entry converted to the generic token dictionary entry format.
 pub fn debug_add_dictionary_entry<D: DatabaseInterface>(db: &D, json_str: &str, signature: &str) -> Result<String> {
-    db.start_transaction()
-        .and_then(|_| CoreType::check_is_initialized(db))
-        .and_then(|_| get_debug_command_hash!(function_name!(), json_str)())
-        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
-        .and_then(|_| EthEvmTokenDictionary::get_from_db(db))
-        .and_then(|dictionary| dictionary.add_and_update_in_db(EthEvmTokenDictionaryEntry::from_str(json_str)?, db))
-        .and_then(|_| db.end_transaction())
-        .map(|_| json!({"add_dictionary_entry_success:":"true"}).to_string())
+    let entry = TokenDictionaryEntry::<EthAddress, EthAddress>::from(&EthEvmTokenDictionaryEntry::from_str(json_str)?);
+    debug_add_entry_to_token_dictionary::<D, EthAddress, EthAddress>(
+        db,
+        &serde_json::to_string(&entry.to_json())?,
+        &CORE_TYPE,
+        signature,
+    )
 }
 
 /// # Debug Remove Dictionary Entry
 ///
 /// This function will remove an entry pertaining to the passed in ETH address from the
-/// `EthEvmTokenDictionaryEntry` held in the encrypted database, should that entry exist. If it is
-/// not extant, nothing is changed.
-#[named]
+/// `EthEvmTokenDictionaryEntry` held in the encrypted database.
+///
+/// #### NOTE: This wraps `debug_remove_entry_from_token_dictionary`, whose signature it requires.
 pub fn debug_remove_dictionary_entry<D: DatabaseInterface>(
     db: &D,
     eth_address_str: &str,
     signature: &str,
 ) -> Result<String> {
-    db.start_transaction()
-        .and_then(|_| CoreType::check_is_initialized(db))
-        .and_then(|_| get_debug_command_hash!(function_name!(), eth_address_str)())
-        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
-        .and_then(|_| EthEvmTokenDictionary::get_from_db(db))
-        .and_then(|dictionary| {
-            dictionary.remove_entry_via_eth_address_and_update_in_db(&convert_hex_to_eth_address(eth_address_str)?, db)
-        })
-        .and_then(|_| db.end_transaction())
-        .map(|_| json!({"remove_dictionary_entry_success:":"true"}).to_string())
+    debug_remove_entry_from_token_dictionary::<D, EthAddress, EthAddress>(db, eth_address_str, &CORE_TYPE, signature)
 }
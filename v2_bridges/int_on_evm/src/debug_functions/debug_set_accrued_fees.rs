@@ -1,14 +1,6 @@
-use common::{
-    core_type::CoreType,
-    dictionaries::eth_evm::EthEvmTokenDictionary,
-    traits::DatabaseInterface,
-    types::Result,
-};
-use common_debug_signers::validate_debug_command_signature;
-use common_eth::convert_hex_to_eth_address;
-use ethereum_types::U256;
-use function_name::named;
-use serde_json::json;
+use common::{traits::DatabaseInterface, types::Result};
+use common_dictionary_debug::debug_set_token_dictionary_accrued_fees;
+use ethereum_types::Address as EthAddress;
 
 use crate::constants::CORE_TYPE;
 
@@ -16,27 +8,19 @@ use crate::constants::CORE_TYPE;
 ///
 /// This function updates the accrued fees value in the dictionary entry retrieved from the passed
 /// in ETH address.
-#[named]
+///
+/// #### NOTE: This wraps `debug_set_token_dictionary_accrued_fees`, whose signature it requires.
 pub fn debug_set_accrued_fees_in_dictionary<D: DatabaseInterface>(
     db: &D,
     token_address: &str,
     fee_amount: &str,
     signature: &str,
 ) -> Result<String> {
-    db.start_transaction()?;
-    info!("✔ Debug setting accrued fees in dictionary...");
-    let dictionary = EthEvmTokenDictionary::get_from_db(db)?;
-    let dictionary_entry_eth_address = convert_hex_to_eth_address(token_address)?;
-    CoreType::check_is_initialized(db)
-        .and_then(|_| get_debug_command_hash!(function_name!(), token_address, fee_amount)())
-        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
-        .and_then(|_| {
-            dictionary.set_accrued_fees_and_save_in_db(
-                db,
-                &dictionary_entry_eth_address,
-                U256::from_dec_str(fee_amount)?,
-            )
-        })
-        .and_then(|_| db.end_transaction())
-        .map(|_| json!({"success":true,"fee":fee_amount}).to_string())
+    debug_set_token_dictionary_accrued_fees::<D, EthAddress, EthAddress>(
+        db,
+        token_address,
+        fee_amount,
+        &CORE_TYPE,
+        signature,
+    )
 }
@@ -1,15 +1,6 @@
-use common::{
-    core_type::CoreType,
-    dictionaries::eth_evm::EthEvmTokenDictionary,
-    traits::DatabaseInterface,
-    types::Result,
-    utils::prepend_debug_output_marker_to_string,
-};
-use common_debug_signers::validate_debug_command_signature;
-use common_eth::convert_hex_to_eth_address;
-use common_fees::sanity_check_basis_points_value;
-use function_name::named;
-use serde_json::json;
+use common::{traits::DatabaseInterface, types::Result};
+use common_dictionary_debug::debug_set_token_dictionary_fee_basis_points;
+use ethereum_types::Address as EthAddress;
 
 use crate::constants::CORE_TYPE;
 
@@ -22,23 +13,15 @@ use crate::constants::CORE_TYPE;
 /// as such.
 ///
 /// #### NOTE: Using a fee of 0 will mean no fees are taken.
-#[named]
+///
+/// #### NOTE: This wraps `debug_set_token_dictionary_fee_basis_points`, whose signature it requires.
 pub fn debug_set_fee_basis_points<D: DatabaseInterface>(
     db: &D,
     address: &str,
     new_fee: u64,
     signature: &str,
 ) -> Result<String> {
-    db.start_transaction()
-        .and_then(|_| CoreType::check_is_initialized(db))
-        .map(|_| sanity_check_basis_points_value(new_fee))
-        .and_then(|_| get_debug_command_hash!(function_name!(), address, &new_fee)())
-        .and_then(|hash| validate_debug_command_signature(db, &CORE_TYPE, signature, &hash, cfg!(test)))
-        .and_then(|_| EthEvmTokenDictionary::get_from_db(db))
-        .and_then(|dictionary| {
-            dictionary.change_fee_basis_points_and_update_in_db(db, &convert_hex_to_eth_address(address)?, new_fee)
-        })
-        .and_then(|_| db.end_transaction())
-        .map(|_| json!({"success":true, "address": address, "new_fee": new_fee}).to_string())
-        .map(prepend_debug_output_marker_to_string)
+    debug_set_token_dictionary_fee_basis_points::<D, EthAddress, EthAddress>(
+        db, address, new_fee, &CORE_TYPE, signature,
+    )
 }
@@ -28,6 +28,7 @@ use crate::{
         divert_tx_infos_to_safe_address_if_destination_is_token_address,
         divert_tx_infos_to_safe_address_if_destination_is_vault_address,
         divert_tx_infos_to_safe_address_if_destination_is_zero_address,
+        filter_out_tx_infos_not_covering_fees_from_state,
         filter_out_zero_value_evm_tx_infos_from_state,
         filter_submission_material_for_peg_in_events_in_state,
         get_evm_signed_tx_info_from_int_txs,
@@ -75,7 +76,7 @@ fn reprocess_int_block<D: DatabaseInterface>(
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_vault_address)
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_token_address)
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_router_address)
-        .and_then(account_for_fees_in_evm_tx_infos_in_state)
+        .and_then(filter_out_tx_infos_not_covering_fees_from_state)
         .and_then(|state| {
             if accrue_fees {
                 update_accrued_fees_in_dictionary_and_return_eth_state(state)
@@ -84,6 +85,7 @@ fn reprocess_int_block<D: DatabaseInterface>(
                 Ok(state)
             }
         })
+        .and_then(account_for_fees_in_evm_tx_infos_in_state)
         //.and_then(maybe_divert_evm_txs_to_safe_address_if_destination_is_token_address)
         .and_then(|state| {
             if state.tx_infos.is_empty() {
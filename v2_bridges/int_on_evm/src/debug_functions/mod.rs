@@ -13,7 +13,7 @@ pub use self::{
     debug_get_all_db_keys::debug_get_all_db_keys,
     debug_set_accrued_fees::debug_set_accrued_fees_in_dictionary,
     debug_set_fee_basis_points::debug_set_fee_basis_points,
-    debug_withdraw_fees::debug_withdraw_fees_and_save_in_db,
+    debug_withdraw_fees::{debug_withdraw_fees_and_save_in_db, debug_withdraw_fees_to_beneficiaries},
     evm_block_reprocessor::{
         debug_reprocess_evm_block,
         debug_reprocess_evm_block_with_fee_accrual,
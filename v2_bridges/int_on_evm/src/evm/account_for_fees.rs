@@ -1,6 +1,7 @@
 use common::{dictionaries::eth_evm::EthEvmTokenDictionary, traits::DatabaseInterface, types::Result};
 use common_eth::EthState;
-use common_fees::DISABLE_FEES;
+use common_fees::{FeeBreakdown, FeeSchedules, DISABLE_FEES};
+use common_metadata::MetadataChainId;
 use ethereum_types::{Address as EthAddress, U256};
 
 use crate::{
@@ -25,6 +26,16 @@ impl FeeCalculator for IntOnEvmIntTxInfo {
         self.native_token_amount
     }
 
+    fn get_destination_chain_id(&self) -> MetadataChainId {
+        self.destination_chain_id
+    }
+
+    fn update_fee_breakdown(&self, fee_breakdown: FeeBreakdown) -> Self {
+        let mut new_self = self.clone();
+        new_self.fee_breakdown = Some(fee_breakdown);
+        new_self
+    }
+
     fn subtract_amount(&self, subtrahend: U256) -> Result<Self> {
         if subtrahend >= self.native_token_amount {
             Err("Cannot subtract amount from `IntOnEvmIntTxInfo`: subtrahend too large!".into())
@@ -48,29 +59,42 @@ impl IntOnEvmIntTxInfo {
 }
 
 impl FeesCalculator for IntOnEvmIntTxInfos {
-    fn get_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Vec<(EthAddress, U256)>> {
+    fn get_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Vec<(EthAddress, U256)>> {
         debug!("Calculating fees in `IntOnEvmIntTxInfo`...");
         self.iter()
-            .map(|info| info.calculate_fee_via_dictionary(dictionary))
+            .map(|info| info.calculate_fee_via_schedules_or_dictionary(dictionary, fee_schedules))
             .collect()
     }
 
-    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary) -> Result<Self> {
-        self.get_fees(dictionary).and_then(|fee_tuples| {
-            Ok(Self::new(
-                self.iter()
-                    .zip(fee_tuples.iter())
-                    .map(|(info, (_, fee))| {
-                        if *fee == U256::zero() {
-                            debug!("Not subtracting fee because `fee` is 0!");
-                            Ok(info.clone())
-                        } else {
-                            info.subtract_amount(*fee)
-                        }
-                    })
-                    .collect::<Result<Vec<IntOnEvmIntTxInfo>>>()?,
-            ))
-        })
+    fn subtract_fees(&self, dictionary: &EthEvmTokenDictionary, fee_schedules: &FeeSchedules) -> Result<Self> {
+        Ok(Self::new(
+            self.iter()
+                .map(|info| info.subtract_fee_via_schedules_or_dictionary(dictionary, fee_schedules))
+                .collect::<Result<Vec<IntOnEvmIntTxInfo>>>()?,
+        ))
+    }
+
+    fn filter_out_those_not_covering_fees(
+        &self,
+        dictionary: &EthEvmTokenDictionary,
+        fee_schedules: &FeeSchedules,
+    ) -> Result<Self> {
+        let mut filtered = vec![];
+        for info in self.iter() {
+            if info.amount_covers_fee(dictionary, fee_schedules)? {
+                filtered.push(info.clone())
+            } else {
+                warn!(
+                    "✘ Filtering out `IntOnEvmIntTxInfo` ∵ its amount does not cover its fee: {:?}",
+                    info
+                )
+            }
+        }
+        Ok(Self::new(filtered))
     }
 }
 
@@ -86,11 +110,12 @@ pub fn update_accrued_fees_in_dictionary_and_return_state<D: DatabaseInterface>(
         Ok(state)
     } else {
         info!("✔ Accruing fees during EVM block submission...");
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
         EthEvmTokenDictionary::get_from_db(state.db)
             .and_then(|ref dictionary| {
                 dictionary.increment_accrued_fees_and_save_in_db(
                     state.db,
-                    IntOnEvmIntTxInfos::from_bytes(&state.tx_infos)?.get_fees(dictionary)?,
+                    IntOnEvmIntTxInfos::from_bytes(&state.tx_infos)?.get_fees(dictionary, &fee_schedules)?,
                 )
             })
             .and(Ok(state))
@@ -111,16 +136,37 @@ pub fn account_for_fees_in_eth_tx_infos_in_state<D: DatabaseInterface>(state: Et
             tx_info_type
         );
         let tx_infos = IntOnEvmIntTxInfos::from_bytes(&state.tx_infos)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
         EthEvmTokenDictionary::get_from_db(state.db)
-            .and_then(|ref dictionary| tx_infos.subtract_fees(dictionary))
+            .and_then(|ref dictionary| tx_infos.subtract_fees(dictionary, &fee_schedules))
             .and_then(|updated_infos| updated_infos.to_bytes())
             .map(|bytes| state.add_tx_infos(bytes))
     }
 }
 
+/// Rejects any pegs whose amount does not exceed the fee they'd be charged, so they're neither
+/// charged nor signed.
+pub fn filter_out_tx_infos_not_covering_fees_from_state<D: DatabaseInterface>(
+    state: EthState<D>,
+) -> Result<EthState<D>> {
+    if DISABLE_FEES || state.tx_infos.is_empty() {
+        Ok(state)
+    } else {
+        info!("✔ Filtering out `IntOnEvmIntTxInfos` not covering their fees...");
+        let tx_infos = IntOnEvmIntTxInfos::from_bytes(&state.tx_infos)?;
+        let fee_schedules = FeeSchedules::get_from_db(state.db)?;
+        EthEvmTokenDictionary::get_from_db(state.db)
+            .and_then(|ref dictionary| tx_infos.filter_out_those_not_covering_fees(dictionary, &fee_schedules))
+            .and_then(|filtered_infos| filtered_infos.to_bytes())
+            .map(|bytes| state.add_tx_infos(bytes))
+    }
+}
+
 pub fn maybe_account_for_fees<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
     info!("✔ Accounting for fees in `IntOnEvmIntTxInfos` during EVM block submission...");
-    update_accrued_fees_in_dictionary_and_return_state(state).and_then(account_for_fees_in_eth_tx_infos_in_state)
+    filter_out_tx_infos_not_covering_fees_from_state(state)
+        .and_then(update_accrued_fees_in_dictionary_and_return_state)
+        .and_then(account_for_fees_in_eth_tx_infos_in_state)
 }
 
 #[cfg(test)]
@@ -0,0 +1,33 @@
+use common::{dictionaries::eth_evm::apply_token_limits, traits::DatabaseInterface, types::Result, BridgeSide};
+use common_eth::EthState;
+use ethereum_types::{Address as EthAddress, U256};
+
+use crate::evm::int_tx_info::{IntOnEvmIntTxInfo, IntOnEvmIntTxInfos};
+
+fn get_token_address_and_amount(tx_info: &IntOnEvmIntTxInfo) -> (EthAddress, U256) {
+    (tx_info.eth_token_address, tx_info.native_token_amount)
+}
+
+pub fn apply_token_limits_to_int_tx_infos_in_state<D: DatabaseInterface>(state: EthState<D>) -> Result<EthState<D>> {
+    info!("✔ Applying token limits to `IntOnEvmIntTxInfos`...");
+    let timestamp = state.get_eth_submission_material()?.get_timestamp().as_secs();
+    IntOnEvmIntTxInfos::from_bytes(&state.tx_infos)
+        .and_then(|tx_infos| {
+            apply_token_limits(
+                state.db,
+                &BridgeSide::Host,
+                tx_infos.0,
+                get_token_address_and_amount,
+                timestamp,
+            )
+        })
+        .and_then(|tx_infos| {
+            debug!("✔ Num `IntOnEvmIntTxInfos` to process: {}", tx_infos.len());
+            if tx_infos.is_empty() {
+                Ok(vec![])
+            } else {
+                IntOnEvmIntTxInfos::new(tx_infos).to_bytes()
+            }
+        })
+        .map(|bytes| state.add_tx_infos(bytes))
+}
@@ -6,6 +6,7 @@ use common::{
     utils::convert_bytes_to_string,
 };
 use common_eth::{convert_eth_address_to_string, convert_eth_hash_to_string};
+use common_fees::FeeBreakdown;
 use common_metadata::MetadataChainId;
 use common_safe_addresses::SAFE_ETH_ADDRESS_STR;
 use derive_more::{Constructor, Deref, IntoIterator};
@@ -26,6 +27,8 @@ pub struct IntOnEvmIntTxInfo {
     pub eth_token_address: EthAddress,
     pub origin_chain_id: MetadataChainId,
     pub destination_chain_id: MetadataChainId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_breakdown: Option<FeeBreakdown>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Constructor, Deref, IntoIterator, Serialize, Deserialize)]
@@ -1,4 +1,5 @@
 mod account_for_fees;
+mod apply_token_limits;
 mod divert_to_safe_address;
 mod filter_submission_material;
 mod filter_tx_info_with_no_erc20_transfer_event;
@@ -14,6 +15,7 @@ mod submit_evm_block;
 pub(super) use self::{
     account_for_fees::{
         account_for_fees_in_eth_tx_infos_in_state,
+        filter_out_tx_infos_not_covering_fees_from_state,
         update_accrued_fees_in_dictionary_and_return_state as update_accrued_fees_in_dictionary_and_return_evm_state,
     },
     divert_to_safe_address::{
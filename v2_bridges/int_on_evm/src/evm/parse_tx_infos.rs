@@ -74,6 +74,7 @@ impl IntOnEvmIntTxInfos {
                         eth_token_address: dictionary.get_eth_address_from_evm_address(&log.address)?,
                         native_token_amount: dictionary
                             .convert_evm_amount_to_eth_amount(&log.address, event_params.value)?,
+                        fee_breakdown: None,
                     };
                     info!("✔ Parsed tx info: {:?}", tx_info);
                     Ok(tx_info)
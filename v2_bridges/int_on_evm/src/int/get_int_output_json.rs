@@ -6,6 +6,7 @@ use common::{
     types::{NoneError, Result},
 };
 use common_eth::{EthDbUtilsExt, EthState, EthTransaction, EthTxInfoCompatible, RelayTransaction};
+use common_fees::FeeBreakdown;
 
 use crate::int::evm_tx_info::{IntOnEvmEvmTxInfo, IntOnEvmEvmTxInfos};
 
@@ -31,6 +32,7 @@ make_struct_with_test_assertions_on_equality_check!(
         broadcast_tx_hash: Option<String>,
         broadcast_timestamp: Option<String>,
         any_sender_tx: Option<RelayTransaction>,
+        fee_breakdown: Option<FeeBreakdown>,
     }
 );
 
@@ -50,6 +52,7 @@ impl EvmTxInfo {
             broadcast_timestamp: None,
             evm_signed_tx: tx.eth_tx_hex(),
             any_sender_tx: tx.any_sender_tx(),
+            fee_breakdown: tx_info.fee_breakdown.clone(),
             _id: if tx.is_any_sender() {
                 format!("pint-on-evm-evm-any-sender-{}", nonce)
             } else {
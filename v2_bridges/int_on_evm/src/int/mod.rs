@@ -1,4 +1,5 @@
 mod account_for_fees;
+mod apply_token_limits;
 mod divert_to_safe_address;
 mod evm_tx_info;
 mod filter_submission_material;
@@ -16,6 +17,7 @@ pub(super) use self::evm_tx_info::IntOnEvmEvmTxInfo;
 pub(super) use self::{
     account_for_fees::{
         account_for_fees_in_evm_tx_infos_in_state,
+        filter_out_tx_infos_not_covering_fees_from_state,
         update_accrued_fees_in_dictionary_and_return_state as update_accrued_fees_in_dictionary_and_return_eth_state,
     },
     divert_to_safe_address::{
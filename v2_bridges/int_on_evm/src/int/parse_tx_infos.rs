@@ -58,6 +58,7 @@ impl IntOnEvmEvmTxInfos {
                         destination_chain_id: event_params.get_destination_chain_id()?,
                         metadata_event: PTokensRouterMetadataEvent::try_from(receipt).ok(),
                         evm_token_address: dictionary.get_evm_address_from_eth_address(&event_params.token_address)?,
+                        fee_breakdown: None,
                     };
                     info!("✔ Parsed tx info: {:?}", tx_info);
                     Ok(tx_info)
@@ -5,6 +5,7 @@ use common_eth::{
     check_for_parent_of_eth_block_in_state,
     maybe_add_eth_block_and_receipts_to_db_and_return_state,
     maybe_increment_evm_account_nonce_and_return_eth_state,
+    maybe_process_eth_light_client_update_in_state,
     maybe_remove_old_eth_tail_block_and_return_state,
     maybe_remove_receipts_from_eth_canon_block_and_return_state,
     maybe_update_eth_canon_block_hash_and_return_state,
@@ -21,6 +22,7 @@ use common_eth::{
 
 use crate::int::{
     account_for_fees::maybe_account_for_fees,
+    apply_token_limits::apply_token_limits_to_evm_tx_infos_in_state,
     divert_to_safe_address::{
         divert_tx_infos_to_safe_address_if_destination_is_router_address,
         divert_tx_infos_to_safe_address_if_destination_is_token_address,
@@ -39,6 +41,7 @@ fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJs
     parse_eth_submission_material_json_and_put_in_state(json, EthState::init(db))
         .and_then(validate_eth_block_in_state)
         .and_then(|state| state.get_eth_evm_token_dictionary_and_add_to_state())
+        .and_then(maybe_process_eth_light_client_update_in_state)
         .and_then(check_for_parent_of_eth_block_in_state)
         .and_then(validate_receipts_in_state)
         .and_then(filter_submission_material_for_peg_in_events_in_state)
@@ -50,6 +53,7 @@ fn submit_int_block<D: DatabaseInterface>(db: &D, json: &EthSubmissionMaterialJs
         .and_then(maybe_parse_tx_info_from_canon_block_and_add_to_state)
         .and_then(filter_out_zero_value_evm_tx_infos_from_state)
         .and_then(filter_tx_info_with_no_erc20_transfer_event)
+        .and_then(apply_token_limits_to_evm_tx_infos_in_state)
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_zero_address)
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_vault_address)
         .and_then(divert_tx_infos_to_safe_address_if_destination_is_token_address)
@@ -120,7 +124,11 @@ pub fn submit_int_blocks_to_core<D: DatabaseInterface>(db: &D, blocks: &str) ->
 mod tests {
     use std::fs::read_to_string;
 
-    use common::{dictionaries::eth_evm::EthEvmTokenDictionary, test_utils::get_test_database};
+    use common::{
+        dictionaries::eth_evm::{EthEvmPendingTxInfos, EthEvmTokenDictionary, TokenLimitViolation},
+        test_utils::get_test_database,
+        BridgeSide,
+    };
     use common_chain_ids::EthChainId;
     use common_eth::{
         convert_hex_to_eth_address,
@@ -131,6 +139,7 @@ mod tests {
         EvmDbUtils,
         VaultUsingCores,
     };
+    use ethereum_types::U256;
     use serde_json::json;
 
     use super::*;
@@ -284,6 +293,47 @@ mod tests {
         let result = submit_int_blocks_to_core(&db, &batch);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn should_hold_peg_in_exceeding_max_tx_amount_in_pending_queue() {
+        let db = get_test_database();
+        let confirmations = 0;
+        let gas_price = 20_000_000_000;
+        initialize_eth_core_with_vault_and_router_contracts_and_return_state(
+            &get_sample_int_init_block_json_string(),
+            &EthChainId::Ropsten,
+            gas_price,
+            confirmations,
+            EthState::init(&db),
+            &get_sample_vault_address(),
+            &get_sample_router_address(),
+            &VaultUsingCores::IntOnEvm,
+            true, // NOTE: is_native
+        )
+        .unwrap();
+        initialize_evm_core_with_no_contract_tx(
+            &get_sample_evm_init_block_json_string(),
+            &EthChainId::Ropsten,
+            gas_price,
+            confirmations,
+            EthState::init(&db),
+            false, // NOTE: is_native
+        )
+        .unwrap();
+        let entry = get_sample_token_dictionary_entry();
+        EthEvmTokenDictionary::new(vec![])
+            .add_and_update_in_db(entry.set_token_limits(None, Some(U256::from(1000)), None, None), &db)
+            .unwrap();
+        let submission_string = read_to_string("src/test_utils/peg-in-block-1.json").unwrap();
+        let output = submit_int_block_to_core(&db, &submission_string).unwrap();
+        let result = IntOutput::from_str(&output).unwrap();
+        assert!(result.evm_signed_transactions.is_empty());
+        let pending_tx_infos = EthEvmPendingTxInfos::get_from_db(&db).unwrap();
+        assert_eq!(pending_tx_infos.len(), 1);
+        assert_eq!(pending_tx_infos[0].side, BridgeSide::Native);
+        assert_eq!(pending_tx_infos[0].token_address, entry.eth_address);
+        assert_eq!(pending_tx_infos[0].reason, TokenLimitViolation::AboveMaximum);
+    }
 }
 
 #[cfg(all(test, feature = "include-origin-tx-details"))]
@@ -15,6 +15,7 @@ echo [+] Initializing \'$BINARY_NAME\'s NATIVE side...
 --confs=0 \
 --difficulty=0 \
 --network="Testnet" \
+--chain="bitcoin" \
 --file=btc-submission-material.json
 
 echo [+] \'$BINARY_NAME\'s NATIVE side initialized!
@@ -8,17 +8,21 @@ make_cli_args_struct!(
     flag_chainId => u64,
     flag_gasPrice => u64,
     flag_version => bool,
+    flag_chain => String,
     flag_network => String,
     flag_difficulty => u64,
     flag_recipient => String,
     flag_ethNetwork => String,
     flag_routerAddress => String,
     flag_pTokenAddress => String,
+    arg_blockRoot => String,
+    arg_bootstrapJson => String,
     arg_wei => u64,
     arg_fee => u64,
     arg_vOut => u32,
     arg_nonce => u64,
     arg_key => String,
+    arg_chain => String,
     arg_txId => String,
     arg_value => String,
     arg_numUtxos => usize,
@@ -34,10 +38,12 @@ make_cli_args_struct!(
     cmd_debugAddUtxos => bool,
     cmd_submitIntBlock => bool,
     cmd_debugSetBtcFee => bool,
+    cmd_debugSetUtxoChain => bool,
     cmd_debugRemoveUtxo => bool,
     cmd_submitIntBlocks => bool,
     cmd_debugClearAllUtxos => bool,
     cmd_debugResetIntChain => bool,
+    cmd_debugInitIntLightClient => bool,
     cmd_debugSetIntGasPrice => bool,
     cmd_debugMaybeAddUtxoToDb => bool,
     cmd_debugConsolidateUtxos => bool,
@@ -68,5 +74,13 @@ impl CliArgs {
 }
 
 pub fn get_cli_args(usage_info: &str) -> Result<CliArgs> {
-    CliArgs::parse(usage_info).and_then(CliArgs::maybe_update_utxos)
+    CliArgs::parse(usage_info).and_then(update_cli_args)
+}
+
+pub fn get_cli_args_from_argv(usage_info: &str, argv: Vec<String>) -> Result<CliArgs> {
+    CliArgs::parse_from_argv(usage_info, argv).and_then(update_cli_args)
+}
+
+fn update_cli_args(cli_args: CliArgs) -> Result<CliArgs> {
+    cli_args.maybe_update_utxos()
 }
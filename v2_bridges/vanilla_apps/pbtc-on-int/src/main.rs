@@ -21,6 +21,7 @@ use btc_on_int::{
     debug_get_signed_erc777_change_pnetwork_tx,
     debug_get_signed_erc777_proxy_change_pnetwork_by_proxy_tx,
     debug_get_signed_erc777_proxy_change_pnetwork_tx,
+    debug_init_int_light_client,
     debug_maybe_add_utxo_to_db,
     debug_remove_debug_signer,
     debug_remove_utxo,
@@ -33,10 +34,11 @@ use btc_on_int::{
     debug_set_int_account_nonce,
     debug_set_int_gas_price,
     debug_set_key_in_db_to_value,
+    debug_set_utxo_chain,
     get_all_utxos,
     get_enclave_state,
     get_latest_block_numbers,
-    maybe_initialize_btc_core,
+    maybe_initialize_btc_core_for_chain,
     maybe_initialize_int_core,
     sign_ascii_msg_with_int_key_with_no_prefix,
     sign_hex_msg_with_int_key_with_prefix,
@@ -44,18 +46,20 @@ use btc_on_int::{
     submit_int_block_to_core,
     submit_int_blocks_to_core,
 };
-use common::AppError;
+use common::{AppError, DatabaseInterface};
 #[cfg(feature = "file-logger")]
 use common_file_logger::init_logger;
 #[cfg(feature = "json-rpc")]
 use common_jsonrpc_db::get_db;
 #[cfg(feature = "rocks-db")]
 use common_rocksdb_database::get_db;
+use common_simulator::{EmptyDatabase, Simulator, SimulatorOptions};
 #[cfg(feature = "stderr-logger")]
 use common_stderr_logger::init_logger;
+use common_vanilla_server::{VanillaServer, VanillaServerConfig};
 
 use crate::{
-    get_cli_args::{get_cli_args, CliArgs},
+    get_cli_args::{get_cli_args, get_cli_args_from_argv, CliArgs},
     usage_info::USAGE_INFO,
 };
 
@@ -77,13 +81,45 @@ fn program() -> anyhow::Result<String> {
     init_logger()?;
     let db = get_db()?;
     let cli_args = get_cli_args(USAGE_INFO)?;
+    match cli_args {
+        CliArgs { cmd_serve: true, .. } => {
+            info!("✔ Serving JSON-RPC requests...");
+            let config = VanillaServerConfig::new(cli_args.flag_port, &cli_args.flag_socket)?;
+            let server = VanillaServer::new(env!("CARGO_PKG_NAME"), db, |db, argv| {
+                handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+            });
+            Ok(server.serve(&config)?)
+        },
+        CliArgs { cmd_simulate: true, .. } => {
+            info!("✔ Simulating steps in '{}'...", cli_args.arg_stepsDir);
+            let options = SimulatorOptions {
+                // NOTE: There's nothing to persist to when starting from scratch.
+                persist: cli_args.flag_persist && !cli_args.flag_fresh,
+                report_path: Some(cli_args.flag_report.clone()).filter(|path| !path.is_empty()),
+            };
+            let simulator = Simulator::new(env!("CARGO_PKG_NAME"), &cli_args.arg_stepsDir, options)?;
+            if cli_args.flag_fresh {
+                Ok(simulator.run_and_get_output(&EmptyDatabase, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            } else {
+                Ok(simulator.run_and_get_output(&db, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            }
+        },
+        _ => handle_cli_args(&db, cli_args),
+    }
+}
+
+fn handle_cli_args<D: DatabaseInterface>(db: &D, cli_args: CliArgs) -> anyhow::Result<String> {
     Ok(match cli_args {
         CliArgs {
             cmd_submitIntBlocks: true,
             ..
         } => {
             info!("✔ Submitting INT blocks to core...");
-            Ok(submit_int_blocks_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_blocks_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_debugAddDebugSigners: true,
@@ -91,7 +127,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding mulitple debug signers...");
             Ok(debug_add_multiple_debug_signers(
-                &db,
+                db,
                 &cli_args.arg_debugSignersJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -103,7 +139,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Initializing INT enclave...");
             Ok(maybe_initialize_int_core(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_chainId,
                 cli_args.flag_gasPrice,
@@ -116,14 +152,15 @@ fn program() -> anyhow::Result<String> {
             cmd_initializeBtc: true,
             ..
         } => {
-            info!("✔ Initializing BTC enclave...");
-            Ok(maybe_initialize_btc_core(
-                &db,
+            info!("✔ Initializing BTC enclave for {}...", cli_args.flag_chain);
+            Ok(maybe_initialize_btc_core_for_chain(
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_fee,
                 cli_args.flag_difficulty,
                 &cli_args.flag_network,
                 cli_args.flag_confs,
+                &cli_args.flag_chain,
             )?)
         },
         CliArgs {
@@ -132,7 +169,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting `child-pays-for-parent` tx...");
             Ok(debug_get_child_pays_for_parent_btc_tx(
-                &db,
+                db,
                 cli_args.flag_fee,
                 &cli_args.arg_txId,
                 cli_args.arg_vOut,
@@ -145,34 +182,34 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Getting enclave state...");
-            Ok(get_enclave_state(&db)?)
+            Ok(get_enclave_state(db)?)
         },
         CliArgs {
             cmd_debugGetAllDbKeys: true,
             ..
         } => {
             info!("✔ Debug getting all DB keys....");
-            Ok(debug_get_all_db_keys(&db, &cli_args.flag_sig)?)
+            Ok(debug_get_all_db_keys(db, &cli_args.flag_sig)?)
         },
         CliArgs {
             cmd_getLatestBlockNumbers: true,
             ..
         } => {
             info!("✔ Maybe getting block numbers...");
-            Ok(get_latest_block_numbers(&db)?)
+            Ok(get_latest_block_numbers(db)?)
         },
         CliArgs {
             cmd_getAllUtxos: true, ..
         } => {
             info!("✔ Getting all UTXOs from the database...");
-            Ok(get_all_utxos(&db)?)
+            Ok(get_all_utxos(db)?)
         },
         CliArgs {
             cmd_debugClearAllUtxos: true,
             ..
         } => {
             info!("✔ Debug clearing all UTXOs from the database...");
-            Ok(debug_clear_all_utxos(&db, &CliArgs::core_type(), &cli_args.flag_sig)?)
+            Ok(debug_clear_all_utxos(db, &CliArgs::core_type(), &cli_args.flag_sig)?)
         },
         CliArgs {
             cmd_debugGetKeyFromDb: true,
@@ -180,7 +217,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Maybe getting a key from the database...");
             Ok(debug_get_key_from_db(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -192,7 +229,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug maybe adding UTXO to db...");
             Ok(debug_maybe_add_utxo_to_db(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -203,7 +240,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding multiple UTXOs...");
             Ok(debug_add_multiple_utxos(
-                &db,
+                db,
                 &cli_args.arg_utxosJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -215,7 +252,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing INT block...");
             Ok(debug_reprocess_int_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -226,7 +263,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing BTC block...");
             Ok(debug_reprocess_btc_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -237,7 +274,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing BTC block with nonce...");
             Ok(debug_reprocess_btc_block_with_nonce(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.arg_nonce,
                 &cli_args.flag_sig,
@@ -248,14 +285,14 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Submitting INT block to enclave...");
-            Ok(submit_int_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_submitBtcBlock: true,
             ..
         } => {
             info!("✔ Submitting BTC block to enclave...");
-            Ok(submit_btc_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_btc_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_debugRemoveUtxo: true,
@@ -263,7 +300,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug removing UTXO...");
             Ok(debug_remove_utxo(
-                &db,
+                db,
                 &cli_args.arg_txId,
                 cli_args.arg_vOut,
                 &CliArgs::core_type(),
@@ -275,7 +312,7 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Signing HEX message with INT key & INT-specific prefix...");
-            Ok(sign_hex_msg_with_int_key_with_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_hex_msg_with_int_key_with_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_debugConsolidateUtxos: true,
@@ -283,7 +320,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug consolidating utxos...");
             Ok(debug_consolidate_utxos(
-                &db,
+                db,
                 cli_args.flag_fee,
                 cli_args.arg_numUtxos,
                 &CliArgs::core_type(),
@@ -296,7 +333,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug consolidating UTXOS...");
             Ok(debug_consolidate_utxos_to_address(
-                &db,
+                db,
                 cli_args.flag_fee,
                 cli_args.arg_numUtxos,
                 &cli_args.arg_address,
@@ -310,7 +347,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting `changePNetwork` tx...");
             Ok(debug_get_signed_erc777_change_pnetwork_tx(
-                &db,
+                db,
                 &cli_args.arg_address,
                 &cli_args.flag_sig,
             )?)
@@ -324,7 +361,7 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Signing ASCII message with INT key & NO prefix...");
-            Ok(sign_ascii_msg_with_int_key_with_no_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_ascii_msg_with_int_key_with_no_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_debugSetKeyInDbToValue: true,
@@ -332,7 +369,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Setting a key in the database to a value...");
             Ok(debug_set_key_in_db_to_value(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &cli_args.arg_value,
                 &CliArgs::core_type(),
@@ -345,7 +382,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting `changePNetwork` in the proxy tx...");
             Ok(debug_get_signed_erc777_proxy_change_pnetwork_tx(
-                &db,
+                db,
                 &cli_args.arg_address,
                 &cli_args.flag_sig,
             )?)
@@ -356,7 +393,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting `changePNetworkByProxy` tx...");
             Ok(debug_get_signed_erc777_proxy_change_pnetwork_by_proxy_tx(
-                &db,
+                db,
                 &cli_args.arg_address,
                 &cli_args.flag_sig,
             )?)
@@ -367,7 +404,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting INT gas price to {} Wei..", cli_args.arg_wei);
             Ok(debug_set_int_gas_price(
-                &db,
+                db,
                 cli_args.arg_wei,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -379,19 +416,44 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting BTC fee to {} Satoshis-per-byte...", cli_args.arg_fee);
             Ok(debug_set_btc_fee(
-                &db,
+                db,
                 cli_args.arg_fee,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugSetUtxoChain: true,
+            ..
+        } => {
+            info!("✔ Debug setting UTXO chain to {}...", cli_args.arg_chain);
+            Ok(debug_set_utxo_chain(
+                db,
+                &cli_args.arg_chain,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugInitIntLightClient: true,
+            ..
+        } => {
+            info!("✔ Debug initializing INT light client...");
+            Ok(debug_init_int_light_client(
+                db,
+                &cli_args.arg_bootstrapJson,
+                &cli_args.arg_blockRoot,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
         CliArgs {
             cmd_debugResetIntChain: true,
             ..
         } => {
             info!("✔ Debug resetting INT chain...");
             Ok(debug_reset_int_chain(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_confs,
                 &CliArgs::core_type(),
@@ -404,7 +466,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting INT account nonce...");
             Ok(debug_set_int_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -416,7 +478,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting BTC account nonce...");
             Ok(debug_set_btc_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -428,7 +490,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding debug signer...");
             Ok(debug_add_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_name,
                 &cli_args.arg_address,
                 &CliArgs::core_type(),
@@ -441,7 +503,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug removing debug signer...");
             Ok(debug_remove_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_address,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
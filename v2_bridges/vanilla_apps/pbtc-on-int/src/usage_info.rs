@@ -13,17 +13,20 @@ This Provable vanilla pBTC-on-INT app uses the pToken core in order to manage th
 
 Usage:  pbtc-on-int [--help]
         pbtc-on-int [--version]
+        pbtc-on-int serve [--port=<uint>] [--socket=<path>]
+        pbtc-on-int simulate <stepsDir> [--fresh] [--persist] [--report=<path>]
         pbtc-on-int getAllUtxos
         pbtc-on-int getEnclaveState
         pbtc-on-int getLatestBlockNumbers
         pbtc-on-int submitIntBlock (<blockJson> | --file=<path>)
         pbtc-on-int submitBtcBlock (<blockJson> | --file=<path>)
         pbtc-on-int submitIntBlocks (<blockJson> | --file=<path>)
-        pbtc-on-int initializeBtc (<blockJson> | --file=<path>) [--network=<string>] [--difficulty=<uint>] [--fee=<uint>] [--confs=<uint>]
+        pbtc-on-int initializeBtc (<blockJson> | --file=<path>) --chain=<string> [--network=<string>] [--difficulty=<uint>] [--fee=<uint>] [--confs=<uint>]
         pbtc-on-int initializeInt (<blockJson> | --file=<path>) --pTokenAddress=<hex> --routerAddress=<hex> [--chainId=<uint>] [--gasPrice=<uint>] [--confs=<uint>]
         pbtc-on-int debugGetAllDbKeys [--sig=<hex>]
         pbtc-on-int debugClearAllUtxos [--sig=<hex>]
         pbtc-on-int debugSetBtcFee <fee> [--sig=<hex>]
+        pbtc-on-int debugSetUtxoChain <chain> [--sig=<hex>]
         pbtc-on-int debugGetKeyFromDb <key> [--sig=<hex>]
         pbtc-on-int debugSetIntGasPrice <wei> [--sig=<hex>]
         pbtc-on-int debugWithdrawFees <address> [--sig=<hex>]
@@ -45,6 +48,7 @@ Usage:  pbtc-on-int [--help]
         pbtc-on-int debugGetChildPaysForParentTx <txId> <vOut> [--fee=<uint>] [--sig=<hex>]
         pbtc-on-eos debugConsolidateUtxosToAddress <numUtxos> <address>[--fee=<uint>] [--sig=<hex>]
         pbtc-on-int debugResetIntChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
+        pbtc-on-int debugInitIntLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
         pbtc-on-int debugReprocessBtcBlockWithNonce <nonce> (<blockJson> | --file=<path>) [--sig=<hex>]
         pbtc-on-int signMessageWithIntKey <message>
         pbtc-on-int signHexMsgWithIntKeyWithPrefix <message>
@@ -52,6 +56,19 @@ Usage:  pbtc-on-int [--help]
 
 Commands:
 
+    serve                               ❍ Runs the app as a long-lived JSON-RPC server, keeping the database open
+                                          between calls. Each request's method & params are a command & its args as
+                                          they'd be passed to this app, & its result is the command's output. Core
+                                          calls are made one at a time. Stops on SIGINT or SIGTERM.
+
+    simulate                            ❍ Replays each JSON file in <stepsDir>, in order of file name, through this
+                                          app against a simulated copy of the database, reporting each step's output
+                                          & database changes. Files named `<index>_<command>.json` are passed as the
+                                          sole arg to that command, eg `0001_submitIntBlock.json`. Otherwise a file
+                                          must hold a JSON-RPC style request, as used by the `serve` command. The
+                                          simulation is a dry run, its changes only persisted to the database if
+                                          `--persist` is passed, & never with `--fresh`.
+
     submitIntBlock                      ❍ Submit an INT block (& its receipts) to the enclave.  NOTE: The enclave must
                                           first have been initialized!
                                           ➔ blockJson Format:
@@ -118,6 +135,10 @@ Commands:
 
     debugSetBtcFee                      ❍ Sets the BTC fee to use when making transactions.
 
+    debugSetUtxoChain                   ❍ Sets the UTXO chain the core tracks to the given <chain>. Intended for migrating
+                                          cores initialized before the chain was selectable at runtime. It does NOT
+                                          alter the core's keys, UTXOs or blocks.
+
     signHexMsgWithIntKeyWithPrefix      ❍ Signs an ASCII message with the INT private key from the encrypted database.
                                           The message is signed via the `secp256k1` signature scheme and NO prefix is
                                           prepended.
@@ -144,6 +165,10 @@ Commands:
 
     debugReprocessIntBlock              ❍ Submit INT block submisson material for re-processing.
 
+    debugInitIntLightClient             ❍ Initializes a beacon chain light client for the INT chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, INT blocks only
+                                          become canon once the light client has seen them finalized.
+
     debugResetIntChain                  ❍ Resets the INT chain in the encrypted database using the supplied block as a
                                           new starting point.
 
@@ -169,6 +194,11 @@ Commands:
 
     <address>                           ❍ A valid Ethereum or Bitcoin address.
 
+    <bootstrapJson>                     ❍ Valid JSON string of a beacon chain light client bootstrap, as returned by a
+                                          beacon node's `light_client/bootstrap` endpoint.
+
+    <blockRoot>                         ❍ The trusted, finalized beacon block root the bootstrap is for, in HEX format.
+
     <blockJson>                         ❍ Valid JSON string of INT or BTC block.
 
     <txId>                              ❍ The transaction ID of a BTC UTXO.
@@ -193,6 +223,8 @@ Commands:
 
     <fee>                               ❍ The BTC transaction fee, in Satoshis-per-byte.
 
+    <chain>                             ❍ A UTXO chain, either `bitcoin` or `litecoin`.
+
     <name>                              ❍ The name of the debug signer.
 
 Options:
@@ -201,6 +233,17 @@ Options:
 
     --version                           ❍ Returns the core, lib and application versions as well as the application type.
 
+    --port=<uint>                       ❍ Port for the JSON-RPC server to listen on at localhost. [default: 3030]
+
+    --socket=<path>                     ❍ Path of a unix socket for the JSON-RPC server to listen on instead of a
+                                          port. Any file at this path must be removed first.
+
+    --fresh                             ❍ Start the simulation from an empty database rather than this app's one.
+
+    --persist                           ❍ Persist the simulation's changes to the database. Ignored with `--fresh`.
+
+    --report=<path>                     ❍ Path to write the full simulation report to, instead of to stdout.
+
     --file=<path>                       ❍ Path to file containg an INT or BTC block JSON.
 
     --fee=<uint>                        ❍ BTC fee as measured in Satoshis per byte.
@@ -225,6 +268,12 @@ Options:
                                           `Testnet` for the bitcoin public test-net
                                           [default: Bitcoin]
 
+    --chain=<string>                    ❍ The UTXO chain the core should track, either `bitcoin` or `litecoin`.
+                                          Selects the chain's address prefixes, proof-of-work algorithm, retarget
+                                          limit & dust limits.
+                                          NOTE: `dogecoin` is NOT supported, since the light client cannot validate
+                                          its AuxPoW blocks or its per-block difficulty retargeting.
+
     --chainId=<uint>                    ❍ ID of desired chain for transaction:
                                           1  = Intereum Main-Net (default)
                                           3  = Ropsten Test-Net
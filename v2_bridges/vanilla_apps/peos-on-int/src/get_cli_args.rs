@@ -10,8 +10,11 @@ make_cli_args_struct!(
     flag_ethNetwork => String,
     flag_accountName => String,
     flag_routerAddress => String,
+    arg_blockRoot => String,
+    arg_bootstrapJson => String,
     arg_wei => u64,
     arg_nonce => u64,
+    arg_eosTxId => String,
     arg_key => String,
     arg_value => String,
     arg_message => String,
@@ -28,9 +31,12 @@ make_cli_args_struct!(
     cmd_getEnclaveState => bool,
     cmd_signMessageWithIntKey => bool,
     cmd_getLatestBlockNumbers => bool,
+    cmd_debugConfirmEosTxInclusion => bool,
+    cmd_debugResignEosTx => bool,
     cmd_signHexMsgWithIntKeyWithPrefix => bool,
     cmd_signAsciiMsgWithIntKeyWithNoPrefix => bool,
     cmd_debugResetIntChain => bool,
+    cmd_debugInitIntLightClient => bool,
     cmd_debugAddEosSchedule => bool,
     cmd_debugSetIntGasPrice => bool,
     cmd_debugReprocessIntBlock => bool,
@@ -80,8 +86,16 @@ impl CliArgs {
 }
 
 pub fn get_cli_args(usage_info: &str) -> Result<CliArgs> {
-    CliArgs::parse(usage_info)
-        .and_then(CliArgs::maybe_set_block_json)
+    CliArgs::parse(usage_info).and_then(update_cli_args)
+}
+
+pub fn get_cli_args_from_argv(usage_info: &str, argv: Vec<String>) -> Result<CliArgs> {
+    CliArgs::parse_from_argv(usage_info, argv).and_then(update_cli_args)
+}
+
+fn update_cli_args(cli_args: CliArgs) -> Result<CliArgs> {
+    cli_args
+        .maybe_set_block_json()
         .and_then(CliArgs::maybe_set_schedule_json)
         .and_then(CliArgs::maybe_set_incremerkle_json)
         .and_then(CliArgs::maybe_set_dictionary_entry_json)
@@ -9,30 +9,36 @@ extern crate paste;
 mod get_cli_args;
 mod usage_info;
 
-use common::AppError;
+use common::{AppError, DatabaseInterface};
 #[cfg(feature = "file-logger")]
 use common_file_logger::init_logger;
 #[cfg(feature = "json-rpc")]
 use common_jsonrpc_db::get_db;
 #[cfg(feature = "rocks-db")]
 use common_rocksdb_database::get_db;
+use common_simulator::{EmptyDatabase, Simulator, SimulatorOptions};
 #[cfg(feature = "stderr-logger")]
 use common_stderr_logger::init_logger;
+use common_vanilla_server::{VanillaServer, VanillaServerConfig};
+use eos_chain::AccountName as EosAccountName;
 use eos_on_int::{
     debug_add_debug_signer,
+    debug_add_entry_to_token_dictionary,
     debug_add_multiple_debug_signers,
     debug_add_new_eos_schedule,
-    debug_add_token_dictionary_entry,
+    debug_confirm_eos_tx_inclusion,
     debug_disable_eos_protocol_feature,
     debug_enable_eos_protocol_feature,
     debug_get_all_db_keys,
     debug_get_key_from_db,
+    debug_init_int_light_client,
     debug_remove_debug_signer,
-    debug_remove_token_dictionary_entry,
+    debug_remove_entry_from_token_dictionary,
     debug_reprocess_eos_block,
     debug_reprocess_eos_block_with_nonce,
     debug_reprocess_int_block,
     debug_reset_int_chain,
+    debug_resign_eos_tx,
     debug_set_eos_account_nonce,
     debug_set_int_account_nonce,
     debug_set_int_gas_price,
@@ -48,9 +54,10 @@ use eos_on_int::{
     submit_int_block_to_core,
     submit_int_blocks_to_core,
 };
+use ethereum_types::Address as EthAddress;
 
 use crate::{
-    get_cli_args::{get_cli_args, CliArgs},
+    get_cli_args::{get_cli_args, get_cli_args_from_argv, CliArgs},
     usage_info::USAGE_INFO,
 };
 
@@ -72,13 +79,45 @@ fn program() -> anyhow::Result<String> {
     init_logger()?;
     let db = get_db()?;
     let cli_args = get_cli_args(USAGE_INFO)?;
+    match cli_args {
+        CliArgs { cmd_serve: true, .. } => {
+            info!("✔ Serving JSON-RPC requests...");
+            let config = VanillaServerConfig::new(cli_args.flag_port, &cli_args.flag_socket)?;
+            let server = VanillaServer::new(env!("CARGO_PKG_NAME"), db, |db, argv| {
+                handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+            });
+            Ok(server.serve(&config)?)
+        },
+        CliArgs { cmd_simulate: true, .. } => {
+            info!("✔ Simulating steps in '{}'...", cli_args.arg_stepsDir);
+            let options = SimulatorOptions {
+                // NOTE: There's nothing to persist to when starting from scratch.
+                persist: cli_args.flag_persist && !cli_args.flag_fresh,
+                report_path: Some(cli_args.flag_report.clone()).filter(|path| !path.is_empty()),
+            };
+            let simulator = Simulator::new(env!("CARGO_PKG_NAME"), &cli_args.arg_stepsDir, options)?;
+            if cli_args.flag_fresh {
+                Ok(simulator.run_and_get_output(&EmptyDatabase, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            } else {
+                Ok(simulator.run_and_get_output(&db, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            }
+        },
+        _ => handle_cli_args(&db, cli_args),
+    }
+}
+
+fn handle_cli_args<D: DatabaseInterface>(db: &D, cli_args: CliArgs) -> anyhow::Result<String> {
     Ok(match cli_args {
         CliArgs {
             cmd_submitIntBlocks: true,
             ..
         } => {
             info!("✔ Submitting INT blocks to core...");
-            Ok(submit_int_blocks_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_blocks_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_debugAddDebugSigners: true,
@@ -86,7 +125,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding mulitple debug signers...");
             Ok(debug_add_multiple_debug_signers(
-                &db,
+                db,
                 &cli_args.arg_debugSignersJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -99,7 +138,7 @@ fn program() -> anyhow::Result<String> {
             info!("✔ Initializing INT enclave...");
             let chain_id = cli_args.flag_chainId.parse()?;
             Ok(maybe_initialize_int_core(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 chain_id,
                 cli_args.flag_gasPrice,
@@ -112,21 +151,41 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Getting enclave state...");
-            Ok(get_enclave_state(&db)?)
+            Ok(get_enclave_state(db)?)
         },
         CliArgs {
             cmd_debugGetAllDbKeys: true,
             ..
         } => {
             info!("✔ Debug getting all DB keys....");
-            Ok(debug_get_all_db_keys(&db, &cli_args.flag_sig)?)
+            Ok(debug_get_all_db_keys(db, &cli_args.flag_sig)?)
         },
         CliArgs {
             cmd_getLatestBlockNumbers: true,
             ..
         } => {
             info!("✔ Maybe getting block numbers...");
-            Ok(get_latest_block_numbers(&db)?)
+            Ok(get_latest_block_numbers(db)?)
+        },
+        CliArgs {
+            cmd_debugConfirmEosTxInclusion: true,
+            ..
+        } => {
+            info!("✔ Debug confirming EOS tx inclusion...");
+            Ok(debug_confirm_eos_tx_inclusion(
+                db,
+                cli_args.arg_nonce,
+                &cli_args.arg_eosTxId,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugResignEosTx: true,
+            ..
+        } => {
+            info!("✔ Debug re-signing EOS tx...");
+            Ok(debug_resign_eos_tx(db, cli_args.arg_nonce, &cli_args.flag_sig)?)
         },
         CliArgs {
             cmd_debugGetKeyFromDb: true,
@@ -134,7 +193,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Maybe getting a key from the database...");
             Ok(debug_get_key_from_db(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -146,7 +205,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing INT block...");
             Ok(debug_reprocess_int_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -156,14 +215,14 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Submitting INT block to core...");
-            Ok(submit_int_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_signHexMsgWithIntKeyWithPrefix: true,
             ..
         } => {
             info!("✔ Signing HEX message with INT key & INT-specific prefix...");
-            Ok(sign_hex_msg_with_int_key_with_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_hex_msg_with_int_key_with_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_signMessageWithIntKey: true,
@@ -174,7 +233,7 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Signing ASCII message with INT key & NO prefix...");
-            Ok(sign_ascii_msg_with_int_key_with_no_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_ascii_msg_with_int_key_with_no_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_debugSetKeyInDbToValue: true,
@@ -182,7 +241,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Setting a key in the database to a value...");
             Ok(debug_set_key_in_db_to_value(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &cli_args.arg_value,
                 &CliArgs::core_type(),
@@ -195,7 +254,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug enabling EOS protocol feature...");
             Ok(debug_enable_eos_protocol_feature(
-                &db,
+                db,
                 &cli_args.arg_featureHash,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -207,7 +266,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Maybe initializing EOS core...");
             Ok(maybe_initialize_eos_core(
-                &db,
+                db,
                 &cli_args.flag_chainId,
                 &cli_args.flag_accountName,
                 &cli_args.arg_blockJson,
@@ -219,7 +278,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug disabling EOS protocol feature...");
             Ok(debug_disable_eos_protocol_feature(
-                &db,
+                db,
                 &cli_args.arg_featureHash,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -231,7 +290,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Adding EOS schedule to database...");
             Ok(debug_add_new_eos_schedule(
-                &db,
+                db,
                 &cli_args.arg_scheduleJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -242,15 +301,15 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Submitting EOS block to core...");
-            Ok(submit_eos_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_eos_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_debugRemoveDictionaryEntry: true,
             ..
         } => {
             info!("✔ Debug removing `EosEthDictionary` entry...");
-            Ok(debug_remove_token_dictionary_entry(
-                &db,
+            Ok(debug_remove_entry_from_token_dictionary::<_, EosAccountName, EthAddress>(
+                db,
                 &cli_args.arg_ethAddress,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -262,7 +321,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug updating EOS incremerkle...");
             Ok(debug_update_incremerkle(
-                &db,
+                db,
                 &cli_args.arg_eosJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -274,7 +333,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocess EOS block...");
             Ok(debug_reprocess_eos_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -285,7 +344,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocess EOS block with nonce...");
             Ok(debug_reprocess_eos_block_with_nonce(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.arg_nonce,
                 &cli_args.flag_sig,
@@ -296,8 +355,8 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Debug adding `EosEthDictionary` entry...");
-            Ok(debug_add_token_dictionary_entry(
-                &db,
+            Ok(debug_add_entry_to_token_dictionary::<_, EosAccountName, EthAddress>(
+                db,
                 &cli_args.arg_entryJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -309,19 +368,32 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting INT gas price to {} wei...", cli_args.arg_wei);
             Ok(debug_set_int_gas_price(
-                &db,
+                db,
                 cli_args.arg_wei,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugInitIntLightClient: true,
+            ..
+        } => {
+            info!("✔ Debug initializing INT light client...");
+            Ok(debug_init_int_light_client(
+                db,
+                &cli_args.arg_bootstrapJson,
+                &cli_args.arg_blockRoot,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
         CliArgs {
             cmd_debugResetIntChain: true,
             ..
         } => {
             info!("✔ Debug resetting INT chain...");
             Ok(debug_reset_int_chain(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_confs,
                 &CliArgs::core_type(),
@@ -334,7 +406,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting INT account nonce...");
             Ok(debug_set_int_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -346,7 +418,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting EOS account nonce...");
             Ok(debug_set_eos_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -358,7 +430,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding debug signer...");
             Ok(debug_add_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_name,
                 &cli_args.arg_ethAddress,
                 &CliArgs::core_type(),
@@ -371,7 +443,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug removing debug signer...");
             Ok(debug_remove_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_ethAddress,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
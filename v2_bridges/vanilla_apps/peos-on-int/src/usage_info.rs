@@ -13,6 +13,8 @@ EOS tokens and their pTokenized ethereum counterparts.
 
 Usage:  peos-on-int [--help]
         peos-on-int [--version]
+        peos-on-int serve [--port=<uint>] [--socket=<path>]
+        peos-on-int simulate <stepsDir> [--fresh] [--persist] [--report=<path>]
         peos-on-int getEnclaveState
         peos-on-int getLatestBlockNumbers
         peos-on-int submitIntBlock (<blockJson> | --file=<path>)
@@ -25,6 +27,8 @@ Usage:  peos-on-int [--help]
         peos-on-int debugSetIntGasPrice <wei>  [--sig=<hex>]
         peos-on-int debugSetIntAccountNonce <nonce>  [--sig=<hex>]
         peos-on-int debugSetEosAccountNonce <nonce>  [--sig=<hex>]
+        peos-on-int debugResignEosTx <nonce>  [--sig=<hex>]
+        peos-on-int debugConfirmEosTxInclusion <nonce> <eosTxId>  [--sig=<hex>]
         peos-on-int debugRemoveDebugSigner <ethAddress> [--sig=<hex>]
         peos-on-int debugSetKeyInDbToValue <key> <value>  [--sig=<hex>]
         peos-on-int debugAddDebugSigner <name> <ethAddress> [--sig=<hex>]
@@ -38,6 +42,7 @@ Usage:  peos-on-int [--help]
         peos-on-int debugAddEosSchedule (<scheduleJson> | --file=<path>)  [--sig=<hex>]
         peos-on-int debugAddDictionaryEntry (<entryJson> | --file=<path>)  [--sig=<hex>]
         peos-on-int debugResetIntChain (<blockJson> | --file=<path>) [--confs=<uint>]  [--sig=<hex>]
+        peos-on-int debugInitIntLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
         peos-on-int debugReprocessEosBlockWithNonce <nonce> (<blockJson> | --file=<path>)  [--sig=<hex>]
         peos-on-int signMessageWithIntKey <message>
         peos-on-int signHexMsgWithIntKeyWithPrefix <message>
@@ -45,6 +50,19 @@ Usage:  peos-on-int [--help]
 
 Commands:
 
+    serve                               ❍ Runs the app as a long-lived JSON-RPC server, keeping the database open
+                                          between calls. Each request's method & params are a command & its args as
+                                          they'd be passed to this app, & its result is the command's output. Core
+                                          calls are made one at a time. Stops on SIGINT or SIGTERM.
+
+    simulate                            ❍ Replays each JSON file in <stepsDir>, in order of file name, through this
+                                          app against a simulated copy of the database, reporting each step's output
+                                          & database changes. Files named `<index>_<command>.json` are passed as the
+                                          sole arg to that command, eg `0001_submitIntBlock.json`. Otherwise a file
+                                          must hold a JSON-RPC style request, as used by the `serve` command. The
+                                          simulation is a dry run, its changes only persisted to the database if
+                                          `--persist` is passed, & never with `--fresh`.
+
     submitIntBlock                      ❍ Submit an INT block (& its receipts) to the enclave.  NOTE: The enclave must
                                           first have been initialized!
                                           ➔ blockJson Format:
@@ -145,6 +163,19 @@ Commands:
 
     debugSetEosAccountNonce             ❍ Set the EOS account nonce in the encrypted database to the passed in <nonce>.
 
+    debugConfirmEosTxInclusion          ❍ Marks the EOS tx with the passed in <nonce> as included in the EOS chain.
+                                          The passed in <eosTxId> must match the ID of the latest signed version of that tx.
+                                          Txs whose actions are in a submitted EOS block's proofs are confirmed automatically.
+
+    debugResignEosTx                    ❍ Re-signs the EOS tx with the passed in <nonce> using the latest EOS block the
+                                          core has validated as its reference block, giving it a fresh expiration. The tx
+                                          must have expired & its inclusion must not have been confirmed. Ensure the
+                                          original tx was NOT included before using this command!
+
+    debugInitIntLightClient             ❍ Initializes a beacon chain light client for the INT chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, INT blocks only
+                                          become canon once the light client has seen them finalized.
+
     debugResetIntChain                  ❍ Resets the INT chain in the encrypted database using the supplied block as a
                                           new starting point.
 
@@ -168,6 +199,11 @@ Commands:
 
     <value>                             ❍ A database value in HEX format.
 
+    <bootstrapJson>                     ❍ Valid JSON string of a beacon chain light client bootstrap, as returned by a
+                                          beacon node's `light_client/bootstrap` endpoint.
+
+    <blockRoot>                         ❍ The trusted, finalized beacon block root the bootstrap is for, in HEX format.
+
     <blockJson>                         ❍ Valid JSON string of INT or BTC block.
 
     <path>                              ❍ Path to file containing data relevnt to the chosen command.
@@ -176,6 +212,8 @@ Commands:
 
     <nonce>                             ❍ A nonce (as a 64 bit, unsigned integer).
 
+    <eosTxId>                           ❍ The ID of an EOS transaction in hex format.
+
     <message>                           ❍ A message to be signed.
 
     <debugSignersJson>                  ❍ Json array of debug signers objects with the fields:
@@ -195,12 +233,12 @@ Commands:
     <entryJson>                         ❍ Valid JSON string of a dictionary entry.
                                           ➔ JSON Format:
                                           {
-                                             `eos_symbol`: The EOS token symbol,
-                                             `eth_symbol`: The INT token symbol,
-                                             `eos_address`: The EOS token address,
-                                             `eth_address`: The INT token address,
-                                             `eth_token_decimals`: The number of decimals the INT token has,
-                                             `eos_token_decimals`: The number of decimals the EOS token has,
+                                             `native_symbol`: The EOS token symbol,
+                                             `host_symbol`: The INT token symbol,
+                                             `native_address`: The EOS token address,
+                                             `host_address`: The INT token address,
+                                             `native_decimals`: The number of decimals the EOS token has,
+                                             `host_decimals`: The number of decimals the INT token has,
                                           }
 
     <featureHash>                       ❍ A hash as a hex string of an EOS protocol feature.
@@ -219,6 +257,17 @@ Options:
 
     --version                           ❍ Returns the core, lib and application versions as well as the application type.
 
+    --port=<uint>                       ❍ Port for the JSON-RPC server to listen on at localhost. [default: 3030]
+
+    --socket=<path>                     ❍ Path of a unix socket for the JSON-RPC server to listen on instead of a
+                                          port. Any file at this path must be removed first.
+
+    --fresh                             ❍ Start the simulation from an empty database rather than this app's one.
+
+    --persist                           ❍ Persist the simulation's changes to the database. Ignored with `--fresh`.
+
+    --report=<path>                     ❍ Path to write the full simulation report to, instead of to stdout.
+
     --file=<path>                       ❍ Path to file containing a JSON relevant to the chosen command.
 
     --gasPrice=<uint>                   ❍ The gas price to be used in INT transactions.
@@ -8,16 +8,28 @@ make_cli_args_struct!(
     flag_gasPrice => u64,
     flag_version => bool,
     flag_ethNetwork => String,
+    flag_destinationChainId => String,
     flag_vaultAddress => String,
     flag_routerAddress => String,
+    arg_blockRoot => String,
+    arg_bootstrapJson => String,
+    arg_id => String,
     arg_fee => u64,
     arg_nonce => u64,
     arg_key => String,
     arg_value => String,
     arg_gasPrice => u64,
     arg_amount => String,
+    arg_maxAmount => String,
+    arg_minAmount => String,
+    arg_flatFee => String,
+    arg_minFee => String,
+    arg_maxFee => String,
+    arg_volumeCap => String,
+    arg_volumeCapWindow => u64,
     arg_message => String,
     arg_entryJson => String,
+    arg_beneficiariesJson => String,
     arg_ethAddress => String,
     arg_tokenAddress => String,
     arg_vaultAddress => String,
@@ -30,11 +42,22 @@ make_cli_args_struct!(
     cmd_submitEthBlocks => bool,
     cmd_submitIntBlocks => bool,
     cmd_getEnclaveState => bool,
+    cmd_getPendingTxInfos => bool,
+    cmd_getFeeSchedules => bool,
     cmd_debugResetEthChain => bool,
     cmd_debugResetIntChain => bool,
+    cmd_debugInitEthLightClient => bool,
+    cmd_debugInitIntLightClient => bool,
     cmd_getLatestBlockNumbers => bool,
     cmd_debugSetFeeBasisPoints => bool,
+    cmd_debugSetTokenLimits => bool,
+    cmd_debugSetFeeSchedule => bool,
+    cmd_debugRemoveFeeSchedule => bool,
+    cmd_debugReleasePendingTxInfo => bool,
     cmd_debugWithdrawFees => bool,
+    cmd_debugWithdrawFeesToBeneficiaries => bool,
+    cmd_debugSetFeeDistributionPolicy => bool,
+    cmd_debugRemoveFeeDistributionPolicy => bool,
     cmd_debugSetEthGasPrice => bool,
     cmd_debugSetAccruedFees => bool,
     cmd_debugSetIntGasPrice => bool,
@@ -86,8 +109,16 @@ impl CliArgs {
 }
 
 pub fn get_cli_args(usage_info: &str) -> Result<CliArgs> {
-    CliArgs::parse(usage_info)
-        .and_then(CliArgs::maybe_set_dictionary_entry_json)
+    CliArgs::parse(usage_info).and_then(update_cli_args)
+}
+
+pub fn get_cli_args_from_argv(usage_info: &str, argv: Vec<String>) -> Result<CliArgs> {
+    CliArgs::parse_from_argv(usage_info, argv).and_then(update_cli_args)
+}
+
+fn update_cli_args(cli_args: CliArgs) -> Result<CliArgs> {
+    cli_args
+        .maybe_set_dictionary_entry_json()
         .map(CliArgs::maybe_set_vault_address_from_flag)
         .map(CliArgs::maybe_set_router_address_from_flag)
 }
@@ -13,8 +13,12 @@ native ethereum ERC20 tokens and their interim-chain compliant chain pTokenized
 
 Usage:  perc20-on-int [--help]
         perc20-on-int [--version]
+        perc20-on-int serve [--port=<uint>] [--socket=<path>]
+        perc20-on-int simulate <stepsDir> [--fresh] [--persist] [--report=<path>]
         perc20-on-int getEnclaveState
         perc20-on-int getLatestBlockNumbers
+        perc20-on-int getPendingTxInfos
+        perc20-on-int getFeeSchedules
         perc20-on-int submitEthBlock (<blockJson> | --file=<path>)
         perc20-on-int submitIntBlock (<blockJson> | --file=<path>)
         perc20-on-int submitEthBlocks (<blockJson> | --file=<path>)
@@ -36,13 +40,22 @@ Usage:  perc20-on-int [--help]
         perc20-on-int debugRemoveDictionaryEntry <ethAddress> [--sig=<hex>]
         perc20-on-int debugAddDebugSigners <debugSignersJson> [--sig=<hex>]
         perc20-on-int debugSetFeeBasisPoints <ethAddress> <fee> [--sig=<hex>]
+        perc20-on-int debugReleasePendingTxInfo <id> [--sig=<hex>]
+        perc20-on-int debugSetTokenLimits <ethAddress> <minAmount> <maxAmount> <volumeCap> <volumeCapWindow> [--sig=<hex>]
+        perc20-on-int debugSetFeeSchedule <tokenAddress> <flatFee> <fee> <minFee> <maxFee> [--destinationChainId=<id>] [--sig=<hex>]
+        perc20-on-int debugRemoveFeeSchedule <tokenAddress> [--destinationChainId=<id>] [--sig=<hex>]
         perc20-on-int debugSetAccruedFees <ethAddress> <amount> [--sig=<hex>]
         perc20-on-int debugWithdrawFees <tokenAddress> <recipientAddress> [--sig=<hex>]
+        perc20-on-int debugWithdrawFeesToBeneficiaries <tokenAddress> [--sig=<hex>]
+        perc20-on-int debugRemoveFeeDistributionPolicy <tokenAddress> [--sig=<hex>]
+        perc20-on-int debugSetFeeDistributionPolicy <tokenAddress> <beneficiariesJson> [--sig=<hex>]
         perc20-on-int debugReprocessEthBlock (<blockJson> | --file=<path>) [--sig=<hex>]
         perc20-on-int debugReprocessIntBlock (<blockJson> | --file=<path>) [--sig=<hex>]
         perc20-on-int debugAddDictionaryEntry (<entryJson> | --file=<path>) [--sig=<hex>]
         perc20-on-int debugResetEthChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
         perc20-on-int debugResetIntChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
+        perc20-on-int debugInitEthLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
+        perc20-on-int debugInitIntLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
         perc20-on-int debugReprocessEthBlockWithFeeAccrual (<blockJson> | --file=<path>) [--sig=<hex>]
         perc20-on-int debugReprocessIntBlockWithFeeAccrual (<blockJson> | --file=<path>) [--sig=<hex>]
         perc20-on-int debugReprocessEthBlockWithNonce <nonce> (<blockJson> | --file=<path>) [--sig=<hex>]
@@ -54,6 +67,19 @@ Usage:  perc20-on-int [--help]
 
 Commands:
 
+    serve                               ❍ Runs the app as a long-lived JSON-RPC server, keeping the database open
+                                          between calls. Each request's method & params are a command & its args as
+                                          they'd be passed to this app, & its result is the command's output. Core
+                                          calls are made one at a time. Stops on SIGINT or SIGTERM.
+
+    simulate                            ❍ Replays each JSON file in <stepsDir>, in order of file name, through this
+                                          app against a simulated copy of the database, reporting each step's output
+                                          & database changes. Files named `<index>_<command>.json` are passed as the
+                                          sole arg to that command, eg `0001_submitIntBlock.json`. Otherwise a file
+                                          must hold a JSON-RPC style request, as used by the `serve` command. The
+                                          simulation is a dry run, its changes only persisted to the database if
+                                          `--persist` is passed, & never with `--fresh`.
+
     submitEthBlock                      ❍ Submit an ETH block (& its receipts) to the enclave.  NOTE: The enclave must
                                           first have been initialized!
                                           ➔ blockJson Format:
@@ -111,6 +137,20 @@ Commands:
     debugWithdrawFees                   ❍ Withdraw fees for a given token address and send them to the given recipient
                                           address.
 
+    debugWithdrawFeesToBeneficiaries    ❍ Withdraw fees for a given token address, splitting them between the
+                                          beneficiaries of that token's fee distribution policy.
+
+    debugSetFeeDistributionPolicy       ❍ Sets the policy used to split a token's withdrawn fees between beneficiaries.
+                                          ➔ beneficiariesJson Format:
+                                          [
+                                            {
+                                              `recipient`: An ETH address.
+                                              `share_basis_points`: The recipient's share, all of which must total 10000.
+                                            }
+                                          ]
+
+    debugRemoveFeeDistributionPolicy    ❍ Removes the fee distribution policy for a given token address.
+
     debugSetEthGasPrice                 ❍ Sets the ETH gas price to use when making ETH transactions. (Unit: Wei)
 
     debugSetIntGasPrice                 ❍ Sets the INT gas price to use when making ETH transactions. (Unit: Wei)
@@ -124,11 +164,38 @@ Commands:
     debugResetEthChain                  ❍ Resets the ETH chain in the encrypted database using the supplied block as a
                                           new starting point.
 
+    debugInitEthLightClient             ❍ Initializes a beacon chain light client for the ETH chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, ETH blocks only
+                                          become canon once the light client has seen them finalized.
+
+    debugInitIntLightClient             ❍ Initializes a beacon chain light client for the INT chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, INT blocks only
+                                          become canon once the light client has seen them finalized.
+
     debugResetIntChain                  ❍ Resets the INT chain in the encrypted database using the supplied block as a
                                           new starting point.
 
     debugSetAccruedFees                 ❍ Sets the accrued fees value in a dictionary entry.
 
+    debugSetTokenLimits                 ❍ Sets the minimum peg amount, maximum tx amount & rolling volume cap in a
+                                          dictionary entry. Tx infos breaking any of these are held in a pending queue
+                                          rather than signed. Pass 0 to remove a limit.
+
+    debugReleasePendingTxInfo           ❍ Releases a tx info from the pending queue. It will be signed along with those
+                                          from the next block submitted to the side of the core it originated from.
+
+    getPendingTxInfos                   ❍ Returns the queue of tx infos held back due to breaking a token limit.
+
+    debugSetFeeSchedule                 ❍ Sets the fee schedule for a token when pegged to the chain with the passed in
+                                          destination chain ID, or the token's default schedule if no ID is passed in.
+                                          The fee is the flat fee plus the basis points fee, bounded by the minimum &
+                                          maximum fees. Tokens with no schedule use the dictionary's fee basis points.
+
+    debugRemoveFeeSchedule              ❍ Removes the fee schedule for a token & destination chain ID, or the token's
+                                          default schedule if no ID is passed in.
+
+    getFeeSchedules                     ❍ Returns all the fee schedules set in the core.
+
     signHexMsgWithEthKeyWithPrefix      ❍ Signs an ASCII message with the ETH private key from the encrypted database.
                                           The message is signed via the `secp256k1` signature scheme and NO prefix is
                                           prepended.
@@ -181,6 +248,11 @@ Commands:
 
     <value>                             ❍ A database value in HEX format.
 
+    <bootstrapJson>                     ❍ Valid JSON string of a beacon chain light client bootstrap, as returned by a
+                                          beacon node's `light_client/bootstrap` endpoint.
+
+    <blockRoot>                         ❍ The trusted, finalized beacon block root the bootstrap is for, in HEX format.
+
     <blockJson>                         ❍ Valid JSON string of ETH or BTC block.
 
     <vaultAddress>                      ❍ The ETH address of the ERC20 vault smart-contract.
@@ -211,6 +283,23 @@ Commands:
 
     <fee>                               ❍ Fee value in basis points.
 
+    <id>                                ❍ The ID of a pending tx info, as returned by `getPendingTxInfos`.
+
+    <minAmount>                         ❍ The minimum peg amount, in ETH token units, as a string.
+
+    <maxAmount>                         ❍ The maximum amount per tx, in ETH token units, as a string.
+
+    <volumeCap>                         ❍ The maximum volume per rolling window, in ETH token units, as a string.
+
+    <volumeCapWindow>                   ❍ The length of the volume cap's rolling window, in seconds.
+
+    <flatFee>                           ❍ The flat fee charged per peg, in ETH token units, as a string.
+
+    <minFee>                            ❍ The minimum fee charged per peg, in ETH token units, as a string.
+
+    <maxFee>                            ❍ The maximum fee charged per peg, in ETH token units, as a string. Pass 0 for
+                                          no maximum.
+
     <name>                              ❍ The name of the debug signer.
 
     <entryJson>                         ❍ Valid JSON string of a dictionary entry.
@@ -228,6 +317,17 @@ Options:
 
     --version                           ❍ Returns the core, lib and application versions as well as the application type.
 
+    --port=<uint>                       ❍ Port for the JSON-RPC server to listen on at localhost. [default: 3030]
+
+    --socket=<path>                     ❍ Path of a unix socket for the JSON-RPC server to listen on instead of a
+                                          port. Any file at this path must be removed first.
+
+    --fresh                             ❍ Start the simulation from an empty database rather than this app's one.
+
+    --persist                           ❍ Persist the simulation's changes to the database. Ignored with `--fresh`.
+
+    --report=<path>                     ❍ Path to write the full simulation report to, instead of to stdout.
+
     --file=<path>                       ❍ Path to file containg an ETH or BTC block JSON.
 
     --gasPrice=<uint>                   ❍ The gas price to be used in ETH transactions.
@@ -252,5 +352,8 @@ Options:
 
     --gasPrice=<uint>                   ❍ Transaction gas price
 
+    --destinationChainId=<id>           ❍ The metadata chain ID of a fee schedule's destination chain, in hex format or
+                                          as a chain name. Omit to target a token's default fee schedule.
+
     --sig=<hex>                         ❍ A signature over the encoded debug command you want to run, in hex format.
 ";
@@ -11,15 +11,20 @@ make_cli_args_struct!(
     flag_gasPrice => u64,
     flag_version => bool,
     flag_userData => String,
+    flag_useLeases => bool,
     flag_genesisId => String,
     flag_vaultAddress => String,
     flag_routerAddress => String,
+    arg_blockRoot => String,
+    arg_bootstrapJson => String,
     arg_key => String,
     arg_nonce => u64,
     arg_appId => u64,
     arg_amount => u64,
     arg_assetId => u64,
     arg_gasPrice => u64,
+    arg_feePerByte => u64,
+    arg_validityWindow => u64,
     arg_value => String,
     arg_firstValid => u64,
     arg_receiver => String,
@@ -40,6 +45,7 @@ make_cli_args_struct!(
     cmd_submitAlgoBlocks => bool,
     cmd_debugOptInToAsset => bool,
     cmd_debugResetIntChain => bool,
+    cmd_debugInitIntLightClient => bool,
     cmd_debugResetAlgoChain => bool,
     cmd_debugSetIntGasPrice => bool,
     cmd_getLatestBlockNumbers => bool,
@@ -50,6 +56,8 @@ make_cli_args_struct!(
     cmd_debugAddDictionaryEntry => bool,
     cmd_debugReprocessAlgoBlock => bool,
     cmd_debugSetAlgoAccountNonce => bool,
+    cmd_debugResignAlgoTx => bool,
+    cmd_debugSetAlgoTxParams => bool,
     cmd_debugRemoveDictionaryEntry => bool,
     cmd_debugReprocessAlgoBlockWithNonce => bool
 );
@@ -65,5 +73,13 @@ impl CliArgs {
 }
 
 pub fn get_cli_args(usage_info: &str) -> Result<CliArgs> {
-    CliArgs::parse(usage_info).and_then(CliArgs::maybe_set_entry_json)
+    CliArgs::parse(usage_info).and_then(update_cli_args)
+}
+
+pub fn get_cli_args_from_argv(usage_info: &str, argv: Vec<String>) -> Result<CliArgs> {
+    CliArgs::parse_from_argv(usage_info, argv).and_then(update_cli_args)
+}
+
+fn update_cli_args(cli_args: CliArgs) -> Result<CliArgs> {
+    cli_args.maybe_set_entry_json()
 }
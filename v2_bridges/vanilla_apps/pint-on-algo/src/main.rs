@@ -8,33 +8,39 @@ extern crate common_docopt_macros;
 mod get_cli_args;
 mod usage_info;
 
-use common::AppError;
+use common::{AppError, DatabaseInterface};
 #[cfg(feature = "file-logger")]
 use common_file_logger::init_logger;
 #[cfg(feature = "json-rpc")]
 use common_jsonrpc_db::get_db;
 #[cfg(feature = "rocks-db")]
 use common_rocksdb_database::get_db;
+use common_simulator::{EmptyDatabase, Simulator, SimulatorOptions};
 #[cfg(feature = "stderr-logger")]
 use common_stderr_logger::init_logger;
+use common_vanilla_server::{VanillaServer, VanillaServerConfig};
+use ethereum_types::Address as EthAddress;
 use int_on_algo::{
     debug_add_debug_signer,
-    debug_add_dictionary_entry,
+    debug_add_entry_to_token_dictionary,
     debug_add_multiple_debug_signers,
     debug_get_add_supported_token_tx,
     debug_get_algo_pay_tx,
     debug_get_all_db_keys,
     debug_get_key_from_db,
+    debug_init_int_light_client,
     debug_opt_in_to_application,
     debug_opt_in_to_asset,
     debug_remove_debug_signer,
-    debug_remove_dictionary_entry,
+    debug_remove_entry_from_token_dictionary,
     debug_reprocess_algo_block,
     debug_reprocess_algo_block_with_nonce,
     debug_reprocess_int_block,
     debug_reset_algo_chain,
     debug_reset_int_chain,
+    debug_resign_algo_tx,
     debug_set_algo_account_nonce,
+    debug_set_algo_tx_params,
     debug_set_int_account_nonce,
     debug_set_int_gas_price,
     debug_set_key_in_db_to_value,
@@ -50,7 +56,7 @@ use int_on_algo::{
 };
 
 use crate::{
-    get_cli_args::{get_cli_args, CliArgs},
+    get_cli_args::{get_cli_args, get_cli_args_from_argv, CliArgs},
     usage_info::USAGE_INFO,
 };
 
@@ -72,6 +78,38 @@ fn program() -> anyhow::Result<String> {
     init_logger()?;
     let db = get_db()?;
     let cli_args = get_cli_args(USAGE_INFO)?;
+    match cli_args {
+        CliArgs { cmd_serve: true, .. } => {
+            info!("✔ Serving JSON-RPC requests...");
+            let config = VanillaServerConfig::new(cli_args.flag_port, &cli_args.flag_socket)?;
+            let server = VanillaServer::new(env!("CARGO_PKG_NAME"), db, |db, argv| {
+                handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+            });
+            Ok(server.serve(&config)?)
+        },
+        CliArgs { cmd_simulate: true, .. } => {
+            info!("✔ Simulating steps in '{}'...", cli_args.arg_stepsDir);
+            let options = SimulatorOptions {
+                // NOTE: There's nothing to persist to when starting from scratch.
+                persist: cli_args.flag_persist && !cli_args.flag_fresh,
+                report_path: Some(cli_args.flag_report.clone()).filter(|path| !path.is_empty()),
+            };
+            let simulator = Simulator::new(env!("CARGO_PKG_NAME"), &cli_args.arg_stepsDir, options)?;
+            if cli_args.flag_fresh {
+                Ok(simulator.run_and_get_output(&EmptyDatabase, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            } else {
+                Ok(simulator.run_and_get_output(&db, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            }
+        },
+        _ => handle_cli_args(&db, cli_args),
+    }
+}
+
+fn handle_cli_args<D: DatabaseInterface>(db: &D, cli_args: CliArgs) -> anyhow::Result<String> {
     Ok(match cli_args {
         CliArgs {
             cmd_debugAddDebugSigners: true,
@@ -79,7 +117,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding mulitple debug signers...");
             Ok(debug_add_multiple_debug_signers(
-                &db,
+                db,
                 &cli_args.arg_debugSignersJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -90,7 +128,7 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Debug getting all DB keys...");
-            Ok(debug_get_all_db_keys(&db, &cli_args.flag_sig)?)
+            Ok(debug_get_all_db_keys(db, &cli_args.flag_sig)?)
         },
         CliArgs {
             cmd_debugAddSupportedToken: true,
@@ -98,7 +136,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting add supported token tx...");
             Ok(debug_get_add_supported_token_tx(
-                &db,
+                db,
                 &cli_args.arg_evmAddress,
                 &cli_args.flag_sig,
             )?)
@@ -109,7 +147,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting INT gas price...");
             Ok(debug_set_int_gas_price(
-                &db,
+                db,
                 cli_args.arg_gasPrice,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -121,7 +159,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting key in db to value...");
             Ok(debug_set_key_in_db_to_value(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &cli_args.arg_value,
                 &CliArgs::core_type(),
@@ -134,7 +172,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting key in db to value...");
             Ok(debug_get_key_from_db(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -146,18 +184,39 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting ALGO account nonce...");
             Ok(debug_set_algo_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugResignAlgoTx: true,
+            ..
+        } => {
+            info!("✔ Debug re-signing ALGO tx...");
+            Ok(debug_resign_algo_tx(db, cli_args.arg_nonce, &cli_args.flag_sig)?)
+        },
+        CliArgs {
+            cmd_debugSetAlgoTxParams: true,
+            ..
+        } => {
+            info!("✔ Debug setting ALGO tx params...");
+            Ok(debug_set_algo_tx_params(
+                db,
+                cli_args.arg_validityWindow,
+                cli_args.arg_feePerByte,
+                cli_args.flag_useLeases,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
         CliArgs {
             cmd_debugSetIntAccountNonce: true,
             ..
         } => {
             info!("✔ Debug setting INT account nonce...");
             Ok(debug_set_int_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -179,9 +238,10 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Debug removing dictionary entry via EVM address...");
-            Ok(debug_remove_dictionary_entry(
-                &db,
+            Ok(debug_remove_entry_from_token_dictionary::<_, EthAddress, u64>(
+                db,
                 &cli_args.arg_evmAddress,
+                &CliArgs::core_type(),
                 &cli_args.flag_sig,
             )?)
         },
@@ -190,9 +250,10 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Debug adding dictionary entry...");
-            Ok(debug_add_dictionary_entry(
-                &db,
+            Ok(debug_add_entry_to_token_dictionary::<_, EthAddress, u64>(
+                db,
                 &cli_args.arg_entryJson,
+                &CliArgs::core_type(),
                 &cli_args.flag_sig,
             )?)
         },
@@ -202,7 +263,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Getting asset opt-in transaction...");
             Ok(debug_opt_in_to_asset(
-                &db,
+                db,
                 cli_args.arg_assetId,
                 cli_args.arg_firstValid,
                 &cli_args.flag_sig,
@@ -214,19 +275,32 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Getting application opt-in transaction...");
             Ok(debug_opt_in_to_application(
-                &db,
+                db,
                 cli_args.arg_appId,
                 cli_args.arg_firstValid,
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugInitIntLightClient: true,
+            ..
+        } => {
+            info!("✔ Debug initializing INT light client...");
+            Ok(debug_init_int_light_client(
+                db,
+                &cli_args.arg_bootstrapJson,
+                &cli_args.arg_blockRoot,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
         CliArgs {
             cmd_debugResetIntChain: true,
             ..
         } => {
             info!("✔ Debug resetting INT chain...");
             Ok(debug_reset_int_chain(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_confs,
                 &CliArgs::core_type(),
@@ -239,7 +313,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug resetting ALGO chain...");
             Ok(debug_reset_algo_chain(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_confs,
                 &CliArgs::core_type(),
@@ -251,21 +325,21 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Getting enclave state...");
-            Ok(get_enclave_state(&db)?)
+            Ok(get_enclave_state(db)?)
         },
         CliArgs {
             cmd_submitAlgoBlock: true,
             ..
         } => {
             info!("✔ Submitting ALGO block to core...");
-            Ok(submit_algo_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_algo_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_submitAlgoBlocks: true,
             ..
         } => {
             info!("✔ Submitting ALGO blocks to core...");
-            Ok(submit_algo_blocks_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_algo_blocks_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_debugReprocessAlgoBlock: true,
@@ -273,7 +347,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing ALGO block...");
             Ok(debug_reprocess_algo_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -284,7 +358,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing ALGO block with nonce...");
             Ok(debug_reprocess_algo_block_with_nonce(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.arg_nonce,
                 &cli_args.flag_sig,
@@ -296,7 +370,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing INT block...");
             Ok(debug_reprocess_int_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -306,14 +380,14 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Submitting INT blocks to core...");
-            Ok(submit_int_blocks_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_blocks_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_submitIntBlock: true,
             ..
         } => {
             info!("✔ Submitting INT block to core...");
-            Ok(submit_int_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_initializeAlgo: true,
@@ -321,7 +395,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Initializing ALGO core...");
             Ok(maybe_initialize_algo_core(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_genesisId,
                 cli_args.flag_fee,
@@ -334,7 +408,7 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Getting latest block numbers...");
-            Ok(get_latest_block_numbers(&db)?)
+            Ok(get_latest_block_numbers(db)?)
         },
         CliArgs {
             cmd_initializeInt: true,
@@ -342,7 +416,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Initializing INT core...");
             Ok(maybe_initialize_int_core(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_chainId,
                 cli_args.flag_gasPrice,
@@ -357,7 +431,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting ALGO pay tx...");
             Ok(debug_get_algo_pay_tx(
-                &db,
+                db,
                 cli_args.arg_firstValid,
                 &cli_args.flag_genesisId,
                 cli_args.flag_fee,
@@ -373,7 +447,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding debug signer...");
             Ok(debug_add_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_name,
                 &cli_args.arg_evmAddress,
                 &CliArgs::core_type(),
@@ -386,7 +460,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug removing debug signer...");
             Ok(debug_remove_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_evmAddress,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -13,6 +13,8 @@ interim-chain pTokens & their Algorand pTokenized asset counterparts.
 
 Usage:  pint-on-algo [--help]
         pint-on-algo [--version]
+        pint-on-algo serve [--port=<uint>] [--socket=<path>]
+        pint-on-algo simulate <stepsDir> [--fresh] [--persist] [--report=<path>]
         pint-on-algo getEnclaveState
         pint-on-algo getLatestBlockNumbers
         pint-on-algo submitIntBlock (<blockJson> | --file=<path>)
@@ -28,6 +30,8 @@ Usage:  pint-on-algo [--help]
         pint-on-algo debugSetIntAccountNonce <nonce> [--sig=<hex>]
         pint-on-algo debugSetAlgoAccountNonce <nonce> [--sig=<hex>]
         pint-on-algo debugSetAlgoAccountNonce <nonce> [--sig=<hex>]
+        pint-on-algo debugResignAlgoTx <nonce> [--sig=<hex>]
+        pint-on-algo debugSetAlgoTxParams <validityWindow> <feePerByte> [--useLeases] [--sig=<hex>]
         pint-on-algo debugAddSupportedToken <evmAddress> [--sig=<hex>]
         pint-on-algo debugRemoveDebugSigner <evmAddress> [--sig=<hex>]
         pint-on-algo debugSetKeyInDbToValue <key> <value> [--sig=<hex>]
@@ -40,11 +44,25 @@ Usage:  pint-on-algo [--help]
         pint-on-algo debugReprocessAlgoBlock (<blockJson> | --file=<path>) [--sig=<hex>]
         pint-on-algo debugAddDictionaryEntry (<entryJson> | --file=<path>) [--sig=<hex>]
         pint-on-algo debugResetIntChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
+        pint-on-algo debugInitIntLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
         pint-on-algo debugResetAlgoChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
         pint-on-algo debugReprocessAlgoBlockWithNonce (<blockJson> | --file=<path>) <nonce> [--sig=<hex>]
         pint-on-algo debugAlgoPayTx <amount> <receiver> <firstValid> [--fee<ualgos>] [--note=<hex>] [--genesisId=<str>] [--sig=<hex>]
 
 Commands:
+    serve                               ❍ Runs the app as a long-lived JSON-RPC server, keeping the database open
+                                          between calls. Each request's method & params are a command & its args as
+                                          they'd be passed to this app, & its result is the command's output. Core
+                                          calls are made one at a time. Stops on SIGINT or SIGTERM.
+
+    simulate                            ❍ Replays each JSON file in <stepsDir>, in order of file name, through this
+                                          app against a simulated copy of the database, reporting each step's output
+                                          & database changes. Files named `<index>_<command>.json` are passed as the
+                                          sole arg to that command, eg `0001_submitIntBlock.json`. Otherwise a file
+                                          must hold a JSON-RPC style request, as used by the `serve` command. The
+                                          simulation is a dry run, its changes only persisted to the database if
+                                          `--persist` is passed, & never with `--fresh`.
+
     debugAddDebugSigner                 ❍ Adds a new debug signer to the list stored in the encrypted database.
 
     debugAddDebugSigners                ❍ Add multiple new debug signers to the core.
@@ -68,6 +86,14 @@ Commands:
 
     debugSetIntAccountNonce             ❍ Sets the INT account nonce in the database to the passed in nonce.
 
+    debugSetAlgoTxParams                ❍ Sets the validity window (in rounds) & fee per byte (in micro algos) used
+                                          when signing ALGO transactions, and whether to give each signed peg-in a
+                                          lease. The ALGO fee set at initialization is used as a minimum fee.
+
+    debugResignAlgoTx                   ❍ Re-signs the peg-in with the passed in ALGO account nonce using the current
+                                          fee. Once its validity window has passed unconfirmed, a peg-in may be
+                                          re-issued ONCE with a fresh window. Requires ALGO tx leases to be enabled.
+
     encodeAlgoNoteMetadata              ❍ Encodes the Algo note metadata requred for a pToken redeem transaction.
 
     debugReprocessAlgoBlock             ❍ Submit ALGO block submisson material for re-processing.
@@ -87,6 +113,10 @@ Commands:
     debugResetAlgoChain                 ❍ Resets the ALGO chain in the encrypted database using the supplied block
                                           as a new starting point.
 
+    debugInitIntLightClient             ❍ Initializes a beacon chain light client for the INT chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, INT blocks only
+                                          become canon once the light client has seen them finalized.
+
     debugResetIntChain                  ❍ Resets the INT chain in the encrypted database using the supplied block
                                           as a new starting point.
 
@@ -139,6 +169,11 @@ Commands:
 
     getEnclaveState                     ❍ Returns the current state of the enclave as pulled from the database.
 
+    <bootstrapJson>                     ❍ Valid JSON string of a beacon chain light client bootstrap, as returned by a
+                                          beacon node's `light_client/bootstrap` endpoint.
+
+    <blockRoot>                         ❍ The trusted, finalized beacon block root the bootstrap is for, in HEX format.
+
     <blockJson>                         ❍ A valid JSON string of INT or an ALGO block.
 
     <destinationAddress>                ❍ Destination address to deliver assets to on the destination chain.
@@ -163,6 +198,10 @@ Commands:
 
     <nonce>                             ❍ A nonce (as a 64 bit, unsigned integer).
 
+    <validityWindow>                    ❍ The number of rounds an ALGO transaction is valid for, at most 1000.
+
+    <feePerByte>                        ❍ The fee per byte in micro algos to use for ALGO transactions.
+
     <value>                             ❍ A database value in HEX format.
 
     <amount>                            ❍ Amount in uALGOS to transfer.
@@ -178,10 +217,12 @@ Commands:
     <entryJson>                         ❍ Valid JSON string of a dictionary entry.
                                           ➔ JSON Format:
                                           {
-                                              `evm_address`: The EVM token address.
-                                              `algo_asset_id`: The ALGO asset ID.
-                                              `evm_decimals`: The number of decimals of the EVM token.
-                                              `algo_decimals`: The number of decimals of the ALGO asset.
+                                              `native_symbol`: The EVM token symbol.
+                                              `host_symbol`: The ALGO asset symbol.
+                                              `native_address`: The EVM token address.
+                                              `host_address`: The ALGO asset ID.
+                                              `native_decimals`: The number of decimals of the EVM token.
+                                              `host_decimals`: The number of decimals of the ALGO asset.
                                           }
 
 Options:
@@ -190,6 +231,17 @@ Options:
 
     --version                           ❍ Returns the core, lib and application versions plus the application type.
 
+    --port=<uint>                       ❍ Port for the JSON-RPC server to listen on at localhost. [default: 3030]
+
+    --socket=<path>                     ❍ Path of a unix socket for the JSON-RPC server to listen on instead of a
+                                          port. Any file at this path must be removed first.
+
+    --fresh                             ❍ Start the simulation from an empty database rather than this app's one.
+
+    --persist                           ❍ Persist the simulation's changes to the database. Ignored with `--fresh`.
+
+    --report=<path>                     ❍ Path to write the full simulation report to, instead of to stdout.
+
     --file=<path>                       ❍ Path to file containg an INT or ALGO block JSON.
 
     --chainId=<uint>                    ❍ ID of desired chain for transaction [default: 1]
@@ -215,4 +267,6 @@ Options:
     --sig=<hex>                         ❍ A signature over the encoded debug command you want to run, in hex format.
 
     --appId=<uint>                      ❍ The ID of an Algorand application.
+
+    --useLeases                         ❍ Give each signed ALGO peg-in transaction group a lease.
 ";
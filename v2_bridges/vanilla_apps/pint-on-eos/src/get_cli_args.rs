@@ -9,8 +9,11 @@ make_cli_args_struct!(
     flag_chainId => String,
     flag_vaultAddress => String,
     flag_routerAddress => String,
+    arg_blockRoot => String,
+    arg_bootstrapJson => String,
     arg_wei => u64,
     arg_nonce => u64,
+    arg_eosTxId => String,
     arg_key => String,
     arg_value => String,
     arg_message => String,
@@ -26,9 +29,12 @@ make_cli_args_struct!(
     cmd_submitIntBlocks => bool,
     cmd_getEnclaveState => bool,
     cmd_debugResetIntChain => bool,
+    cmd_debugInitIntLightClient => bool,
     cmd_debugSetIntGasPrice => bool,
     cmd_debugAddEosSchedule => bool,
     cmd_getLatestBlockNumbers => bool,
+    cmd_debugConfirmEosTxInclusion => bool,
+    cmd_debugResignEosTx => bool,
     cmd_debugReprocessIntBlock => bool,
     cmd_debugReprocessEosBlock => bool,
     cmd_debugUpdateIncremerkle => bool,
@@ -72,8 +78,16 @@ impl CliArgs {
 }
 
 pub fn get_cli_args(usage_info: &str) -> Result<CliArgs> {
-    CliArgs::parse(usage_info)
-        .and_then(CliArgs::maybe_set_eos_json)
+    CliArgs::parse(usage_info).and_then(update_cli_args)
+}
+
+pub fn get_cli_args_from_argv(usage_info: &str, argv: Vec<String>) -> Result<CliArgs> {
+    CliArgs::parse_from_argv(usage_info, argv).and_then(update_cli_args)
+}
+
+fn update_cli_args(cli_args: CliArgs) -> Result<CliArgs> {
+    cli_args
+        .maybe_set_eos_json()
         .and_then(CliArgs::maybe_set_dictionary_entry_json)
         .and_then(CliArgs::maybe_set_eos_schedule)
 }
@@ -13,6 +13,8 @@ ERC20 Interim pTokens & the pTokenized equivalents on the EOS blockchain.
 
 Usage:  pint-on-eos [--help]
         pint-on-eos [--version]
+        pint-on-eos serve [--port=<uint>] [--socket=<path>]
+        pint-on-eos simulate <stepsDir> [--fresh] [--persist] [--report=<path>]
         pint-on-eos getEnclaveState
         pint-on-eos getLatestBlockNumbers
         pint-on-eos submitIntBlock (<blockJson> | --file=<path>)
@@ -25,6 +27,8 @@ Usage:  pint-on-eos [--help]
         pint-on-eos debugSetIntGasPrice <wei> [--sig=<hex>]
         pint-on-eos debugSetIntAccountNonce <nonce> [--sig=<hex>]
         pint-on-eos debugSetEosAccountNonce <nonce> [--sig=<hex>]
+        pint-on-eos debugResignEosTx <nonce> [--sig=<hex>]
+        pint-on-eos debugConfirmEosTxInclusion <nonce> <eosTxId> [--sig=<hex>]
         pint-on-eos debugRemoveDebugSigner <ethAddress> [--sig=<hex>]
         pint-on-eos debugAddSupportedToken <ethAddress> [--sig=<hex>]
         pint-on-eos debugSetKeyInDbToValue <key> <value> [--sig=<hex>]
@@ -40,12 +44,26 @@ Usage:  pint-on-eos [--help]
         pint-on-eos debugAddEosSchedule (<scheduleJson> | --file=<path>) [--sig=<hex>]
         pint-on-eos debugAddDictionaryEntry (<entryJson> | --file=<path>) [--sig=<hex>]
         pint-on-eos debugResetIntChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
+        pint-on-eos debugInitIntLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
         pint-on-eos debugReprocessEosBlockWithNonce <nonce> (<blockJson> | --file=<path>) [--sig=<hex>]
         pint-on-eos signHexMsgWithIntKeyWithPrefix <message>
         pint-on-eos signAsciiMsgWithIntKeyWithNoPrefix <message>
 
 Commands:
 
+    serve                               ❍ Runs the app as a long-lived JSON-RPC server, keeping the database open
+                                          between calls. Each request's method & params are a command & its args as
+                                          they'd be passed to this app, & its result is the command's output. Core
+                                          calls are made one at a time. Stops on SIGINT or SIGTERM.
+
+    simulate                            ❍ Replays each JSON file in <stepsDir>, in order of file name, through this
+                                          app against a simulated copy of the database, reporting each step's output
+                                          & database changes. Files named `<index>_<command>.json` are passed as the
+                                          sole arg to that command, eg `0001_submitIntBlock.json`. Otherwise a file
+                                          must hold a JSON-RPC style request, as used by the `serve` command. The
+                                          simulation is a dry run, its changes only persisted to the database if
+                                          `--persist` is passed, & never with `--fresh`.
+
     submitIntBlock                      ❍ Submit an INT block (& its receipts) to the enclave.
                                           ➔ blockJson Format:
                                           {
@@ -122,6 +140,10 @@ Commands:
 
     debugSetIntGasPrice                 ❍ Set the gas price for INT transactions.
 
+    debugInitIntLightClient             ❍ Initializes a beacon chain light client for the INT chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, INT blocks only
+                                          become canon once the light client has seen them finalized.
+
     debugResetIntChain                  ❍ Resets the INT chain in the encrypted database using the supplied block as a
                                           new starting point.
 
@@ -165,6 +187,15 @@ Commands:
 
     debugSetEosAccountNonce              ❍ Set the EOS account nonce in the encrypted database to the passed in <nonce>.
 
+    debugConfirmEosTxInclusion          ❍ Marks the EOS tx with the passed in <nonce> as included in the EOS chain.
+                                          The passed in <eosTxId> must match the ID of the latest signed version of that tx.
+                                          Txs whose actions are in a submitted EOS block's proofs are confirmed automatically.
+
+    debugResignEosTx                    ❍ Re-signs the EOS tx with the passed in <nonce> using the latest EOS block the
+                                          core has validated as its reference block, giving it a fresh expiration. The tx
+                                          must have expired & its inclusion must not have been confirmed. Ensure the
+                                          original tx was NOT included before using this command!
+
     debugSetIntAccountNonce              ❍ Set the INT account nonce in the encrypted database to the passed in <nonce>.
 
     <key>                               ❍ A database key in HEX format.
@@ -173,10 +204,17 @@ Commands:
 
     <value>                             ❍ A database value in HEX format.
 
+    <bootstrapJson>                     ❍ Valid JSON string of a beacon chain light client bootstrap, as returned by a
+                                          beacon node's `light_client/bootstrap` endpoint.
+
+    <blockRoot>                         ❍ The trusted, finalized beacon block root the bootstrap is for, in HEX format.
+
     <blockJson>                         ❍ Valid JSON string of EOS or INT block.
 
     <nonce>                             ❍ A nonce (as a 64 bit, unsigned integer).
 
+    <eosTxId>                           ❍ The ID of an EOS transaction in hex format.
+
     <message>                           ❍ A message to be signed.
 
     <scheduleJson>                      ❍ A valid EOS schedule JSON.
@@ -184,12 +222,12 @@ Commands:
     <entryJson>                         ❍ Valid JSON string of a dictionary entry.
                                           ➔ JSON Format:
                                           {
-                                             `eos_symbol`: The EOS token symbol,
-                                             `eth_symbol`: The INT token symbol,
-                                             `eos_address`: The EOS token address,
-                                             `eth_address`: The INT token address,
-                                             `eth_token_decimals`: The number of decimals the INT token has,
-                                             `eos_token_decimals`: The number of decimals the EOS token has,
+                                             `native_symbol`: The INT token symbol,
+                                             `host_symbol`: The EOS token symbol,
+                                             `native_address`: The INT token address,
+                                             `host_address`: The EOS token address,
+                                             `native_decimals`: The number of decimals the INT token has,
+                                             `host_decimals`: The number of decimals the EOS token has,
                                           }
 
     <ethAddress>                        ❍ A valid ethereum address in hex format.
@@ -218,6 +256,17 @@ Options:
 
     --version                           ❍ Returns the core, lib and application versions as well as the application type.
 
+    --port=<uint>                       ❍ Port for the JSON-RPC server to listen on at localhost. [default: 3030]
+
+    --socket=<path>                     ❍ Path of a unix socket for the JSON-RPC server to listen on instead of a
+                                          port. Any file at this path must be removed first.
+
+    --fresh                             ❍ Start the simulation from an empty database rather than this app's one.
+
+    --persist                           ❍ Persist the simulation's changes to the database. Ignored with `--fresh`.
+
+    --report=<path>                     ❍ Path to write the full simulation report to, instead of to stdout.
+
     --file=<path>                       ❍ Path to file containing a JSON relevant to the chosen command.
 
     --confs=<uint>                      ❍ The number of confirmations required before signing transactions. This
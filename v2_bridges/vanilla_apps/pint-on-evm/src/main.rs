@@ -8,15 +8,18 @@ extern crate common_docopt_macros;
 mod get_cli_args;
 mod usage_info;
 
-use common::AppError;
+use common::{AppError, DatabaseInterface};
 #[cfg(feature = "file-logger")]
 use common_file_logger::init_logger;
 #[cfg(feature = "json-rpc")]
 use common_jsonrpc_db::get_db;
 #[cfg(feature = "rocks-db")]
 use common_rocksdb_database::get_db;
+use common_simulator::{EmptyDatabase, Simulator, SimulatorOptions};
 #[cfg(feature = "stderr-logger")]
 use common_stderr_logger::init_logger;
+use common_vanilla_server::{VanillaServer, VanillaServerConfig};
+use ethereum_types::Address as EthAddress;
 use int_on_evm::{
     debug_add_debug_signer,
     debug_add_dictionary_entry,
@@ -25,8 +28,13 @@ use int_on_evm::{
     debug_get_all_db_keys,
     debug_get_key_from_db,
     debug_get_remove_supported_token_tx,
+    debug_init_evm_light_client,
+    debug_init_int_light_client,
+    debug_release_pending_tx_info,
     debug_remove_debug_signer,
     debug_remove_dictionary_entry,
+    debug_remove_fee_distribution_policy,
+    debug_remove_fee_schedule,
     debug_reprocess_evm_block,
     debug_reprocess_evm_block_with_fee_accrual,
     debug_reprocess_evm_block_with_nonce,
@@ -39,12 +47,18 @@ use int_on_evm::{
     debug_set_evm_account_nonce,
     debug_set_evm_gas_price,
     debug_set_fee_basis_points,
+    debug_set_fee_distribution_policy,
+    debug_set_fee_schedule,
     debug_set_int_account_nonce,
     debug_set_int_gas_price,
     debug_set_key_in_db_to_value,
+    debug_set_token_dictionary_limits,
     debug_withdraw_fees_and_save_in_db,
+    debug_withdraw_fees_to_beneficiaries,
     get_enclave_state,
+    get_fee_schedules,
     get_latest_block_numbers,
+    get_pending_tx_infos,
     maybe_initialize_evm_core,
     maybe_initialize_int_core,
     sign_ascii_msg_with_evm_key_with_no_prefix,
@@ -58,7 +72,7 @@ use int_on_evm::{
 };
 
 use crate::{
-    get_cli_args::{get_cli_args, CliArgs},
+    get_cli_args::{get_cli_args, get_cli_args_from_argv, CliArgs},
     usage_info::USAGE_INFO,
 };
 
@@ -80,6 +94,38 @@ fn program() -> anyhow::Result<String> {
     init_logger()?;
     let db = get_db()?;
     let cli_args = get_cli_args(USAGE_INFO)?;
+    match cli_args {
+        CliArgs { cmd_serve: true, .. } => {
+            info!("✔ Serving JSON-RPC requests...");
+            let config = VanillaServerConfig::new(cli_args.flag_port, &cli_args.flag_socket)?;
+            let server = VanillaServer::new(env!("CARGO_PKG_NAME"), db, |db, argv| {
+                handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+            });
+            Ok(server.serve(&config)?)
+        },
+        CliArgs { cmd_simulate: true, .. } => {
+            info!("✔ Simulating steps in '{}'...", cli_args.arg_stepsDir);
+            let options = SimulatorOptions {
+                // NOTE: There's nothing to persist to when starting from scratch.
+                persist: cli_args.flag_persist && !cli_args.flag_fresh,
+                report_path: Some(cli_args.flag_report.clone()).filter(|path| !path.is_empty()),
+            };
+            let simulator = Simulator::new(env!("CARGO_PKG_NAME"), &cli_args.arg_stepsDir, options)?;
+            if cli_args.flag_fresh {
+                Ok(simulator.run_and_get_output(&EmptyDatabase, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            } else {
+                Ok(simulator.run_and_get_output(&db, |db, argv| {
+                    handle_cli_args(db, get_cli_args_from_argv(USAGE_INFO, argv)?)
+                })?)
+            }
+        },
+        _ => handle_cli_args(&db, cli_args),
+    }
+}
+
+fn handle_cli_args<D: DatabaseInterface>(db: &D, cli_args: CliArgs) -> anyhow::Result<String> {
     Ok(match cli_args {
         CliArgs {
             cmd_debugAddDebugSigners: true,
@@ -87,7 +133,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding mulitple debug signers...");
             Ok(debug_add_multiple_debug_signers(
-                &db,
+                db,
                 &cli_args.arg_debugSignersJson,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -99,7 +145,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Initializing INT core...");
             Ok(maybe_initialize_int_core(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_chainId,
                 cli_args.flag_gasPrice,
@@ -114,7 +160,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Initializing EVM core...");
             Ok(maybe_initialize_evm_core(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_chainId,
                 cli_args.flag_gasPrice,
@@ -126,21 +172,21 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Getting core state...");
-            Ok(get_enclave_state(&db)?)
+            Ok(get_enclave_state(db)?)
         },
         CliArgs {
             cmd_debugGetAllDbKeys: true,
             ..
         } => {
             info!("✔ Debug getting all DB keys...");
-            Ok(debug_get_all_db_keys(&db, &cli_args.flag_sig)?)
+            Ok(debug_get_all_db_keys(db, &cli_args.flag_sig)?)
         },
         CliArgs {
             cmd_getLatestBlockNumbers: true,
             ..
         } => {
             info!("✔ Maybe getting block numbers...");
-            Ok(get_latest_block_numbers(&db)?)
+            Ok(get_latest_block_numbers(db)?)
         },
         CliArgs {
             cmd_debugGetKeyFromDb: true,
@@ -148,7 +194,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Maybe getting a key from the database...");
             Ok(debug_get_key_from_db(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -160,7 +206,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing INT block...");
             Ok(debug_reprocess_int_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -171,7 +217,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing EVM block...");
             Ok(debug_reprocess_evm_block(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -182,7 +228,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing INT block with fee accrual...");
             Ok(debug_reprocess_int_block_with_fee_accrual(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -193,7 +239,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing EVM block with fee accrual...");
             Ok(debug_reprocess_evm_block_with_fee_accrual(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 &cli_args.flag_sig,
             )?)
@@ -203,56 +249,56 @@ fn program() -> anyhow::Result<String> {
             ..
         } => {
             info!("✔ Submitting INT block to core...");
-            Ok(submit_int_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_submitIntBlocks: true,
             ..
         } => {
             info!("✔ Submitting INT blocks to core...");
-            Ok(submit_int_blocks_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_int_blocks_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_submitEvmBlock: true,
             ..
         } => {
             info!("✔ Submitting EVM block to core...");
-            Ok(submit_evm_block_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_evm_block_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_submitEvmBlocks: true,
             ..
         } => {
             info!("✔ Submitting EVM blocks to core...");
-            Ok(submit_evm_blocks_to_core(&db, &cli_args.arg_blockJson)?)
+            Ok(submit_evm_blocks_to_core(db, &cli_args.arg_blockJson)?)
         },
         CliArgs {
             cmd_signHexMsgWithIntKeyWithPrefix: true,
             ..
         } => {
             info!("✔ Signing HEX message with INT key & ETH-specific prefix...");
-            Ok(sign_hex_msg_with_int_key_with_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_hex_msg_with_int_key_with_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_signHexMsgWithEvmKeyWithPrefix: true,
             ..
         } => {
             info!("✔ Signing HEX message with EVM key & ETH-specific prefix...");
-            Ok(sign_hex_msg_with_evm_key_with_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_hex_msg_with_evm_key_with_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_signAsciiMsgWithIntKeyWithNoPrefix: true,
             ..
         } => {
             info!("✔ Signing ASCII message with INT key & NO prefix...");
-            Ok(sign_ascii_msg_with_int_key_with_no_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_ascii_msg_with_int_key_with_no_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_signAsciiMsgWithEvmKeyWithNoPrefix: true,
             ..
         } => {
             info!("✔ Signing ASCII message with EVM key & NO prefix...");
-            Ok(sign_ascii_msg_with_evm_key_with_no_prefix(&db, &cli_args.arg_message)?)
+            Ok(sign_ascii_msg_with_evm_key_with_no_prefix(db, &cli_args.arg_message)?)
         },
         CliArgs {
             cmd_debugSetKeyInDbToValue: true,
@@ -260,7 +306,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Setting a key in the database to a value...");
             Ok(debug_set_key_in_db_to_value(
-                &db,
+                db,
                 &cli_args.arg_key,
                 &cli_args.arg_value,
                 &CliArgs::core_type(),
@@ -273,7 +319,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding dictionary entry...");
             Ok(debug_add_dictionary_entry(
-                &db,
+                db,
                 &cli_args.arg_entryJson,
                 &cli_args.flag_sig,
             )?)
@@ -284,7 +330,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug removing dictionary entry...");
             Ok(debug_remove_dictionary_entry(
-                &db,
+                db,
                 &cli_args.arg_ethAddress,
                 &cli_args.flag_sig,
             )?)
@@ -295,7 +341,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting `addSupportedToken` signed transaction...");
             Ok(debug_get_add_supported_token_tx(
-                &db,
+                db,
                 &cli_args.arg_ethAddress,
                 &cli_args.flag_sig,
             )?)
@@ -306,7 +352,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug getting `removeSupportedToken` signed transaction...");
             Ok(debug_get_remove_supported_token_tx(
-                &db,
+                db,
                 &cli_args.arg_ethAddress,
                 &cli_args.flag_sig,
             )?)
@@ -317,31 +363,139 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting fee basis points...");
             Ok(debug_set_fee_basis_points(
-                &db,
+                db,
+                &cli_args.arg_ethAddress,
+                cli_args.arg_fee,
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugSetTokenLimits: true,
+            ..
+        } => {
+            info!("✔ Debug setting token limits...");
+            Ok(debug_set_token_dictionary_limits::<_, EthAddress, EthAddress>(
+                db,
                 &cli_args.arg_ethAddress,
+                &cli_args.arg_minAmount,
+                &cli_args.arg_maxAmount,
+                &cli_args.arg_volumeCap,
+                cli_args.arg_volumeCapWindow,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugReleasePendingTxInfo: true,
+            ..
+        } => {
+            info!("✔ Debug releasing pending tx info...");
+            Ok(debug_release_pending_tx_info(
+                db,
+                &cli_args.arg_id,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_getPendingTxInfos: true,
+            ..
+        } => {
+            info!("✔ Getting pending tx infos...");
+            Ok(get_pending_tx_infos(db)?)
+        },
+        CliArgs {
+            cmd_debugSetFeeSchedule: true,
+            ..
+        } => {
+            info!("✔ Debug setting fee schedule...");
+            Ok(debug_set_fee_schedule(
+                db,
+                &cli_args.arg_tokenAddress,
+                &cli_args.flag_destinationChainId,
+                &cli_args.arg_flatFee,
                 cli_args.arg_fee,
+                &cli_args.arg_minFee,
+                &cli_args.arg_maxFee,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugRemoveFeeSchedule: true,
+            ..
+        } => {
+            info!("✔ Debug removing fee schedule...");
+            Ok(debug_remove_fee_schedule(
+                db,
+                &cli_args.arg_tokenAddress,
+                &cli_args.flag_destinationChainId,
+                &CliArgs::core_type(),
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_getFeeSchedules: true,
+            ..
+        } => {
+            info!("✔ Getting fee schedules...");
+            Ok(get_fee_schedules(db)?)
+        },
         CliArgs {
             cmd_debugWithdrawFees: true,
             ..
         } => {
             info!("✔ Debug withdrawing fees...");
             Ok(debug_withdraw_fees_and_save_in_db(
-                &db,
+                db,
                 &cli_args.arg_tokenAddress,
                 &cli_args.arg_recipientAddress,
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugWithdrawFeesToBeneficiaries: true,
+            ..
+        } => {
+            info!("✔ Debug withdrawing fees to beneficiaries...");
+            Ok(debug_withdraw_fees_to_beneficiaries(
+                db,
+                &cli_args.arg_tokenAddress,
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugSetFeeDistributionPolicy: true,
+            ..
+        } => {
+            info!("✔ Debug setting fee distribution policy...");
+            Ok(debug_set_fee_distribution_policy(
+                db,
+                &cli_args.arg_tokenAddress,
+                &cli_args.arg_beneficiariesJson,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugRemoveFeeDistributionPolicy: true,
+            ..
+        } => {
+            info!("✔ Debug removing fee distribution policy...");
+            Ok(debug_remove_fee_distribution_policy(
+                db,
+                &cli_args.arg_tokenAddress,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
         CliArgs {
             cmd_debugSetIntGasPrice: true,
             ..
         } => {
             info!("✔ Debug setting INT gas price...");
             Ok(debug_set_int_gas_price(
-                &db,
+                db,
                 cli_args.arg_gasPrice,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -353,19 +507,45 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting EVM gas price...");
             Ok(debug_set_evm_gas_price(
-                &db,
+                db,
                 cli_args.arg_gasPrice,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
             )?)
         },
+        CliArgs {
+            cmd_debugInitIntLightClient: true,
+            ..
+        } => {
+            info!("✔ Debug initializing INT light client...");
+            Ok(debug_init_int_light_client(
+                db,
+                &cli_args.arg_bootstrapJson,
+                &cli_args.arg_blockRoot,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
+        CliArgs {
+            cmd_debugInitEvmLightClient: true,
+            ..
+        } => {
+            info!("✔ Debug initializing EVM light client...");
+            Ok(debug_init_evm_light_client(
+                db,
+                &cli_args.arg_bootstrapJson,
+                &cli_args.arg_blockRoot,
+                &CliArgs::core_type(),
+                &cli_args.flag_sig,
+            )?)
+        },
         CliArgs {
             cmd_debugResetIntChain: true,
             ..
         } => {
             info!("✔ Debug resetting INT chain...");
             Ok(debug_reset_int_chain(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_confs,
                 &CliArgs::core_type(),
@@ -378,7 +558,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug resetting EVM chain...");
             Ok(debug_reset_evm_chain(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.flag_confs,
                 &CliArgs::core_type(),
@@ -391,7 +571,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting INT account nonce...");
             Ok(debug_set_int_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -403,7 +583,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting accrued fees...");
             Ok(debug_set_accrued_fees_in_dictionary(
-                &db,
+                db,
                 &cli_args.arg_ethAddress,
                 &cli_args.arg_amount,
                 &cli_args.flag_sig,
@@ -415,7 +595,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing EVM block with nonce...");
             Ok(debug_reprocess_evm_block_with_nonce(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.arg_nonce,
                 &cli_args.flag_sig,
@@ -427,7 +607,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug reprocessing INT block with nonce...");
             Ok(debug_reprocess_int_block_with_nonce(
-                &db,
+                db,
                 &cli_args.arg_blockJson,
                 cli_args.arg_nonce,
                 &cli_args.flag_sig,
@@ -439,7 +619,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug setting EVM account nonce...");
             Ok(debug_set_evm_account_nonce(
-                &db,
+                db,
                 cli_args.arg_nonce,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -451,7 +631,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug adding debug signer...");
             Ok(debug_add_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_name,
                 &cli_args.arg_ethAddress,
                 &CliArgs::core_type(),
@@ -464,7 +644,7 @@ fn program() -> anyhow::Result<String> {
         } => {
             info!("✔ Debug removing debug signer...");
             Ok(debug_remove_debug_signer(
-                &db,
+                db,
                 &cli_args.arg_ethAddress,
                 &CliArgs::core_type(),
                 &cli_args.flag_sig,
@@ -13,8 +13,12 @@ interim-chain pTokens & their native ethereum ERC20 tokens pTokenized counterpar
 
 Usage:  pint-on-evm [--help]
         pint-on-evm [--version]
+        pint-on-evm serve [--port=<uint>] [--socket=<path>]
+        pint-on-evm simulate <stepsDir> [--fresh] [--persist] [--report=<path>]
         pint-on-evm getEnclaveState
         pint-on-evm getLatestBlockNumbers
+        pint-on-evm getPendingTxInfos
+        pint-on-evm getFeeSchedules
         pint-on-evm submitIntBlock (<blockJson> | --file=<path>)
         pint-on-evm submitEvmBlock (<blockJson> | --file=<path>)
         pint-on-evm submitIntBlocks (<blockJson> | --file=<path>)
@@ -35,12 +39,21 @@ Usage:  pint-on-evm [--help]
         pint-on-evm debugAddDebugSigners <debugSignersJson> [--sig=<hex>]
         pint-on-evm debugRemoveDictionaryEntry <ethAddress> [--sig=<hex>]
         pint-on-evm debugSetFeeBasisPoints <ethAddress> <fee> [--sig=<hex>]
+        pint-on-evm debugReleasePendingTxInfo <id> [--sig=<hex>]
+        pint-on-evm debugSetTokenLimits <ethAddress> <minAmount> <maxAmount> <volumeCap> <volumeCapWindow> [--sig=<hex>]
+        pint-on-evm debugSetFeeSchedule <tokenAddress> <flatFee> <fee> <minFee> <maxFee> [--destinationChainId=<id>] [--sig=<hex>]
+        pint-on-evm debugRemoveFeeSchedule <tokenAddress> [--destinationChainId=<id>] [--sig=<hex>]
         pint-on-evm debugSetAccruedFees <ethAddress> <amount> [--sig=<hex>]
         pint-on-evm debugWithdrawFees <tokenAddress> <recipientAddress> [--sig=<hex>]
+        pint-on-evm debugWithdrawFeesToBeneficiaries <tokenAddress> [--sig=<hex>]
+        pint-on-evm debugRemoveFeeDistributionPolicy <tokenAddress> [--sig=<hex>]
+        pint-on-evm debugSetFeeDistributionPolicy <tokenAddress> <beneficiariesJson> [--sig=<hex>]
         pint-on-evm debugReprocessIntBlock (<blockJson> | --file=<path>) [--sig=<hex>]
         pint-on-evm debugReprocessEvmBlock (<blockJson> | --file=<path>) [--sig=<hex>]
         pint-on-evm debugAddDictionaryEntry (<entryJson> | --file=<path>) [--sig=<hex>]
         pint-on-evm debugResetIntChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
+        pint-on-evm debugInitIntLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
+        pint-on-evm debugInitEvmLightClient <bootstrapJson> <blockRoot> [--sig=<hex>]
         pint-on-evm debugResetEvmChain (<blockJson> | --file=<path>) [--confs=<uint>] [--sig=<hex>]
         pint-on-evm debugReprocessIntBlockWithFeeAccrual (<blockJson> | --file=<path>) [--sig=<hex>]
         pint-on-evm debugReprocessEvmBlockWithFeeAccrual (<blockJson> | --file=<path>) [--sig=<hex>]
@@ -53,6 +66,19 @@ Usage:  pint-on-evm [--help]
 
 Commands:
 
+    serve                               ❍ Runs the app as a long-lived JSON-RPC server, keeping the database open
+                                          between calls. Each request's method & params are a command & its args as
+                                          they'd be passed to this app, & its result is the command's output. Core
+                                          calls are made one at a time. Stops on SIGINT or SIGTERM.
+
+    simulate                            ❍ Replays each JSON file in <stepsDir>, in order of file name, through this
+                                          app against a simulated copy of the database, reporting each step's output
+                                          & database changes. Files named `<index>_<command>.json` are passed as the
+                                          sole arg to that command, eg `0001_submitIntBlock.json`. Otherwise a file
+                                          must hold a JSON-RPC style request, as used by the `serve` command. The
+                                          simulation is a dry run, its changes only persisted to the database if
+                                          `--persist` is passed, & never with `--fresh`.
+
     submitIntBlock                       ❍ Submit an ETH block (& its receipts) to the enclave.  NOTE: The enclave must
                                            first have been initialized!
                                            ➔ blockJson Format:
@@ -110,6 +136,20 @@ Commands:
     debugWithdrawFees                   ❍ Withdraw fees for a given token address and send them to the given recipient
                                           address.
 
+    debugWithdrawFeesToBeneficiaries    ❍ Withdraw fees for a given token address, splitting them between the
+                                          beneficiaries of that token's fee distribution policy.
+
+    debugSetFeeDistributionPolicy       ❍ Sets the policy used to split a token's withdrawn fees between beneficiaries.
+                                          ➔ beneficiariesJson Format:
+                                          [
+                                            {
+                                              `recipient`: An ETH address.
+                                              `share_basis_points`: The recipient's share, all of which must total 10000.
+                                            }
+                                          ]
+
+    debugRemoveFeeDistributionPolicy    ❍ Removes the fee distribution policy for a given token address.
+
     debugSetIntGasPrice                 ❍ Sets the ETH gas price to use when making ETH transactions. (Unit: Wei)
 
     debugSetEvmGasPrice                 ❍ Sets the EVM gas price to use when making ETH transactions. (Unit: Wei)
@@ -117,6 +157,14 @@ Commands:
     debugGetKeyFromDb                   ❍ Get a given <key> from the database. This function can only be called if the
                                           `debug` flag is set to true when the tool was built.
 
+    debugInitIntLightClient             ❍ Initializes a beacon chain light client for the INT chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, INT blocks only
+                                          become canon once the light client has seen them finalized.
+
+    debugInitEvmLightClient             ❍ Initializes a beacon chain light client for the EVM chain from a bootstrap &
+                                          the trusted beacon block root it is for. Once initialized, EVM blocks only
+                                          become canon once the light client has seen them finalized.
+
     debugResetIntChain                  ❍ Resets the ETH chain in the encrypted database using the supplied block as a
                                           new starting point.
 
@@ -125,6 +173,25 @@ Commands:
 
     debugSetAccruedFees                 ❍ Sets the accrued fees value in a dictionary entry.
 
+    debugSetTokenLimits                 ❍ Sets the minimum peg amount, maximum tx amount & rolling volume cap in a
+                                          dictionary entry. Tx infos breaking any of these are held in a pending queue
+                                          rather than signed. Pass 0 to remove a limit.
+
+    debugReleasePendingTxInfo           ❍ Releases a tx info from the pending queue. It will be signed along with those
+                                          from the next block submitted to the side of the core it originated from.
+
+    getPendingTxInfos                   ❍ Returns the queue of tx infos held back due to breaking a token limit.
+
+    debugSetFeeSchedule                 ❍ Sets the fee schedule for a token when pegged to the chain with the passed in
+                                          destination chain ID, or the token's default schedule if no ID is passed in.
+                                          The fee is the flat fee plus the basis points fee, bounded by the minimum &
+                                          maximum fees. Tokens with no schedule use the dictionary's fee basis points.
+
+    debugRemoveFeeSchedule              ❍ Removes the fee schedule for a token & destination chain ID, or the token's
+                                          default schedule if no ID is passed in.
+
+    getFeeSchedules                     ❍ Returns all the fee schedules set in the core.
+
     signHexMsgWithIntKeyWithPrefix      ❍ Signs an ASCII message with the ETH private key from the encrypted database.
                                           The message is signed via the `secp256k1` signature scheme and NO prefix is
                                           prepended.
@@ -177,6 +244,11 @@ Commands:
 
     <value>                             ❍ A database value in HEX format.
 
+    <bootstrapJson>                     ❍ Valid JSON string of a beacon chain light client bootstrap, as returned by a
+                                          beacon node's `light_client/bootstrap` endpoint.
+
+    <blockRoot>                         ❍ The trusted, finalized beacon block root the bootstrap is for, in HEX format.
+
     <blockJson>                         ❍ Valid JSON string of an INT or EVM block.
 
     <vaultAddress>                      ❍ The ETH address of the ERC20 vault smart-contract.
@@ -201,6 +273,23 @@ Commands:
 
     <fee>                               ❍ Fee value in basis points.
 
+    <id>                                ❍ The ID of a pending tx info, as returned by `getPendingTxInfos`.
+
+    <minAmount>                         ❍ The minimum peg amount, in INT token units, as a string.
+
+    <maxAmount>                         ❍ The maximum amount per tx, in INT token units, as a string.
+
+    <volumeCap>                         ❍ The maximum volume per rolling window, in INT token units, as a string.
+
+    <volumeCapWindow>                   ❍ The length of the volume cap's rolling window, in seconds.
+
+    <flatFee>                           ❍ The flat fee charged per peg, in INT token units, as a string.
+
+    <minFee>                            ❍ The minimum fee charged per peg, in INT token units, as a string.
+
+    <maxFee>                            ❍ The maximum fee charged per peg, in INT token units, as a string. Pass 0 for
+                                          no maximum.
+
     <debugSignersJson>                  ❍ Json array of debug signers objects with the fields:
                                         {
                                           `name`: The name of the debug signer,
@@ -224,6 +313,17 @@ Options:
 
     --version                           ❍ Returns the core, lib and application versions as well as the application type.
 
+    --port=<uint>                       ❍ Port for the JSON-RPC server to listen on at localhost. [default: 3030]
+
+    --socket=<path>                     ❍ Path of a unix socket for the JSON-RPC server to listen on instead of a
+                                          port. Any file at this path must be removed first.
+
+    --fresh                             ❍ Start the simulation from an empty database rather than this app's one.
+
+    --persist                           ❍ Persist the simulation's changes to the database. Ignored with `--fresh`.
+
+    --report=<path>                     ❍ Path to write the full simulation report to, instead of to stdout.
+
     --file=<path>                       ❍ Path to file containg an ETH or BTC block JSON.
 
     --gasPrice=<uint>                   ❍ The gas price to be used in ETH transactions.
@@ -248,5 +348,8 @@ Options:
 
     --gasPrice=<uint>                   ❍ Transaction gas price
 
+    --destinationChainId=<id>           ❍ The metadata chain ID of a fee schedule's destination chain, in hex format or
+                                          as a chain name. Omit to target a token's default fee schedule.
+
     --sig=<hex>                         ❍ A signature over the encoded debug command you want to run, in hex format.
 ";
@@ -0,0 +1,206 @@
+use std::result::Result;
+
+use common_network_ids::NetworkId;
+use common_sentinel::{
+    call_core,
+    get_sub_mat,
+    BackfillReport,
+    Batch,
+    LatestBlockInfos,
+    SentinelConfig,
+    SentinelError,
+    UserOp,
+    UserOpList,
+    UserOps,
+    WebSocketMessagesEncodable,
+    WebSocketMessagesProcessBatchArgs,
+};
+use futures::{stream, StreamExt};
+
+use super::{backfill_jobs::BackfillJobState, BackfillJob};
+use crate::type_aliases::{SharedBackfillJobs, WebSocketTx};
+
+// NOTE: The receipts of each block are themselves fetched concurrently, so we keep this low.
+const MAX_CONCURRENT_BLOCKS: usize = 5;
+
+// NOTE: Caps how much of the chain a single backfill re-processes, since it holds up the core whilst doing so.
+const MAX_BACKFILL_RANGE: u64 = 10_000;
+
+async fn get_user_ops_from_core(
+    timeout: u64,
+    websocket_tx: &WebSocketTx,
+    ops: &UserOps,
+) -> Result<UserOps, SentinelError> {
+    let list = UserOpList::try_from(
+        call_core(timeout, websocket_tx.clone(), WebSocketMessagesEncodable::GetUserOpList).await?,
+    )?;
+
+    let mut uids = vec![];
+    let mut core_ops = vec![];
+
+    for op in ops.iter() {
+        let uid = op.uid()?;
+        if list.includes(&uid) && !uids.contains(&uid) {
+            let msg = WebSocketMessagesEncodable::GetUserOp(uid.into());
+            core_ops.push(UserOp::try_from(call_core(timeout, websocket_tx.clone(), msg).await?)?);
+            uids.push(uid);
+        }
+    }
+
+    Ok(UserOps::new(core_ops))
+}
+
+async fn process_batch(
+    config: &SentinelConfig,
+    websocket_tx: &WebSocketTx,
+    batch: &mut Batch,
+    report: &mut BackfillReport,
+) -> Result<(), SentinelError> {
+    let timeout = *config.core().timeout();
+    let network_id = *report.network_id();
+    let pnetwork_hubs = batch.get_pnetwork_hubs().clone();
+    let sub_mats = batch.to_submission_material();
+    batch.drain();
+    debug!(
+        "submitting {network_id} backfill batch up to block {}",
+        sub_mats.get_last_block_num()?
+    );
+
+    let mut ops_by_hub = vec![];
+    for sub_mat in sub_mats.iter() {
+        for pnetwork_hub in pnetwork_hubs.active_at(sub_mat.get_block_number()?.as_u64()) {
+            ops_by_hub.push(UserOps::from_sub_mat(&network_id, &pnetwork_hub, sub_mat)?);
+        }
+    }
+    let ops = UserOps::from(ops_by_hub);
+
+    let ops_before = get_user_ops_from_core(timeout, websocket_tx, &ops).await?;
+
+    // NOTE: Reprocessing skips the block chain appending step, so the syncer's chain head is left
+    // undisturbed by blocks from the past.
+    let args = WebSocketMessagesProcessBatchArgs::new(
+        config.validate(&network_id)?,
+        false, // NOTE: Not a dry run
+        true,  // NOTE: Reprocess
+        network_id,
+        pnetwork_hubs,
+        sub_mats,
+        config.governance_address(&network_id),
+        None, // NOTE: Challenges started by this sentinel are tracked by the syncer
+    );
+    let msg = WebSocketMessagesEncodable::ProcessBatch(Box::new(args));
+    if let WebSocketMessagesEncodable::Error(e) = call_core(timeout, websocket_tx.clone(), msg).await? {
+        return Err(e.into());
+    };
+
+    let ops_after = get_user_ops_from_core(timeout, websocket_tx, &ops).await?;
+    report.update(&ops_before, &ops_after)?;
+
+    Ok(())
+}
+
+async fn get_latest_core_block_num(
+    timeout: u64,
+    websocket_tx: &WebSocketTx,
+    network_id: &NetworkId,
+) -> Result<u64, SentinelError> {
+    let msg = WebSocketMessagesEncodable::GetLatestBlockInfos(vec![*network_id]);
+    let infos = LatestBlockInfos::try_from(call_core(timeout, websocket_tx.clone(), msg).await?)?;
+    Ok(*infos.get_for(network_id)?.block_number())
+}
+
+async fn backfill(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    network_id: NetworkId,
+    from: u64,
+    to: u64,
+) -> Result<BackfillReport, SentinelError> {
+    info!("backfilling {network_id} blocks {from} to {to}...");
+
+    let endpoints = config.endpoints(&network_id)?;
+    let batch_size = config.batch_size(&network_id)?;
+    let mut batch = Batch::new_from_config(network_id, config)?;
+    let mut report = BackfillReport::new(network_id, from, to);
+
+    // NOTE: We use our own client rather than the eth rpc loop's, so as not to hold up the syncer.
+    let ws_client = endpoints.get_first_ws_client().await?;
+    let sleep_time = *endpoints.sleep_time();
+    let use_quicknode = endpoints.use_quicknode();
+
+    let mut sub_mats = stream::iter(from..=to)
+        .map(|n| get_sub_mat(&ws_client, n, sleep_time, &network_id, use_quicknode))
+        .buffered(MAX_CONCURRENT_BLOCKS);
+
+    while let Some(sub_mat) = sub_mats.next().await {
+        batch.push(sub_mat?);
+        if batch.size_in_blocks() >= batch_size {
+            process_batch(config, &websocket_tx, &mut batch, &mut report).await?;
+        };
+    }
+
+    if !batch.is_empty() {
+        process_batch(config, &websocket_tx, &mut batch, &mut report).await?;
+    };
+
+    info!("finished backfilling {network_id} blocks {from} to {to}");
+    Ok(report)
+}
+
+/// Validates the requested range, clamping its end to the latest block the core has accepted, then
+/// spawns the backfill in the background. Its report can be fetched via the backfill jobs once done.
+pub(crate) async fn start_backfill(
+    config: SentinelConfig,
+    websocket_tx: WebSocketTx,
+    jobs: SharedBackfillJobs,
+    network_id: NetworkId,
+    from: u64,
+    to: u64,
+) -> Result<BackfillJob, SentinelError> {
+    let latest = get_latest_core_block_num(*config.core().timeout(), &websocket_tx, &network_id).await?;
+    let to = if to > latest {
+        warn!("clamping {network_id} backfill to the core's latest block {latest}");
+        latest
+    } else {
+        to
+    };
+
+    if from > to {
+        return Err(SentinelError::Custom(format!(
+            "cannot backfill {network_id} from block {from} to {to}, invalid range"
+        )));
+    };
+
+    let range = to - from + 1;
+    if range > MAX_BACKFILL_RANGE {
+        return Err(SentinelError::Custom(format!(
+            "cannot backfill {range} {network_id} blocks, max range is {MAX_BACKFILL_RANGE}"
+        )));
+    };
+
+    let job = BackfillJob::new(network_id, from, to);
+    {
+        let mut jobs = jobs.lock().await;
+        if jobs.get(&network_id).map(|job| job.is_running()).unwrap_or_default() {
+            return Err(SentinelError::Custom(format!(
+                "a backfill is already running for {network_id}"
+            )));
+        };
+        jobs.add(job.clone());
+    }
+
+    tokio::spawn(async move {
+        let state = match backfill(&config, websocket_tx, network_id, from, to).await {
+            Ok(report) => BackfillJobState::Finished(report),
+            Err(e) => {
+                error!("{network_id} backfill failed: {e}");
+                BackfillJobState::Failed(e.to_string())
+            },
+        };
+        if let Some(job) = jobs.lock().await.get_mut(&network_id) {
+            job.finish(state);
+        };
+    });
+
+    Ok(job)
+}
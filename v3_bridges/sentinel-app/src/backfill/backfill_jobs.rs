@@ -0,0 +1,80 @@
+use common_network_ids::NetworkId;
+use common_sentinel::BackfillReport;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum BackfillJobState {
+    Running,
+    Finished(BackfillReport),
+    Failed(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub(crate) struct BackfillJob {
+    network_id: NetworkId,
+    from: u64,
+    to: u64,
+    state: BackfillJobState,
+}
+
+impl BackfillJob {
+    pub(crate) fn new(network_id: NetworkId, from: u64, to: u64) -> Self {
+        Self {
+            network_id,
+            from,
+            to,
+            state: BackfillJobState::Running,
+        }
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.state == BackfillJobState::Running
+    }
+
+    pub(crate) fn finish(&mut self, state: BackfillJobState) {
+        self.state = state;
+    }
+}
+
+// NOTE: Only the latest job per network is kept, so its report can be fetched once it has finished.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BackfillJobs(Vec<BackfillJob>);
+
+impl BackfillJobs {
+    pub(crate) fn get(&self, network_id: &NetworkId) -> Option<&BackfillJob> {
+        self.0.iter().find(|job| job.network_id() == network_id)
+    }
+
+    pub(crate) fn get_mut(&mut self, network_id: &NetworkId) -> Option<&mut BackfillJob> {
+        self.0.iter_mut().find(|job| job.network_id() == network_id)
+    }
+
+    pub(crate) fn add(&mut self, job: BackfillJob) {
+        self.0.retain(|j| j.network_id() != job.network_id());
+        self.0.push(job);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn should_only_keep_latest_job_per_network() {
+        let nid = NetworkId::from_str("binance").unwrap();
+        let mut jobs = BackfillJobs::default();
+        jobs.add(BackfillJob::new(nid, 1, 10));
+        jobs.get_mut(&nid)
+            .unwrap()
+            .finish(BackfillJobState::Failed("some error".into()));
+        jobs.add(BackfillJob::new(nid, 11, 20));
+        let job = jobs.get(&nid).unwrap();
+        assert!(job.is_running());
+        assert_eq!(*job.from(), 11);
+        assert_eq!(jobs.0.len(), 1);
+    }
+}
@@ -0,0 +1,7 @@
+mod backfill;
+mod backfill_jobs;
+
+pub(crate) use self::{
+    backfill::start_backfill,
+    backfill_jobs::{BackfillJob, BackfillJobs},
+};
@@ -0,0 +1,118 @@
+use common_sentinel::{
+    BalanceMonitorBroadcastChannelMessages,
+    BalanceRunways,
+    BroadcastChannelMessages,
+    Env,
+    EthRpcSenders,
+    SentinelConfig,
+    SentinelError,
+    SentinelEvent,
+    SentinelEvents,
+    StatusPublisherBroadcastChannelMessages,
+};
+use ethereum_types::Address as EthAddress;
+use tokio::time::{sleep, Duration};
+
+use super::get_balance_runways;
+use crate::{
+    notifier::notify_with_timeout,
+    type_aliases::{BroadcastChannelRx, BroadcastChannelTx, NotifierTx},
+};
+
+async fn broadcast_channel_loop(
+    mut broadcast_channel_rx: BroadcastChannelRx,
+) -> Result<BalanceMonitorBroadcastChannelMessages, SentinelError> {
+    // NOTE: This loops continuously listening to the broadcasting channel, and only returns if we
+    // receive a pertinent message.
+    'broadcast_channel_loop: loop {
+        match broadcast_channel_rx.recv().await {
+            Ok(BroadcastChannelMessages::BalanceMonitor(msg)) => break 'broadcast_channel_loop Ok(msg),
+            Ok(_) => continue 'broadcast_channel_loop, // NOTE: The message wasn't for us
+            Err(e) => break 'broadcast_channel_loop Err(e.into()),
+        }
+    }
+}
+
+async fn check_balances(
+    config: &SentinelConfig,
+    address: &EthAddress,
+    previous_runways: &BalanceRunways,
+    eth_rpc_senders: &EthRpcSenders,
+    broadcast_channel_tx: &BroadcastChannelTx,
+    notifier_tx: &NotifierTx,
+) -> Result<BalanceRunways, SentinelError> {
+    // NOTE: Failures are warned about per network, so the remaining runways are still checked.
+    let (runways, _) = get_balance_runways(config, eth_rpc_senders, address).await;
+
+    for runway in runways.iter() {
+        if runway.is_low() {
+            warn!("broadcaster 0x{} balance is low! {runway}", hex::encode(address));
+        } else {
+            debug!("{runway}");
+        }
+    }
+
+    // NOTE: We only notify when a balance first drops below its runway, rather than on every check.
+    let events = runways
+        .low()
+        .iter()
+        .filter(|r| !previous_runways.is_low(r.network_id()))
+        .map(|r| SentinelEvent::LowBalance { runway: r.clone() })
+        .collect::<Vec<_>>();
+    if !events.is_empty() {
+        notify_with_timeout(notifier_tx, SentinelEvents::new(events)).await?;
+    };
+
+    broadcast_channel_tx.send(BroadcastChannelMessages::StatusPublisher(
+        StatusPublisherBroadcastChannelMessages::BalanceRunways(runways.clone()),
+    ))?;
+
+    Ok(runways)
+}
+
+pub async fn balance_monitor_loop(
+    mut config: SentinelConfig,
+    eth_rpc_senders: EthRpcSenders,
+    broadcast_channel_tx: BroadcastChannelTx,
+    notifier_tx: NotifierTx,
+) -> Result<(), SentinelError> {
+    let name = "balance monitor";
+    Env::init()?;
+    let address = Env::get_private_key()?.to_address();
+
+    let mut runways = BalanceRunways::default();
+    let mut sleep_time = 0; // NOTE: So that the first check happens straight away
+
+    'balance_monitor_loop: loop {
+        tokio::select! {
+            _ = sleep(Duration::from_secs(sleep_time)) => {
+                sleep_time = *config.balances().check_frequency();
+                match check_balances(
+                    &config,
+                    &address,
+                    &runways,
+                    &eth_rpc_senders,
+                    &broadcast_channel_tx,
+                    &notifier_tx,
+                ).await {
+                    Ok(r) => runways = r,
+                    Err(e) => error!("{name} could not check broadcaster balances: {e}"),
+                };
+                info!("{name} sleeping for {sleep_time}s...");
+                continue 'balance_monitor_loop
+            },
+            r = broadcast_channel_loop(broadcast_channel_tx.subscribe()) => match r {
+                Ok(BalanceMonitorBroadcastChannelMessages::ConfigUpdated(new_config)) => {
+                    info!("{name} using updated config");
+                    config = *new_config;
+                    continue 'balance_monitor_loop
+                },
+                Err(e) => break 'balance_monitor_loop Err(e),
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'balance_monitor_loop Err(SentinelError::SigInt(name.into()))
+            },
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use common_network_ids::NetworkId;
+use common_sentinel::{
+    BalanceRunway,
+    BalanceRunways,
+    EthRpcMessages,
+    EthRpcSenders,
+    SentinelConfig,
+    SentinelError,
+    CHALLENGE_RESPONSE_GAS_LIMIT,
+};
+use ethereum_types::Address as EthAddress;
+
+async fn get_balance_runway(
+    config: &SentinelConfig,
+    eth_rpc_senders: &EthRpcSenders,
+    address: &EthAddress,
+    network_id: NetworkId,
+) -> Result<BalanceRunway, SentinelError> {
+    let min_runway = *config.balances().min_runway();
+    let sender = eth_rpc_senders.sender(&network_id)?;
+
+    let (msg, rx) = EthRpcMessages::get_eth_balance_msg(network_id, *address);
+    sender.send(msg).await?;
+    let balance = rx.await??;
+
+    let gas_price = if let Ok(Some(p)) = config.gas_price(&network_id) {
+        p
+    } else {
+        let (msg, rx) = EthRpcMessages::get_gas_price_msg(network_id);
+        sender.send(msg).await?;
+        rx.await??
+    };
+
+    // NOTE: The broadcaster pays for both cancellations & challenge responses, so we use the
+    // larger of the two gas limits to avoid overestimating the runway.
+    let gas_limit = std::cmp::max(config.gas_limit(&network_id)?, CHALLENGE_RESPONSE_GAS_LIMIT);
+
+    Ok(BalanceRunway::new(
+        network_id, balance, gas_price, gas_limit, min_runway,
+    ))
+}
+
+/// Gets the broadcaster's balance runway on each network, along with a warning for each network
+/// whose runway could not be got, so that one failing network doesn't hide the others.
+pub(crate) async fn get_balance_runways(
+    config: &SentinelConfig,
+    eth_rpc_senders: &EthRpcSenders,
+    address: &EthAddress,
+) -> (BalanceRunways, Vec<String>) {
+    let mut runways = vec![];
+    let mut warnings = vec![];
+
+    for network_id in config.network_ids() {
+        match get_balance_runway(config, eth_rpc_senders, address, network_id).await {
+            Ok(runway) => runways.push(runway),
+            Err(e) => {
+                let warning = format!("could not get {network_id} balance runway: {e}");
+                warn!("{warning}");
+                warnings.push(warning);
+            },
+        }
+    }
+
+    (BalanceRunways::new(runways), warnings)
+}
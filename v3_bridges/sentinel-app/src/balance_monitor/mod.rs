@@ -0,0 +1,5 @@
+mod balance_monitor_loop;
+mod get_balance_runways;
+
+pub use self::balance_monitor_loop::balance_monitor_loop;
+pub(crate) use self::get_balance_runways::get_balance_runways;
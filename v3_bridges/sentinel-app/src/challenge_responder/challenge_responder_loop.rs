@@ -13,6 +13,7 @@ use common_sentinel::{
     SentinelConfig,
     SentinelError,
     WebSocketMessagesEncodable,
+    CHALLENGE_RESPONSE_GAS_LIMIT,
 };
 use ethereum_types::U256;
 use tokio::time::{sleep, Duration};
@@ -41,7 +42,7 @@ async fn respond_to_challenge(
     info.challenge().check_affordability(balance, gas_limit, gas_price)?;
 
     let c_network_id = *info.challenge().network_id();
-    let hub = config.pnetwork_hub(&c_network_id)?;
+    let hub = config.pnetwork_hub_or_current(&c_network_id, *info.challenge().pnetwork_hub())?;
     let signed_tx = info.challenge().to_solve_challenge_tx(
         nonce,
         gas_price,
@@ -104,7 +105,7 @@ async fn respond_to_challenges(
     let address = pk.to_address();
     let mut nonce: Option<u64> = None;
     let mut gas_price: Option<u64> = None;
-    let gas_limit = 1_000_000; // FIXME make configurable for this
+    let gas_limit = CHALLENGE_RESPONSE_GAS_LIMIT; // FIXME make configurable for this
 
     for challenge_info in unsolved_challenges.iter() {
         let network_id = *challenge_info.challenge().network_id();
@@ -191,7 +192,7 @@ async fn respond_to_challenges_loop(
 }
 
 pub async fn challenge_responder_loop(
-    config: SentinelConfig,
+    mut config: SentinelConfig,
     mut challenge_responder_rx: ChallengeResponderRx,
     challenge_responder_tx: ChallengeResponderTx,
     broadcast_channel_tx: BroadcastChannelTx,
@@ -282,6 +283,11 @@ pub async fn challenge_responder_loop(
                                 core_is_connected = false;
                                 continue 'challenge_response_loop
                             },
+                            ChallengeResponderBroadcastChannelMessages::ConfigUpdated(new_config) => {
+                                info!("config updated in {name} {note}");
+                                config = *new_config;
+                                continue 'challenge_response_loop
+                            },
                         }
                     },
                 }
@@ -0,0 +1,506 @@
+use std::sync::Arc;
+
+use common_eth::{EthPrivateKey, EthTransaction};
+use common_network_ids::NetworkId;
+use common_sentinel::{
+    call_core,
+    get_utc_timestamp,
+    parse_status_from_pubsub_line,
+    subscribe_to_statuses,
+    Actor,
+    Actors,
+    BroadcastChannelMessages,
+    ChallengeState,
+    ChallengerBroadcastChannelMessages,
+    ChallengerMessages,
+    Env,
+    EthRpcMessages,
+    EthRpcSenders,
+    Heartbeats,
+    SentinelConfig,
+    SentinelError,
+    StartedChallenge,
+    StartedChallenges,
+    WebSocketMessagesEncodable,
+};
+use ethereum_types::Address as EthAddress;
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    sync::Mutex,
+    time::{sleep, Duration},
+};
+
+use crate::type_aliases::{
+    BroadcastChannelRx,
+    BroadcastChannelTx,
+    ChallengerRx,
+    ChallengerTx,
+    CoreCxnStatus,
+    EthRpcTx,
+    WebSocketTx,
+};
+
+const IPFS_RESUBSCRIBE_SLEEP_TIME: u64 = 30;
+
+async fn get_gas_price(
+    config: &SentinelConfig,
+    network_id: &NetworkId,
+    eth_rpc_tx: EthRpcTx,
+) -> Result<u64, SentinelError> {
+    let p = if let Ok(Some(p)) = config.gas_price(network_id) {
+        debug!("using {network_id} gas price from config: {p}");
+        p
+    } else {
+        let (msg, rx) = EthRpcMessages::get_gas_price_msg(*network_id);
+        eth_rpc_tx.send(msg).await?;
+        let p = rx.await??;
+        debug!("using {network_id} gas price from rpc: {p}");
+        p
+    };
+    Ok(p)
+}
+
+async fn get_nonce(network_id: &NetworkId, address: EthAddress, eth_rpc_tx: EthRpcTx) -> Result<u64, SentinelError> {
+    let (msg, rx) = EthRpcMessages::get_nonce_msg(*network_id, address);
+    eth_rpc_tx.send(msg).await?;
+    rx.await?
+}
+
+async fn push_tx(tx: EthTransaction, network_id: &NetworkId, eth_rpc_tx: EthRpcTx) -> Result<(), SentinelError> {
+    let (msg, rx) = EthRpcMessages::get_push_tx_msg(tx, *network_id);
+    eth_rpc_tx.send(msg).await?;
+    let tx_hash = rx.await??;
+    info!("tx hash: 0x{}", hex::encode(tx_hash));
+    Ok(())
+}
+
+async fn start_challenge(
+    actor: &Actor,
+    actors: &Actors,
+    nonce: u64,
+    gas_price: u64,
+    config: &SentinelConfig,
+    broadcaster_pk: &EthPrivateKey,
+    eth_rpc_tx: EthRpcTx,
+) -> Result<(), SentinelError> {
+    let c_config = config.challenger();
+    let network_id = *c_config.network_id();
+    let lock_amount = *c_config.lock_amount();
+    let gas_limit = *c_config.gas_limit();
+
+    let (balance_msg, balance_rx) = EthRpcMessages::get_eth_balance_msg(network_id, broadcaster_pk.to_address());
+    eth_rpc_tx.send(balance_msg).await?;
+    let balance = balance_rx.await??;
+    Actor::check_challenge_affordability(balance, lock_amount, gas_limit, gas_price)?;
+
+    let proof = actors.get_inclusion_proof_for_actor(actor)?;
+    let hub = config.pnetwork_hub(&network_id)?;
+    let signed_tx = actor.to_start_challenge_tx(
+        &proof,
+        lock_amount,
+        nonce,
+        gas_price,
+        gas_limit,
+        &network_id,
+        &hub,
+        broadcaster_pk,
+    )?;
+
+    info!("starting challenge against {}", actor.actor_address());
+    push_tx(signed_tx, &network_id, eth_rpc_tx).await
+}
+
+fn get_stale_actors(
+    actors: &Actors,
+    started_challenges: &StartedChallenges,
+    heartbeats: &Heartbeats,
+    threshold: u64,
+    now: u64,
+    own_addresses: &[EthAddress],
+) -> Vec<Actor> {
+    actors
+        .actors()
+        .iter()
+        .filter(|actor| !own_addresses.contains(actor.actor_address()))
+        .filter(|actor| heartbeats.is_stale(actor.actor_address(), threshold, now))
+        .filter(|actor| {
+            // NOTE: There's no point challenging an actor who's already got an open challenge
+            // from us.
+            !started_challenges
+                .iter()
+                .any(|c| c.status() == &ChallengeState::Pending && c.challenge().actor() == *actor)
+        })
+        .cloned()
+        .collect()
+}
+
+async fn update_started_challenge(
+    started_challenge: &StartedChallenge,
+    nonce: &mut Option<u64>,
+    gas_price: u64,
+    now: u64,
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    core_timeout: &u64,
+    broadcaster_pk: &EthPrivateKey,
+    eth_rpc_senders: &EthRpcSenders,
+) -> Result<(), SentinelError> {
+    let challenge = *started_challenge.challenge();
+    let network_id = *challenge.network_id();
+    let eth_rpc_tx = eth_rpc_senders.sender(&network_id)?;
+    let hub = config.pnetwork_hub_or_current(&network_id, *challenge.pnetwork_hub())?;
+
+    let (msg, rx) = EthRpcMessages::get_challenge_state_msg(network_id, challenge, hub);
+    eth_rpc_tx.send(msg).await?;
+    let state = rx.await??;
+
+    if state != ChallengeState::Pending {
+        info!("started challenge {} is now {state}", challenge.id()?);
+        let msg = WebSocketMessagesEncodable::SetStartedChallengeState(challenge.id()?, state);
+        call_core(*core_timeout, websocket_tx, msg).await?;
+        return Ok(());
+    };
+
+    if !challenge.has_expired(*config.challenger().challenge_duration(), now) {
+        debug!("started challenge {} is still pending", challenge.id()?);
+        return Ok(());
+    };
+
+    // NOTE: The challenge went unanswered, so we slash the actor & claim our reward. The challenge
+    // state will be updated once we next see it change on chain.
+    let n = match nonce {
+        Some(n) => *n,
+        None => get_nonce(&network_id, broadcaster_pk.to_address(), eth_rpc_tx.clone()).await?,
+    };
+    let gas_limit = *config.challenger().gas_limit();
+    let signed_tx = challenge.to_slash_by_challenge_tx(n, gas_price, gas_limit, &network_id, &hub, broadcaster_pk)?;
+    info!(
+        "slashing {} via expired challenge {}",
+        challenge.actor().actor_address(),
+        challenge.id()?
+    );
+    push_tx(signed_tx, &network_id, eth_rpc_tx).await?;
+    *nonce = Some(n + 1);
+    Ok(())
+}
+
+async fn challenge_stale_actors(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    core_timeout: &u64,
+    eth_rpc_senders: EthRpcSenders,
+    pk: &EthPrivateKey,
+    heartbeats: &Arc<Mutex<Heartbeats>>,
+    listening_since: Option<u64>,
+) -> Result<(), SentinelError> {
+    info!("checking for stale actors to challenge...");
+    let c_config = config.challenger();
+    let network_id = *c_config.network_id();
+    let eth_rpc_tx = eth_rpc_senders.sender(&network_id)?;
+    let threshold = *c_config.heartbeat_threshold();
+    let now = get_utc_timestamp()?;
+
+    let started_challenges = StartedChallenges::try_from(
+        call_core(
+            *core_timeout,
+            websocket_tx.clone(),
+            WebSocketMessagesEncodable::GetStartedChallenges,
+        )
+        .await?,
+    )?;
+
+    let gas_price = get_gas_price(config, &network_id, eth_rpc_tx.clone()).await?;
+    let mut nonce: Option<u64> = None;
+
+    for started_challenge in started_challenges
+        .iter()
+        .filter(|c| c.status() == &ChallengeState::Pending)
+    {
+        // NOTE: One failed update shouldn't stop us checking on the rest of our started challenges.
+        if let Err(e) = update_started_challenge(
+            started_challenge,
+            &mut nonce,
+            gas_price,
+            now,
+            config,
+            websocket_tx.clone(),
+            core_timeout,
+            pk,
+            &eth_rpc_senders,
+        )
+        .await
+        {
+            error!("error updating started challenge: {e}");
+            // NOTE: The nonce may or may not have been used, so we get it afresh next time it's needed.
+            nonce = None;
+        };
+    }
+
+    if listening_since.map_or(true, |t| now.saturating_sub(t) < threshold) {
+        // NOTE: Otherwise every actor would look stale simply because we've not been listening for
+        // long enough to have heard from them.
+        info!("not challenging any actors until heartbeats have been listened to for {threshold}s");
+        return Ok(());
+    };
+
+    let actors = Actors::try_from(
+        call_core(
+            *core_timeout,
+            websocket_tx.clone(),
+            WebSocketMessagesEncodable::GetActors,
+        )
+        .await?,
+    )?;
+    if actors.actors().is_empty() {
+        warn!("no actors in core, cannot challenge any stale ones");
+        return Ok(());
+    };
+
+    let own_addresses = [pk.to_address()];
+    let heartbeats = heartbeats.lock().await.clone();
+    if !heartbeats.has_recent_heartbeat_from_others(&own_addresses, threshold, now) {
+        // NOTE: If we've not recently heard from anyone else, it's far more likely that we're not
+        // receiving heartbeats than that every other actor has gone stale at once.
+        warn!("no recent heartbeats from any other actor, not challenging any actors");
+        return Ok(());
+    };
+
+    let stale_actors = get_stale_actors(&actors, &started_challenges, &heartbeats, threshold, now, &own_addresses);
+    if stale_actors.is_empty() {
+        info!("no stale actors to challenge");
+        return Ok(());
+    };
+
+    let mut nonce = match nonce {
+        Some(n) => n,
+        None => get_nonce(&network_id, pk.to_address(), eth_rpc_tx.clone()).await?,
+    };
+    for actor in stale_actors.iter() {
+        match start_challenge(actor, &actors, nonce, gas_price, config, pk, eth_rpc_tx.clone()).await {
+            Ok(_) => nonce += 1,
+            Err(e) => {
+                // NOTE: We can't know whether a failed tx consumed the nonce, so we get it afresh.
+                error!("error starting challenge against {}: {e}", actor.actor_address());
+                nonce = get_nonce(&network_id, pk.to_address(), eth_rpc_tx.clone()).await?;
+            },
+        }
+    }
+
+    Ok(())
+}
+
+async fn subscribe_and_record_heartbeats(
+    ipfs_bin_path: &str,
+    heartbeats: &Arc<Mutex<Heartbeats>>,
+) -> Result<(), SentinelError> {
+    let mut child = subscribe_to_statuses(ipfs_bin_path)?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| SentinelError::Custom("no stdout for ipfs status subscription".into()))?;
+    let mut lines = BufReader::new(stdout).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        match parse_status_from_pubsub_line(&line) {
+            Ok(status) => heartbeats.lock().await.record(&status),
+            Err(e) => debug!("ignoring unparseable pubsub message: {e}"),
+        }
+    }
+
+    Ok(())
+}
+
+async fn listen_for_heartbeats(ipfs_bin_path: String, heartbeats: Arc<Mutex<Heartbeats>>) {
+    // NOTE: This feeds the heartbeats from any statuses published over ipfs into the passed in arc,
+    // resubscribing whenever the subscription ends. It never returns.
+    loop {
+        match subscribe_and_record_heartbeats(&ipfs_bin_path, &heartbeats).await {
+            Ok(_) => warn!("ipfs status subscription ended"),
+            Err(e) => error!("ipfs status subscription error: {e}"),
+        };
+        warn!("resubscribing to ipfs statuses in {IPFS_RESUBSCRIBE_SLEEP_TIME}s...");
+        sleep(Duration::from_secs(IPFS_RESUBSCRIBE_SLEEP_TIME)).await;
+    }
+}
+
+fn start_heartbeat_listener(ipfs_bin_path: &str, heartbeats: &Arc<Mutex<Heartbeats>>) -> Result<u64, SentinelError> {
+    info!("starting to listen for actors' status heartbeats...");
+    tokio::spawn(listen_for_heartbeats(ipfs_bin_path.to_string(), heartbeats.clone()));
+    get_utc_timestamp()
+}
+
+async fn broadcast_channel_loop(
+    mut broadcast_channel_rx: BroadcastChannelRx,
+) -> Result<ChallengerBroadcastChannelMessages, SentinelError> {
+    // NOTE: This loops continuously listening to the broadcasting channel, and only returns if we
+    // receive a pertinent message. This way, other messages won't cause early returns in the main
+    // tokios::select, so that the main_loop can continue doing its work.
+    'broadcast_channel_loop: loop {
+        match broadcast_channel_rx.recv().await {
+            Ok(BroadcastChannelMessages::Challenger(msg)) => break 'broadcast_channel_loop Ok(msg),
+            Ok(_) => continue 'broadcast_channel_loop, // NOTE: The message wasn't for us
+            Err(e) => break 'broadcast_channel_loop Err(e.into()),
+        }
+    }
+}
+
+async fn challenge_stale_actors_loop(
+    frequency: &u64,
+    challenger_tx: ChallengerTx,
+    core_cxn_status: &CoreCxnStatus,
+    challenger_is_enabled: &bool,
+) -> Result<(), SentinelError> {
+    // NOTE: This loop runs to send messages to the challenger loop at a configurable frequency to
+    // tell it to check for stale actors. It should never return, except in error.
+    'challenge_stale_actors_loop: loop {
+        info!("challenger loop sleeping for {frequency}s...");
+        sleep(Duration::from_secs(*frequency)).await;
+
+        if !core_cxn_status {
+            warn!("core is currently not connected so cannot challenge stale actors");
+            continue 'challenge_stale_actors_loop;
+        }
+
+        if !challenger_is_enabled {
+            warn!("challenger currently disabled so will not challenge stale actors");
+            continue 'challenge_stale_actors_loop;
+        }
+
+        info!("{frequency}s has elapsed - sending message to challenge stale actors...");
+        match challenger_tx.send(ChallengerMessages::ChallengeStaleActors).await {
+            Ok(_) => continue 'challenge_stale_actors_loop,
+            Err(e) => break 'challenge_stale_actors_loop Err(e.into()),
+        }
+    }
+}
+
+pub async fn challenger_loop(
+    mut config: SentinelConfig,
+    mut challenger_rx: ChallengerRx,
+    challenger_tx: ChallengerTx,
+    broadcast_channel_tx: BroadcastChannelTx,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: EthRpcSenders,
+    disable: bool,
+) -> Result<(), SentinelError> {
+    let name = "challenger loop";
+
+    let mut core_is_connected = false;
+    // NOTE: The challenger is opt-in, so it must be enabled in the config as well.
+    let mut challenger_is_enabled = !disable && *config.challenger().enabled();
+    let core_timeout = *config.core().timeout();
+    let frequency = *config.challenger().frequency();
+    let ipfs_bin_path = config.ipfs().ipfs_bin_path().clone();
+
+    Env::init()?;
+    let pk = Env::get_private_key()?;
+
+    // NOTE: We only listen for heartbeats once the challenger is first enabled. Until then there's
+    // no need to, & we track when we started since any actor will look stale before then.
+    let heartbeats = Arc::new(Mutex::new(Heartbeats::default()));
+    let mut listening_since = if challenger_is_enabled {
+        Some(start_heartbeat_listener(&ipfs_bin_path, &heartbeats)?)
+    } else {
+        None
+    };
+
+    'challenger_loop: loop {
+        tokio::select! {
+            r = challenge_stale_actors_loop(
+                    &frequency,
+                    challenger_tx.clone(),
+                    &core_is_connected,
+                    &challenger_is_enabled,
+                ) => {
+                match r {
+                    Ok(_) => { warn!("challenge stale actors loop returned Ok(()) for some reason") },
+                    Err(e) => { error!("challenge stale actors loop error: {e}") },
+                };
+
+                let sleep_time = 30; // FIXME make configurable
+                warn!("sleeping for {sleep_time}s and restarting challenge stale actors loop");
+                sleep(Duration::from_secs(sleep_time)).await;
+                continue 'challenger_loop
+            },
+            r = challenger_rx.recv() => match r {
+                Some(ChallengerMessages::ChallengeStaleActors) => {
+                    if !core_is_connected {
+                        warn!("not challenging stale actors because no core is connected");
+                        continue 'challenger_loop
+                    } else {
+                        match challenge_stale_actors(
+                            &config,
+                            websocket_tx.clone(),
+                            &core_timeout,
+                            eth_rpc_senders.clone(),
+                            &pk,
+                            &heartbeats,
+                            listening_since,
+                        ).await {
+                            Ok(_) => continue 'challenger_loop,
+                            Err(e) => {
+                                // NOTE: A failed challenge shouldn't take down the whole sentinel,
+                                // we'll simply try again next time around.
+                                error!("error challenging stale actors: {e}");
+                                continue 'challenger_loop
+                            },
+                        }
+                    }
+                },
+                None => {
+                    let m = "all {name} senders dropped!";
+                    warn!("{m}");
+                    break 'challenger_loop Err(SentinelError::Custom(name.into()))
+                },
+            },
+            r = broadcast_channel_loop(broadcast_channel_tx.subscribe()) => {
+                match r {
+                    Err(e) => break 'challenger_loop Err(e),
+                    Ok(msg) => {
+                        let note = format!("(core is currently {}connected)", if core_is_connected { "" } else { "not "});
+                        match msg {
+                            ChallengerBroadcastChannelMessages::Stop => {
+                                warn!("msg received to stop the challenger {note}");
+                                challenger_is_enabled = false;
+                                continue 'challenger_loop
+                            },
+                            ChallengerBroadcastChannelMessages::Start => {
+                                warn!("msg received to start the challenger {note}");
+                                if listening_since.is_none() {
+                                    listening_since = Some(start_heartbeat_listener(&ipfs_bin_path, &heartbeats)?);
+                                };
+                                challenger_is_enabled = true;
+                                continue 'challenger_loop
+                            },
+                            ChallengerBroadcastChannelMessages::CoreConnected => {
+                                warn!("core connected message received in {name} {note}");
+                                core_is_connected = true;
+                                continue 'challenger_loop
+                            },
+                            ChallengerBroadcastChannelMessages::CoreDisconnected => {
+                                warn!("core disconnected message received in {name} {note}");
+                                core_is_connected = false;
+                                continue 'challenger_loop
+                            },
+                            ChallengerBroadcastChannelMessages::ConfigUpdated(new_config) => {
+                                // NOTE: The challenger section itself requires a restart to change,
+                                // but eg the gas prices it uses do not.
+                                info!("config updated in {name} {note}");
+                                config = *new_config;
+                                continue 'challenger_loop
+                            },
+                        }
+                    },
+                }
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'challenger_loop Err(SentinelError::SigInt(name.into()))
+            },
+            else => {
+                warn!("in {name} `else` branch, {name} is currently {}abled", if challenger_is_enabled { "en" } else { "dis" });
+                continue 'challenger_loop
+            },
+        }
+    }
+}
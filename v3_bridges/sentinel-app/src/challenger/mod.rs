@@ -0,0 +1,3 @@
+mod challenger_loop;
+
+pub use self::challenger_loop::challenger_loop;
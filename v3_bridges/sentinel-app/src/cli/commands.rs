@@ -8,4 +8,101 @@ pub enum Commands {
 
     /// Get submission materail for given block number from given endpoint
     GetSubMat { block_num: u64, endpoint: String },
+
+    /// Decode any user ops (& their uids) from the logs of the given tx, fetched from the given endpoint
+    DecodeUserOpsFromTx {
+        tx_hash: String,
+        network_id: String,
+        endpoint: String,
+
+        /// Only decode logs emitted by this pNetwork hub
+        #[arg(long)]
+        pnetwork_hub: Option<String>,
+    },
+
+    /// Decode a user op (& its uid) from raw log json, eg '{"address":"0x..","topics":["0x.."],"data":"0x.."}', or a
+    /// path to a file containing it
+    DecodeUserOpFromLog {
+        log: String,
+        network_id: String,
+
+        /// Hash of the tx the log is from (required for a witnessed user op, since its uid depends on it)
+        #[arg(long)]
+        tx_hash: Option<String>,
+
+        /// Hash of the block the log is from (required for a witnessed user op, since its uid depends on it)
+        #[arg(long)]
+        block_hash: Option<String>,
+
+        /// Timestamp of the block the log is from
+        #[arg(long, default_value_t = 0)]
+        block_timestamp: u64,
+    },
+
+    /// Verify an actor inclusion proof json (eg from the `getInclusionProof` rpc call), or a path to a file containing
+    /// it, against an actors merkle root
+    VerifyInclusionProof {
+        proof: String,
+        root: String,
+        actor_address: String,
+
+        #[arg(long, default_value = "sentinel")]
+        actor_type: String,
+    },
+
+    /// Validate a config file & print a summary of it with its network IDs resolved (defaults to the --configPath)
+    ValidateConfig { path: Option<String> },
+
+    /// Write a commented config template to the given path
+    GenerateConfig {
+        #[arg(default_value = "./sentinel-config.toml")]
+        path: String,
+    },
+
+    /// Verify a signed sentinel status json, or a path to a file containing it
+    VerifyStatus { status: String },
+
+    /// Verify an android key attestation certificate chain (eg from the `getAttestationCertificate` rpc call), or a
+    /// path to a file containing it, against google's hardware attestation roots & print its key description
+    VerifyAttestation {
+        chain: String,
+
+        /// Path to a PEM file of roots to verify against instead of the bundled ones
+        #[arg(long)]
+        roots_path: Option<String>,
+    },
+
+    /// Verify a db integrity proof json (eg from the `getDbIntegrityProof` rpc call), or a path to a file containing
+    /// it, against the signed db commitment root it contains
+    VerifyDbIntegrityProof {
+        proof: String,
+
+        /// Hex encoded value the proven db entry is expected to hold
+        #[arg(long)]
+        value: Option<String>,
+
+        /// Address of the core expected to have signed the db commitment
+        #[arg(long)]
+        signer: Option<String>,
+    },
+
+    /// Start re-processing the given block range on the given network in the background of a running sentinel. The
+    /// range's end is clamped to the core's latest block. Use `getBackfillReport` to fetch the result
+    Backfill {
+        network_id: String,
+        from: u64,
+        to: u64,
+
+        #[arg(long, default_value = "http://127.0.0.1:3030/v1/rpc")]
+        rpc_url: String,
+    },
+
+    /// Get the state of the latest backfill on the given network from a running sentinel, including a report of any
+    /// user ops which were added or changed as a result once it has finished
+    GetBackfillReport {
+        network_id: String,
+
+        #[arg(long, default_value = "http://127.0.0.1:3030/v1/rpc")]
+        rpc_url: String,
+    },
 }
@@ -0,0 +1,43 @@
+use std::result::Result;
+
+use common_sentinel::SentinelError;
+use reqwest::Client;
+use serde_json::{json, Value as Json};
+
+// NOTE: The backfill requires the core, so it's done by the running sentinel, which we ask via its rpc server.
+async fn call_sentinel_rpc(method: &str, params: Vec<String>, rpc_url: String) -> Result<Json, SentinelError> {
+    let body = json!({
+        "id": 1,
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": params,
+    });
+    let err = |e: reqwest::Error| SentinelError::Custom(format!("{method} rpc call to '{rpc_url}' failed: {e}"));
+    let response: Json = Client::new()
+        .post(&rpc_url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(err)?
+        .json()
+        .await
+        .map_err(err)?;
+
+    match (response.get("result"), response.get("error")) {
+        (Some(result), _) => Ok(result.clone()),
+        (None, Some(error)) => Err(SentinelError::Json(error.clone())),
+        _ => Err(SentinelError::Custom(format!(
+            "unexpected {method} rpc response: {response}"
+        ))),
+    }
+}
+
+pub async fn handle_backfill(network_id: String, from: u64, to: u64, rpc_url: String) -> Result<Json, SentinelError> {
+    debug!("handling backfill...");
+    call_sentinel_rpc("backfill", vec![network_id, from.to_string(), to.to_string()], rpc_url).await
+}
+
+pub async fn handle_get_backfill_report(network_id: String, rpc_url: String) -> Result<Json, SentinelError> {
+    debug!("handling get backfill report...");
+    call_sentinel_rpc("getBackfillReport", vec![network_id], rpc_url).await
+}
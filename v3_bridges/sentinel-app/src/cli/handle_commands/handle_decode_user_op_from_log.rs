@@ -0,0 +1,45 @@
+use std::{result::Result, str::FromStr};
+
+use common_eth::{EthLog, EthLogJson};
+use common_network_ids::NetworkId;
+use common_sentinel::{get_utc_timestamp, SentinelError, UserOp};
+use ethereum_types::H256 as EthHash;
+use serde_json::{json, Value as Json};
+
+use super::parse_json_arg;
+
+pub fn handle_decode_user_op_from_log(
+    log: String,
+    network_id: String,
+    tx_hash: Option<String>,
+    block_hash: Option<String>,
+    block_timestamp: u64,
+) -> Result<Json, SentinelError> {
+    debug!("decoding user op from log...");
+    let log_json: EthLogJson = serde_json::from_value(parse_json_arg(&log)?)?;
+    let log = EthLog::from_json(&log_json)?;
+    let network_id = NetworkId::try_from(network_id)?;
+
+    if UserOp::uid_requires_origin_hashes(&log)? && (tx_hash.is_none() || block_hash.is_none()) {
+        return Err(SentinelError::Custom(
+            "`--tx-hash` & `--block-hash` are required to get the correct uid of a witnessed user op".into(),
+        ));
+    };
+
+    let tx_hash = tx_hash.map(|s| EthHash::from_str(&s)).transpose()?.unwrap_or_default();
+    let block_hash = block_hash
+        .map(|s| EthHash::from_str(&s))
+        .transpose()?
+        .unwrap_or_default();
+
+    let op = UserOp::from_log(
+        get_utc_timestamp()?,
+        block_timestamp,
+        block_hash,
+        tx_hash,
+        &network_id,
+        &log,
+    )?;
+
+    Ok(json!({ "uid": op.uid_hex()?, "userOp": op }))
+}
@@ -0,0 +1,43 @@
+use std::{result::Result, str::FromStr};
+
+use common_eth::convert_hex_to_eth_address;
+use common_network_ids::NetworkId;
+use common_sentinel::{get_block, get_receipts, get_utc_timestamp, Endpoints, SentinelError, UserOps};
+use ethereum_types::H256 as EthHash;
+use serde_json::{json, Value as Json};
+use tokio::time::{sleep, Duration};
+
+pub async fn handle_decode_user_ops_from_tx(
+    tx_hash: String,
+    network_id: String,
+    endpoint: String,
+    pnetwork_hub: Option<String>,
+) -> Result<Json, SentinelError> {
+    debug!("decoding user ops from tx...");
+    let sleep_time = 30;
+    let tx_hash = EthHash::from_str(&tx_hash)?;
+    let network_id = NetworkId::try_from(network_id)?;
+    let pnetwork_hub = pnetwork_hub.map(|s| convert_hex_to_eth_address(&s)).transpose()?;
+
+    let endpoints = Endpoints::new(sleep_time, network_id, vec![endpoint]);
+    let client = endpoints.get_first_ws_client().await?;
+    let receipts = get_receipts(&client, &[tx_hash], sleep_time, &network_id).await?;
+    let receipt = receipts
+        .first()
+        .ok_or_else(|| SentinelError::Custom(format!("no receipt found for tx 0x{}", hex::encode(tx_hash))))?;
+    let block = get_block(&client, receipt.block_number.as_u64(), sleep_time, &network_id).await?;
+
+    let user_ops = UserOps::from_receipt(
+        &network_id,
+        pnetwork_hub.as_ref(),
+        receipt,
+        receipt.block_hash,
+        block.timestamp.as_u64(),
+        get_utc_timestamp()?,
+    )?;
+    let uids = user_ops.iter().map(|op| op.uid_hex()).collect::<Result<Vec<_>, _>>()?;
+
+    // NOTE: sleep to let any connection teardown logs finish
+    sleep(Duration::from_secs(1)).await;
+    Ok(json!({ "uids": uids, "userOps": user_ops }))
+}
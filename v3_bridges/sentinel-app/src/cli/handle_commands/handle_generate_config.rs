@@ -0,0 +1,15 @@
+use std::{fs::write, path::Path, result::Result};
+
+use common_sentinel::{SentinelConfig, SentinelError};
+use serde_json::{json, Value as Json};
+
+pub fn handle_generate_config(path: String) -> Result<Json, SentinelError> {
+    debug!("generating config template...");
+    if Path::new(&path).exists() {
+        return Err(SentinelError::Custom(format!(
+            "not overwriting existing file at '{path}'"
+        )));
+    };
+    write(&path, SentinelConfig::template())?;
+    Ok(json!(format!("config template written to '{path}'")))
+}
@@ -0,0 +1,43 @@
+use std::result::Result;
+
+use common_sentinel::{SentinelConfig, SentinelError};
+use serde_json::{json, Value as Json};
+
+pub fn handle_validate_config(path: String) -> Result<Json, SentinelError> {
+    debug!("validating config at '{path}'...");
+    // NOTE: Parsing the config runs all the same checks as starting the sentinel does.
+    let config = SentinelConfig::new(&path)?;
+
+    let networks = config
+        .network_ids()
+        .iter()
+        .map(|nid| {
+            Ok(json!({
+                "networkId": nid.to_string(),
+                "networkIdHex": nid.to_hex()?,
+                "pnetworkHub": config.pnetwork_hub(nid)?,
+                "pnetworkHubs": config.pnetwork_hubs(nid)?,
+                "numEndpoints": config.endpoints(nid)?.endpoints().len(),
+                "gasLimit": config.gas_limit(nid)?,
+                "gasPrice": config.gas_price(nid)?,
+            }))
+        })
+        .collect::<Result<Vec<Json>, SentinelError>>()?;
+    let governance_network_id = config.governance().network_id();
+
+    Ok(json!({
+        "path": path,
+        "valid": true,
+        "networks": networks,
+        "governance": {
+            "networkId": governance_network_id.to_string(),
+            "networkIdHex": governance_network_id.to_hex()?,
+            "address": config.governance().governance_address(),
+        },
+        "core": config.core(),
+        "challenger": config.challenger(),
+        "notifications": config.notifications(),
+        "balances": config.balances(),
+        "attestation": config.attestation(),
+    }))
+}
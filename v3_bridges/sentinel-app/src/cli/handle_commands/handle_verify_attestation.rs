@@ -0,0 +1,24 @@
+use std::{fs::read_to_string, path::Path, result::Result, str::FromStr};
+
+use common_sentinel::{get_utc_timestamp, AttestationCertificateChain, AttestationRoots, SentinelError};
+use serde_json::{json, Value as Json};
+
+pub fn handle_verify_attestation(chain: String, roots_path: Option<String>) -> Result<Json, SentinelError> {
+    debug!("verifying attestation certificate chain...");
+    // NOTE: The chain can be passed in directly, or as a path to a file containing it.
+    let chain = if Path::new(&chain).is_file() {
+        AttestationCertificateChain::from_str(&read_to_string(&chain)?)?
+    } else {
+        AttestationCertificateChain::from_str(&chain)?
+    };
+    let roots = match roots_path {
+        Some(ref path) => AttestationRoots::from_file(path)?,
+        None => AttestationRoots::bundled()?,
+    };
+    let verified = chain.verify(&roots, get_utc_timestamp()?)?;
+    Ok(json!({
+        "verified": true,
+        "chainLength": chain.len(),
+        "attestation": verified.to_json(),
+    }))
+}
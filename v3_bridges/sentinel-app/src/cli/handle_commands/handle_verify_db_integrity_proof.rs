@@ -0,0 +1,31 @@
+use std::result::Result;
+
+use common::strip_hex_prefix;
+use common_eth::convert_hex_to_eth_address;
+use common_sentinel::{DbIntegrityProof, SentinelError};
+use serde_json::{json, Value as Json};
+
+use super::parse_json_arg;
+
+pub fn handle_verify_db_integrity_proof(
+    proof: String,
+    value: Option<String>,
+    signer: Option<String>,
+) -> Result<Json, SentinelError> {
+    debug!("verifying db integrity proof...");
+    let proof = DbIntegrityProof::try_from(parse_json_arg(&proof)?)?;
+    let value = value.map(|v| hex::decode(strip_hex_prefix(&v))).transpose()?;
+    let recovered_signer = proof.signer_address()?;
+    let signer_matches = match signer {
+        Some(ref s) => convert_hex_to_eth_address(s)? == recovered_signer,
+        None => true,
+    };
+    let included = proof.verify(value.as_deref())?;
+    Ok(json!({
+        "verified": included && signer_matches,
+        "key": format!("0x{}", hex::encode(proof.key())),
+        "root": proof.root(),
+        "numEntries": proof.num_entries(),
+        "signer": recovered_signer,
+    }))
+}
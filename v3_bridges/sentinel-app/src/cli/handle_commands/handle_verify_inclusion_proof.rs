@@ -0,0 +1,25 @@
+use std::{result::Result, str::FromStr};
+
+use common_eth::convert_hex_to_eth_address;
+use common_sentinel::{Actor, ActorInclusionProof, ActorType, SentinelError};
+use ethereum_types::H256 as EthHash;
+use serde_json::{json, Value as Json};
+
+use super::parse_json_arg;
+
+pub fn handle_verify_inclusion_proof(
+    proof: String,
+    root: String,
+    actor_address: String,
+    actor_type: String,
+) -> Result<Json, SentinelError> {
+    debug!("verifying actor inclusion proof...");
+    let proof = ActorInclusionProof::try_from(parse_json_arg(&proof)?)?;
+    let root = EthHash::from_str(&root)?;
+    let actor = Actor::new(
+        ActorType::from_str(&actor_type)?,
+        convert_hex_to_eth_address(&actor_address)?,
+    );
+    let verified = proof.verify(&actor, &root)?;
+    Ok(json!({ "verified": verified, "actor": actor, "root": format!("0x{}", hex::encode(root)) }))
+}
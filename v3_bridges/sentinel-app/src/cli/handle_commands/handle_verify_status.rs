@@ -0,0 +1,18 @@
+use std::result::Result;
+
+use common_sentinel::{SentinelError, SentinelStatus};
+use serde_json::{json, Value as Json};
+
+use super::parse_json_arg;
+
+pub fn handle_verify_status(status: String) -> Result<Json, SentinelError> {
+    debug!("verifying sentinel status...");
+    let status: SentinelStatus = serde_json::from_value(parse_json_arg(&status)?)?;
+    let signer = status.verify()?;
+    Ok(json!({
+        "verified": true,
+        "signer": format!("0x{}", hex::encode(signer)),
+        "timestamp": status.timestamp(),
+        "balanceWarnings": status.balance_warnings(),
+    }))
+}
@@ -1,4 +1,39 @@
+mod handle_backfill;
+mod handle_decode_user_op_from_log;
+mod handle_decode_user_ops_from_tx;
+mod handle_generate_config;
 mod handle_get_sub_mat;
 mod handle_test_endpoint;
+mod handle_validate_config;
+mod handle_verify_attestation;
+mod handle_verify_db_integrity_proof;
+mod handle_verify_inclusion_proof;
+mod handle_verify_status;
 
-pub(super) use self::{handle_get_sub_mat::handle_get_sub_mat, handle_test_endpoint::handle_test_endpoint};
+use std::{fs::read_to_string, path::Path};
+
+use common_sentinel::SentinelError;
+use serde_json::Value as Json;
+
+pub(super) use self::{
+    handle_backfill::{handle_backfill, handle_get_backfill_report},
+    handle_decode_user_op_from_log::handle_decode_user_op_from_log,
+    handle_decode_user_ops_from_tx::handle_decode_user_ops_from_tx,
+    handle_generate_config::handle_generate_config,
+    handle_get_sub_mat::handle_get_sub_mat,
+    handle_test_endpoint::handle_test_endpoint,
+    handle_validate_config::handle_validate_config,
+    handle_verify_attestation::handle_verify_attestation,
+    handle_verify_db_integrity_proof::handle_verify_db_integrity_proof,
+    handle_verify_inclusion_proof::handle_verify_inclusion_proof,
+    handle_verify_status::handle_verify_status,
+};
+
+// NOTE: Json args can be passed in directly, or as a path to a file containing them.
+fn parse_json_arg(s: &str) -> Result<Json, SentinelError> {
+    if Path::new(s).is_file() {
+        Ok(serde_json::from_str(&read_to_string(s)?)?)
+    } else {
+        Ok(serde_json::from_str(s)?)
+    }
+}
@@ -4,12 +4,58 @@ use common_sentinel::SentinelError;
 use serde_json::json;
 
 pub(crate) use self::commands::Commands;
-use self::handle_commands::{handle_get_sub_mat, handle_test_endpoint};
+use self::handle_commands::{
+    handle_backfill,
+    handle_decode_user_op_from_log,
+    handle_decode_user_ops_from_tx,
+    handle_generate_config,
+    handle_get_backfill_report,
+    handle_get_sub_mat,
+    handle_test_endpoint,
+    handle_validate_config,
+    handle_verify_attestation,
+    handle_verify_db_integrity_proof,
+    handle_verify_inclusion_proof,
+    handle_verify_status,
+};
 
-pub async fn handle_cli(cmds: Commands) -> Result<String, SentinelError> {
+pub async fn handle_cli(cmds: Commands, config_path: String) -> Result<String, SentinelError> {
     let result = match cmds {
-        Commands::TestEndpoint { endpoint } => handle_test_endpoint(endpoint).await,
-        Commands::GetSubMat { block_num, endpoint } => handle_get_sub_mat(block_num, endpoint).await,
+        Commands::TestEndpoint { endpoint } => handle_test_endpoint(endpoint).await.map(|s| json!(s)),
+        Commands::GetSubMat { block_num, endpoint } => handle_get_sub_mat(block_num, endpoint).await.map(|s| json!(s)),
+        Commands::DecodeUserOpsFromTx {
+            tx_hash,
+            network_id,
+            endpoint,
+            pnetwork_hub,
+        } => handle_decode_user_ops_from_tx(tx_hash, network_id, endpoint, pnetwork_hub).await,
+        Commands::DecodeUserOpFromLog {
+            log,
+            network_id,
+            tx_hash,
+            block_hash,
+            block_timestamp,
+        } => handle_decode_user_op_from_log(log, network_id, tx_hash, block_hash, block_timestamp),
+        Commands::VerifyInclusionProof {
+            proof,
+            root,
+            actor_address,
+            actor_type,
+        } => handle_verify_inclusion_proof(proof, root, actor_address, actor_type),
+        Commands::ValidateConfig { path } => handle_validate_config(path.unwrap_or(config_path)),
+        Commands::GenerateConfig { path } => handle_generate_config(path),
+        Commands::VerifyStatus { status } => handle_verify_status(status),
+        Commands::VerifyAttestation { chain, roots_path } => handle_verify_attestation(chain, roots_path),
+        Commands::VerifyDbIntegrityProof { proof, value, signer } => {
+            handle_verify_db_integrity_proof(proof, value, signer)
+        },
+        Commands::Backfill {
+            network_id,
+            from,
+            to,
+            rpc_url,
+        } => handle_backfill(network_id, from, to, rpc_url).await,
+        Commands::GetBackfillReport { network_id, rpc_url } => handle_get_backfill_report(network_id, rpc_url).await,
     };
 
     result
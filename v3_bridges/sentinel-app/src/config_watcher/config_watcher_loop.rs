@@ -0,0 +1,172 @@
+use std::{path::Path, time::SystemTime};
+
+use common_sentinel::{
+    BalanceMonitorBroadcastChannelMessages,
+    BroadcastChannelMessages,
+    ChallengeResponderBroadcastChannelMessages,
+    ChallengerBroadcastChannelMessages,
+    ConfigWatcherMessages,
+    EthRpcBroadcastChannelMessages,
+    RpcServerBroadcastChannelMessages,
+    SentinelConfig,
+    SentinelError,
+    SyncerBroadcastChannelMessages,
+    UserOpCancellerBroadcastChannelMessages,
+};
+use serde_json::{json, Value as Json};
+use tokio::time::{sleep, Duration};
+
+use crate::type_aliases::{BroadcastChannelTx, ConfigWatcherRx};
+
+const CONFIG_POLL_FREQUENCY: u64 = 10; // NOTE: In seconds
+
+fn get_config_file_path(config_path: &str) -> String {
+    // NOTE: The config crate accepts the path without its extension, so we must too.
+    let with_extension = format!("{config_path}.toml");
+    if Path::new(config_path).is_file() || !Path::new(&with_extension).is_file() {
+        config_path.to_string()
+    } else {
+        with_extension
+    }
+}
+
+async fn get_last_modified(path: &str) -> Option<SystemTime> {
+    tokio::fs::metadata(path).await.and_then(|m| m.modified()).ok()
+}
+
+fn broadcast_config(config: &SentinelConfig, broadcast_channel_tx: &BroadcastChannelTx) -> Result<(), SentinelError> {
+    let c = || Box::new(config.clone());
+    for nid in config.network_ids() {
+        broadcast_channel_tx.send(BroadcastChannelMessages::Syncer(
+            nid,
+            SyncerBroadcastChannelMessages::ConfigUpdated(c()),
+        ))?;
+    }
+    broadcast_channel_tx.send(BroadcastChannelMessages::EthRpc(
+        EthRpcBroadcastChannelMessages::ConfigUpdated(c()),
+    ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::UserOpCanceller(
+        UserOpCancellerBroadcastChannelMessages::ConfigUpdated(c()),
+    ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::ChallengeResponder(
+        ChallengeResponderBroadcastChannelMessages::ConfigUpdated(c()),
+    ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::Challenger(
+        ChallengerBroadcastChannelMessages::ConfigUpdated(c()),
+    ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::RpcServer(
+        RpcServerBroadcastChannelMessages::ConfigUpdated(c()),
+    ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::BalanceMonitor(
+        BalanceMonitorBroadcastChannelMessages::ConfigUpdated(c()),
+    ))?;
+    Ok(())
+}
+
+fn reload_config(
+    config_path: &str,
+    config: &mut SentinelConfig,
+    broadcast_channel_tx: &BroadcastChannelTx,
+) -> Result<Json, SentinelError> {
+    // NOTE: Parsing the config runs all the usual sanity checks on it.
+    let new_config = SentinelConfig::new(config_path)?;
+    if new_config == *config {
+        info!("config unchanged, nothing to reload");
+        return Ok(json!({ "reloaded": false, "reason": "config unchanged" }));
+    };
+    config.check_is_hot_reloadable(&new_config)?;
+    broadcast_config(&new_config, broadcast_channel_tx)?;
+    *config = new_config;
+    info!("config reloaded from '{config_path}'");
+    Ok(json!({ "reloaded": true }))
+}
+
+pub async fn config_watcher_loop(
+    config_path: String,
+    mut config: SentinelConfig,
+    mut config_watcher_rx: ConfigWatcherRx,
+    broadcast_channel_tx: BroadcastChannelTx,
+) -> Result<(), SentinelError> {
+    let name = "config watcher";
+    let file_path = get_config_file_path(&config_path);
+    let mut last_modified = get_last_modified(&file_path).await;
+    if last_modified.is_none() {
+        warn!("cannot get modification time of '{file_path}', only reloading via rpc call");
+    };
+
+    'config_watcher_loop: loop {
+        tokio::select! {
+            _ = sleep(Duration::from_secs(CONFIG_POLL_FREQUENCY)), if last_modified.is_some() => {
+                let modified = get_last_modified(&file_path).await;
+                if modified == last_modified {
+                    continue 'config_watcher_loop
+                };
+                info!("config file '{file_path}' changed, reloading...");
+                last_modified = modified;
+                if let Err(e) = reload_config(&config_path, &mut config, &broadcast_channel_tx) {
+                    // NOTE: A bad edit shouldn't take down the sentinel, we simply keep running with
+                    // the last good config.
+                    error!("could not reload config, continuing with the current one: {e}");
+                };
+                continue 'config_watcher_loop
+            },
+            r = config_watcher_rx.recv() => match r {
+                Some(ConfigWatcherMessages::Reload(responder)) => {
+                    let _ = responder.send(reload_config(&config_path, &mut config, &broadcast_channel_tx));
+                    continue 'config_watcher_loop
+                },
+                None => {
+                    let m = "all {name} senders dropped!";
+                    warn!("{m}");
+                    break 'config_watcher_loop Err(SentinelError::Custom(name.into()))
+                },
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'config_watcher_loop Err(SentinelError::SigInt(name.into()))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env::temp_dir,
+        fs::{read_to_string, write},
+    };
+
+    use tokio::sync::broadcast;
+
+    use super::*;
+
+    fn write_sample_config_with_chain_registry(name: &str, replacements: &[(&str, &str)]) -> String {
+        // NOTE: The sample config's paths are relative to the sentinel crate, so we make them absolute.
+        let dir = format!("{}/../../common/sentinel", env!("CARGO_MANIFEST_DIR"));
+        let path = format!("{dir}/src/config/test_utils/sample-config-with-chain-registry.toml");
+        let toml = replacements.iter().fold(
+            read_to_string(path).unwrap().replace("\"src/", &format!("\"{dir}/src/")),
+            |toml, (from, to)| toml.replace(from, to),
+        );
+        let config_path = temp_dir().join(name);
+        write(config_path.with_extension("toml"), toml).unwrap();
+        config_path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn should_reload_config_with_chain_registry() {
+        let config_path = write_sample_config_with_chain_registry("sentinel-config-with-chain-registry", &[]);
+        let mut config = SentinelConfig::new(&config_path).unwrap();
+        let (broadcast_channel_tx, _broadcast_channel_rx) = broadcast::channel(100);
+
+        let result = reload_config(&config_path, &mut config, &broadcast_channel_tx).unwrap();
+        assert_eq!(result, json!({ "reloaded": false, "reason": "config unchanged" }));
+
+        let from = "user_op_cancellation_frequency = 120";
+        let to = "user_op_cancellation_frequency = 180";
+        write_sample_config_with_chain_registry("sentinel-config-with-chain-registry", &[(from, to)]);
+        let result = reload_config(&config_path, &mut config, &broadcast_channel_tx).unwrap();
+        assert_eq!(result, json!({ "reloaded": true }));
+        assert_eq!(*config.core().user_op_cancellation_frequency(), 180);
+    }
+}
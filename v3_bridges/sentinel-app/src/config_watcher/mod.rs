@@ -0,0 +1,3 @@
+mod config_watcher_loop;
+
+pub use self::config_watcher_loop::config_watcher_loop;
@@ -13,6 +13,7 @@ use common_sentinel::{
     push_tx,
     BroadcastChannelMessages,
     Endpoints,
+    EthRpcBroadcastChannelMessages,
     EthRpcMessages,
     SentinelConfig,
     SentinelError,
@@ -45,20 +46,59 @@ async fn rotate_endpoint(
     Ok(())
 }
 
+async fn broadcast_channel_loop(
+    mut broadcast_channel_rx: MpMcRx<BroadcastChannelMessages>,
+) -> Result<EthRpcBroadcastChannelMessages, SentinelError> {
+    // NOTE: This loops continuously listening to the broadcasting channel, and only returns if we
+    // receive a pertinent message.
+    'broadcast_channel_loop: loop {
+        match broadcast_channel_rx.recv().await {
+            Ok(BroadcastChannelMessages::EthRpc(msg)) => break 'broadcast_channel_loop Ok(msg),
+            Ok(_) => continue 'broadcast_channel_loop, // NOTE: The message wasn't for us
+            Err(e) => break 'broadcast_channel_loop Err(e.into()),
+        }
+    }
+}
+
 pub async fn eth_rpc_loop(
     mut eth_rpc_rx: MpscRx<EthRpcMessages>,
-    config: SentinelConfig,
+    mut config: SentinelConfig,
     network_id: NetworkId,
-    _broadcast_channel_tx: MpMcTx<BroadcastChannelMessages>,
+    broadcast_channel_tx: MpMcTx<BroadcastChannelMessages>,
     _broadcast_channel_rx: MpMcRx<BroadcastChannelMessages>,
 ) -> Result<(), SentinelError> {
     let mut endpoints = config.endpoints(&network_id)?;
     let mut use_quicknode = endpoints.use_quicknode();
-    let sleep_duration = *endpoints.sleep_time();
+    let mut sleep_duration = *endpoints.sleep_time();
     let mut ws_client = endpoints.get_first_ws_client().await?;
 
     'eth_rpc_loop: loop {
         tokio::select! {
+            r = broadcast_channel_loop(broadcast_channel_tx.subscribe()) => match r {
+                Ok(EthRpcBroadcastChannelMessages::ConfigUpdated(new_config)) => {
+                    // NOTE: We compare against the old config rather than the current endpoints since
+                    // the latter will differ if they've been rotated.
+                    let new_endpoints = new_config.endpoints(&network_id)?;
+                    if new_endpoints == config.endpoints(&network_id)? {
+                        debug!("{network_id} endpoints unchanged by config update");
+                        config = *new_config;
+                        continue 'eth_rpc_loop
+                    };
+                    info!("{network_id} endpoints changed, connecting to new endpoint...");
+                    match new_endpoints.get_first_ws_client().await {
+                        Ok(client) => {
+                            ws_client = client;
+                            endpoints = new_endpoints;
+                            use_quicknode = endpoints.use_quicknode();
+                            sleep_duration = *endpoints.sleep_time();
+                            config = *new_config;
+                        },
+                        Err(e) => error!("could not connect to new {network_id} endpoints, keeping the old ones: {e}"),
+                    };
+                    continue 'eth_rpc_loop
+                },
+                Err(e) => break 'eth_rpc_loop Err(e),
+            },
             r = eth_rpc_rx.recv() => match r {
                 Some(msg) => {
                     match msg {
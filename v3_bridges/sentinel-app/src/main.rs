@@ -1,6 +1,11 @@
+mod backfill;
+mod balance_monitor;
 mod challenge_responder;
+mod challenger;
 mod cli;
+mod config_watcher;
 mod eth_rpc;
+mod notifier;
 mod rpc_server;
 mod start_sentinel;
 mod status_publisher;
@@ -55,16 +60,20 @@ async fn start() -> Result<String, SentinelError> {
     } else {
         "sentinel-config".to_string()
     };
-    let config = SentinelConfig::new(&config_path)?;
+    let config = SentinelConfig::new(&config_path);
 
-    if config.log().is_enabled() {
-        init_logger(config.log(), cli_args.log_level())?
+    if let Ok(ref c) = config {
+        if c.log().is_enabled() {
+            init_logger(c.log(), cli_args.log_level())?
+        };
     };
 
     if let Some(commands) = cli_args.commands {
-        handle_cli(commands).await
+        // NOTE: The cli commands are for use offline, eg during incidents, so must not require a
+        // valid config.
+        handle_cli(commands, config_path).await
     } else {
-        start_sentinel::start_sentinel(&config, cli_args.disable)
+        start_sentinel::start_sentinel(&config?, &config_path, cli_args.disable)
             .await
             .map_err(|e| SentinelError::Json(json!({"jsonrpc": "2.0", "error": e.to_string()})))
     }
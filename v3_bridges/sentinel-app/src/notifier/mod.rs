@@ -0,0 +1,4 @@
+mod notifier_loop;
+
+pub(crate) use self::notifier_loop::notify_with_timeout;
+pub use self::notifier_loop::notifier_loop;
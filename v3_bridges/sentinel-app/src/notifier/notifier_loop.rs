@@ -0,0 +1,253 @@
+use common_eth::EthPrivateKey;
+use common_sentinel::{
+    call_core,
+    deliver_webhook,
+    get_utc_timestamp,
+    BroadcastChannelMessages,
+    Env,
+    Notification,
+    NotificationsConfig,
+    NotifierBroadcastChannelMessages,
+    NotifierMessages,
+    SentinelConfig,
+    SentinelError,
+    SentinelEvents,
+    WebSocketMessagesEncodable,
+    WebhookQueue,
+};
+use futures::stream::{self, StreamExt};
+use reqwest::Client;
+use tokio::{
+    sync::mpsc::error::SendTimeoutError,
+    time::{sleep, Duration},
+};
+
+use crate::type_aliases::{
+    BroadcastChannelRx,
+    BroadcastChannelTx,
+    NotificationsTx,
+    NotifierRx,
+    NotifierTx,
+    WebSocketTx,
+};
+
+// NOTE: So that a few slow or unresponsive webhooks can't hold up the notifier loop for long.
+const MAX_CONCURRENT_WEBHOOK_DELIVERIES: usize = 10;
+
+// NOTE: How long a sender waits for room in the notifier's channel before dropping its events.
+const NOTIFIER_SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Sends the passed in events to the notifier, waiting up to `NOTIFIER_SEND_TIMEOUT` for room in
+/// its channel so that a briefly backed up notifier applies backpressure to the sender.
+///
+/// ### NOTE:
+///
+/// Should the channel still be full after that, the events are dropped, & with them their
+/// notifications & webhook deliveries, so that a stalled notifier can never stall the sender.
+pub(crate) async fn notify_with_timeout(notifier_tx: &NotifierTx, events: SentinelEvents) -> Result<(), SentinelError> {
+    match notifier_tx
+        .send_timeout(NotifierMessages::Notify(events), NOTIFIER_SEND_TIMEOUT)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(SendTimeoutError::Timeout(msg)) => {
+            error!("notifier channel still full after {NOTIFIER_SEND_TIMEOUT:?}, dropping message: {msg:?}");
+            Ok(())
+        },
+        Err(SendTimeoutError::Closed(msg)) => Err(tokio::sync::mpsc::error::SendError(msg).into()),
+    }
+}
+
+fn notify(
+    events: SentinelEvents,
+    notifications_tx: &NotificationsTx,
+    queue: &mut WebhookQueue,
+    n_config: &NotificationsConfig,
+) -> Result<(), SentinelError> {
+    for event in events.iter() {
+        let notification = Notification::new(event.clone())?;
+        info!("{notification}");
+        if notifications_tx.send(notification.clone()).is_err() {
+            debug!("no websocket subscribers for {notification}");
+        };
+        queue.enqueue(&notification, n_config.webhooks());
+    }
+    Ok(())
+}
+
+async fn deliver_due_webhooks(
+    queue: &mut WebhookQueue,
+    client: &Client,
+    pk: &EthPrivateKey,
+    n_config: &NotificationsConfig,
+) -> Result<(), SentinelError> {
+    let now = get_utc_timestamp()?;
+    let failed = stream::iter(queue.take_due(now))
+        .map(|delivery| async move {
+            match deliver_webhook(client, &delivery, pk, *n_config.timeout()).await {
+                Ok(_) => None,
+                Err(e) => {
+                    warn!("webhook delivery failed: {e}");
+                    Some(delivery)
+                },
+            }
+        })
+        .buffer_unordered(MAX_CONCURRENT_WEBHOOK_DELIVERIES)
+        .filter_map(|maybe_failed| async move { maybe_failed })
+        .collect::<Vec<_>>()
+        .await;
+    for delivery in failed {
+        queue.requeue_failed(delivery, now, *n_config.retry_frequency(), *n_config.max_attempts());
+    }
+    Ok(())
+}
+
+async fn persist_queue(queue: &WebhookQueue, websocket_tx: WebSocketTx, core_timeout: &u64) {
+    // NOTE: The queue lives in memory & is mirrored to the core's db so that undelivered webhooks
+    // survive restarts. Failure here is non-fatal since we'll try again on the next change.
+    let msg = WebSocketMessagesEncodable::SetWebhookQueue(queue.clone());
+    if let Err(e) = call_core(*core_timeout, websocket_tx, msg).await {
+        warn!("could not persist webhook queue: {e}");
+    }
+}
+
+async fn broadcast_channel_loop(
+    mut broadcast_channel_rx: BroadcastChannelRx,
+) -> Result<NotifierBroadcastChannelMessages, SentinelError> {
+    // NOTE: This loops continuously listening to the broadcasting channel, and only returns if we
+    // receive a pertinent message. This way, other messages won't cause early returns in the main
+    // tokios::select, so that the main_loop can continue doing its work.
+    'broadcast_channel_loop: loop {
+        match broadcast_channel_rx.recv().await {
+            Ok(BroadcastChannelMessages::Notifier(msg)) => break 'broadcast_channel_loop Ok(msg),
+            Ok(_) => continue 'broadcast_channel_loop, // NOTE: The message wasn't for us
+            Err(e) => break 'broadcast_channel_loop Err(e.into()),
+        }
+    }
+}
+
+async fn retry_webhooks_loop(
+    frequency: &u64,
+    notifier_tx: NotifierTx,
+    has_webhooks: &bool,
+) -> Result<(), SentinelError> {
+    // NOTE: This loop runs to send messages to the notifier loop at a configurable frequency to
+    // tell it to retry any failed webhook deliveries. It should never return, except in error.
+    if !has_webhooks {
+        // NOTE: With no webhooks configured there's never anything to retry.
+        return std::future::pending().await;
+    };
+
+    'retry_webhooks_loop: loop {
+        sleep(Duration::from_secs(*frequency)).await;
+        match notifier_tx.send(NotifierMessages::RetryWebhooks).await {
+            Ok(_) => continue 'retry_webhooks_loop,
+            Err(e) => break 'retry_webhooks_loop Err(e.into()),
+        }
+    }
+}
+
+pub async fn notifier_loop(
+    config: SentinelConfig,
+    mut notifier_rx: NotifierRx,
+    notifier_tx: NotifierTx,
+    notifications_tx: NotificationsTx,
+    broadcast_channel_tx: BroadcastChannelTx,
+    websocket_tx: WebSocketTx,
+) -> Result<(), SentinelError> {
+    let name = "notifier loop";
+
+    let mut core_is_connected = false;
+    let core_timeout = *config.core().timeout();
+    let n_config = config.notifications().clone();
+    let has_webhooks = n_config.has_webhooks();
+    let frequency = *n_config.retry_frequency();
+    let client = Client::new();
+    let mut queue = WebhookQueue::default();
+
+    // NOTE: Webhook bodies are signed by the app's broadcaster key so receivers can verify them.
+    let pk = if has_webhooks {
+        Env::init()?;
+        Some(Env::get_private_key()?)
+    } else {
+        None
+    };
+
+    'notifier_loop: loop {
+        tokio::select! {
+            r = retry_webhooks_loop(&frequency, notifier_tx.clone(), &has_webhooks) => {
+                match r {
+                    Ok(_) => { warn!("retry webhooks loop returned Ok(()) for some reason") },
+                    Err(e) => { error!("retry webhooks loop error: {e}") },
+                };
+
+                let sleep_time = 30; // FIXME make configurable
+                warn!("sleeping for {sleep_time}s and restarting retry webhooks loop");
+                sleep(Duration::from_secs(sleep_time)).await;
+                continue 'notifier_loop
+            },
+            r = notifier_rx.recv() => {
+                let events = match r {
+                    Some(NotifierMessages::Notify(events)) => Some(events),
+                    Some(NotifierMessages::RetryWebhooks) => None,
+                    None => {
+                        let m = format!("all {name} senders dropped!");
+                        warn!("{m}");
+                        break 'notifier_loop Err(SentinelError::Custom(m))
+                    },
+                };
+                if let Some(events) = events {
+                    if let Err(e) = notify(events, &notifications_tx, &mut queue, &n_config) {
+                        error!("error creating notifications: {e}");
+                    };
+                };
+                if let Some(ref pk) = pk {
+                    if queue.is_empty() {
+                        continue 'notifier_loop
+                    };
+                    if let Err(e) = deliver_due_webhooks(&mut queue, &client, pk, &n_config).await {
+                        error!("error delivering webhooks: {e}");
+                    };
+                    if core_is_connected {
+                        persist_queue(&queue, websocket_tx.clone(), &core_timeout).await;
+                    };
+                };
+                continue 'notifier_loop
+            },
+            r = broadcast_channel_loop(broadcast_channel_tx.subscribe()) => {
+                match r {
+                    Err(e) => break 'notifier_loop Err(e),
+                    Ok(NotifierBroadcastChannelMessages::CoreConnected) => {
+                        warn!("core connected message received in {name}");
+                        core_is_connected = true;
+                        if has_webhooks {
+                            // NOTE: Pick up any deliveries left over from before a restart, then
+                            // write back whatever we queued whilst the core was away.
+                            let msg = WebSocketMessagesEncodable::GetWebhookQueue;
+                            match call_core(core_timeout, websocket_tx.clone(), msg).await.map(WebhookQueue::try_from) {
+                                Ok(Ok(q)) => queue.merge(q),
+                                Ok(Err(e)) => warn!("could not parse webhook queue from core: {e}"),
+                                Err(e) => warn!("could not get webhook queue from core: {e}"),
+                            };
+                            persist_queue(&queue, websocket_tx.clone(), &core_timeout).await;
+                        };
+                        continue 'notifier_loop
+                    },
+                    Ok(NotifierBroadcastChannelMessages::CoreDisconnected) => {
+                        warn!("core disconnected message received in {name}");
+                        core_is_connected = false;
+                        continue 'notifier_loop
+                    },
+                }
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'notifier_loop Err(SentinelError::SigInt(name.into()))
+            },
+            else => {
+                warn!("in {name} `else` branch");
+                continue 'notifier_loop
+            },
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use common_network_ids::NetworkId;
+use common_sentinel::{SentinelConfig, SentinelError};
+
+use crate::{
+    backfill::{start_backfill, BackfillJob},
+    rpc_server::{RpcCalls, RpcParams},
+    type_aliases::{SharedBackfillJobs, WebSocketTx},
+};
+
+impl RpcCalls {
+    pub(crate) async fn handle_backfill(
+        config: SentinelConfig,
+        websocket_tx: WebSocketTx,
+        backfill_jobs: SharedBackfillJobs,
+        params: RpcParams,
+        core_cxn: bool,
+    ) -> Result<BackfillJob, SentinelError> {
+        debug!("handling backfill...");
+        Self::check_core_is_connected(core_cxn)?;
+        let checked_params = Self::check_params(params, 3)?;
+        let network_id = NetworkId::try_from(&checked_params[0])?;
+        let from = checked_params[1].parse::<u64>()?;
+        let to = checked_params[2].parse::<u64>()?;
+        start_backfill(config, websocket_tx, backfill_jobs, network_id, from, to).await
+    }
+}
@@ -0,0 +1,25 @@
+use common_sentinel::{BroadcastChannelMessages, ChallengerBroadcastChannelMessages, SentinelError};
+use serde_json::{json, Value as Json};
+
+use crate::{rpc_server::RpcCalls, type_aliases::BroadcastChannelTx};
+
+impl RpcCalls {
+    pub(crate) async fn handle_challenger_start_stop(
+        broadcast_channel_tx: BroadcastChannelTx,
+        start: bool,
+    ) -> Result<Json, SentinelError> {
+        debug!("handling challenger start/stop...");
+        let json = json!({"status": format!("{} message sent to challenger via broadcast channel", if start { "start" } else { "stop" })});
+
+        let m = if start {
+            ChallengerBroadcastChannelMessages::Start
+        } else {
+            ChallengerBroadcastChannelMessages::Stop
+        };
+
+        // NOTE: As with the other components, the challenger's broadcast channel loop runs
+        // regardless of whether the module is turned on or off.
+        broadcast_channel_tx.send(BroadcastChannelMessages::Challenger(m))?;
+        Ok(json)
+    }
+}
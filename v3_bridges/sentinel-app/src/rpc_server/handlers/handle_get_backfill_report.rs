@@ -0,0 +1,25 @@
+use common_network_ids::NetworkId;
+use common_sentinel::SentinelError;
+
+use crate::{
+    backfill::BackfillJob,
+    rpc_server::{RpcCalls, RpcParams},
+    type_aliases::SharedBackfillJobs,
+};
+
+impl RpcCalls {
+    pub(crate) async fn handle_get_backfill_report(
+        backfill_jobs: SharedBackfillJobs,
+        params: RpcParams,
+    ) -> Result<BackfillJob, SentinelError> {
+        debug!("handling get backfill report...");
+        let checked_params = Self::check_params(params, 1)?;
+        let network_id = NetworkId::try_from(&checked_params[0])?;
+        backfill_jobs
+            .lock()
+            .await
+            .get(&network_id)
+            .cloned()
+            .ok_or_else(|| SentinelError::Custom(format!("no backfill has been run for {network_id}")))
+    }
+}
@@ -0,0 +1,22 @@
+use common_sentinel::{Env, EthRpcSenders, SentinelConfig, SentinelError};
+use serde_json::{json, Value as Json};
+
+use crate::{balance_monitor::get_balance_runways, rpc_server::RpcCalls};
+
+impl RpcCalls {
+    pub(crate) async fn handle_get_balance_runways(
+        config: SentinelConfig,
+        eth_rpc_senders: EthRpcSenders,
+    ) -> Result<Json, SentinelError> {
+        debug!("handling get balance runways...");
+        Env::init()?;
+        let address = Env::get_private_key()?.to_address();
+        let (runways, warnings) = get_balance_runways(&config, &eth_rpc_senders, &address).await;
+        Ok(json!({
+            "broadcaster": format!("0x{}", hex::encode(address)),
+            "runways": runways,
+            "low": runways.low(),
+            "warnings": warnings,
+        }))
+    }
+}
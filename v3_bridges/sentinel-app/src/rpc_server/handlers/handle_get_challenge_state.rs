@@ -31,8 +31,8 @@ impl RpcCalls {
 
         // NOTE We're still stuck with host and native for now, so we need to figure out which of
         // those this challenge originated.
-        let (msg, rx) =
-            EthRpcMessages::get_challenge_state_msg(*network_id, challenge, config.pnetwork_hub(network_id)?);
+        let hub = config.pnetwork_hub_or_current(network_id, *challenge.pnetwork_hub())?;
+        let (msg, rx) = EthRpcMessages::get_challenge_state_msg(*network_id, challenge, hub);
 
         let sender = eth_rpc_senders.sender(network_id)?;
         sender.send(msg).await?;
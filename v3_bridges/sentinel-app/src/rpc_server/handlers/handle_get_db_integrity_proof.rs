@@ -0,0 +1,31 @@
+use common::strip_hex_prefix;
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+impl RpcCalls {
+    pub(crate) async fn handle_get_db_integrity_proof(
+        websocket_tx: WebSocketTx,
+        params: RpcParams,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        debug!("handling get db integrity proof rpc call...");
+        Self::check_core_is_connected(core_cxn)?;
+        let checked_params = Self::check_params(params, 1)?;
+
+        let key = hex::decode(strip_hex_prefix(&checked_params[0])).map_err(|e| {
+            error!("{e}");
+            SentinelError::Custom(format!("invalid hex: '{}'", checked_params[0]))
+        })?;
+
+        call_core(
+            STRONGBOX_TIMEOUT,
+            websocket_tx.clone(),
+            WebSocketMessagesEncodable::GetDbIntegrityProof(key),
+        )
+        .await
+    }
+}
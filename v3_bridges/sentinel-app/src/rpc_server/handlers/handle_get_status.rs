@@ -1,5 +1,5 @@
 use common_network_ids::NetworkId;
-use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
+use common_sentinel::{call_core, BalanceRunways, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
 
 use crate::{
     rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
@@ -28,7 +28,8 @@ impl RpcCalls {
         call_core(
             STRONGBOX_TIMEOUT,
             websocket_tx.clone(),
-            WebSocketMessagesEncodable::GetStatus(network_ids),
+            // NOTE: Balance warnings are only included in the statuses the status publisher publishes.
+            WebSocketMessagesEncodable::GetStatus(network_ids, BalanceRunways::default()),
         )
         .await
     }
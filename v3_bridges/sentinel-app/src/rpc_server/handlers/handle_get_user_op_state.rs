@@ -36,9 +36,13 @@ impl RpcCalls {
         let o_id = *user_op.origin_network_id();
         let d_id = user_op.destination_network_id();
 
-        let (o_msg, o_rx) = EthRpcMessages::get_user_op_state_msg(o_id, user_op.clone(), config.pnetwork_hub(&o_id)?);
+        // NOTE: Query the hubs which emitted the op's events, in case they've since been superseded.
+        let o_hub = config.pnetwork_hub_or_current(&o_id, user_op.pnetwork_hub_on(&o_id))?;
+        let d_hub = config.pnetwork_hub_or_current(&d_id, user_op.pnetwork_hub_on(&d_id))?;
 
-        let (d_msg, d_rx) = EthRpcMessages::get_user_op_state_msg(d_id, user_op, config.pnetwork_hub(&d_id)?);
+        let (o_msg, o_rx) = EthRpcMessages::get_user_op_state_msg(o_id, user_op.clone(), o_hub);
+
+        let (d_msg, d_rx) = EthRpcMessages::get_user_op_state_msg(d_id, user_op, d_hub);
 
         eth_rpc_senders.sender(&o_id)?.send(o_msg).await?;
         eth_rpc_senders.sender(&d_id)?.send(d_msg).await?;
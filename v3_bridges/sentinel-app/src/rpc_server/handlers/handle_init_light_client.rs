@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use common_network_ids::NetworkId;
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
+use ethereum_types::H256 as EthHash;
+
+use crate::{
+    rpc_server::{type_aliases::RpcParams, RpcCalls, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+impl RpcCalls {
+    pub(crate) async fn handle_init_light_client(
+        websocket_tx: WebSocketTx,
+        params: RpcParams,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+        // NOTE: Params are the network ID, the `LightClientBootstrap` json (as returned by a beacon
+        // node's `light_client/bootstrap` endpoint), the trusted block root it is for & a signature.
+        let checked_params = Self::check_params(params, 3)?;
+        let network_id = NetworkId::try_from(&checked_params[0])?;
+        let bootstrap_json = checked_params[1].clone();
+        let trusted_block_root = EthHash::from_str(&checked_params[2])?;
+        call_core(
+            STRONGBOX_TIMEOUT,
+            websocket_tx.clone(),
+            WebSocketMessagesEncodable::InitLightClient(
+                network_id,
+                bootstrap_json,
+                trusted_block_root,
+                checked_params.get(3).into(),
+            ),
+        )
+        .await
+    }
+}
@@ -50,9 +50,10 @@ impl RpcCalls {
                     dry_run,
                     reprocess,
                     network_id,
-                    config.pnetwork_hub(&network_id)?,
+                    config.pnetwork_hubs(&network_id)?,
                     batch,
                     config.governance_address(&network_id),
+                    None, // NOTE: Challenges started by this sentinel are tracked by the syncer
                 );
                 let msg = WebSocketMessagesEncodable::ProcessBatch(Box::new(submit_args));
                 call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
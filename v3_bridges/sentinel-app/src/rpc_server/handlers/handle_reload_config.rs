@@ -0,0 +1,13 @@
+use common_sentinel::{ConfigWatcherMessages, SentinelError};
+use serde_json::Value as Json;
+
+use crate::{rpc_server::RpcCalls, type_aliases::ConfigWatcherTx};
+
+impl RpcCalls {
+    pub(crate) async fn handle_reload_config(tx: ConfigWatcherTx) -> Result<Json, SentinelError> {
+        debug!("handling reload config rpc call...");
+        let (msg, rx) = ConfigWatcherMessages::get_reload_msg();
+        tx.send(msg).await?;
+        rx.await?
+    }
+}
@@ -1,11 +1,16 @@
 use std::{process::Command, str::from_utf8};
 
 use common::strip_hex_prefix;
-use common_eth::{EthPrivateKey, EthSigningCapabilities};
-use common_sentinel::SentinelError;
+use common_chain_ids::EthChainId;
+use common_debug_signers::DebugSignature;
+use common_eth::{check_eip_712_domain, hash_eip_712_typed_data, EthPrivateKey, EthSigningCapabilities};
+use common_sentinel::{call_core, SentinelConfig, SentinelError, WebSocketMessagesEncodable};
 use serde_json::{json, Value as Json};
 
-use crate::rpc_server::{RpcCalls, RpcParams};
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
 
 fn strip_new_lines_from_str(string: String) -> String {
     string.replace('\n', "")
@@ -27,6 +32,24 @@ fn decrypt_pk(path: &str) -> Result<String, SentinelError> {
     }
 }
 
+/// Checks the typed data's EIP-712 domain is for a network in the config, & that its verifying
+/// contract is one of that network's pNetwork hubs or its governance contract.
+fn check_typed_data_domain_is_allowed(config: &SentinelConfig, typed_data: &Json) -> Result<(), SentinelError> {
+    for nid in config.network_ids() {
+        let chain_id = EthChainId::try_from(&nid)?.to_u64();
+        let mut allowed_contracts = config.pnetwork_hubs(&nid)?.addresses();
+        if let Some(a) = config.governance_address(&nid) {
+            allowed_contracts.push(a);
+        };
+        if check_eip_712_domain(typed_data, chain_id, &allowed_contracts).is_ok() {
+            return Ok(());
+        };
+    }
+    Err(SentinelError::Custom(
+        "typed data domain is not for any pNetwork hub or governance contract in the config".into(),
+    ))
+}
+
 fn decode_hex(s: &str) -> Result<Vec<u8>, SentinelError> {
     hex::decode(s).map_err(|e| SentinelError::Custom(format!("invalid hex error: {}", e)))
 }
@@ -47,4 +70,39 @@ impl RpcCalls {
             "signature": signature.to_string(),
         }))
     }
+
+    /// Allows signing of passed in EIP-712 typed data using the gpg-encrypted private key at the
+    /// passed in path. The typed data's domain must be for one of the pNetwork hubs or the
+    /// governance contract in the config, & the passed in debug signature over the typed data's
+    /// hash must be valid per the core's debug signers. NOTE: This function makes a linux command
+    /// call, thus only works on linux.
+    pub(crate) async fn handle_sign_typed_data(
+        config: SentinelConfig,
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<Json, SentinelError> {
+        debug!("handling sign typed data...");
+        Self::check_core_is_connected(core_cxn)?;
+        let checked_params = Self::check_params(params, 2)?;
+        let typed_data: Json = serde_json::from_str(&checked_params[1])?;
+        check_typed_data_domain_is_allowed(&config, &typed_data)?;
+        let hash = hash_eip_712_typed_data(&typed_data)?;
+
+        let debug_sig = DebugSignature::new(checked_params.get(2).cloned());
+        let msg = WebSocketMessagesEncodable::AuthorizeSignTypedData(hash, debug_sig);
+        if let WebSocketMessagesEncodable::Error(e) = call_core(STRONGBOX_TIMEOUT, websocket_tx, msg).await? {
+            return Err(e.into());
+        };
+
+        let pk = EthPrivateKey::from_slice(&decode_hex(&decrypt_pk(&checked_params[0])?)?)?;
+        let signer = format!("0x{}", hex::encode(pk.to_address().as_bytes()));
+        let signature = pk.sign_hash_and_set_eth_recovery_param(hash)?;
+        Ok(json!({
+            "signer": signer,
+            "msg": typed_data,
+            "hash": format!("0x{}", hex::encode(hash.as_bytes())),
+            "signature": signature.to_string(),
+        }))
+    }
 }
@@ -1,30 +1,39 @@
 mod handle_add_debug_signers;
+mod handle_backfill;
 mod handle_cancel_user_ops;
 mod handle_challenge_responder_start_stop;
+mod handle_challenger_start_stop;
 mod handle_db_ops;
 mod handle_get_attestation_certificate;
 mod handle_get_attestation_signature;
+mod handle_get_backfill_report;
+mod handle_get_balance_runways;
 mod handle_get_balances;
 mod handle_get_cancellable_user_ops;
 mod handle_get_challenge;
 mod handle_get_challenge_state;
 mod handle_get_challenges_list;
 mod handle_get_core_state;
+mod handle_get_db_integrity_proof;
 mod handle_get_inclusion_proof;
 mod handle_get_registration_extension_tx;
 mod handle_get_registration_signature;
+mod handle_get_started_challenges;
 mod handle_get_status;
 mod handle_get_unsolved_challenges;
 mod handle_get_user_op;
 mod handle_get_user_op_by_tx_hash;
 mod handle_get_user_op_list;
+mod handle_get_user_op_route;
 mod handle_get_user_op_state;
 mod handle_get_user_ops;
 mod handle_hard_reset;
 mod handle_init;
+mod handle_init_light_client;
 mod handle_latest_block_infos;
 mod handle_process_block;
 mod handle_purge_user_ops;
+mod handle_reload_config;
 mod handle_remove_challenge;
 mod handle_remove_debug_signer;
 mod handle_remove_user_op;
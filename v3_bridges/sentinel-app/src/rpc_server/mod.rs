@@ -1,6 +1,7 @@
 mod constants;
 mod handlers;
 mod json_rpc_request;
+mod notifications_subscriber;
 mod rpc_calls;
 mod rpc_server_loop;
 mod type_aliases;
@@ -9,6 +10,7 @@ pub(crate) use self::rpc_server_loop::rpc_server_loop;
 use self::{
     constants::STRONGBOX_TIMEOUT,
     json_rpc_request::JsonRpcRequest,
+    notifications_subscriber::forward_notifications_to_subscriber,
     rpc_calls::RpcCalls,
     type_aliases::RpcParams,
 };
@@ -0,0 +1,39 @@
+use futures::{SinkExt, StreamExt};
+use tokio::sync::broadcast::error::RecvError;
+use warp::ws::{Message, WebSocket};
+
+use crate::type_aliases::NotificationsRx;
+
+pub(super) async fn forward_notifications_to_subscriber(socket: WebSocket, mut notifications_rx: NotificationsRx) {
+    // NOTE: Subscribers only receive notifications, anything they send us other than a close is
+    // ignored.
+    info!("new notifications subscriber connected");
+    let (mut ws_tx, mut ws_rx) = socket.split();
+
+    'notifications_subscriber_loop: loop {
+        tokio::select! {
+            r = notifications_rx.recv() => match r {
+                Ok(notification) => match notification.to_json_string() {
+                    Ok(s) => {
+                        if let Err(e) = ws_tx.send(Message::text(s)).await {
+                            warn!("error sending notification to subscriber: {e}");
+                            break 'notifications_subscriber_loop
+                        }
+                    },
+                    Err(e) => error!("error serializing {notification}: {e}"),
+                },
+                Err(RecvError::Lagged(n)) => {
+                    warn!("notifications subscriber lagged, skipping {n} notifications");
+                    continue 'notifications_subscriber_loop
+                },
+                Err(RecvError::Closed) => break 'notifications_subscriber_loop,
+            },
+            m = ws_rx.next() => match m {
+                Some(Ok(msg)) if !msg.is_close() => continue 'notifications_subscriber_loop,
+                _ => break 'notifications_subscriber_loop,
+            },
+        }
+    }
+
+    info!("notifications subscriber disconnected");
+}
@@ -17,7 +17,9 @@ use super::{
 use crate::type_aliases::{
     BroadcastChannelTx,
     ChallengeResponderTx,
+    ConfigWatcherTx,
     CoreCxnStatus,
+    SharedBackfillJobs,
     StatusPublisherTx,
     UserOpCancellerTx,
     WebSocketTx,
@@ -51,8 +53,10 @@ fn create_json_rpc_response_from_result<T: Serialize>(id: RpcId, r: Result<T, Se
 
 pub(crate) enum RpcCalls {
     Ping(RpcId),
+    ReloadConfig(RpcId, ConfigWatcherTx),
     Unknown(RpcId, String),
     SignMessage(RpcId, RpcParams),
+    SignTypedData(RpcId, Box<SentinelConfig>, RpcParams, WebSocketTx, CoreCxnStatus),
     GetUserOps(RpcId, WebSocketTx, CoreCxnStatus),
     GetUserOpList(RpcId, WebSocketTx, CoreCxnStatus),
     Get(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
@@ -72,16 +76,22 @@ pub(crate) enum RpcCalls {
     GetCoreState(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     GetAttestionCertificate(RpcId, WebSocketTx, CoreCxnStatus),
     ChallengeResponderStartStop(RpcId, BroadcastChannelTx, bool),
+    ChallengerStartStop(RpcId, BroadcastChannelTx, bool),
+    GetStartedChallenges(RpcId, WebSocketTx, CoreCxnStatus),
     AddDebugSigners(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     RemoveChallenge(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
+    InitLightClient(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
     GetUserOpByTxHash(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    GetUserOpRoute(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     StopSyncer(RpcId, BroadcastChannelTx, RpcParams, CoreCxnStatus),
     RemoveDebugSigner(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     StartSyncer(RpcId, BroadcastChannelTx, RpcParams, CoreCxnStatus),
     SetUserOpCancellerFrequency(RpcId, RpcParams, UserOpCancellerTx),
     GetBalances(RpcId, Box<SentinelConfig>, RpcParams, EthRpcSenders),
+    GetBalanceRunways(RpcId, Box<SentinelConfig>, EthRpcSenders),
     SetStatusPublishingFrequency(RpcId, RpcParams, StatusPublisherTx),
     GetAttestionSignature(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    GetDbIntegrityProof(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     SetChallengeResponderFrequency(RpcId, RpcParams, ChallengeResponderTx),
     GetRegistrationSignature(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
     UserOpCancellerStartStop(RpcId, BroadcastChannelTx, CoreCxnStatus, bool),
@@ -122,6 +132,15 @@ pub(crate) enum RpcCalls {
         CoreCxnStatus,
     ),
     ProcessBlock(RpcId, Box<SentinelConfig>, EthRpcSenders, WebSocketTx, RpcParams, bool),
+    GetBackfillReport(RpcId, SharedBackfillJobs, RpcParams),
+    Backfill(
+        RpcId,
+        Box<SentinelConfig>,
+        WebSocketTx,
+        SharedBackfillJobs,
+        RpcParams,
+        CoreCxnStatus,
+    ),
 }
 
 impl RpcCalls {
@@ -135,12 +154,18 @@ impl RpcCalls {
         status_tx: StatusPublisherTx,
         challenge_responder_tx: ChallengeResponderTx,
         core_cxn: bool,
+        config_watcher_tx: ConfigWatcherTx,
+        backfill_jobs: SharedBackfillJobs,
     ) -> Self {
         match r.method().as_ref() {
             "ping" => Self::Ping(*r.id()),
+            "reloadConfig" => Self::ReloadConfig(*r.id(), config_watcher_tx),
             "get" => Self::Get(*r.id(), websocket_tx, r.params(), core_cxn),
             "put" => Self::Put(*r.id(), websocket_tx, r.params(), core_cxn),
             "signMessage" | "sign" => Self::SignMessage(*r.id(), r.params()),
+            "signTypedData" => {
+                Self::SignTypedData(*r.id(), Box::new(config.clone()), r.params(), websocket_tx, core_cxn)
+            },
             "getUserOps" => Self::GetUserOps(*r.id(), websocket_tx, core_cxn),
             "delete" => Self::Delete(*r.id(), websocket_tx, r.params(), core_cxn),
             "getUserOpList" => Self::GetUserOpList(*r.id(), websocket_tx, core_cxn),
@@ -155,16 +180,23 @@ impl RpcCalls {
             "startSyncer" => Self::StartSyncer(*r.id(), broadcast_channel_tx, r.params(), core_cxn),
             "getChallangeResponses" => Self::GetUnsolvedChallenges(*r.id(), websocket_tx, core_cxn),
             "getBalances" => Self::GetBalances(*r.id(), Box::new(config), r.params(), eth_rpc_senders),
+            "getBalanceRunways" | "getRunways" => Self::GetBalanceRunways(*r.id(), Box::new(config), eth_rpc_senders),
             "getUserOpByTxHash" => Self::GetUserOpByTxHash(*r.id(), r.params(), websocket_tx, core_cxn),
+            "getUserOpRoute" => Self::GetUserOpRoute(*r.id(), r.params(), websocket_tx, core_cxn),
             "removeDebugSigner" => Self::RemoveDebugSigner(*r.id(), r.params(), websocket_tx, core_cxn),
             "getAttestationCertificate" => Self::GetAttestionCertificate(*r.id(), websocket_tx, core_cxn),
             "cancel" | "cancelUserOps" => Self::CancelUserOps(*r.id(), user_op_canceller_tx.clone(), core_cxn),
             "startChallengeResponder" => Self::ChallengeResponderStartStop(*r.id(), broadcast_channel_tx, true),
             "stopChallengeResponder" => Self::ChallengeResponderStartStop(*r.id(), broadcast_channel_tx, false),
+            "startChallenger" => Self::ChallengerStartStop(*r.id(), broadcast_channel_tx, true),
+            "stopChallenger" => Self::ChallengerStartStop(*r.id(), broadcast_channel_tx, false),
+            "getStartedChallenges" => Self::GetStartedChallenges(*r.id(), websocket_tx, core_cxn),
             "getChallengesList" | "getChallengeList" => Self::GetChallengesList(*r.id(), websocket_tx, core_cxn),
             "setStatusPublishingFrequency" => Self::SetStatusPublishingFrequency(*r.id(), r.params(), status_tx),
             "getAttestationSignature" => Self::GetAttestionSignature(*r.id(), r.params(), websocket_tx, core_cxn),
+            "getDbIntegrityProof" => Self::GetDbIntegrityProof(*r.id(), r.params(), websocket_tx, core_cxn),
             "removeChallenge" | "rmChallenge" => Self::RemoveChallenge(*r.id(), websocket_tx, r.params(), core_cxn),
+            "initLightClient" => Self::InitLightClient(*r.id(), websocket_tx, r.params(), core_cxn),
             "addDebugSigners" | "addDebugSigner" => Self::AddDebugSigners(*r.id(), r.params(), websocket_tx, core_cxn),
             "getRegistrationExtensionTx" => {
                 Self::GetRegistrationExtensionTx(*r.id(), Box::new(config.clone()), r.params(), eth_rpc_senders.clone())
@@ -232,6 +264,15 @@ impl RpcCalls {
                 r.params(),
                 core_cxn,
             ),
+            "getBackfillReport" => Self::GetBackfillReport(*r.id(), backfill_jobs, r.params()),
+            "backfill" => Self::Backfill(
+                *r.id(),
+                Box::new(config),
+                websocket_tx,
+                backfill_jobs,
+                r.params(),
+                core_cxn,
+            ),
             "processBlock" | "process" | "submitBlock" | "submit" => Self::ProcessBlock(
                 *r.id(),
                 Box::new(config),
@@ -273,6 +314,11 @@ impl RpcCalls {
 
     pub(super) async fn handle(self) -> Result<impl warp::Reply, Rejection> {
         match self {
+            Self::ReloadConfig(id, config_watcher_tx) => {
+                let result = Self::handle_reload_config(config_watcher_tx).await;
+                let json = create_json_rpc_response_from_result(id, result, 1337);
+                Ok(warp::reply::json(&json))
+            },
             Self::SetStatusPublishingFrequency(id, status_tx, params) => {
                 let result = Self::handle_set_status_publishing_frequency(status_tx, params).await;
                 let json = create_json_rpc_response_from_result(id, result, 1337);
@@ -346,11 +392,21 @@ impl RpcCalls {
                 let json = create_json_rpc_response_from_result(id, result, 1337);
                 Ok(warp::reply::json(&json))
             },
+            Self::SignTypedData(id, config, params, websocket_tx, core_cxn) => {
+                let result = Self::handle_sign_typed_data(*config, params, websocket_tx, core_cxn).await;
+                let json = create_json_rpc_response_from_result(id, result, 1337);
+                Ok(warp::reply::json(&json))
+            },
             Self::ChallengeResponderStartStop(id, broadcast_channel_tx, start) => {
                 let result = Self::handle_challenge_responder_start_stop(broadcast_channel_tx, start).await;
                 let json = create_json_rpc_response_from_result(id, result, 1337);
                 Ok(warp::reply::json(&json))
             },
+            Self::ChallengerStartStop(id, broadcast_channel_tx, start) => {
+                let result = Self::handle_challenger_start_stop(broadcast_channel_tx, start).await;
+                let json = create_json_rpc_response_from_result(id, result, 1337);
+                Ok(warp::reply::json(&json))
+            },
             Self::StatusPublisherStartStop(id, broadcast_channel_tx, start_status_publisher) => {
                 let result =
                     Self::handle_status_publisher_start_stop(broadcast_channel_tx, start_status_publisher).await;
@@ -377,6 +433,11 @@ impl RpcCalls {
                 let json = create_json_rpc_response_from_result(id, result, 1337);
                 Ok(warp::reply::json(&json))
             },
+            Self::GetBalanceRunways(id, config, eth_rpc_senders) => {
+                let result = Self::handle_get_balance_runways(*config, eth_rpc_senders).await;
+                let json = create_json_rpc_response_from_result(id, result, 1337);
+                Ok(warp::reply::json(&json))
+            },
             Self::GetUnsolvedChallenges(id, websocket_tx, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_get_unsolved_challenges(websocket_tx, core_cxn).await)
             },
@@ -406,6 +467,16 @@ impl RpcCalls {
                 id,
                 Self::handle_process_block(*config, eth_rpc_senders, websocket_tx, params, core_cxn).await,
             ),
+            Self::Backfill(id, config, websocket_tx, backfill_jobs, params, core_cxn) => {
+                let result = Self::handle_backfill(*config, websocket_tx, backfill_jobs, params, core_cxn).await;
+                let json = create_json_rpc_response_from_result(id, result, 1337);
+                Ok(warp::reply::json(&json))
+            },
+            Self::GetBackfillReport(id, backfill_jobs, params) => {
+                let result = Self::handle_get_backfill_report(backfill_jobs, params).await;
+                let json = create_json_rpc_response_from_result(id, result, 1337);
+                Ok(warp::reply::json(&json))
+            },
             Self::Ping(id) => Ok(warp::reply::json(&create_json_rpc_response(id, "pong"))),
             Self::Init(id, config, eth_rpc_senders, websocket_tx, params, core_cxn) => Self::handle_ws_result(
                 id,
@@ -425,6 +496,10 @@ impl RpcCalls {
                 id,
                 Self::handle_get_attestation_signature(websocket_tx, params, core_cxn).await,
             ),
+            Self::GetDbIntegrityProof(id, params, websocket_tx, core_cxn) => Self::handle_ws_result(
+                id,
+                Self::handle_get_db_integrity_proof(websocket_tx, params, core_cxn).await,
+            ),
             Self::GetUserOp(id, params, websocket_tx, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_get_user_op(params, websocket_tx, core_cxn).await)
             },
@@ -440,10 +515,19 @@ impl RpcCalls {
             Self::RemoveChallenge(id, websocket_tx, params, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_remove_challenge(websocket_tx, params, core_cxn).await)
             },
+            Self::InitLightClient(id, websocket_tx, params, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_init_light_client(websocket_tx, params, core_cxn).await)
+            },
             Self::GetUserOpByTxHash(id, params, websocket_tx, core_cxn) => Self::handle_ws_result(
                 id,
                 Self::handle_get_user_op_by_tx_hash(params, websocket_tx, core_cxn).await,
             ),
+            Self::GetStartedChallenges(id, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_get_started_challenges(websocket_tx, core_cxn).await)
+            },
+            Self::GetUserOpRoute(id, params, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_get_user_op_route(params, websocket_tx, core_cxn).await)
+            },
             Self::GetInclusionProof(id, websocket_tx, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_get_inclusion_proof(websocket_tx, core_cxn).await)
             },
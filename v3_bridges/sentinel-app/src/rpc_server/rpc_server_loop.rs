@@ -7,11 +7,14 @@ use common_sentinel::{
 };
 use warp::Filter;
 
-use super::{JsonRpcRequest, RpcCalls};
+use super::{forward_notifications_to_subscriber, JsonRpcRequest, RpcCalls};
 use crate::type_aliases::{
     BroadcastChannelRx,
     BroadcastChannelTx,
     ChallengeResponderTx,
+    ConfigWatcherTx,
+    NotificationsTx,
+    SharedBackfillJobs,
     StatusPublisherTx,
     UserOpCancellerTx,
     WebSocketTx,
@@ -26,6 +29,9 @@ async fn start_rpc_server(
     user_op_canceller_tx: UserOpCancellerTx,
     status_tx: StatusPublisherTx,
     challenge_responder_tx: ChallengeResponderTx,
+    notifications_tx: NotificationsTx,
+    config_watcher_tx: ConfigWatcherTx,
+    backfill_jobs: SharedBackfillJobs,
 ) -> Result<(), SentinelError> {
     debug!("rpc server listening!");
     let core_cxn_filter = warp::any().map(move || core_cxn);
@@ -35,6 +41,8 @@ async fn start_rpc_server(
     let broadcaster_tx_filter = warp::any().map(move || user_op_canceller_tx.clone());
     let broadcast_channel_tx_filter = warp::any().map(move || broadcast_channel_tx.clone());
     let challenge_responder_tx_filter = warp::any().map(move || challenge_responder_tx.clone());
+    let config_watcher_tx_filter = warp::any().map(move || config_watcher_tx.clone());
+    let backfill_jobs_filter = warp::any().map(move || backfill_jobs.clone());
 
     let rpc = warp::path("v1")
         .and(warp::path("rpc"))
@@ -50,10 +58,21 @@ async fn start_rpc_server(
         .and(status_tx_filter.clone())
         .and(challenge_responder_tx_filter.clone())
         .and(core_cxn_filter)
+        .and(config_watcher_tx_filter)
+        .and(backfill_jobs_filter)
         .map(RpcCalls::new)
         .and_then(|r: RpcCalls| async move { r.handle().await });
 
-    warp::serve(rpc).run(([127, 0, 0, 1], 3030)).await; // FIXME make configurable
+    let notifications = warp::path("v1")
+        .and(warp::path("notifications"))
+        .and(warp::path::end())
+        .and(warp::ws())
+        .and(warp::any().map(move || notifications_tx.subscribe()))
+        .map(|ws: warp::ws::Ws, notifications_rx| {
+            ws.on_upgrade(move |socket| forward_notifications_to_subscriber(socket, notifications_rx))
+        });
+
+    warp::serve(rpc.or(notifications)).run(([127, 0, 0, 1], 3030)).await; // FIXME make configurable
 
     Ok(())
 }
@@ -77,16 +96,20 @@ async fn broadcast_channel_loop(
 pub async fn rpc_server_loop(
     eth_rpc_senders: EthRpcSenders,
     websocket_tx: WebSocketTx,
-    config: SentinelConfig,
+    mut config: SentinelConfig,
     broadcast_channel_tx: BroadcastChannelTx,
     user_op_canceller_tx: UserOpCancellerTx,
     status_tx: StatusPublisherTx,
     challenge_responder_tx: ChallengeResponderTx,
+    notifications_tx: NotificationsTx,
+    config_watcher_tx: ConfigWatcherTx,
 ) -> Result<(), SentinelError> {
     let name = "rpc server";
 
     let rpc_server_is_enabled = true; // FIXME rm
     let mut core_connection_status = false;
+    // NOTE: Created outside the loop so backfill jobs outlive any restarts of the rpc server.
+    let backfill_jobs = SharedBackfillJobs::default();
 
     'rpc_server_loop: loop {
         tokio::select! {
@@ -100,6 +123,10 @@ pub async fn rpc_server_loop(
                         core_connection_status = false;
                         continue 'rpc_server_loop
                     },
+                    Ok(RpcServerBroadcastChannelMessages::ConfigUpdated(new_config)) => {
+                        config = *new_config;
+                        continue 'rpc_server_loop
+                    },
                     Err(e) => break 'rpc_server_loop Err(e),
                 }
             },
@@ -112,6 +139,9 @@ pub async fn rpc_server_loop(
                 user_op_canceller_tx.clone(),
                 status_tx.clone(),
                 challenge_responder_tx.clone(),
+                notifications_tx.clone(),
+                config_watcher_tx.clone(),
+                backfill_jobs.clone(),
             ), if rpc_server_is_enabled => {
                 if r.is_ok() {
                     warn!("{name} returned, restarting {name} now...");
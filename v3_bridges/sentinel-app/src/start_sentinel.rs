@@ -4,8 +4,11 @@ use common_sentinel::{
     flatten_join_handle,
     Batch,
     ChallengeResponderMessages,
+    ChallengerMessages,
+    ConfigWatcherMessages,
     EthRpcChannels,
     EthRpcSenders,
+    NotifierMessages,
     SentinelConfig,
     SentinelError,
     StatusPublisherMessages,
@@ -21,8 +24,12 @@ use tokio::sync::{
 };
 
 use crate::{
+    balance_monitor::balance_monitor_loop,
     challenge_responder::challenge_responder_loop,
+    challenger::challenger_loop,
+    config_watcher::config_watcher_loop,
     eth_rpc::eth_rpc_loop,
+    notifier::notifier_loop,
     rpc_server::rpc_server_loop,
     status_publisher::status_publisher_loop,
     syncer::syncer,
@@ -32,7 +39,11 @@ use crate::{
 
 const MAX_CHANNEL_CAPACITY: usize = 1337;
 
-pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<String, SentinelError> {
+pub async fn start_sentinel(
+    config: &SentinelConfig,
+    config_path: &str,
+    disable: bool,
+) -> Result<String, SentinelError> {
     let network_ids = config.network_ids();
     let eth_rpc_channels = EthRpcChannels::from(network_ids);
 
@@ -41,11 +52,23 @@ pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<St
         MpscRx<ChallengeResponderMessages>,
     ) = mpsc::channel(MAX_CHANNEL_CAPACITY);
 
+    let (challenger_tx, challenger_rx): (MpscTx<ChallengerMessages>, MpscRx<ChallengerMessages>) =
+        mpsc::channel(MAX_CHANNEL_CAPACITY);
+
     let (status_tx, status_rx): (MpscTx<StatusPublisherMessages>, MpscRx<StatusPublisherMessages>) =
         mpsc::channel(MAX_CHANNEL_CAPACITY);
 
     let (broadcast_channel_tx, _) = broadcast::channel(MAX_CHANNEL_CAPACITY);
 
+    let (notifier_tx, notifier_rx): (MpscTx<NotifierMessages>, MpscRx<NotifierMessages>) =
+        mpsc::channel(MAX_CHANNEL_CAPACITY);
+
+    // NOTE: Each websocket subscriber to the rpc server's notifications endpoint subscribes to this.
+    let (notifications_tx, _) = broadcast::channel(MAX_CHANNEL_CAPACITY);
+
+    let (config_watcher_tx, config_watcher_rx): (MpscTx<ConfigWatcherMessages>, MpscRx<ConfigWatcherMessages>) =
+        mpsc::channel(MAX_CHANNEL_CAPACITY);
+
     let (websocket_tx, websocket_rx): (MpscTx<WebSocketMessages>, MpscRx<WebSocketMessages>) =
         mpsc::channel(MAX_CHANNEL_CAPACITY);
 
@@ -73,6 +96,16 @@ pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<St
         disable,
     ));
 
+    let challenger_thread = tokio::spawn(challenger_loop(
+        config.clone(),
+        challenger_rx,
+        challenger_tx.clone(),
+        broadcast_channel_tx.clone(),
+        websocket_tx.clone(),
+        EthRpcSenders::from(&eth_rpc_channels),
+        disable,
+    ));
+
     let user_op_canceller_thread = tokio::spawn(user_op_canceller_loop(
         user_op_canceller_rx,
         EthRpcSenders::from(&eth_rpc_channels),
@@ -80,9 +113,19 @@ pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<St
         broadcast_channel_tx.clone(),
         websocket_tx.clone(),
         user_op_canceller_tx.clone(),
+        notifier_tx.clone(),
         disable,
     ));
 
+    let notifier_thread = tokio::spawn(notifier_loop(
+        config.clone(),
+        notifier_rx,
+        notifier_tx.clone(),
+        notifications_tx.clone(),
+        broadcast_channel_tx.clone(),
+        websocket_tx.clone(),
+    ));
+
     let rpc_server_thread = tokio::spawn(rpc_server_loop(
         EthRpcSenders::from(&eth_rpc_channels),
         websocket_tx.clone(),
@@ -91,6 +134,22 @@ pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<St
         user_op_canceller_tx.clone(),
         status_tx.clone(),
         challenge_responder_tx.clone(),
+        notifications_tx.clone(),
+        config_watcher_tx.clone(),
+    ));
+
+    let balance_monitor_thread = tokio::spawn(balance_monitor_loop(
+        config.clone(),
+        EthRpcSenders::from(&eth_rpc_channels),
+        broadcast_channel_tx.clone(),
+        notifier_tx.clone(),
+    ));
+
+    let config_watcher_thread = tokio::spawn(config_watcher_loop(
+        config_path.to_string(),
+        config.clone(),
+        config_watcher_rx,
+        broadcast_channel_tx.clone(),
     ));
 
     let ws_server_thread = tokio::spawn(ws_server_loop(
@@ -113,6 +172,7 @@ pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<St
                 EthRpcSenders::from(&eth_rpc_channels),
                 websocket_tx.clone(),
                 broadcast_channel_tx.clone(),
+                notifier_tx.clone(),
                 disable,
             )))
         })
@@ -143,6 +203,10 @@ pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<St
         user_op_canceller_thread,
         status_thread,
         challenge_responder_thread,
+        challenger_thread,
+        notifier_thread,
+        config_watcher_thread,
+        balance_monitor_thread,
     ];
     threads.append(&mut other_threads);
 
@@ -3,6 +3,7 @@ use common_sentinel::{
     call_core,
     check_ipfs_daemon_is_running,
     publish_status as publish_status_via_ipfs,
+    BalanceRunways,
     BroadcastChannelMessages,
     SentinelConfig,
     SentinelError,
@@ -23,11 +24,12 @@ async fn publish_status(
     websocket_tx: WebSocketTx,
     core_timeout: &u64,
     network_ids: Vec<NetworkId>,
+    balance_runways: BalanceRunways,
 ) -> Result<(), SentinelError> {
     let core_result = call_core(
         *core_timeout,
         websocket_tx.clone(),
-        WebSocketMessagesEncodable::GetStatus(network_ids),
+        WebSocketMessagesEncodable::GetStatus(network_ids, balance_runways),
     )
     .await?;
 
@@ -105,6 +107,8 @@ pub async fn status_publisher_loop(
     let mut core_is_connected = false;
     let network_ids = config.network_ids();
     let mut status_publisher_is_enabled = !disable;
+    // NOTE: Kept up to date by the balance monitor, so that any low balances are warned about in our status.
+    let mut balance_runways = BalanceRunways::default();
 
     if status_publisher_is_enabled {
         // NOTE: this will actually _error_ and exit the program if the check fails. Whereas later
@@ -144,6 +148,7 @@ pub async fn status_publisher_loop(
                             websocket_tx.clone(),
                             &core_timeout,
                             network_ids.clone(),
+                            balance_runways.clone(),
                         ).await {
                             Ok(_) => continue 'status_loop,
                             Err(e) => break 'status_loop Err(e)
@@ -187,6 +192,11 @@ pub async fn status_publisher_loop(
                                 core_is_connected = false;
                                 continue 'status_loop
                             },
+                            StatusPublisherBroadcastChannelMessages::BalanceRunways(runways) => {
+                                debug!("balance runways received in {name}");
+                                balance_runways = runways;
+                                continue 'status_loop
+                            },
                         }
                     },
                 }
@@ -1,14 +1,15 @@
 use common_sentinel::{Batch, EthRpcSenders, SentinelConfig, SentinelError, SyncerBroadcastChannelMessages};
 
 use super::{broadcast_channel_loop, syncer_loop};
-use crate::type_aliases::{BroadcastChannelTx, WebSocketTx};
+use crate::type_aliases::{BroadcastChannelTx, NotifierTx, WebSocketTx};
 
 pub async fn syncer(
-    batch: Batch,
-    config: SentinelConfig,
+    mut batch: Batch,
+    mut config: SentinelConfig,
     eth_rpc_senders: EthRpcSenders,
     websocket_tx: WebSocketTx,
     broadcast_channel_tx: BroadcastChannelTx,
+    notifier_tx: NotifierTx,
     disable_syncer: bool,
 ) -> Result<(), SentinelError> {
     batch.check_endpoint().await?;
@@ -53,6 +54,18 @@ pub async fn syncer(
                                 core_is_connected = false;
                                 continue 'syncer_loop
                             },
+                            SyncerBroadcastChannelMessages::ConfigUpdated(new_config) => {
+                                // NOTE: The syncer loop restarts on any broadcast message, so the
+                                // new config takes effect from the next block it gets.
+                                match batch.update_from_config(&new_config) {
+                                    Ok(_) => {
+                                        info!("config updated in {name} {note}");
+                                        config = *new_config;
+                                    },
+                                    Err(e) => error!("could not update {name} config, continuing with the old one: {e}"),
+                                };
+                                continue 'syncer_loop
+                            },
                         }
                     },
                     Err(e) => break 'syncer_loop Err(e),
@@ -63,6 +76,7 @@ pub async fn syncer(
                 config.clone(),
                 eth_rpc_tx.clone(),
                 websocket_tx.clone(),
+                notifier_tx.clone(),
                 &core_is_connected,
                 &core_time_limit,
             ), if core_is_connected && syncer_is_enabled => {
@@ -1,10 +1,12 @@
 use common_sentinel::{
     call_core,
     Batch,
+    Env,
     EthRpcMessages,
     LatestBlockInfos,
     SentinelConfig,
     SentinelError,
+    SentinelEvents,
     WebSocketMessages,
     WebSocketMessagesEncodable,
     WebSocketMessagesError,
@@ -12,7 +14,10 @@ use common_sentinel::{
 };
 use tokio::time::{sleep, Duration};
 
-use crate::type_aliases::{EthRpcTx, WebSocketTx};
+use crate::{
+    notifier::notify_with_timeout,
+    type_aliases::{EthRpcTx, NotifierTx, WebSocketTx},
+};
 
 const SLEEP_TIME: u64 = 10; // FIXME make configurable
 
@@ -21,14 +26,23 @@ pub(super) async fn syncer_loop(
     config: SentinelConfig,
     eth_rpc_tx: EthRpcTx,
     websocket_tx: WebSocketTx,
+    notifier_tx: NotifierTx,
     core_is_connected: &bool,
     core_time_limit: &u64,
 ) -> Result<(), SentinelError> {
     let network_id = *batch.network_id();
     let log_prefix = format!("{network_id} syncer");
     let validate = matches!(config.validate(&network_id), Ok(true));
-    let pnetwork_hub = config.pnetwork_hub(&network_id)?;
+    let pnetwork_hubs = config.pnetwork_hubs(&network_id)?;
     let sleep_duration = batch.get_sleep_duration();
+    // NOTE: Any challenges this sentinel starts are broadcast from the app's key, so the core needs
+    // that address in order to track them.
+    let challenger_address = if *config.challenger().enabled() {
+        Env::init()?;
+        Some(Env::get_private_key()?.to_address())
+    } else {
+        None
+    };
 
     let latest_block_numbers = 'latest_block_getter_loop: loop {
         if !core_is_connected {
@@ -76,9 +90,10 @@ pub(super) async fn syncer_loop(
                 let args = WebSocketMessagesProcessBatchArgs::new_for_syncer(
                     validate,
                     network_id,
-                    pnetwork_hub,
+                    pnetwork_hubs.clone(),
                     batch.to_submission_material(),
                     *batch.governance_address(),
+                    challenger_address,
                 );
                 let (msg, rx) = WebSocketMessages::new(WebSocketMessagesEncodable::ProcessBatch(args));
                 websocket_tx.send(msg).await?;
@@ -94,6 +109,13 @@ pub(super) async fn syncer_loop(
                 match websocket_response {
                     Ok(WebSocketMessagesEncodable::Success(output)) => {
                         debug!("{log_prefix} websocket channel returned success output: {output}");
+                        match SentinelEvents::try_from(output.clone()) {
+                            Ok(events) if !events.is_empty() => {
+                                notify_with_timeout(&notifier_tx, events).await?;
+                            },
+                            Ok(_) => (),
+                            Err(e) => warn!("{log_prefix} could not get events from processor output: {e}"),
+                        };
                         batch.update_bpm_from_json(output);
                         batch.increment_block_num();
                     },
@@ -1,7 +1,13 @@
+use std::sync::Arc;
+
 use common_sentinel::{
     BroadcastChannelMessages,
     ChallengeResponderMessages,
+    ChallengerMessages,
+    ConfigWatcherMessages,
     EthRpcMessages,
+    Notification,
+    NotifierMessages,
     StatusPublisherMessages,
     UserOpCancellerMessages,
     WebSocketMessages,
@@ -9,8 +15,11 @@ use common_sentinel::{
 use tokio::sync::{
     broadcast::{Receiver as MpmcRx, Sender as MpmcTx},
     mpsc::{Receiver as MpscRx, Sender as MpscTx},
+    Mutex,
 };
 
+use crate::backfill::BackfillJobs;
+
 pub(crate) type CoreCxnStatus = bool;
 pub(crate) type EthRpcTx = MpscTx<EthRpcMessages>;
 pub(crate) type WebSocketRx = MpscRx<WebSocketMessages>;
@@ -23,3 +32,12 @@ pub(crate) type BroadcastChannelTx = MpmcTx<BroadcastChannelMessages>;
 pub(crate) type BroadcastChannelRx = MpmcRx<BroadcastChannelMessages>;
 pub(crate) type ChallengeResponderTx = MpscTx<ChallengeResponderMessages>;
 pub(crate) type ChallengeResponderRx = MpscRx<ChallengeResponderMessages>;
+pub(crate) type ChallengerTx = MpscTx<ChallengerMessages>;
+pub(crate) type ChallengerRx = MpscRx<ChallengerMessages>;
+pub(crate) type NotifierTx = MpscTx<NotifierMessages>;
+pub(crate) type NotifierRx = MpscRx<NotifierMessages>;
+pub(crate) type NotificationsTx = MpmcTx<Notification>;
+pub(crate) type NotificationsRx = MpmcRx<Notification>;
+pub(crate) type ConfigWatcherTx = MpscTx<ConfigWatcherMessages>;
+pub(crate) type ConfigWatcherRx = MpscRx<ConfigWatcherMessages>;
+pub(crate) type SharedBackfillJobs = Arc<Mutex<BackfillJobs>>;
@@ -13,6 +13,8 @@ use common_sentinel::{
     EthRpcSenders,
     SentinelConfig,
     SentinelError,
+    SentinelEvent,
+    SentinelEvents,
     UserOpCancellationSignature,
     UserOpCancellerBroadcastChannelMessages,
     UserOpCancellerMessages,
@@ -23,13 +25,17 @@ use common_sentinel::{
 use ethereum_types::{H256 as EthHash, U256};
 use tokio::time::{sleep, Duration};
 
-use crate::type_aliases::{
-    BroadcastChannelRx,
-    BroadcastChannelTx,
-    EthRpcTx,
-    UserOpCancellerRx,
-    UserOpCancellerTx,
-    WebSocketTx,
+use crate::{
+    notifier::notify_with_timeout,
+    type_aliases::{
+        BroadcastChannelRx,
+        BroadcastChannelTx,
+        EthRpcTx,
+        NotifierTx,
+        UserOpCancellerRx,
+        UserOpCancellerTx,
+        WebSocketTx,
+    },
 };
 
 async fn cancel_user_op(
@@ -50,7 +56,10 @@ async fn cancel_user_op(
 
     let network_id_to_cancel_on = cancellable_user_op.network_id_to_cancel_on()?;
 
-    let pnetwork_hub = config.pnetwork_hub(&network_id_to_cancel_on)?;
+    let pnetwork_hub = config.pnetwork_hub_or_current(
+        &network_id_to_cancel_on,
+        cancellable_user_op.pnetwork_hub_to_cancel_on()?,
+    )?;
 
     debug!("cancelling user op on enqueued network: {network_id_to_cancel_on} nonce: {nonce} gas price: {gas_price}");
 
@@ -120,6 +129,7 @@ async fn cancel_user_ops(
     websocket_tx: WebSocketTx,
     eth_rpc_senders: EthRpcSenders,
     broadcasting_pk: &EthPrivateKey,
+    notifier_tx: NotifierTx,
 ) -> Result<(), SentinelError> {
     info!("handling user op cancellation request...");
     let cancellable_user_ops = CancellableUserOps::try_from(
@@ -170,12 +180,24 @@ async fn cancel_user_ops(
         {
             Err(e) => {
                 error!("{err_msg} {uid} {e}");
+                let event = SentinelEvent::CancellationTxFailed {
+                    uid,
+                    network_id: network_id_to_cancel_on,
+                    error: e.to_string(),
+                };
+                notify_with_timeout(&notifier_tx, SentinelEvents::new(vec![event])).await?;
             },
             Ok(tx_hash) => {
                 info!(
                     "user cancellable op {uid} cancelled successfully @ tx {} on {network_id_to_cancel_on}",
                     hex::encode(tx_hash.as_bytes())
                 );
+                let event = SentinelEvent::CancellationTxPushed {
+                    uid,
+                    tx_hash,
+                    network_id: network_id_to_cancel_on,
+                };
+                notify_with_timeout(&notifier_tx, SentinelEvents::new(vec![event])).await?;
             },
         }
     }
@@ -214,15 +236,16 @@ async fn cancellation_loop(frequency: &u64, user_op_canceller_tx: UserOpCancelle
 pub async fn user_op_canceller_loop(
     mut user_op_canceller_rx: UserOpCancellerRx,
     eth_rpc_senders: EthRpcSenders,
-    config: SentinelConfig,
+    mut config: SentinelConfig,
     broadcast_channel_tx: BroadcastChannelTx,
     websocket_tx: WebSocketTx,
     user_op_canceller_tx: UserOpCancellerTx,
+    notifier_tx: NotifierTx,
     disable: bool,
 ) -> Result<(), SentinelError> {
     let name = "user op canceller";
 
-    let mut frequency = 120; // FIXME make configurable! Make updatable whilst running too!
+    let mut frequency = *config.core().user_op_cancellation_frequency();
     let mut is_enabled = !disable;
     let mut core_is_connected = false;
 
@@ -275,6 +298,17 @@ pub async fn user_op_canceller_loop(
                                 core_is_connected = false;
                                 continue 'user_op_canceller_loop
                             },
+                            UserOpCancellerBroadcastChannelMessages::ConfigUpdated(new_config) => {
+                                info!("config updated in {name} {note}");
+                                let new_frequency = *new_config.core().user_op_cancellation_frequency();
+                                if new_frequency != *config.core().user_op_cancellation_frequency() {
+                                    // NOTE: Only a change in the config overrides any frequency set via RPC.
+                                    info!("updated {name} frequency to {new_frequency}");
+                                    frequency = new_frequency;
+                                };
+                                config = *new_config;
+                                continue 'user_op_canceller_loop
+                            },
                         }
                     },
                     Err(e) => break 'user_op_canceller_loop Err(e),
@@ -292,6 +326,7 @@ pub async fn user_op_canceller_loop(
                         websocket_tx.clone(),
                         eth_rpc_senders.clone(),
                         &broadcasting_pk,
+                        notifier_tx.clone(),
                     ).await {
                         Ok(_) => {
                             info!("finished handling user op cancellation request");
@@ -11,10 +11,18 @@ use axum::{
     Router,
     TypedHeader,
 };
+use common_debug_signers::DebugSignature;
 use common_network_ids::NetworkId;
 use common_sentinel::{
+    get_attestation_nonce,
+    get_utc_timestamp,
+    verify_core_attestation,
+    AttestationConfig,
     BroadcastChannelMessages,
     ChallengeResponderBroadcastChannelMessages,
+    ChallengerBroadcastChannelMessages,
+    DbIntegrity,
+    NotifierBroadcastChannelMessages,
     RpcServerBroadcastChannelMessages,
     SentinelConfig,
     SentinelError,
@@ -32,12 +40,74 @@ use tower_http::services::ServeDir;
 
 use crate::type_aliases::{BroadcastChannelTx, WebSocketRx};
 
+const STRONGBOX_TIMEOUT_MS: u64 = 30000; // TODO make configurable
+
+async fn request_from_core(
+    socket: &mut WebSocket,
+    msg: WebSocketMessagesEncodable,
+) -> Result<WebSocketMessagesEncodable, SentinelError> {
+    socket.send(Message::Text(msg.try_into()?)).await?;
+    tokio::select! {
+        _ = sleep(Duration::from_millis(STRONGBOX_TIMEOUT_MS)) => {
+            Err(WebSocketMessagesError::Timedout(STRONGBOX_TIMEOUT_MS).into())
+        },
+        r = socket.recv() => match r {
+            Some(Ok(Message::Text(m))) => WebSocketMessagesEncodable::try_from(m),
+            r => Err(SentinelError::Custom(format!("websocket did not return with expected response: {r:?}"))),
+        },
+    }
+}
+
+async fn check_core_attestation(socket: &mut WebSocket, config: &AttestationConfig) -> Result<(), SentinelError> {
+    let nonce = get_attestation_nonce();
+    let certificate_response = request_from_core(socket, WebSocketMessagesEncodable::GetAttestationCertificate).await?;
+    let signature_response = request_from_core(
+        socket,
+        WebSocketMessagesEncodable::GetAttestationSignature(nonce.clone()),
+    )
+    .await?;
+    let verified = verify_core_attestation(
+        &config.roots()?,
+        &config.policy()?,
+        certificate_response,
+        &nonce,
+        signature_response,
+        get_utc_timestamp()?,
+    )?;
+    debug!("core attestation verified: {}", verified.to_json());
+    Ok(())
+}
+
+async fn check_core_db_integrity(
+    socket: &mut WebSocket,
+    migration_signature: &Option<String>,
+) -> Result<DbIntegrity, SentinelError> {
+    let db_integrity =
+        DbIntegrity::try_from(request_from_core(socket, WebSocketMessagesEncodable::CheckDbIntegrity).await?)?;
+    match (db_integrity, migration_signature) {
+        (DbIntegrity::Invalid, Some(sig)) => {
+            // NOTE: The core only allows a db with no commitment at all to be migrated, & only once
+            // per signature since the debug signature's nonce is incremented.
+            warn!("core db integrity is invalid - attempting a signed db commitment migration");
+            let msg = WebSocketMessagesEncodable::MigrateDbCommitment(DebugSignature::new(Some(sig.clone())));
+            let r = request_from_core(socket, msg).await?;
+            if r.is_error() {
+                warn!("could not migrate core db commitment: {r}");
+            };
+            DbIntegrity::try_from(request_from_core(socket, WebSocketMessagesEncodable::CheckDbIntegrity).await?)
+        },
+        (db_integrity, _) => Ok(db_integrity),
+    }
+}
+
 async fn handle_socket(
     mut socket: WebSocket,
     who: SocketAddr,
     websocket_rx: Arc<Mutex<WebSocketRx>>,
     broadcast_channel_tx: BroadcastChannelTx,
     network_ids: Vec<NetworkId>,
+    attestation_config: AttestationConfig,
+    db_commitment_migration_signature: Option<String>,
 ) -> Result<(), SentinelError> {
     if socket.send(Message::Ping(vec![1, 3, 3, 7])).await.is_ok() {
         debug!("pinged {}...", who);
@@ -65,6 +135,35 @@ async fn handle_socket(
         },
     };
 
+    if *attestation_config.enabled() {
+        // NOTE: A core whose key isn't attested to be in genuine secure hardware is refused, before
+        // any other component is told that a core is connected.
+        if let Err(e) = check_core_attestation(&mut socket, &attestation_config).await {
+            error!("refusing core at {who} - could not verify its attestation: {e}");
+            return Ok(());
+        };
+        info!("core attestation verified for {who}");
+    };
+
+    // NOTE: A core whose db has been edited outside of it is refused too.
+    match check_core_db_integrity(&mut socket, &db_commitment_migration_signature).await {
+        Ok(DbIntegrity::NoHash) => {
+            warn!("core at {who} is not initialized yet - its db commitment is created on its next write")
+        },
+        Ok(DbIntegrity::ValidSinceMigration) => {
+            warn!("core at {who} has a migrated db - only entries written since are protected by its db commitment")
+        },
+        Ok(db_integrity) if db_integrity.is_valid() => info!("core at {who} db integrity: {db_integrity}"),
+        Ok(db_integrity) => {
+            error!("refusing core at {who} - its db integrity is not valid: {db_integrity}");
+            return Ok(());
+        },
+        Err(e) => {
+            error!("refusing core at {who} - could not check its db integrity: {e}");
+            return Ok(());
+        },
+    };
+
     let (mut sender, mut receiver) = socket.split();
 
     // NOTE: Trying the lock here limits us to one active connection.
@@ -91,6 +190,12 @@ async fn handle_socket(
     broadcast_channel_tx.send(BroadcastChannelMessages::ChallengeResponder(
         ChallengeResponderBroadcastChannelMessages::CoreConnected,
     ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::Challenger(
+        ChallengerBroadcastChannelMessages::CoreConnected,
+    ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::Notifier(
+        NotifierBroadcastChannelMessages::CoreConnected,
+    ))?;
 
     'ws_loop: loop {
         tokio::select! {
@@ -99,7 +204,6 @@ async fn handle_socket(
                     // NOTE: Pass the message on to whomever is connected to the server.
                     sender.send(Message::Text(msg.try_into()?)).await?;
 
-                    const STRONGBOX_TIMEOUT_MS: u64 = 30000; // TODO make configurable
                      // NOTE: We race the response against a timeout
                     tokio::select! {
                         _ = sleep(Duration::from_millis(STRONGBOX_TIMEOUT_MS)) => {
@@ -172,6 +276,12 @@ async fn handle_socket(
     broadcast_channel_tx.send(BroadcastChannelMessages::ChallengeResponder(
         ChallengeResponderBroadcastChannelMessages::CoreDisconnected,
     ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::Challenger(
+        ChallengerBroadcastChannelMessages::CoreDisconnected,
+    ))?;
+    broadcast_channel_tx.send(BroadcastChannelMessages::Notifier(
+        NotifierBroadcastChannelMessages::CoreDisconnected,
+    ))?;
 
     error!("websocket context {who} destroyed");
     Ok(())
@@ -182,13 +292,23 @@ struct AppState {
     network_ids: Vec<NetworkId>,
     websocket_rx: Arc<Mutex<WebSocketRx>>,
     broadcast_channel_tx: BroadcastChannelTx,
+    attestation_config: AttestationConfig,
+    db_commitment_migration_signature: Option<String>,
 }
 
 impl AppState {
-    fn new(websocket_rx: WebSocketRx, broadcast_channel_tx: BroadcastChannelTx, network_ids: Vec<NetworkId>) -> Self {
+    fn new(
+        websocket_rx: WebSocketRx,
+        broadcast_channel_tx: BroadcastChannelTx,
+        network_ids: Vec<NetworkId>,
+        attestation_config: AttestationConfig,
+        db_commitment_migration_signature: Option<String>,
+    ) -> Self {
         Self {
             network_ids,
             broadcast_channel_tx,
+            attestation_config,
+            db_commitment_migration_signature,
             websocket_rx: Arc::new(Mutex::new(websocket_rx)),
         }
     }
@@ -213,6 +333,8 @@ async fn ws_handler(
             state.websocket_rx,
             state.broadcast_channel_tx,
             state.network_ids,
+            state.attestation_config,
+            state.db_commitment_migration_signature,
         )
         .await
         {
@@ -235,7 +357,13 @@ async fn start_ws_server(
     let app = Router::new()
         .fallback_service(ServeDir::new(assets_dir).append_index_html_on_directories(true))
         .route("/ws", get(ws_handler))
-        .with_state(AppState::new(websocket_rx, broadcast_channel_tx, config.network_ids()));
+        .with_state(AppState::new(
+            websocket_rx,
+            broadcast_channel_tx,
+            config.network_ids(),
+            config.attestation().clone(),
+            config.core().db_commitment_migration_signature().clone(),
+        ));
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000)); // FIXME make configurable
     debug!("ws server listening on {}", addr);
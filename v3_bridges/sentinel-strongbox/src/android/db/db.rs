@@ -1,7 +1,8 @@
+use std::cell::RefCell;
+
 use common::Bytes;
-use common_sentinel::SentinelError;
+use common_sentinel::{DbChanges, SentinelError};
 use derive_getters::Getters;
-use derive_more::Constructor;
 use jni::{
     objects::{JObject, JValue},
     JNIEnv,
@@ -12,13 +13,23 @@ use crate::android::{
     type_aliases::{ByteArray, DataSensitivity},
 };
 
-#[derive(Constructor, Getters)]
+#[derive(Getters)]
 pub struct Database<'a> {
     env: &'a JNIEnv<'a>,
     db_java_class: JObject<'a>,
+    // NOTE: Folded into the db commitment when the transaction ends.
+    changes: RefCell<DbChanges>,
 }
 
 impl<'a> Database<'a> {
+    pub fn new(env: &'a JNIEnv<'a>, db_java_class: JObject<'a>) -> Self {
+        Self {
+            env,
+            db_java_class,
+            changes: RefCell::new(DbChanges::default()),
+        }
+    }
+
     fn to_java_byte_array(&self, bs: &ByteArray) -> Result<JValue, SentinelError> {
         Ok(JValue::from(JObject::from(self.env.byte_array_from_slice(bs)?)))
     }
@@ -29,7 +40,11 @@ impl<'a> Database<'a> {
             .env
             .call_method(self.db_java_class, "delete", "([B)V", &[self.to_java_byte_array(k)?])
         {
-            Ok(_) => check_and_handle_java_exceptions(self.env, print_exceptions),
+            Ok(_) => {
+                check_and_handle_java_exceptions(self.env, print_exceptions)?;
+                self.changes.borrow_mut().record_delete(k);
+                Ok(())
+            },
             Err(e) => self.handle_error(Err(e), print_exceptions),
         }
     }
@@ -63,7 +78,11 @@ impl<'a> Database<'a> {
             JValue::from(sensitivity.unwrap_or_default()),
         ];
         match self.env.call_method(self.db_java_class, "put", "([B[BB)V", &args) {
-            Ok(_) => check_and_handle_java_exceptions(self.env, print_exceptions),
+            Ok(_) => {
+                check_and_handle_java_exceptions(self.env, print_exceptions)?;
+                self.changes.borrow_mut().record_put(k, v, sensitivity);
+                Ok(())
+            },
             Err(e) => self.handle_error(Err(e), print_exceptions),
         }
     }
@@ -1,7 +1,19 @@
-use common::{AppError as CommonError, Bytes, DatabaseInterface};
+use common::{constants::DB_KEY_NOT_FOUND_ERROR, AppError as CommonError, Bytes, DatabaseInterface};
+use common_sentinel::SentinelError;
 
 use crate::android::{type_aliases::DataSensitivity, Database};
 
+// NOTE: The java db throws an exception when it doesn't hold a key, so we map that to the error
+// the rust core expects for missing keys, letting it tell those apart from actual failures.
+fn convert_get_error(e: SentinelError) -> CommonError {
+    match e {
+        SentinelError::JavaExceptionOccurred | SentinelError::JniError(jni::errors::Error::JavaException) => {
+            CommonError::Custom(DB_KEY_NOT_FOUND_ERROR.into())
+        },
+        e => e.into(),
+    }
+}
+
 impl DatabaseInterface for Database<'_> {
     fn end_transaction(&self) -> Result<(), CommonError> {
         self.end_transaction().map_err(|e| e.into())
@@ -16,10 +28,22 @@ impl DatabaseInterface for Database<'_> {
     }
 
     fn get(&self, key: Bytes, data_sensitivity: DataSensitivity) -> Result<Bytes, CommonError> {
-        self.get(&key, data_sensitivity).map_err(|e| e.into())
+        self.get(&key, data_sensitivity).map_err(convert_get_error)
     }
 
     fn put(&self, key: Bytes, value: Bytes, data_sensitivity: DataSensitivity) -> Result<(), CommonError> {
         self.put(&key, &value, data_sensitivity).map_err(|e| e.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_report_java_exceptions_on_get_as_missing_keys() {
+        assert!(convert_get_error(SentinelError::JavaExceptionOccurred).is_db_key_not_found());
+        assert!(convert_get_error(SentinelError::JniError(jni::errors::Error::JavaException)).is_db_key_not_found());
+        assert!(!convert_get_error(SentinelError::Custom("some other error".into())).is_db_key_not_found());
+    }
+}
@@ -1,4 +1,4 @@
-use common_sentinel::{DbIntegrity, SentinelError};
+use common_sentinel::{DbCommitment, DbIntegrity, SentinelError};
 use jni::objects::JString;
 
 use super::DbJsonResponse;
@@ -36,6 +36,10 @@ impl<'a> Database<'a> {
     pub fn end_transaction(&self) -> Result<(), SentinelError> {
         debug!("ending db transaction...");
 
+        // NOTE: The commitment is written within the transaction, so that it's only ever
+        // committed along with the changes it covers.
+        DbCommitment::update(self, self.changes().take())?;
+
         let env = self.env();
         match env.call_method(*self.db_java_class(), "endTransaction", "()Ljava/lang/String;", &[]) {
             Err(e) => self.handle_error(Err(e), PRINT_JAVA_ERRORS),
@@ -34,26 +34,41 @@ pub fn handle_websocket_message(state: State) -> Result<State, SentinelError> {
     info!("handling websocket msg: '{msg}'...");
     let final_state = match msg {
         Msg::GetUserOps => super::handlers::get_user_ops(state),
+        Msg::GetActors => super::handlers::get_actors(state),
         Msg::GetUserOpList => super::handlers::get_user_op_list(state),
         Msg::Initialize(args) => super::handlers::init(*args.clone(), state),
         Msg::GetChallengesList => super::handlers::get_challenges_list(state),
         Msg::GetInclusionProof => super::handlers::get_inclusion_proof(state),
         Msg::GetChallenge(hash) => super::handlers::get_challenge(*hash, state),
         Msg::GetUserOp(uid) => super::handlers::get_user_op(uid.clone(), state),
-        Msg::GetStatus(nids) => super::handlers::get_status(nids.clone(), state),
+        Msg::GetStatus(nids, runways) => super::handlers::get_status(nids.clone(), runways.clone(), state),
         Msg::ResetChain(args) => super::handlers::reset_chain(*args.clone(), state),
         Msg::GetUnsolvedChallenges => super::handlers::get_unsolved_challenges(state),
+        Msg::GetStartedChallenges => super::handlers::get_started_challenges(state),
+        Msg::GetWebhookQueue => super::handlers::get_webhook_queue(state),
+        Msg::SetWebhookQueue(queue) => super::handlers::set_webhook_queue(queue.clone(), state),
         Msg::CheckInit(network_id) => super::handlers::check_init(*network_id, state),
+        Msg::CheckDbIntegrity => super::handlers::check_db_integrity(state),
+        Msg::GetDbIntegrityProof(key) => super::handlers::get_db_integrity_proof(key.clone(), state),
+        Msg::MigrateDbCommitment(debug_sig) => super::handlers::migrate_db_commitment(debug_sig.clone(), state),
         Msg::ProcessBatch(args) => super::handlers::process_batch(*args.clone(), state),
         Msg::GetCoreState(nids) => super::handlers::get_core_state(nids.clone(), state),
         Msg::HardReset(debug_sig) => super::handlers::hard_reset(debug_sig.clone(), state),
+        Msg::AuthorizeSignTypedData(hash, sig) => super::handlers::authorize_sign_typed_data(*hash, sig.clone(), state),
         Msg::GetAttestationCertificate => super::handlers::get_attestation_certificate(state),
         Msg::GetUserOpByTxHash(tx_hash) => super::handlers::get_user_op_by_tx_hash(*tx_hash, state),
+        Msg::GetUserOpRoute(uid) => super::handlers::get_user_op_route(uid.clone(), state),
         Msg::PurgeUserOps(epoch, sig) => super::handlers::purge_user_ops(*epoch, sig.clone(), state),
         Msg::GetLatestBlockInfos(nids) => super::handlers::get_latest_block_infos(nids.clone(), state),
         Msg::RemoveUserOp(uid, sig) => super::handlers::remove_user_op(uid.clone(), sig.clone(), state),
         Msg::RemoveChallenge(hash, sig) => super::handlers::remove_challenge(*hash, sig.clone(), state),
+        Msg::InitLightClient(nid, bootstrap, root, sig) => {
+            super::handlers::init_light_client(*nid, bootstrap.clone(), *root, sig.clone(), state)
+        },
         Msg::SetChallengesToSolved(ids) => super::handlers::set_challenges_to_solved(ids.clone(), state),
+        Msg::SetStartedChallengeState(id, status) => {
+            super::handlers::set_started_challenge_state(*id, status.clone(), state)
+        },
         Msg::GetCancellableUserOps(nids) => super::handlers::get_cancellable_user_ops(nids.clone(), state),
         Msg::GetAttestationSignature(bytes) => super::handlers::get_attestation_signature(bytes.clone(), state),
         Msg::AddDebugSigners(signers, sig) => super::handlers::add_debug_signers(signers.clone(), sig.clone(), state),
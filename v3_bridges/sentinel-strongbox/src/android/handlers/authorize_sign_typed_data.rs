@@ -0,0 +1,16 @@
+use common_debug_signers::{validate_debug_command_signature, DebugSignature};
+use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
+use ethereum_types::H256 as EthHash;
+use function_name::named;
+use serde_json::json;
+
+use crate::android::{State, CORE_TYPE};
+
+#[named]
+pub fn authorize_sign_typed_data(hash: EthHash, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
+    debug!("authorizing signing of typed data with hash {hash}...");
+    let h = get_debug_command_hash!(function_name!(), &hash)()?;
+    validate_debug_command_signature(state.db(), &CORE_TYPE, &sig.to_string(), &h, cfg!(test))?;
+    let r = WebSocketMessagesEncodable::Success(json!({ "authorized": true, "typedDataHash": hash }));
+    Ok(state.add_response(r))
+}
@@ -0,0 +1,15 @@
+use common_sentinel::{DbCommitment, SentinelError, WebSocketMessagesEncodable};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn check_db_integrity(state: State) -> Result<State, SentinelError> {
+    debug!("checking db integrity...");
+    let db_integrity = DbCommitment::check(state.db())?;
+    debug!("db integrity: {db_integrity}");
+    let r = WebSocketMessagesEncodable::Success(json!({
+        "dbIntegrity": db_integrity,
+        "isValid": db_integrity.is_valid(),
+    }));
+    Ok(state.add_response(r))
+}
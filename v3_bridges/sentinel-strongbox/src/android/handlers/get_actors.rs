@@ -0,0 +1,10 @@
+use common_sentinel::{Actors, SentinelDbUtils, SentinelError, WebSocketMessagesEncodable};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn get_actors(state: State) -> Result<State, SentinelError> {
+    let actors = Actors::get(&SentinelDbUtils::new(state.db()));
+    let r = WebSocketMessagesEncodable::Success(json!(actors));
+    Ok(state.add_response(r))
+}
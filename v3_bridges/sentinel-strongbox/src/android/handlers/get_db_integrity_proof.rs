@@ -0,0 +1,11 @@
+use common_sentinel::{DbCommitment, SentinelError, WebSocketMessagesEncodable};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn get_db_integrity_proof(key: Vec<u8>, state: State) -> Result<State, SentinelError> {
+    debug!("getting db integrity proof for key 0x{}...", hex::encode(&key));
+    let proof = DbCommitment::get_proof_from_db(state.db(), &key)?;
+    let r = WebSocketMessagesEncodable::Success(json!(proof));
+    Ok(state.add_response(r))
+}
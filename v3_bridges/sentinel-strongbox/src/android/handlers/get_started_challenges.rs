@@ -0,0 +1,10 @@
+use common_sentinel::{SentinelDbUtils, SentinelError, StartedChallengesList, WebSocketMessagesEncodable};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn get_started_challenges(state: State) -> Result<State, SentinelError> {
+    let started = StartedChallengesList::get_started_challenges(&SentinelDbUtils::new(state.db()))?;
+    let r = WebSocketMessagesEncodable::Success(json!(started));
+    Ok(state.add_response(r))
+}
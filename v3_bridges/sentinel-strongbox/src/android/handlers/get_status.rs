@@ -1,12 +1,16 @@
 use common_eth::{Chain, ChainDbUtils};
 use common_metadata::MetadataChainId;
 use common_network_ids::{NetworkId, NetworkIdError};
-use common_sentinel::{SentinelError, SentinelStatus, WebSocketMessagesEncodable};
+use common_sentinel::{BalanceRunways, SentinelError, SentinelStatus, WebSocketMessagesEncodable};
 use serde_json::json;
 
 use crate::android::State;
 
-pub fn get_status(network_ids: Vec<NetworkId>, state: State) -> Result<State, SentinelError> {
+pub fn get_status(
+    network_ids: Vec<NetworkId>,
+    balance_runways: BalanceRunways,
+    state: State,
+) -> Result<State, SentinelError> {
     debug!("handling `getStatus` message in strongbox...");
     let db_utils = ChainDbUtils::new(state.db());
     let mcids = network_ids
@@ -20,7 +24,7 @@ pub fn get_status(network_ids: Vec<NetworkId>, state: State) -> Result<State, Se
 
     let key = db_utils.get_pk()?;
 
-    let status = SentinelStatus::new(&key, chains)?;
+    let status = SentinelStatus::new(&key, chains, balance_runways)?;
 
     let r = WebSocketMessagesEncodable::Success(json!(status));
     Ok(state.add_response(r))
@@ -0,0 +1,11 @@
+use common_sentinel::{SentinelDbUtils, SentinelError, UserOpRoute, UserOpUniqueId, WebSocketMessagesEncodable};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn get_user_op_route(uid: UserOpUniqueId, state: State) -> Result<State, SentinelError> {
+    let db_utils = SentinelDbUtils::new(state.db());
+    let route = UserOpRoute::get(&uid, &db_utils)?;
+    let r = WebSocketMessagesEncodable::Success(json!(route));
+    Ok(state.add_response(r))
+}
@@ -0,0 +1,10 @@
+use common_sentinel::{SentinelDbUtils, SentinelError, WebSocketMessagesEncodable, WebhookQueue};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn get_webhook_queue(state: State) -> Result<State, SentinelError> {
+    let queue = WebhookQueue::get(&SentinelDbUtils::new(state.db()));
+    let r = WebSocketMessagesEncodable::Success(json!(queue));
+    Ok(state.add_response(r))
+}
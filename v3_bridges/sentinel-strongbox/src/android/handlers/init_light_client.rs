@@ -0,0 +1,38 @@
+use common_debug_signers::{validate_debug_command_signature, DebugSignature};
+use common_eth::{Chain, ChainDbUtils, ChainError};
+use common_metadata::MetadataChainId;
+use common_network_ids::NetworkId;
+use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
+use ethereum_types::H256 as EthHash;
+use function_name::named;
+use serde_json::json;
+
+use crate::android::{State, CORE_TYPE};
+
+#[named]
+pub fn init_light_client(
+    network_id: NetworkId,
+    bootstrap_json: String,
+    trusted_block_root: EthHash,
+    sig: DebugSignature,
+    state: State,
+) -> Result<State, SentinelError> {
+    let h = get_debug_command_hash!(function_name!(), &network_id, &bootstrap_json, &trusted_block_root)()?;
+    validate_debug_command_signature(state.db(), &CORE_TYPE, &sig.to_string(), &h, cfg!(test))?;
+    let mcid = MetadataChainId::try_from(network_id)?;
+    debug!("initializing light client for {network_id} chain...");
+
+    let store = Chain::init_light_client(
+        &ChainDbUtils::new(state.db()),
+        mcid,
+        &bootstrap_json,
+        &trusted_block_root,
+    )?;
+
+    Ok(state.add_response(WebSocketMessagesEncodable::Success(json!({
+        "mcid": mcid,
+        "lightClientInitialized": true,
+        "finalizedSlot": store.finalized_header.beacon.slot,
+        "finalizedBlockNum": store.get_finalized_execution_block_number().map_err(ChainError::from)?,
+    }))))
+}
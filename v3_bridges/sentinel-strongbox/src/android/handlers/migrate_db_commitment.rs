@@ -0,0 +1,16 @@
+use common_debug_signers::{validate_debug_command_signature, DebugSignature};
+use common_sentinel::{DbCommitment, SentinelError, WebSocketMessagesEncodable};
+use function_name::named;
+use serde_json::json;
+
+use crate::android::{State, CORE_TYPE};
+
+#[named]
+pub fn migrate_db_commitment(debug_sig: DebugSignature, state: State) -> Result<State, SentinelError> {
+    debug!("migrating db commitment...");
+    let h = get_debug_command_hash!(function_name!())()?;
+    validate_debug_command_signature(state.db(), &CORE_TYPE, &debug_sig.to_string(), &h, cfg!(test))?;
+    let commitment = DbCommitment::migrate(state.db())?;
+    let r = WebSocketMessagesEncodable::Success(json!({ "dbCommitmentMigrated": true, "root": commitment.root() }));
+    Ok(state.add_response(r))
+}
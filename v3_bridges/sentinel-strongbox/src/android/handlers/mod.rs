@@ -1,24 +1,33 @@
 mod add_debug_signers;
+mod authorize_sign_typed_data;
+mod check_db_integrity;
 mod check_init;
 mod db_ops;
+mod get_actors;
 mod get_attestation_certificate;
 mod get_attestation_signature;
 mod get_cancellable_user_ops;
 mod get_challenge;
 mod get_challenges_list;
 mod get_core_state;
+mod get_db_integrity_proof;
 mod get_inclusion_proof;
 mod get_latest_block_infos;
 mod get_registration_signature;
+mod get_started_challenges;
 mod get_status;
 mod get_unsolved_challenges;
 mod get_user_op;
 mod get_user_op_by_tx_hash;
 mod get_user_op_cancellation_signature;
 mod get_user_op_list;
+mod get_user_op_route;
 mod get_user_ops;
+mod get_webhook_queue;
 mod hard_reset;
 mod init;
+mod init_light_client;
+mod migrate_db_commitment;
 mod process_batch;
 mod purge_user_ops;
 mod remove_challenge;
@@ -26,29 +35,40 @@ mod remove_debug_signer;
 mod remove_user_op;
 mod reset_chain;
 mod set_challenges_to_solved;
+mod set_started_challenge_state;
+mod set_webhook_queue;
 
 pub(crate) use self::{
     add_debug_signers::add_debug_signers,
+    authorize_sign_typed_data::authorize_sign_typed_data,
+    check_db_integrity::check_db_integrity,
     check_init::check_init,
     db_ops::{delete, get, put},
+    get_actors::get_actors,
     get_attestation_certificate::get_attestation_certificate,
     get_attestation_signature::get_attestation_signature,
     get_cancellable_user_ops::get_cancellable_user_ops,
     get_challenge::get_challenge,
     get_challenges_list::get_challenges_list,
     get_core_state::get_core_state,
+    get_db_integrity_proof::get_db_integrity_proof,
     get_inclusion_proof::get_inclusion_proof,
     get_latest_block_infos::get_latest_block_infos,
     get_registration_signature::get_registration_signature,
+    get_started_challenges::get_started_challenges,
     get_status::get_status,
     get_unsolved_challenges::get_unsolved_challenges,
     get_user_op::get_user_op,
     get_user_op_by_tx_hash::get_user_op_by_tx_hash,
     get_user_op_cancellation_signature::get_user_op_cancellation_signature,
     get_user_op_list::get_user_op_list,
+    get_user_op_route::get_user_op_route,
     get_user_ops::get_user_ops,
+    get_webhook_queue::get_webhook_queue,
     hard_reset::hard_reset,
     init::init,
+    init_light_client::init_light_client,
+    migrate_db_commitment::migrate_db_commitment,
     process_batch::process_batch,
     purge_user_ops::purge_user_ops,
     remove_challenge::remove_challenge,
@@ -56,4 +76,6 @@ pub(crate) use self::{
     remove_user_op::remove_user_op,
     reset_chain::reset_chain,
     set_challenges_to_solved::set_challenges_to_solved,
+    set_started_challenge_state::set_started_challenge_state,
+    set_webhook_queue::set_webhook_queue,
 };
@@ -16,7 +16,7 @@ pub fn process_batch(args: WebSocketMessagesProcessBatchArgs, state: State) -> R
 
     let result = process_batch_of_blocks(
         state.db(),
-        args.pnetwork_hub(),
+        args.pnetwork_hubs(),
         args.sub_mat_batch(),
         *args.validate(),
         network_id,
@@ -24,6 +24,7 @@ pub fn process_batch(args: WebSocketMessagesProcessBatchArgs, state: State) -> R
         *args.dry_run(),
         *args.governance_address(),
         sentinel_address,
+        *args.challenger_address(),
     );
 
     let response = match result {
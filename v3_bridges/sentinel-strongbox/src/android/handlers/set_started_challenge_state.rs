@@ -0,0 +1,22 @@
+use common_sentinel::{
+    ChallengeState,
+    SentinelDbUtils,
+    SentinelError,
+    StartedChallengesList,
+    WebSocketMessagesEncodable,
+};
+use ethereum_types::H256 as EthHash;
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn set_started_challenge_state(id: EthHash, status: ChallengeState, state: State) -> Result<State, SentinelError> {
+    debug!("setting started challenge status to {status} for id: {id}");
+    let s_db_utils = SentinelDbUtils::new(state.db());
+
+    let mut list = StartedChallengesList::get(&s_db_utils);
+    list.update_challenge_status(&s_db_utils, &id, status.clone())?;
+
+    let r = WebSocketMessagesEncodable::Success(json!({"startedChallenge": id, "status": status}));
+    Ok(state.add_response(r))
+}
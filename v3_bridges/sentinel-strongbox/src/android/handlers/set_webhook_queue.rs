@@ -0,0 +1,11 @@
+use common_sentinel::{DbUtilsT, SentinelDbUtils, SentinelError, WebSocketMessagesEncodable, WebhookQueue};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn set_webhook_queue(queue: WebhookQueue, state: State) -> Result<State, SentinelError> {
+    debug!("setting webhook queue with {} pending deliveries", queue.len());
+    queue.update_in_db(&SentinelDbUtils::new(state.db()))?;
+    let r = WebSocketMessagesEncodable::Success(json!({"webhookQueueLength": queue.len()}));
+    Ok(state.add_response(r))
+}